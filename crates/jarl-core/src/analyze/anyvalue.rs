@@ -2,10 +2,21 @@ use crate::checker::Checker;
 use crate::rule_set::Rule;
 use air_r_syntax::AnyRValue;
 
+use crate::lints::base::embedded_data::embedded_data::embedded_data;
+use crate::lints::base::magic_numbers::magic_numbers::magic_numbers;
 use crate::lints::base::numeric_leading_zero::numeric_leading_zero::numeric_leading_zero;
 use crate::lints::base::quotes::quotes::quotes;
 
 pub fn anyvalue(r_expr: &AnyRValue, checker: &mut Checker) -> anyhow::Result<()> {
+    if checker.is_rule_enabled(Rule::EmbeddedData) {
+        checker.report_diagnostic(embedded_data(
+            r_expr,
+            checker.rule_options.embedded_data.max_size,
+        )?);
+    }
+    if checker.is_rule_enabled(Rule::MagicNumbers) {
+        checker.report_diagnostic(magic_numbers(r_expr, checker)?);
+    }
     if checker.is_rule_enabled(Rule::NumericLeadingZero) {
         checker.report_diagnostic(numeric_leading_zero(r_expr)?);
     }
@@ -4,20 +4,31 @@ use air_r_syntax::RBinaryExpression;
 
 use crate::lints::base::any_is_na::any_is_na::any_is_na_2;
 use crate::lints::base::assignment::assignment::assignment;
+use crate::lints::base::case_comparison::case_comparison::case_comparison;
 use crate::lints::base::class_equals::class_equals::class_equals;
+use crate::lints::base::comparison_chain::comparison_chain::comparison_chain;
 use crate::lints::base::empty_assignment::empty_assignment::empty_assignment;
 use crate::lints::base::equals_na::equals_na::equals_na;
 use crate::lints::base::equals_nan::equals_nan::equals_nan;
 use crate::lints::base::equals_null::equals_null::equals_null;
+use crate::lints::base::for_loop_index_assignment::for_loop_index_assignment::for_loop_index_assignment;
 use crate::lints::base::implicit_assignment::implicit_assignment::implicit_assignment;
 use crate::lints::base::is_numeric::is_numeric::is_numeric;
+use crate::lints::base::length_zero::length_zero::length_zero_2;
+use crate::lints::base::na_unsafe_string_check::na_unsafe_string_check::na_unsafe_string_check;
 use crate::lints::base::nested_pipe::nested_pipe::nested_pipe;
 use crate::lints::base::nzchar::nzchar::nzchar;
 use crate::lints::base::pipe_consistency::pipe_consistency::pipe_consistency;
 use crate::lints::base::pipe_return::pipe_return::pipe_return;
 use crate::lints::base::redundant_equals::redundant_equals::redundant_equals;
+use crate::lints::base::roxygen_completeness::roxygen_completeness::roxygen_completeness;
+use crate::lints::base::scalar_logical_check::scalar_logical_check::scalar_logical_check;
 use crate::lints::base::seq::seq::seq;
 use crate::lints::base::string_boundary::string_boundary::string_boundary;
+use crate::lints::base::suspicious_rounding::suspicious_rounding::suspicious_rounding;
+use crate::lints::base::sys_time_comparison::sys_time_comparison::sys_time_comparison;
+use crate::lints::base::unprinted_ggplot::unprinted_ggplot::unprinted_ggplot;
+use crate::lints::base::unsafe_vector_recycling::unsafe_vector_recycling::unsafe_vector_recycling;
 use crate::lints::base::vector_logic::vector_logic::vector_logic;
 
 pub fn binary_expression(r_expr: &RBinaryExpression, checker: &mut Checker) -> anyhow::Result<()> {
@@ -30,9 +41,15 @@ pub fn binary_expression(r_expr: &RBinaryExpression, checker: &mut Checker) -> a
             checker.rule_options.assignment.operator,
         )?);
     }
+    if checker.is_rule_enabled(Rule::CaseComparison) {
+        checker.report_diagnostic(case_comparison(r_expr)?);
+    }
     if checker.is_rule_enabled(Rule::ClassEquals) {
         checker.report_diagnostic(class_equals(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::ComparisonChain) {
+        checker.report_diagnostic(comparison_chain(r_expr)?);
+    }
     if checker.is_rule_enabled(Rule::VectorLogic) {
         checker.report_diagnostic(vector_logic(r_expr)?);
     }
@@ -48,12 +65,24 @@ pub fn binary_expression(r_expr: &RBinaryExpression, checker: &mut Checker) -> a
     if checker.is_rule_enabled(Rule::EqualsNull) {
         checker.report_diagnostic(equals_null(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::ForLoopIndexAssignment) {
+        checker.report_diagnostic(for_loop_index_assignment(r_expr)?);
+    }
     if checker.is_rule_enabled(Rule::ImplicitAssignment) {
         checker.report_diagnostic(implicit_assignment(r_expr, checker)?);
     }
     if checker.is_rule_enabled(Rule::IsNumeric) {
         checker.report_diagnostic(is_numeric(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::LengthZero) {
+        checker.report_diagnostic(length_zero_2(
+            r_expr,
+            checker.rule_options.length_zero.idiom,
+        )?);
+    }
+    if checker.is_rule_enabled(Rule::NaUnsafeStringCheck) {
+        checker.report_diagnostic(na_unsafe_string_check(r_expr)?);
+    }
     if checker.is_rule_enabled(Rule::NestedPipe) {
         checker.report_diagnostic(nested_pipe(r_expr, checker)?);
     }
@@ -72,11 +101,34 @@ pub fn binary_expression(r_expr: &RBinaryExpression, checker: &mut Checker) -> a
     if checker.is_rule_enabled(Rule::RedundantEquals) {
         checker.report_diagnostic(redundant_equals(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::RoxygenCompleteness) {
+        for diagnostic in roxygen_completeness(r_expr, checker)? {
+            checker.report_diagnostic(Some(diagnostic));
+        }
+    }
+    if checker.is_rule_enabled(Rule::ScalarLogicalCheck) {
+        checker.report_diagnostic(scalar_logical_check(
+            r_expr,
+            checker.rule_options.scalar_logical_check.target,
+        )?);
+    }
     if checker.is_rule_enabled(Rule::Seq) {
         checker.report_diagnostic(seq(r_expr)?);
     }
     if checker.is_rule_enabled(Rule::StringBoundary) {
         checker.report_diagnostic(string_boundary(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::SuspiciousRounding) {
+        checker.report_diagnostic(suspicious_rounding(r_expr)?);
+    }
+    if checker.is_rule_enabled(Rule::SysTimeComparison) {
+        checker.report_diagnostic(sys_time_comparison(r_expr)?);
+    }
+    if checker.is_rule_enabled(Rule::UnprintedGgplot) {
+        checker.report_diagnostic(unprinted_ggplot(r_expr)?);
+    }
+    if checker.is_rule_enabled(Rule::UnsafeVectorRecycling) {
+        checker.report_diagnostic(unsafe_vector_recycling(r_expr)?);
+    }
     Ok(())
 }
@@ -6,38 +6,63 @@ use air_r_syntax::RCall;
 use crate::lints::base::all_equal::all_equal::all_equal;
 use crate::lints::base::any_duplicated::any_duplicated::any_duplicated;
 use crate::lints::base::any_is_na::any_is_na::any_is_na;
+use crate::lints::base::as_date_format::as_date_format::as_date_format;
 use crate::lints::base::browser::browser::browser;
 use crate::lints::base::class_equals::class_equals::class_identical;
 use crate::lints::base::condition_call::condition_call::condition_call;
 use crate::lints::base::condition_message::condition_message::condition_message;
 use crate::lints::base::download_file::download_file::download_file;
 use crate::lints::base::duplicated_arguments::duplicated_arguments::duplicated_arguments;
+use crate::lints::base::embedded_data::embedded_data::embedded_data_2;
+use crate::lints::base::file_list_order::file_list_order::file_list_order;
 use crate::lints::base::fixed_regex::fixed_regex::fixed_regex;
 use crate::lints::base::glue::glue::glue;
 use crate::lints::base::grepv::grepv::grepv;
 use crate::lints::base::if_not_else::if_not_else::if_not_else_call;
+use crate::lints::base::invisible_return::invisible_return::invisible_return;
 use crate::lints::base::length_levels::length_levels::length_levels;
 use crate::lints::base::length_test::length_test::length_test;
+use crate::lints::base::length_zero::length_zero::length_zero;
 use crate::lints::base::lengths::lengths::lengths;
+use crate::lints::base::list_splice::list_splice::list_splice;
 use crate::lints::base::list2df::list2df::list2df;
 use crate::lints::base::literal_coercion::literal_coercion::literal_coercion;
+use crate::lints::base::map_length_mismatch::map_length_mismatch::map_length_mismatch;
 use crate::lints::base::matrix_apply::matrix_apply::matrix_apply;
 use crate::lints::base::missing_argument::missing_argument::missing_argument;
+use crate::lints::base::namespace_modification::namespace_modification::namespace_modification;
+use crate::lints::base::options_reset::options_reset::options_reset;
 use crate::lints::base::outer_negation::outer_negation::outer_negation;
+use crate::lints::base::package_dependency::package_dependency::package_dependency_attach;
+use crate::lints::base::paste_formula::paste_formula::paste_formula;
+use crate::lints::base::paste_to_paste0::paste_to_paste0::paste_to_paste0;
 use crate::lints::base::redundant_ifelse::redundant_ifelse::redundant_ifelse;
 use crate::lints::base::rep_times_ignored::rep_times_ignored::rep_times_ignored;
+use crate::lints::base::return_assignment::return_assignment::return_assignment;
+use crate::lints::base::rlang_abort_call::rlang_abort_call::rlang_abort_call;
 use crate::lints::base::sample_int::sample_int::sample_int;
 use crate::lints::base::seq2::seq2::seq2;
+use crate::lints::base::shell_injection::shell_injection::shell_injection;
+use crate::lints::base::shiny_render_context::shiny_render_context::shiny_render_context;
+use crate::lints::base::split_apply_combine::split_apply_combine::split_apply_combine;
 use crate::lints::base::sprintf::sprintf::sprintf;
 use crate::lints::base::stopifnot_all::stopifnot_all::stopifnot_all;
 use crate::lints::base::strings_as_factors::strings_as_factors::strings_as_factors;
+use crate::lints::base::suspicious_rounding::suspicious_rounding::suspicious_trunc;
+use crate::lints::base::sys_setlocale::sys_setlocale::sys_setlocale;
 use crate::lints::base::system_file::system_file::system_file;
+use crate::lints::base::tibble_rownames::tibble_rownames::tibble_rownames;
 use crate::lints::base::undesirable_function::undesirable_function::undesirable_function;
+use crate::lints::base::use_method_dispatch::use_method_dispatch::use_method_dispatch;
+use crate::lints::base::validation_in_loop::validation_in_loop::validation_in_loop;
+use crate::lints::base::vapply_fun_value::vapply_fun_value::vapply_fun_value;
+use crate::lints::base::vapply_over_sapply::vapply_over_sapply::vapply_over_sapply;
 use crate::lints::base::which_grepl::which_grepl::which_grepl;
 
 use crate::lints::dplyr::dplyr_filter_out::dplyr_filter_out::dplyr_filter_out;
 use crate::lints::dplyr::dplyr_group_by_ungroup::dplyr_group_by_ungroup::dplyr_group_by_ungroup;
 
+use crate::lints::testthat::expect_identical::expect_identical::expect_identical;
 use crate::lints::testthat::expect_length::expect_length::expect_length;
 use crate::lints::testthat::expect_match::expect_match::expect_match;
 use crate::lints::testthat::expect_named::expect_named::expect_named;
@@ -46,6 +71,7 @@ use crate::lints::testthat::expect_not::expect_not::expect_not;
 use crate::lints::testthat::expect_null::expect_null::expect_null;
 use crate::lints::testthat::expect_s3_class::expect_s3_class::expect_s3_class;
 use crate::lints::testthat::expect_s4_class::expect_s4_class::expect_s4_class;
+use crate::lints::testthat::expect_setequal::expect_setequal::expect_setequal;
 use crate::lints::testthat::expect_true_false::expect_true_false::expect_true_false;
 use crate::lints::testthat::expect_type::expect_type::expect_type;
 
@@ -67,6 +93,9 @@ pub fn call(r_expr: &RCall, checker: &mut Checker) -> anyhow::Result<()> {
     if checker.is_rule_enabled(Rule::AnyIsNa) {
         checker.report_diagnostic(any_is_na(r_expr, fn_name)?);
     }
+    if checker.is_rule_enabled(Rule::AsDateFormat) {
+        checker.report_diagnostic(as_date_format(r_expr, fn_name, ns_prefix)?);
+    }
     if checker.is_rule_enabled(Rule::Browser) {
         checker.report_diagnostic(browser(r_expr, fn_name)?);
     }
@@ -85,6 +114,12 @@ pub fn call(r_expr: &RCall, checker: &mut Checker) -> anyhow::Result<()> {
     if checker.is_rule_enabled(Rule::DuplicatedArguments) {
         checker.report_diagnostic(duplicated_arguments(r_expr, checker)?);
     }
+    if checker.is_rule_enabled(Rule::EmbeddedData) {
+        checker.report_diagnostic(embedded_data_2(r_expr, fn_name)?);
+    }
+    if checker.is_rule_enabled(Rule::FileListOrder) {
+        checker.report_diagnostic(file_list_order(r_expr, fn_name, checker)?);
+    }
     if checker.is_rule_enabled(Rule::FixedRegex) {
         checker.report_diagnostic(fixed_regex(r_expr, fn_name)?);
     }
@@ -97,42 +132,93 @@ pub fn call(r_expr: &RCall, checker: &mut Checker) -> anyhow::Result<()> {
     if checker.is_rule_enabled(Rule::IfNotElse) {
         checker.report_diagnostic(if_not_else_call(r_expr, fn_name, checker)?);
     }
+    if checker.is_rule_enabled(Rule::InvisibleReturn) {
+        checker.report_diagnostic(invisible_return(r_expr, fn_name)?);
+    }
     if checker.is_rule_enabled(Rule::LengthLevels) {
         checker.report_diagnostic(length_levels(r_expr, fn_name)?);
     }
     if checker.is_rule_enabled(Rule::LengthTest) {
         checker.report_diagnostic(length_test(r_expr, fn_name)?);
     }
+    if checker.is_rule_enabled(Rule::LengthZero) {
+        checker.report_diagnostic(length_zero(
+            r_expr,
+            fn_name,
+            checker.rule_options.length_zero.idiom,
+        )?);
+    }
     if checker.is_rule_enabled(Rule::Lengths) {
         checker.report_diagnostic(lengths(r_expr, fn_name)?);
     }
     if checker.is_rule_enabled(Rule::List2df) {
         checker.report_diagnostic(list2df(r_expr, fn_name)?);
     }
+    if checker.is_rule_enabled(Rule::ListSplice) {
+        checker.report_diagnostic(list_splice(r_expr, fn_name, checker)?);
+    }
     if checker.is_rule_enabled(Rule::LiteralCoercion) {
         checker.report_diagnostic(literal_coercion(r_expr, fn_name, ns_prefix)?);
     }
+    if checker.is_rule_enabled(Rule::MapLengthMismatch) {
+        checker.report_diagnostic(map_length_mismatch(r_expr, fn_name, ns_prefix)?);
+    }
     if checker.is_rule_enabled(Rule::MatrixApply) {
         checker.report_diagnostic(matrix_apply(r_expr, fn_name)?);
     }
     if checker.is_rule_enabled(Rule::MissingArgument) {
         checker.report_diagnostic(missing_argument(r_expr, fn_name, checker)?);
     }
+    if checker.is_rule_enabled(Rule::NamespaceModification) {
+        checker.report_diagnostic(namespace_modification(r_expr, fn_name, ns_prefix, checker)?);
+    }
+    if checker.is_rule_enabled(Rule::OptionsReset) {
+        checker.report_diagnostic(options_reset(r_expr, fn_name, ns_prefix, checker)?);
+    }
     if checker.is_rule_enabled(Rule::OuterNegation) {
         checker.report_diagnostic(outer_negation(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::PackageDependency) {
+        checker.report_diagnostic(package_dependency_attach(r_expr, fn_name, checker)?);
+    }
+    if checker.is_rule_enabled(Rule::PasteFormula) {
+        checker.report_diagnostic(paste_formula(r_expr, fn_name, ns_prefix)?);
+    }
+    if checker.is_rule_enabled(Rule::PasteToPaste0) {
+        checker.report_diagnostic(paste_to_paste0(r_expr, fn_name, ns_prefix)?);
+    }
     if checker.is_rule_enabled(Rule::RedundantIfelse) {
         checker.report_diagnostic(redundant_ifelse(r_expr, fn_name)?);
     }
     if checker.is_rule_enabled(Rule::RepTimesIgnored) {
         checker.report_diagnostic(rep_times_ignored(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::ReturnAssignment) {
+        checker.report_diagnostic(return_assignment(r_expr, fn_name)?);
+    }
+    if checker.is_rule_enabled(Rule::RlangAbortCall) {
+        checker.report_diagnostic(rlang_abort_call(r_expr, fn_name, ns_prefix, checker)?);
+    }
     if checker.is_rule_enabled(Rule::SampleInt) {
         checker.report_diagnostic(sample_int(r_expr, fn_name)?);
     }
     if checker.is_rule_enabled(Rule::Seq2) {
         checker.report_diagnostic(seq2(r_expr, fn_name)?);
     }
+    if checker.is_rule_enabled(Rule::ShellInjection) {
+        checker.report_diagnostic(shell_injection(r_expr, fn_name, checker)?);
+    }
+    if checker.is_rule_enabled(Rule::ShinyRenderContext) {
+        checker.report_diagnostic(shiny_render_context(r_expr, fn_name, checker)?);
+    }
+    if checker.is_rule_enabled(Rule::SplitApplyCombine) {
+        checker.report_diagnostic(split_apply_combine(
+            r_expr,
+            fn_name,
+            ns_prefix,
+            checker.rule_options.split_apply_combine.backend,
+        )?);
+    }
     if checker.is_rule_enabled(Rule::Sprintf) {
         checker.report_diagnostic(sprintf(r_expr, fn_name)?);
     }
@@ -142,12 +228,33 @@ pub fn call(r_expr: &RCall, checker: &mut Checker) -> anyhow::Result<()> {
     if checker.is_rule_enabled(Rule::StringsAsFactors) {
         checker.report_diagnostic(strings_as_factors(r_expr, fn_name, checker)?);
     }
+    if checker.is_rule_enabled(Rule::SuspiciousRounding) {
+        checker.report_diagnostic(suspicious_trunc(r_expr, fn_name)?);
+    }
+    if checker.is_rule_enabled(Rule::SysSetlocale) {
+        checker.report_diagnostic(sys_setlocale(r_expr, fn_name, ns_prefix, checker)?);
+    }
     if checker.is_rule_enabled(Rule::SystemFile) {
         checker.report_diagnostic(system_file(r_expr, fn_name)?);
     }
+    if checker.is_rule_enabled(Rule::TibbleRownames) {
+        checker.report_diagnostic(tibble_rownames(r_expr, fn_name, checker)?);
+    }
     if checker.is_rule_enabled(Rule::UndesirableFunction) {
         checker.report_diagnostic(undesirable_function(r_expr, fn_name, checker)?);
     }
+    if checker.is_rule_enabled(Rule::UseMethodDispatch) {
+        checker.report_diagnostic(use_method_dispatch(r_expr, fn_name, checker)?);
+    }
+    if checker.is_rule_enabled(Rule::ValidationInLoop) {
+        checker.report_diagnostic(validation_in_loop(r_expr, fn_name, ns_prefix)?);
+    }
+    if checker.is_rule_enabled(Rule::VapplyFunValue) {
+        checker.report_diagnostic(vapply_fun_value(r_expr, fn_name)?);
+    }
+    if checker.is_rule_enabled(Rule::VapplyOverSapply) {
+        checker.report_diagnostic(vapply_over_sapply(r_expr, fn_name, ns_prefix, checker)?);
+    }
     if checker.is_rule_enabled(Rule::WhichGrepl) {
         checker.report_diagnostic(which_grepl(r_expr, fn_name)?);
     }
@@ -165,6 +272,9 @@ pub fn call(r_expr: &RCall, checker: &mut Checker) -> anyhow::Result<()> {
     //
     // ------------- TESTTHAT -------------
     //
+    if checker.is_rule_enabled(Rule::TestthatExpectIdentical) {
+        checker.report_diagnostic(expect_identical(r_expr, fn_name)?);
+    }
     if checker.is_rule_enabled(Rule::TestthatExpectLength) {
         checker.report_diagnostic(expect_length(r_expr, fn_name)?);
     }
@@ -189,6 +299,9 @@ pub fn call(r_expr: &RCall, checker: &mut Checker) -> anyhow::Result<()> {
     if checker.is_rule_enabled(Rule::TestthatExpectS4Class) {
         checker.report_diagnostic(expect_s4_class(r_expr, fn_name)?);
     }
+    if checker.is_rule_enabled(Rule::TestthatExpectSetequal) {
+        checker.report_diagnostic(expect_setequal(r_expr, fn_name)?);
+    }
     if checker.is_rule_enabled(Rule::TestthatExpectType) {
         checker.report_diagnostic(expect_type(r_expr, fn_name)?);
     }
@@ -23,6 +23,7 @@ pub(crate) fn check_document(
     checker: &mut Checker,
     duplicate_assignments: &[(String, biome_rowan::TextRange, String)],
     unused_functions: &[(String, biome_rowan::TextRange, String)],
+    missing_docs: &[(String, biome_rowan::TextRange, String)],
 ) -> anyhow::Result<()> {
     // --- Document-level analysis ---
 
@@ -131,6 +132,20 @@ pub(crate) fn check_document(
         }
     }
 
+    if checker.is_rule_enabled(Rule::ExportedFunctionMissingDocs) {
+        for (name, range, help) in missing_docs {
+            checker.report_diagnostic(Some(Diagnostic::new(
+                ViolationData::new(
+                    "exported_function_missing_docs".to_string(),
+                    format!("`{name}` is exported but has no documentation."),
+                    Some(help.clone()),
+                ),
+                *range,
+                Fix::empty(),
+            )));
+        }
+    }
+
     if checker.is_rule_enabled(Rule::EmptyFile) {
         checker.report_diagnostic(empty_file(&expressions, syntax));
     }
@@ -96,6 +96,7 @@ pub(crate) fn check_expression(
             check_expression(&body?, checker)?;
         }
         AnyRExpression::RRepeatStatement(children) => {
+            analyze::repeat_::repeat_(children, checker)?;
             let body = children.body();
             check_expression(&body?, checker)?;
         }
@@ -5,7 +5,9 @@ use air_r_syntax::RIfStatement;
 use crate::lints::base::coalesce::coalesce::coalesce;
 use crate::lints::base::if_always_true::if_always_true::if_always_true;
 use crate::lints::base::if_not_else::if_not_else::if_not_else;
+use crate::lints::base::stopifnot_all::stopifnot_all::stopifnot_all_if;
 use crate::lints::base::unnecessary_nesting::unnecessary_nesting::unnecessary_nesting;
+use crate::lints::base::vector_condition::vector_condition::vector_condition_if;
 
 pub fn if_(r_expr: &RIfStatement, checker: &mut Checker) -> anyhow::Result<()> {
     if checker.is_rule_enabled(Rule::Coalesce) {
@@ -17,8 +19,14 @@ pub fn if_(r_expr: &RIfStatement, checker: &mut Checker) -> anyhow::Result<()> {
     if checker.is_rule_enabled(Rule::IfNotElse) {
         checker.report_diagnostic(if_not_else(r_expr, checker)?);
     }
+    if checker.is_rule_enabled(Rule::StopifnotAll) {
+        checker.report_diagnostic(stopifnot_all_if(r_expr, checker)?);
+    }
     if checker.is_rule_enabled(Rule::UnnecessaryNesting) {
         checker.report_diagnostic(unnecessary_nesting(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::VectorCondition) {
+        checker.report_diagnostic(vector_condition_if(r_expr)?);
+    }
     Ok(())
 }
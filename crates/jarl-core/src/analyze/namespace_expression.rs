@@ -3,13 +3,17 @@ use crate::rule_set::Rule;
 use air_r_syntax::RNamespaceExpression;
 
 use crate::lints::base::internal_function::internal_function::internal_function;
+use crate::lints::base::package_dependency::package_dependency::package_dependency_namespace;
 
 pub fn namespace_expression(
     r_expr: &RNamespaceExpression,
     checker: &mut Checker,
 ) -> anyhow::Result<()> {
     if checker.is_rule_enabled(Rule::InternalFunction) {
-        checker.report_diagnostic(internal_function(r_expr)?);
+        checker.report_diagnostic(internal_function(r_expr, checker)?);
+    }
+    if checker.is_rule_enabled(Rule::PackageDependency) {
+        checker.report_diagnostic(package_dependency_namespace(r_expr, checker)?);
     }
     Ok(())
 }
@@ -0,0 +1,12 @@
+use crate::checker::Checker;
+use crate::rule_set::Rule;
+use air_r_syntax::RRepeatStatement;
+
+use crate::lints::base::infinite_loop::infinite_loop::infinite_loop_repeat;
+
+pub fn repeat_(r_expr: &RRepeatStatement, checker: &mut Checker) -> anyhow::Result<()> {
+    if checker.is_rule_enabled(Rule::InfiniteLoop) {
+        checker.report_diagnostic(infinite_loop_repeat(r_expr, checker)?);
+    }
+    Ok(())
+}
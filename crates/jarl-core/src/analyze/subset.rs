@@ -2,9 +2,13 @@ use crate::checker::Checker;
 use crate::rule_set::Rule;
 use air_r_syntax::RSubset;
 
+use crate::lints::base::file_list_order::file_list_order::file_list_order_subset;
 use crate::lints::base::sort::sort::sort;
 
 pub fn subset(r_expr: &RSubset, checker: &mut Checker) -> anyhow::Result<()> {
+    if checker.is_rule_enabled(Rule::FileListOrder) {
+        checker.report_diagnostic(file_list_order_subset(r_expr, checker)?);
+    }
     if checker.is_rule_enabled(Rule::Sort) {
         checker.report_diagnostic(sort(r_expr)?);
     }
@@ -2,11 +2,19 @@ use crate::checker::Checker;
 use crate::rule_set::Rule;
 use air_r_syntax::RWhileStatement;
 
+use crate::lints::base::infinite_loop::infinite_loop::infinite_loop_while;
 use crate::lints::base::repeat::repeat::repeat;
+use crate::lints::base::vector_condition::vector_condition::vector_condition_while;
 
 pub fn while_(r_expr: &RWhileStatement, checker: &mut Checker) -> anyhow::Result<()> {
+    if checker.is_rule_enabled(Rule::InfiniteLoop) {
+        checker.report_diagnostic(infinite_loop_while(r_expr, checker)?);
+    }
     if checker.is_rule_enabled(Rule::Repeat) {
         checker.report_diagnostic(repeat(r_expr)?);
     }
+    if checker.is_rule_enabled(Rule::VectorCondition) {
+        checker.report_diagnostic(vector_condition_while(r_expr)?);
+    }
     Ok(())
 }
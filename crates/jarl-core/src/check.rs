@@ -1,18 +1,17 @@
-use crate::error::ParseError;
 use crate::package::{
-    FilePackageInfo, FileScope, PackageAnalysis, PackageContext, make_package_analysis,
+    CodeKind, FilePackageInfo, FileScope, PackageAnalysis, PackageContext, make_package_analysis,
     summarize_package_info,
 };
 use crate::roxygen::{extract_roxygen_examples, remap_roxygen_fix, remap_roxygen_range};
 use crate::suppression::SuppressionManager;
-use crate::vcs::check_version_control;
+use crate::vcs::{StagedFile, check_version_control, merge_contents};
 use air_fs::relativize_path;
 use air_r_parser::RParserOptions;
 use air_r_syntax::{RExpressionList, RSyntaxNode};
 use anyhow::{Context, Result};
+use biome_rowan::{AstNode, SyntaxKind, TextRange};
 use rayon::prelude::*;
 use std::collections::HashMap;
-use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -23,11 +22,127 @@ pub use crate::checker::Checker;
 use crate::config::Config;
 use crate::diagnostic::*;
 use crate::fix::*;
-use crate::rule_set::RuleSet;
+use crate::rule_set::{Rule, RuleSet};
 use crate::utils::*;
 
 pub fn check(config: Config) -> Vec<(String, Result<Vec<Diagnostic>, anyhow::Error>)> {
-    let (pkg_contexts, file_pkg_info) = summarize_package_info(&config.paths);
+    // Ensure that all paths are covered by VCS. This is conservative because
+    // technically we could apply fixes on those that are covered by VCS and
+    // error for the others, but I'd rather be on the safe side and force the
+    // user to deal with that before applying any fixes.
+    if (config.apply_fixes || config.apply_unsafe_fixes)
+        && let Err((path, e)) = ensure_version_control(&config)
+    {
+        return vec![(path, Err(e))];
+    }
+
+    run_on_paths(config, check_path)
+}
+
+/// Run [`check()`] on several configs in parallel, e.g. one per package when
+/// checking a directory that contains several R packages.
+pub fn check_configs(
+    configs: Vec<Config>,
+) -> Vec<(String, Result<Vec<Diagnostic>, anyhow::Error>)> {
+    configs.into_par_iter().flat_map_iter(check).collect()
+}
+
+/// Lint `contents` as the content of the paths of `config` instead of reading
+/// them, e.g. for code read from stdin. The paths don't need to exist but are
+/// used to find the package the code belongs to. Fixes are never applied.
+pub fn check_contents(
+    config: Config,
+    contents: &str,
+) -> Vec<(String, Result<Vec<Diagnostic>, anyhow::Error>)> {
+    run_on_paths(config, |path, config, pkg, pkg_contexts, file_pkg_info| {
+        if crate::fs::looks_generated(contents) {
+            return Ok(Vec::new());
+        }
+        let path = relativize_path(path);
+        get_checks(
+            contents,
+            &PathBuf::from(&path),
+            &config,
+            &pkg,
+            &pkg_contexts,
+            &file_pkg_info,
+        )
+        .with_context(|| format!("Failed to get checks for file: {path}"))
+    })
+}
+
+/// Return an error for the first path if the paths are not all covered by VCS.
+fn ensure_version_control(config: &Config) -> Result<(), (String, anyhow::Error)> {
+    if config.paths.is_empty() {
+        return Ok(());
+    }
+    let path_strings: Vec<String> = config.paths.iter().map(relativize_path).collect();
+    check_version_control(&path_strings, config).map_err(|e| {
+        let first_path = path_strings.first().unwrap().clone();
+        (first_path, e)
+    })
+}
+
+/// The content of a file before and after applying the automatic fixes.
+pub struct FixedFile {
+    pub original: String,
+    pub fixed: String,
+    /// Names of the rules whose fix was applied, once per fix.
+    pub applied_fixes: Vec<String>,
+}
+
+/// Compute the automatic fixes of each file without modifying it.
+///
+/// Files that don't change are returned as `None`. Since no file is written,
+/// this doesn't require the files to be covered by VCS.
+pub fn preview_fixes(config: Config) -> Vec<(String, Result<Option<FixedFile>, anyhow::Error>)> {
+    run_on_paths(config, preview_fixes_path)
+}
+
+/// Apply the automatic fixes of each file and write the fixed files.
+///
+/// Unlike [`check()`], this doesn't lint the fixed files again. Files that
+/// don't change are returned as `None`.
+pub fn fix(config: Config) -> Vec<(String, Result<Option<FixedFile>, anyhow::Error>)> {
+    // Same as in `check()`: all paths must be covered by VCS before any file
+    // is modified.
+    if let Err((path, e)) = ensure_version_control(&config) {
+        return vec![(path, Err(e))];
+    }
+
+    run_on_paths(config, |path, config, pkg, pkg_contexts, file_pkg_info| {
+        let fixed = preview_fixes_path(path, config, pkg, pkg_contexts, file_pkg_info)?;
+        if let Some(file) = &fixed {
+            let path = relativize_path(path);
+            config
+                .file_system
+                .write(Path::new(&path), &file.fixed)
+                .with_context(|| format!("Failed to write file: {path}",))?;
+            if let Some(command) = &config.post_fix_command {
+                run_post_fix_command(command, &path)?;
+            }
+        }
+        Ok(fixed)
+    })
+}
+
+/// Run `f` on every path of the config in parallel, after computing the
+/// package information shared by all files.
+fn run_on_paths<T, F>(config: Config, f: F) -> Vec<(String, Result<T, anyhow::Error>)>
+where
+    T: Send,
+    F: Fn(
+            &PathBuf,
+            Arc<Config>,
+            Arc<PackageAnalysis>,
+            Arc<HashMap<PathBuf, PackageContext>>,
+            Arc<HashMap<PathBuf, FilePackageInfo>>,
+        ) -> Result<T, anyhow::Error>
+        + Sync
+        + Send,
+{
+    let (pkg_contexts, file_pkg_info) =
+        summarize_package_info(&config.paths, config.file_system.as_ref());
 
     let namespace_contents: HashMap<PathBuf, String> = pkg_contexts
         .iter()
@@ -41,18 +156,6 @@ pub fn check(config: Config) -> Vec<(String, Result<Vec<Diagnostic>, anyhow::Err
     let pkg_contexts = Arc::new(pkg_contexts);
     let file_pkg_info = Arc::new(file_pkg_info);
 
-    // Ensure that all paths are covered by VCS. This is conservative because
-    // technically we could apply fixes on those that are covered by VCS and
-    // error for the others, but I'd rather be on the safe side and force the
-    // user to deal with that before applying any fixes.
-    if (config.apply_fixes || config.apply_unsafe_fixes) && !config.paths.is_empty() {
-        let path_strings: Vec<String> = config.paths.iter().map(relativize_path).collect();
-        if let Err(e) = check_version_control(&path_strings, &config) {
-            let first_path = path_strings.first().unwrap().clone();
-            return vec![(first_path, Err(e))];
-        }
-    }
-
     // Wrap config and package analysis in Arc to avoid expensive clones in parallel execution
     let config = Arc::new(config);
     let pkg = Arc::new(pkg);
@@ -61,7 +164,7 @@ pub fn check(config: Config) -> Vec<(String, Result<Vec<Diagnostic>, anyhow::Err
         .paths
         .par_iter()
         .map(|file| {
-            let res = check_path(
+            let res = f(
                 file,
                 Arc::clone(&config),
                 Arc::clone(&pkg),
@@ -80,6 +183,11 @@ pub fn check_path(
     pkg_contexts: Arc<HashMap<PathBuf, PackageContext>>,
     file_pkg_info: Arc<HashMap<PathBuf, FilePackageInfo>>,
 ) -> Result<Vec<Diagnostic>, anyhow::Error> {
+    // Rmd/Qmd files never get autofixes applied, and their violations are not
+    // reported with --fix-only.
+    if config.fix_only && crate::fs::has_rmd_extension(path) {
+        return Ok(Vec::new());
+    }
     if config.apply_fixes || config.apply_unsafe_fixes {
         lint_fix(path, config, pkg, pkg_contexts, file_pkg_info)
     } else {
@@ -88,14 +196,20 @@ pub fn check_path(
 }
 
 /// Filter `config.rules_to_apply` down to the rules that apply to `path` after
-/// accounting for `[lint.per-file-ignores]`.
-fn effective_rules_for_file(config: &Config, path: &Path) -> RuleSet {
-    if config.per_file_ignores.is_empty() {
+/// accounting for `[[lint.overrides]]`, `[lint.per-file-ignores]`, and the
+/// per-rule `include`/`exclude` patterns.
+pub fn effective_rules_for_file(config: &Config, path: &Path) -> RuleSet {
+    if config.overrides.is_empty()
+        && config.per_file_ignores.is_empty()
+        && config.rule_paths.is_empty()
+    {
         return config.rules_to_apply.clone();
     }
-    let ignored = config.per_file_ignores.ignored_rules(path);
+    let mut ignored = config.per_file_ignores.ignored_rules(path);
+    ignored.extend(config.rule_paths.skipped_rules(path));
     config
-        .rules_to_apply
+        .overrides
+        .rules(path, &config.rules_to_apply)
         .iter()
         .filter(|rule| !ignored.contains(rule))
         .collect()
@@ -109,7 +223,9 @@ pub fn lint_only(
     file_pkg_info: Arc<HashMap<PathBuf, FilePackageInfo>>,
 ) -> Result<Vec<Diagnostic>, anyhow::Error> {
     let path = relativize_path(path);
-    let contents = fs::read_to_string(Path::new(&path))
+    let contents = config
+        .file_system
+        .read_to_string(Path::new(&path))
         .with_context(|| format!("Failed to read file: {path}"))?;
 
     // Files starting with "# Generated by" are ignored but they still
@@ -146,24 +262,166 @@ pub fn lint_fix(
 
     let path = relativize_path(path);
 
-    let mut checks: Vec<Diagnostic>;
+    if config.respect_staged
+        && let Some(staged) = StagedFile::read(Path::new(&path))?
+    {
+        return lint_fix_staged(&path, staged, &config, &pkg, &pkg_contexts, &file_pkg_info);
+    }
+
+    let contents = config
+        .file_system
+        .read_to_string(Path::new(&path))
+        .with_context(|| format!("Failed to read file: {path}",))?;
 
-    loop {
-        let contents = fs::read_to_string(Path::new(&path))
-            .with_context(|| format!("Failed to read file: {path}",))?;
+    // Skip auto-generated files: no diagnostics, no fixes.
+    if crate::fs::looks_generated(&contents) {
+        return Ok(Vec::new());
+    }
 
-        // Skip auto-generated files: no diagnostics, no fixes.
-        if crate::fs::looks_generated(&contents) {
-            return Ok(Vec::new());
+    let (fixed_text, checks, _) = fix_contents(
+        &path,
+        contents.clone(),
+        &config,
+        &pkg,
+        &pkg_contexts,
+        &file_pkg_info,
+    )?;
+
+    if fixed_text != contents {
+        config
+            .file_system
+            .write(Path::new(&path), &fixed_text)
+            .with_context(|| format!("Failed to write file: {path}",))?;
+
+        // The command can modify the file again, e.g. to format it, so the
+        // locations of the remaining violations must be computed again.
+        if let Some(command) = &config.post_fix_command {
+            run_post_fix_command(command, &path)?;
+            return lint_only(
+                &PathBuf::from(&path),
+                config,
+                pkg,
+                pkg_contexts,
+                file_pkg_info,
+            );
         }
+    }
+
+    Ok(checks)
+}
 
-        checks = get_checks(
+/// Apply the fixes to the staged content of a file (`--respect-staged`) and
+/// stage the result. The fixes are also merged in the working tree, unless
+/// they conflict with the unstaged changes of the file. The post-fix command
+/// isn't run since it would only modify the working tree.
+fn lint_fix_staged(
+    path: &str,
+    staged: StagedFile,
+    config: &Config,
+    pkg: &PackageAnalysis,
+    pkg_contexts: &HashMap<PathBuf, PackageContext>,
+    file_pkg_info: &HashMap<PathBuf, FilePackageInfo>,
+) -> Result<Vec<Diagnostic>, anyhow::Error> {
+    if crate::fs::looks_generated(&staged.content) {
+        return Ok(Vec::new());
+    }
+
+    let (fixed_text, checks, _) = fix_contents(
+        path,
+        staged.content.clone(),
+        config,
+        pkg,
+        pkg_contexts,
+        file_pkg_info,
+    )?;
+    if fixed_text == staged.content {
+        return Ok(checks);
+    }
+
+    staged
+        .stage(&fixed_text)
+        .with_context(|| format!("Failed to stage file: {path}"))?;
+
+    let working_tree = config
+        .file_system
+        .read_to_string(Path::new(path))
+        .with_context(|| format!("Failed to read file: {path}",))?;
+    let fixed_working_tree = if working_tree == staged.content {
+        Some(fixed_text)
+    } else {
+        merge_contents(&working_tree, &staged.content, &fixed_text)?
+    };
+    match fixed_working_tree {
+        Some(fixed) => config
+            .file_system
+            .write(Path::new(path), &fixed)
+            .with_context(|| format!("Failed to write file: {path}",))?,
+        None => tracing::warn!(
+            "The fixes of {path} conflict with its unstaged changes, they were only applied to the staged content."
+        ),
+    }
+
+    Ok(checks)
+}
+
+fn preview_fixes_path(
+    path: &PathBuf,
+    config: Arc<Config>,
+    pkg: Arc<PackageAnalysis>,
+    pkg_contexts: Arc<HashMap<PathBuf, PackageContext>>,
+    file_pkg_info: Arc<HashMap<PathBuf, FilePackageInfo>>,
+) -> Result<Option<FixedFile>, anyhow::Error> {
+    // Rmd/Qmd files never get autofixes applied.
+    if crate::fs::has_rmd_extension(path) {
+        return Ok(None);
+    }
+
+    let path = relativize_path(path);
+    let contents = config
+        .file_system
+        .read_to_string(Path::new(&path))
+        .with_context(|| format!("Failed to read file: {path}",))?;
+
+    if crate::fs::looks_generated(&contents) {
+        return Ok(None);
+    }
+
+    let (fixed, _, applied_fixes) = fix_contents(
+        &path,
+        contents.clone(),
+        &config,
+        &pkg,
+        &pkg_contexts,
+        &file_pkg_info,
+    )?;
+
+    if fixed == contents {
+        return Ok(None);
+    }
+    Ok(Some(FixedFile { original: contents, fixed, applied_fixes }))
+}
+
+/// Apply the automatic fixes to `contents` until there is nothing left to fix,
+/// and return the fixed content with its remaining diagnostics and the names
+/// of the rules whose fix was applied.
+fn fix_contents(
+    path: &str,
+    mut contents: String,
+    config: &Config,
+    pkg: &PackageAnalysis,
+    pkg_contexts: &HashMap<PathBuf, PackageContext>,
+    file_pkg_info: &HashMap<PathBuf, FilePackageInfo>,
+) -> Result<(String, Vec<Diagnostic>, Vec<String>), anyhow::Error> {
+    let mut applied_fixes = Vec::new();
+    let mut iterations = 0;
+    loop {
+        let checks = get_checks(
             &contents,
-            &PathBuf::from(&path),
-            &config,
-            &pkg,
-            &pkg_contexts,
-            &file_pkg_info,
+            &PathBuf::from(path),
+            config,
+            pkg,
+            pkg_contexts,
+            file_pkg_info,
         )
         .with_context(|| format!("Failed to get checks for file: {path}",))?;
 
@@ -171,21 +429,27 @@ pub fn lint_fix(
             .iter()
             .any(|d| d.has_safe_fix() || d.has_unsafe_fix());
         if !has_fixable {
-            break;
+            return Ok((contents, checks, applied_fixes));
+        }
+        if iterations == MAX_FIX_ITERATIONS {
+            tracing::warn!(
+                "Fixes for {path} didn't converge after {MAX_FIX_ITERATIONS} iterations, some violations are left."
+            );
+            return Ok((contents, checks, applied_fixes));
         }
+        iterations += 1;
 
-        let fixed_text = apply_fixes(&checks, &contents);
+        let (fixed_text, applied) = apply_fixes(&checks, &contents);
 
         // No progress was made (e.g. all fixes overlap), stop to avoid an
         // infinite loop.
         if fixed_text == contents {
-            break;
+            return Ok((contents, checks, applied_fixes));
         }
 
-        fs::write(&path, fixed_text).with_context(|| format!("Failed to write file: {path}",))?;
+        applied_fixes.extend(applied);
+        contents = fixed_text;
     }
-
-    Ok(checks)
 }
 
 // Takes the R code as a string, parses it, and obtains a (possibly empty)
@@ -210,8 +474,9 @@ pub fn get_checks(
 
     // The parser recovers from syntax errors: each failed statement is wrapped
     // in a bogus node and the rest of the file is parsed normally, so we can
-    // still lint the valid code. Autofixes are disabled for the whole file
-    // because edits computed around broken code are not reliable.
+    // still lint the valid code and report the broken regions as
+    // `syntax_error`. Autofixes are disabled for the whole file because edits
+    // computed around broken code are not reliable.
     let has_parse_errors = parsed.has_error();
 
     let syntax = &parsed.syntax();
@@ -219,10 +484,16 @@ pub fn get_checks(
 
     let suppression = SuppressionManager::from_node(syntax, contents);
 
-    let mut checker = Checker::new(suppression, config.rule_options.clone());
-    // Drop any rules ignored for this file via `[lint.per-file-ignores]`.
+    let rule_options = config.overrides.rule_options(file, &config.rule_options)?;
+    let mut checker = Checker::new(suppression, rule_options);
+    // Drop any rules ignored for this file via `[[lint.overrides]]`,
+    // `[lint.per-file-ignores]`, or the rules' own `include`/`exclude`
+    // patterns.
     checker.rule_set = effective_rules_for_file(config, file);
     checker.minimum_r_version = config.minimum_r_version;
+    checker.is_test_file = crate::fs::is_test_file(file);
+    checker.is_shiny_app_file =
+        crate::shiny::find_shiny_app_root_in(file, config.file_system.as_ref()).is_some();
 
     // Wire up package context for package-specific rules.
     get_package_info(
@@ -241,6 +512,7 @@ pub fn get_checks(
         .cloned()
         .unwrap_or_default();
     let unused_functions = pkg.unused_functions.get(file).cloned().unwrap_or_default();
+    let missing_docs = pkg.missing_docs.get(file).cloned().unwrap_or_default();
 
     // We run checks at expression-level. This gathers all violations, no matter
     // whether they are suppressed or not. They are filtered out in the next
@@ -253,7 +525,10 @@ pub fn get_checks(
     // Collected before check_document so that suppression filtering (which
     // runs inside check_document) can match `# jarl-ignore` comments in
     // the main file against violations found in roxygen examples.
+    // With --fix-only, those violations are not reported so examples are only
+    // checked if their fixes are applied.
     if config.check_roxygen
+        && (config.fix_roxygen || !config.fix_only)
         && contents.contains("#'")
         && contents.contains("@examples")
         && matches!(
@@ -276,6 +551,7 @@ pub fn get_checks(
         &mut checker,
         &duplicate_assignments,
         &unused_functions,
+        &missing_docs,
     )?;
 
     // Some rules have a fix available in their implementation but do not have
@@ -291,11 +567,18 @@ pub fn get_checks(
         .map(|x| x.name().to_string())
         .collect::<Vec<String>>();
 
-    let diagnostics: Vec<Diagnostic> = checker
+    let kind_severity = config
+        .severity_by_kind
+        .get(&CodeKind::from_path(file, file_pkg_info));
+
+    let mut diagnostics: Vec<Diagnostic> = checker
         .diagnostics
         .into_iter()
         .map(|mut x| {
             x.filename = file.to_path_buf();
+            if let Some(severity) = kind_severity.or_else(|| config.severity.get(&x.message.name)) {
+                x.severity = *severity;
+            }
             // Check if fix should be skipped based on fixable/unfixable settings
             if rules_without_fix.contains(&x.message.name) {
                 x.fix = Fix::empty();
@@ -322,14 +605,47 @@ pub fn get_checks(
         })
         .collect();
 
+    if has_parse_errors {
+        diagnostics.extend(syntax_errors(syntax, expressions).into_iter().map(|mut d| {
+            d.filename = file.to_path_buf();
+            d
+        }));
+    }
+
     let loc_new_lines = find_new_lines(syntax)?;
     let diagnostics = compute_lints_location(diagnostics, &loc_new_lines);
 
-    if has_parse_errors {
-        return Err(ParseError { filename: file.to_path_buf(), diagnostics }.into());
+    Ok(diagnostics)
+}
+
+/// Report the regions of code that couldn't be parsed.
+///
+/// The parser wraps the code it couldn't parse in bogus nodes, so we report
+/// the outermost ones. Some errors, like a missing closing parenthesis at the
+/// end of the file, don't produce a bogus node: in this case, we report the
+/// last expression of the file.
+fn syntax_errors(syntax: &RSyntaxNode, expressions: &RExpressionList) -> Vec<Diagnostic> {
+    let diagnostics: Vec<Diagnostic> = syntax
+        .descendants()
+        .filter(|node| {
+            node.kind().is_bogus()
+                && node
+                    .ancestors()
+                    .skip(1)
+                    .all(|ancestor| !ancestor.kind().is_bogus())
+        })
+        .map(|node| Diagnostic::syntax_error(node.text_trimmed_range()))
+        .collect();
+
+    if !diagnostics.is_empty() {
+        return diagnostics;
     }
 
-    Ok(diagnostics)
+    let range = match expressions.iter().last() {
+        Some(expr) => expr.syntax().text_trimmed_range(),
+        None => TextRange::empty(syntax.text_trimmed_range().end()),
+    };
+    vec![Diagnostic::syntax_error(range)]
 }
 
 /// Populate package context on the checker from pre-computed data.
@@ -345,9 +661,12 @@ fn get_package_info(
     file_pkg_info: &HashMap<PathBuf, FilePackageInfo>,
 ) {
     match file_pkg_info.get(file) {
-        Some(FilePackageInfo::InPackage { package_root, .. }) => {
+        Some(FilePackageInfo::InPackage { package_root, scope }) => {
+            checker.file_scope = Some(*scope);
             if let Some(ctx) = pkg_contexts.get(package_root) {
                 checker.loaded_packages = ctx.loaded_packages.clone();
+                checker.suggested_packages = ctx.suggested_packages.clone();
+                checker.package_name = ctx.package_name.clone();
                 checker.import_from = ctx.import_from.clone();
                 checker.namespace_exports = ctx.namespace_exports.clone();
             }
@@ -364,9 +683,23 @@ fn get_package_info(
     checker.package_cache = config.package_cache.clone();
 }
 
+/// Rules that are not applied to the code in roxygen examples. They either
+/// need the rest of the file or package, or report code that is expected in
+/// examples, such as inline numbers and data.
+const ROXYGEN_EXAMPLES_SKIPPED_RULES: &[Rule] = &[
+    Rule::DuplicatedFunctionDefinition,
+    Rule::EmbeddedData,
+    Rule::EmptyFile,
+    Rule::MagicNumbers,
+    Rule::PackageDependency,
+    Rule::RoxygenCompleteness,
+    Rule::UnusedFunction,
+];
+
 /// Lint R code inside roxygen `@examples` and `@examplesIf` sections.
 ///
-/// Each examples section is extracted, parsed as standalone R code, and linted.
+/// Each examples section is extracted, parsed as standalone R code, and linted
+/// with the rules of the file, except [ROXYGEN_EXAMPLES_SKIPPED_RULES].
 /// Diagnostic byte ranges are remapped to point to the correct position in the
 /// original file. Autofixes are disabled because the `#'` prefix makes
 /// position-based edits unsafe.
@@ -379,6 +712,11 @@ fn get_checks_roxygen(
     let chunks = extract_roxygen_examples(syntax, contents);
     let mut all_diagnostics: Vec<Diagnostic> = Vec::new();
 
+    let rule_set: RuleSet = effective_rules_for_file(config, file)
+        .iter()
+        .filter(|rule| !ROXYGEN_EXAMPLES_SKIPPED_RULES.contains(rule))
+        .collect();
+
     for chunk in &chunks {
         let parsed = air_r_parser::parse(&chunk.code, RParserOptions::default());
         if parsed.has_error() {
@@ -390,8 +728,9 @@ fn get_checks_roxygen(
         let syntax = parsed.syntax();
         let suppression = SuppressionManager::from_node(&syntax, &chunk.code);
         let has_suppressions = suppression.has_any_suppressions;
-        let mut checker = Checker::new(suppression, config.rule_options.clone());
-        checker.rule_set = effective_rules_for_file(config, file);
+        let rule_options = config.overrides.rule_options(file, &config.rule_options)?;
+        let mut checker = Checker::new(suppression, rule_options);
+        checker.rule_set = rule_set.clone();
         checker.minimum_r_version = config.minimum_r_version;
 
         for expr in expressions {
@@ -403,7 +742,7 @@ fn get_checks_roxygen(
         // otherwise unnecessary here (no package-level analysis, no
         // suppression-related diagnostics to report).
         if has_suppressions {
-            check_document(expressions, &syntax, &mut checker, &[], &[])?;
+            check_document(expressions, &syntax, &mut checker, &[], &[], &[])?;
         }
 
         for mut d in checker.diagnostics {
@@ -429,9 +768,16 @@ fn get_checks_roxygen(
 /// - `#| jarl-ignore-chunk:` YAML blocks are translated to `# jarl-ignore-start`
 ///   / `# jarl-ignore-end` pairs before linting
 /// - Chunks with parse errors are silently dropped
+/// - Chunks with one of the options in `skip-chunks` are not checked
 /// - Diagnostic ranges are remapped from virtual-string offsets to original file offsets
 fn get_checks_rmd(contents: &str, file: &Path, config: &Config) -> Result<Vec<Diagnostic>> {
-    let chunks = crate::rmd::extract_r_chunks(contents);
+    let mut chunks = crate::rmd::extract_document_chunks(contents, file);
+    chunks.retain(|chunk| {
+        !config
+            .skip_chunks
+            .iter()
+            .any(|option| chunk.has_option(option))
+    });
     let (virtual_source, offset_map) = crate::rmd::build_virtual_r_source(&chunks);
 
     if virtual_source.trim().is_empty() {
@@ -443,7 +789,8 @@ fn get_checks_rmd(contents: &str, file: &Path, config: &Config) -> Result<Vec<Di
 
     let syntax = parsed.syntax();
     let suppression = SuppressionManager::from_node(&syntax, &virtual_source);
-    let mut checker = Checker::new(suppression, config.rule_options.clone());
+    let rule_options = config.overrides.rule_options(file, &config.rule_options)?;
+    let mut checker = Checker::new(suppression, rule_options);
     checker.rule_set = effective_rules_for_file(config, file);
     checker.minimum_r_version = config.minimum_r_version;
 
@@ -454,7 +801,15 @@ fn get_checks_rmd(contents: &str, file: &Path, config: &Config) -> Result<Vec<Di
     // check_document runs suppression filtering internally, so
     // checker.diagnostics is the post-suppression list after this call.
     // Rmd chunks don't participate in package-level analysis, so pass empty slices.
-    check_document(expressions, &syntax, &mut checker, &[], &[])?;
+    check_document(expressions, &syntax, &mut checker, &[], &[], &[])?;
+
+    let kind_severity = config.severity_by_kind.get(&CodeKind::Notebook);
+
+    if has_parse_errors {
+        checker
+            .diagnostics
+            .extend(syntax_errors(&syntax, expressions));
+    }
 
     // Remap ranges from virtual-string offsets to original Rmd file offsets.
     let diagnostics: Vec<Diagnostic> = checker
@@ -462,6 +817,12 @@ fn get_checks_rmd(contents: &str, file: &Path, config: &Config) -> Result<Vec<Di
         .into_iter()
         .map(|mut d| {
             d.filename = file.to_path_buf();
+            if !d.is_syntax_error()
+                && let Some(severity) =
+                    kind_severity.or_else(|| config.severity.get(&d.message.name))
+            {
+                d.severity = *severity;
+            }
             d.fix = Fix::empty();
             d.range = offset_map.remap_range(d.range);
             d
@@ -471,10 +832,6 @@ fn get_checks_rmd(contents: &str, file: &Path, config: &Config) -> Result<Vec<Di
     let loc_new_lines = crate::utils::find_new_lines_from_content(contents);
     let diagnostics = compute_lints_location(diagnostics, &loc_new_lines);
 
-    if has_parse_errors {
-        return Err(ParseError { filename: file.to_path_buf(), diagnostics }.into());
-    }
-
     Ok(diagnostics)
 }
 
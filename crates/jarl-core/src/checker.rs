@@ -1,4 +1,5 @@
 use crate::diagnostic::Diagnostic;
+use crate::package::FileScope;
 use crate::package_cache::PackageCache;
 use crate::rule_options::ResolvedRuleOptions;
 use crate::rule_set::{Rule, RuleSet};
@@ -74,6 +75,19 @@ pub struct Checker {
     // `S3method()`, etc.).  Used to suppress false positives in rules
     // like `unused_object` — exported names are "used" by definition.
     pub namespace_exports: HashSet<String>,
+    // Scope of the file in its R package, `None` if the file is not part of a
+    // package.
+    pub file_scope: Option<FileScope>,
+    // Packages that are only in the `Suggests` field of the package's
+    // DESCRIPTION.
+    pub suggested_packages: Vec<String>,
+    // Name of the package the file belongs to, from its DESCRIPTION.
+    pub package_name: Option<String>,
+    // Whether the file contains tests, e.g. `tests/testthat/test-foo.R`.
+    pub is_test_file: bool,
+    // Whether the file is sourced by a Shiny app when it runs, e.g. `app.R`
+    // or `R/plots.R` in the folder of the app.
+    pub is_shiny_app_file: bool,
 }
 
 impl Checker {
@@ -91,6 +105,11 @@ impl Checker {
             package_cache: None,
             import_from: HashMap::new(),
             namespace_exports: HashSet::new(),
+            file_scope: None,
+            suggested_packages: Vec::new(),
+            package_name: None,
+            is_test_file: false,
+            is_shiny_app_file: false,
         }
     }
 
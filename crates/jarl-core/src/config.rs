@@ -1,18 +1,29 @@
 use crate::{
     description::Description,
+    diagnostic::Severity,
     error::UnknownRulesError,
+    file_system::{FileSystem, OsFileSystem},
     lints::all_rules_enabled_by_default,
+    overrides::Overrides,
+    package::{CodeKind, find_package_root_in},
     package_cache::PackageCache,
     per_file_ignores::PerFileIgnores,
     rule_options::ResolvedRuleOptions,
-    rule_set::{Category, Rule, RuleSet},
+    rule_paths::RulePaths,
+    rule_set::{Category, Profile, Rule, RuleSet},
     settings::Settings,
+    toml::UnsafeFixesConfig,
 };
 use air_r_syntax::RSyntaxKind;
 use anyhow::Result;
-use std::{collections::HashSet, fs, path::PathBuf, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    sync::Arc,
+};
 
 use crate::lints::base::assignment::options::ResolvedAssignmentOptions;
+use crate::rmd::ChunkOption;
 
 /// Parsed rule selection from CLI or TOML configuration.
 /// Contains selected rules, extended rules, and ignored rules.
@@ -32,6 +43,9 @@ pub struct ArgsConfig {
     pub fix: bool,
     /// Did the user pass the --unsafe-fixes flag?
     pub unsafe_fixes: bool,
+    /// Rules passed to `--unsafe-fixes=...`, whose unsafe fixes are applied. A
+    /// single string with commas between rule names, empty for all rules.
+    pub unsafe_fixes_rules: String,
     /// Did the user pass the --fix-only flag?
     pub fix_only: bool,
     /// Names of rules to use. A single string with commas between rule names.
@@ -48,8 +62,16 @@ pub struct ArgsConfig {
     pub allow_dirty: bool,
     /// Apply fixes even if there is no version control system?
     pub allow_no_vcs: bool,
+    /// Apply the fixes to the content staged in Git instead of the working
+    /// tree, and stage the result?
+    pub respect_staged: bool,
     /// Which assignment operator to use? Can be `"<-"` or `"="`.
     pub assignment: Option<String>,
+    /// Did the user pass the --preview flag?
+    pub preview: bool,
+    /// Set of rules to use instead of the default one, unless `select` is
+    /// passed.
+    pub profile: Option<Profile>,
 }
 
 #[derive(Clone)]
@@ -68,6 +90,10 @@ pub struct Config {
     pub apply_fixes: bool,
     /// Did the user pass the --unsafe-fixes flag?
     pub apply_unsafe_fixes: bool,
+    /// Did the user pass the --fix-only flag? In this case, `rules_to_apply`
+    /// only contains rules whose fixes can be applied, and the code that can't
+    /// be fixed (e.g. R Markdown documents) isn't checked.
+    pub fix_only: bool,
     /// The minimum R version used in the project. Used to disable some rules
     /// that require functions that are not available in all R versions, e.g.
     /// grepv() introduced in R 4.5.0.
@@ -76,6 +102,9 @@ pub struct Config {
     pub allow_dirty: bool,
     /// Apply fixes even if there is no version control system?
     pub allow_no_vcs: bool,
+    /// Apply the fixes to the content staged in Git instead of the working
+    /// tree, and stage the result?
+    pub respect_staged: bool,
     /// Rules that should not have their fixes applied (from unfixable setting)
     pub unfixable: HashSet<String>,
     /// Rules that are allowed to have fixes applied (from fixable setting)
@@ -85,6 +114,9 @@ pub struct Config {
     pub check_roxygen: bool,
     /// Whether to apply autofixes to roxygen examples
     pub fix_roxygen: bool,
+    /// Chunks of R Markdown, Quarto, and Sweave documents with one of these
+    /// options are not checked.
+    pub skip_chunks: Vec<ChunkOption>,
     /// Resolved per-rule options (wrapped in Arc to avoid expensive clones)
     pub rule_options: Arc<ResolvedRuleOptions>,
     /// Shared cache of installed R package metadata for package-specific rules.
@@ -92,25 +124,90 @@ pub struct Config {
     pub package_cache: Option<Arc<PackageCache>>,
     /// Per-file rule ignores resolved from `[lint.per-file-ignores]`.
     pub per_file_ignores: PerFileIgnores,
+    /// Per-rule `include`/`exclude` patterns resolved from `[lint.<rule>]`.
+    pub rule_paths: RulePaths,
+    /// Per-rule severity resolved from `[lint.severity]`, by rule name. Rules
+    /// that aren't listed report warnings.
+    pub severity: HashMap<String, Severity>,
+    /// Severity of all violations in a file resolved from
+    /// `[lint.severity-by-kind]`, by kind of file. This takes precedence over
+    /// `severity`.
+    pub severity_by_kind: HashMap<CodeKind, Severity>,
+    /// Per-path rule selection and options resolved from `[[lint.overrides]]`.
+    pub overrides: Overrides,
+    /// Command run on every file modified by fixes, from `post-command` in
+    /// `[fix]`.
+    pub post_fix_command: Option<String>,
+    /// File system used to read the files to lint and write the fixed files.
+    /// This is the file system of the OS unless replaced, e.g. to lint files
+    /// that only exist in memory.
+    pub file_system: Arc<dyn FileSystem>,
 }
 
 pub fn build_config(
     check_config: &ArgsConfig,
     toml_settings: Option<&Settings>,
     paths: Vec<PathBuf>,
+) -> Result<Config> {
+    build_config_in(check_config, toml_settings, paths, Arc::new(OsFileSystem))
+}
+
+/// Same as [build_config()] but the files, including the DESCRIPTION used to
+/// find the minimum R version, are read from `file_system`.
+pub fn build_config_in(
+    check_config: &ArgsConfig,
+    toml_settings: Option<&Settings>,
+    paths: Vec<PathBuf>,
+    file_system: Arc<dyn FileSystem>,
 ) -> Result<Config> {
     // Determining the minimum R version has to come first since if it is
     // unknown then only rules that don't have a version restriction are
     // selected.
-    let minimum_r_version = determine_minimum_r_version(check_config, &paths)?;
+    let minimum_r_version =
+        determine_minimum_r_version(check_config, &paths, file_system.as_ref())?;
 
-    let rules_cli = parse_rules_cli(
+    let mut rules_cli = parse_rules_cli(
         &check_config.select,
         &check_config.extend_select,
         &check_config.ignore,
     )?;
-    let rules_toml = parse_rules_toml(toml_settings)?;
-    let rules = reconcile_rules(rules_cli, rules_toml)?;
+    let mut rules_toml = parse_rules_toml(toml_settings)?;
+
+    // Preview rules can only be used in preview mode, whether they are
+    // selected by default, by name, or by group.
+    let preview = check_config.preview
+        || toml_settings
+            .and_then(|s| s.linter.preview)
+            .unwrap_or(false);
+
+    // A profile selects its rules, unless `select` is passed in the same
+    // place.
+    if let Some(profile) = check_config.profile
+        && rules_cli.selected.is_none()
+    {
+        rules_cli.selected = Some(HashSet::from_iter(profile.rules(preview)));
+    }
+    if let Some(profile) = toml_settings.and_then(|s| s.linter.profile)
+        && rules_toml.selected.is_none()
+    {
+        rules_toml.selected = Some(HashSet::from_iter(profile.rules(preview)));
+    }
+
+    // Rules that `[[lint.overrides]]` can enable: those passed to `--select`
+    // (if any) and not passed to `--ignore`.
+    let rules_overridable: RuleSet = Rule::all()
+        .iter()
+        .filter(|rule| {
+            rules_cli
+                .selected
+                .as_ref()
+                .is_none_or(|selected| selected.contains(rule.name()))
+                && !rules_cli.ignored.contains(rule.name())
+                && (preview || !rule.is_preview())
+        })
+        .collect();
+
+    let rules = reconcile_rules(rules_cli, rules_toml, preview)?;
 
     let rules = filter_rules_by_version(&rules, minimum_r_version);
 
@@ -118,33 +215,13 @@ pub fn build_config(
     // These will be stored in Config and checked when applying fixes.
     let (fixable_toml, unfixable_toml) = parse_fixable_toml(toml_settings)?;
 
-    // Resolve the interaction between --fix and --unsafe-fixes first. Using
-    // --unsafe-fixes implies using --fix, but the opposite is not true.
-    let rules_to_apply = match (check_config.fix, check_config.unsafe_fixes) {
-        (false, false) => rules.clone(),
+    let unsafe_fixes = resolve_unsafe_fixes(check_config, toml_settings)?;
 
-        (true, false) => rules
-            .iter()
-            .filter(|r| r.has_no_fix() || r.has_safe_fix())
-            .collect::<RuleSet>(),
-
-        (_, true) => rules
-            .iter()
-            .filter(|r| r.has_no_fix() || r.has_safe_fix() || r.has_unsafe_fix())
-            .collect::<RuleSet>(),
-    };
-
-    // We can now drop rules that don't have any fix if the user passed
-    // --fix-only. This could maybe be done above but dealing with the three
-    // args at the same time makes it much more complex.
-    let rules_to_apply = if check_config.fix_only {
-        rules
-            .iter()
-            .filter(|r| !r.has_no_fix())
-            .collect::<RuleSet>()
-    } else {
-        rules_to_apply
+    let fixable = FixableRules {
+        fixable: fixable_toml.as_ref(),
+        unfixable: &unfixable_toml,
     };
+    let rules_to_apply = filter_rules_by_fix_mode(&rules, check_config, &unsafe_fixes, &fixable);
 
     let mut rule_options = toml_settings
         .map(|s| s.linter.rule_options.clone())
@@ -163,29 +240,195 @@ pub fn build_config(
         .and_then(|s| s.linter.fix_roxygen)
         .unwrap_or(false);
 
+    let skip_chunks = toml_settings
+        .map(|s| s.linter.skip_chunks.clone())
+        .unwrap_or_default();
+
     let per_file_ignores = toml_settings
         .map(|s| s.linter.per_file_ignores.clone())
         .unwrap_or_default();
 
+    let rule_paths = toml_settings
+        .map(|s| s.linter.rule_paths.clone())
+        .unwrap_or_default();
+
+    let severity = toml_settings
+        .map(|s| s.linter.severity.clone())
+        .unwrap_or_default();
+
+    let severity_by_kind = toml_settings
+        .map(|s| s.linter.severity_by_kind.clone())
+        .unwrap_or_default();
+
+    let post_fix_command = toml_settings.and_then(|s| s.fix.post_command.clone());
+
+    // Overrides can only enable rules that could have been selected in
+    // `jarl.toml`, given the CLI arguments and the minimum R version.
+    let mut overrides = toml_settings
+        .map(|s| s.linter.overrides.clone())
+        .unwrap_or_default();
+    if !overrides.is_empty() {
+        let rules_overridable = filter_rules_by_version(&rules_overridable, minimum_r_version);
+        overrides.retain_rules(&filter_rules_by_fix_mode(
+            &rules_overridable,
+            check_config,
+            &unsafe_fixes,
+            &fixable,
+        ));
+    }
+
     Ok(Config {
         paths,
         rules,
         rules_to_apply,
         apply_fixes: check_config.fix,
-        apply_unsafe_fixes: check_config.unsafe_fixes,
+        apply_unsafe_fixes: unsafe_fixes != UnsafeFixes::None,
+        fix_only: check_config.fix_only,
         minimum_r_version,
         allow_dirty: check_config.allow_dirty,
         allow_no_vcs: check_config.allow_no_vcs,
+        respect_staged: check_config.respect_staged,
         unfixable: unfixable_toml,
         fixable: fixable_toml,
         check_roxygen,
         fix_roxygen,
+        skip_chunks,
         rule_options: Arc::new(rule_options),
         package_cache: None,
         per_file_ignores,
+        rule_paths,
+        severity,
+        severity_by_kind,
+        overrides,
+        post_fix_command,
+        file_system,
     })
 }
 
+/// Rules whose unsafe fixes are applied.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum UnsafeFixes {
+    /// Only safe fixes are applied.
+    #[default]
+    None,
+    All,
+    Rules(HashSet<String>),
+}
+
+impl UnsafeFixes {
+    pub fn allows(&self, rule: &Rule) -> bool {
+        match self {
+            UnsafeFixes::None => false,
+            UnsafeFixes::All => true,
+            UnsafeFixes::Rules(rules) => rules.contains(rule.name()),
+        }
+    }
+}
+
+/// Resolve the rules whose unsafe fixes are applied from `--unsafe-fixes` or,
+/// when fixes are applied, from `unsafe-fixes` in `jarl.toml`.
+fn resolve_unsafe_fixes(
+    check_config: &ArgsConfig,
+    toml_settings: Option<&Settings>,
+) -> Result<UnsafeFixes> {
+    let (rules, source) = if check_config.unsafe_fixes {
+        if check_config.unsafe_fixes_rules.is_empty() {
+            return Ok(UnsafeFixes::All);
+        }
+        let rules = check_config
+            .unsafe_fixes_rules
+            .split(',')
+            .map(|rule| rule.to_string())
+            .collect::<Vec<_>>();
+        (rules, "`--unsafe-fixes`")
+    } else {
+        let toml_unsafe_fixes = toml_settings.and_then(|s| s.linter.unsafe_fixes.clone());
+        match toml_unsafe_fixes {
+            _ if !check_config.fix => return Ok(UnsafeFixes::None),
+            None | Some(UnsafeFixesConfig::Enabled(false)) => return Ok(UnsafeFixes::None),
+            Some(UnsafeFixesConfig::Enabled(true)) => return Ok(UnsafeFixes::All),
+            Some(UnsafeFixesConfig::Rules(rules)) => (rules, "field `unsafe-fixes` in 'jarl.toml'"),
+        }
+    };
+
+    let all_rules = Rule::all();
+    let passed_by_user = rules.iter().map(|s| s.as_str()).collect();
+    let expanded_rules = replace_group_rules(&passed_by_user, all_rules);
+    if let Some(invalid) = get_invalid_rules(all_rules, &expanded_rules) {
+        return Err(unknown_rules_error(
+            format!("Unknown rules in {source}: {}", invalid.names.join(", ")),
+            invalid.help,
+        ));
+    }
+
+    Ok(UnsafeFixes::Rules(HashSet::from_iter(
+        all_rules
+            .iter()
+            .filter(|r| expanded_rules.iter().any(|name| name == r.name()))
+            .map(|x| x.name().to_string()),
+    )))
+}
+
+/// Rules whose fixes are allowed by `fixable` and `unfixable` in `jarl.toml`.
+struct FixableRules<'a> {
+    fixable: Option<&'a HashSet<String>>,
+    unfixable: &'a HashSet<String>,
+}
+
+impl FixableRules<'_> {
+    fn allows(&self, rule: &Rule) -> bool {
+        !self.unfixable.contains(rule.name())
+            && self
+                .fixable
+                .is_none_or(|fixable| fixable.contains(rule.name()))
+    }
+}
+
+/// Keep the rules that can be applied given `--fix`, `--unsafe-fixes`, and
+/// `--fix-only`.
+fn filter_rules_by_fix_mode(
+    rules: &RuleSet,
+    check_config: &ArgsConfig,
+    unsafe_fixes: &UnsafeFixes,
+    fixable: &FixableRules,
+) -> RuleSet {
+    // Resolve the interaction between --fix and --unsafe-fixes first. Using
+    // --unsafe-fixes implies using --fix, but the opposite is not true. Rules
+    // whose unsafe fixes are not selected are dropped like with --fix.
+    // --fix-only also implies --fix.
+    let fix = check_config.fix || check_config.fix_only;
+    let rules_to_apply = match (fix, unsafe_fixes) {
+        (false, UnsafeFixes::None) => rules.clone(),
+
+        (true, UnsafeFixes::None) => rules
+            .iter()
+            .filter(|r| r.has_no_fix() || r.has_safe_fix())
+            .collect::<RuleSet>(),
+
+        (_, unsafe_fixes) => rules
+            .iter()
+            .filter(|r| {
+                r.has_no_fix() || r.has_safe_fix() || (r.has_unsafe_fix() && unsafe_fixes.allows(r))
+            })
+            .collect::<RuleSet>(),
+    };
+
+    // We can now drop the rules that can't contribute any fix if the user
+    // passed --fix-only: those that don't have a fix, and those whose fixes
+    // are disabled with `fixable` or `unfixable` in `jarl.toml`. They are not
+    // run at all since their violations are not reported. This could maybe be
+    // done above but dealing with the three args at the same time makes it
+    // much more complex.
+    if check_config.fix_only {
+        rules_to_apply
+            .iter()
+            .filter(|r| !r.has_no_fix() && fixable.allows(r))
+            .collect::<RuleSet>()
+    } else {
+        rules_to_apply
+    }
+}
+
 /// Parse CLI rule arguments and return (selected_rules, ignored_rules).
 ///
 /// Returns None for selected_rules if no --select was specified.
@@ -517,19 +760,8 @@ pub(crate) fn get_invalid_rules(
 
         names.push(rule.clone());
 
-        let suggestions = suggest_rule_names(trimmed, &suggestion_candidates);
-        match suggestions.as_slice() {
-            [] => {}
-            [only] => help.push(format!("Did you mean \"{only}\"?")),
-            many => {
-                let quoted = many
-                    .iter()
-                    .map(|s| format!("\"{s}\""))
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                help.push(format!("Did you mean one of {quoted}?"));
-            }
-        }
+        let suggestions = suggest_names(trimmed, &suggestion_candidates);
+        help.extend(did_you_mean(&suggestions));
     }
 
     if names.is_empty() {
@@ -539,17 +771,27 @@ pub(crate) fn get_invalid_rules(
     }
 }
 
+/// Format `suggestions` as a "did you mean" help line, if there are any.
+pub(crate) fn did_you_mean(suggestions: &[String]) -> Option<String> {
+    match suggestions {
+        [] => None,
+        [only] => Some(format!("Did you mean \"{only}\"?")),
+        many => {
+            let quoted = many
+                .iter()
+                .map(|s| format!("\"{s}\""))
+                .collect::<Vec<_>>()
+                .join(", ");
+            Some(format!("Did you mean one of {quoted}?"))
+        }
+    }
+}
+
 /// Build an `Unknown rules` error carrying optional "did you mean" help lines.
 pub(crate) fn unknown_rules_error(message: String, help: Vec<String>) -> anyhow::Error {
     anyhow::Error::new(UnknownRulesError { message, help })
 }
 
-/// Find valid rule names closest to `input` for a "did you mean" suggestion.
-///
-/// Returns up to 3 candidates that all share the minimum edit distance, and
-/// only when that distance is within an acceptance threshold (so unrelated
-/// input like "foo" yields no suggestion). Uses Damerau-Levenshtein distance,
-/// which also accounts for adjacent transpositions (e.g. "treu" -> "true").
 /// Suggest known rule names close to `input`, for "did you mean" hints when a
 /// user passes an unknown rule (e.g. to `jarl rule <name>`).
 pub fn suggest_rules(input: &str) -> Vec<String> {
@@ -557,10 +799,17 @@ pub fn suggest_rules(input: &str) -> Vec<String> {
         .iter()
         .map(|rule| rule.name())
         .collect();
-    suggest_rule_names(input, &candidates)
+    suggest_names(input, &candidates)
 }
 
-fn suggest_rule_names(input: &str, candidates: &[&str]) -> Vec<String> {
+/// Find the candidates closest to `input` for a "did you mean" suggestion,
+/// e.g. rule names or configuration fields.
+///
+/// Returns up to 3 candidates that all share the minimum edit distance, and
+/// only when that distance is within an acceptance threshold (so unrelated
+/// input like "foo" yields no suggestion). Uses Damerau-Levenshtein distance,
+/// which also accounts for adjacent transpositions (e.g. "treu" -> "true").
+pub(crate) fn suggest_names(input: &str, candidates: &[&str]) -> Vec<String> {
     // Allow roughly one edit per three characters, with a floor of 1 so short
     // typos are still caught.
     let threshold = (input.chars().count() / 3).max(1);
@@ -587,10 +836,16 @@ fn suggest_rule_names(input: &str, candidates: &[&str]) -> Vec<String> {
 /// Reconcile rules from CLI and TOML configuration.
 ///
 /// Strategy:
-/// - CLI select takes precedence over TOML select
+/// - CLI select takes precedence over TOML select, a profile is a shortcut
+///   for select
 /// - CLI ignore and TOML ignore are combined (both applied)
 /// - If neither CLI nor TOML specify select, start with all rules
-fn reconcile_rules(rules_cli: RuleSelection, rules_toml: RuleSelection) -> Result<RuleSet> {
+/// - Preview rules are dropped unless `preview` is `true`
+fn reconcile_rules(
+    rules_cli: RuleSelection,
+    rules_toml: RuleSelection,
+    preview: bool,
+) -> Result<RuleSet> {
     let all_rules = Rule::all();
     let cli_selected = rules_cli.selected;
     let cli_extended = rules_cli.extended;
@@ -608,7 +863,7 @@ fn reconcile_rules(rules_cli: RuleSelection, rules_toml: RuleSelection) -> Resul
         toml_selected
     } else {
         // Neither CLI nor TOML specified select rules, use the default set of rules
-        HashSet::from_iter(all_rules_enabled_by_default())
+        HashSet::from_iter(all_rules_enabled_by_default(preview))
     };
 
     // Step 2: Add extended rules (CLI extend-select takes precedence over TOML extend-select)
@@ -629,6 +884,7 @@ fn reconcile_rules(rules_cli: RuleSelection, rules_toml: RuleSelection) -> Resul
     let final_rules: RuleSet = all_rules
         .iter()
         .filter(|r| final_rule_names.iter().any(|name| name == r.name()))
+        .filter(|r| preview || !r.is_preview())
         .collect();
 
     Ok(final_rules)
@@ -638,25 +894,26 @@ fn reconcile_rules(rules_cli: RuleSelection, rules_toml: RuleSelection) -> Resul
 fn determine_minimum_r_version(
     check_config: &ArgsConfig,
     paths: &[PathBuf],
+    file_system: &dyn FileSystem,
 ) -> Result<Option<(u32, u32, u32)>> {
     if let Some(version_string) = &check_config.min_r_version {
         return Ok(Some(parse_r_version(version_string.clone())?));
     }
 
-    // Look for DESCRIPTION file in any of the project paths
-    // TODO: this seems wasteful but I don't have a good infrastructure for now
-    // for getting the common root of the paths.
+    // Look for the DESCRIPTION file of the package containing the paths. The
+    // CLI builds one config per package, so the paths all belong to the same
+    // package (if any).
     for path in paths {
-        let desc_path = if path.is_dir() {
+        let desc_path = if file_system.is_dir(path) {
             path.join("DESCRIPTION")
-        } else if let Some(parent) = path.parent() {
-            parent.join("DESCRIPTION")
+        } else if let Some(package_root) = find_package_root_in(path, file_system) {
+            package_root.join("DESCRIPTION")
         } else {
             continue;
         };
 
-        if desc_path.exists() {
-            let desc = fs::read_to_string(&desc_path)?;
+        if file_system.exists(&desc_path) {
+            let desc = file_system.read_to_string(&desc_path)?;
             if let Ok(versions) = Description::get_depend_r_version(&desc)
                 && let Some(version_str) = versions.first()
             {
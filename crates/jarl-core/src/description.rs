@@ -37,6 +37,14 @@ impl Description {
         packages
     }
 
+    /// Extract the name of the package from the `Package` field.
+    pub fn get_package_name(contents: &str) -> Option<String> {
+        parse_dcf(contents)
+            .get("Package")
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+    }
+
     /// Extract R version requirements from the Depends field of a DESCRIPTION file
     ///
     /// Returns a vector of version strings found in R dependencies.
@@ -83,7 +91,7 @@ fn extract_version_from_dependency(dep: &str) -> Option<String> {
 
 /// Parse a DCF (Debian Control File) format string into a key-value map
 /// Minimal implementation focused on extracting the Depends field
-fn parse_dcf(input: &str) -> HashMap<String, String> {
+pub(crate) fn parse_dcf(input: &str) -> HashMap<String, String> {
     let mut fields = HashMap::new();
     let mut current_key: Option<String> = None;
     let mut current_value = String::new();
@@ -129,6 +137,19 @@ fn parse_dcf(input: &str) -> HashMap<String, String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_get_package_name() {
+        let description = r#"
+Package: mypackage
+Version: 1.0.0
+"#;
+        assert_eq!(
+            Description::get_package_name(description),
+            Some("mypackage".to_string())
+        );
+        assert_eq!(Description::get_package_name("Version: 1.0.0"), None);
+    }
+
     #[test]
     fn test_no_depends_field() {
         let description = r#"
@@ -2,7 +2,9 @@ use annotate_snippets::{Level, Renderer, Snippet};
 use biome_rowan::TextRange;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
-use std::path::PathBuf;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use crate::location::Location;
 use crate::rule_set::{FixStatus, Rule};
@@ -29,6 +31,10 @@ impl Fix {
     }
 }
 
+/// Name of the diagnostics reported for code that couldn't be parsed. This is
+/// not a rule: syntax errors are always reported and can't be suppressed.
+pub const SYNTAX_ERROR: &str = "syntax_error";
+
 /// Details on the violated rule.
 pub trait Violation {
     /// Name of the rule.
@@ -41,6 +47,53 @@ pub trait Violation {
     }
 }
 
+/// How serious a violation is.
+///
+/// All rules report warnings by default. This can be changed per rule with
+/// `[lint.severity]` in `jarl.toml`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    #[default]
+    Warning,
+    Info,
+}
+
+impl Severity {
+    pub const ALL: [Severity; 3] = [Severity::Error, Severity::Warning, Severity::Info];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Severity::Error),
+            "warning" => Ok(Severity::Warning),
+            "info" => Ok(Severity::Info),
+            _ => Err(format!(
+                "Invalid severity `{s}`. Expected one of: `error`, `warning`, `info`."
+            )),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
 pub struct ViolationData {
     pub name: String,
@@ -53,6 +106,8 @@ pub struct ViolationData {
 pub struct Diagnostic {
     // The name and description of the violated rule.
     pub message: ViolationData,
+    // How serious the violation is, `warning` unless configured otherwise.
+    pub severity: Severity,
     // Location of the violated rule.
     pub filename: PathBuf,
     pub range: TextRange,
@@ -89,6 +144,7 @@ impl Diagnostic {
     pub fn new<T: Into<ViolationData>>(message: T, range: TextRange, fix: Fix) -> Self {
         Self {
             message: message.into(),
+            severity: Severity::default(),
             range,
             location: None,
             fix,
@@ -99,6 +155,7 @@ impl Diagnostic {
     pub fn empty() -> Self {
         Self {
             message: ViolationData::empty(),
+            severity: Severity::default(),
             range: TextRange::empty(0.into()),
             location: None,
             fix: Fix::empty(),
@@ -106,6 +163,28 @@ impl Diagnostic {
         }
     }
 
+    /// Report code that couldn't be parsed.
+    pub fn syntax_error(range: TextRange) -> Self {
+        Self {
+            message: ViolationData::new(
+                SYNTAX_ERROR.to_string(),
+                "This code couldn't be parsed.".to_string(),
+                Some(
+                    "Fixes are disabled in this file until the syntax error is fixed.".to_string(),
+                ),
+            ),
+            severity: Severity::Error,
+            range,
+            location: None,
+            fix: Fix::empty(),
+            filename: "".into(),
+        }
+    }
+
+    pub fn is_syntax_error(&self) -> bool {
+        self.message.name == SYNTAX_ERROR
+    }
+
     // TODO: in these three functions, the first condition should be removed
     // once comments in nodes are better handled, #95.
     pub fn has_safe_fix(&self) -> bool {
@@ -132,6 +211,27 @@ impl Diagnostic {
             .map(|r| r.fix_status() == FixStatus::None)
             .unwrap_or(true)
     }
+
+    /// Fingerprint of the diagnostic. `canonical_path` is the canonicalized
+    /// path of the file, so that `R/foo.R`, `./R/foo.R`, or a symlink to this
+    /// file all have the same fingerprint.
+    pub fn fingerprint(&self, canonical_path: &Path) -> DiagnosticFingerprint {
+        DiagnosticFingerprint {
+            path: canonical_path.to_path_buf(),
+            rule: self.message.name.clone(),
+            range: self.range,
+        }
+    }
+}
+
+/// Identity of a diagnostic: a rule violated at a location of a file. It is
+/// used to report each violation once when the same file is checked several
+/// times, e.g. with `jarl check R R/foo.R`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DiagnosticFingerprint {
+    path: PathBuf,
+    rule: String,
+    range: TextRange,
 }
 
 impl Ord for Diagnostic {
@@ -153,7 +253,7 @@ impl PartialOrd for Diagnostic {
 /// Render a single diagnostic as an annotated code snippet.
 ///
 /// Uses `annotate_snippets` to produce a formatted message with the source
-/// context, warning label, and optional suggestion footer.
+/// context, severity label, and optional suggestion footer.
 ///
 /// The `title` parameter allows callers to customize the message title
 /// (e.g. the CLI uses a hyperlinked rule name, while tests use the plain name).
@@ -173,16 +273,22 @@ pub fn render_diagnostic(
     // that contain the annotation span to avoid scanning the entire file.
     let (expanded, adj_start, adj_end) = expand_span_line_tabs(source, start_offset, end_offset);
 
+    let level = match diagnostic.severity {
+        Severity::Error => Level::Error,
+        Severity::Warning => Level::Warning,
+        Severity::Info => Level::Info,
+    };
+
     let snippet = Snippet::source(&expanded)
         .origin(origin)
         .fold(true)
         .annotation(
-            Level::Warning
+            level
                 .span(adj_start..adj_end)
                 .label(&diagnostic.message.body),
         );
 
-    let mut message = Level::Warning.title(title).snippet(snippet);
+    let mut message = level.title(title).snippet(snippet);
 
     if let Some(suggestion_text) = &diagnostic.message.suggestion {
         message = message.footer(Level::Help.title(suggestion_text));
@@ -5,16 +5,23 @@
 // MIT License - Posit PBC
 
 use ignore::DirEntry;
+use regex::Regex;
 use rustc_hash::FxHashSet;
+use std::collections::HashMap;
 use std::path::Path;
 use std::path::PathBuf;
 
+use crate::config::unknown_rules_error;
+use crate::error::UnknownRulesError;
+use crate::file_system::{FileSystem, OsFileSystem};
 use crate::fs;
 use crate::fs::has_r_extension;
 use crate::fs::has_rmd_extension;
+use crate::package::find_package_root_in;
+use crate::package::package_relative_path;
 use crate::settings::Settings;
 use crate::toml::find_jarl_toml_in_directory;
-use crate::toml::parse_jarl_toml;
+use crate::toml::parse_jarl_toml_in;
 use air_workspace::resolve::PathResolver;
 use etcetera::BaseStrategy;
 
@@ -23,6 +30,7 @@ use etcetera::BaseStrategy;
 pub const DEFAULT_EXCLUDE_PATTERNS: &[&str] = &[
     ".git/",
     "renv/",
+    "packrat/",
     "revdep/",
     "cpp11.R",
     "RcppExports.R",
@@ -54,6 +62,15 @@ fn get_user_config_dir() -> Option<PathBuf> {
 /// - If no config found in ancestors, fall back to checking the user config directory
 /// - If `path` is a directory, also walk down into it to find any nested `jarl.toml`s
 pub fn discover_settings<P: AsRef<Path>>(paths: &[P]) -> anyhow::Result<Vec<DiscoveredSettings>> {
+    discover_settings_in(&OsFileSystem, paths)
+}
+
+/// Same as [discover_settings()] but looks for the `jarl.toml`s in
+/// `file_system`.
+pub fn discover_settings_in<P: AsRef<Path>>(
+    file_system: &dyn FileSystem,
+    paths: &[P],
+) -> anyhow::Result<Vec<DiscoveredSettings>> {
     let paths: Vec<PathBuf> = paths.iter().map(fs::normalize_path).collect();
 
     let mut seen = FxHashSet::default();
@@ -72,8 +89,8 @@ pub fn discover_settings<P: AsRef<Path>>(paths: &[P]) -> anyhow::Result<Vec<Disc
                 break;
             }
 
-            if let Some(toml) = find_jarl_toml_in_directory(ancestor) {
-                let settings = parse_settings(&toml, ancestor)?;
+            if let Some(toml) = find_jarl_toml_in_directory(ancestor, file_system) {
+                let settings = parse_settings(&toml, ancestor, file_system)?;
                 discovered_settings.push(DiscoveredSettings {
                     directory: ancestor.to_path_buf(),
                     settings,
@@ -95,9 +112,9 @@ pub fn discover_settings<P: AsRef<Path>>(paths: &[P]) -> anyhow::Result<Vec<Disc
         if !found_config
             && let Some(ref config_dir) = user_config_dir
             && seen.insert(config_dir.as_path())
-            && let Some(toml) = find_jarl_toml_in_directory(config_dir)
+            && let Some(toml) = find_jarl_toml_in_directory(config_dir, file_system)
         {
-            let settings = parse_settings(&toml, config_dir)?;
+            let settings = parse_settings(&toml, config_dir, file_system)?;
             discovered_settings.push(DiscoveredSettings {
                 directory: config_dir.clone(),
                 settings,
@@ -115,8 +132,13 @@ pub fn discover_settings<P: AsRef<Path>>(paths: &[P]) -> anyhow::Result<Vec<Disc
         .collect();
 
     for path in &paths {
-        if path.is_dir() {
-            discover_nested_settings(path, &mut already_found, &mut discovered_settings)?;
+        if file_system.is_dir(path) {
+            discover_nested_settings(
+                file_system,
+                path,
+                &mut already_found,
+                &mut discovered_settings,
+            )?;
         }
     }
 
@@ -125,47 +147,28 @@ pub fn discover_settings<P: AsRef<Path>>(paths: &[P]) -> anyhow::Result<Vec<Disc
 
 /// Walk down into `root`, collecting any nested `jarl.toml` files not yet in `already_found`.
 fn discover_nested_settings(
+    file_system: &dyn FileSystem,
     root: &Path,
     already_found: &mut FxHashSet<PathBuf>,
     discovered_settings: &mut Vec<DiscoveredSettings>,
 ) -> anyhow::Result<()> {
-    let walker = ignore::WalkBuilder::new(root)
-        .hidden(true)
-        .parents(true)
-        .ignore(false)
-        .git_ignore(true)
-        .git_global(true)
-        .git_exclude(true)
-        .build();
-
-    for result in walker {
-        let entry = match result {
-            Ok(entry) => entry,
-            Err(_) => continue,
-        };
-
-        let path = entry.path();
-
-        // Skip the root itself; its config was already handled by the ancestor walk above
-        if path == root {
-            continue;
-        }
-
-        // Only check directories for nested jarl.toml files
-        if entry.file_type().is_none_or(|ft| !ft.is_dir()) {
-            continue;
-        }
+    let directories = if file_system.is_os() {
+        walk_os_directories(root)
+    } else {
+        walk_file_system_directories(file_system, root)
+    };
 
+    for path in directories {
         // Skip if this directory's config was already discovered
-        if already_found.contains(path) {
+        if already_found.contains(&path) {
             continue;
         }
 
-        if let Some(toml) = find_jarl_toml_in_directory(path) {
-            already_found.insert(path.to_path_buf());
-            let settings = parse_settings(&toml, path)?;
+        if let Some(toml) = find_jarl_toml_in_directory(&path, file_system) {
+            let settings = parse_settings(&toml, &path, file_system)?;
+            already_found.insert(path.clone());
             discovered_settings.push(DiscoveredSettings {
-                directory: path.to_path_buf(),
+                directory: path,
                 settings,
                 config_path: Some(toml),
             });
@@ -175,15 +178,65 @@ fn discover_nested_settings(
     Ok(())
 }
 
-/// Parse [Settings] from a given `jarl.toml`
-// TODO(hierarchical): Allow for an `extends` option in `jarl.toml`, which will make things
-// more complex, but will be very useful once we support hierarchical configuration as a
-// way of "inheriting" most top level configuration while slightly tweaking it in a nested directory.
-fn parse_settings(toml: &Path, root_directory: &Path) -> anyhow::Result<Settings> {
-    let options = parse_jarl_toml(toml)?;
-    let settings = options
-        .into_settings(root_directory)
-        .map_err(|err| anyhow::anyhow!("Invalid configuration in {}:\n{err}", toml.display()))?;
+/// Directories below `root` (excluding `root` itself), skipping hidden and
+/// git-ignored ones.
+fn walk_os_directories(root: &Path) -> Vec<PathBuf> {
+    let walker = ignore::WalkBuilder::new(root)
+        .hidden(true)
+        .parents(true)
+        .ignore(false)
+        .git_ignore(true)
+        .git_global(true)
+        .git_exclude(true)
+        .build();
+
+    walker
+        .filter_map(Result::ok)
+        // Skip the root itself; its config was already handled by the ancestor walk
+        .filter(|entry| entry.path() != root)
+        .filter(|entry| entry.file_type().is_some_and(|ft| ft.is_dir()))
+        .map(|entry| entry.into_path())
+        .collect()
+}
+
+/// Same as [walk_os_directories()] for other file systems, which have no
+/// `.gitignore`.
+fn walk_file_system_directories(file_system: &dyn FileSystem, root: &Path) -> Vec<PathBuf> {
+    let mut directories = Vec::new();
+    let mut stack = vec![root.to_path_buf()];
+    while let Some(dir) = stack.pop() {
+        let Ok(entries) = file_system.read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries {
+            let is_hidden = entry
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+            if file_system.is_dir(&entry) && !is_hidden {
+                directories.push(entry.clone());
+                stack.push(entry);
+            }
+        }
+    }
+    directories
+}
+
+/// Parse [Settings] from a given `jarl.toml`, including the configuration it
+/// `extends` (if any).
+fn parse_settings(
+    toml: &Path,
+    root_directory: &Path,
+    file_system: &dyn FileSystem,
+) -> anyhow::Result<Settings> {
+    let options = parse_jarl_toml_in(toml, file_system)?;
+    let settings = options.into_settings(root_directory).map_err(|err| {
+        let message = format!("Invalid configuration in {}:\n{err}", toml.display());
+        // Keep the "did you mean" suggestions of unknown rules and fields.
+        match err.downcast::<UnknownRulesError>() {
+            Ok(unknown) => unknown_rules_error(message, unknown.help),
+            Err(_) => anyhow::anyhow!(message),
+        }
+    })?;
     Ok(settings)
 }
 
@@ -215,15 +268,16 @@ fn excluded_with_parents(overrides: &ignore::overrides::Override, relative: &Pat
     false
 }
 
-/// Validate user-supplied `--exclude` glob patterns, returning an error on the
-/// first invalid one so a bad pattern is a hard failure rather than a
-/// silently-ignored warning during discovery.
-pub fn validate_exclude_patterns(patterns: &[String]) -> anyhow::Result<()> {
+/// Validate user-supplied `--exclude` or `--extend-exclude` glob patterns,
+/// returning an error on the first invalid one so a bad pattern is a hard
+/// failure rather than a silently-ignored warning during discovery. `option`
+/// is the name of the CLI option, used in the error message.
+pub fn validate_exclude_patterns(patterns: &[String], option: &str) -> anyhow::Result<()> {
     let mut builder = ignore::overrides::OverrideBuilder::new(".");
     for pattern in patterns {
         builder
             .add(pattern)
-            .map_err(|err| anyhow::anyhow!("invalid `--exclude` pattern: {err}"))?;
+            .map_err(|err| anyhow::anyhow!("invalid `{option}` pattern: {err}"))?;
     }
     Ok(())
 }
@@ -231,6 +285,9 @@ pub fn validate_exclude_patterns(patterns: &[String]) -> anyhow::Result<()> {
 /// For each provided `path`, recursively search for any R files within that `path`
 /// that match our inclusion criteria
 ///
+/// Symbolic links are skipped unless `follow_symlinks` is set, in which case the
+/// links that form a cycle are skipped instead.
+///
 /// NOTE: Make sure that the inclusion criteria that guide `path` discovery are also
 /// consistently applied to [discover_settings()].
 pub fn discover_r_file_paths<P: AsRef<Path>>(
@@ -239,93 +296,60 @@ pub fn discover_r_file_paths<P: AsRef<Path>>(
     resolver: &PathResolver<Settings>,
     use_linter_settings: bool,
     no_default_exclude: bool,
+    follow_symlinks: bool,
+) -> DiscoveredFiles {
+    discover_r_file_paths_in(
+        &OsFileSystem,
+        paths,
+        cli_exclude,
+        resolver,
+        use_linter_settings,
+        no_default_exclude,
+        follow_symlinks,
+    )
+}
+
+/// Same as [discover_r_file_paths()] but looks for the files in `file_system`.
+///
+/// Unlike the file system of the OS, other file systems are walked with
+/// [FileSystem::read_dir()]: `.gitignore` files are not respected and there
+/// are no symbolic links.
+pub fn discover_r_file_paths_in<P: AsRef<Path>>(
+    file_system: &dyn FileSystem,
+    paths: &[P],
+    cli_exclude: &[String],
+    resolver: &PathResolver<Settings>,
+    use_linter_settings: bool,
+    no_default_exclude: bool,
+    follow_symlinks: bool,
 ) -> DiscoveredFiles {
     let paths: Vec<PathBuf> = paths.iter().map(fs::normalize_path).collect();
+    let explicit_paths: FxHashSet<PathBuf> = paths.iter().cloned().collect();
 
     let Some((first_path, paths)) = paths.split_first() else {
         // No paths provided
         return Vec::new();
     };
 
-    let mut builder = ignore::WalkBuilder::new(first_path);
-
-    for path in paths {
-        builder.add(path);
-    }
-
-    // TODO: Make these configurable options (possibly just one?)
-    // Right now we explicitly call them even though they are `true` by default
-    // to remind us to expose them.
-    //
-    // "This toggles, as a group, all the filters that are enabled by default"
-    // builder.standard_filters(true)
-    builder.hidden(true);
-    builder.parents(true);
-    builder.ignore(false);
-    builder.git_ignore(true);
-    builder.git_global(true);
-    builder.git_exclude(true);
-
     // Add exclude patterns from settings if linter settings should be used
-    if use_linter_settings {
-        // Build custom ignore patterns
-        let mut patterns = Vec::new();
-
-        // Anchor patterns at the checked base by default. `paths` are normalized
-        // to absolute paths, so a relative root like "." would never prefix-match
-        // the walk entries and path-anchored patterns (e.g. `R/foo.R`) wouldn't
-        // apply. A discovered config overrides this with its own directory.
-        let mut root = first_path.as_path();
-
-        if let Some(settings_item) = resolver.items().first() {
-            let settings = settings_item.value();
-            root = settings_item.path();
-
-            // Add custom exclude patterns from jarl.toml
-            if let Some(exclude_patterns) = &settings.linter.exclude {
-                for pattern in exclude_patterns {
-                    patterns.push(pattern.as_str());
-                }
-            }
-            if settings.linter.default_exclude.unwrap_or(true) {
-                // Add default exclude patterns
-                patterns.extend_from_slice(DEFAULT_EXCLUDE_PATTERNS);
-            }
-        } else if !no_default_exclude {
-            // Add default exclude patterns
-            patterns.extend_from_slice(DEFAULT_EXCLUDE_PATTERNS);
-        }
-
-        // If we have patterns, create an override and add it to the builder
-        if !patterns.is_empty() {
-            let mut override_builder = ignore::overrides::OverrideBuilder::new(root);
-            for pattern in patterns {
-                // Add as negation pattern (exclude)
-                if let Err(e) = override_builder.add(&format!("!{pattern}")) {
-                    tracing::warn!("Failed to add exclude pattern '{}': {}", pattern, e);
-                }
-            }
-            if let Ok(overrides) = override_builder.build() {
-                builder.overrides(overrides);
-            }
-        }
-    }
-
-    // Prefer `available_parallelism()`, with a max of 12 threads
-    builder.threads(
-        std::thread::available_parallelism()
-            .map_or(1, std::num::NonZeroUsize::get)
-            .min(12),
-    );
-
-    let walker = builder.build_parallel();
-
-    // Run the `WalkParallel` to collect all R files.
-    let state = FilesState::new();
-    let mut visitor_builder = FilesVisitorBuilder::new(&state);
-    walker.visit(&mut visitor_builder);
+    let overrides = if use_linter_settings {
+        exclude_overrides(first_path, resolver, no_default_exclude)
+    } else {
+        None
+    };
 
-    let mut files = state.finish();
+    let mut files = if file_system.is_os() {
+        walk_os(
+            first_path,
+            paths,
+            resolver,
+            use_linter_settings,
+            overrides,
+            follow_symlinks,
+        )
+    } else {
+        walk_file_system(file_system, first_path, paths, overrides.as_ref())
+    };
 
     // Post-filter: apply `--exclude` patterns from the CLI. These are anchored
     // at the current working directory (where the command is run), not at the
@@ -351,13 +375,16 @@ pub fn discover_r_file_paths<P: AsRef<Path>>(
         }
     }
 
-    // Post-filter: apply per-config exclude and include patterns.
+    // Post-filter: apply per-config exclude and include patterns,
+    // `.Rbuildignore` if `respect-rbuildignore` is set, and skip the documents
+    // of packages if `include-vignettes = false`.
     //
     // The WalkBuilder above only applies the first config's exclude patterns.
     // When there are multiple configs (e.g. root jarl.toml + mypkg/jarl.toml),
     // nested configs' exclude/include patterns must be enforced here by
     // resolving each file to its nearest config.
     if use_linter_settings {
+        let mut rbuildignore_by_package = HashMap::new();
         files.retain(|result| {
             let Ok(path) = result else {
                 return true;
@@ -370,6 +397,19 @@ pub fn discover_r_file_paths<P: AsRef<Path>>(
             let settings = item.value();
             let root = item.path();
 
+            if settings.linter.respect_rbuildignore.unwrap_or(false)
+                && matches_rbuildignore(path, file_system, &mut rbuildignore_by_package)
+            {
+                return false;
+            }
+
+            if !settings.linter.include_vignettes.unwrap_or(true)
+                && !explicit_paths.contains(path)
+                && is_package_document(path, file_system)
+            {
+                return false;
+            }
+
             // Exclude filter: remove files matching any exclude pattern
             if let Some(exclude_patterns) = &settings.linter.exclude
                 && !exclude_patterns.is_empty()
@@ -418,9 +458,271 @@ pub fn discover_r_file_paths<P: AsRef<Path>>(
         });
     }
 
+    // The same file can be reached with several paths, through a symlinked
+    // folder or with a different casing on case-insensitive file systems, so
+    // only the first path of each file is kept. Paths are sorted first so that
+    // the kept path doesn't depend on the order of the parallel walk.
+    files.sort_by(|a, b| a.as_ref().ok().cmp(&b.as_ref().ok()));
+    let mut seen = FxHashSet::default();
+    files.retain(|result| {
+        let Ok(path) = result else {
+            return true;
+        };
+        let canonical = file_system
+            .canonicalize(path)
+            .unwrap_or_else(|_| path.clone());
+        seen.insert(fs::path_key(&canonical))
+    });
+
+    files
+}
+
+/// Build the exclude patterns of the first config, or the default ones, as
+/// overrides of the walk.
+fn exclude_overrides(
+    first_path: &Path,
+    resolver: &PathResolver<Settings>,
+    no_default_exclude: bool,
+) -> Option<ignore::overrides::Override> {
+    // Build custom ignore patterns
+    let mut patterns = Vec::new();
+
+    // Anchor patterns at the checked base by default. `paths` are normalized
+    // to absolute paths, so a relative root like "." would never prefix-match
+    // the walk entries and path-anchored patterns (e.g. `R/foo.R`) wouldn't
+    // apply. A discovered config overrides this with its own directory.
+    let mut root = first_path;
+
+    if let Some(settings_item) = resolver.items().first() {
+        let settings = settings_item.value();
+        root = settings_item.path();
+
+        // Add custom exclude patterns from jarl.toml
+        if let Some(exclude_patterns) = &settings.linter.exclude {
+            for pattern in exclude_patterns {
+                patterns.push(pattern.as_str());
+            }
+        }
+        if settings.linter.default_exclude.unwrap_or(true) {
+            // Add default exclude patterns
+            patterns.extend_from_slice(DEFAULT_EXCLUDE_PATTERNS);
+        }
+    } else if !no_default_exclude {
+        // Add default exclude patterns
+        patterns.extend_from_slice(DEFAULT_EXCLUDE_PATTERNS);
+    }
+
+    if patterns.is_empty() {
+        return None;
+    }
+
+    let mut override_builder = ignore::overrides::OverrideBuilder::new(root);
+    for pattern in patterns {
+        // Add as negation pattern (exclude)
+        if let Err(e) = override_builder.add(&format!("!{pattern}")) {
+            tracing::warn!("Failed to add exclude pattern '{}': {}", pattern, e);
+        }
+    }
+    override_builder.build().ok()
+}
+
+/// Walk the paths of the file system of the OS with `ignore`.
+fn walk_os(
+    first_path: &Path,
+    paths: &[PathBuf],
+    resolver: &PathResolver<Settings>,
+    use_linter_settings: bool,
+    overrides: Option<ignore::overrides::Override>,
+    follow_symlinks: bool,
+) -> DiscoveredFiles {
+    let mut builder = ignore::WalkBuilder::new(first_path);
+
+    for path in paths {
+        builder.add(path);
+    }
+
+    // TODO: Make these configurable options (possibly just one?)
+    // Right now we explicitly call them even though they are `true` by default
+    // to remind us to expose them.
+    //
+    // "This toggles, as a group, all the filters that are enabled by default"
+    // builder.standard_filters(true)
+    builder.hidden(true);
+    builder.parents(true);
+    builder.ignore(false);
+    // `ignore` detects the cycles when following links and reports them as
+    // errors, which are dropped in `FilesVisitor::visit()`.
+    builder.follow_links(follow_symlinks);
+
+    // Like the exclude patterns of the walk, `respect-gitignore` comes from the
+    // first config.
+    let respect_gitignore = !use_linter_settings
+        || resolver
+            .items()
+            .first()
+            .is_none_or(|item| item.value().linter.respect_gitignore.unwrap_or(true));
+    builder.git_ignore(respect_gitignore);
+    builder.git_global(respect_gitignore);
+    builder.git_exclude(respect_gitignore);
+
+    if let Some(overrides) = overrides {
+        builder.overrides(overrides);
+    }
+
+    // Prefer `available_parallelism()`, with a max of 12 threads
+    builder.threads(
+        std::thread::available_parallelism()
+            .map_or(1, std::num::NonZeroUsize::get)
+            .min(12),
+    );
+
+    let walker = builder.build_parallel();
+
+    // Run the `WalkParallel` to collect all R files.
+    let state = FilesState::new();
+    let mut visitor_builder = FilesVisitorBuilder::new(&state, follow_symlinks);
+    walker.visit(&mut visitor_builder);
+
+    state.finish()
+}
+
+/// Walk the paths of another file system with [FileSystem::read_dir()],
+/// accepting the same files as [FilesVisitor].
+fn walk_file_system(
+    file_system: &dyn FileSystem,
+    first_path: &Path,
+    paths: &[PathBuf],
+    overrides: Option<&ignore::overrides::Override>,
+) -> DiscoveredFiles {
+    let mut files = Vec::new();
+
+    for path in std::iter::once(first_path).chain(paths.iter().map(PathBuf::as_path)) {
+        // Accept explicitly provided files, regardless of exclusion/inclusion
+        // criteria (including extension).
+        if file_system.is_file(path) {
+            files.push(Ok(path.to_path_buf()));
+            continue;
+        }
+        if !file_system.is_dir(path) {
+            let err =
+                std::io::Error::new(std::io::ErrorKind::NotFound, "No such file or directory");
+            files.push(Err(ignore::Error::WithPath {
+                path: path.to_path_buf(),
+                err: Box::new(ignore::Error::Io(err)),
+            }));
+            continue;
+        }
+
+        let mut stack = vec![path.to_path_buf()];
+        while let Some(dir) = stack.pop() {
+            let entries = match file_system.read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(err) => {
+                    files.push(Err(ignore::Error::WithPath {
+                        path: dir.clone(),
+                        err: Box::new(ignore::Error::Io(err)),
+                    }));
+                    continue;
+                }
+            };
+            for entry in entries {
+                let is_directory = file_system.is_dir(&entry);
+                let is_hidden = entry
+                    .file_name()
+                    .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+                let is_excluded = overrides
+                    .is_some_and(|overrides| overrides.matched(&entry, is_directory).is_ignore());
+                if is_hidden || is_excluded {
+                    continue;
+                }
+                if is_directory {
+                    stack.push(entry);
+                } else if has_r_extension(&entry) || has_rmd_extension(&entry) {
+                    files.push(Ok(entry));
+                }
+            }
+        }
+    }
+
     files
 }
 
+/// Whether `path` is an R Markdown or Quarto document of an R package: a
+/// vignette, a document in `inst/`, or the `README` at the root of the package.
+fn is_package_document(path: &Path, file_system: &dyn FileSystem) -> bool {
+    if !has_rmd_extension(path) {
+        return false;
+    }
+    let Some(package_root) = find_package_root_in(path, file_system) else {
+        return false;
+    };
+    let Some(relative) = package_relative_path(path, &package_root, file_system) else {
+        return false;
+    };
+    let mut components = relative.components().map(|c| c.as_os_str());
+    match (components.next(), components.next()) {
+        (Some(dir), Some(_)) => dir == "vignettes" || dir == "inst",
+        (Some(_), None) => path.file_stem().is_some_and(|stem| stem == "README"),
+        _ => false,
+    }
+}
+
+/// Whether `path` is ignored by the `.Rbuildignore` of its package.
+///
+/// As in `R CMD build`, each line of `.Rbuildignore` is a case-insensitive
+/// regular expression matched against the paths relative to the root of the
+/// package, and a file is also ignored if one of its parent directories is.
+/// The patterns are read once per package and stored in `patterns_by_package`.
+fn matches_rbuildignore(
+    path: &Path,
+    file_system: &dyn FileSystem,
+    patterns_by_package: &mut HashMap<PathBuf, Vec<Regex>>,
+) -> bool {
+    let Some(package_root) = find_package_root_in(path, file_system) else {
+        return false;
+    };
+    let Some(relative) = package_relative_path(path, &package_root, file_system) else {
+        return false;
+    };
+    let patterns = patterns_by_package
+        .entry(package_root.clone())
+        .or_insert_with(|| read_rbuildignore(&package_root, file_system));
+    if patterns.is_empty() {
+        return false;
+    }
+
+    let mut candidate = String::new();
+    relative.components().any(|component| {
+        if !candidate.is_empty() {
+            candidate.push('/');
+        }
+        candidate.push_str(&component.as_os_str().to_string_lossy());
+        patterns.iter().any(|pattern| pattern.is_match(&candidate))
+    })
+}
+
+/// Read the patterns of the `.Rbuildignore` of a package, skipping the empty
+/// lines and the patterns that are not valid regular expressions.
+fn read_rbuildignore(package_root: &Path, file_system: &dyn FileSystem) -> Vec<Regex> {
+    let Ok(contents) = file_system.read_to_string(&package_root.join(".Rbuildignore")) else {
+        return Vec::new();
+    };
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .filter_map(|line| {
+            regex::RegexBuilder::new(line)
+                .case_insensitive(true)
+                .build()
+                .inspect_err(|e| {
+                    tracing::warn!("Failed to parse pattern '{line}' of .Rbuildignore: {e}")
+                })
+                .ok()
+        })
+        .collect()
+}
+
 /// Shared state across the threads of the walker
 struct FilesState {
     files: std::sync::Mutex<DiscoveredFiles>,
@@ -442,18 +744,23 @@ impl FilesState {
 /// [ignore::WalkParallel] utilizes to create one [FilesVisitor] per thread.
 struct FilesVisitorBuilder<'state> {
     state: &'state FilesState,
+    follow_symlinks: bool,
 }
 
 impl<'state> FilesVisitorBuilder<'state> {
-    fn new(state: &'state FilesState) -> Self {
-        Self { state }
+    fn new(state: &'state FilesState, follow_symlinks: bool) -> Self {
+        Self { state, follow_symlinks }
     }
 }
 
 impl<'state> ignore::ParallelVisitorBuilder<'state> for FilesVisitorBuilder<'state> {
     /// Constructs the per-thread [FilesVisitor], called for us by `ignore`
     fn build(&mut self) -> Box<dyn ignore::ParallelVisitor + 'state> {
-        Box::new(FilesVisitor { files: vec![], state: self.state })
+        Box::new(FilesVisitor {
+            files: vec![],
+            state: self.state,
+            follow_symlinks: self.follow_symlinks,
+        })
     }
 }
 
@@ -465,6 +772,7 @@ impl<'state> ignore::ParallelVisitorBuilder<'state> for FilesVisitorBuilder<'sta
 struct FilesVisitor<'state> {
     files: DiscoveredFiles,
     state: &'state FilesState,
+    follow_symlinks: bool,
 }
 
 impl ignore::ParallelVisitor for FilesVisitor<'_> {
@@ -489,6 +797,12 @@ impl ignore::ParallelVisitor for FilesVisitor<'_> {
         // Determine if `ignore` gave us a valid `result` or not
         let entry = match result {
             Ok(entry) => entry,
+            Err(error) if is_loop_error(&error) => {
+                // A symlink pointing to one of its ancestors, e.g. a `renv`
+                // cache linked in the project. Its content is already visited.
+                tracing::trace!("Skipped symlink cycle: {error}");
+                return ignore::WalkState::Continue;
+            }
             Err(error) => {
                 // Store error but continue walking
                 self.files.push(Err(error));
@@ -500,6 +814,14 @@ impl ignore::ParallelVisitor for FilesVisitor<'_> {
 
         // An entry is explicit if it was provided directly, not discovered by looking into a directory
         let is_explicit = entry.depth() == 0;
+
+        if !is_explicit && !self.follow_symlinks && entry.path_is_symlink() {
+            tracing::trace!(
+                "Excluded symlink {path}, use `--follow-symlinks` to include it",
+                path = path.display()
+            );
+            return ignore::WalkState::Skip;
+        }
         let is_directory = entry.file_type().is_none_or(|ft| ft.is_dir());
 
         if is_explicit && !is_directory {
@@ -531,6 +853,18 @@ impl ignore::ParallelVisitor for FilesVisitor<'_> {
     }
 }
 
+/// Whether `error` comes from a symlink that creates a cycle.
+fn is_loop_error(error: &ignore::Error) -> bool {
+    match error {
+        ignore::Error::Loop { .. } => true,
+        ignore::Error::WithPath { err, .. }
+        | ignore::Error::WithDepth { err, .. }
+        | ignore::Error::WithLineNumber { err, .. } => is_loop_error(err),
+        ignore::Error::Partial(errors) => errors.iter().all(is_loop_error),
+        _ => false,
+    }
+}
+
 impl Drop for FilesVisitor<'_> {
     fn drop(&mut self) {
         // Lock the global shared set of `files`
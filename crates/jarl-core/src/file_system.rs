@@ -0,0 +1,415 @@
+//! Access to the files that are linted.
+//!
+//! Discovery, package scanning, linting, and fixes go through a [FileSystem]
+//! so that jarl can lint file trees that don't exist on disk, e.g. the unsaved
+//! buffers of an editor or the files of a test. [OsFileSystem] is used by
+//! default, [MemoryFileSystem] keeps the files in memory.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::time::SystemTime;
+
+use crate::fs::normalize_path;
+
+pub trait FileSystem: Debug + Send + Sync {
+    fn read_to_string(&self, path: &Path) -> io::Result<String>;
+
+    /// Replace the content of an existing file.
+    fn write(&self, path: &Path, contents: &str) -> io::Result<()>;
+
+    fn is_file(&self, path: &Path) -> bool;
+
+    fn is_dir(&self, path: &Path) -> bool;
+
+    fn exists(&self, path: &Path) -> bool {
+        self.is_file(path) || self.is_dir(path)
+    }
+
+    /// Paths of the files and directories directly in `path`.
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf>;
+
+    fn modified(&self, path: &Path) -> io::Result<SystemTime>;
+
+    /// Whether this is the file system of the OS. Discovery then uses
+    /// `ignore` to walk directories, which also respects `.gitignore`.
+    fn is_os(&self) -> bool {
+        false
+    }
+}
+
+/// The file system of the OS.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OsFileSystem;
+
+impl FileSystem for OsFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> io::Result<()> {
+        crate::fs::write_atomic(path, contents)
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        path.is_file()
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        path.is_dir()
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        std::fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect()
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        std::fs::canonicalize(path)
+    }
+
+    fn modified(&self, path: &Path) -> io::Result<SystemTime> {
+        std::fs::metadata(path)?.modified()
+    }
+
+    fn is_os(&self) -> bool {
+        true
+    }
+}
+
+/// A file system whose files only exist in memory.
+///
+/// Paths are made absolute based on the current working directory, so
+/// `R/foo.R` and `./R/foo.R` refer to the same file. Directories don't need
+/// to be created: a directory exists if it contains a file.
+#[derive(Debug, Default)]
+pub struct MemoryFileSystem {
+    files: RwLock<BTreeMap<PathBuf, MemoryFile>>,
+}
+
+#[derive(Debug)]
+struct MemoryFile {
+    contents: String,
+    modified: SystemTime,
+}
+
+impl MemoryFileSystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file, e.g. `MemoryFileSystem::new().with_file("R/foo.R", "x <- 1")`.
+    pub fn with_file<P: AsRef<Path>>(self, path: P, contents: &str) -> Self {
+        self.insert(path, contents);
+        self
+    }
+
+    /// Add a file or replace its content.
+    pub fn insert<P: AsRef<Path>>(&self, path: P, contents: &str) {
+        let file = MemoryFile {
+            contents: contents.to_string(),
+            modified: SystemTime::now(),
+        };
+        self.files
+            .write()
+            .unwrap()
+            .insert(normalize_path(path), file);
+    }
+
+    pub fn remove<P: AsRef<Path>>(&self, path: P) -> Option<String> {
+        self.files
+            .write()
+            .unwrap()
+            .remove(&normalize_path(path))
+            .map(|file| file.contents)
+    }
+
+    /// Paths of all files, sorted.
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.files.read().unwrap().keys().cloned().collect()
+    }
+}
+
+fn not_found(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("{} doesn't exist", path.display()),
+    )
+}
+
+impl FileSystem for MemoryFileSystem {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        self.files
+            .read()
+            .unwrap()
+            .get(&normalize_path(path))
+            .map(|file| file.contents.clone())
+            .ok_or_else(|| not_found(path))
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> io::Result<()> {
+        let mut files = self.files.write().unwrap();
+        let file = files
+            .get_mut(&normalize_path(path))
+            .ok_or_else(|| not_found(path))?;
+        file.contents = contents.to_string();
+        file.modified = SystemTime::now();
+        Ok(())
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.files
+            .read()
+            .unwrap()
+            .contains_key(&normalize_path(path))
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        let path = normalize_path(path);
+        self.files
+            .read()
+            .unwrap()
+            .keys()
+            .any(|file| file != &path && file.starts_with(&path))
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let dir = normalize_path(path);
+        let mut entries: Vec<PathBuf> = self
+            .files
+            .read()
+            .unwrap()
+            .keys()
+            .filter(|file| *file != &dir)
+            .filter_map(|file| {
+                let child = file.strip_prefix(&dir).ok()?.components().next()?;
+                Some(dir.join(child))
+            })
+            .collect();
+        if entries.is_empty() {
+            return Err(not_found(path));
+        }
+        entries.dedup();
+        Ok(entries)
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        if self.exists(path) {
+            Ok(normalize_path(path))
+        } else {
+            Err(not_found(path))
+        }
+    }
+
+    fn modified(&self, path: &Path) -> io::Result<SystemTime> {
+        self.files
+            .read()
+            .unwrap()
+            .get(&normalize_path(path))
+            .map(|file| file.modified)
+            .ok_or_else(|| not_found(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check::check;
+    use crate::config::{ArgsConfig, build_config, build_config_in};
+    use crate::discovery::{discover_r_file_paths_in, discover_settings_in};
+    use crate::settings::Settings;
+    use air_workspace::resolve::PathResolver;
+    use std::sync::Arc;
+
+    fn args_config(select: &str, fix: bool) -> ArgsConfig {
+        ArgsConfig {
+            files: vec![],
+            fix,
+            unsafe_fixes: false,
+            unsafe_fixes_rules: String::new(),
+            fix_only: false,
+            select: select.to_string(),
+            extend_select: String::new(),
+            ignore: String::new(),
+            min_r_version: None,
+            allow_dirty: false,
+            allow_no_vcs: true,
+            respect_staged: false,
+            assignment: None,
+            preview: false,
+            profile: None,
+        }
+    }
+
+    #[test]
+    fn test_memory_file_system() {
+        let fs = MemoryFileSystem::new()
+            .with_file("pkg/DESCRIPTION", "Package: pkg")
+            .with_file("pkg/R/a.R", "a <- 1")
+            .with_file("pkg/R/b.R", "b <- 2");
+
+        assert_eq!(fs.read_to_string(Path::new("pkg/R/a.R")).unwrap(), "a <- 1");
+        assert_eq!(
+            fs.read_to_string(Path::new("./pkg/R/b.R")).unwrap(),
+            "b <- 2"
+        );
+        assert!(fs.read_to_string(Path::new("pkg/R/c.R")).is_err());
+
+        assert!(fs.is_file(Path::new("pkg/R/a.R")));
+        assert!(!fs.is_dir(Path::new("pkg/R/a.R")));
+        assert!(fs.is_dir(Path::new("pkg/R")));
+        assert!(fs.is_dir(Path::new("pkg")));
+        assert!(!fs.exists(Path::new("pkg/tests")));
+
+        assert_eq!(
+            fs.read_dir(Path::new("pkg")).unwrap(),
+            vec![normalize_path("pkg/DESCRIPTION"), normalize_path("pkg/R")]
+        );
+        assert!(fs.read_dir(Path::new("other")).is_err());
+    }
+
+    #[test]
+    fn test_memory_file_system_write() {
+        let fs = MemoryFileSystem::new().with_file("a.R", "x = 1");
+
+        fs.write(Path::new("a.R"), "x <- 1").unwrap();
+        assert_eq!(fs.read_to_string(Path::new("a.R")).unwrap(), "x <- 1");
+
+        // Like `write_atomic()`, only existing files can be written.
+        assert!(fs.write(Path::new("b.R"), "y <- 1").is_err());
+        assert!(!fs.is_file(Path::new("b.R")));
+    }
+
+    #[test]
+    fn test_discover_in_memory() {
+        let fs = MemoryFileSystem::new()
+            .with_file("memproj/R/a.R", "a <- 1")
+            .with_file("memproj/analysis/report.qmd", "")
+            .with_file("memproj/renv/activate.R", "")
+            .with_file("memproj/.hidden/b.R", "")
+            .with_file("memproj/notes.txt", "");
+        let resolver = PathResolver::new(Settings::default());
+
+        let mut files: Vec<PathBuf> =
+            discover_r_file_paths_in(&fs, &["memproj"], &[], &resolver, true, false, false)
+                .into_iter()
+                .map(|file| file.unwrap())
+                .collect();
+        files.sort();
+
+        assert_eq!(
+            files,
+            vec![
+                normalize_path("memproj/R/a.R"),
+                normalize_path("memproj/analysis/report.qmd")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_settings_and_description_in_memory() {
+        let fs = MemoryFileSystem::new()
+            .with_file("memconf/jarl.toml", "[lint]\nselect = [\"any_is_na\"]\n")
+            .with_file(
+                "memconf/sub/jarl.toml",
+                "[lint]\nselect = [\"any_duplicated\"]\n",
+            )
+            .with_file("memconf/sub/a.R", "any(is.na(x))\n")
+            .with_file(
+                "mempkg/DESCRIPTION",
+                "Package: mempkg\nDepends: R (>= 4.1.0)\n",
+            )
+            .with_file("mempkg/R/a.R", "x <- 1\n");
+
+        let discovered = discover_settings_in(&fs, &["memconf"]).unwrap();
+        let found: Vec<(PathBuf, Option<Vec<String>>)> = discovered
+            .into_iter()
+            .map(|ds| (ds.directory, ds.settings.linter.select))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    normalize_path("memconf"),
+                    Some(vec!["any_is_na".to_string()])
+                ),
+                (
+                    normalize_path("memconf/sub"),
+                    Some(vec!["any_duplicated".to_string()])
+                ),
+            ]
+        );
+
+        let config = build_config_in(
+            &args_config("ALL", false),
+            None,
+            vec![PathBuf::from("mempkg/R/a.R")],
+            Arc::new(fs),
+        )
+        .unwrap();
+        assert_eq!(config.minimum_r_version, Some((4, 1, 0)));
+    }
+
+    #[test]
+    fn test_check_package_in_memory() {
+        let fs = MemoryFileSystem::new()
+            .with_file("mempkg/DESCRIPTION", "Package: mempkg")
+            .with_file("mempkg/NAMESPACE", "export(public_fn)\n")
+            .with_file("mempkg/R/a.R", "public_fn <- function() 1\n")
+            .with_file("mempkg/R/b.R", "unused_helper <- function() 2\n");
+        let paths = vec![PathBuf::from("mempkg/R/a.R"), PathBuf::from("mempkg/R/b.R")];
+
+        let mut config = build_config(&args_config("unused_function", false), None, paths).unwrap();
+        config.file_system = Arc::new(fs);
+
+        let mut results = check(config);
+        results.sort_by(|a, b| a.0.cmp(&b.0));
+        let names: Vec<(String, Vec<String>)> = results
+            .into_iter()
+            .map(|(path, result)| {
+                let names = result
+                    .unwrap()
+                    .into_iter()
+                    .map(|d| d.message.name)
+                    .collect();
+                (path, names)
+            })
+            .collect();
+
+        assert_eq!(
+            names,
+            vec![
+                ("mempkg/R/a.R".to_string(), vec![]),
+                (
+                    "mempkg/R/b.R".to_string(),
+                    vec!["unused_function".to_string()]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fix_in_memory() {
+        let fs = Arc::new(MemoryFileSystem::new().with_file("memfix/a.R", "any(is.na(x))\n"));
+        let paths = vec![PathBuf::from("memfix/a.R")];
+
+        let mut config = build_config(&args_config("any_is_na", true), None, paths).unwrap();
+        config.file_system = fs.clone();
+        check(config);
+
+        assert_eq!(
+            fs.read_to_string(Path::new("memfix/a.R")).unwrap(),
+            "anyNA(x)\n"
+        );
+        assert!(!Path::new("memfix/a.R").exists());
+    }
+}
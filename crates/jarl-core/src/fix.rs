@@ -1,42 +1,172 @@
 use crate::diagnostic::*;
 
+/// Maximum number of times a file is linted and fixed again in a single run.
+/// Each iteration applies all the fixes that don't conflict, so this is only
+/// reached if some fixes keep producing new violations.
+pub const MAX_FIX_ITERATIONS: usize = 100;
+
 /// Takes all diagnostics found in a given file and the content of this file,
 /// and applies automatic fixes.
 ///
-/// Overlapping fixes are skipped rather than applied, since adjusting their
-/// ranges in a single pass is error-prone. The caller is expected to re-lint
-/// and re-apply until the content stabilizes (no more fixable diagnostics or
-/// no progress made).
-pub fn apply_fixes(fixes: &[Diagnostic], contents: &str) -> String {
-    let fixes = fixes
+/// Fixes are applied in the order of their position in the file. When two
+/// fixes overlap, for instance because two rules report the same code, only
+/// the first one is applied: the ranges of the second one were computed on
+/// the original content and can't be trusted anymore. The caller is expected
+/// to re-lint and re-apply until the content stabilizes (no more fixable
+/// diagnostics or no progress made), see [`MAX_FIX_ITERATIONS`].
+///
+/// This also returns the names of the rules whose fix was applied, once per
+/// fix.
+pub fn apply_fixes(diagnostics: &[Diagnostic], contents: &str) -> (String, Vec<String>) {
+    let mut fixes: Vec<&Diagnostic> = diagnostics
         .iter()
-        .map(|diagnostic| &diagnostic.fix)
-        .collect::<Vec<_>>();
+        .filter(|d| d.has_safe_fix() || d.has_unsafe_fix())
+        .collect();
+    fixes.sort_by_key(|d| (d.fix.start, d.fix.end));
 
-    let old_content = contents;
-    let mut new_content = old_content.to_string();
-    // Track the end of the last applied fix in original positions so that
-    // overlap detection works even when earlier fixes change the content
-    // length.
-    let mut last_original_end: usize = 0;
+    let mut applied = Vec::new();
+    let mut new_content = String::with_capacity(contents.len());
+    // End of the last applied fix, in the original content. Everything
+    // before it has already been copied to `new_content`.
+    let mut last_end: usize = 0;
+    let mut last_start: Option<usize> = None;
 
-    let old_length = old_content.chars().count() as i32;
-    let mut new_length = old_length;
-
-    for fix in fixes {
+    for diagnostic in fixes {
+        let fix = &diagnostic.fix;
         // Skip overlapping fixes; they'll be handled in the next iteration.
-        if fix.start < last_original_end {
+        // Two fixes starting at the same position also conflict, e.g. two
+        // insertions whose order is unknown.
+        if fix.start < last_end || last_start == Some(fix.start) {
+            continue;
+        }
+        if fix.end > contents.len()
+            || !contents.is_char_boundary(fix.start)
+            || !contents.is_char_boundary(fix.end)
+        {
             continue;
         }
 
-        let diff_length = new_length - old_length;
-        let start = (fix.start as i32 + diff_length) as usize;
-        let end = (fix.end as i32 + diff_length) as usize;
+        new_content.push_str(&contents[last_end..fix.start]);
+        new_content.push_str(&fix.content);
+        last_end = fix.end;
+        last_start = Some(fix.start);
+
+        applied.push(diagnostic.message.name.clone());
+    }
+
+    new_content.push_str(&contents[last_end..]);
 
-        new_content.replace_range(start..end, &fix.content);
-        new_length = new_content.chars().count() as i32;
-        last_original_end = fix.end;
+    (new_content, applied)
+}
+
+/// Run the `post-command` of `[fix]` on a file that was modified by fixes.
+///
+/// `{file}` is replaced by `path` in each word of the command, or `path` is
+/// added at the end if the command doesn't contain `{file}`. The command is
+/// split on whitespace and isn't run in a shell.
+pub fn run_post_fix_command(command: &str, path: &str) -> anyhow::Result<()> {
+    let mut words: Vec<String> = command.split_whitespace().map(String::from).collect();
+    if words.iter().any(|word| word.contains("{file}")) {
+        for word in words.iter_mut() {
+            *word = word.replace("{file}", path);
+        }
+    } else {
+        words.push(path.to_string());
     }
+    let Some((program, args)) = words.split_first() else {
+        return Err(anyhow::anyhow!("`post-command` in `[fix]` can't be empty."));
+    };
 
-    new_content
+    let output = std::process::Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|err| {
+            anyhow::anyhow!(
+                "Failed to run `{command}` on {path} after the fixes were written: {err}"
+            )
+        })?;
+
+    if !output.status.success() {
+        let mut message = format!(
+            "`{command}` failed on {path} after the fixes were written ({}).",
+            output.status
+        );
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if !stderr.trim().is_empty() {
+            message.push('\n');
+            message.push_str(stderr.trim_end());
+        }
+        return Err(anyhow::anyhow!(message));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_rowan::{TextRange, TextSize};
+
+    fn diagnostic(rule: &str, start: usize, end: usize, content: &str) -> Diagnostic {
+        Diagnostic::new(
+            ViolationData::new(rule.to_string(), String::new(), None),
+            TextRange::new(TextSize::from(start as u32), TextSize::from(end as u32)),
+            Fix {
+                content: content.to_string(),
+                start,
+                end,
+                to_skip: false,
+            },
+        )
+    }
+
+    #[test]
+    fn test_apply_fixes_in_order_of_position() {
+        let contents = "any(is.na(x))\nany(is.na(y))\n";
+        // Diagnostics are not necessarily sorted by the position of their fix.
+        let diagnostics = vec![
+            diagnostic("any_is_na", 14, 27, "anyNA(y)"),
+            diagnostic("any_is_na", 0, 13, "anyNA(x)"),
+        ];
+
+        let (fixed, applied) = apply_fixes(&diagnostics, contents);
+        assert_eq!(fixed, "anyNA(x)\nanyNA(y)\n");
+        assert_eq!(applied, vec!["any_is_na", "any_is_na"]);
+    }
+
+    #[test]
+    fn test_apply_fixes_skips_overlapping_fixes() {
+        let contents = "any(is.na(x)) == TRUE\nany(is.na(y))\n";
+        let diagnostics = vec![
+            diagnostic("redundant_equals", 0, 21, "any(is.na(x))"),
+            diagnostic("any_is_na", 0, 13, "anyNA(x)"),
+            diagnostic("any_is_na", 22, 35, "anyNA(y)"),
+        ];
+
+        let (fixed, applied) = apply_fixes(&diagnostics, contents);
+        assert_eq!(fixed, "anyNA(x) == TRUE\nanyNA(y)\n");
+        assert_eq!(applied, vec!["any_is_na", "any_is_na"]);
+    }
+
+    #[test]
+    fn test_apply_fixes_with_multibyte_characters() {
+        let contents = "x <- \"é\"; any(is.na(x)); any(is.na(y))";
+        let diagnostics = vec![
+            diagnostic("any_is_na", 11, 24, "anyNA(x)"),
+            diagnostic("any_is_na", 26, 39, "anyNA(y)"),
+        ];
+
+        let (fixed, _) = apply_fixes(&diagnostics, contents);
+        assert_eq!(fixed, "x <- \"é\"; anyNA(x); anyNA(y)");
+    }
+
+    #[test]
+    fn test_apply_fixes_ignores_diagnostics_without_fix() {
+        let contents = "any(is.na(x))\n";
+        let mut no_fix = diagnostic("any_is_na", 0, 13, "anyNA(x)");
+        no_fix.fix = Fix::empty();
+
+        let (fixed, applied) = apply_fixes(&[no_fix], contents);
+        assert_eq!(fixed, contents);
+        assert!(applied.is_empty());
+    }
 }
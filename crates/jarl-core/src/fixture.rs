@@ -0,0 +1,164 @@
+//! Test fixture functions
+//!
+//! Functions like `withr::local_options()` or `testthat::local_mocked_bindings()`
+//! change some state until the end of the current function or test. Their
+//! arguments often contain code that would look suspicious elsewhere, so some
+//! rules consult this list to avoid false positives in tests.
+//!
+//! The list is set with `fixture-functions` in `[lint]`, and is shared by all
+//! rules instead of being repeated in the options of each rule.
+
+use air_r_syntax::RCall;
+
+use crate::utils::{get_function_name, get_function_namespace_prefix};
+
+/// Default patterns of fixture functions.
+pub const DEFAULT_FIXTURE_FUNCTIONS: &[&str] =
+    &["rlang::local_*", "testthat::local_*", "withr::local_*"];
+
+/// A pattern like `withr::local_*`, where `*` matches any sequence of
+/// characters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct FixturePattern {
+    namespace: Option<String>,
+    name: String,
+}
+
+impl FixturePattern {
+    fn parse(pattern: &str) -> anyhow::Result<Self> {
+        let (namespace, name) = match pattern.split_once("::") {
+            Some((namespace, name)) => (Some(namespace.to_string()), name),
+            None => (None, pattern),
+        };
+        if name.is_empty()
+            || name.contains("::")
+            || namespace
+                .as_ref()
+                .is_some_and(|namespace| namespace.is_empty() || namespace.contains('*'))
+        {
+            return Err(anyhow::anyhow!(
+                "Invalid pattern in `fixture-functions` in `[lint]`: \"{pattern}\". \
+                 Expected a function name like \"local_options\" or \"withr::local_*\"."
+            ));
+        }
+        Ok(Self { namespace, name: name.to_string() })
+    }
+
+    /// A pattern with a namespace also matches calls without namespace, since
+    /// packages like `withr` are commonly attached in tests.
+    fn matches(&self, namespace: Option<&str>, name: &str) -> bool {
+        let same_namespace = match (&self.namespace, namespace) {
+            (Some(expected), Some(actual)) => expected == actual,
+            _ => true,
+        };
+        same_namespace && wildcard_match(&self.name, name)
+    }
+}
+
+/// Whether `text` matches `pattern`, where `*` matches any sequence of
+/// characters.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    // `split()` always yields at least one element.
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No `*` in the pattern.
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+/// Resolved list of fixture functions, ready for use during linting.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixtureFunctions {
+    patterns: Vec<FixturePattern>,
+}
+
+impl FixtureFunctions {
+    pub fn resolve(patterns: Option<&Vec<String>>) -> anyhow::Result<Self> {
+        let patterns = match patterns {
+            Some(patterns) => patterns
+                .iter()
+                .map(|pattern| FixturePattern::parse(pattern))
+                .collect::<anyhow::Result<_>>()?,
+            None => DEFAULT_FIXTURE_FUNCTIONS
+                .iter()
+                .map(|pattern| FixturePattern::parse(pattern))
+                .collect::<anyhow::Result<_>>()?,
+        };
+        Ok(Self { patterns })
+    }
+
+    /// Whether `call` is a call to a fixture function, e.g.
+    /// `withr::local_options(...)` or `local_mocked_bindings(...)`.
+    pub fn is_fixture_call(&self, call: &RCall) -> bool {
+        let Ok(function) = call.function() else {
+            return false;
+        };
+        let namespace = get_function_namespace_prefix(function.clone());
+        let namespace = namespace
+            .as_deref()
+            .map(|namespace| namespace.trim_end_matches("::"));
+        let name = get_function_name(function);
+        self.patterns
+            .iter()
+            .any(|pattern| pattern.matches(namespace, &name))
+    }
+}
+
+impl Default for FixtureFunctions {
+    fn default() -> Self {
+        Self::resolve(None).expect("default fixture functions should always resolve")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("local_*", "local_options"));
+        assert!(wildcard_match("local_*", "local_"));
+        assert!(wildcard_match("*_fixture", "create_fixture"));
+        assert!(wildcard_match("local_*_bindings", "local_mocked_bindings"));
+        assert!(wildcard_match("defer", "defer"));
+        assert!(!wildcard_match("defer", "deferred"));
+        assert!(!wildcard_match("local_*", "with_options"));
+        assert!(!wildcard_match("a*a", "a"));
+    }
+
+    #[test]
+    fn test_pattern_matches() {
+        let pattern = FixturePattern::parse("withr::local_*").unwrap();
+        assert!(pattern.matches(Some("withr"), "local_options"));
+        assert!(pattern.matches(None, "local_options"));
+        assert!(!pattern.matches(Some("rlang"), "local_options"));
+        assert!(!pattern.matches(Some("withr"), "with_options"));
+
+        let pattern = FixturePattern::parse("local_db").unwrap();
+        assert!(pattern.matches(None, "local_db"));
+        assert!(pattern.matches(Some("mypkg"), "local_db"));
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        for pattern in ["", "withr::", "::local_*", "*::local_*", "a::b::c"] {
+            assert!(
+                FixturePattern::parse(pattern).is_err(),
+                "pattern {pattern:?} should be invalid"
+            );
+        }
+    }
+}
@@ -6,6 +6,7 @@
 
 use path_absolutize::Absolutize;
 use std::ffi::OsStr;
+use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
 
@@ -33,6 +34,17 @@ pub fn is_r_extension(extension: &str) -> bool {
     matches!(extension, "r" | "R")
 }
 
+/// Whether the file contains tests, i.e. it is in a `tests/` or `tinytest/`
+/// folder.
+pub fn is_test_file(path: &Path) -> bool {
+    path.parent()
+        .into_iter()
+        .flat_map(|parent| parent.components())
+        .any(|component| matches!(component.as_os_str().to_str(), Some("tests" | "tinytest")))
+}
+
+/// Whether the file is a document containing R code chunks: R Markdown,
+/// Quarto, or Sweave.
 pub fn has_rmd_extension(path: &Path) -> bool {
     path.extension()
         .and_then(OsStr::to_str)
@@ -40,7 +52,17 @@ pub fn has_rmd_extension(path: &Path) -> bool {
 }
 
 pub fn is_rmd_extension(ext: &str) -> bool {
-    matches!(ext, "rmd" | "Rmd" | "qmd" | "Qmd")
+    matches!(ext, "rmd" | "Rmd" | "qmd" | "Qmd") || is_rnw_extension(ext)
+}
+
+pub fn has_rnw_extension(path: &Path) -> bool {
+    path.extension()
+        .and_then(OsStr::to_str)
+        .is_some_and(is_rnw_extension)
+}
+
+pub fn is_rnw_extension(ext: &str) -> bool {
+    matches!(ext, "rnw" | "Rnw")
 }
 
 /// Convert any path to an absolute path (based on the current working
@@ -68,3 +90,141 @@ pub fn relativize_path<P: AsRef<Path>>(path: P) -> String {
     }
     format!("{}", path.display())
 }
+
+/// Key identifying the file at the `canonical` path when comparing paths. The
+/// file systems of macOS and Windows are case-insensitive by default, so paths
+/// that only differ by their casing point to the same file there.
+pub fn path_key(canonical: &Path) -> PathBuf {
+    fold_path_case(
+        canonical,
+        cfg!(any(target_os = "macos", target_os = "windows")),
+    )
+}
+
+fn fold_path_case(path: &Path, case_insensitive: bool) -> PathBuf {
+    if case_insensitive {
+        PathBuf::from(path.to_string_lossy().to_lowercase())
+    } else {
+        path.to_path_buf()
+    }
+}
+
+/// Replace the content of an existing file.
+///
+/// The new content is written to a temporary file in the same directory,
+/// which is then renamed to `path`. The rename is atomic, so an interrupted
+/// run leaves either the old or the new file but never a half-written one.
+/// The permissions of the original file are kept.
+pub fn write_atomic<P: AsRef<Path>>(path: P, contents: &str) -> std::io::Result<()> {
+    let path = path.as_ref();
+    let permissions = std::fs::metadata(path)?.permissions();
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+
+    let mut temp = tempfile::Builder::new()
+        .prefix(".jarl-")
+        .suffix(".tmp")
+        .tempfile_in(dir)?;
+    temp.write_all(contents.as_bytes())?;
+    temp.as_file().sync_all()?;
+    temp.as_file().set_permissions(permissions)?;
+
+    // If this fails, the temporary file is removed when dropped.
+    temp.persist(path).map_err(|e| e.error)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_is_test_file() {
+        assert!(is_test_file(Path::new("tests/testthat/test-foo.R")));
+        assert!(is_test_file(Path::new("pkg/inst/tinytest/foo.R")));
+        assert!(!is_test_file(Path::new("R/test.R")));
+        assert!(!is_test_file(Path::new("analysis/tests.R")));
+        assert!(!is_test_file(Path::new("tests.R")));
+    }
+
+    #[test]
+    fn test_fold_path_case() {
+        assert_eq!(
+            fold_path_case(Path::new("pkg/R/Foo.R"), true),
+            fold_path_case(Path::new("pkg/r/foo.R"), true)
+        );
+        assert_ne!(
+            fold_path_case(Path::new("pkg/R/Foo.R"), false),
+            fold_path_case(Path::new("pkg/r/foo.R"), false)
+        );
+    }
+
+    fn dir_entries(dir: &Path) -> Vec<String> {
+        let mut entries: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        entries.sort();
+        entries
+    }
+
+    #[test]
+    fn test_write_atomic_replaces_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("test.R");
+        fs::write(&file, "any(is.na(x))\n").unwrap();
+
+        write_atomic(&file, "anyNA(x)\n").unwrap();
+
+        assert_eq!(fs::read_to_string(&file).unwrap(), "anyNA(x)\n");
+        // No temporary file is left behind.
+        assert_eq!(dir_entries(dir.path()), vec!["test.R"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_atomic_keeps_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("script.R");
+        fs::write(&file, "x = 1\n").unwrap();
+        fs::set_permissions(&file, fs::Permissions::from_mode(0o755)).unwrap();
+
+        write_atomic(&file, "x <- 1\n").unwrap();
+
+        let mode = fs::metadata(&file).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o755);
+    }
+
+    #[test]
+    fn test_write_atomic_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("missing.R");
+
+        assert!(write_atomic(&file, "x <- 1\n").is_err());
+        assert!(dir_entries(dir.path()).is_empty());
+    }
+
+    #[test]
+    fn test_write_atomic_failed_rename_keeps_original() {
+        // Renaming a file onto a non-empty directory fails, which simulates a
+        // write that is interrupted before the file is replaced.
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("test.R");
+        fs::create_dir(&target).unwrap();
+        fs::write(target.join("inner.R"), "x <- 1\n").unwrap();
+
+        assert!(write_atomic(&target, "y <- 2\n").is_err());
+
+        assert!(target.is_dir());
+        assert_eq!(
+            fs::read_to_string(target.join("inner.R")).unwrap(),
+            "x <- 1\n"
+        );
+        assert_eq!(dir_entries(dir.path()), vec!["test.R"]);
+    }
+}
@@ -18,13 +18,18 @@ pub mod diagnostic;
 pub mod directive;
 pub mod discovery;
 pub mod error;
+pub mod file_system;
 pub mod fix;
+pub mod fixture;
 pub mod fs;
 pub mod library_calls;
 pub mod library_paths;
+pub mod lintr_config;
 pub mod lints;
 pub mod location;
 pub mod namespace;
+pub mod nolint;
+pub mod overrides;
 pub mod package;
 pub mod package_cache;
 pub mod per_file_ignores;
@@ -32,8 +37,10 @@ pub mod rmd;
 pub mod roxygen;
 pub mod rule_docs;
 pub mod rule_options;
+pub mod rule_paths;
 pub mod rule_set;
 pub mod settings;
+pub mod shiny;
 pub mod suppression;
 pub mod suppression_edit;
 pub mod toml;
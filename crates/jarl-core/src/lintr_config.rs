@@ -0,0 +1,481 @@
+//! Conversion of a `.lintr` file to a `jarl.toml`
+//!
+//! `.lintr` is a DCF file whose fields contain R code, for instance:
+//!
+//! ```text
+//! linters: linters_with_defaults(
+//!     line_length_linter(120),
+//!     any_is_na_linter(),
+//!     quotes_linter = NULL
+//!   )
+//! exclusions: list("R/RcppExports.R", "R/utils.R" = list(seq_linter = Inf))
+//! ```
+//!
+//! Only the fields `linters` and `exclusions` can be converted. Everything
+//! else, like the linters that don't have an equivalent rule in Jarl, is
+//! reported to the user.
+
+use air_r_parser::RParserOptions;
+use air_r_syntax::*;
+use anyhow::anyhow;
+use biome_rowan::AstNode;
+
+use crate::description::parse_dcf;
+use crate::rule_set::Rule;
+use crate::utils::get_function_name;
+
+/// lintr linters whose name is not the name of the Jarl rule followed by
+/// `_linter`.
+const LINTER_ALIASES: &[(&str, &[&str])] = &[
+    ("T_and_F_symbol", &["true_false_symbol"]),
+    ("duplicate_argument", &["duplicated_arguments"]),
+    ("paste", &["paste_to_paste0"]),
+    ("seq", &["seq", "seq2"]),
+];
+
+/// The closest equivalent of a `.lintr` file in `jarl.toml`.
+#[derive(Debug, Default, PartialEq)]
+pub struct LintrMigration {
+    /// Rules in `select`, if `.lintr` doesn't start from the default linters.
+    pub select: Option<Vec<String>>,
+    pub extend_select: Vec<String>,
+    pub ignore: Vec<String>,
+    pub exclude: Vec<String>,
+    pub per_file_ignores: Vec<(String, Vec<String>)>,
+    /// lintr linters that are enabled but have no equivalent in Jarl.
+    pub unmatched_linters: Vec<String>,
+    /// Other parts of `.lintr` that couldn't be converted.
+    pub notes: Vec<String>,
+}
+
+impl LintrMigration {
+    /// Convert the contents of a `.lintr` file.
+    pub fn from_lintr(contents: &str) -> anyhow::Result<Self> {
+        let fields = parse_dcf(contents);
+        let mut names: Vec<&String> = fields.keys().collect();
+        names.sort();
+
+        let mut migration = Self::default();
+        for name in names {
+            match name.as_str() {
+                "linters" => migration.convert_linters(&fields[name])?,
+                "exclusions" => migration.convert_exclusions(&fields[name])?,
+                "encoding" => {}
+                _ => migration.notes.push(format!(
+                    "The field `{name}` has no equivalent in Jarl and was not converted."
+                )),
+            }
+        }
+
+        Ok(migration)
+    }
+
+    /// Contents of the `jarl.toml` file.
+    pub fn to_toml(&self) -> String {
+        let mut config = String::from(
+            "# Converted from `.lintr` with `jarl migrate-lintr`. All the options are\n\
+             # described in https://jarl.etiennebacher.com/reference/config-file.\n\n\
+             [lint]\n",
+        );
+
+        if let Some(select) = &self.select {
+            config.push_str(&format!("select = {}\n", toml_list(select)));
+        }
+        if !self.extend_select.is_empty() {
+            config.push_str(&format!(
+                "extend-select = {}\n",
+                toml_list(&self.extend_select)
+            ));
+        }
+        if !self.ignore.is_empty() {
+            config.push_str(&format!("ignore = {}\n", toml_list(&self.ignore)));
+        }
+        if !self.exclude.is_empty() {
+            config.push_str(&format!("exclude = {}\n", toml_list(&self.exclude)));
+        }
+
+        // This table must come last, see the docs of `per-file-ignores`.
+        if !self.per_file_ignores.is_empty() {
+            config.push_str("\n[lint.per-file-ignores]\n");
+            for (path, rules) in &self.per_file_ignores {
+                config.push_str(&format!("{} = {}\n", toml_string(path), toml_list(rules)));
+            }
+        }
+
+        config
+    }
+
+    /// Convert `linters`, which is usually a call to `linters_with_defaults()`
+    /// where linters are added with `x_linter()` and removed with
+    /// `x_linter = NULL`.
+    fn convert_linters(&mut self, code: &str) -> anyhow::Result<()> {
+        let Some(expr) = parse_field("linters", code)? else {
+            return Ok(());
+        };
+        let Some(call) = expr.as_r_call() else {
+            self.notes
+                .push("The field `linters` is not a function call and was not converted.".into());
+            return Ok(());
+        };
+
+        let function = get_function_name(call.function()?);
+        match function.as_str() {
+            "linters_with_defaults" => {}
+            "all_linters" => self.select = Some(vec!["ALL".to_string()]),
+            "list" => self.select = Some(Vec::new()),
+            "linters_with_tags" => self.notes.push(
+                "Jarl doesn't have tags: the linters selected by `linters_with_tags()` were not converted."
+                    .into(),
+            ),
+            _ => {
+                self.notes.push(format!(
+                    "The field `linters` uses `{function}()`, which was not converted."
+                ));
+                return Ok(());
+            }
+        }
+
+        for arg in call.arguments()?.items().iter().filter_map(Result::ok) {
+            let name = argument_name(&arg);
+            let Some(value) = arg.value() else {
+                continue;
+            };
+
+            if let Some(name) = &name
+                && !name.ends_with("_linter")
+            {
+                // Arguments of the function itself, e.g. `defaults` or `tags`.
+                self.notes.push(format!(
+                    "The argument `{name}` of `{function}()` was not converted."
+                ));
+                continue;
+            }
+
+            if matches!(value, AnyRExpression::RNullExpression(_)) {
+                if let Some(name) = name {
+                    self.disable(&name);
+                }
+                continue;
+            }
+
+            let (linter, has_arguments) = match &value {
+                AnyRExpression::RCall(call) => (
+                    get_function_name(call.function()?),
+                    call.arguments()?.items().iter().next().is_some(),
+                ),
+                AnyRExpression::RIdentifier(_) | AnyRExpression::RNamespaceExpression(_) => {
+                    (get_function_name(value.clone()), false)
+                }
+                _ => {
+                    self.notes.push(format!(
+                        "The linter `{}` was not converted.",
+                        value.syntax().text_trimmed()
+                    ));
+                    continue;
+                }
+            };
+            let linter = name.unwrap_or(linter);
+            self.enable(&linter);
+            if has_arguments && !jarl_rules(&linter).is_empty() {
+                self.notes.push(format!(
+                    "The arguments of `{linter}()` were not converted, see the options of the corresponding rules in `jarl.toml`."
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Convert `exclusions`: whole files and folders become `exclude`, and
+    /// files excluded for some linters only become `per-file-ignores`.
+    /// Jarl can't exclude specific lines in `jarl.toml`.
+    fn convert_exclusions(&mut self, code: &str) -> anyhow::Result<()> {
+        let Some(expr) = parse_field("exclusions", code)? else {
+            return Ok(());
+        };
+        let Some(args) = list_arguments(&expr) else {
+            self.notes
+                .push("The field `exclusions` is not a `list()` and was not converted.".into());
+            return Ok(());
+        };
+
+        for arg in args {
+            let Some(value) = arg.value() else {
+                continue;
+            };
+            let Some(path) = argument_name(&arg) else {
+                match string_value(&value) {
+                    Some(path) => push_unique(&mut self.exclude, path),
+                    None => self.notes.push(format!(
+                        "The exclusion `{}` was not converted.",
+                        value.syntax().text_trimmed()
+                    )),
+                }
+                continue;
+            };
+
+            if matches!(value, AnyRExpression::RInfExpression(_)) {
+                push_unique(&mut self.exclude, path);
+                continue;
+            }
+            let Some(linters) = list_arguments(&value) else {
+                self.notes.push(lines_note(&path));
+                continue;
+            };
+
+            let mut rules = Vec::new();
+            let mut has_lines = false;
+            for linter in linters {
+                let Some(value) = linter.value() else {
+                    continue;
+                };
+                let name = match argument_name(&linter) {
+                    Some(name) if matches!(value, AnyRExpression::RInfExpression(_)) => name,
+                    Some(_) => {
+                        has_lines = true;
+                        continue;
+                    }
+                    None => match string_value(&value) {
+                        Some(name) => name,
+                        None => continue,
+                    },
+                };
+                for rule in jarl_rules(&name) {
+                    push_unique(&mut rules, rule.to_string());
+                }
+            }
+            if has_lines {
+                self.notes.push(lines_note(&path));
+            }
+            if !rules.is_empty() {
+                self.per_file_ignores.push((path, rules));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn enable(&mut self, linter: &str) {
+        let rules = jarl_rules(linter);
+        if rules.is_empty() {
+            push_unique(&mut self.unmatched_linters, linter.to_string());
+            return;
+        }
+
+        let selected = match &mut self.select {
+            Some(select) if select.iter().any(|rule| rule == "ALL") => return,
+            Some(select) => select,
+            None => &mut self.extend_select,
+        };
+        for rule in rules {
+            push_unique(selected, rule.to_string());
+        }
+    }
+
+    fn disable(&mut self, linter: &str) {
+        for rule in jarl_rules(linter) {
+            let selected = self.select.as_mut().unwrap_or(&mut self.extend_select);
+            if selected.iter().any(|selected| selected == rule) {
+                selected.retain(|selected| selected != rule);
+            } else {
+                push_unique(&mut self.ignore, rule.to_string());
+            }
+        }
+    }
+}
+
+/// Names of the Jarl rules equivalent to a lintr linter, e.g. `any_is_na`
+/// for `any_is_na_linter`.
+pub fn jarl_rules(linter: &str) -> Vec<&'static str> {
+    let name = linter.strip_suffix("_linter").unwrap_or(linter);
+    if let Some((_, rules)) = LINTER_ALIASES.iter().find(|(alias, _)| *alias == name) {
+        return rules.to_vec();
+    }
+    Rule::from_name(name)
+        .map(|rule| vec![rule.name()])
+        .unwrap_or_default()
+}
+
+fn parse_field(field: &str, code: &str) -> anyhow::Result<Option<AnyRExpression>> {
+    let parsed = air_r_parser::parse(code, RParserOptions::default());
+    if parsed.has_error() {
+        return Err(anyhow!("Failed to parse the field `{field}` of `.lintr`."));
+    }
+    Ok(parsed.tree().expressions().into_iter().next())
+}
+
+/// Arguments of `list(...)` or `c(...)`.
+fn list_arguments(expr: &AnyRExpression) -> Option<Vec<RArgument>> {
+    let call = expr.as_r_call()?;
+    let function = get_function_name(call.function().ok()?);
+    if !matches!(function.as_str(), "list" | "c") {
+        return None;
+    }
+    Some(
+        call.arguments()
+            .ok()?
+            .items()
+            .iter()
+            .filter_map(Result::ok)
+            .collect(),
+    )
+}
+
+/// Name of an argument, without quotes or backticks.
+fn argument_name(arg: &RArgument) -> Option<String> {
+    let name = arg.name_clause()?.name().ok()?.to_string();
+    Some(name.trim().trim_matches(['"', '\'', '`']).to_string())
+}
+
+fn string_value(expr: &AnyRExpression) -> Option<String> {
+    let AnyRExpression::AnyRValue(value) = expr else {
+        return None;
+    };
+    let text = value
+        .as_r_string_value()?
+        .syntax()
+        .text_trimmed()
+        .to_string();
+    Some(text[1..text.len() - 1].to_string())
+}
+
+fn lines_note(path: &str) -> String {
+    format!(
+        "The exclusion of specific lines in `{path}` was not converted, use `# jarl-ignore` comments instead."
+    )
+}
+
+fn push_unique(values: &mut Vec<String>, value: String) {
+    if !values.contains(&value) {
+        values.push(value);
+    }
+}
+
+fn toml_string(value: &str) -> String {
+    toml::Value::String(value.to_string()).to_string()
+}
+
+fn toml_list(values: &[String]) -> String {
+    let values: Vec<String> = values.iter().map(|value| toml_string(value)).collect();
+    format!("[{}]", values.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jarl_rules() {
+        assert_eq!(jarl_rules("any_is_na_linter"), vec!["any_is_na"]);
+        assert_eq!(
+            jarl_rules("T_and_F_symbol_linter"),
+            vec!["true_false_symbol"]
+        );
+        assert_eq!(jarl_rules("seq_linter"), vec!["seq", "seq2"]);
+        assert!(jarl_rules("line_length_linter").is_empty());
+    }
+
+    #[test]
+    fn test_linters_with_defaults() {
+        let lintr = r#"linters: linters_with_defaults(
+    line_length_linter(120),
+    any_is_na_linter(),
+    lintr::expect_null_linter,
+    quotes_linter = NULL,
+    object_name_linter = NULL
+  )
+encoding: "UTF-8"
+"#;
+        let migration = LintrMigration::from_lintr(lintr).unwrap();
+        assert_eq!(migration.select, None);
+        assert_eq!(migration.extend_select, vec!["any_is_na", "expect_null"]);
+        assert_eq!(migration.ignore, vec!["quotes"]);
+        assert_eq!(migration.unmatched_linters, vec!["line_length_linter"]);
+        assert!(migration.notes.is_empty());
+    }
+
+    #[test]
+    fn test_list_of_linters() {
+        let lintr =
+            "linters: list(seq_linter(), undesirable_function_linter(fun = c(browser = NULL)))";
+        let migration = LintrMigration::from_lintr(lintr).unwrap();
+        assert_eq!(
+            migration.select,
+            Some(vec![
+                "seq".to_string(),
+                "seq2".to_string(),
+                "undesirable_function".to_string()
+            ])
+        );
+        assert_eq!(migration.notes.len(), 1);
+    }
+
+    #[test]
+    fn test_all_linters() {
+        let lintr = "linters: all_linters(packages = 'lintr', sort_linter = NULL)";
+        let migration = LintrMigration::from_lintr(lintr).unwrap();
+        assert_eq!(migration.select, Some(vec!["ALL".to_string()]));
+        assert_eq!(migration.ignore, vec!["sort"]);
+        assert_eq!(
+            migration.notes,
+            vec!["The argument `packages` of `all_linters()` was not converted."]
+        );
+    }
+
+    #[test]
+    fn test_exclusions() {
+        let lintr = r#"exclusions: list(
+    "R/RcppExports.R",
+    "inst/doc" = Inf,
+    "R/utils.R" = list(seq_linter = Inf, "quotes_linter", object_name_linter = Inf),
+    "R/other.R" = 1:10
+  )
+"#;
+        let migration = LintrMigration::from_lintr(lintr).unwrap();
+        assert_eq!(migration.exclude, vec!["R/RcppExports.R", "inst/doc"]);
+        assert_eq!(
+            migration.per_file_ignores,
+            vec![(
+                "R/utils.R".to_string(),
+                vec!["seq".to_string(), "seq2".to_string(), "quotes".to_string()]
+            )]
+        );
+        assert_eq!(migration.notes, vec![lines_note("R/other.R")]);
+    }
+
+    #[test]
+    fn test_unsupported_fields() {
+        let lintr = "exclude: '# nolint'\nlinters: linters_with_defaults()";
+        let migration = LintrMigration::from_lintr(lintr).unwrap();
+        assert_eq!(
+            migration.notes,
+            vec!["The field `exclude` has no equivalent in Jarl and was not converted."]
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert!(LintrMigration::from_lintr("linters: list(").is_err());
+    }
+
+    #[test]
+    fn test_to_toml() {
+        let migration = LintrMigration {
+            extend_select: vec!["any_is_na".to_string()],
+            exclude: vec!["R/RcppExports.R".to_string()],
+            per_file_ignores: vec![("R/utils.R".to_string(), vec!["quotes".to_string()])],
+            ..Default::default()
+        };
+        insta::assert_snapshot!(migration.to_toml(), @r#"
+        # Converted from `.lintr` with `jarl migrate-lintr`. All the options are
+        # described in https://jarl.etiennebacher.com/reference/config-file.
+
+        [lint]
+        extend-select = ["any_is_na"]
+        exclude = ["R/RcppExports.R"]
+
+        [lint.per-file-ignores]
+        "R/utils.R" = ["quotes"]
+        "#);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::diagnostic::*;
+use crate::utils::{get_arg_by_name, get_arg_by_name_then_position};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for calls to `as.Date()` on a string literal that is not in the
+/// `"YYYY-MM-DD"` or `"YYYY/MM/DD"` format, without `format` or `tryFormats`.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Why is this bad?
+///
+/// Without `format`, `as.Date()` only tries the formats `"%Y-%m-%d"` and
+/// `"%Y/%m/%d"`. Other formats either fail or are silently parsed as
+/// something else: `as.Date("01/02/2024")` returns `"0001-02-20"`. Whether
+/// `"01/02/2024"` is the 1st of February or the 2nd of January also depends
+/// on the conventions of the place where the code was written, so the format
+/// should be explicit.
+///
+/// This rule is in preview, so it is only used with `--preview` or
+/// `preview = true` in `jarl.toml`.
+///
+/// ## Example
+///
+/// ```r
+/// start <- as.Date("01/02/2024")
+/// ```
+///
+/// Use instead:
+/// ```r
+/// start <- as.Date("01/02/2024", format = "%d/%m/%Y")
+/// # or
+/// start <- as.Date("2024-02-01")
+/// ```
+///
+/// ## References
+///
+/// See `?as.Date` and `?strptime`
+pub fn as_date_format(
+    ast: &RCall,
+    fn_name: &str,
+    ns_prefix: Option<&str>,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if fn_name != "as.Date" || !matches!(ns_prefix, None | Some("base::")) {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+    // `format` is the first unnamed argument after `x`.
+    let format_position = if get_arg_by_name(&args, "x").is_some() {
+        1
+    } else {
+        2
+    };
+    if get_arg_by_name_then_position(&args, "format", format_position).is_some()
+        || get_arg_by_name(&args, "tryFormats").is_some()
+    {
+        return Ok(None);
+    }
+
+    let x = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "x", 1));
+    let x = unwrap_or_return_none!(x.value());
+    let x = unwrap_or_return_none!(x.as_any_r_value());
+    let string = unwrap_or_return_none!(x.as_r_string_value());
+    let text = string.to_trimmed_string();
+    let contents = text.trim_matches(|c| c == '"' || c == '\'');
+    if is_iso_date(contents) {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "as_date_format".to_string(),
+            format!("`as.Date({text})` is ambiguous without `format`."),
+            Some("Pass the `format` of the date, or write it as \"YYYY-MM-DD\".".to_string()),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Whether `date` starts with a date in one of the default formats of
+/// `as.Date()`, e.g. "2024-02-01" or "2024/2/1 10:00".
+fn is_iso_date(date: &str) -> bool {
+    let mut parts = date.splitn(3, ['-', '/']);
+    let (Some(year), Some(month), Some(day)) = (parts.next(), parts.next(), parts.next()) else {
+        return false;
+    };
+    let day = day
+        .split(|c: char| !c.is_ascii_digit())
+        .next()
+        .unwrap_or("");
+    let is_number = |s: &str, len: std::ops::RangeInclusive<usize>| {
+        len.contains(&s.len()) && s.chars().all(|c| c.is_ascii_digit())
+    };
+    is_number(year, 4..=4) && is_number(month, 1..=2) && is_number(day, 1..=2)
+}
@@ -0,0 +1,70 @@
+pub(crate) mod as_date_format;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "as_date_format", None)
+    }
+
+    #[test]
+    fn test_no_lint_as_date_format() {
+        expect_no_lint("as.Date('2024-02-01')", "as_date_format", None);
+        expect_no_lint("as.Date('2024/2/1')", "as_date_format", None);
+        expect_no_lint("as.Date('2024-02-01 10:00:00')", "as_date_format", None);
+        expect_no_lint(
+            "as.Date('01/02/2024', format = '%d/%m/%Y')",
+            "as_date_format",
+            None,
+        );
+        expect_no_lint("as.Date('01/02/2024', '%d/%m/%Y')", "as_date_format", None);
+        expect_no_lint(
+            "as.Date(format = '%d/%m/%Y', x = '01/02/2024')",
+            "as_date_format",
+            None,
+        );
+        expect_no_lint(
+            "as.Date('01/02/2024', tryFormats = c('%d/%m/%Y', '%m/%d/%Y'))",
+            "as_date_format",
+            None,
+        );
+        // Not a string literal
+        expect_no_lint("as.Date(x)", "as_date_format", None);
+        expect_no_lint("as.Date(19000)", "as_date_format", None);
+        // Other functions
+        expect_no_lint("foo::as.Date('01/02/2024')", "as_date_format", None);
+        expect_no_lint("as.POSIXct('01/02/2024')", "as_date_format", None);
+    }
+
+    #[test]
+    fn test_lint_as_date_format() {
+        assert_snapshot!(
+            snapshot_lint("as.Date('01/02/2024')"),
+            @r#"
+        warning: as_date_format
+         --> <test>:1:1
+          |
+        1 | as.Date('01/02/2024')
+          | --------------------- `as.Date('01/02/2024')` is ambiguous without `format`.
+          |
+          = help: Pass the `format` of the date, or write it as "YYYY-MM-DD".
+        Found 1 error.
+        "#
+        );
+        assert_snapshot!(
+            snapshot_lint("base::as.Date(x = \"1 Feb 2024\", tz = 'UTC')"),
+            @r#"
+        warning: as_date_format
+         --> <test>:1:1
+          |
+        1 | base::as.Date(x = "1 Feb 2024", tz = 'UTC')
+          | ------------------------------------------- `as.Date("1 Feb 2024")` is ambiguous without `format`.
+          |
+          = help: Pass the `format` of the date, or write it as "YYYY-MM-DD".
+        Found 1 error.
+        "#
+        );
+    }
+}
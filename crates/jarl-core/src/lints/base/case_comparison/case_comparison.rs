@@ -0,0 +1,90 @@
+use crate::diagnostic::*;
+use crate::utils::{get_function_name, get_function_namespace_prefix};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for comparisons with `==`, `!=`, or `%in%` where one side is a call
+/// to `tolower()` or `toupper()`, such as `tolower(x) == "id"`.
+///
+/// This rule is disabled by default. This rule doesn't have an automatic fix.
+///
+/// ## Why is this bad?
+///
+/// `tolower()` and `toupper()` depend on the locale: in a Turkish locale,
+/// `toupper("i")` is `"İ"` and not `"I"`, so `toupper(x) == "ID"` is `FALSE`
+/// for `x = "id"`. Comparisons of identifiers (column names, options, file
+/// extensions, etc.) then behave differently depending on the machine.
+///
+/// `casefold()` makes it explicit that the comparison is meant to be
+/// case-insensitive. Note that it calls `tolower()` or `toupper()` internally,
+/// so the only way to not depend on the locale is to compare the identifiers
+/// with their expected casing.
+///
+/// ## Example
+///
+/// ```r
+/// if (tolower(ext) == "csv") {
+///   read.csv(path)
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// if (casefold(ext) == "csv") {
+///   read.csv(path)
+/// }
+/// ```
+///
+/// ## References
+///
+/// See `?casefold`
+pub fn case_comparison(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>> {
+    let RBinaryExpressionFields { left, operator, right } = ast.as_fields();
+
+    let operator = operator?;
+    let left = left?;
+    let right = right?;
+    if operator.kind() != RSyntaxKind::EQUAL2
+        && operator.kind() != RSyntaxKind::NOT_EQUAL
+        && operator.text_trimmed() != "%in%"
+    {
+        return Ok(None);
+    }
+
+    let Some(fn_name) = case_function(&left).or_else(|| case_function(&right)) else {
+        return Ok(None);
+    };
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "case_comparison".to_string(),
+            format!("Comparing strings after `{fn_name}()` depends on the locale."),
+            Some("Use `casefold()` for case-insensitive comparisons.".to_string()),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// The name of the function if `expr` is a call to `tolower()` or `toupper()`.
+fn case_function(expr: &AnyRExpression) -> Option<&'static str> {
+    let function = expr.as_r_call()?.function().ok()?;
+    if !matches!(
+        get_function_namespace_prefix(function.clone()).as_deref(),
+        None | Some("base::")
+    ) {
+        return None;
+    }
+    match get_function_name(function).as_str() {
+        "tolower" => Some("tolower"),
+        "toupper" => Some("toupper"),
+        _ => None,
+    }
+}
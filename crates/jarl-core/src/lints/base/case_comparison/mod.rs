@@ -0,0 +1,64 @@
+pub(crate) mod case_comparison;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "case_comparison", None)
+    }
+
+    #[test]
+    fn test_no_lint_case_comparison() {
+        expect_no_lint("casefold(x) == 'id'", "case_comparison", None);
+        expect_no_lint("x == 'id'", "case_comparison", None);
+        expect_no_lint("tolower(x)", "case_comparison", None);
+        expect_no_lint("y <- tolower(x)", "case_comparison", None);
+        expect_no_lint("nchar(tolower(x)) > 2", "case_comparison", None);
+        expect_no_lint("foo::tolower(x) == 'id'", "case_comparison", None);
+    }
+
+    #[test]
+    fn test_lint_case_comparison() {
+        assert_snapshot!(
+            snapshot_lint("tolower(ext) == 'csv'"),
+            @r"
+        warning: case_comparison
+         --> <test>:1:1
+          |
+        1 | tolower(ext) == 'csv'
+          | --------------------- Comparing strings after `tolower()` depends on the locale.
+          |
+          = help: Use `casefold()` for case-insensitive comparisons.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("'ID' != base::toupper(x)"),
+            @r"
+        warning: case_comparison
+         --> <test>:1:1
+          |
+        1 | 'ID' != base::toupper(x)
+          | ------------------------ Comparing strings after `toupper()` depends on the locale.
+          |
+          = help: Use `casefold()` for case-insensitive comparisons.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("toupper(names(df)) %in% c('ID', 'NAME')"),
+            @r"
+        warning: case_comparison
+         --> <test>:1:1
+          |
+        1 | toupper(names(df)) %in% c('ID', 'NAME')
+          | --------------------------------------- Comparing strings after `toupper()` depends on the locale.
+          |
+          = help: Use `casefold()` for case-insensitive comparisons.
+        Found 1 error.
+        "
+        );
+    }
+}
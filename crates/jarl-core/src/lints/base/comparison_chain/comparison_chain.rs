@@ -0,0 +1,135 @@
+use crate::diagnostic::*;
+use crate::utils::node_contains_comments;
+use crate::utils_ast::AstNodeExt;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for chained comparisons, such as `0 < x < 1` or `x == y == z`.
+///
+/// ## Why is this bad?
+///
+/// Unlike in Python or in mathematical notation, comparisons can't be chained
+/// in R: `0 < x < 1` is a syntax error ("unexpected '<'"), so the file can't be
+/// run or sourced. To check that `x` is between `0` and `1`, both comparisons
+/// have to be written explicitly, e.g. `0 < x & x < 1`.
+///
+/// This rule has an unsafe fix that splits the chain into several comparisons
+/// combined with `&&` in the condition of `if()` and `while()` statements, and
+/// with `&` otherwise. The fix is unsafe because the operands in the middle of
+/// the chain are evaluated twice.
+///
+/// ## Example
+///
+/// ```r
+/// if (0 < x < 1) {
+///   print("x is a proportion")
+/// }
+/// x[0 < x < 1]
+/// ```
+///
+/// Use instead:
+/// ```r
+/// if (0 < x && x < 1) {
+///   print("x is a proportion")
+/// }
+/// x[0 < x & x < 1]
+/// ```
+///
+/// ## References
+///
+/// See `?Syntax` and `?Comparison`
+pub fn comparison_chain(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>> {
+    let operator = ast.operator()?;
+    if !is_comparison(operator.kind()) {
+        return Ok(None);
+    }
+
+    // Only report the outermost comparison of a chain.
+    if let Some(parent) = ast.parent::<RBinaryExpression>()
+        && is_comparison(parent.operator()?.kind())
+    {
+        return Ok(None);
+    }
+
+    // Collect the operands and operators of the chain: `a < b <= c` is parsed
+    // as `(a < b) <= c`.
+    let mut operands = vec![ast.right()?];
+    let mut operators = vec![operator];
+    let mut current = ast.left()?;
+    loop {
+        match current.as_r_binary_expression() {
+            Some(inner) if is_comparison(inner.operator()?.kind()) => {
+                operands.push(inner.right()?);
+                operators.push(inner.operator()?);
+                current = inner.left()?;
+            }
+            _ => {
+                operands.push(current);
+                break;
+            }
+        }
+    }
+    if operators.len() < 2 {
+        return Ok(None);
+    }
+    operands.reverse();
+    operators.reverse();
+
+    let is_condition = ast.parent_is_if_condition() || ast.parent_is_while_condition();
+    let and = if is_condition { " && " } else { " & " };
+    let comparisons: Vec<String> = operators
+        .iter()
+        .enumerate()
+        .map(|(i, operator)| {
+            format!(
+                "{} {} {}",
+                operands[i].to_trimmed_string(),
+                operator.text_trimmed(),
+                operands[i + 1].to_trimmed_string()
+            )
+        })
+        .collect();
+    let mut content = comparisons.join(and);
+    // `!` has a lower precedence than comparisons but a higher precedence than
+    // `&` and `&&`.
+    if ast.parent::<RUnaryExpression>().is_some() {
+        content = format!("({content})");
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "comparison_chain".to_string(),
+            format!(
+                "`{}` can't be parsed by R, comparisons can't be chained.",
+                ast.to_trimmed_string()
+            ),
+            Some(format!("Use `{content}` instead.")),
+        ),
+        range,
+        Fix {
+            content,
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(ast.syntax()),
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
+
+fn is_comparison(kind: RSyntaxKind) -> bool {
+    matches!(
+        kind,
+        RSyntaxKind::LESS_THAN
+            | RSyntaxKind::LESS_THAN_OR_EQUAL_TO
+            | RSyntaxKind::GREATER_THAN
+            | RSyntaxKind::GREATER_THAN_OR_EQUAL_TO
+            | RSyntaxKind::EQUAL2
+            | RSyntaxKind::NOT_EQUAL
+    )
+}
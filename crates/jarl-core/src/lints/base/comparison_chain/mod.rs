@@ -0,0 +1,85 @@
+pub(crate) mod comparison_chain;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "comparison_chain", None)
+    }
+
+    #[test]
+    fn test_no_lint_comparison_chain() {
+        expect_no_lint("0 < x", "comparison_chain", None);
+        expect_no_lint("0 < x && x < 1", "comparison_chain", None);
+        expect_no_lint("0 < x & x < 1", "comparison_chain", None);
+        expect_no_lint("(a < b) < c", "comparison_chain", None);
+        expect_no_lint("a == (b == c)", "comparison_chain", None);
+        expect_no_lint("x + 1 < y", "comparison_chain", None);
+        expect_no_lint("f(a < b) < c", "comparison_chain", None);
+    }
+
+    #[test]
+    fn test_lint_comparison_chain() {
+        assert_snapshot!(
+            snapshot_lint("0 < x < 1"),
+            @"
+        warning: comparison_chain
+         --> <test>:1:1
+          |
+        1 | 0 < x < 1
+          | --------- `0 < x < 1` can't be parsed by R, comparisons can't be chained.
+          |
+          = help: Use `0 < x & x < 1` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("if (a == b == c) 1"),
+            @"
+        warning: comparison_chain
+         --> <test>:1:5
+          |
+        1 | if (a == b == c) 1
+          |     ----------- `a == b == c` can't be parsed by R, comparisons can't be chained.
+          |
+          = help: Use `a == b && b == c` instead.
+        Found 1 error.
+        "
+        );
+        // Only the outermost comparison is reported.
+        assert_snapshot!(
+            snapshot_lint("a < b <= c < d"),
+            @"
+        warning: comparison_chain
+         --> <test>:1:1
+          |
+        1 | a < b <= c < d
+          | -------------- `a < b <= c < d` can't be parsed by R, comparisons can't be chained.
+          |
+          = help: Use `a < b & b <= c & c < d` instead.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_fix_comparison_chain() {
+        assert_snapshot!(
+            "fix_output",
+            get_unsafe_fixed_text(
+                vec![
+                    "0 < x < 1",
+                    "x[0 <= x <= 1]",
+                    "if (a == b == c) 1",
+                    "while (x > y > z) 1",
+                    "!(0 < x < 1)",
+                    "if (!a < b < c) 1",
+                    "y <- f(x) != g(x) != h(x)",
+                ],
+                "comparison_chain",
+            )
+        );
+    }
+}
@@ -31,6 +31,7 @@ mod tests {
                 },
                 ..Default::default()
             },
+            ..Default::default()
         }
     }
 
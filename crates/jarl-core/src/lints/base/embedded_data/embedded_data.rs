@@ -0,0 +1,94 @@
+use crate::diagnostic::*;
+use crate::utils::{get_arg_by_name_then_position, get_function_name};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for string literals that are larger than a given size (10000 bytes
+/// by default), and for calls to `unserialize(charToRaw(...))`. This rule is
+/// disabled by default.
+///
+/// ## Why is this bad?
+///
+/// Very large strings in R code are usually data that was pasted in the code,
+/// such as base64-encoded files or serialized R objects. They make the code
+/// hard to read and to review, slow down editors and tools, and inflate the
+/// diffs every time the data changes. `unserialize(charToRaw(...))` is a
+/// common way to restore an R object from such a string.
+///
+/// It is better to store this data in a separate file, for example in
+/// `inst/extdata` in a package, and to read it at runtime.
+///
+/// The maximum size of string literals can be changed in `jarl.toml`:
+/// ```toml
+/// [lint.embedded_data]
+/// max-size = 50000
+/// ```
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Example
+///
+/// ```r
+/// model <- unserialize(charToRaw("A\n3\n263169\n..."))
+/// ```
+///
+/// Use instead:
+/// ```r
+/// model <- readRDS(system.file("extdata", "model.rds", package = "mypkg"))
+/// ```
+pub fn embedded_data(ast: &AnyRValue, max_size: usize) -> anyhow::Result<Option<Diagnostic>> {
+    let string = unwrap_or_return_none!(ast.as_r_string_value());
+
+    let size = string.to_trimmed_string().len();
+    if size <= max_size {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "embedded_data".to_string(),
+            format!("This string has {size} bytes, more than the maximum of {max_size} bytes."),
+            Some(help()),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+pub fn embedded_data_2(ast: &RCall, fn_name: &str) -> anyhow::Result<Option<Diagnostic>> {
+    if fn_name != "unserialize" {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+    let connection = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "connection", 1));
+    let connection = unwrap_or_return_none!(connection.value());
+    let inner = unwrap_or_return_none!(connection.as_r_call());
+    if get_function_name(inner.function()?) != "charToRaw" {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "embedded_data".to_string(),
+            "`unserialize(charToRaw(...))` restores an object stored in the code.".to_string(),
+            Some(help()),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+fn help() -> String {
+    "Store the data in a separate file instead, e.g. in `inst/extdata`.".to_string()
+}
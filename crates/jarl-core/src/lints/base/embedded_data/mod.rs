@@ -0,0 +1,101 @@
+pub(crate) mod embedded_data;
+pub(crate) mod options;
+
+#[cfg(test)]
+mod tests {
+    use crate::lints::base::embedded_data::options::EmbeddedDataOptions;
+    use crate::lints::base::embedded_data::options::ResolvedEmbeddedDataOptions;
+    use crate::rule_options::ResolvedRuleOptions;
+    use crate::settings::{LinterSettings, Settings};
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "embedded_data", None)
+    }
+
+    /// Build a `Settings` with a custom `max-size`.
+    fn settings_with_max_size(max_size: usize) -> Settings {
+        let options = EmbeddedDataOptions { max_size: Some(max_size) };
+        Settings {
+            linter: LinterSettings {
+                rule_options: ResolvedRuleOptions {
+                    embedded_data: ResolvedEmbeddedDataOptions::resolve(Some(&options)).unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_lint_embedded_data() {
+        expect_no_lint("x <- 'hello'", "embedded_data", None);
+        expect_no_lint(
+            &format!("x <- '{}'", "a".repeat(9998)),
+            "embedded_data",
+            None,
+        );
+        expect_no_lint("unserialize(x)", "embedded_data", None);
+        expect_no_lint("unserialize(readRDS(x))", "embedded_data", None);
+        expect_no_lint("charToRaw(x)", "embedded_data", None);
+        expect_no_lint_with_settings(
+            "x <- 'hello'",
+            "embedded_data",
+            None,
+            settings_with_max_size(7),
+        );
+    }
+
+    #[test]
+    fn test_lint_embedded_data() {
+        let code = format!("x <- '{}'", "a".repeat(9999));
+        assert_eq!(check_code(&code, "embedded_data", None).len(), 1);
+
+        assert_snapshot!(
+            format_diagnostics_with_settings(
+                "x <- 'hello'",
+                "embedded_data",
+                None,
+                Some(settings_with_max_size(6)),
+            ),
+            @"
+        warning: embedded_data
+         --> <test>:1:6
+          |
+        1 | x <- 'hello'
+          |      ------- This string has 7 bytes, more than the maximum of 6 bytes.
+          |
+          = help: Store the data in a separate file instead, e.g. in `inst/extdata`.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("x <- unserialize(charToRaw(y))"),
+            @"
+        warning: embedded_data
+         --> <test>:1:6
+          |
+        1 | x <- unserialize(charToRaw(y))
+          |      ------------------------- `unserialize(charToRaw(...))` restores an object stored in the code.
+          |
+          = help: Store the data in a separate file instead, e.g. in `inst/extdata`.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("base::unserialize(connection = base::charToRaw(y))"),
+            @"
+        warning: embedded_data
+         --> <test>:1:1
+          |
+        1 | base::unserialize(connection = base::charToRaw(y))
+          | -------------------------------------------------- `unserialize(charToRaw(...))` restores an object stored in the code.
+          |
+          = help: Store the data in a separate file instead, e.g. in `inst/extdata`.
+        Found 1 error.
+        "
+        );
+    }
+}
@@ -0,0 +1,29 @@
+/// Default maximum size of a string literal, in bytes.
+const DEFAULT_MAX_SIZE: usize = 10_000;
+
+/// TOML options for `[lint.embedded_data]`.
+///
+/// Use `max-size` to set the maximum size (in bytes) of a string literal
+/// before it is reported. The default is 10000 bytes.
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct EmbeddedDataOptions {
+    pub max_size: Option<usize>,
+}
+
+/// Resolved options for the `embedded_data` rule.
+#[derive(Clone, Debug)]
+pub struct ResolvedEmbeddedDataOptions {
+    pub max_size: usize,
+}
+
+impl ResolvedEmbeddedDataOptions {
+    pub fn resolve(options: Option<&EmbeddedDataOptions>) -> anyhow::Result<Self> {
+        let max_size = options
+            .and_then(|opts| opts.max_size)
+            .unwrap_or(DEFAULT_MAX_SIZE);
+
+        Ok(Self { max_size })
+    }
+}
@@ -0,0 +1,165 @@
+use biome_rowan::TextRange;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::namespace::parse_namespace_exported_objects;
+use crate::package::{FileScope, SharedFileData};
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for functions exported by a package (i.e. listed in `export()` or
+/// matched by `exportPattern()` in its `NAMESPACE` file) that are not
+/// documented. A function defined in `R/` is considered documented if:
+///
+/// - it is preceded by a roxygen block that doesn't use `@noRd` (e.g. a block
+///   containing only `#' @export`, or `#' @rdname other_function`);
+/// - its name appears in a `@name` or `@aliases` tag of a roxygen block of the
+///   package;
+/// - it is an alias of a `.Rd` file of `man/` that isn't generated by
+///   roxygen2.
+///
+/// This rule is disabled by default and doesn't have an automatic fix.
+///
+/// ## Why is this bad?
+///
+/// `R CMD check` warns about exported objects that have no documentation
+/// ("Undocumented code objects"), and users of the package can't get help on
+/// these functions with `?`.
+///
+/// ## Example
+///
+/// ```r
+/// # In NAMESPACE: export(add, add_one)
+///
+/// # In R/add.R:
+/// #' Add two numbers
+/// #'
+/// #' @param x,y Numbers.
+/// #' @export
+/// add <- function(x, y) {
+///   x + y
+/// }
+///
+/// add_one <- function(x) {
+///   add(x, 1)
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// #' Add two numbers
+/// #'
+/// #' @param x,y Numbers.
+/// #' @export
+/// add <- function(x, y) {
+///   x + y
+/// }
+///
+/// #' @rdname add
+/// #' @export
+/// add_one <- function(x) {
+///   add(x, 1)
+/// }
+/// ```
+///
+/// ## References
+///
+/// See the section "Functions" in [R Packages](https://r-pkgs.org/man.html#sec-man-functions).
+pub fn scan_rd_aliases(content: &str) -> Vec<String> {
+    // Aliases of an `.Rd` file, i.e. the topics it documents, unless it was
+    // generated by roxygen2. Generated files are not considered since they
+    // are removed when the roxygen blocks they come from are removed.
+    let is_generated = content
+        .lines()
+        .find(|line| !line.trim().is_empty())
+        .is_some_and(|line| line.starts_with("% Generated by roxygen2"));
+    if is_generated {
+        return Vec::new();
+    }
+
+    content
+        .lines()
+        .filter_map(|line| {
+            let rest = line.trim_start().strip_prefix("\\alias{")?;
+            let end = rest.rfind('}')?;
+            // Special characters are escaped in Rd, e.g. `\%in\%`.
+            Some(rest[..end].replace("\\%", "%"))
+        })
+        .collect()
+}
+
+/// Find the exported functions that are not documented, from pre-scanned
+/// shared file data.
+///
+/// `namespace_contents` maps package root paths to their NAMESPACE file
+/// contents, and `rd_aliases` to the aliases of their hand-written `.Rd`
+/// files. Packages without a NAMESPACE entry are skipped.
+pub(crate) fn compute_missing_docs_from_shared(
+    shared_data: &[SharedFileData],
+    namespace_contents: &HashMap<PathBuf, String>,
+    rd_aliases: &HashMap<PathBuf, HashSet<String>>,
+) -> HashMap<PathBuf, Vec<(String, TextRange, String)>> {
+    // Group by package root
+    let mut packages: HashMap<&Path, Vec<&SharedFileData>> = HashMap::new();
+    for fd in shared_data.iter().filter(|fd| fd.scope == FileScope::R) {
+        packages.entry(&fd.package_root).or_default().push(fd);
+    }
+
+    let mut result: HashMap<PathBuf, Vec<(String, TextRange, String)>> = HashMap::new();
+
+    for (package_root, r_files) in packages {
+        let Some(ns_content) = namespace_contents.get(package_root) else {
+            continue;
+        };
+
+        let all_defined_names: Vec<&str> = r_files
+            .iter()
+            .flat_map(|f| f.assignments.iter().map(|(name, _, _, _)| name.as_str()))
+            .collect();
+        let exports = parse_namespace_exported_objects(ns_content, &all_defined_names);
+
+        // A function defined several times is documented if one of its
+        // definitions is.
+        let mut documented: HashSet<&str> = r_files
+            .iter()
+            .flat_map(|f| {
+                f.roxygen_names
+                    .documented
+                    .iter()
+                    .chain(&f.roxygen_names.aliases)
+            })
+            .map(String::as_str)
+            .collect();
+        if let Some(aliases) = rd_aliases.get(package_root) {
+            documented.extend(aliases.iter().map(String::as_str));
+        }
+
+        for file in &r_files {
+            let missing: Vec<(String, TextRange, String)> = file
+                .assignments
+                .iter()
+                .filter(|(name, _, _, _)| {
+                    exports.contains(name) && !documented.contains(name.as_str())
+                })
+                .map(|(name, range, _, _)| {
+                    let help = format!(
+                        "Document `{name}` with a roxygen block, or with another function using `@rdname`."
+                    );
+                    (name.clone(), *range, help)
+                })
+                .collect();
+
+            if !missing.is_empty() {
+                result.insert(file.rel_path.clone(), missing);
+            }
+        }
+    }
+
+    result
+}
+
+pub(crate) fn has_rd_extension(path: &Path) -> bool {
+    matches!(path.extension().and_then(|e| e.to_str()), Some("Rd" | "rd"))
+}
@@ -0,0 +1,129 @@
+pub(crate) mod exported_function_missing_docs;
+
+#[cfg(test)]
+mod tests {
+    use super::exported_function_missing_docs::scan_rd_aliases;
+    use crate::check::check;
+    use crate::config::{ArgsConfig, build_config};
+    use crate::file_system::MemoryFileSystem;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    /// Names reported in each R file of the package made of `files`.
+    fn lint_package(files: &[(&str, &str)]) -> Vec<(String, Vec<String>)> {
+        let fs = files
+            .iter()
+            .fold(MemoryFileSystem::new(), |fs, (path, contents)| {
+                fs.with_file(path, contents)
+            });
+        let paths: Vec<PathBuf> = files
+            .iter()
+            .filter(|(path, _)| path.ends_with(".R"))
+            .map(|(path, _)| PathBuf::from(path))
+            .collect();
+        let args = ArgsConfig {
+            files: vec![],
+            fix: false,
+            unsafe_fixes: false,
+            unsafe_fixes_rules: String::new(),
+            fix_only: false,
+            select: "exported_function_missing_docs".to_string(),
+            extend_select: String::new(),
+            ignore: String::new(),
+            min_r_version: None,
+            allow_dirty: false,
+            allow_no_vcs: true,
+            respect_staged: false,
+            assignment: None,
+            preview: false,
+            profile: None,
+        };
+        let mut config = build_config(&args, None, paths).unwrap();
+        config.file_system = Arc::new(fs);
+
+        let mut results: Vec<(String, Vec<String>)> = check(config)
+            .into_iter()
+            .map(|(path, result)| {
+                let messages = result
+                    .unwrap()
+                    .into_iter()
+                    .map(|d| d.message.body)
+                    .collect();
+                (path, messages)
+            })
+            .collect();
+        results.sort();
+        results
+    }
+
+    #[test]
+    fn test_exported_function_missing_docs() {
+        let results = lint_package(&[
+            ("docpkg/DESCRIPTION", "Package: docpkg"),
+            (
+                "docpkg/NAMESPACE",
+                "export(add)\nexport(add_one)\nexport(helper)\nS3method(print, foo)\n",
+            ),
+            (
+                "docpkg/R/add.R",
+                "#' Add\n#' @export\nadd <- function(x, y) x + y\n\nadd_one <- function(x) add(x, 1)\n",
+            ),
+            (
+                "docpkg/R/helper.R",
+                "#' @noRd\nhelper <- function() 1\n\nprint.foo <- function(x, ...) x\n",
+            ),
+        ]);
+        assert_eq!(
+            results,
+            vec![
+                (
+                    "docpkg/R/add.R".to_string(),
+                    vec!["`add_one` is exported but has no documentation.".to_string()]
+                ),
+                (
+                    "docpkg/R/helper.R".to_string(),
+                    vec!["`helper` is exported but has no documentation.".to_string()]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_exported_function_documented_elsewhere() {
+        let results = lint_package(&[
+            ("docpkg2/DESCRIPTION", "Package: docpkg2"),
+            ("docpkg2/NAMESPACE", "exportPattern(\"^[a-z]\")\n"),
+            (
+                "docpkg2/R/a.R",
+                "#' Operators\n#' @name ops\n#' @aliases plus minus\nNULL\n\nminus <- function(x, y) x - y\n",
+            ),
+            (
+                "docpkg2/R/b.R",
+                "#' Times\n#' @export\n\n# A regular comment\ntimes <- function(x, y) x * y\n\nhand_written <- function() 1\n",
+            ),
+            (
+                "docpkg2/man/hand_written.Rd",
+                "\\name{hand_written}\n\\alias{hand_written}\n\\title{Hand written}\n",
+            ),
+        ]);
+        assert_eq!(
+            results,
+            vec![
+                ("docpkg2/R/a.R".to_string(), vec![]),
+                ("docpkg2/R/b.R".to_string(), vec![]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_scan_rd_aliases() {
+        assert_eq!(
+            scan_rd_aliases("\\name{ops}\n\\alias{ops}\n\\alias{\\%+\\%}\n"),
+            vec!["ops".to_string(), "%+%".to_string()]
+        );
+        assert!(
+            scan_rd_aliases("% Generated by roxygen2: do not edit by hand\n\\alias{ops}\n")
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use crate::checker::Checker;
+use crate::diagnostic::*;
+use crate::utils::{get_arg_by_name_then_position, get_function_name, is_pipe, piped_input};
+use air_r_syntax::*;
+use biome_rowan::{AstNode, TextRange};
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for code that relies on the order of the files returned by
+/// `list.files()` or `Sys.glob()` without sorting them first, i.e. code that
+/// extracts files by position (`list.files("data")[1]`) or that passes them
+/// to `head()`, `tail()`, or `setdiff()`.
+///
+/// By default, the result is considered sorted when it goes through `sort()`.
+/// Use `require-full-names = true` in `[lint.file_list_order]` to also require
+/// `full.names = TRUE` in `list.files()`, so that the paths that are sorted
+/// are the ones that are used afterwards.
+///
+/// This rule is disabled by default and doesn't have an automatic fix.
+///
+/// ## Why is this bad?
+///
+/// The order of the files returned by these functions depends on the
+/// platform, the locale, and sometimes the file system. Code taking the first
+/// file of a folder can therefore use a different file on another computer,
+/// without any error.
+///
+/// ## Example
+///
+/// ```r
+/// latest <- list.files("results", full.names = TRUE)[1]
+/// previous <- head(Sys.glob("results/*.csv"), 2)
+/// ```
+///
+/// Use instead:
+/// ```r
+/// latest <- sort(list.files("results", full.names = TRUE))[1]
+/// previous <- head(sort(Sys.glob("results/*.csv")), 2)
+/// ```
+///
+/// ## References
+///
+/// See `?list.files` and `?Sys.glob`
+pub fn file_list_order(
+    ast: &RCall,
+    fn_name: &str,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if !matches!(fn_name, "head" | "tail" | "setdiff") {
+        return Ok(None);
+    }
+
+    // i.e. `list.files() |> head(1)`
+    let node = ast.syntax();
+    let (input, range) = match piped_input(node) {
+        // Safety: `piped_input()` only returns something if `node` has a parent.
+        Some(input) => (input, node.parent().unwrap().text_trimmed_range()),
+        None => {
+            let args = ast.arguments()?.items();
+            let x = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "x", 1));
+            (unwrap_or_return_none!(x.value()), node.text_trimmed_range())
+        }
+    };
+
+    let require_full_names = checker.rule_options.file_list_order.require_full_names;
+    let producer = unwrap_or_return_none!(unsorted_file_list(&input, require_full_names));
+
+    Ok(Some(diagnostic(producer, range, require_full_names)))
+}
+
+/// Same as [file_list_order] but for `list.files()[1]`.
+pub fn file_list_order_subset(
+    ast: &RSubset,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    let RSubsetFields { function, arguments } = ast.as_fields();
+    let inside_brackets: Vec<_> = arguments?.items().into_iter().collect();
+
+    // No lint for x[1, 2] or x[].
+    if inside_brackets.len() != 1 {
+        return Ok(None);
+    }
+
+    // Safety: we know that `inside_brackets` contains a single element.
+    let arg = inside_brackets.first().unwrap().clone()?;
+    if arg.name_clause().is_some() {
+        return Ok(None);
+    }
+    let index = unwrap_or_return_none!(arg.value());
+    if !is_position(&index) {
+        return Ok(None);
+    }
+
+    let require_full_names = checker.rule_options.file_list_order.require_full_names;
+    let producer = unwrap_or_return_none!(unsorted_file_list(&function?, require_full_names));
+
+    Ok(Some(diagnostic(
+        producer,
+        ast.syntax().text_trimmed_range(),
+        require_full_names,
+    )))
+}
+
+fn diagnostic(producer: &str, range: TextRange, require_full_names: bool) -> Diagnostic {
+    let help = if require_full_names {
+        "Use `full.names = TRUE` and sort the files with `sort()` before relying on their order."
+    } else {
+        "Sort the files with `sort()` before relying on their order."
+    };
+
+    Diagnostic::new(
+        ViolationData::new(
+            "file_list_order".to_string(),
+            format!(
+                "The order of the files returned by `{producer}()` depends on the platform and the locale."
+            ),
+            Some(help.to_string()),
+        ),
+        range,
+        Fix::empty(),
+    )
+}
+
+/// Whether `index` is a position, e.g. `1`, `2L`, or `-1`.
+fn is_position(index: &AnyRExpression) -> bool {
+    match index {
+        AnyRExpression::AnyRValue(value) => {
+            value.as_r_integer_value().is_some() || value.as_r_double_value().is_some()
+        }
+        AnyRExpression::RUnaryExpression(unary) => {
+            unary
+                .operator()
+                .is_ok_and(|op| op.kind() == RSyntaxKind::MINUS)
+                && unary.argument().is_ok_and(|arg| is_position(&arg))
+        }
+        _ => false,
+    }
+}
+
+/// Name of the function that listed the files in `expr` if they are not
+/// sorted, e.g. `list.files` for `list.files("data")` but not for
+/// `sort(list.files("data"))` or `list.files("data") |> sort()`.
+fn unsorted_file_list(expr: &AnyRExpression, require_full_names: bool) -> Option<&'static str> {
+    let sorted = match expr {
+        AnyRExpression::RParenthesizedExpression(expr) => {
+            return unsorted_file_list(&expr.body().ok()?, require_full_names);
+        }
+        AnyRExpression::RCall(call) => {
+            if get_function_name(call.function().ok()?) != "sort" {
+                return file_list(call).map(|(producer, _)| producer);
+            }
+            let args = call.arguments().ok()?.items();
+            get_arg_by_name_then_position(&args, "x", 1)?.value()?
+        }
+        AnyRExpression::RBinaryExpression(binary) => {
+            if !is_pipe(&binary.operator().ok()?) {
+                return None;
+            }
+            let right = binary.right().ok()?;
+            if get_function_name(right.as_r_call()?.function().ok()?) != "sort" {
+                return None;
+            }
+            binary.left().ok()?
+        }
+        _ => return None,
+    };
+
+    // The files are sorted, which is enough unless full names are required.
+    if !require_full_names {
+        return None;
+    }
+    let (producer, full_names) = file_list(sorted.as_r_call()?)?;
+    (!full_names).then_some(producer)
+}
+
+/// If `call` lists files, returns the name of the function and whether it
+/// returns full paths.
+fn file_list(call: &RCall) -> Option<(&'static str, bool)> {
+    match get_function_name(call.function().ok()?).as_str() {
+        "list.files" => {
+            let args = call.arguments().ok()?.items();
+            let full_names = get_arg_by_name_then_position(&args, "full.names", 4)
+                .and_then(|arg| arg.value())
+                .is_some_and(|value| matches!(value.to_trimmed_string().as_str(), "TRUE" | "T"));
+            Some(("list.files", full_names))
+        }
+        // Paths are returned as they match the pattern.
+        "Sys.glob" => Some(("Sys.glob", true)),
+        _ => None,
+    }
+}
@@ -0,0 +1,155 @@
+pub(crate) mod file_list_order;
+pub(crate) mod options;
+
+#[cfg(test)]
+mod tests {
+    use crate::lints::base::file_list_order::options::FileListOrderOptions;
+    use crate::lints::base::file_list_order::options::ResolvedFileListOrderOptions;
+    use crate::rule_options::ResolvedRuleOptions;
+    use crate::settings::{LinterSettings, Settings};
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "file_list_order", None)
+    }
+
+    fn snapshot_lint_with_settings(code: &str, settings: Settings) -> String {
+        format_diagnostics_with_settings(code, "file_list_order", None, Some(settings))
+    }
+
+    /// Build a `Settings` with custom `FileListOrderOptions`.
+    fn settings_with_options(options: FileListOrderOptions) -> Settings {
+        Settings {
+            linter: LinterSettings {
+                rule_options: ResolvedRuleOptions {
+                    file_list_order: ResolvedFileListOrderOptions::resolve(Some(&options)).unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_lint_file_list_order() {
+        expect_no_lint("sort(list.files(\"data\"))[1]", "file_list_order", None);
+        expect_no_lint(
+            "(list.files(\"data\") |> sort())[1]",
+            "file_list_order",
+            None,
+        );
+        expect_no_lint(
+            "list.files(\"data\") |> sort() |> head(2)",
+            "file_list_order",
+            None,
+        );
+        expect_no_lint("head(sort(Sys.glob(\"*.csv\")))", "file_list_order", None);
+        expect_no_lint("list.files(\"data\")[i]", "file_list_order", None);
+        expect_no_lint("list.files(\"data\")[is_csv]", "file_list_order", None);
+        expect_no_lint("length(list.files(\"data\"))", "file_list_order", None);
+        expect_no_lint("setdiff(x, list.files(\"data\"))", "file_list_order", None);
+        expect_no_lint("head(x, 1)", "file_list_order", None);
+        expect_no_lint("x[1]", "file_list_order", None);
+    }
+
+    #[test]
+    fn test_lint_file_list_order() {
+        assert_snapshot!(
+            snapshot_lint("list.files(\"data\")[1]"),
+            @r#"
+        warning: file_list_order
+         --> <test>:1:1
+          |
+        1 | list.files("data")[1]
+          | --------------------- The order of the files returned by `list.files()` depends on the platform and the locale.
+          |
+          = help: Sort the files with `sort()` before relying on their order.
+        Found 1 error.
+        "#
+        );
+        assert_snapshot!(
+            snapshot_lint("head(Sys.glob(\"*.csv\"), 2)"),
+            @r#"
+        warning: file_list_order
+         --> <test>:1:1
+          |
+        1 | head(Sys.glob("*.csv"), 2)
+          | -------------------------- The order of the files returned by `Sys.glob()` depends on the platform and the locale.
+          |
+          = help: Sort the files with `sort()` before relying on their order.
+        Found 1 error.
+        "#
+        );
+        assert_snapshot!(
+            snapshot_lint("list.files(\"R\") |> tail(1)"),
+            @r#"
+        warning: file_list_order
+         --> <test>:1:1
+          |
+        1 | list.files("R") |> tail(1)
+          | -------------------------- The order of the files returned by `list.files()` depends on the platform and the locale.
+          |
+          = help: Sort the files with `sort()` before relying on their order.
+        Found 1 error.
+        "#
+        );
+        assert_snapshot!(
+            snapshot_lint("setdiff(list.files(\"R\"), \"zzz.R\")"),
+            @r#"
+        warning: file_list_order
+         --> <test>:1:1
+          |
+        1 | setdiff(list.files("R"), "zzz.R")
+          | --------------------------------- The order of the files returned by `list.files()` depends on the platform and the locale.
+          |
+          = help: Sort the files with `sort()` before relying on their order.
+        Found 1 error.
+        "#
+        );
+        assert_snapshot!(
+            snapshot_lint("x <- base::list.files(\".\")[-1]"),
+            @r#"
+        warning: file_list_order
+         --> <test>:1:6
+          |
+        1 | x <- base::list.files(".")[-1]
+          |      ------------------------- The order of the files returned by `list.files()` depends on the platform and the locale.
+          |
+          = help: Sort the files with `sort()` before relying on their order.
+        Found 1 error.
+        "#
+        );
+    }
+
+    #[test]
+    fn test_file_list_order_require_full_names() {
+        let options = FileListOrderOptions { require_full_names: Some(true) };
+        expect_no_lint_with_settings(
+            "sort(list.files(\"data\", full.names = TRUE))[1]",
+            "file_list_order",
+            None,
+            settings_with_options(options.clone()),
+        );
+        expect_no_lint_with_settings(
+            "sort(Sys.glob(\"*.csv\"))[1]",
+            "file_list_order",
+            None,
+            settings_with_options(options.clone()),
+        );
+        assert_snapshot!(
+            snapshot_lint_with_settings("sort(list.files(\"data\"))[1]", settings_with_options(options.clone())),
+            @r#"
+        warning: file_list_order
+         --> <test>:1:1
+          |
+        1 | sort(list.files("data"))[1]
+          | --------------------------- The order of the files returned by `list.files()` depends on the platform and the locale.
+          |
+          = help: Use `full.names = TRUE` and sort the files with `sort()` before relying on their order.
+        Found 1 error.
+        "#
+        );
+    }
+}
@@ -0,0 +1,27 @@
+/// TOML options for `[lint.file_list_order]`.
+///
+/// Use `require-full-names` to only consider the output of `list.files()` as
+/// sorted when it is called with `full.names = TRUE` and passed to `sort()`.
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct FileListOrderOptions {
+    pub require_full_names: Option<bool>,
+}
+
+/// Resolved options for the `file_list_order` rule, ready for use during
+/// linting.
+#[derive(Clone, Debug)]
+pub struct ResolvedFileListOrderOptions {
+    pub require_full_names: bool,
+}
+
+impl ResolvedFileListOrderOptions {
+    pub fn resolve(options: Option<&FileListOrderOptions>) -> anyhow::Result<Self> {
+        let require_full_names = options
+            .and_then(|opts| opts.require_full_names)
+            .unwrap_or(false);
+
+        Ok(Self { require_full_names })
+    }
+}
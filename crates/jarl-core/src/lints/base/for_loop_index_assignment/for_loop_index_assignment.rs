@@ -0,0 +1,93 @@
+use crate::diagnostic::*;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+pub struct ForLoopIndexAssignment;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for assignments to the index variable of a `for` loop in the body
+/// of this loop.
+///
+/// ## Why is this bad?
+///
+/// In R, the values taken by the index variable are determined once when the
+/// loop starts. Modifying the index in the body of the loop doesn't change the
+/// following iterations: at the start of the next iteration, the index takes
+/// the next value of the sequence anyway. This usually indicates a
+/// misunderstanding of how the loop works, and makes the code confusing.
+///
+/// Assignments in functions defined in the body of the loop are not reported
+/// since they don't modify the index.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// This rule is in preview, so it is only used with `--preview` or
+/// `preview = true` in `jarl.toml`.
+///
+/// ## Example
+///
+/// ```r
+/// for (i in 1:10) {
+///   if (skip(i)) {
+///     i <- i + 1
+///   }
+///   print(i)
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// for (i in 1:10) {
+///   if (skip(i)) {
+///     next
+///   }
+///   print(i)
+/// }
+/// ```
+impl Violation for ForLoopIndexAssignment {
+    fn name(&self) -> String {
+        "for_loop_index_assignment".to_string()
+    }
+    fn body(&self) -> String {
+        "This modifies the index of the `for` loop, which doesn't change the following iterations."
+            .to_string()
+    }
+    fn suggestion(&self) -> Option<String> {
+        Some("Use another variable or `next` instead.".to_string())
+    }
+}
+
+pub fn for_loop_index_assignment(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>> {
+    let RBinaryExpressionFields { left, operator, right } = ast.as_fields();
+
+    let target = match operator?.kind() {
+        RSyntaxKind::EQUAL | RSyntaxKind::ASSIGN => left?,
+        RSyntaxKind::ASSIGN_RIGHT => right?,
+        _ => return Ok(None),
+    };
+    let target = unwrap_or_return_none!(target.as_r_identifier());
+    let target = target.to_trimmed_string();
+
+    let range = ast.syntax().text_trimmed_range();
+    for ancestor in ast.syntax().ancestors().skip(1) {
+        // A function defined in the loop has its own environment.
+        if ancestor.kind() == RSyntaxKind::R_FUNCTION_DEFINITION {
+            return Ok(None);
+        }
+        let Some(for_loop) = RForStatement::cast(ancestor) else {
+            continue;
+        };
+        let in_body = for_loop
+            .body()
+            .is_ok_and(|body| body.syntax().text_trimmed_range().contains_range(range));
+        if in_body && for_loop.variable()?.to_trimmed_string() == target {
+            let diagnostic = Diagnostic::new(ForLoopIndexAssignment, range, Fix::empty());
+            return Ok(Some(diagnostic));
+        }
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,104 @@
+pub(crate) mod for_loop_index_assignment;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "for_loop_index_assignment", None)
+    }
+
+    #[test]
+    fn test_no_lint_for_loop_index_assignment() {
+        expect_no_lint(
+            "for (i in x) { j <- i + 1 }",
+            "for_loop_index_assignment",
+            None,
+        );
+        expect_no_lint(
+            "for (i in x) { x[i] <- 1 }",
+            "for_loop_index_assignment",
+            None,
+        );
+        expect_no_lint(
+            "for (i in x) { i[1] <- 1 }",
+            "for_loop_index_assignment",
+            None,
+        );
+        expect_no_lint("for (i in x) f(i = 1)", "for_loop_index_assignment", None);
+        expect_no_lint("for (i in x) i <<- 1", "for_loop_index_assignment", None);
+        // Assignments outside of the body
+        expect_no_lint(
+            "i <- 1; for (i in x) print(i); i <- 2",
+            "for_loop_index_assignment",
+            None,
+        );
+        expect_no_lint(
+            "for (i in (i <- x)) print(i)",
+            "for_loop_index_assignment",
+            None,
+        );
+        // Functions defined in the loop have their own environment
+        expect_no_lint(
+            "for (i in x) { f <- function() { i <- 1 } }",
+            "for_loop_index_assignment",
+            None,
+        );
+        expect_no_lint(
+            "for (i in x) { lapply(y, \\(j) { i <- j }) }",
+            "for_loop_index_assignment",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_for_loop_index_assignment() {
+        assert_snapshot!(
+            snapshot_lint("for (i in 1:n) { i <- i + 1 }"),
+            @"
+        warning: for_loop_index_assignment
+         --> <test>:1:18
+          |
+        1 | for (i in 1:n) { i <- i + 1 }
+          |                  ---------- This modifies the index of the `for` loop, which doesn't change the following iterations.
+          |
+          = help: Use another variable or `next` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("for (i in x) 1 -> i"),
+            @"
+        warning: for_loop_index_assignment
+         --> <test>:1:14
+          |
+        1 | for (i in x) 1 -> i
+          |              ------ This modifies the index of the `for` loop, which doesn't change the following iterations.
+          |
+          = help: Use another variable or `next` instead.
+        Found 1 error.
+        "
+        );
+        // Index of a parent loop
+        assert_snapshot!(
+            snapshot_lint(
+                "for (i in x) {
+  for (j in y) {
+    i = j
+  }
+}"
+            ),
+            @"
+        warning: for_loop_index_assignment
+         --> <test>:3:5
+          |
+        3 |     i = j
+          |     ----- This modifies the index of the `for` loop, which doesn't change the following iterations.
+          |
+          = help: Use another variable or `next` instead.
+        Found 1 error.
+        "
+        );
+    }
+}
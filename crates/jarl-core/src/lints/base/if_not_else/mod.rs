@@ -24,6 +24,7 @@ mod tests {
                 },
                 ..Default::default()
             },
+            ..Default::default()
         }
     }
 
@@ -1,6 +1,6 @@
 use crate::check::Checker;
 use crate::diagnostic::*;
-use crate::utils::get_function_name;
+use crate::utils::{get_function_name, node_contains_comments};
 use crate::utils_ast::AstNodeExt;
 use air_r_syntax::*;
 use biome_rowan::AstNode;
@@ -16,6 +16,20 @@ use biome_rowan::AstNode;
 /// Assigning inside function calls or other situations such as in `if()` makes
 /// the code difficult to read, and should be avoided.
 ///
+/// Assignments in the arguments of test fixtures, such as
+/// `local_mocked_bindings(f = function() x <- 1)`, are not reported. The list
+/// of fixture functions can be changed with `fixture-functions` in `[lint]`.
+///
+/// This rule has an unsafe automatic fix that moves the assignment on its own
+/// line before the statement that contains it, e.g. `if (x <- f()) ...`
+/// becomes `x <- f()` followed by `if (x) ...`. The fix is unsafe because the
+/// assignment is then evaluated before the rest of the statement, for instance
+/// before the other arguments of a function call. There is no fix when the
+/// assignment might not be evaluated at all or might be evaluated several
+/// times, such as in `while()` conditions, after `&&` or `||`, or in the
+/// arguments of functions that are not known to evaluate them right away, like
+/// `try()`, `quote()`, or user-defined functions.
+///
 /// ## Example
 ///
 /// ```r
@@ -113,6 +127,20 @@ pub fn implicit_assignment(
                 }
             }
         }
+
+        // Do not report assignments anywhere in the arguments of test fixtures,
+        // e.g. `local_mocked_bindings(f = function() x <- 1)`.
+        let fixture_functions = &checker.rule_options.fixture_functions;
+        for ancestor in ast.syntax().ancestors() {
+            if RBracedExpressions::can_cast(ancestor.kind()) {
+                break;
+            }
+            if let Some(call) = RCall::cast(ancestor)
+                && fixture_functions.is_fixture_call(&call)
+            {
+                return Ok(None);
+            }
+        }
     }
 
     // We want to skip cases like
@@ -202,12 +230,165 @@ pub fn implicit_assignment(
         unreachable!()
     };
 
+    let fix = if ancestor_is_while {
+        None
+    } else {
+        hoist_assignment(ast, &operator)?
+    };
+
     let range = ast.syntax().text_trimmed_range();
     let diagnostic = Diagnostic::new(
         ViolationData::new("implicit_assignment".to_string(), msg.to_string(), None),
         range,
-        Fix::empty(),
+        fix.unwrap_or_else(Fix::empty),
     );
 
     Ok(Some(diagnostic))
 }
+
+/// Functions known to evaluate each of their arguments exactly once when they
+/// are called. Arguments of other functions are lazy: they may be evaluated
+/// later, several times, or never (e.g. `quote()`, `substitute()`, `try()`, or
+/// any user-defined function), so the assignment can't be moved out of them.
+const EAGER_FUNCTIONS: &[&str] = &[
+    "abs",
+    "all",
+    "any",
+    "c",
+    "cat",
+    "identical",
+    "is.na",
+    "is.null",
+    "isFALSE",
+    "isTRUE",
+    "length",
+    "list",
+    "max",
+    "mean",
+    "min",
+    "names",
+    "nchar",
+    "ncol",
+    "nrow",
+    "paste",
+    "paste0",
+    "print",
+    "rev",
+    "round",
+    "seq_along",
+    "seq_len",
+    "sort",
+    "sqrt",
+    "sum",
+    "unique",
+    "unlist",
+    "which",
+];
+
+/// Build the fix moving the assignment on its own line before the statement
+/// that contains it, with the same indentation.
+///
+/// Return `None` if the assignment isn't necessarily evaluated exactly once
+/// when the statement runs, or if the statement doesn't start its line.
+fn hoist_assignment(
+    ast: &RBinaryExpression,
+    operator: &RSyntaxToken,
+) -> anyhow::Result<Option<Fix>> {
+    let target = match operator.kind() {
+        RSyntaxKind::ASSIGN => ast.left()?,
+        RSyntaxKind::ASSIGN_RIGHT => ast.right()?,
+        // `<<-` doesn't assign in the current environment, so the target can't
+        // replace the assignment.
+        _ => return Ok(None),
+    };
+
+    // Replace `(x <- 1)` by `x` rather than `(x)`.
+    let replaced = match ast.syntax().parent() {
+        Some(parent) if RParenthesizedExpression::can_cast(parent.kind()) => parent,
+        _ => ast.syntax().clone(),
+    };
+
+    // The statement is the ancestor that is directly in the list of
+    // expressions of the file or of a `{ }` block.
+    let mut child = replaced.clone();
+    let statement = loop {
+        let Some(parent) = child.parent() else {
+            return Ok(None);
+        };
+        if parent.kind() == RSyntaxKind::R_EXPRESSION_LIST {
+            break child;
+        }
+        if !is_evaluated_once(&parent, &child)? {
+            return Ok(None);
+        }
+        child = parent;
+    };
+
+    let root = statement.ancestors().last().unwrap_or(statement.clone());
+    let text = root.text().to_string();
+    let statement_start: usize = statement.text_trimmed_range().start().into();
+    let replaced_start: usize = replaced.text_trimmed_range().start().into();
+    let replaced_end: usize = replaced.text_trimmed_range().end().into();
+
+    let line_start = text[..statement_start].rfind('\n').map_or(0, |pos| pos + 1);
+    let indent = &text[line_start..statement_start];
+    if !indent.chars().all(|c| c == ' ' || c == '\t') {
+        return Ok(None);
+    }
+
+    let content = format!(
+        "{}\n{indent}{}{}",
+        ast.to_trimmed_string(),
+        &text[statement_start..replaced_start],
+        target.to_trimmed_string()
+    );
+
+    Ok(Some(Fix {
+        content,
+        start: statement_start,
+        end: replaced_end,
+        to_skip: node_contains_comments(ast.syntax()),
+    }))
+}
+
+/// Whether `child` is evaluated exactly once, and before anything else that
+/// could have side effects, when `parent` is evaluated.
+fn is_evaluated_once(parent: &RSyntaxNode, child: &RSyntaxNode) -> anyhow::Result<bool> {
+    if let Some(if_statement) = RIfStatement::cast(parent.clone()) {
+        return Ok(if_statement.condition()?.syntax() == child);
+    }
+    if let Some(for_statement) = RForStatement::cast(parent.clone()) {
+        return Ok(for_statement.sequence()?.syntax() == child);
+    }
+    if let Some(binary) = RBinaryExpression::cast(parent.clone()) {
+        // The right-hand side of `&&` and `||` is not always evaluated.
+        let is_lazy = matches!(
+            binary.operator()?.kind(),
+            RSyntaxKind::AND2 | RSyntaxKind::OR2
+        );
+        return Ok(!is_lazy || binary.left()?.syntax() == child);
+    }
+    if let Some(call) = RCall::cast(parent.clone()) {
+        let function = call.function()?;
+        // Only the arguments are evaluated lazily, not the function itself.
+        if function.syntax() == child {
+            return Ok(true);
+        }
+        let function_name = get_function_name(function);
+        return Ok(EAGER_FUNCTIONS.contains(&function_name.as_str()));
+    }
+
+    Ok(matches!(
+        parent.kind(),
+        RSyntaxKind::R_CALL_ARGUMENTS
+            | RSyntaxKind::R_ARGUMENT_LIST
+            | RSyntaxKind::R_ARGUMENT
+            | RSyntaxKind::R_PARENTHESIZED_EXPRESSION
+            | RSyntaxKind::R_UNARY_EXPRESSION
+            | RSyntaxKind::R_SUBSET
+            | RSyntaxKind::R_SUBSET2
+            | RSyntaxKind::R_SUBSET_ARGUMENTS
+            | RSyntaxKind::R_SUBSET2_ARGUMENTS
+            | RSyntaxKind::R_EXTRACT_EXPRESSION
+    ))
+}
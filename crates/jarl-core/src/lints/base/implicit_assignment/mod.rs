@@ -3,6 +3,7 @@ pub(crate) mod options;
 
 #[cfg(test)]
 mod tests {
+    use crate::fixture::FixtureFunctions;
     use crate::lints::base::implicit_assignment::options::ImplicitAssignmentOptions;
     use crate::lints::base::implicit_assignment::options::ResolvedImplicitAssignmentOptions;
     use crate::rule_options::ResolvedRuleOptions;
@@ -29,6 +30,7 @@ mod tests {
                 },
                 ..Default::default()
             },
+            ..Default::default()
         }
     }
 
@@ -318,6 +320,97 @@ mod tests {
         expect_no_lint_with_settings("pkg::my_fun(a <- 1)", "implicit_assignment", None, settings);
     }
 
+    #[test]
+    fn test_no_lint_in_fixture_functions() {
+        expect_no_lint(
+            "local_mocked_bindings(f = function() x <- 1)",
+            "implicit_assignment",
+            None,
+        );
+        expect_no_lint(
+            "testthat::local_mocked_bindings(f = function() x <- 1)",
+            "implicit_assignment",
+            None,
+        );
+        expect_no_lint(
+            "withr::local_options(list(digits = d <- 3))",
+            "implicit_assignment",
+            None,
+        );
+        expect_no_lint(
+            "rlang::local_options(lifecycle_verbosity = v <- 'quiet')",
+            "implicit_assignment",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_outside_fixture_functions() {
+        assert_snapshot!(
+            snapshot_lint("foo::local_options(list(digits = d <- 3))"),
+            @"
+        warning: implicit_assignment
+         --> <test>:1:34
+          |
+        1 | foo::local_options(list(digits = d <- 3))
+          |                                  ------ Avoid implicit assignments in function calls.
+          |
+        Found 1 error.
+        "
+        );
+        // Only the arguments of the fixture are skipped, not the code in a
+        // braced expression.
+        assert_snapshot!(
+            snapshot_lint("local_mocked_bindings(f = function() {\n  mean(x <- 1)\n})"),
+            @"
+        warning: implicit_assignment
+         --> <test>:2:8
+          |
+        2 |   mean(x <- 1)
+          |        ------ Avoid implicit assignments in function calls.
+          |
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_custom_fixture_functions() {
+        let settings = || Settings {
+            linter: LinterSettings {
+                rule_options: ResolvedRuleOptions {
+                    fixture_functions: FixtureFunctions::resolve(Some(&vec![
+                        "local_test_db".to_string(),
+                    ]))
+                    .unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        expect_no_lint_with_settings(
+            "local_test_db(path <- tempfile())",
+            "implicit_assignment",
+            None,
+            settings(),
+        );
+        // The custom list replaces the default one.
+        assert_snapshot!(
+            snapshot_lint_with_settings("withr::local_options(list(digits = d <- 3))", settings()),
+            @"
+        warning: implicit_assignment
+         --> <test>:1:36
+          |
+        1 | withr::local_options(list(digits = d <- 3))
+          |                                    ------ Avoid implicit assignments in function calls.
+          |
+        Found 1 error.
+        "
+        );
+    }
+
     #[test]
     fn test_implicit_assignment_with_interceding_comments() {
         assert_snapshot!(
@@ -362,4 +455,46 @@ mod tests {
         "
         );
     }
+
+    #[test]
+    fn test_fix_implicit_assignment() {
+        assert_snapshot!(
+            "fix_output",
+            get_unsafe_fixed_text(
+                vec![
+                    "if (x <- f()) print(x)",
+                    "if (f(1) -> x) print(x)",
+                    "if ((x <- f()) > 0) print(x)",
+                    "if ((x <- f()) && (y <- g())) print(x)",
+                    "mean(x <- c(1, 2, 3))",
+                    "for (i in (x <- 1:3)) print(i)",
+                    "f <- function() {\n  if (any(y <- x > 0)) {\n    print(y)\n  }\n}",
+                ],
+                "implicit_assignment",
+            )
+        );
+    }
+
+    #[test]
+    fn test_no_fix_implicit_assignment() {
+        // The assignment might not be evaluated, or be evaluated several
+        // times, or the statement doesn't start its line.
+        assert_snapshot!(
+            "no_fix_output",
+            get_unsafe_fixed_text(
+                vec![
+                    "while (x <- f()) print(x)",
+                    "if (a && (x <- f())) print(x)",
+                    "if (x <<- f()) print(x)",
+                    "if (a) 1 else if (x <- f()) 2",
+                    "y <- 1; if (x <- f()) print(x)",
+                    "try(x <- f())",
+                    "quote(f(x <- 1))",
+                    "substitute(x <- 1)",
+                    "g(x <- f())",
+                ],
+                "implicit_assignment",
+            )
+        );
+    }
 }
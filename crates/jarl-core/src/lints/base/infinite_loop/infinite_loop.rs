@@ -0,0 +1,116 @@
+use crate::checker::Checker;
+use crate::diagnostic::*;
+use crate::lints::base::unreachable_code::cfg::{build_cfg_loop_body, can_exit};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for `while (TRUE)` and `repeat` loops that can never be left, i.e.
+/// loops whose body doesn't have any reachable `break`, `return()`, or
+/// `stop()` (or equivalent).
+///
+/// Functions that stop the execution are the ones listed in the
+/// `stopping-functions` option of `unreachable_code`.
+///
+/// ## Why is this bad?
+///
+/// Such a loop runs forever. This is almost always a mistake, for instance
+/// because the `break` condition was forgotten or because it is placed after
+/// a `next` statement.
+///
+/// This rule does not have an automatic fix.
+///
+/// ## Example
+///
+/// ```r
+/// repeat {
+///   x <- x + 1
+/// }
+///
+/// while (TRUE) {
+///   x <- x + 1
+///   next
+///   if (x > 10) break
+/// }
+/// ```
+///
+/// Use instead:
+///
+/// ```r
+/// repeat {
+///   x <- x + 1
+///   if (x > 10) break
+/// }
+/// ```
+pub fn infinite_loop_while(
+    ast: &RWhileStatement,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if ast.condition()?.as_r_true_expression().is_none() {
+        return Ok(None);
+    }
+
+    let range = ast.while_token()?.text_trimmed_range();
+    infinite_loop(&ast.body()?, range, checker)
+}
+
+pub fn infinite_loop_repeat(
+    ast: &RRepeatStatement,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    let range = ast.repeat_token()?.text_trimmed_range();
+    infinite_loop(&ast.body()?, range, checker)
+}
+
+fn infinite_loop(
+    body: &AnyRExpression,
+    range: TextRange,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if has_exit_in_call_arguments(body.syntax()) {
+        return Ok(None);
+    }
+
+    let stopping = &checker.rule_options.unreachable_code.stopping_functions;
+    let cfg = build_cfg_loop_body(body.syntax(), stopping);
+    if can_exit(&cfg) {
+        return Ok(None);
+    }
+
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "infinite_loop".to_string(),
+            "This loop never ends: it has no reachable `break`, `return()`, or `stop()`."
+                .to_string(),
+            None,
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// The control flow graph only looks at statements, so `break` or `return()`
+/// used in the arguments of a call (e.g. in `switch()`) are not taken into
+/// account. We don't report those loops to avoid false positives.
+fn has_exit_in_call_arguments(body: &RSyntaxNode) -> bool {
+    body.descendants().any(|node| {
+        let is_exit = match node.kind() {
+            RSyntaxKind::R_BREAK_EXPRESSION => true,
+            RSyntaxKind::R_CALL => node
+                .first_child()
+                .is_some_and(|function| function.text_trimmed() == "return"),
+            _ => false,
+        };
+
+        is_exit
+            && node
+                .ancestors()
+                .take_while(|ancestor| ancestor != body)
+                .any(|ancestor| ancestor.kind() == RSyntaxKind::R_CALL_ARGUMENTS)
+    })
+}
@@ -0,0 +1,153 @@
+pub(crate) mod infinite_loop;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "infinite_loop", None)
+    }
+
+    #[test]
+    fn test_no_lint_infinite_loop() {
+        expect_no_lint("repeat { if (x) break }", "infinite_loop", None);
+        expect_no_lint("repeat break", "infinite_loop", None);
+        expect_no_lint(
+            "while (TRUE) { x <- x + 1; if (x > 10) return(x) }",
+            "infinite_loop",
+            None,
+        );
+        expect_no_lint(
+            "repeat { x <- x + 1; if (x > 10) stop('too big') }",
+            "infinite_loop",
+            None,
+        );
+        expect_no_lint(
+            "repeat { if (x > 10) rlang::abort('too big') }",
+            "infinite_loop",
+            None,
+        );
+        expect_no_lint(
+            "repeat { if (x) { next } else { break } }",
+            "infinite_loop",
+            None,
+        );
+        expect_no_lint(
+            "repeat { for (i in x) { next }; break }",
+            "infinite_loop",
+            None,
+        );
+
+        // Not a `while (TRUE)` loop
+        expect_no_lint("while (x) { x <- x + 1 }", "infinite_loop", None);
+        expect_no_lint("for (i in 1:10) { x <- x + 1 }", "infinite_loop", None);
+
+        // `break` in the arguments of a call is not handled by the control
+        // flow graph, so we don't report those loops.
+        expect_no_lint(
+            "repeat { switch(x, a = break, b = 1) }",
+            "infinite_loop",
+            None,
+        );
+        expect_no_lint("repeat { invisible(return(1)) }", "infinite_loop", None);
+    }
+
+    #[test]
+    fn test_lint_infinite_loop() {
+        assert_snapshot!(
+            snapshot_lint("repeat { x <- x + 1 }"),
+            @"
+        warning: infinite_loop
+         --> <test>:1:1
+          |
+        1 | repeat { x <- x + 1 }
+          | ------ This loop never ends: it has no reachable `break`, `return()`, or `stop()`.
+          |
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("while (TRUE) {\n  x <- x + 1\n  next\n  if (x > 10) break\n}"),
+            @"
+        warning: infinite_loop
+         --> <test>:1:1
+          |
+        1 | while (TRUE) {
+          | ----- This loop never ends: it has no reachable `break`, `return()`, or `stop()`.
+          |
+        Found 1 error.
+        "
+        );
+        // `break` only exits the inner loop
+        assert_snapshot!(
+            snapshot_lint("repeat { for (i in 1:3) break }"),
+            @"
+        warning: infinite_loop
+         --> <test>:1:1
+          |
+        1 | repeat { for (i in 1:3) break }
+          | ------ This loop never ends: it has no reachable `break`, `return()`, or `stop()`.
+          |
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("repeat { repeat { break } }"),
+            @"
+        warning: infinite_loop
+         --> <test>:1:1
+          |
+        1 | repeat { repeat { break } }
+          | ------ This loop never ends: it has no reachable `break`, `return()`, or `stop()`.
+          |
+        Found 1 error.
+        "
+        );
+        // `return()` only exits the inner function
+        assert_snapshot!(
+            snapshot_lint("repeat { f <- function() return(1) }"),
+            @"
+        warning: infinite_loop
+         --> <test>:1:1
+          |
+        1 | repeat { f <- function() return(1) }
+          | ------ This loop never ends: it has no reachable `break`, `return()`, or `stop()`.
+          |
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_infinite_loop_custom_stopping_functions() {
+        use crate::lints::base::unreachable_code::options::{
+            ResolvedUnreachableCodeOptions, UnreachableCodeOptions,
+        };
+        use crate::rule_options::ResolvedRuleOptions;
+        use crate::settings::{LinterSettings, Settings};
+
+        let settings = Settings {
+            linter: LinterSettings {
+                rule_options: ResolvedRuleOptions {
+                    unreachable_code: ResolvedUnreachableCodeOptions::resolve(Some(
+                        &UnreachableCodeOptions {
+                            stopping_functions: None,
+                            extend_stopping_functions: Some(vec!["my_stop".to_string()]),
+                        },
+                    ))
+                    .unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        expect_no_lint_with_settings(
+            "repeat { if (x) my_stop() }",
+            "infinite_loop",
+            None,
+            settings,
+        );
+    }
+}
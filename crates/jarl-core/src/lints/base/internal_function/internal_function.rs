@@ -1,3 +1,4 @@
+use crate::checker::Checker;
 use crate::diagnostic::*;
 use air_r_syntax::*;
 use biome_rowan::AstNode;
@@ -8,6 +9,11 @@ use biome_rowan::AstNode;
 ///
 /// Checks for usage of `:::`.
 ///
+/// Use `allow-own-package = true` in `[lint.internal_function]` to allow `:::`
+/// on the namespace of the package being checked (as given by the `Package`
+/// field of its `DESCRIPTION`), e.g. `mypkg:::helper()` in the tests of
+/// `mypkg`.
+///
 /// ## Why is this bad?
 ///
 /// Using `:::` to access a package's internal functions is unsafe. Those
@@ -15,13 +21,28 @@ use biome_rowan::AstNode;
 /// or removed by the maintainers without notice. Use public functions via `::`
 /// instead.
 ///
+/// `R CMD check` also reports calls to `:::` on other packages, and CRAN
+/// usually asks to remove them.
+///
 /// This rule doesn't have an automatic fix.
-pub fn internal_function(ast: &RNamespaceExpression) -> anyhow::Result<Option<Diagnostic>> {
+pub fn internal_function(
+    ast: &RNamespaceExpression,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
     let op = ast.operator()?;
     if op.kind() != RSyntaxKind::COLON3 {
         return Ok(None);
     };
 
+    if checker.rule_options.internal_function.allow_own_package
+        && let Some(own_package) = &checker.package_name
+    {
+        let left = ast.left()?.to_trimmed_string();
+        if left.trim_matches(['"', '\'', '`']) == own_package {
+            return Ok(None);
+        }
+    }
+
     let range = ast.syntax().text_trimmed_range();
     let diagnostic = Diagnostic::new(
         ViolationData::new(
@@ -1,14 +1,85 @@
 pub(crate) mod internal_function;
+pub(crate) mod options;
 
 #[cfg(test)]
 mod tests {
+    use crate::check::check;
+    use crate::config::{ArgsConfig, build_config};
+    use crate::file_system::MemoryFileSystem;
+    use crate::lints::base::internal_function::options::InternalFunctionOptions;
+    use crate::lints::base::internal_function::options::ResolvedInternalFunctionOptions;
+    use crate::rule_options::ResolvedRuleOptions;
+    use crate::settings::{LinterSettings, Settings};
     use crate::utils_test::*;
     use insta::assert_snapshot;
+    use std::path::PathBuf;
+    use std::sync::Arc;
 
     fn snapshot_lint(code: &str) -> String {
         format_diagnostics(code, "internal_function", None)
     }
 
+    /// Code of the diagnostics in the R files of the package made of `files`.
+    fn lint_package(files: &[(&str, &str)], options: InternalFunctionOptions) -> Vec<String> {
+        let fs = files
+            .iter()
+            .fold(MemoryFileSystem::new(), |fs, (path, contents)| {
+                fs.with_file(path, contents)
+            });
+        let paths: Vec<PathBuf> = files
+            .iter()
+            .filter(|(path, _)| path.ends_with(".R"))
+            .map(|(path, _)| PathBuf::from(path))
+            .collect();
+        let args = ArgsConfig {
+            files: vec![],
+            fix: false,
+            unsafe_fixes: false,
+            unsafe_fixes_rules: String::new(),
+            fix_only: false,
+            select: "internal_function".to_string(),
+            extend_select: String::new(),
+            ignore: String::new(),
+            min_r_version: None,
+            allow_dirty: false,
+            allow_no_vcs: true,
+            respect_staged: false,
+            assignment: None,
+            preview: false,
+            profile: None,
+        };
+        let settings = Settings {
+            linter: LinterSettings {
+                rule_options: ResolvedRuleOptions {
+                    internal_function: ResolvedInternalFunctionOptions::resolve(Some(&options))
+                        .unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut config = build_config(&args, Some(&settings), paths).unwrap();
+        config.file_system = Arc::new(fs);
+
+        let mut results: Vec<String> = check(config)
+            .into_iter()
+            .flat_map(|(path, result)| {
+                let contents = files.iter().find(|(p, _)| *p == path).unwrap().1;
+                result
+                    .unwrap()
+                    .into_iter()
+                    .map(|d| {
+                        contents[usize::from(d.range.start())..usize::from(d.range.end())]
+                            .to_string()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        results.sort();
+        results
+    }
+
     #[test]
     fn test_no_lint_internal_function() {
         expect_no_lint("foo::bar()", "internal_function", None);
@@ -44,4 +115,36 @@ mod tests {
         "
         );
     }
+
+    #[test]
+    fn test_internal_function_allow_own_package() {
+        let files = [
+            ("ownpkg/DESCRIPTION", "Package: ownpkg\nVersion: 1.0.0\n"),
+            (
+                "ownpkg/R/a.R",
+                "f <- function() ownpkg:::helper()\ng <- function() other:::helper()\n",
+            ),
+            (
+                "ownpkg/tests/testthat/test-a.R",
+                "expect_true(ownpkg:::helper())\nexpect_true(`ownpkg`:::helper())\n",
+            ),
+        ];
+
+        assert_eq!(
+            lint_package(&files, InternalFunctionOptions::default()),
+            vec![
+                "`ownpkg`:::helper",
+                "other:::helper",
+                "ownpkg:::helper",
+                "ownpkg:::helper"
+            ]
+        );
+        assert_eq!(
+            lint_package(
+                &files,
+                InternalFunctionOptions { allow_own_package: Some(true) }
+            ),
+            vec!["other:::helper"]
+        );
+    }
 }
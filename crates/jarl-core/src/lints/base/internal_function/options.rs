@@ -0,0 +1,27 @@
+/// TOML options for `[lint.internal_function]`.
+///
+/// Use `allow-own-package` to allow `:::` on the namespace of the package
+/// that is being checked, e.g. `mypkg:::helper()` in the tests of `mypkg`.
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct InternalFunctionOptions {
+    pub allow_own_package: Option<bool>,
+}
+
+/// Resolved options for the `internal_function` rule, ready for use during
+/// linting.
+#[derive(Clone, Debug)]
+pub struct ResolvedInternalFunctionOptions {
+    pub allow_own_package: bool,
+}
+
+impl ResolvedInternalFunctionOptions {
+    pub fn resolve(options: Option<&InternalFunctionOptions>) -> anyhow::Result<Self> {
+        let allow_own_package = options
+            .and_then(|opts| opts.allow_own_package)
+            .unwrap_or(false);
+
+        Ok(Self { allow_own_package })
+    }
+}
@@ -0,0 +1,151 @@
+use crate::diagnostic::*;
+use crate::utils::{get_arg_by_name_then_position, get_function_name, node_contains_comments};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for misuses of `invisible()`:
+///
+/// - `invisible(return(x))`, where `invisible()` has no effect;
+/// - `invisible()` without argument used as a statement whose value is
+///   discarded, e.g. that is not the last one of a block, where it doesn't do
+///   anything.
+///
+/// This rule is in preview, so it is only used with `--preview` or
+/// `preview = true` in `jarl.toml`. It has a safe automatic fix that replaces
+/// `invisible(return(x))` by `return(invisible(x))`.
+///
+/// ## Why is this bad?
+///
+/// `return()` exits the function as soon as it is evaluated. In
+/// `invisible(return(x))`, `invisible()` never gets the value of `x`, so the
+/// function returns `x` visibly, which is likely not the intent.
+///
+/// `invisible()` only changes the visibility of the value of the expression
+/// it is called in. When this value is discarded, e.g. because it is not the
+/// last statement of a function, it has no effect and only adds noise.
+///
+/// ## Example
+///
+/// ```r
+/// set_option <- function(name, value) {
+///   old <- options(setNames(list(value), name))
+///   invisible(return(old))
+/// }
+///
+/// log_message <- function(msg) {
+///   cat(msg, "\n")
+///   invisible()
+///   msg
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// set_option <- function(name, value) {
+///   old <- options(setNames(list(value), name))
+///   return(invisible(old))
+/// }
+///
+/// log_message <- function(msg) {
+///   cat(msg, "\n")
+///   msg
+/// }
+/// ```
+///
+/// ## References
+///
+/// See `?invisible` and `?return`
+pub fn invisible_return(ast: &RCall, fn_name: &str) -> anyhow::Result<Option<Diagnostic>> {
+    if fn_name != "invisible" {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+    let range = ast.syntax().text_trimmed_range();
+
+    if args.iter().count() == 0 {
+        if !is_discarded_statement(ast.syntax()) {
+            return Ok(None);
+        }
+        let diagnostic = Diagnostic::new(
+            ViolationData::new(
+                "invisible_return".to_string(),
+                "`invisible()` has no effect since its value is discarded.".to_string(),
+                Some(
+                    "Remove it, it only has an effect on the value returned by a function."
+                        .to_string(),
+                ),
+            ),
+            range,
+            Fix::empty(),
+        );
+        return Ok(Some(diagnostic));
+    }
+
+    // i.e. `invisible(return(x))`
+    if args.iter().count() != 1 {
+        return Ok(None);
+    }
+    let arg = unwrap_or_return_none!(args.iter().next().and_then(|arg| arg.ok()));
+    if arg.name_clause().is_some() {
+        return Ok(None);
+    }
+    let AnyRExpression::RCall(inner) = unwrap_or_return_none!(arg.value()) else {
+        return Ok(None);
+    };
+    let return_fn = inner.function()?;
+    if get_function_name(return_fn.clone()) != "return" {
+        return Ok(None);
+    }
+
+    let invisible_fn = ast.function()?.syntax().text_trimmed().to_string();
+    let return_fn = return_fn.syntax().text_trimmed().to_string();
+    let return_args = inner.arguments()?.items();
+    if return_args.iter().count() > 1 {
+        return Ok(None);
+    }
+    let value = match get_arg_by_name_then_position(&return_args, "value", 1) {
+        Some(value) => unwrap_or_return_none!(value.value())
+            .syntax()
+            .text_trimmed()
+            .to_string(),
+        None => String::new(),
+    };
+
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "invisible_return".to_string(),
+            "`invisible()` has no effect since `return()` exits the function first.".to_string(),
+            Some("Call `invisible()` inside `return()` instead.".to_string()),
+        ),
+        range,
+        Fix {
+            content: format!("{return_fn}({invisible_fn}({value}))"),
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(ast.syntax()),
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Whether `node` is a statement whose value is discarded, i.e. a top-level
+/// statement or a statement of a block that is not the last one.
+fn is_discarded_statement(node: &RSyntaxNode) -> bool {
+    let Some(list) = node.parent().and_then(RExpressionList::cast) else {
+        return false;
+    };
+    let Some(parent) = list.syntax().parent() else {
+        return false;
+    };
+    if RRoot::can_cast(parent.kind()) {
+        return true;
+    }
+    RBracedExpressions::can_cast(parent.kind())
+        && list.iter().last().is_some_and(|last| last.syntax() != node)
+}
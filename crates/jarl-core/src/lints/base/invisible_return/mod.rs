@@ -0,0 +1,99 @@
+pub(crate) mod invisible_return;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "invisible_return", None)
+    }
+
+    #[test]
+    fn test_no_lint_invisible_return() {
+        expect_no_lint("function(x) return(invisible(x))", "invisible_return", None);
+        expect_no_lint("function(x) invisible(x)", "invisible_return", None);
+        expect_no_lint("invisible(print(x))", "invisible_return", None);
+        // Returned by the function
+        expect_no_lint(
+            "function(x) {\n  cat(x)\n  invisible()\n}",
+            "invisible_return",
+            None,
+        );
+        expect_no_lint("function(x) invisible()", "invisible_return", None);
+        expect_no_lint("x <- invisible()", "invisible_return", None);
+        // Invalid code
+        expect_no_lint("invisible(return(x, y))", "invisible_return", None);
+    }
+
+    #[test]
+    fn test_lint_invisible_return() {
+        assert_snapshot!(
+            snapshot_lint("function(x) invisible(return(x))"),
+            @"
+        warning: invisible_return
+         --> <test>:1:13
+          |
+        1 | function(x) invisible(return(x))
+          |             -------------------- `invisible()` has no effect since `return()` exits the function first.
+          |
+          = help: Call `invisible()` inside `return()` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("function(x) {\n  cat(x)\n  invisible()\n  x\n}"),
+            @"
+        warning: invisible_return
+         --> <test>:3:3
+          |
+        3 |   invisible()
+          |   ----------- `invisible()` has no effect since its value is discarded.
+          |
+          = help: Remove it, it only has an effect on the value returned by a function.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("invisible()"),
+            @"
+        warning: invisible_return
+         --> <test>:1:1
+          |
+        1 | invisible()
+          | ----------- `invisible()` has no effect since its value is discarded.
+          |
+          = help: Remove it, it only has an effect on the value returned by a function.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_fix_invisible_return() {
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "function(x) invisible(return(x))",
+                    "function(x) base::invisible(return(value = x + 1))",
+                    "function() invisible(return())",
+                ],
+                "invisible_return",
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_invisible_return_with_comments_no_fix() {
+        assert_snapshot!(
+            "no_fix_with_comments",
+            get_fixed_text(
+                vec!["function(x) invisible(return(x # comment\n))"],
+                "invisible_return",
+                None
+            )
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use crate::diagnostic::*;
+use crate::lints::base::length_zero::options::PreferredEmptinessCheck;
+use crate::utils::{get_function_name, node_contains_comments};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for consistency of the way the emptiness of a vector is checked,
+/// such as `length(x) == 0`, `!length(x)`, `length(x) < 1`, or
+/// `identical(length(x), 0L)`. This rule is disabled by default.
+///
+/// ## Why is this bad?
+///
+/// All of these spellings are equivalent, but mixing them in a codebase makes
+/// it harder to read, and harder to search for. By default, this rule expects
+/// `length(x) == 0` (`length(x) == 0L` is also accepted).
+///
+/// To prefer `!length(x)`, set this in `jarl.toml`:
+/// ```toml
+/// [lint.length_zero]
+/// idiom = "!length(x)"
+/// ```
+///
+/// This rule has a safe fix for the checks that only use `length()` and a
+/// comparison or a negation. `identical(length(x), 0L)` is reported but not
+/// fixed since it can give a different result when `length()` doesn't return
+/// an integer, e.g. for some objects with a custom `length()` method.
+///
+/// ## Example
+///
+/// ```r
+/// if (length(x) < 1) {
+///   stop("`x` must not be empty.")
+/// }
+/// if (!length(y)) {
+///   return(NULL)
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// if (length(x) == 0) {
+///   stop("`x` must not be empty.")
+/// }
+/// if (length(y) == 0) {
+///   return(NULL)
+/// }
+/// ```
+pub fn length_zero(
+    ast: &RCall,
+    fn_name: &str,
+    idiom: PreferredEmptinessCheck,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if fn_name != "identical" {
+        return Ok(None);
+    }
+
+    let args: Vec<RArgument> = ast
+        .arguments()?
+        .items()
+        .iter()
+        .filter_map(|arg| arg.ok())
+        .collect();
+    let [first, second] = args.as_slice() else {
+        return Ok(None);
+    };
+    if first.name_clause().is_some() || second.name_clause().is_some() {
+        return Ok(None);
+    }
+    let (Some(first), Some(second)) = (first.value(), second.value()) else {
+        return Ok(None);
+    };
+
+    // `identical(length(x), 0)` is always `FALSE` since `length()` returns an
+    // integer, so it isn't an emptiness check.
+    let form = if as_length_call(&first).is_some() && is_literal(&second, &["0L"]) {
+        "identical(length(x), 0L)"
+    } else if is_literal(&first, &["0L"]) && as_length_call(&second).is_some() {
+        "identical(0L, length(x))"
+    } else {
+        return Ok(None);
+    };
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(violation(form, idiom), range, Fix::empty());
+
+    Ok(Some(diagnostic))
+}
+
+pub fn length_zero_2(
+    ast: &RBinaryExpression,
+    idiom: PreferredEmptinessCheck,
+) -> anyhow::Result<Option<Diagnostic>> {
+    let RBinaryExpressionFields { left, operator, right } = ast.as_fields();
+
+    let left = left?;
+    let operator = operator?;
+    let right = right?;
+
+    let (length_call, literal, length_on_left) =
+        match (as_length_call(&left), as_length_call(&right)) {
+            (Some(call), None) => (call, &right, true),
+            (None, Some(call)) => (call, &left, false),
+            _ => return Ok(None),
+        };
+
+    // Only keep the comparisons that are `TRUE` when `length(x)` is 0 and
+    // `FALSE` otherwise.
+    let is_emptiness_check = match (operator.kind(), length_on_left) {
+        (RSyntaxKind::EQUAL2, _) => is_literal(literal, &["0", "0L"]),
+        (RSyntaxKind::LESS_THAN, true) | (RSyntaxKind::GREATER_THAN, false) => {
+            is_literal(literal, &["1", "1L"])
+        }
+        (RSyntaxKind::LESS_THAN_OR_EQUAL_TO, true)
+        | (RSyntaxKind::GREATER_THAN_OR_EQUAL_TO, false) => is_literal(literal, &["0", "0L"]),
+        _ => false,
+    };
+    if !is_emptiness_check {
+        return Ok(None);
+    }
+
+    if idiom == PreferredEmptinessCheck::Comparison
+        && operator.kind() == RSyntaxKind::EQUAL2
+        && length_on_left
+    {
+        return Ok(None);
+    }
+
+    let literal = literal.to_trimmed_string();
+    let form = if length_on_left {
+        format!("length(x) {} {literal}", operator.text_trimmed())
+    } else {
+        format!("{literal} {} length(x)", operator.text_trimmed())
+    };
+
+    Ok(Some(diagnostic(ast.syntax(), &length_call, &form, idiom)))
+}
+
+pub fn length_zero_3(
+    ast: &RUnaryExpression,
+    idiom: PreferredEmptinessCheck,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if idiom == PreferredEmptinessCheck::Negation {
+        return Ok(None);
+    }
+
+    let operator = ast.operator()?;
+    if operator.kind() != RSyntaxKind::BANG {
+        return Ok(None);
+    }
+
+    let length_call = unwrap_or_return_none!(as_length_call(&ast.argument()?));
+
+    // `!!length(x)` checks that `x` is *not* empty.
+    if ast
+        .syntax()
+        .parent()
+        .and_then(RUnaryExpression::cast)
+        .and_then(|parent| parent.operator().ok())
+        .is_some_and(|operator| operator.kind() == RSyntaxKind::BANG)
+    {
+        return Ok(None);
+    }
+
+    Ok(Some(diagnostic(
+        ast.syntax(),
+        &length_call,
+        "!length(x)",
+        idiom,
+    )))
+}
+
+fn violation(form: &str, idiom: PreferredEmptinessCheck) -> ViolationData {
+    ViolationData::new(
+        "length_zero".to_string(),
+        format!("`{form}` is not the preferred way to check if a vector is empty."),
+        Some(format!("Use `{}` instead.", idiom.as_str())),
+    )
+}
+
+/// Build the diagnostic replacing `node` by the preferred idiom.
+fn diagnostic(
+    node: &RSyntaxNode,
+    length_call: &RCall,
+    form: &str,
+    idiom: PreferredEmptinessCheck,
+) -> Diagnostic {
+    let length_call = length_call.to_trimmed_string();
+    let replacement = match idiom {
+        PreferredEmptinessCheck::Comparison => format!("{length_call} == 0"),
+        PreferredEmptinessCheck::Negation => format!("!{length_call}"),
+    };
+    let replacement = if needs_parentheses(node) {
+        format!("({replacement})")
+    } else {
+        replacement
+    };
+
+    let range = node.text_trimmed_range();
+    Diagnostic::new(
+        violation(form, idiom),
+        range,
+        Fix {
+            content: replacement,
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(node),
+        },
+    )
+}
+
+/// Whether the replacement of `node` must be wrapped in parentheses to keep
+/// the same meaning, e.g. `1 + !length(x)` must become
+/// `1 + (length(x) == 0)`, and `length(x) < 1 == y` must become
+/// `(!length(x)) == y`.
+fn needs_parentheses(node: &RSyntaxNode) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+
+    if RUnaryExpression::can_cast(parent.kind()) {
+        return true;
+    }
+
+    let Some(operator) = RBinaryExpression::cast(parent).and_then(|parent| parent.operator().ok())
+    else {
+        return false;
+    };
+
+    // Operators with a lower precedence than both `==` and `!`.
+    !matches!(
+        operator.kind(),
+        RSyntaxKind::AND
+            | RSyntaxKind::AND2
+            | RSyntaxKind::OR
+            | RSyntaxKind::OR2
+            | RSyntaxKind::ASSIGN
+            | RSyntaxKind::SUPER_ASSIGN
+            | RSyntaxKind::ASSIGN_RIGHT
+            | RSyntaxKind::SUPER_ASSIGN_RIGHT
+            | RSyntaxKind::EQUAL
+            | RSyntaxKind::TILDE
+            | RSyntaxKind::WAT
+    )
+}
+
+/// If `expr` is a call to `length()` with a single argument, return it.
+fn as_length_call(expr: &AnyRExpression) -> Option<RCall> {
+    let call = expr.as_r_call()?;
+    if get_function_name(call.function().ok()?) != "length" {
+        return None;
+    }
+    let args = call.arguments().ok()?.items();
+    (args.iter().count() == 1).then(|| call.clone())
+}
+
+/// Whether `expr` is a numeric literal written as one of `values`.
+fn is_literal(expr: &AnyRExpression, values: &[&str]) -> bool {
+    expr.as_any_r_value().is_some() && values.contains(&expr.to_trimmed_string().as_str())
+}
@@ -0,0 +1,255 @@
+pub(crate) mod length_zero;
+pub(crate) mod options;
+
+#[cfg(test)]
+mod tests {
+    use crate::lints::base::length_zero::options::LengthZeroOptions;
+    use crate::lints::base::length_zero::options::ResolvedLengthZeroOptions;
+    use crate::rule_options::ResolvedRuleOptions;
+    use crate::settings::{LinterSettings, Settings};
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "length_zero", None)
+    }
+
+    fn snapshot_lint_with_settings(code: &str, settings: Settings) -> String {
+        format_diagnostics_with_settings(code, "length_zero", None, Some(settings))
+    }
+
+    /// Build a `Settings` that prefers `!length(x)`.
+    fn negation_settings() -> Settings {
+        let options = LengthZeroOptions { idiom: Some("!length(x)".to_string()) };
+        Settings {
+            linter: LinterSettings {
+                rule_options: ResolvedRuleOptions {
+                    length_zero: ResolvedLengthZeroOptions::resolve(Some(&options)).unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_lint_length_zero() {
+        expect_no_lint("length(x) == 0", "length_zero", None);
+        expect_no_lint("length(x) == 0L", "length_zero", None);
+        expect_no_lint("base::length(x) == 0", "length_zero", None);
+        // Not emptiness checks
+        expect_no_lint("length(x) == 1", "length_zero", None);
+        expect_no_lint("length(x) > 0", "length_zero", None);
+        expect_no_lint("length(x) < 2", "length_zero", None);
+        expect_no_lint("1 < length(x)", "length_zero", None);
+        expect_no_lint("length(x) == length(y)", "length_zero", None);
+        expect_no_lint("!!length(x)", "length_zero", None);
+        expect_no_lint("!nchar(x)", "length_zero", None);
+        expect_no_lint("nrow(x) < 1", "length_zero", None);
+        // Always `FALSE`
+        expect_no_lint("identical(length(x), 0)", "length_zero", None);
+        expect_no_lint("identical(length(x), 0L, FALSE)", "length_zero", None);
+    }
+
+    #[test]
+    fn test_no_lint_length_zero_negation() {
+        let settings = negation_settings();
+        expect_no_lint_with_settings("!length(x)", "length_zero", None, settings.clone());
+        expect_no_lint_with_settings("if (!length(x)) 1", "length_zero", None, settings);
+    }
+
+    #[test]
+    fn test_lint_length_zero() {
+        assert_snapshot!(
+            snapshot_lint("length(x) < 1"),
+            @"
+        warning: length_zero
+         --> <test>:1:1
+          |
+        1 | length(x) < 1
+          | ------------- `length(x) < 1` is not the preferred way to check if a vector is empty.
+          |
+          = help: Use `length(x) == 0` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("if (!length(x)) 1"),
+            @"
+        warning: length_zero
+         --> <test>:1:5
+          |
+        1 | if (!length(x)) 1
+          |     ---------- `!length(x)` is not the preferred way to check if a vector is empty.
+          |
+          = help: Use `length(x) == 0` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("identical(length(x), 0L)"),
+            @"
+        warning: length_zero
+         --> <test>:1:1
+          |
+        1 | identical(length(x), 0L)
+          | ------------------------ `identical(length(x), 0L)` is not the preferred way to check if a vector is empty.
+          |
+          = help: Use `length(x) == 0` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint_with_settings("length(x) == 0", negation_settings()),
+            @"
+        warning: length_zero
+         --> <test>:1:1
+          |
+        1 | length(x) == 0
+          | -------------- `length(x) == 0` is not the preferred way to check if a vector is empty.
+          |
+          = help: Use `!length(x)` instead.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_fix_length_zero() {
+        assert_snapshot!(
+            get_fixed_text(
+                vec![
+                    "length(x) < 1",
+                    "0L == length(x)",
+                    "1 > length(x)",
+                    "length(x) <= 0",
+                    "!length(x)",
+                    "if (!length(foo(x))) 1",
+                    "1 + !length(x)",
+                    "identical(length(x), 0L)",
+                ],
+                "length_zero",
+                None,
+            ),
+            @"
+        OLD:
+        ====
+        length(x) < 1
+        NEW:
+        ====
+        length(x) == 0
+
+        OLD:
+        ====
+        0L == length(x)
+        NEW:
+        ====
+        length(x) == 0
+
+        OLD:
+        ====
+        1 > length(x)
+        NEW:
+        ====
+        length(x) == 0
+
+        OLD:
+        ====
+        length(x) <= 0
+        NEW:
+        ====
+        length(x) == 0
+
+        OLD:
+        ====
+        !length(x)
+        NEW:
+        ====
+        length(x) == 0
+
+        OLD:
+        ====
+        if (!length(foo(x))) 1
+        NEW:
+        ====
+        if (length(foo(x)) == 0) 1
+
+        OLD:
+        ====
+        1 + !length(x)
+        NEW:
+        ====
+        1 + (length(x) == 0)
+
+        OLD:
+        ====
+        identical(length(x), 0L)
+        NEW:
+        ====
+        identical(length(x), 0L)
+        "
+        );
+    }
+
+    #[test]
+    fn test_fix_length_zero_negation() {
+        assert_snapshot!(
+            get_fixed_text_with_settings(
+                vec![
+                    "length(x) == 0",
+                    "length(x) < 1L && y",
+                    "length(x) == 0 == y",
+                ],
+                "length_zero",
+                None,
+                Some(negation_settings()),
+            ),
+            @"
+        OLD:
+        ====
+        length(x) == 0
+        NEW:
+        ====
+        !length(x)
+
+        OLD:
+        ====
+        length(x) < 1L && y
+        NEW:
+        ====
+        !length(x) && y
+
+        OLD:
+        ====
+        length(x) == 0 == y
+        NEW:
+        ====
+        (!length(x)) == y
+        "
+        );
+    }
+
+    #[test]
+    fn test_length_zero_with_comments_no_fix() {
+        assert_snapshot!(
+            get_fixed_text(
+                vec!["length(x) <\n  # comment\n  1"],
+                "length_zero",
+                None,
+            ),
+            @"
+        OLD:
+        ====
+        length(x) <
+          # comment
+          1
+        NEW:
+        ====
+        length(x) <
+          # comment
+          1
+        "
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PreferredEmptinessCheck {
+    /// `length(x) == 0`
+    Comparison,
+    /// `!length(x)`
+    Negation,
+}
+
+impl PreferredEmptinessCheck {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Comparison => "length(x) == 0",
+            Self::Negation => "!length(x)",
+        }
+    }
+}
+
+/// TOML options for `[lint.length_zero]`.
+///
+/// Use `idiom` to specify which check of emptiness to enforce. Valid values
+/// are `"length(x) == 0"` (the default) and `"!length(x)"`.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct LengthZeroOptions {
+    pub idiom: Option<String>,
+}
+
+/// Resolved options for the `length_zero` rule.
+#[derive(Clone, Debug)]
+pub struct ResolvedLengthZeroOptions {
+    pub idiom: PreferredEmptinessCheck,
+}
+
+impl ResolvedLengthZeroOptions {
+    pub fn resolve(options: Option<&LengthZeroOptions>) -> anyhow::Result<Self> {
+        let idiom = match options {
+            Some(opts) => match opts.idiom.as_deref() {
+                Some("length(x) == 0") | None => PreferredEmptinessCheck::Comparison,
+                Some("!length(x)") => PreferredEmptinessCheck::Negation,
+                Some(other) => {
+                    return Err(anyhow::anyhow!(
+                        "Invalid value for `idiom` in `[lint.length_zero]`: \"{other}\". \
+                         Expected \"length(x) == 0\" or \"!length(x)\"."
+                    ));
+                }
+            },
+            None => PreferredEmptinessCheck::Comparison,
+        };
+
+        Ok(Self { idiom })
+    }
+}
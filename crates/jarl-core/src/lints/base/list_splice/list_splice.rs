@@ -0,0 +1,109 @@
+use crate::checker::Checker;
+use crate::diagnostic::*;
+use crate::utils::get_function_name;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for calls to `c()` that append the output of a function returning a
+/// list, such as `lapply()` or `strsplit()`, to another object.
+///
+/// ## Why is this bad?
+///
+/// When one of its inputs is a list, `c()` doesn't add this list as a new
+/// element but splices its elements into the result. This is a common mistake
+/// when growing a list in a loop: `c(x, el)` adds as many elements as `el`
+/// contains (or none if it is empty), so `x` doesn't have one element per
+/// iteration.
+///
+/// By default, only `c()` calls located in the body of a loop are reported.
+/// Use `only-in-loops = false` in `[lint.list_splice]` to report them
+/// everywhere. The list of functions that are known to return a list can be
+/// changed with `list-functions` or `extend-list-functions`.
+///
+/// This rule is disabled by default and doesn't have an automatic fix since
+/// splicing might be intended.
+///
+/// ## Example
+///
+/// ```r
+/// out <- list()
+/// for (i in 1:3) {
+///   out <- c(out, strsplit(x[i], ","))
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// out <- list()
+/// for (i in 1:3) {
+///   out <- append(out, list(strsplit(x[i], ",")))
+///   # or
+///   out[[length(out) + 1]] <- strsplit(x[i], ",")
+/// }
+/// ```
+pub fn list_splice(
+    ast: &RCall,
+    fn_name: &str,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if fn_name != "c" {
+        return Ok(None);
+    }
+
+    let options = &checker.rule_options.list_splice;
+
+    let args = ast.arguments()?.items();
+    if args.iter().count() < 2 {
+        return Ok(None);
+    }
+
+    // The first argument is the object that is grown, only the next ones are
+    // appended to it.
+    let list_function = args.iter().skip(1).find_map(|arg| {
+        let value = arg.ok()?.value()?;
+        let call = value.as_r_call()?;
+        let name = get_function_name(call.function().ok()?);
+        options.list_functions.contains(&name).then_some(name)
+    });
+    let list_function = unwrap_or_return_none!(list_function);
+
+    if options.only_in_loops && !is_in_loop_body(ast.syntax()) {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "list_splice".to_string(),
+            format!(
+                "`c()` splices the elements of the list returned by `{list_function}()` instead of appending it as one element."
+            ),
+            Some("Use `append(x, list(el))` or `x[[length(x) + 1]] <- el` instead.".to_string()),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Whether `node` is in the body of a `for`, `while`, or `repeat` loop of the
+/// current function.
+fn is_in_loop_body(node: &RSyntaxNode) -> bool {
+    let mut child = node.clone();
+    for ancestor in node.ancestors().skip(1) {
+        match ancestor.kind() {
+            RSyntaxKind::R_FUNCTION_DEFINITION => return false,
+            RSyntaxKind::R_FOR_STATEMENT if child.index() == 6 => return true,
+            RSyntaxKind::R_WHILE_STATEMENT if child.index() == 4 => return true,
+            RSyntaxKind::R_REPEAT_STATEMENT => return true,
+            _ => {}
+        }
+        child = ancestor;
+    }
+    false
+}
@@ -0,0 +1,166 @@
+pub(crate) mod list_splice;
+pub(crate) mod options;
+
+#[cfg(test)]
+mod tests {
+    use crate::lints::base::list_splice::options::ListSpliceOptions;
+    use crate::lints::base::list_splice::options::ResolvedListSpliceOptions;
+    use crate::rule_options::ResolvedRuleOptions;
+    use crate::settings::{LinterSettings, Settings};
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "list_splice", None)
+    }
+
+    fn snapshot_lint_with_settings(code: &str, settings: Settings) -> String {
+        format_diagnostics_with_settings(code, "list_splice", None, Some(settings))
+    }
+
+    /// Build a `Settings` with custom `ListSpliceOptions`.
+    fn settings_with_options(options: ListSpliceOptions) -> Settings {
+        Settings {
+            linter: LinterSettings {
+                rule_options: ResolvedRuleOptions {
+                    list_splice: ResolvedListSpliceOptions::resolve(Some(&options)).unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_lint_list_splice() {
+        // Outside of loops
+        expect_no_lint("out <- c(out, lapply(x, f))", "list_splice", None);
+        expect_no_lint(
+            "for (i in c(x, lapply(y, f))) print(i)",
+            "list_splice",
+            None,
+        );
+        expect_no_lint(
+            "while (length(c(x, as.list(y)))) x <- NULL",
+            "list_splice",
+            None,
+        );
+        // Function definitions stop the search for a loop
+        expect_no_lint(
+            "for (i in 1:3) f <- function(x) c(x, as.list(i))",
+            "list_splice",
+            None,
+        );
+        // Appending a single element
+        expect_no_lint(
+            "for (i in x) out <- c(out, list(strsplit(i, ',')))",
+            "list_splice",
+            None,
+        );
+        expect_no_lint("for (i in x) out <- c(out, i)", "list_splice", None);
+        // The first argument is not appended
+        expect_no_lint("for (i in x) out <- c(lapply(i, f))", "list_splice", None);
+        expect_no_lint(
+            "for (i in x) out <- c(as.list(i), out)",
+            "list_splice",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_list_splice() {
+        assert_snapshot!(
+            snapshot_lint("for (i in x) {\n  out <- c(out, strsplit(i, ','))\n}"),
+            @"
+        warning: list_splice
+         --> <test>:2:10
+          |
+        2 |   out <- c(out, strsplit(i, ','))
+          |          ------------------------ `c()` splices the elements of the list returned by `strsplit()` instead of appending it as one element.
+          |
+          = help: Use `append(x, list(el))` or `x[[length(x) + 1]] <- el` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("while (cond) out <- c(out, base::lapply(i, f))"),
+            @"
+        warning: list_splice
+         --> <test>:1:21
+          |
+        1 | while (cond) out <- c(out, base::lapply(i, f))
+          |                     -------------------------- `c()` splices the elements of the list returned by `lapply()` instead of appending it as one element.
+          |
+          = help: Use `append(x, list(el))` or `x[[length(x) + 1]] <- el` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("repeat {\n  out <- c(out, y, as.list(z))\n  break\n}"),
+            @"
+        warning: list_splice
+         --> <test>:2:10
+          |
+        2 |   out <- c(out, y, as.list(z))
+          |          --------------------- `c()` splices the elements of the list returned by `as.list()` instead of appending it as one element.
+          |
+          = help: Use `append(x, list(el))` or `x[[length(x) + 1]] <- el` instead.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_list_splice_options() {
+        // Report `c()` calls outside of loops.
+        let settings = settings_with_options(ListSpliceOptions {
+            only_in_loops: Some(false),
+            ..Default::default()
+        });
+        assert_snapshot!(
+            snapshot_lint_with_settings("out <- c(out, lapply(x, f))", settings),
+            @"
+        warning: list_splice
+         --> <test>:1:8
+          |
+        1 | out <- c(out, lapply(x, f))
+          |        -------------------- `c()` splices the elements of the list returned by `lapply()` instead of appending it as one element.
+          |
+          = help: Use `append(x, list(el))` or `x[[length(x) + 1]] <- el` instead.
+        Found 1 error.
+        "
+        );
+
+        // Add a custom function returning a list.
+        let settings = settings_with_options(ListSpliceOptions {
+            extend_list_functions: Some(vec!["list_files".to_string()]),
+            ..Default::default()
+        });
+        assert_snapshot!(
+            snapshot_lint_with_settings("for (d in dirs) out <- c(out, list_files(d))", settings),
+            @"
+        warning: list_splice
+         --> <test>:1:24
+          |
+        1 | for (d in dirs) out <- c(out, list_files(d))
+          |                        --------------------- `c()` splices the elements of the list returned by `list_files()` instead of appending it as one element.
+          |
+          = help: Use `append(x, list(el))` or `x[[length(x) + 1]] <- el` instead.
+        Found 1 error.
+        "
+        );
+
+        // Entirely replace the default list.
+        let settings = settings_with_options(ListSpliceOptions {
+            list_functions: Some(vec!["list_files".to_string()]),
+            ..Default::default()
+        });
+        expect_no_lint_with_settings(
+            "for (i in x) out <- c(out, lapply(i, f))",
+            "list_splice",
+            None,
+            settings,
+        );
+    }
+}
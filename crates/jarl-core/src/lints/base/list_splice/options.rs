@@ -0,0 +1,52 @@
+use std::collections::HashSet;
+
+use crate::rule_options::resolve_with_extend;
+
+/// Default functions that return a list, whose elements are spliced by `c()`.
+const DEFAULT_LIST_FUNCTIONS: &[&str] = &[
+    "Map",
+    "as.list",
+    "data.frame",
+    "lapply",
+    "split",
+    "strsplit",
+];
+
+/// TOML options for `[lint.list_splice]`.
+///
+/// Use `list-functions` to fully replace the default list of functions that
+/// are known to return a list. Use `extend-list-functions` to add to the
+/// default list. Specifying both is an error.
+///
+/// Use `only-in-loops` to choose whether only `c()` calls located in the body
+/// of a loop are reported (the default) or all of them.
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ListSpliceOptions {
+    pub list_functions: Option<Vec<String>>,
+    pub extend_list_functions: Option<Vec<String>>,
+    pub only_in_loops: Option<bool>,
+}
+
+/// Resolved options for the `list_splice` rule, ready for use during linting.
+#[derive(Clone, Debug)]
+pub struct ResolvedListSpliceOptions {
+    pub list_functions: HashSet<String>,
+    pub only_in_loops: bool,
+}
+
+impl ResolvedListSpliceOptions {
+    pub fn resolve(options: Option<&ListSpliceOptions>) -> anyhow::Result<Self> {
+        let list_functions = resolve_with_extend(
+            options.and_then(|opts| opts.list_functions.as_ref()),
+            options.and_then(|opts| opts.extend_list_functions.as_ref()),
+            DEFAULT_LIST_FUNCTIONS,
+            "list_splice",
+            "list-functions",
+        )?;
+        let only_in_loops = options.and_then(|opts| opts.only_in_loops).unwrap_or(true);
+
+        Ok(Self { list_functions, only_in_loops })
+    }
+}
@@ -0,0 +1,126 @@
+use crate::checker::Checker;
+use crate::diagnostic::*;
+use crate::utils::{get_function_name, numeric_value};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for numeric literals written inline in expressions, such as
+/// `x * 3600` or `head(x, 37)`.
+///
+/// The numbers `-1`, `0`, `1`, `2`, and `100` are not reported by default,
+/// and this list can be changed in `[lint.magic_numbers]`. Numbers are also
+/// not reported when their meaning is already clear:
+///
+/// - when they are assigned to a variable, e.g. `max_tries <- 5` or
+///   `probs <- c(0.25, 0.75)`;
+/// - when they are passed as named arguments, e.g. `round(x, digits = 3)`;
+/// - when they are default values of function arguments;
+/// - in test files (in a `tests/` or `tinytest/` folder), where expected
+///   values are usually written inline.
+///
+/// This rule is disabled by default and doesn't have an automatic fix.
+///
+/// ## Why is this bad?
+///
+/// A "magic number" doesn't say what it represents: is `86400` a number of
+/// seconds, a sample size, or an ID? Storing it in a constant with a
+/// descriptive name documents it and ensures that it is changed everywhere
+/// it is used.
+///
+/// ## Example
+///
+/// ```r
+/// elapsed <- as.numeric(end - start) / 86400
+/// ```
+///
+/// Use instead:
+/// ```r
+/// SECONDS_PER_DAY <- 86400
+/// elapsed <- as.numeric(end - start) / SECONDS_PER_DAY
+/// ```
+pub fn magic_numbers(ast: &AnyRValue, checker: &Checker) -> anyhow::Result<Option<Diagnostic>> {
+    if checker.is_test_file {
+        return Ok(None);
+    }
+    if ast.as_r_integer_value().is_none() && ast.as_r_double_value().is_none() {
+        return Ok(None);
+    }
+
+    // Include the sign in the value and in the range of `-5`.
+    let mut node = ast.syntax().clone();
+    if let Some(unary) = node.parent().and_then(RUnaryExpression::cast)
+        && unary.operator()?.kind() == RSyntaxKind::MINUS
+    {
+        node = unary.into_syntax();
+    }
+
+    let Some(value) = AnyRExpression::cast_ref(&node).and_then(|expr| numeric_value(&expr)) else {
+        return Ok(None);
+    };
+    if checker.rule_options.magic_numbers.is_allowed(value)
+        || is_named_value(&node)
+        || node
+            .ancestors()
+            .any(|ancestor| RParameterDefault::can_cast(ancestor.kind()))
+    {
+        return Ok(None);
+    }
+
+    let number = node.text_trimmed().to_string();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "magic_numbers".to_string(),
+            format!("`{number}` is a magic number."),
+            Some("Store it in a constant with a descriptive name.".to_string()),
+        ),
+        node.text_trimmed_range(),
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Whether the number is assigned to a variable, possibly in a `c()` call, or
+/// passed as a named argument.
+fn is_named_value(node: &RSyntaxNode) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+
+    if let Some(argument) = RArgument::cast_ref(&parent) {
+        if argument.name_clause().is_some() {
+            return true;
+        }
+        // `c(0.25, 0.75)`: check whether the whole vector is assigned.
+        let call = argument
+            .syntax()
+            .parent()
+            .and_then(|list| list.parent())
+            .and_then(|arguments| arguments.parent())
+            .and_then(RCall::cast);
+        return call.is_some_and(|call| {
+            call.function()
+                .is_ok_and(|function| get_function_name(function) == "c")
+                && is_named_value(call.syntax())
+        });
+    }
+
+    let Some(binary) = RBinaryExpression::cast(parent) else {
+        return false;
+    };
+    let (Ok(operator), Ok(left), Ok(right)) = (binary.operator(), binary.left(), binary.right())
+    else {
+        return false;
+    };
+    match operator.kind() {
+        RSyntaxKind::ASSIGN | RSyntaxKind::SUPER_ASSIGN | RSyntaxKind::EQUAL => {
+            right.syntax() == node
+        }
+        RSyntaxKind::ASSIGN_RIGHT | RSyntaxKind::SUPER_ASSIGN_RIGHT => left.syntax() == node,
+        _ => false,
+    }
+}
@@ -0,0 +1,148 @@
+pub(crate) mod magic_numbers;
+pub(crate) mod options;
+
+#[cfg(test)]
+mod tests {
+    use crate::lints::base::magic_numbers::options::MagicNumbersOptions;
+    use crate::lints::base::magic_numbers::options::ResolvedMagicNumbersOptions;
+    use crate::rule_options::ResolvedRuleOptions;
+    use crate::settings::{LinterSettings, Settings};
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "magic_numbers", None)
+    }
+
+    /// Build a `Settings` with custom `MagicNumbersOptions`.
+    fn settings_with_options(options: MagicNumbersOptions) -> Settings {
+        Settings {
+            linter: LinterSettings {
+                rule_options: ResolvedRuleOptions {
+                    magic_numbers: ResolvedMagicNumbersOptions::resolve(Some(&options)).unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_lint_magic_numbers() {
+        expect_no_lint("x + 1", "magic_numbers", None);
+        expect_no_lint("x[-1]", "magic_numbers", None);
+        expect_no_lint("x * 100", "magic_numbers", None);
+        expect_no_lint("x / 2L", "magic_numbers", None);
+        expect_no_lint("0.0", "magic_numbers", None);
+        // Assigned to a variable
+        expect_no_lint("max_tries <- 5", "magic_numbers", None);
+        expect_no_lint("MIN_SIZE = -10", "magic_numbers", None);
+        expect_no_lint("15 -> timeout", "magic_numbers", None);
+        expect_no_lint("probs <- c(0.25, 0.75)", "magic_numbers", None);
+        expect_no_lint("cache$size <<- 256", "magic_numbers", None);
+        // Named arguments
+        expect_no_lint("round(x, digits = 3)", "magic_numbers", None);
+        expect_no_lint("rnorm(10, sd = -0.5)", "magic_numbers", None);
+        // Default values of arguments
+        expect_no_lint("f <- function(x, n = 10) x", "magic_numbers", None);
+        expect_no_lint(
+            "f <- function(x, range = c(5, 10)) x",
+            "magic_numbers",
+            None,
+        );
+        // Not numbers
+        expect_no_lint("x + '5'", "magic_numbers", None);
+    }
+
+    #[test]
+    fn test_lint_magic_numbers() {
+        assert_snapshot!(
+            snapshot_lint("elapsed <- seconds / 86400"),
+            @"
+        warning: magic_numbers
+         --> <test>:1:22
+          |
+        1 | elapsed <- seconds / 86400
+          |                      ----- `86400` is a magic number.
+          |
+          = help: Store it in a constant with a descriptive name.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("head(x, 37L)"),
+            @"
+        warning: magic_numbers
+         --> <test>:1:9
+          |
+        1 | head(x, 37L)
+          |         --- `37L` is a magic number.
+          |
+          = help: Store it in a constant with a descriptive name.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("if (x > -5) y"),
+            @"
+        warning: magic_numbers
+         --> <test>:1:9
+          |
+        1 | if (x > -5) y
+          |         -- `-5` is a magic number.
+          |
+          = help: Store it in a constant with a descriptive name.
+        Found 1 error.
+        "
+        );
+        // Only the vector is named, not the numbers used to compute it.
+        assert_snapshot!(
+            snapshot_lint("x <- c(3, y * 4)"),
+            @"
+        warning: magic_numbers
+         --> <test>:1:15
+          |
+        1 | x <- c(3, y * 4)
+          |               - `4` is a magic number.
+          |
+          = help: Store it in a constant with a descriptive name.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_magic_numbers_options() {
+        let settings = settings_with_options(MagicNumbersOptions {
+            extend_allowed: Some(vec![60.0, 24.0]),
+            ..Default::default()
+        });
+        expect_no_lint_with_settings("x * 60 * 24 + 1", "magic_numbers", None, settings);
+
+        // `allowed` replaces the default list.
+        let settings = settings_with_options(MagicNumbersOptions {
+            allowed: Some(vec![0.0]),
+            ..Default::default()
+        });
+        assert_snapshot!(
+            format_diagnostics_with_settings("x + 1", "magic_numbers", None, Some(settings)),
+            @"
+        warning: magic_numbers
+         --> <test>:1:5
+          |
+        1 | x + 1
+          |     - `1` is a magic number.
+          |
+          = help: Store it in a constant with a descriptive name.
+        Found 1 error.
+        "
+        );
+
+        let options = MagicNumbersOptions {
+            allowed: Some(vec![0.0]),
+            extend_allowed: Some(vec![1.0]),
+        };
+        assert!(ResolvedMagicNumbersOptions::resolve(Some(&options)).is_err());
+    }
+}
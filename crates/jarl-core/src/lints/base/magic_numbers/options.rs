@@ -0,0 +1,46 @@
+/// Default numbers that are not reported.
+const DEFAULT_ALLOWED: &[f64] = &[-1.0, 0.0, 1.0, 2.0, 100.0];
+
+/// TOML options for `[lint.magic_numbers]`.
+///
+/// Use `allowed` to fully replace the default list of numbers that are not
+/// reported. Use `extend-allowed` to add to the default list. Specifying both
+/// is an error.
+#[derive(Clone, Debug, PartialEq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct MagicNumbersOptions {
+    pub allowed: Option<Vec<f64>>,
+    pub extend_allowed: Option<Vec<f64>>,
+}
+
+/// Resolved options for the `magic_numbers` rule, ready for use during
+/// linting.
+#[derive(Clone, Debug)]
+pub struct ResolvedMagicNumbersOptions {
+    pub allowed: Vec<f64>,
+}
+
+impl ResolvedMagicNumbersOptions {
+    pub fn resolve(options: Option<&MagicNumbersOptions>) -> anyhow::Result<Self> {
+        let allowed = options.and_then(|opts| opts.allowed.as_ref());
+        let extend_allowed = options.and_then(|opts| opts.extend_allowed.as_ref());
+
+        let allowed = match (allowed, extend_allowed) {
+            (Some(_), Some(_)) => {
+                return Err(anyhow::anyhow!(
+                    "Cannot specify both `allowed` and `extend-allowed` in `[lint.magic_numbers]`."
+                ));
+            }
+            (Some(allowed), None) => allowed.clone(),
+            (None, Some(extend)) => DEFAULT_ALLOWED.iter().chain(extend).copied().collect(),
+            (None, None) => DEFAULT_ALLOWED.to_vec(),
+        };
+
+        Ok(Self { allowed })
+    }
+
+    pub fn is_allowed(&self, value: f64) -> bool {
+        self.allowed.contains(&value)
+    }
+}
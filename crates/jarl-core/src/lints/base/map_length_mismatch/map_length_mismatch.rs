@@ -0,0 +1,129 @@
+use crate::diagnostic::*;
+use crate::utils::static_length;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for calls to `Map()` and `mapply()` whose inputs have different
+/// lengths that can be known without running the code, for instance `1:3`
+/// and `1:4`, `c("a", "b")`, or `character(0)`.
+///
+/// Inputs of length 1 are ignored since recycling them is common and
+/// intended.
+///
+/// ## Why is this bad?
+///
+/// `Map()` and `mapply()` recycle the shorter inputs to the length of the
+/// longest one. This is rarely intended when the inputs have different
+/// lengths, and it is only signaled by a warning when the longest length is
+/// not a multiple of the other ones. Mixing inputs of length zero with longer
+/// inputs is an error.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Example
+///
+/// ```r
+/// Map(function(x, y) x + y, 1:3, 1:4)
+/// mapply(paste, c("a", "b"), c("x", "y", "z", "w"))
+/// ```
+///
+/// Use instead:
+/// ```r
+/// Map(function(x, y) x + y, 1:4, 1:4)
+/// mapply(paste, c("a", "b", "c", "d"), c("x", "y", "z", "w"))
+/// ```
+///
+/// ## References
+///
+/// See `?Map` and `?mapply`
+pub fn map_length_mismatch(
+    ast: &RCall,
+    fn_name: &str,
+    ns_prefix: Option<&str>,
+) -> anyhow::Result<Option<Diagnostic>> {
+    // Named arguments that are not vectorized over.
+    let formals: &[&str] = match fn_name {
+        "Map" => &["f"],
+        "mapply" => &["FUN", "MoreArgs", "SIMPLIFY", "USE.NAMES"],
+        _ => return Ok(None),
+    };
+    if ns_prefix.is_some_and(|ns| ns != "base::") {
+        return Ok(None);
+    }
+
+    let mut function_is_named = false;
+    let mut inputs = Vec::new();
+    for arg in ast.arguments()?.items().iter() {
+        let arg = arg?;
+        let name = match arg.name_clause() {
+            Some(name_clause) => Some(name_clause.name()?.to_trimmed_string()),
+            None => None,
+        };
+        match name {
+            Some(name) if formals.contains(&name.as_str()) => {
+                function_is_named |= name == formals[0];
+            }
+            _ => inputs.push(arg),
+        }
+    }
+    // The first unnamed argument is the function if it isn't named.
+    if !function_is_named {
+        let position = unwrap_or_return_none!(
+            inputs
+                .iter()
+                .position(|input| input.name_clause().is_none())
+        );
+        inputs.remove(position);
+    }
+
+    let mut lengths = Vec::new();
+    for input in &inputs {
+        // `...` can contain any number of inputs of any length.
+        if input.to_trimmed_string() == "..." {
+            return Ok(None);
+        }
+        let value = unwrap_or_return_none!(input.value());
+        if let Some(length) = static_length(&value) {
+            lengths.push(length);
+        }
+    }
+
+    let longest = unwrap_or_return_none!(lengths.iter().max().copied());
+    let has_empty = lengths.contains(&0);
+    // Inputs of length 1 are recycled on purpose, except with empty inputs.
+    let shortest = unwrap_or_return_none!(
+        lengths
+            .iter()
+            .copied()
+            .filter(|&length| length != 1 || has_empty)
+            .min()
+    );
+    if shortest == longest {
+        return Ok(None);
+    }
+
+    let help = if shortest == 0 {
+        "Inputs of length zero can't be mixed with longer inputs, so this is an error."
+    } else {
+        "The shorter inputs are recycled, which is likely a mistake."
+    };
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "map_length_mismatch".to_string(),
+            format!(
+                "`{fn_name}()` is called on inputs of different lengths ({shortest} and {longest})."
+            ),
+            Some(help.to_string()),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
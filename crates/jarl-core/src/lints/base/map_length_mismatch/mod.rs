@@ -0,0 +1,114 @@
+pub(crate) mod map_length_mismatch;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "map_length_mismatch", None)
+    }
+
+    #[test]
+    fn test_no_lint_map_length_mismatch() {
+        expect_no_lint("Map(f, 1:3, 3:1)", "map_length_mismatch", None);
+        expect_no_lint("mapply(f, x, 1:4)", "map_length_mismatch", None);
+        expect_no_lint("Map(f, 1:3, 1)", "map_length_mismatch", None);
+        expect_no_lint("Map(f, c(1, 2), \"a\", TRUE)", "map_length_mismatch", None);
+        expect_no_lint("Map(f, 1:3, c(x, y))", "map_length_mismatch", None);
+        expect_no_lint("Map(f, 1:3, seq_len(n))", "map_length_mismatch", None);
+        expect_no_lint("Map(f, 1:3, ...)", "map_length_mismatch", None);
+        expect_no_lint(
+            "mapply(f, 1:3, MoreArgs = list(1, 2))",
+            "map_length_mismatch",
+            None,
+        );
+        expect_no_lint("mapply(1:3, FUN = f, 1:3)", "map_length_mismatch", None);
+        expect_no_lint("Map(1:2, f = f, 1:2)", "map_length_mismatch", None);
+        expect_no_lint("purrr::map2(1:3, 1:4, f)", "map_length_mismatch", None);
+        expect_no_lint("foo::Map(f, 1:3, 1:4)", "map_length_mismatch", None);
+        expect_no_lint("Reduce(f, 1:3, 1:4)", "map_length_mismatch", None);
+    }
+
+    #[test]
+    fn test_lint_map_length_mismatch() {
+        assert_snapshot!(
+            snapshot_lint("Map(f, 1:3, 1:4)"),
+            @"
+        warning: map_length_mismatch
+         --> <test>:1:1
+          |
+        1 | Map(f, 1:3, 1:4)
+          | ---------------- `Map()` is called on inputs of different lengths (3 and 4).
+          |
+          = help: The shorter inputs are recycled, which is likely a mistake.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("mapply(paste, c(\"a\", \"b\"), c(\"x\", \"y\", \"z\", \"w\"))"),
+            @r#"
+        warning: map_length_mismatch
+         --> <test>:1:1
+          |
+        1 | mapply(paste, c("a", "b"), c("x", "y", "z", "w"))
+          | ------------------------------------------------- `mapply()` is called on inputs of different lengths (2 and 4).
+          |
+          = help: The shorter inputs are recycled, which is likely a mistake.
+        Found 1 error.
+        "#
+        );
+        assert_snapshot!(
+            snapshot_lint("base::Map(function(x, y) x + y, x = seq_len(5), y = -1:1)"),
+            @"
+        warning: map_length_mismatch
+         --> <test>:1:1
+          |
+        1 | base::Map(function(x, y) x + y, x = seq_len(5), y = -1:1)
+          | --------------------------------------------------------- `Map()` is called on inputs of different lengths (3 and 5).
+          |
+          = help: The shorter inputs are recycled, which is likely a mistake.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("mapply(x = 1:2, y = list(1, 2, 3), FUN = f, SIMPLIFY = FALSE)"),
+            @"
+        warning: map_length_mismatch
+         --> <test>:1:1
+          |
+        1 | mapply(x = 1:2, y = list(1, 2, 3), FUN = f, SIMPLIFY = FALSE)
+          | ------------------------------------------------------------- `mapply()` is called on inputs of different lengths (2 and 3).
+          |
+          = help: The shorter inputs are recycled, which is likely a mistake.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("Map(f, 1, character(0))"),
+            @"
+        warning: map_length_mismatch
+         --> <test>:1:1
+          |
+        1 | Map(f, 1, character(0))
+          | ----------------------- `Map()` is called on inputs of different lengths (0 and 1).
+          |
+          = help: Inputs of length zero can't be mixed with longer inputs, so this is an error.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("mapply(f, NULL, c(1, 2))"),
+            @"
+        warning: map_length_mismatch
+         --> <test>:1:1
+          |
+        1 | mapply(f, NULL, c(1, 2))
+          | ------------------------ `mapply()` is called on inputs of different lengths (0 and 2).
+          |
+          = help: Inputs of length zero can't be mixed with longer inputs, so this is an error.
+        Found 1 error.
+        "
+        );
+    }
+}
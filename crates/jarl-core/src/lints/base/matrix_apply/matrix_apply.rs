@@ -8,7 +8,7 @@ use biome_rowan::AstSeparatedList;
 ///
 /// ## What it does
 ///
-/// Checks for usage of `apply(x, 1/2, mean/sum)`.
+/// Checks for usage of `apply(x, 1/2, mean/sum)` and `Reduce("+", x)`.
 ///
 /// ## Why is this bad?
 ///
@@ -16,6 +16,12 @@ use biome_rowan::AstSeparatedList;
 /// 1 or 2. `colSums()`, `rowSums()`, `colMeans()`, `rowMeans()` are both easier
 /// to read and much more efficient.
 ///
+/// `Reduce("+", x)` adds the elements of `x` one at a time, creating a new
+/// vector at each step. When `x` is a list of vectors of the same length,
+/// `rowSums(do.call(cbind, x))` computes the same sums in a single pass. Since
+/// this replacement doesn't work when `x` is a list of matrices, `Reduce()`
+/// calls don't have an automatic fix.
+///
 /// This rule provides an automated fix, except when extra arguments (outside
 /// of `na.rm`) are provided. In other words, this would be marked as lint and
 /// could be automatically replaced:
@@ -38,6 +44,8 @@ use biome_rowan::AstSeparatedList;
 /// apply(dat, 1, mean)
 /// apply(dat, 2, mean)
 /// apply(dat, 2, mean, na.rm = TRUE)
+///
+/// Reduce("+", list(dat$x, dat$y))
 /// ```
 ///
 /// Use instead:
@@ -48,12 +56,17 @@ use biome_rowan::AstSeparatedList;
 /// rowMeans(dat)
 /// colMeans(dat)
 /// colMeans(dat, na.rm = TRUE)
+///
+/// rowSums(do.call(cbind, list(dat$x, dat$y)))
 /// ```
 ///
 /// ## References
 ///
 /// See `?colSums`
 pub fn matrix_apply(ast: &RCall, fn_name: &str) -> anyhow::Result<Option<Diagnostic>> {
+    if fn_name == "Reduce" {
+        return matrix_reduce(ast);
+    }
     if fn_name != "apply" {
         return Ok(None);
     }
@@ -149,3 +162,35 @@ pub fn matrix_apply(ast: &RCall, fn_name: &str) -> anyhow::Result<Option<Diagnos
 
     Ok(Some(diagnostic))
 }
+
+/// `Reduce("+", x)`, which doesn't have a fix.
+fn matrix_reduce(ast: &RCall) -> anyhow::Result<Option<Diagnostic>> {
+    let args = ast.arguments()?.items();
+    // `init`, `accumulate`, etc. change the result.
+    if args.iter().count() != 2 {
+        return Ok(None);
+    }
+
+    let f = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "f", 1));
+    let x = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "x", 2));
+    let f = unwrap_or_return_none!(f.value()).to_trimmed_string();
+    if !matches!(f.as_str(), "\"+\"" | "'+'" | "`+`") {
+        return Ok(None);
+    }
+    let x = unwrap_or_return_none!(x.value()).to_trimmed_string();
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "matrix_apply".to_string(),
+            "`Reduce(\"+\", x)` is inefficient.".to_string(),
+            Some(format!(
+                "If `{x}` is a list of vectors of the same length, use `rowSums(do.call(cbind, {x}))` instead."
+            )),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
@@ -187,6 +187,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_no_lint_matrix_reduce() {
+        expect_no_lint("Reduce(`*`, x)", "matrix_apply", None);
+        expect_no_lint("Reduce(sum, x)", "matrix_apply", None);
+        expect_no_lint("Reduce('+', x, accumulate = TRUE)", "matrix_apply", None);
+        expect_no_lint("Reduce('+', x, 0)", "matrix_apply", None);
+    }
+
+    #[test]
+    fn test_lint_matrix_reduce() {
+        assert_snapshot!(
+            snapshot_lint("Reduce('+', x)"),
+            @r#"
+        warning: matrix_apply
+         --> <test>:1:1
+          |
+        1 | Reduce('+', x)
+          | -------------- `Reduce("+", x)` is inefficient.
+          |
+          = help: If `x` is a list of vectors of the same length, use `rowSums(do.call(cbind, x))` instead.
+        Found 1 error.
+        "#
+        );
+        assert_snapshot!(
+            snapshot_lint("base::Reduce(x = list(a, b), f = `+`)"),
+            @r#"
+        warning: matrix_apply
+         --> <test>:1:1
+          |
+        1 | base::Reduce(x = list(a, b), f = `+`)
+          | ------------------------------------- `Reduce("+", x)` is inefficient.
+          |
+          = help: If `list(a, b)` is a list of vectors of the same length, use `rowSums(do.call(cbind, list(a, b)))` instead.
+        Found 1 error.
+        "#
+        );
+        // No fix since the replacement doesn't work with lists of matrices.
+        let diagnostics = check_code("Reduce('+', x)", "matrix_apply", None);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(!diagnostics[0].has_safe_fix());
+    }
+
     #[test]
     fn test_matrix_apply_with_comments_no_fix() {
         // Should detect lint but skip fix when comments are present to avoid destroying them
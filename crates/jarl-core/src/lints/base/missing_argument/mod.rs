@@ -29,6 +29,7 @@ mod tests {
                 },
                 ..Default::default()
             },
+            ..Default::default()
         }
     }
 
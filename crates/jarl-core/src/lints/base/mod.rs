@@ -1,43 +1,62 @@
 pub(crate) mod all_equal;
 pub(crate) mod any_duplicated;
 pub(crate) mod any_is_na;
+pub(crate) mod as_date_format;
 pub(crate) mod assignment;
 pub(crate) mod browser;
+pub(crate) mod case_comparison;
 pub(crate) mod class_equals;
 pub(crate) mod coalesce;
+pub(crate) mod comparison_chain;
 pub(crate) mod comparison_negation;
 pub(crate) mod condition_call;
 pub(crate) mod condition_message;
 pub(crate) mod download_file;
 pub(crate) mod duplicated_arguments;
 pub(crate) mod duplicated_function_definition;
+pub(crate) mod embedded_data;
 pub(crate) mod empty_assignment;
 pub(crate) mod empty_file;
 pub(crate) mod equals_na;
 pub(crate) mod equals_nan;
 pub(crate) mod equals_null;
+pub(crate) mod exported_function_missing_docs;
+pub(crate) mod file_list_order;
 pub(crate) mod fixed_regex;
 pub(crate) mod for_loop_dup_index;
 pub(crate) mod for_loop_index;
+pub(crate) mod for_loop_index_assignment;
 pub(crate) mod glue;
 pub(crate) mod grepv;
 pub(crate) mod if_always_true;
 pub(crate) mod if_not_else;
 pub(crate) mod implicit_assignment;
+pub(crate) mod infinite_loop;
 pub(crate) mod internal_function;
+pub(crate) mod invisible_return;
 pub(crate) mod is_numeric;
 pub(crate) mod length_levels;
 pub(crate) mod length_test;
+pub(crate) mod length_zero;
 pub(crate) mod lengths;
 pub(crate) mod list2df;
+pub(crate) mod list_splice;
 pub(crate) mod literal_coercion;
+pub(crate) mod magic_numbers;
+pub(crate) mod map_length_mismatch;
 pub(crate) mod matrix_apply;
 pub(crate) mod missing_argument;
+pub(crate) mod na_unsafe_string_check;
+pub(crate) mod namespace_modification;
 pub(crate) mod nested_pipe;
 pub(crate) mod notin;
 pub(crate) mod numeric_leading_zero;
 pub(crate) mod nzchar;
+pub(crate) mod options_reset;
 pub(crate) mod outer_negation;
+pub(crate) mod package_dependency;
+pub(crate) mod paste_formula;
+pub(crate) mod paste_to_paste0;
 pub(crate) mod pipe_consistency;
 pub(crate) mod pipe_return;
 pub(crate) mod quotes;
@@ -45,20 +64,38 @@ pub(crate) mod redundant_equals;
 pub(crate) mod redundant_ifelse;
 pub(crate) mod rep_times_ignored;
 pub(crate) mod repeat;
+pub(crate) mod return_assignment;
+pub(crate) mod rlang_abort_call;
+pub(crate) mod roxygen_completeness;
 pub(crate) mod sample_int;
+pub(crate) mod scalar_logical_check;
 pub(crate) mod seq;
 pub(crate) mod seq2;
+pub(crate) mod shell_injection;
+pub(crate) mod shiny_render_context;
 pub(crate) mod sort;
+pub(crate) mod split_apply_combine;
 pub(crate) mod sprintf;
 pub(crate) mod stopifnot_all;
 pub(crate) mod string_boundary;
 pub(crate) mod strings_as_factors;
+pub(crate) mod suspicious_rounding;
+pub(crate) mod sys_setlocale;
+pub(crate) mod sys_time_comparison;
 pub(crate) mod system_file;
+pub(crate) mod tibble_rownames;
 pub(crate) mod true_false_symbol;
 pub(crate) mod undesirable_function;
 pub(crate) mod unnecessary_nesting;
 pub(crate) mod unnecessary_parentheses;
+pub(crate) mod unprinted_ggplot;
 pub(crate) mod unreachable_code;
+pub(crate) mod unsafe_vector_recycling;
 pub(crate) mod unused_function;
+pub(crate) mod use_method_dispatch;
+pub(crate) mod validation_in_loop;
+pub(crate) mod vapply_fun_value;
+pub(crate) mod vapply_over_sapply;
+pub(crate) mod vector_condition;
 pub(crate) mod vector_logic;
 pub(crate) mod which_grepl;
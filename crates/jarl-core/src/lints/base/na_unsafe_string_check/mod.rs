@@ -0,0 +1,98 @@
+pub(crate) mod na_unsafe_string_check;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "na_unsafe_string_check", None)
+    }
+
+    #[test]
+    fn test_no_lint_na_unsafe_string_check() {
+        // Missing values are checked first
+        expect_no_lint(
+            "x[!is.na(x) & nchar(x) > 0]",
+            "na_unsafe_string_check",
+            None,
+        );
+        expect_no_lint(
+            "if (is.na(y) || trimws(y) == '') 1",
+            "na_unsafe_string_check",
+            None,
+        );
+        // Not used to subset
+        expect_no_lint("nchar(x) > 0", "na_unsafe_string_check", None);
+        expect_no_lint("which(nchar(x) > 0)", "na_unsafe_string_check", None);
+        expect_no_lint("x[[nchar(x) > 0]]", "na_unsafe_string_check", None);
+        // Keeps empty strings
+        expect_no_lint("x[nchar(x) == 0]", "na_unsafe_string_check", None);
+        // Not an emptiness check
+        expect_no_lint("x[nchar(x) > 2]", "na_unsafe_string_check", None);
+        expect_no_lint("x[trimws(x) == 'a']", "na_unsafe_string_check", None);
+        expect_no_lint("x[toupper(x) != '']", "na_unsafe_string_check", None);
+        // `is.na()` is called on another variable
+        expect_no_lint(
+            "if (trimws(y) == '' || is.na(z)) 1",
+            "na_unsafe_string_check",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_na_unsafe_string_check() {
+        assert_snapshot!(
+            snapshot_lint("x[nchar(x) > 0]"),
+            @"
+        warning: na_unsafe_string_check
+         --> <test>:1:3
+          |
+        1 | x[nchar(x) > 0]
+          |   ------------ `nchar(x) > 0` is `NA` for missing values, so they are not removed.
+          |
+          = help: Check for missing values first: `!is.na(x) & nchar(x) > 0`.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("df[0 < nchar(trimws(df$name)) & df$age > 18, ]"),
+            @"
+        warning: na_unsafe_string_check
+         --> <test>:1:4
+          |
+        1 | df[0 < nchar(trimws(df$name)) & df$age > 18, ]
+          |    -------------------------- `0 < nchar(trimws(df$name))` is `NA` for missing values, so they are not removed.
+          |
+          = help: Check for missing values first: `!is.na(df$name) & 0 < nchar(trimws(df$name))`.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("if (trimws(y) == \"\" || is.na(y)) 1"),
+            @r#"
+        warning: na_unsafe_string_check
+         --> <test>:1:5
+          |
+        1 | if (trimws(y) == "" || is.na(y)) 1
+          |     --------------- `trimws(y) == ""` is evaluated before checking for missing values with `is.na(y)`.
+          |
+          = help: Check for missing values first: `is.na(y) || trimws(y) == ""`.
+        Found 1 error.
+        "#
+        );
+        assert_snapshot!(
+            snapshot_lint("x[(nchar(x) > 0) & !is.na(x)]"),
+            @"
+        warning: na_unsafe_string_check
+         --> <test>:1:4
+          |
+        1 | x[(nchar(x) > 0) & !is.na(x)]
+          |    ------------ `nchar(x) > 0` is evaluated before checking for missing values with `is.na(x)`.
+          |
+          = help: Check for missing values first: `!is.na(x) & nchar(x) > 0`.
+        Found 1 error.
+        "
+        );
+    }
+}
@@ -0,0 +1,237 @@
+use crate::diagnostic::*;
+use crate::utils::{get_function_name, get_unnamed_arg_by_position};
+use crate::utils_ast::AstNodeExt;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for emptiness checks on strings, such as `nchar(x) > 0` or
+/// `trimws(x) == ""`, that don't handle missing values:
+///
+/// - `nchar(x) > 0` used to filter a vector with `x[...]` without checking
+///   `is.na(x)`;
+/// - `nchar(x) > 0` or `trimws(x) == ""` placed before `is.na(x)` in the same
+///   condition.
+///
+/// ## Why is this bad?
+///
+/// `nchar()` and `trimws()` propagate missing values: `nchar(NA_character_)`
+/// is `NA`, so `x[nchar(x) > 0]` keeps the missing values of `x` (as `NA`)
+/// instead of removing them. Moreover, `nchar(NA)` is `2` when `x` is a
+/// logical `NA`, so the check silently passes.
+///
+/// When the same condition also contains `is.na(x)`, it should come first so
+/// that the string check is only evaluated on non-missing values. This makes
+/// the intent clearer and doesn't rely on the way `&&` and `||` combine `NA`
+/// with `TRUE` or `FALSE`.
+///
+/// This rule is disabled by default.
+///
+/// ## Example
+///
+/// ```r
+/// x <- c("a", "", NA)
+/// x[nchar(x) > 0]
+///
+/// if (trimws(y) == "" || is.na(y)) {
+///   stop("`y` must be provided.")
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// x <- c("a", "", NA)
+/// x[!is.na(x) & nchar(x) > 0]
+///
+/// if (is.na(y) || trimws(y) == "") {
+///   stop("`y` must be provided.")
+/// }
+/// ```
+///
+/// ## References
+///
+/// See `?nchar` and `?NA`
+pub fn na_unsafe_string_check(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>> {
+    let check = unwrap_or_return_none!(get_emptiness_check(ast)?);
+    let check_text = ast.syntax().text_trimmed().to_string();
+    let check_range = ast.syntax().text_trimmed_range();
+
+    let chain = get_logical_chain(ast);
+    let na_check = chain.as_ref().and_then(|chain| {
+        chain
+            .syntax()
+            .descendants()
+            .filter_map(RCall::cast)
+            .find(|call| is_na_call_on(call, &check.subject))
+    });
+
+    let (body, help) = match (&chain, na_check) {
+        (Some(chain), Some(na_check)) => {
+            // `is.na(x)` is already checked before, this is fine.
+            if na_check.syntax().text_trimmed_range().start() < check_range.start() {
+                return Ok(None);
+            }
+            // Keep the negation in `!is.na(x)`.
+            let na_check_text = match na_check.syntax().parent() {
+                Some(parent) if na_check.parent_is_bang_unary() => parent.text_trimmed(),
+                _ => na_check.syntax().text_trimmed(),
+            };
+            let operator = chain.operator()?;
+            (
+                format!(
+                    "`{check_text}` is evaluated before checking for missing values with `is.na({})`.",
+                    check.subject
+                ),
+                format!(
+                    "Check for missing values first: `{na_check_text} {} {check_text}`.",
+                    operator.text_trimmed()
+                ),
+            )
+        }
+        _ => {
+            let outer = chain
+                .as_ref()
+                .map(|chain| chain.syntax().clone())
+                .unwrap_or_else(|| ast.syntax().clone());
+            if check.function != "nchar" || !check.keeps_non_empty || !is_subset_index(&outer) {
+                return Ok(None);
+            }
+            (
+                format!("`{check_text}` is `NA` for missing values, so they are not removed."),
+                format!(
+                    "Check for missing values first: `!is.na({}) & {check_text}`.",
+                    check.subject
+                ),
+            )
+        }
+    };
+
+    let diagnostic = Diagnostic::new(
+        ViolationData::new("na_unsafe_string_check".to_string(), body, Some(help)),
+        check_range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+struct EmptinessCheck {
+    /// The function used for the check, `nchar` or `trimws`.
+    function: &'static str,
+    /// The string being checked, e.g. `x` in `nchar(trimws(x)) > 0`.
+    subject: String,
+    /// Whether the check is `TRUE` for non-empty strings.
+    keeps_non_empty: bool,
+}
+
+/// Detects `nchar(x) <op> 0|1` and `trimws(x) ==|!= ""`, in both directions.
+fn get_emptiness_check(ast: &RBinaryExpression) -> anyhow::Result<Option<EmptinessCheck>> {
+    let RBinaryExpressionFields { left, operator, right } = ast.as_fields();
+    let left = left?;
+    let operator = operator?;
+    let right = right?;
+
+    // Normalize the comparison so that the call is on the left-hand side.
+    let (call, value, operator) = match (left.as_r_call(), right.as_r_call()) {
+        (Some(call), None) => (call.clone(), right, operator.kind()),
+        (None, Some(call)) => {
+            let flipped = match operator.kind() {
+                RSyntaxKind::GREATER_THAN => RSyntaxKind::LESS_THAN,
+                RSyntaxKind::GREATER_THAN_OR_EQUAL_TO => RSyntaxKind::LESS_THAN_OR_EQUAL_TO,
+                RSyntaxKind::LESS_THAN => RSyntaxKind::GREATER_THAN,
+                RSyntaxKind::LESS_THAN_OR_EQUAL_TO => RSyntaxKind::GREATER_THAN_OR_EQUAL_TO,
+                kind => kind,
+            };
+            (call.clone(), left, flipped)
+        }
+        _ => return Ok(None),
+    };
+
+    let function = get_function_name(call.function()?);
+    let value = value.to_trimmed_string();
+
+    let (function, keeps_non_empty) = match function.as_str() {
+        "nchar" => {
+            let keeps_non_empty = match (operator, value.as_str()) {
+                (RSyntaxKind::GREATER_THAN | RSyntaxKind::NOT_EQUAL, "0" | "0L") => true,
+                (RSyntaxKind::GREATER_THAN_OR_EQUAL_TO, "1" | "1L") => true,
+                (RSyntaxKind::EQUAL2 | RSyntaxKind::LESS_THAN_OR_EQUAL_TO, "0" | "0L") => false,
+                (RSyntaxKind::LESS_THAN, "1" | "1L") => false,
+                _ => return Ok(None),
+            };
+            ("nchar", keeps_non_empty)
+        }
+        "trimws" => {
+            if value != "\"\"" && value != "''" {
+                return Ok(None);
+            }
+            match operator {
+                RSyntaxKind::NOT_EQUAL => ("trimws", true),
+                RSyntaxKind::EQUAL2 => ("trimws", false),
+                _ => return Ok(None),
+            }
+        }
+        _ => return Ok(None),
+    };
+
+    let subject = unwrap_or_return_none!(get_first_arg(&call));
+    // `nchar(trimws(x)) > 0` checks `x`.
+    let subject = match subject.as_r_call() {
+        Some(inner) if function == "nchar" && get_function_name(inner.function()?) == "trimws" => {
+            unwrap_or_return_none!(get_first_arg(inner))
+        }
+        _ => subject,
+    };
+
+    Ok(Some(EmptinessCheck {
+        function,
+        subject: subject.to_trimmed_string(),
+        keeps_non_empty,
+    }))
+}
+
+fn get_first_arg(call: &RCall) -> Option<AnyRExpression> {
+    let args = call.arguments().ok()?.items();
+    get_unnamed_arg_by_position(&args, 1)?.value()
+}
+
+fn is_na_call_on(call: &RCall, subject: &str) -> bool {
+    call.function()
+        .is_ok_and(|function| get_function_name(function) == "is.na")
+        && get_first_arg(call).is_some_and(|arg| arg.to_trimmed_string() == subject)
+}
+
+/// Returns the outermost chain of `&`, `&&`, `|`, and `||` containing `ast`,
+/// looking through parentheses.
+fn get_logical_chain(ast: &RBinaryExpression) -> Option<RBinaryExpression> {
+    let mut chain = None;
+    let mut current = ast.syntax().parent();
+    while let Some(node) = current {
+        if let Some(binary) = RBinaryExpression::cast(node.clone())
+            && binary.operator().is_ok_and(|op| {
+                matches!(
+                    op.kind(),
+                    RSyntaxKind::AND | RSyntaxKind::AND2 | RSyntaxKind::OR | RSyntaxKind::OR2
+                )
+            })
+        {
+            chain = Some(binary);
+        } else if node.kind() != RSyntaxKind::R_PARENTHESIZED_EXPRESSION {
+            break;
+        }
+        current = node.parent();
+    }
+    chain
+}
+
+/// Whether `node` is the index in `x[...]`.
+fn is_subset_index(node: &RSyntaxNode) -> bool {
+    node.ancestors().skip(1).take(3).map(|n| n.kind()).eq([
+        RSyntaxKind::R_ARGUMENT,
+        RSyntaxKind::R_ARGUMENT_LIST,
+        RSyntaxKind::R_SUBSET_ARGUMENTS,
+    ])
+}
@@ -0,0 +1,166 @@
+pub(crate) mod namespace_modification;
+pub(crate) mod options;
+
+#[cfg(test)]
+mod tests {
+    use crate::check::check;
+    use crate::config::{ArgsConfig, build_config};
+    use crate::file_system::MemoryFileSystem;
+    use crate::lints::base::namespace_modification::options::NamespaceModificationOptions;
+    use crate::lints::base::namespace_modification::options::ResolvedNamespaceModificationOptions;
+    use crate::rule_options::ResolvedRuleOptions;
+    use crate::settings::{LinterSettings, Settings};
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "namespace_modification", None)
+    }
+
+    /// Code of the diagnostics in the R files made of `files`.
+    fn lint_files(files: &[(&str, &str)], options: NamespaceModificationOptions) -> Vec<String> {
+        let fs = files
+            .iter()
+            .fold(MemoryFileSystem::new(), |fs, (path, contents)| {
+                fs.with_file(path, contents)
+            });
+        let paths: Vec<PathBuf> = files.iter().map(|(path, _)| PathBuf::from(path)).collect();
+        let args = ArgsConfig {
+            files: vec![],
+            fix: false,
+            unsafe_fixes: false,
+            unsafe_fixes_rules: String::new(),
+            fix_only: false,
+            select: "namespace_modification".to_string(),
+            extend_select: String::new(),
+            ignore: String::new(),
+            min_r_version: None,
+            allow_dirty: false,
+            allow_no_vcs: true,
+            respect_staged: false,
+            assignment: None,
+            preview: false,
+            profile: None,
+        };
+        let settings = Settings {
+            linter: LinterSettings {
+                rule_options: ResolvedRuleOptions {
+                    namespace_modification: ResolvedNamespaceModificationOptions::resolve(Some(
+                        &options,
+                    ))
+                    .unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut config = build_config(&args, Some(&settings), paths).unwrap();
+        config.file_system = Arc::new(fs);
+
+        let mut results: Vec<String> = check(config)
+            .into_iter()
+            .flat_map(|(path, result)| {
+                let contents = files.iter().find(|(p, _)| *p == path).unwrap().1;
+                result
+                    .unwrap()
+                    .into_iter()
+                    .map(|d| {
+                        contents[usize::from(d.range.start())..usize::from(d.range.end())]
+                            .to_string()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        results.sort();
+        results
+    }
+
+    #[test]
+    fn test_no_lint_namespace_modification() {
+        expect_no_lint(
+            "assign('x', 1, envir = env)",
+            "namespace_modification",
+            None,
+        );
+        expect_no_lint("lockBinding('x', env)", "namespace_modification", None);
+        expect_no_lint("asNamespace('utils')", "namespace_modification", None);
+        expect_no_lint(
+            "foo::assignInNamespace('f', g, 'pkg')",
+            "namespace_modification",
+            None,
+        );
+        expect_no_lint("base::env_unlock(env)", "namespace_modification", None);
+    }
+
+    #[test]
+    fn test_lint_namespace_modification() {
+        assert_snapshot!(
+            snapshot_lint("assignInNamespace('f', g, ns = 'pkg')"),
+            @"
+        warning: namespace_modification
+         --> <test>:1:1
+          |
+        1 | assignInNamespace('f', g, ns = 'pkg')
+          | ------------------------------------- `assignInNamespace()` modifies locked bindings, which is fragile and not allowed on CRAN.
+          |
+          = help: Pass the value as an argument or use an option instead, or mock functions with `testthat::local_mocked_bindings()` in tests.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("base::unlockBinding('f', asNamespace('pkg'))"),
+            @"
+        warning: namespace_modification
+         --> <test>:1:1
+          |
+        1 | base::unlockBinding('f', asNamespace('pkg'))
+          | -------------------------------------------- `unlockBinding()` modifies locked bindings, which is fragile and not allowed on CRAN.
+          |
+          = help: Pass the value as an argument or use an option instead, or mock functions with `testthat::local_mocked_bindings()` in tests.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("rlang::env_unlock(ns_env('pkg'))"),
+            @"
+        warning: namespace_modification
+         --> <test>:1:1
+          |
+        1 | rlang::env_unlock(ns_env('pkg'))
+          | -------------------------------- `env_unlock()` modifies locked bindings, which is fragile and not allowed on CRAN.
+          |
+          = help: Pass the value as an argument or use an option instead, or mock functions with `testthat::local_mocked_bindings()` in tests.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_namespace_modification_allow_in_tests() {
+        let files = [
+            ("mypkg/R/a.R", "utils::fixInNamespace('f', 'pkg')\n"),
+            (
+                "mypkg/tests/testthat/test-a.R",
+                "assignInNamespace('f', g, 'pkg')\n",
+            ),
+        ];
+
+        assert_eq!(
+            lint_files(&files, NamespaceModificationOptions::default()),
+            vec![
+                "assignInNamespace('f', g, 'pkg')",
+                "utils::fixInNamespace('f', 'pkg')"
+            ]
+        );
+        assert_eq!(
+            lint_files(
+                &files,
+                NamespaceModificationOptions { allow_in_tests: Some(true) }
+            ),
+            vec!["utils::fixInNamespace('f', 'pkg')"]
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use crate::checker::Checker;
+use crate::diagnostic::*;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for calls to functions that modify the namespace of a package or
+/// unlock the bindings of an environment:
+///
+/// - `assignInNamespace()` and `fixInNamespace()`;
+/// - `unlockBinding()`;
+/// - `rlang::env_unlock()` and `rlang::env_binding_unlock()`.
+///
+/// Use `allow-in-tests = true` in `[lint.namespace_modification]` to ignore
+/// test files (i.e. files in a `tests/` or `tinytest/` folder), where mocking
+/// frameworks may need these functions.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Why is this bad?
+///
+/// Namespaces and their bindings are locked so that the functions of a
+/// package can't be changed while it is used. Working around this makes the
+/// code depend on the internals of another package, which may change without
+/// notice, and changes the behavior of this package for all of its users in
+/// the session.
+///
+/// The CRAN policies forbid packages from modifying the namespaces of other
+/// packages, and `R CMD check` reports calls to `assignInNamespace()` and
+/// `unlockBinding()`.
+///
+/// This rule is in preview, so it is only used with `--preview` or
+/// `preview = true` in `jarl.toml`.
+///
+/// ## Example
+///
+/// ```r
+/// assignInNamespace("download.file", my_download, ns = "utils")
+/// ```
+///
+/// Use instead:
+/// ```r
+/// # Pass the function as an argument, or use an option.
+/// fetch <- function(url, destfile, download = utils::download.file) {
+///   download(url, destfile)
+/// }
+/// ```
+///
+/// In tests, use `testthat::local_mocked_bindings()` instead.
+///
+/// ## References
+///
+/// See the section "Source packages" in the [CRAN Repository Policy](https://cran.r-project.org/web/packages/policies.html).
+pub fn namespace_modification(
+    ast: &RCall,
+    fn_name: &str,
+    ns_prefix: Option<&str>,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    let is_modification = match fn_name {
+        "assignInNamespace" | "fixInNamespace" => matches!(ns_prefix, None | Some("utils::")),
+        "unlockBinding" => matches!(ns_prefix, None | Some("base::")),
+        "env_unlock" | "env_binding_unlock" => matches!(ns_prefix, None | Some("rlang::")),
+        _ => false,
+    };
+    if !is_modification {
+        return Ok(None);
+    }
+
+    if checker.rule_options.namespace_modification.allow_in_tests && checker.is_test_file {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "namespace_modification".to_string(),
+            format!(
+                "`{fn_name}()` modifies locked bindings, which is fragile and not allowed on CRAN."
+            ),
+            Some(
+                "Pass the value as an argument or use an option instead, or mock functions with `testthat::local_mocked_bindings()` in tests."
+                    .to_string(),
+            ),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
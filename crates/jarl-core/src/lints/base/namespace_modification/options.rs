@@ -0,0 +1,27 @@
+/// TOML options for `[lint.namespace_modification]`.
+///
+/// Use `allow-in-tests` to allow modifying namespaces in test files, e.g.
+/// when a mocking framework requires it.
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct NamespaceModificationOptions {
+    pub allow_in_tests: Option<bool>,
+}
+
+/// Resolved options for the `namespace_modification` rule, ready for use
+/// during linting.
+#[derive(Clone, Debug)]
+pub struct ResolvedNamespaceModificationOptions {
+    pub allow_in_tests: bool,
+}
+
+impl ResolvedNamespaceModificationOptions {
+    pub fn resolve(options: Option<&NamespaceModificationOptions>) -> anyhow::Result<Self> {
+        let allow_in_tests = options
+            .and_then(|opts| opts.allow_in_tests)
+            .unwrap_or(false);
+
+        Ok(Self { allow_in_tests })
+    }
+}
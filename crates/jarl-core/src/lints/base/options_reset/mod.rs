@@ -0,0 +1,103 @@
+pub(crate) mod options_reset;
+
+#[cfg(test)]
+mod tests {
+    use crate::check::check;
+    use crate::config::{ArgsConfig, build_config};
+    use crate::file_system::MemoryFileSystem;
+    use crate::utils_test::*;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    /// Messages of the diagnostics in the R files of the package made of
+    /// `files`.
+    fn lint_package(files: &[(&str, &str)]) -> Vec<(String, Vec<String>)> {
+        let fs = files
+            .iter()
+            .fold(MemoryFileSystem::new(), |fs, (path, contents)| {
+                fs.with_file(path, contents)
+            });
+        let paths: Vec<PathBuf> = files
+            .iter()
+            .filter(|(path, _)| path.ends_with(".R"))
+            .map(|(path, _)| PathBuf::from(path))
+            .collect();
+        let args = ArgsConfig {
+            files: vec![],
+            fix: false,
+            unsafe_fixes: false,
+            unsafe_fixes_rules: String::new(),
+            fix_only: false,
+            select: "options_reset".to_string(),
+            extend_select: String::new(),
+            ignore: String::new(),
+            min_r_version: None,
+            allow_dirty: false,
+            allow_no_vcs: true,
+            respect_staged: false,
+            assignment: None,
+            preview: false,
+            profile: None,
+        };
+        let mut config = build_config(&args, None, paths).unwrap();
+        config.file_system = Arc::new(fs);
+
+        let mut results: Vec<(String, Vec<String>)> = check(config)
+            .into_iter()
+            .map(|(path, result)| {
+                let messages = result
+                    .unwrap()
+                    .into_iter()
+                    .map(|d| d.message.body)
+                    .collect();
+                (path, messages)
+            })
+            .collect();
+        results.sort();
+        results
+    }
+
+    #[test]
+    fn test_no_lint_options_reset_outside_package() {
+        expect_no_lint("f <- function() setwd('data')", "options_reset", None);
+    }
+
+    #[test]
+    fn test_options_reset() {
+        let results = lint_package(&[
+            ("statepkg/DESCRIPTION", "Package: statepkg"),
+            (
+                "statepkg/R/bad.R",
+                "f <- function(dir) {\n  setwd(dir)\n}\n\ng <- function() {\n  base::options(digits = 3)\n  print(pi)\n}\n\nh <- function(x) {\n  old <- par(mfrow = c(1, 2))\n  on.exit(options(old))\n  plot(x)\n}\n",
+            ),
+            (
+                "statepkg/R/good.R",
+                "f <- function(dir) {\n  old <- setwd(dir)\n  on.exit(setwd(old), add = TRUE)\n}\n\ng <- function() {\n  old <- options(digits = 3)\n  on.exit(options(old))\n  print(pi)\n}\n\nh <- function() {\n  on.exit(par(mfrow = c(1, 1)))\n  par(mfrow = c(1, 2))\n}\n\ni <- function() {\n  options('digits')\n  par('mfrow')\n}\n\noptions(digits = 3)\n",
+            ),
+            (
+                "statepkg/R/nested.R",
+                "f <- function() {\n  par(mar = c(0, 0, 0, 0))\n  g <- function() on.exit(par(old))\n}\n",
+            ),
+        ]);
+        assert_eq!(
+            results,
+            vec![
+                (
+                    "statepkg/R/bad.R".to_string(),
+                    vec![
+                        "`setwd()` changes the working directory without restoring it with `on.exit()`.".to_string(),
+                        "`options()` changes global options without restoring them with `on.exit()`.".to_string(),
+                        "`par()` changes graphical parameters without restoring them with `on.exit()`.".to_string(),
+                    ]
+                ),
+                ("statepkg/R/good.R".to_string(), vec![]),
+                (
+                    "statepkg/R/nested.R".to_string(),
+                    vec![
+                        "`par()` changes graphical parameters without restoring them with `on.exit()`.".to_string(),
+                    ]
+                ),
+            ]
+        );
+    }
+}
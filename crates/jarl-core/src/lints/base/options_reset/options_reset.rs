@@ -0,0 +1,140 @@
+use crate::checker::Checker;
+use crate::diagnostic::*;
+use crate::package::FileScope;
+use crate::utils::{enclosing_scope, get_function_name};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for functions in the `R/` folder of a package that change the
+/// global state of the R session with `setwd()`, `options()` or `par()`
+/// without restoring it with `on.exit()` in the same function.
+///
+/// `options()` and `par()` are only reported when they set a value, i.e. when
+/// they have a named argument such as `options(digits = 3)`. Calls in the code
+/// of `on.exit()` are not reported since they restore the previous state.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Why is this bad?
+///
+/// Calling a function of a package shouldn't change the working directory,
+/// the options, or the graphical parameters of the user, since this changes
+/// the behavior of the code that runs afterwards. The CRAN policies require
+/// packages to restore them, and using `on.exit()` ensures that they are
+/// restored even if the function throws an error.
+///
+/// This rule is in preview, so it is only used with `--preview` or
+/// `preview = true` in `jarl.toml`.
+///
+/// ## Example
+///
+/// ```r
+/// plot_pair <- function(x, y) {
+///   par(mfrow = c(1, 2))
+///   plot(x)
+///   plot(y)
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// plot_pair <- function(x, y) {
+///   old <- par(mfrow = c(1, 2))
+///   on.exit(par(old), add = TRUE)
+///   plot(x)
+///   plot(y)
+/// }
+/// ```
+///
+/// ## References
+///
+/// - See the section "Source packages" in the [CRAN Repository Policy](https://cran.r-project.org/web/packages/policies.html)
+/// - See `?on.exit`
+pub fn options_reset(
+    ast: &RCall,
+    fn_name: &str,
+    ns_prefix: Option<&str>,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if checker.file_scope != Some(FileScope::R) {
+        return Ok(None);
+    }
+
+    let (state, pronoun, is_setter) = match (fn_name, ns_prefix) {
+        ("setwd", None | Some("base::")) => ("the working directory", "it", true),
+        ("options", None | Some("base::")) => ("global options", "them", has_named_arg(ast)?),
+        ("par", None | Some("graphics::")) => ("graphical parameters", "them", has_named_arg(ast)?),
+        _ => return Ok(None),
+    };
+    if !is_setter {
+        return Ok(None);
+    }
+
+    let node = ast.syntax();
+    let scope = enclosing_scope(node);
+    if scope.kind() != RSyntaxKind::R_FUNCTION_DEFINITION {
+        return Ok(None);
+    }
+
+    // Calls in `on.exit()` restore the state. Otherwise, the state must be
+    // restored by a call to the same function in `on.exit()`.
+    let in_on_exit = node
+        .ancestors()
+        .skip(1)
+        .take_while(|ancestor| ancestor != &scope)
+        .filter_map(RCall::cast)
+        .any(|call| is_on_exit(&call));
+    if in_on_exit {
+        return Ok(None);
+    }
+    let is_restored = scope
+        .descendants()
+        .filter_map(RCall::cast)
+        .filter(|call| is_on_exit(call) && enclosing_scope(call.syntax()) == scope)
+        .any(|call| {
+            call.syntax()
+                .descendants()
+                .filter_map(RCall::cast)
+                .any(|inner| {
+                    inner
+                        .function()
+                        .is_ok_and(|f| get_function_name(f) == fn_name)
+                })
+        });
+    if is_restored {
+        return Ok(None);
+    }
+
+    let range = node.text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "options_reset".to_string(),
+            format!("`{fn_name}()` changes {state} without restoring {pronoun} with `on.exit()`."),
+            Some(format!(
+                "Store the previous value with `old <- {fn_name}(...)` and restore it with `on.exit({fn_name}(old), add = TRUE)`."
+            )),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+fn has_named_arg(ast: &RCall) -> anyhow::Result<bool> {
+    Ok(ast
+        .arguments()?
+        .items()
+        .iter()
+        .filter_map(|arg| arg.ok())
+        .any(|arg| arg.name_clause().is_some()))
+}
+
+fn is_on_exit(call: &RCall) -> bool {
+    call.function()
+        .is_ok_and(|f| get_function_name(f) == "on.exit")
+}
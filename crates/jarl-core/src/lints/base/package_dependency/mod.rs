@@ -0,0 +1,15 @@
+pub(crate) mod package_dependency;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    // Cases in R packages are tested in the integration tests of the CLI since
+    // they require a DESCRIPTION file.
+    #[test]
+    fn test_no_lint_package_dependency_outside_package() {
+        expect_no_lint("library(tidyverse)", "package_dependency", None);
+        expect_no_lint("require('tidyverse')", "package_dependency", None);
+        expect_no_lint("ggplot2::ggplot(x)", "package_dependency", None);
+    }
+}
@@ -0,0 +1,190 @@
+use crate::checker::Checker;
+use crate::diagnostic::*;
+use crate::package::FileScope;
+use crate::utils::{get_arg_by_name_then_position, get_function_name};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for problematic uses of dependencies in the `R/` folder of a
+/// package:
+///
+/// - attaching a meta-package such as `tidyverse` with `library()` or
+///   `require()`;
+/// - using a package that is only listed in the `Suggests` field of the
+///   DESCRIPTION file, e.g. `pkg::fun()`, without checking that it is
+///   installed.
+///
+/// A package is considered checked if the function that uses it (or the file,
+/// for top-level code) also calls `requireNamespace("pkg")`,
+/// `rlang::check_installed("pkg")`, `rlang::is_installed("pkg")`, or a similar
+/// function.
+///
+/// ## Why is this bad?
+///
+/// Meta-packages attach dozens of packages, most of which are not used by the
+/// package. Packages should declare the packages they use in the `Imports`
+/// field of DESCRIPTION instead.
+///
+/// Packages in `Suggests` are not necessarily installed when the package is
+/// installed. Using them unconditionally fails at runtime for users who don't
+/// have them, and `R CMD check` can't detect it.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Example
+///
+/// With `ggplot2` in `Suggests`:
+///
+/// ```r
+/// library(tidyverse)
+///
+/// plot_data <- function(x) {
+///   ggplot2::ggplot(x)
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// plot_data <- function(x) {
+///   rlang::check_installed("ggplot2")
+///   ggplot2::ggplot(x)
+/// }
+/// ```
+///
+/// ## References
+///
+/// See the section "Suggests" in [R Packages](https://r-pkgs.org/dependencies-in-practice.html#sec-dependencies-in-suggests).
+pub fn package_dependency_attach(
+    ast: &RCall,
+    fn_name: &str,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if checker.file_scope != Some(FileScope::R) || !matches!(fn_name, "library" | "require") {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+    let package = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "package", 1));
+    let package = unwrap_or_return_none!(package.value());
+    let package = unwrap_or_return_none!(package_name(&package));
+    if !META_PACKAGES.contains(&package.as_str()) {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "package_dependency".to_string(),
+            format!("`{fn_name}({package})` attaches many packages in package code."),
+            Some(
+                "List the packages that are used in the `Imports` field of DESCRIPTION instead."
+                    .to_string(),
+            ),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+pub fn package_dependency_namespace(
+    ast: &RNamespaceExpression,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if checker.file_scope != Some(FileScope::R) {
+        return Ok(None);
+    }
+
+    let package = unwrap_or_return_none!(package_name(&ast.left()?));
+    if !checker.suggested_packages.contains(&package) || is_checked(ast.syntax(), &package) {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "package_dependency".to_string(),
+            format!(
+                "`{package}` is only in `Suggests` but is used without checking that it is installed."
+            ),
+            Some(format!(
+                "Call `rlang::check_installed(\"{package}\")` first, or move `{package}` to `Imports`."
+            )),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Packages that only attach a collection of other packages.
+const META_PACKAGES: &[&str] = &["easystats", "fpp3", "tidymodels", "tidyverse", "tidyverts"];
+
+/// Functions that check whether a package is installed.
+const CHECK_FUNCTIONS: &[&str] = &[
+    "check_installed",
+    "is_installed",
+    "loadNamespace",
+    "require",
+    "requireNamespace",
+];
+
+/// Name of a package passed as an identifier or a string.
+fn package_name(value: &AnyRExpression) -> Option<String> {
+    let name = value.to_trimmed_string();
+    if value.as_r_identifier().is_some() {
+        return Some(name);
+    }
+    let is_string = value
+        .as_any_r_value()
+        .is_some_and(|v| v.as_r_string_value().is_some());
+    if is_string {
+        return Some(name[1..name.len() - 1].to_string());
+    }
+    None
+}
+
+/// Whether the enclosing function (or the file for top-level code) checks that
+/// `package` is installed.
+fn is_checked(node: &RSyntaxNode, package: &str) -> bool {
+    let scope = node
+        .ancestors()
+        .find(|ancestor| ancestor.kind() == RSyntaxKind::R_FUNCTION_DEFINITION)
+        .or_else(|| node.ancestors().last());
+    let Some(scope) = scope else {
+        return false;
+    };
+
+    scope.descendants().filter_map(RCall::cast).any(|call| {
+        let Ok(function) = call.function() else {
+            return false;
+        };
+        if !CHECK_FUNCTIONS.contains(&get_function_name(function).as_str()) {
+            return false;
+        }
+        // The first argument can also be a vector of packages, e.g.
+        // `rlang::check_installed(c("pkg1", "pkg2"))`.
+        let Ok(arguments) = call.arguments() else {
+            return false;
+        };
+        let Some(value) = arguments
+            .items()
+            .iter()
+            .next()
+            .and_then(|arg| arg.ok())
+            .and_then(|arg| arg.value())
+        else {
+            return false;
+        };
+        let text = value.to_trimmed_string();
+        package_name(&value).as_deref() == Some(package)
+            || text.contains(&format!("\"{package}\""))
+            || text.contains(&format!("'{package}'"))
+    })
+}
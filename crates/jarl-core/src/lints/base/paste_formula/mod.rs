@@ -0,0 +1,101 @@
+pub(crate) mod paste_formula;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "paste_formula", None)
+    }
+
+    #[test]
+    fn test_no_lint_paste_formula() {
+        expect_no_lint("as.formula(y ~ x)", "paste_formula", None);
+        expect_no_lint("as.formula(f)", "paste_formula", None);
+        expect_no_lint("as.formula('y ~ x')", "paste_formula", None);
+        expect_no_lint("reformulate(vars, response = 'y')", "paste_formula", None);
+        expect_no_lint("formula(paste('y ~', x))", "paste_formula", None);
+        expect_no_lint("foo::as.formula(paste('y ~', x))", "paste_formula", None);
+        expect_no_lint("as.formula(glue::paste('y ~', x))", "paste_formula", None);
+        expect_no_lint("paste('y ~', x)", "paste_formula", None);
+    }
+
+    #[test]
+    fn test_lint_paste_formula() {
+        assert_snapshot!(
+            snapshot_lint("as.formula(paste('y ~', x))"),
+            @"
+        warning: paste_formula
+         --> <test>:1:1
+          |
+        1 | as.formula(paste('y ~', x))
+          | --------------------------- `as.formula()` is called on a string built with `paste()`.
+          |
+          = help: Use `reformulate()` with a vector of terms instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("stats::as.formula(paste0('y~', x))"),
+            @"
+        warning: paste_formula
+         --> <test>:1:1
+          |
+        1 | stats::as.formula(paste0('y~', x))
+          | ---------------------------------- `as.formula()` is called on a string built with `paste0()`.
+          |
+          = help: Use `reformulate()` with a vector of terms instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("reformulate(paste(vars, collapse = ' + '))"),
+            @"
+        warning: paste_formula
+         --> <test>:1:1
+          |
+        1 | reformulate(paste(vars, collapse = ' + '))
+          | ------------------------------------------ `reformulate()` is called on a string built with `paste()`.
+          |
+          = help: Pass the vector of terms to `reformulate()` directly.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_fix_paste_formula() {
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "as.formula(paste('y ~', x))",
+                    "as.formula(paste(\"log(y) ~ \", terms))",
+                    "stats::as.formula(object = paste('y~', x))",
+                ],
+                "paste_formula",
+                None
+            )
+        );
+
+        // No fix for one-sided formulas, custom separators, other arguments,
+        // or `paste0()`.
+        assert_snapshot!(
+            "no_fix_output",
+            get_fixed_text(
+                vec![
+                    "as.formula(paste('~', x))",
+                    "as.formula(paste(y, '~', x))",
+                    "as.formula(paste(lhs, x))",
+                    "as.formula(paste('y ~', x, sep = ''))",
+                    "as.formula(paste('y ~', x), env = e)",
+                    "as.formula(paste0('y ~ ', x))",
+                    "reformulate(paste(vars, collapse = ' + '))",
+                ],
+                "paste_formula",
+                None
+            )
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use crate::diagnostic::*;
+use crate::utils::{
+    get_arg_by_name, get_arg_by_name_then_position, get_function_name,
+    get_function_namespace_prefix, node_contains_comments,
+};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for calls to `as.formula()` and `reformulate()` whose input is a
+/// string built with `paste()` or `paste0()`.
+///
+/// This rule is disabled by default.
+///
+/// ## Why is this bad?
+///
+/// Building a formula by pasting strings is error-prone: variable names that
+/// contain spaces or special characters must be quoted with backticks, and
+/// the string is parsed as R code, so a column name coming from user input can
+/// inject arbitrary code in the formula. `reformulate()` takes a vector of
+/// terms and the name of the response, which makes the intent clearer.
+///
+/// This rule has a safe fix for the simple two-sided case
+/// `as.formula(paste("y ~", x))`, which is replaced by
+/// `reformulate(x, response = "y")`.
+///
+/// ## Example
+///
+/// ```r
+/// as.formula(paste("y ~", x))
+/// as.formula(paste("y ~", paste(vars, collapse = " + ")))
+/// reformulate(paste(vars, collapse = " + "))
+/// ```
+///
+/// Use instead:
+/// ```r
+/// reformulate(x, response = "y")
+/// reformulate(vars, response = "y")
+/// reformulate(vars)
+/// ```
+///
+/// ## References
+///
+/// See `?reformulate`
+pub fn paste_formula(
+    ast: &RCall,
+    fn_name: &str,
+    ns_prefix: Option<&str>,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if fn_name != "as.formula" && fn_name != "reformulate" {
+        return Ok(None);
+    }
+    if ns_prefix.is_some_and(|ns| ns != "stats::") {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+    let main_arg = if fn_name == "as.formula" {
+        "object"
+    } else {
+        "termlabels"
+    };
+    let value = unwrap_or_return_none!(get_arg_by_name_then_position(&args, main_arg, 1));
+    let value = unwrap_or_return_none!(value.value());
+    let paste_call = unwrap_or_return_none!(value.as_r_call());
+    let paste_fn = paste_call.function()?;
+    let paste_name = get_function_name(paste_fn.clone());
+    if paste_name != "paste" && paste_name != "paste0" {
+        return Ok(None);
+    }
+    if get_function_namespace_prefix(paste_fn).is_some_and(|ns| ns != "base::") {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+
+    if fn_name == "reformulate" {
+        let diagnostic = Diagnostic::new(
+            ViolationData::new(
+                "paste_formula".to_string(),
+                format!("`reformulate()` is called on a string built with `{paste_name}()`."),
+                Some("Pass the vector of terms to `reformulate()` directly.".to_string()),
+            ),
+            range,
+            Fix::empty(),
+        );
+        return Ok(Some(diagnostic));
+    }
+
+    // Other arguments, such as `env`, can't be passed to `reformulate()`.
+    let fix = if args.iter().count() == 1 {
+        match two_sided_fix(paste_call, &paste_name)? {
+            Some((response, terms)) => Fix {
+                content: format!(
+                    "{}reformulate({terms}, response = \"{response}\")",
+                    ns_prefix.unwrap_or_default()
+                ),
+                start: range.start().into(),
+                end: range.end().into(),
+                to_skip: node_contains_comments(ast.syntax()),
+            },
+            None => Fix::empty(),
+        }
+    } else {
+        Fix::empty()
+    };
+
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "paste_formula".to_string(),
+            format!("`as.formula()` is called on a string built with `{paste_name}()`."),
+            Some("Use `reformulate()` with a vector of terms instead.".to_string()),
+        ),
+        range,
+        fix,
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// For `paste("y ~", x)`, return the response `y` and the terms `x`.
+fn two_sided_fix(paste_call: &RCall, paste_name: &str) -> anyhow::Result<Option<(String, String)>> {
+    if paste_name != "paste" {
+        return Ok(None);
+    }
+    let args = paste_call.arguments()?.items();
+    if get_arg_by_name(&args, "sep").is_some() || get_arg_by_name(&args, "collapse").is_some() {
+        return Ok(None);
+    }
+    let args: Vec<RArgument> = args.iter().filter_map(|arg| arg.ok()).collect();
+    let [lhs, terms] = args.as_slice() else {
+        return Ok(None);
+    };
+    if lhs.name_clause().is_some() || terms.name_clause().is_some() {
+        return Ok(None);
+    }
+
+    let lhs = unwrap_or_return_none!(lhs.value());
+    let lhs = unwrap_or_return_none!(lhs.as_any_r_value().and_then(|v| v.as_r_string_value()));
+    let lhs = lhs.to_trimmed_string();
+    // Only simple quoted strings, e.g. `"y ~"` or `'y ~'`.
+    if lhs.len() < 2 || !(lhs.starts_with('"') || lhs.starts_with('\'')) {
+        return Ok(None);
+    }
+    let lhs = &lhs[1..lhs.len() - 1];
+    let Some(response) = lhs.trim_end().strip_suffix('~') else {
+        return Ok(None);
+    };
+    let response = response.trim();
+    if response.is_empty() || response.contains(['"', '\'', '\\', '~']) {
+        return Ok(None);
+    }
+
+    let terms = unwrap_or_return_none!(terms.value());
+    Ok(Some((response.to_string(), terms.to_trimmed_string())))
+}
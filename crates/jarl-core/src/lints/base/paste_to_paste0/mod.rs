@@ -0,0 +1,176 @@
+pub(crate) mod paste_to_paste0;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "paste_to_paste0", None)
+    }
+
+    #[test]
+    fn test_no_lint_paste_to_paste0() {
+        expect_no_lint("paste('a', 'b')", "paste_to_paste0", None);
+        expect_no_lint("paste('a', 'b', sep = ' ')", "paste_to_paste0", None);
+        expect_no_lint("paste('a', 'b', sep = '-')", "paste_to_paste0", None);
+        expect_no_lint("paste('a', 'b', sep = x)", "paste_to_paste0", None);
+        expect_no_lint("paste('a', 'b', collapse = '')", "paste_to_paste0", None);
+        expect_no_lint("paste0('a', 'b')", "paste_to_paste0", None);
+        expect_no_lint("paste0('a', 'b', collapse = '-')", "paste_to_paste0", None);
+        expect_no_lint("foo::paste('a', 'b', sep = '')", "paste_to_paste0", None);
+        expect_no_lint("foo::paste0('a', sep = '-')", "paste_to_paste0", None);
+        expect_no_lint("paste('a', 'b', sep =)", "paste_to_paste0", None);
+    }
+
+    #[test]
+    fn test_lint_paste_to_paste0() {
+        assert_snapshot!(
+            snapshot_lint("paste('a', 'b', sep = '')"),
+            @r#"
+        warning: paste_to_paste0
+         --> <test>:1:1
+          |
+        1 | paste('a', 'b', sep = '')
+          | ------------------------- `paste(..., sep = "")` can be simplified.
+          |
+          = help: Use `paste0(...)` instead.
+        Found 1 error.
+        "#
+        );
+        assert_snapshot!(
+            snapshot_lint("base::paste(sep = \"\", x, collapse = ', ')"),
+            @r#"
+        warning: paste_to_paste0
+         --> <test>:1:1
+          |
+        1 | base::paste(sep = "", x, collapse = ', ')
+          | ----------------------------------------- `paste(..., sep = "")` can be simplified.
+          |
+          = help: Use `paste0(...)` instead.
+        Found 1 error.
+        "#
+        );
+        assert_snapshot!(
+            snapshot_lint("paste0('a', 'b', sep = '-')"),
+            @"
+        warning: paste_to_paste0
+         --> <test>:1:1
+          |
+        1 | paste0('a', 'b', sep = '-')
+          | --------------------------- `paste0()` doesn't have a `sep` argument, its value is pasted with the other inputs.
+          |
+          = help: Use `paste(..., sep = )` to set a separator.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("paste0(x, sep = '')"),
+            @"
+        warning: paste_to_paste0
+         --> <test>:1:1
+          |
+        1 | paste0(x, sep = '')
+          | ------------------- `paste0()` doesn't have a `sep` argument, its value is pasted with the other inputs.
+          |
+          = help: Use `paste(..., sep = )` to set a separator.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_fix_paste_to_paste0() {
+        assert_snapshot!(
+            get_fixed_text(
+                vec![
+                    "paste('a', 'b', sep = '')",
+                    "paste(x, y, sep = \"\", collapse = '-')",
+                    "base::paste(sep = '', x)",
+                    "paste(sep = '')",
+                    // No fix for `paste0()` with `sep`
+                    "paste0('a', 'b', sep = '-')",
+                ],
+                "paste_to_paste0",
+                None,
+            ),
+            @r#"
+        OLD:
+        ====
+        paste('a', 'b', sep = '')
+        NEW:
+        ====
+        paste0('a', 'b')
+
+        OLD:
+        ====
+        paste(x, y, sep = "", collapse = '-')
+        NEW:
+        ====
+        paste0(x, y, collapse = '-')
+
+        OLD:
+        ====
+        base::paste(sep = '', x)
+        NEW:
+        ====
+        base::paste0(x)
+
+        OLD:
+        ====
+        paste(sep = '')
+        NEW:
+        ====
+        paste0()
+
+        OLD:
+        ====
+        paste0('a', 'b', sep = '-')
+        NEW:
+        ====
+        paste0('a', 'b', sep = '-')
+        "#
+        );
+    }
+
+    #[test]
+    fn test_paste_to_paste0_with_comments_no_fix() {
+        assert_snapshot!(
+            get_fixed_text(
+                vec![
+                    "# leading comment\npaste('a', 'b', sep = '')",
+                    "paste('a', # comment\n  'b', sep = '')",
+                    "paste('a', 'b', sep = '') # trailing comment",
+                ],
+                "paste_to_paste0",
+                None,
+            ),
+            @"
+        OLD:
+        ====
+        # leading comment
+        paste('a', 'b', sep = '')
+        NEW:
+        ====
+        # leading comment
+        paste0('a', 'b')
+
+        OLD:
+        ====
+        paste('a', # comment
+          'b', sep = '')
+        NEW:
+        ====
+        paste('a', # comment
+          'b', sep = '')
+
+        OLD:
+        ====
+        paste('a', 'b', sep = '') # trailing comment
+        NEW:
+        ====
+        paste0('a', 'b') # trailing comment
+        "
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use crate::diagnostic::*;
+use crate::utils::{get_arg_by_name, node_contains_comments};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for calls to `paste(..., sep = "")` and for calls to `paste0()` that
+/// have a `sep` argument.
+///
+/// ## Why is this bad?
+///
+/// `paste0(...)` is equivalent to `paste(..., sep = "")` and is more readable.
+///
+/// `paste0()` doesn't have a `sep` argument, so `paste0(..., sep = "x")` is
+/// very likely a mistake: `sep` is not used as a separator but is captured by
+/// `...` and pasted with the other inputs.
+///
+/// This rule has a safe fix for `paste(..., sep = "")`. There is no automatic
+/// fix for `paste0(..., sep = "x")` since the intent is unclear.
+///
+/// ## Example
+///
+/// ```r
+/// paste("a", "b", sep = "")
+/// paste0("a", "b", sep = "-")
+/// ```
+///
+/// Use instead:
+/// ```r
+/// paste0("a", "b")
+/// paste("a", "b", sep = "-")
+/// ```
+///
+/// ## References
+///
+/// See `?paste`
+pub fn paste_to_paste0(
+    ast: &RCall,
+    fn_name: &str,
+    ns_prefix: Option<&str>,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if fn_name != "paste" && fn_name != "paste0" {
+        return Ok(None);
+    }
+    if ns_prefix.is_some_and(|ns| ns != "base::") {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+    let sep = unwrap_or_return_none!(get_arg_by_name(&args, "sep"));
+    let sep_value = unwrap_or_return_none!(sep.value());
+
+    let range = ast.syntax().text_trimmed_range();
+
+    if fn_name == "paste0" {
+        let diagnostic = Diagnostic::new(
+            ViolationData::new(
+                "paste_to_paste0".to_string(),
+                "`paste0()` doesn't have a `sep` argument, its value is pasted with the other inputs."
+                    .to_string(),
+                Some("Use `paste(..., sep = )` to set a separator.".to_string()),
+            ),
+            range,
+            Fix::empty(),
+        );
+        return Ok(Some(diagnostic));
+    }
+
+    let sep_text = sep_value.to_trimmed_string();
+    if sep_text != "\"\"" && sep_text != "''" {
+        return Ok(None);
+    }
+
+    let other_args = args
+        .iter()
+        .filter_map(|arg| arg.ok())
+        .filter(|arg| arg.syntax() != sep.syntax())
+        .map(|arg| arg.syntax().text_trimmed().to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "paste_to_paste0".to_string(),
+            "`paste(..., sep = \"\")` can be simplified.".to_string(),
+            Some("Use `paste0(...)` instead.".to_string()),
+        ),
+        range,
+        Fix {
+            content: format!("{}paste0({other_args})", ns_prefix.unwrap_or_default()),
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(ast.syntax()),
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
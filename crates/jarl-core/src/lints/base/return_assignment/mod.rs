@@ -0,0 +1,137 @@
+pub(crate) mod return_assignment;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "return_assignment", None)
+    }
+
+    #[test]
+    fn test_no_lint_return_assignment() {
+        // Not in a function
+        expect_no_lint("return(x <- 1)", "return_assignment", None);
+        expect_no_lint("invisible(x <- 1)", "return_assignment", None);
+        // The variable outlives the function
+        expect_no_lint("function() return(x <<- 1)", "return_assignment", None);
+        expect_no_lint("function() return(1 ->> x)", "return_assignment", None);
+        expect_no_lint("function() return(x$a <- 1)", "return_assignment", None);
+        expect_no_lint("function() return(x = 1)", "return_assignment", None);
+        expect_no_lint("function() return((x <- 1))", "return_assignment", None);
+        // The variable is still used
+        expect_no_lint(
+            "function() return(f <- function(n) if (n > 0) f(n - 1))",
+            "return_assignment",
+            None,
+        );
+        expect_no_lint(
+            "function() {\n  on.exit(print(x))\n  return(x <- 1)\n}",
+            "return_assignment",
+            None,
+        );
+        // The value of `invisible()` is not returned
+        expect_no_lint(
+            "function() {\n  invisible(x <- 1)\n  x\n}",
+            "return_assignment",
+            None,
+        );
+        expect_no_lint(
+            "function() local(invisible(x <- 1))",
+            "return_assignment",
+            None,
+        );
+        expect_no_lint(
+            "function() if (invisible(x <- 1)) 2",
+            "return_assignment",
+            None,
+        );
+        expect_no_lint("function() print(x <- 1)", "return_assignment", None);
+    }
+
+    #[test]
+    fn test_lint_return_assignment() {
+        assert_snapshot!(
+            snapshot_lint("f <- function(x) return(y <- x + 1)"),
+            @"
+        warning: return_assignment
+         --> <test>:1:25
+          |
+        1 | f <- function(x) return(y <- x + 1)
+          |                         ---------- `y` is assigned in `return()`, but it is removed right after when the function exits.
+          |
+          = help: Remove the assignment.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("function(x) {\n  names(x) <- nms\n  invisible(out <- x)\n}"),
+            @"
+        warning: return_assignment
+         --> <test>:3:13
+          |
+        3 |   invisible(out <- x)
+          |             -------- `out` is assigned in `invisible()`, but it is removed right after when the function exits.
+          |
+          = help: Remove the assignment.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("function(x) return(invisible(x + 1 -> y))"),
+            @"
+        warning: return_assignment
+         --> <test>:1:30
+          |
+        1 | function(x) return(invisible(x + 1 -> y))
+          |                              ---------- `y` is assigned in `invisible()`, but it is removed right after when the function exits.
+          |
+          = help: Remove the assignment.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("function(x) if (x) invisible(y <- 1) else 2"),
+            @"
+        warning: return_assignment
+         --> <test>:1:30
+          |
+        1 | function(x) if (x) invisible(y <- 1) else 2
+          |                              ------ `y` is assigned in `invisible()`, but it is removed right after when the function exits.
+          |
+          = help: Remove the assignment.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_fix_return_assignment() {
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "function(x) return(y <- x + 1)",
+                    "function(x) {\n  invisible(out <- x)\n}",
+                    "function(x) return(invisible(x + 1 -> y))",
+                    "function(x) {\n  if (x) {\n    1\n  } else {\n    invisible(y <- 2)\n  }\n}",
+                ],
+                "return_assignment",
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_return_assignment_with_comments_no_fix() {
+        assert_snapshot!(
+            "no_fix_with_comments",
+            get_fixed_text(
+                vec!["function(x) return(y <- # comment\n  x + 1)"],
+                "return_assignment",
+                None
+            )
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use crate::diagnostic::*;
+use crate::utils::{enclosing_scope, get_function_name, node_contains_comments};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for assignments to a variable in `return()`, such as
+/// `return(x <- value)`, and in `invisible()` when it gives the value returned
+/// by a function, such as `invisible(x <- value)` as the last expression of a
+/// function or `return(invisible(x <- value))`.
+///
+/// The assignment is not reported if the variable is used in the assigned
+/// value, e.g. in `return(f <- function(n) if (n > 0) f(n - 1))`, or if the
+/// function calls `on.exit()`, since the variable can still be used in those
+/// cases.
+///
+/// This rule is disabled by default. It has a safe automatic fix that removes
+/// the assignment. Since an assignment makes the value invisible,
+/// `return(x <- value)` is replaced by `return(invisible(value))` so that the
+/// output of the function is unchanged.
+///
+/// ## Why is this bad?
+///
+/// The variable is local to the function and is removed as soon as the
+/// function exits, which happens right after the assignment. The assignment is
+/// therefore useless and makes the reader wonder where the variable is used.
+///
+/// This rule targets the value returned by a function, while
+/// `implicit_assignment` reports assignments in the arguments of all function
+/// calls.
+///
+/// ## Example
+///
+/// ```r
+/// add_one <- function(x) {
+///   return(result <- x + 1)
+/// }
+///
+/// set_names <- function(x, nms) {
+///   names(x) <- nms
+///   invisible(out <- x)
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// add_one <- function(x) {
+///   return(x + 1)
+/// }
+///
+/// set_names <- function(x, nms) {
+///   names(x) <- nms
+///   invisible(x)
+/// }
+/// ```
+pub fn return_assignment(ast: &RCall, fn_name: &str) -> anyhow::Result<Option<Diagnostic>> {
+    if fn_name != "return" && fn_name != "invisible" {
+        return Ok(None);
+    }
+
+    let node = ast.syntax();
+    let function = enclosing_scope(node);
+    if function.kind() != RSyntaxKind::R_FUNCTION_DEFINITION {
+        return Ok(None);
+    }
+    if fn_name == "invisible" && !is_returned_value(node) {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+    if args.iter().count() != 1 {
+        return Ok(None);
+    }
+    let arg = unwrap_or_return_none!(args.iter().next().and_then(|arg| arg.ok()));
+    if arg.name_clause().is_some() {
+        return Ok(None);
+    }
+    let AnyRExpression::RBinaryExpression(assignment) = unwrap_or_return_none!(arg.value()) else {
+        return Ok(None);
+    };
+
+    // Only local assignments, `<<-` and `->>` modify a variable that outlives
+    // the function.
+    let (target, value) = match assignment.operator()?.kind() {
+        RSyntaxKind::ASSIGN => (assignment.left()?, assignment.right()?),
+        RSyntaxKind::ASSIGN_RIGHT => (assignment.right()?, assignment.left()?),
+        _ => return Ok(None),
+    };
+    let AnyRExpression::RIdentifier(target) = target else {
+        return Ok(None);
+    };
+    let variable = target.syntax().text_trimmed().to_string();
+
+    // The variable can still be used after the assignment, e.g. by a recursive
+    // function or by the code of `on.exit()`.
+    let uses_variable = value
+        .syntax()
+        .descendants()
+        .filter_map(RIdentifier::cast)
+        .any(|ident| ident.syntax().text_trimmed() == variable);
+    let calls_on_exit = function.descendants().filter_map(RCall::cast).any(|call| {
+        call.function()
+            .is_ok_and(|f| get_function_name(f) == "on.exit")
+    });
+    if uses_variable || calls_on_exit {
+        return Ok(None);
+    }
+
+    let value = value.syntax().text_trimmed().to_string();
+    let content = if fn_name == "return" {
+        format!("invisible({value})")
+    } else {
+        value
+    };
+    let range = assignment.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "return_assignment".to_string(),
+            format!(
+                "`{variable}` is assigned in `{fn_name}()`, but it is removed right after when the function exits."
+            ),
+            Some("Remove the assignment.".to_string()),
+        ),
+        range,
+        Fix {
+            content,
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(assignment.syntax()),
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Whether the value of `node` is returned by its enclosing function, either
+/// because it is passed to `return()` or because it is the last expression
+/// evaluated in the function.
+fn is_returned_value(node: &RSyntaxNode) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+
+    // i.e. `return(invisible(x <- 1))`
+    if RArgument::can_cast(parent.kind()) {
+        let call = parent.ancestors().find_map(RCall::cast);
+        return call
+            .and_then(|call| call.function().ok())
+            .is_some_and(|function| get_function_name(function) == "return");
+    }
+
+    // i.e. `function() invisible(x <- 1)`
+    if let Some(parent) = RFunctionDefinition::cast_ref(&parent) {
+        return parent.body().is_ok_and(|body| body.syntax() == node);
+    }
+
+    // i.e. `{ y; invisible(x <- 1) }`
+    if RExpressionList::can_cast(parent.kind()) {
+        return parent.last_child().as_ref() == Some(node)
+            && parent.parent().is_some_and(|braces| {
+                RBracedExpressions::can_cast(braces.kind()) && is_returned_value(&braces)
+            });
+    }
+
+    // i.e. `if (cond) invisible(x <- 1)`
+    if let Some(parent) = RIfStatement::cast_ref(&parent)
+        && parent
+            .consequence()
+            .is_ok_and(|consequence| consequence.syntax() == node)
+    {
+        return is_returned_value(parent.syntax());
+    }
+
+    // i.e. `if (cond) y else invisible(x <- 1)`
+    if let Some(parent) = RElseClause::cast_ref(&parent)
+        && parent
+            .alternative()
+            .is_ok_and(|alternative| alternative.syntax() == node)
+        && let Some(if_statement) = parent.syntax().parent()
+    {
+        return is_returned_value(&if_statement);
+    }
+
+    false
+}
@@ -0,0 +1,137 @@
+pub(crate) mod options;
+pub(crate) mod rlang_abort_call;
+
+#[cfg(test)]
+mod tests {
+    use crate::lints::base::rlang_abort_call::options::ResolvedRlangAbortCallOptions;
+    use crate::lints::base::rlang_abort_call::options::RlangAbortCallOptions;
+    use crate::rule_options::ResolvedRuleOptions;
+    use crate::settings::{LinterSettings, Settings};
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "rlang_abort_call", None)
+    }
+
+    /// Build a `Settings` that only requires the arguments in `require`.
+    fn settings_require(require: &[&str]) -> Settings {
+        let options = RlangAbortCallOptions {
+            require: Some(require.iter().map(|arg| arg.to_string()).collect()),
+        };
+        Settings {
+            linter: LinterSettings {
+                rule_options: ResolvedRuleOptions {
+                    rlang_abort_call: ResolvedRlangAbortCallOptions::resolve(Some(&options))
+                        .unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_lint_rlang_abort_call() {
+        expect_no_lint(
+            "abort('msg', class = 'my_error', call = call)",
+            "rlang_abort_call",
+            None,
+        );
+        expect_no_lint(
+            "rlang::abort('msg', call = caller_env(), class = 'my_error')",
+            "rlang_abort_call",
+            None,
+        );
+        expect_no_lint(
+            "cli::cli_abort('msg', class = 'my_error', call = NULL)",
+            "rlang_abort_call",
+            None,
+        );
+        // Arguments may be passed through `...`.
+        expect_no_lint("abort('msg', ...)", "rlang_abort_call", None);
+        // Other functions
+        expect_no_lint("stop('msg')", "rlang_abort_call", None);
+        expect_no_lint("foo::abort('msg')", "rlang_abort_call", None);
+        expect_no_lint("rlang::cli_abort('msg')", "rlang_abort_call", None);
+    }
+
+    #[test]
+    fn test_lint_rlang_abort_call() {
+        assert_snapshot!(
+            snapshot_lint("abort('msg')"),
+            @r"
+        warning: rlang_abort_call
+         --> <test>:1:1
+          |
+        1 | abort('msg')
+          | ------------ `abort()` is called without `call` and `class` arguments.
+          |
+          = help: Pass `call` (e.g. `call = caller_env()` in helpers) and a `class` to identify the error.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("rlang::abort('msg', class = 'my_error')"),
+            @r"
+        warning: rlang_abort_call
+         --> <test>:1:1
+          |
+        1 | rlang::abort('msg', class = 'my_error')
+          | --------------------------------------- `abort()` is called without a `call` argument.
+          |
+          = help: Pass `call` (e.g. `call = caller_env()` in helpers) to show the right function in the error.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("cli_abort('msg', call = call)"),
+            @r"
+        warning: rlang_abort_call
+         --> <test>:1:1
+          |
+        1 | cli_abort('msg', call = call)
+          | ----------------------------- `cli_abort()` is called without a `class` argument.
+          |
+          = help: Pass a `class` so that the error can be caught and tested without matching its message.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_rlang_abort_call_require() {
+        let settings = settings_require(&["class"]);
+        expect_no_lint_with_settings(
+            "abort('msg', class = 'my_error')",
+            "rlang_abort_call",
+            None,
+            settings.clone(),
+        );
+        assert_snapshot!(
+            format_diagnostics_with_settings(
+                "abort('msg', call = call)",
+                "rlang_abort_call",
+                None,
+                Some(settings),
+            ),
+            @r"
+        warning: rlang_abort_call
+         --> <test>:1:1
+          |
+        1 | abort('msg', call = call)
+          | ------------------------- `abort()` is called without a `class` argument.
+          |
+          = help: Pass a `class` so that the error can be caught and tested without matching its message.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_rlang_abort_call_invalid_require() {
+        let options = RlangAbortCallOptions { require: Some(vec!["message".to_string()]) };
+        assert!(ResolvedRlangAbortCallOptions::resolve(Some(&options)).is_err());
+    }
+}
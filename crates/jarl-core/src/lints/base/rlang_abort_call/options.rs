@@ -0,0 +1,42 @@
+/// TOML options for `[lint.rlang_abort_call]`.
+///
+/// Use `require` to choose which arguments `abort()` and `cli_abort()` must
+/// have. Valid values are `"call"` and `"class"` (default: both).
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct RlangAbortCallOptions {
+    pub require: Option<Vec<String>>,
+}
+
+/// Resolved options for the `rlang_abort_call` rule, ready for use during
+/// linting.
+#[derive(Clone, Debug)]
+pub struct ResolvedRlangAbortCallOptions {
+    pub require_call: bool,
+    pub require_class: bool,
+}
+
+impl ResolvedRlangAbortCallOptions {
+    pub fn resolve(options: Option<&RlangAbortCallOptions>) -> anyhow::Result<Self> {
+        let Some(require) = options.and_then(|opts| opts.require.as_ref()) else {
+            return Ok(Self { require_call: true, require_class: true });
+        };
+
+        let mut resolved = Self { require_call: false, require_class: false };
+        for arg in require {
+            match arg.as_str() {
+                "call" => resolved.require_call = true,
+                "class" => resolved.require_class = true,
+                other => {
+                    return Err(anyhow::anyhow!(
+                        "Invalid value in `require` in `[lint.rlang_abort_call]`: \"{other}\". \
+                         Expected \"call\" or \"class\"."
+                    ));
+                }
+            }
+        }
+
+        Ok(resolved)
+    }
+}
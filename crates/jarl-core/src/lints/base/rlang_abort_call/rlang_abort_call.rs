@@ -0,0 +1,106 @@
+use crate::checker::Checker;
+use crate::diagnostic::*;
+use crate::utils::get_arg_by_name;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for calls to `rlang::abort()` and `cli::cli_abort()` that don't
+/// have a `call` or a `class` argument. Use `require` in
+/// `[lint.rlang_abort_call]` to choose which of these arguments are required
+/// (default: both).
+///
+/// Calls that pass `...` are ignored since these arguments may be part of it.
+///
+/// This rule is disabled by default and doesn't have an automatic fix.
+///
+/// ## Why is this bad?
+///
+/// By default, the error message mentions the function that called
+/// `abort()`. When this is an internal helper, e.g. a function checking the
+/// inputs, the user sees a function they never called. Passing
+/// `call = caller_env()` to the helper and then to `abort()` makes the error
+/// mention the user-facing function instead.
+///
+/// Without a `class`, an error can only be caught or tested by matching its
+/// message, which breaks as soon as the message is rephrased.
+///
+/// ## Example
+///
+/// ```r
+/// check_positive <- function(x) {
+///   if (any(x < 0)) {
+///     abort("`x` must be positive.")
+///   }
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// check_positive <- function(x, call = caller_env()) {
+///   if (any(x < 0)) {
+///     abort("`x` must be positive.", class = "mypkg_error_negative", call = call)
+///   }
+/// }
+/// ```
+///
+/// ## References
+///
+/// - <https://rlang.r-lib.org/reference/topic-error-call.html>
+/// - <https://rlang.r-lib.org/reference/topic-error-chaining.html>
+pub fn rlang_abort_call(
+    ast: &RCall,
+    fn_name: &str,
+    ns_prefix: Option<&str>,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    match (fn_name, ns_prefix) {
+        ("abort", None | Some("rlang::")) | ("cli_abort", None | Some("cli::")) => {}
+        _ => return Ok(None),
+    }
+
+    let args = ast.arguments()?.items();
+    let passes_dots = args
+        .iter()
+        .filter_map(|arg| arg.ok())
+        .any(|arg| arg.name_clause().is_none() && arg.to_trimmed_string() == "...");
+    if passes_dots {
+        return Ok(None);
+    }
+
+    let options = &checker.rule_options.rlang_abort_call;
+    let missing_call = options.require_call && get_arg_by_name(&args, "call").is_none();
+    let missing_class = options.require_class && get_arg_by_name(&args, "class").is_none();
+
+    let (missing, help) = match (missing_call, missing_class) {
+        (true, true) => (
+            "`call` and `class` arguments",
+            "Pass `call` (e.g. `call = caller_env()` in helpers) and a `class` to identify the error.",
+        ),
+        (true, false) => (
+            "a `call` argument",
+            "Pass `call` (e.g. `call = caller_env()` in helpers) to show the right function in the error.",
+        ),
+        (false, true) => (
+            "a `class` argument",
+            "Pass a `class` so that the error can be caught and tested without matching its message.",
+        ),
+        (false, false) => return Ok(None),
+    };
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "rlang_abort_call".to_string(),
+            format!("`{fn_name}()` is called without {missing}."),
+            Some(help.to_string()),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
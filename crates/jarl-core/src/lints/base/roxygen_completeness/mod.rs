@@ -0,0 +1,17 @@
+pub(crate) mod roxygen_completeness;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+
+    // Cases in R packages are tested in the integration tests of the CLI since
+    // they require a DESCRIPTION and a NAMESPACE file.
+    #[test]
+    fn test_no_lint_roxygen_completeness_outside_package() {
+        expect_no_lint(
+            "#' Title\n#' @param z A value\n#' @export\nfoo <- function(x) x",
+            "roxygen_completeness",
+            None,
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crate::checker::Checker;
+use crate::diagnostic::*;
+use crate::package::FileScope;
+use crate::roxygen::RoxygenBlock;
+use air_r_syntax::*;
+use biome_rowan::{AstNode, TextRange};
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks the roxygen documentation of the functions exported by a package
+/// (i.e. listed in its `NAMESPACE` file). It reports:
+///
+/// - a documentation that has no `@return` (or `@returns`) tag;
+/// - arguments of the function that are not documented with `@param`;
+/// - `@param` tags that document an argument the function doesn't have.
+///
+/// Only the functions defined in the `R/` folder of a package and preceded by
+/// a roxygen block are checked. Blocks that use `@noRd`, `@rdname`, or
+/// `@describeIn` are skipped since their documentation is (at least partly)
+/// elsewhere. `@inherit` and `@inheritParams` are also taken into account.
+///
+/// This rule is disabled by default and doesn't have an automatic fix.
+///
+/// ## Why is this bad?
+///
+/// `R CMD check` warns about undocumented arguments and about `@param` tags
+/// that don't match an argument, and CRAN requires the documentation of
+/// exported functions to describe the value they return. Checking this while
+/// writing code avoids discovering it when submitting the package.
+///
+/// ## Example
+///
+/// ```r
+/// # In NAMESPACE: export(add)
+///
+/// #' Add two numbers
+/// #'
+/// #' @param x A number.
+/// #' @param z A number.
+/// #' @export
+/// add <- function(x, y) {
+///   x + y
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// #' Add two numbers
+/// #'
+/// #' @param x A number.
+/// #' @param y A number.
+/// #' @return The sum of `x` and `y`.
+/// #' @export
+/// add <- function(x, y) {
+///   x + y
+/// }
+/// ```
+///
+/// ## References
+///
+/// See the section "Functions" in [R Packages](https://r-pkgs.org/man.html#sec-man-functions).
+pub fn roxygen_completeness(
+    ast: &RBinaryExpression,
+    checker: &Checker,
+) -> anyhow::Result<Vec<Diagnostic>> {
+    let mut diagnostics = Vec::new();
+
+    if checker.file_scope != Some(FileScope::R) || !is_top_level(ast.syntax()) {
+        return Ok(diagnostics);
+    }
+
+    let operator = ast.operator()?;
+    if !matches!(operator.kind(), RSyntaxKind::ASSIGN | RSyntaxKind::EQUAL) {
+        return Ok(diagnostics);
+    }
+    let AnyRExpression::RIdentifier(name) = ast.left()? else {
+        return Ok(diagnostics);
+    };
+    let AnyRExpression::RFunctionDefinition(function) = ast.right()? else {
+        return Ok(diagnostics);
+    };
+    let name = name.syntax().text_trimmed().to_string();
+    if !checker.namespace_exports.contains(&name) {
+        return Ok(diagnostics);
+    }
+
+    let Some(block) = ast
+        .syntax()
+        .first_token()
+        .and_then(|token| RoxygenBlock::from_leading_trivia(&token))
+    else {
+        return Ok(diagnostics);
+    };
+    if block.has_any_tag(&["noRd", "rdname", "describeIn"]) {
+        return Ok(diagnostics);
+    }
+
+    let name_range = ast.left()?.syntax().text_trimmed_range();
+    let inherits = block.has_any_tag(&["inherit"]);
+
+    if !inherits && !block.has_any_tag(&["return", "returns"]) {
+        diagnostics.push(new_diagnostic(
+            format!("`{name}()` is exported but its documentation has no `@return` tag."),
+            format!("Describe the value returned by `{name}()` with `@return`."),
+            name_range,
+        ));
+    }
+
+    let arguments = function
+        .parameters()?
+        .items()
+        .into_iter()
+        .filter_map(|param| param.ok()?.name().ok())
+        .map(|param| param.syntax().text_trimmed().to_string())
+        .collect::<Vec<_>>();
+
+    // `@param x,y` documents several arguments at once.
+    let mut documented: Vec<String> = Vec::new();
+    for tag in block.tags("param") {
+        let Some(names) = tag.value.split_whitespace().next() else {
+            continue;
+        };
+        for param in names.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            if !arguments.iter().any(|argument| argument == param) {
+                diagnostics.push(new_diagnostic(
+                    format!("`@param {param}` documents an argument that `{name}()` doesn't have."),
+                    format!("Remove it or rename it to match an argument of `{name}()`."),
+                    tag.range,
+                ));
+            }
+            documented.push(param.to_string());
+        }
+    }
+
+    if !inherits && !block.has_any_tag(&["inheritParams"]) {
+        let inherits_dots = block.has_any_tag(&["inheritDotParams"]);
+        for argument in &arguments {
+            if documented.contains(argument) || (argument == "..." && inherits_dots) {
+                continue;
+            }
+            diagnostics.push(new_diagnostic(
+                format!(
+                    "Argument `{argument}` of the exported function `{name}()` is not documented."
+                ),
+                format!("Document it with `@param {argument}`."),
+                name_range,
+            ));
+        }
+    }
+
+    Ok(diagnostics)
+}
+
+fn new_diagnostic(message: String, help: String, range: TextRange) -> Diagnostic {
+    Diagnostic::new(
+        ViolationData::new("roxygen_completeness".to_string(), message, Some(help)),
+        range,
+        Fix::empty(),
+    )
+}
+
+/// Whether `node` is an expression at the top level of the file.
+fn is_top_level(node: &RSyntaxNode) -> bool {
+    node.parent().is_some_and(|parent| {
+        RExpressionList::can_cast(parent.kind())
+            && parent
+                .parent()
+                .is_some_and(|grandparent| RRoot::can_cast(grandparent.kind()))
+    })
+}
@@ -0,0 +1,305 @@
+pub(crate) mod options;
+pub(crate) mod scalar_logical_check;
+
+#[cfg(test)]
+mod tests {
+    use crate::lints::base::scalar_logical_check::options::{
+        PreferredPredicate, ResolvedScalarLogicalCheckOptions,
+    };
+    use crate::rule_options::ResolvedRuleOptions;
+    use crate::settings::{LinterSettings, Settings};
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "scalar_logical_check", None)
+    }
+
+    fn rlang_settings() -> Settings {
+        Settings {
+            linter: LinterSettings {
+                rule_options: ResolvedRuleOptions {
+                    scalar_logical_check: ResolvedScalarLogicalCheckOptions {
+                        target: PreferredPredicate::Rlang,
+                    },
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_lint_scalar_logical_check() {
+        expect_no_lint(
+            "is.logical(x) && length(x) == 1",
+            "scalar_logical_check",
+            None,
+        );
+        expect_no_lint("is.logical(x) && !is.na(x)", "scalar_logical_check", None);
+        expect_no_lint(
+            "is.logical(x) && length(y) == 1 && !is.na(x)",
+            "scalar_logical_check",
+            None,
+        );
+        expect_no_lint(
+            "is.logical(x) && length(x) == 2 && !is.na(x)",
+            "scalar_logical_check",
+            None,
+        );
+        expect_no_lint(
+            "is.logical(x) && length(x) > 0 && !is.na(x)",
+            "scalar_logical_check",
+            None,
+        );
+        expect_no_lint(
+            "is.logical(x) & length(x) == 1 & !is.na(x)",
+            "scalar_logical_check",
+            None,
+        );
+        expect_no_lint(
+            "is.numeric(x) && length(x) == 1 && !is.na(x)",
+            "scalar_logical_check",
+            None,
+        );
+        expect_no_lint(
+            "length(x) == 1 && is.logical(x) && !is.na(x)",
+            "scalar_logical_check",
+            None,
+        );
+        expect_no_lint(
+            "foo::is.logical(x) && length(x) == 1 && !is.na(x)",
+            "scalar_logical_check",
+            None,
+        );
+        expect_no_lint(
+            "is.logical(x = x) && length(x) == 1 && !is.na(x)",
+            "scalar_logical_check",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_scalar_logical_check() {
+        assert_snapshot!(
+            snapshot_lint("is.logical(x) && length(x) == 1 && !is.na(x)"),
+            @"
+        warning: scalar_logical_check
+         --> <test>:1:1
+          |
+        1 | is.logical(x) && length(x) == 1 && !is.na(x)
+          | -------------------------------------------- This checks whether `x` is a single `TRUE` or `FALSE` in a verbose way.
+          |
+          = help: Use `isTRUE(x) || isFALSE(x)` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("is.logical(x) && length(x) == 1 && !is.na(x) && x"),
+            @"
+        warning: scalar_logical_check
+         --> <test>:1:1
+          |
+        1 | is.logical(x) && length(x) == 1 && !is.na(x) && x
+          | ------------------------------------------------- This checks whether `x` is `TRUE` in a verbose way.
+          |
+          = help: Use `isTRUE(x)` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("is.logical(x) && length(x) == 1 && !is.na(x) && !x"),
+            @"
+        warning: scalar_logical_check
+         --> <test>:1:1
+          |
+        1 | is.logical(x) && length(x) == 1 && !is.na(x) && !x
+          | -------------------------------------------------- This checks whether `x` is `FALSE` in a verbose way.
+          |
+          = help: Use `isFALSE(x)` instead.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_lint_scalar_logical_check_rlang() {
+        assert_snapshot!(
+            format_diagnostics_with_settings(
+                "is.logical(x) && length(x) == 1 && !is.na(x)",
+                "scalar_logical_check",
+                None,
+                Some(rlang_settings()),
+            ),
+            @"
+        warning: scalar_logical_check
+         --> <test>:1:1
+          |
+        1 | is.logical(x) && length(x) == 1 && !is.na(x)
+          | -------------------------------------------- This checks whether `x` is a single `TRUE` or `FALSE` in a verbose way.
+          |
+          = help: Use `rlang::is_bool(x)` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            format_diagnostics_with_settings(
+                "is.logical(x) && length(x) == 1 && !is.na(x) && x",
+                "scalar_logical_check",
+                None,
+                Some(rlang_settings()),
+            ),
+            @"
+        warning: scalar_logical_check
+         --> <test>:1:1
+          |
+        1 | is.logical(x) && length(x) == 1 && !is.na(x) && x
+          | ------------------------------------------------- This checks whether `x` is `TRUE` in a verbose way.
+          |
+          = help: Use `rlang::is_true(x)` instead.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_fix_scalar_logical_check() {
+        assert_snapshot!(
+            get_fixed_text(
+                vec![
+                    "is.logical(x) && length(x) == 1 && !is.na(x)",
+                    "is.logical(x) && length(x) == 1L && !is.na(x)",
+                    "is.logical(x) && length(x) == 1 && !is.na(x) && x",
+                    "is.logical(x) && length(x) == 1 && !is.na(x) && !x",
+                    "if (!(is.logical(x) && length(x) == 1 && !is.na(x))) stop()",
+                    "is.logical(x) && length(x) == 1 && !is.na(x) && y > 0",
+                    "is.logical(args$x) && length(args$x) == 1 && !is.na(args$x) && args$x",
+                    "base::is.logical(x) && base::length(x) == 1 && !base::is.na(x)",
+                ],
+                "scalar_logical_check",
+                None,
+            ),
+            @"
+        OLD:
+        ====
+        is.logical(x) && length(x) == 1 && !is.na(x)
+        NEW:
+        ====
+        isTRUE(x) || isFALSE(x)
+
+        OLD:
+        ====
+        is.logical(x) && length(x) == 1L && !is.na(x)
+        NEW:
+        ====
+        isTRUE(x) || isFALSE(x)
+
+        OLD:
+        ====
+        is.logical(x) && length(x) == 1 && !is.na(x) && x
+        NEW:
+        ====
+        isTRUE(x)
+
+        OLD:
+        ====
+        is.logical(x) && length(x) == 1 && !is.na(x) && !x
+        NEW:
+        ====
+        isFALSE(x)
+
+        OLD:
+        ====
+        if (!(is.logical(x) && length(x) == 1 && !is.na(x))) stop()
+        NEW:
+        ====
+        if (!(isTRUE(x) || isFALSE(x))) stop()
+
+        OLD:
+        ====
+        is.logical(x) && length(x) == 1 && !is.na(x) && y > 0
+        NEW:
+        ====
+        (isTRUE(x) || isFALSE(x)) && y > 0
+
+        OLD:
+        ====
+        is.logical(args$x) && length(args$x) == 1 && !is.na(args$x) && args$x
+        NEW:
+        ====
+        isTRUE(args$x)
+
+        OLD:
+        ====
+        base::is.logical(x) && base::length(x) == 1 && !base::is.na(x)
+        NEW:
+        ====
+        isTRUE(x) || isFALSE(x)
+        "
+        );
+        assert_snapshot!(
+            get_fixed_text_with_settings(
+                vec![
+                    "is.logical(x) && length(x) == 1 && !is.na(x)",
+                    "is.logical(x) && length(x) == 1 && !is.na(x) && x",
+                    "is.logical(x) && length(x) == 1 && !is.na(x) && !x",
+                    "is.logical(x) && length(x) == 1 && !is.na(x) && y > 0",
+                ],
+                "scalar_logical_check",
+                None,
+                Some(rlang_settings()),
+            ),
+            @"
+        OLD:
+        ====
+        is.logical(x) && length(x) == 1 && !is.na(x)
+        NEW:
+        ====
+        rlang::is_bool(x)
+
+        OLD:
+        ====
+        is.logical(x) && length(x) == 1 && !is.na(x) && x
+        NEW:
+        ====
+        rlang::is_true(x)
+
+        OLD:
+        ====
+        is.logical(x) && length(x) == 1 && !is.na(x) && !x
+        NEW:
+        ====
+        rlang::is_false(x)
+
+        OLD:
+        ====
+        is.logical(x) && length(x) == 1 && !is.na(x) && y > 0
+        NEW:
+        ====
+        rlang::is_bool(x) && y > 0
+        "
+        );
+    }
+
+    #[test]
+    fn test_scalar_logical_check_with_comments_no_fix() {
+        assert_snapshot!(
+            get_fixed_text(
+                vec!["is.logical(x) && # comment\n  length(x) == 1 && !is.na(x)"],
+                "scalar_logical_check",
+                None,
+            ),
+            @"
+        OLD:
+        ====
+        is.logical(x) && # comment
+          length(x) == 1 && !is.na(x)
+        NEW:
+        ====
+        is.logical(x) && # comment
+          length(x) == 1 && !is.na(x)
+        "
+        );
+    }
+}
@@ -0,0 +1,46 @@
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PreferredPredicate {
+    /// `isTRUE()` and `isFALSE()`
+    Base,
+    /// `rlang::is_bool()`, `rlang::is_true()`, and `rlang::is_false()`
+    Rlang,
+}
+
+/// TOML options for `[lint.scalar_logical_check]`.
+///
+/// Use `target` to specify which functions are recommended. Valid values are
+/// `"base"` (the default) and `"rlang"`.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ScalarLogicalCheckOptions {
+    pub target: Option<String>,
+}
+
+/// Resolved options for the `scalar_logical_check` rule.
+#[derive(Clone, Debug)]
+pub struct ResolvedScalarLogicalCheckOptions {
+    pub target: PreferredPredicate,
+}
+
+impl ResolvedScalarLogicalCheckOptions {
+    pub fn resolve(options: Option<&ScalarLogicalCheckOptions>) -> anyhow::Result<Self> {
+        let target = match options {
+            Some(opts) => match opts.target.as_deref() {
+                Some("base") | None => PreferredPredicate::Base,
+                Some("rlang") => PreferredPredicate::Rlang,
+                Some(other) => {
+                    return Err(anyhow::anyhow!(
+                        "Invalid value for `target` in `[lint.scalar_logical_check]`: \"{other}\". \
+                         Expected \"base\" or \"rlang\"."
+                    ));
+                }
+            },
+            None => PreferredPredicate::Base,
+        };
+
+        Ok(Self { target })
+    }
+}
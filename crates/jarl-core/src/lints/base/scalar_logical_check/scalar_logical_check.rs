@@ -0,0 +1,206 @@
+use crate::diagnostic::*;
+use crate::lints::base::scalar_logical_check::options::PreferredPredicate;
+use crate::utils::{get_function_name, get_function_namespace_prefix, node_contains_comments};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for verbose tests of whether an object is a single `TRUE` or `FALSE`,
+/// such as `is.logical(x) && length(x) == 1 && !is.na(x)`.
+///
+/// ## Why is this bad?
+///
+/// Those checks are common when validating the arguments of a function, but
+/// they are long to write and to read. Base R and `rlang` provide functions
+/// that do the same thing:
+///
+/// - `is.logical(x) && length(x) == 1 && !is.na(x)` is `isTRUE(x) || isFALSE(x)`
+///   or `rlang::is_bool(x)`;
+/// - `is.logical(x) && length(x) == 1 && !is.na(x) && x` is `isTRUE(x)` or
+///   `rlang::is_true(x)`;
+/// - `is.logical(x) && length(x) == 1 && !is.na(x) && !x` is `isFALSE(x)` or
+///   `rlang::is_false(x)`.
+///
+/// The recommended functions can be chosen with the `target` option in
+/// `[lint.scalar_logical_check]`: `"base"` (the default) or `"rlang"`.
+///
+/// This rule has a safe automatic fix, which only applies when the checks are
+/// written exactly as above (in this order, with `length(x) == 1` or
+/// `length(x) == 1L`).
+///
+/// ## Example
+///
+/// ```r
+/// if (!(is.logical(verbose) && length(verbose) == 1 && !is.na(verbose))) {
+///   stop("`verbose` must be TRUE or FALSE.")
+/// }
+/// if (is.logical(quiet) && length(quiet) == 1 && !is.na(quiet) && quiet) {
+///   message("Done.")
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// if (!(isTRUE(verbose) || isFALSE(verbose))) {
+///   stop("`verbose` must be TRUE or FALSE.")
+/// }
+/// if (isTRUE(quiet)) {
+///   message("Done.")
+/// }
+///
+/// # or with `target = "rlang"`
+/// if (!rlang::is_bool(verbose)) {
+///   stop("`verbose` must be TRUE or FALSE.")
+/// }
+/// if (rlang::is_true(quiet)) {
+///   message("Done.")
+/// }
+/// ```
+///
+/// ## References
+///
+/// See `?isTRUE` and `?rlang::is_bool`
+pub fn scalar_logical_check(
+    ast: &RBinaryExpression,
+    target: PreferredPredicate,
+) -> anyhow::Result<Option<Diagnostic>> {
+    let RBinaryExpressionFields { left, operator, right } = ast.as_fields();
+    if operator?.kind() != RSyntaxKind::AND2 {
+        return Ok(None);
+    }
+    let left = left?;
+    let right = right?;
+
+    let (x, value) = if let Some(x) = checked_variable(&left)
+        && let Some(value) = tested_value(&right, &x)
+    {
+        // `<checks> && x` or `<checks> && !x`
+        (x, Some(value))
+    } else {
+        // `<checks>` alone. If it is followed by `&& x` or `&& !x`, the
+        // parent expression is reported instead.
+        let x = unwrap_or_return_none!(checked_variable(&AnyRExpression::RBinaryExpression(
+            ast.clone()
+        )));
+        if let Some(parent) = ast.syntax().parent().and_then(RBinaryExpression::cast)
+            && parent.operator()?.kind() == RSyntaxKind::AND2
+            && parent.left()?.syntax() == ast.syntax()
+            && tested_value(&parent.right()?, &x).is_some()
+        {
+            return Ok(None);
+        }
+        (x, None)
+    };
+
+    let (what, replacement) = match (value, target) {
+        (None, PreferredPredicate::Base) => {
+            let replacement = format!("isTRUE({x}) || isFALSE({x})");
+            // `||` has a lower precedence than the operators around it.
+            let needs_parentheses = ast.syntax().parent().is_some_and(|parent| {
+                RBinaryExpression::can_cast(parent.kind())
+                    || RUnaryExpression::can_cast(parent.kind())
+            });
+            let replacement = if needs_parentheses {
+                format!("({replacement})")
+            } else {
+                replacement
+            };
+            ("a single `TRUE` or `FALSE`", replacement)
+        }
+        (None, PreferredPredicate::Rlang) => {
+            ("a single `TRUE` or `FALSE`", format!("rlang::is_bool({x})"))
+        }
+        (Some(true), PreferredPredicate::Base) => ("`TRUE`", format!("isTRUE({x})")),
+        (Some(true), PreferredPredicate::Rlang) => ("`TRUE`", format!("rlang::is_true({x})")),
+        (Some(false), PreferredPredicate::Base) => ("`FALSE`", format!("isFALSE({x})")),
+        (Some(false), PreferredPredicate::Rlang) => ("`FALSE`", format!("rlang::is_false({x})")),
+    };
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "scalar_logical_check".to_string(),
+            format!("This checks whether `{x}` is {what} in a verbose way."),
+            Some(format!("Use `{replacement}` instead.")),
+        ),
+        range,
+        Fix {
+            content: replacement,
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(ast.syntax()),
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// If `expr` is `is.logical(x) && length(x) == 1 && !is.na(x)`, return `x`.
+fn checked_variable(expr: &AnyRExpression) -> Option<String> {
+    let (first, is_not_na) = and_operands(expr)?;
+    let (is_logical, length_one) = and_operands(&first)?;
+
+    let x = single_argument(&is_logical, "is.logical")?;
+
+    let length_one = length_one.as_r_binary_expression()?;
+    if length_one.operator().ok()?.kind() != RSyntaxKind::EQUAL2 {
+        return None;
+    }
+    let one = length_one.right().ok()?.syntax().text_trimmed().to_string();
+    if single_argument(&length_one.left().ok()?, "length")? != x
+        || !matches!(one.as_str(), "1" | "1L")
+    {
+        return None;
+    }
+
+    let is_not_na = is_not_na.as_r_unary_expression()?;
+    if is_not_na.operator().ok()?.kind() != RSyntaxKind::BANG
+        || single_argument(&is_not_na.argument().ok()?, "is.na")? != x
+    {
+        return None;
+    }
+
+    Some(x)
+}
+
+/// Left and right operands of `expr` if it is a `&&` expression.
+fn and_operands(expr: &AnyRExpression) -> Option<(AnyRExpression, AnyRExpression)> {
+    let binary = expr.as_r_binary_expression()?;
+    if binary.operator().ok()?.kind() != RSyntaxKind::AND2 {
+        return None;
+    }
+    Some((binary.left().ok()?, binary.right().ok()?))
+}
+
+/// If `expr` is a call to the base function `name` with a single unnamed
+/// argument, return the text of this argument.
+fn single_argument(expr: &AnyRExpression, name: &str) -> Option<String> {
+    let call = expr.as_r_call()?;
+    let function = call.function().ok()?;
+    if get_function_name(function.clone()) != name
+        || get_function_namespace_prefix(function).is_some_and(|ns| ns != "base::")
+    {
+        return None;
+    }
+
+    let args = call.arguments().ok()?.items();
+    let mut args = args.iter();
+    let arg = args.next()?.ok()?;
+    if args.next().is_some() || arg.name_clause().is_some() {
+        return None;
+    }
+    Some(arg.value()?.syntax().text_trimmed().to_string())
+}
+
+/// Whether `expr` is `x` (`Some(true)`) or `!x` (`Some(false)`).
+fn tested_value(expr: &AnyRExpression, x: &str) -> Option<bool> {
+    if let Some(unary) = expr.as_r_unary_expression() {
+        let is_negation = unary.operator().ok()?.kind() == RSyntaxKind::BANG;
+        return (is_negation && unary.argument().ok()?.syntax().text_trimmed() == x)
+            .then_some(false);
+    }
+    (expr.syntax().text_trimmed() == x).then_some(true)
+}
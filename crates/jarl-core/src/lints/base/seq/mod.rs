@@ -15,6 +15,8 @@ mod tests {
         expect_no_lint("2:length(x)", "seq", None);
         expect_no_lint("1:(length(x) || 1)", "seq", None);
         expect_no_lint("1:foo(x)", "seq", None);
+        expect_no_lint("length(x):2", "seq", None);
+        expect_no_lint("foo(x):1", "seq", None);
 
         // TODO: would be nice to support that
         expect_no_lint("1:dim(x)[1]", "seq", None);
@@ -45,7 +47,7 @@ mod tests {
         1 | 1:nrow(x)
           | --------- `1:nrow(...)` can be wrong if the RHS is 0.
           |
-          = help: Use `seq_len(nrow((...))` instead.
+          = help: Use `seq_len(nrow(...))` instead.
         Found 1 error.
         "
         );
@@ -128,7 +130,7 @@ mod tests {
         1 | 1L:nrow(x)
           | ---------- `1:nrow(...)` can be wrong if the RHS is 0.
           |
-          = help: Use `seq_len(nrow((...))` instead.
+          = help: Use `seq_len(nrow(...))` instead.
         Found 1 error.
         "
         );
@@ -195,6 +197,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_lint_seq_reversed() {
+        assert_snapshot!(
+            snapshot_lint("length(x):1"),
+            @"
+        warning: seq
+         --> <test>:1:1
+          |
+        1 | length(x):1
+          | ----------- `length(...):1` can be wrong if the LHS is 0.
+          |
+          = help: Use `rev(seq_along(...))` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("NROW(x):1L"),
+            @"
+        warning: seq
+         --> <test>:1:1
+          |
+        1 | NROW(x):1L
+          | ---------- `NROW(...):1` can be wrong if the LHS is 0.
+          |
+          = help: Use `rev(seq_len(NROW(...)))` instead.
+        Found 1 error.
+        "
+        );
+
+        assert_snapshot!(
+            "fix_output_reversed",
+            get_fixed_text(
+                vec![
+                    "length(x):1",
+                    "nrow(x):1",
+                    "ncol(x):1L",
+                    "NROW(x):1",
+                    "NCOL(x):1L",
+                    "rev(1:length(x))",
+                    "rev(1L:nrow(x))",
+                ],
+                "seq",
+                None
+            )
+        );
+    }
+
     #[test]
     fn test_seq_with_comments_no_fix() {
         // Should detect lint but skip fix when comments are present to avoid destroying them
@@ -10,15 +10,18 @@ use biome_rowan::AstNode;
 /// ## What it does
 ///
 /// Checks for `1:length(...)`, `1:nrow(...)`, `1:ncol(...)`, `1:NROW(...)` and
-/// `1:NCOL(...)` expressions. See also [seq2](https://jarl.etiennebacher.com/rules/seq2).
+/// `1:NCOL(...)` expressions, as well as their reversed counterparts such as
+/// `length(...):1`. See also [seq2](https://jarl.etiennebacher.com/rules/seq2).
 ///
 /// ## Why is this bad?
 ///
 /// Those patterns are often used to generate sequences from 1 to a given
-/// number. However, when the right-hand side of `:` is 0, then this creates
-/// a sequence `1,0` which is often overlooked.
+/// number. However, when the other side of `:` is 0, then this creates
+/// a sequence `1,0` (or `0,1`) which is often overlooked.
 ///
 /// This rule comes with safe automatic fixes using `seq_along()` or `seq_len()`.
+/// Reversed sequences are wrapped in `rev()`, e.g. `nrow(x):1` is replaced by
+/// `rev(seq_len(nrow(x)))`.
 ///
 /// ## Example
 ///
@@ -30,6 +33,10 @@ use biome_rowan::AstNode;
 /// for (i in 1:length(data)) {
 ///   print("hi")
 /// }
+///
+/// for (i in length(data):1) {
+///   print("hi")
+/// }
 /// ```
 ///
 /// Use instead:
@@ -41,6 +48,10 @@ use biome_rowan::AstNode;
 /// for (i in seq_along(data)) {
 ///   print("hi")
 /// }
+///
+/// for (i in rev(seq_along(data))) {
+///   print("hi")
+/// }
 /// ```
 pub fn seq(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>> {
     let operator = ast.operator()?;
@@ -52,21 +63,26 @@ pub fn seq(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>> {
     let left = ast.left()?;
     let right = ast.right()?;
 
-    let right_call = unwrap_or_return_none!(right.as_r_call());
-
-    let left_is_literal_one = left.to_trimmed_text() == "1" || left.to_trimmed_text() == "1L";
+    let is_literal_one = |x: &AnyRExpression| {
+        let text = x.to_trimmed_text();
+        text == "1" || text == "1L"
+    };
 
-    if !left_is_literal_one {
+    // `1:f(x)` or the reversed `f(x):1`.
+    let (call, reversed) = if is_literal_one(&left) {
+        (unwrap_or_return_none!(right.as_r_call()).clone(), false)
+    } else if is_literal_one(&right) {
+        (unwrap_or_return_none!(left.as_r_call()).clone(), true)
+    } else {
         return Ok(None);
-    }
+    };
 
-    let right_fun = right_call.function()?;
-    let right_fun_name = get_function_name(right_fun);
-    if !["length", "nrow", "ncol", "NROW", "NCOL"].contains(&right_fun_name.as_str()) {
+    let fun_name = get_function_name(call.function()?);
+    if !["length", "nrow", "ncol", "NROW", "NCOL"].contains(&fun_name.as_str()) {
         return Ok(None);
     }
 
-    let right_fun_content = right_call
+    let fun_content = call
         .arguments()?
         .items()
         .into_iter()
@@ -74,36 +90,35 @@ pub fn seq(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>> {
         .collect::<Vec<String>>()
         .join(", ");
 
-    let (suggestion, replacement) = match right_fun_name.as_str() {
+    let (suggestion, replacement) = match fun_name.as_str() {
         "length" => (
-            "seq_along(...)",
-            format!("seq_along({})", right_fun_content),
-        ),
-        "nrow" => (
-            "seq_len(nrow((...))",
-            format!("seq_len(nrow({}))", right_fun_content),
-        ),
-        "ncol" => (
-            "seq_len(ncol(...))",
-            format!("seq_len(ncol({}))", right_fun_content),
+            "seq_along(...)".to_string(),
+            format!("seq_along({fun_content})"),
         ),
-        "NROW" => (
-            "seq_len(NROW(...))",
-            format!("seq_len(NROW({}))", right_fun_content),
+        "nrow" | "ncol" | "NROW" | "NCOL" => (
+            format!("seq_len({fun_name}(...))"),
+            format!("seq_len({fun_name}({fun_content}))"),
         ),
-        "NCOL" => (
-            "seq_len(NCOL(...))",
-            format!("seq_len(NCOL({}))", right_fun_content),
-        ),
-        // We checked the choices of right_fun_name above.
+        // We checked the choices of fun_name above.
         _ => unreachable!(),
     };
 
+    let (pattern, side, suggestion, replacement) = if reversed {
+        (
+            format!("{fun_name}(...):1"),
+            "LHS",
+            format!("rev({suggestion})"),
+            format!("rev({replacement})"),
+        )
+    } else {
+        (format!("1:{fun_name}(...)"), "RHS", suggestion, replacement)
+    };
+
     let range = ast.syntax().text_trimmed_range();
     let diagnostic = Diagnostic::new(
         ViolationData::new(
             "seq".to_string(),
-            format!("`1:{right_fun_name}(...)` can be wrong if the RHS is 0.").to_string(),
+            format!("`{pattern}` can be wrong if the {side} is 0."),
             Some(format!("Use `{suggestion}` instead.").to_string()),
         ),
         range,
@@ -0,0 +1,159 @@
+pub(crate) mod options;
+pub(crate) mod shell_injection;
+
+#[cfg(test)]
+mod tests {
+    use crate::lints::base::shell_injection::options::ResolvedShellInjectionOptions;
+    use crate::lints::base::shell_injection::options::ShellInjectionOptions;
+    use crate::rule_options::ResolvedRuleOptions;
+    use crate::settings::{LinterSettings, Settings};
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "shell_injection", None)
+    }
+
+    fn snapshot_lint_with_settings(code: &str, settings: Settings) -> String {
+        format_diagnostics_with_settings(code, "shell_injection", None, Some(settings))
+    }
+
+    /// Build a `Settings` with custom `ShellInjectionOptions`.
+    fn settings_with_options(options: ShellInjectionOptions) -> Settings {
+        Settings {
+            linter: LinterSettings {
+                rule_options: ResolvedRuleOptions {
+                    shell_injection: ResolvedShellInjectionOptions::resolve(Some(&options))
+                        .unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_lint_shell_injection() {
+        expect_no_lint("system('ls -la')", "shell_injection", None);
+        expect_no_lint("system(cmd)", "shell_injection", None);
+        expect_no_lint(
+            "system2('ls', args = c('-la', dir))",
+            "shell_injection",
+            None,
+        );
+        // Only literals are pasted
+        expect_no_lint("system(paste('ls', '-la'))", "shell_injection", None);
+        expect_no_lint(
+            "system(paste('ls', '-la', sep = sep))",
+            "shell_injection",
+            None,
+        );
+        // Inputs are quoted
+        expect_no_lint(
+            "system(paste('cat', shQuote(file)))",
+            "shell_injection",
+            None,
+        );
+        expect_no_lint(
+            "system2('cat', args = paste(c('-n', shQuote(file)), collapse = ' '))",
+            "shell_injection",
+            None,
+        );
+        // Not a shell-invoking function
+        expect_no_lint("message(paste('cat', file))", "shell_injection", None);
+        expect_no_lint(
+            "system.file(paste0('extdata/', file))",
+            "shell_injection",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_shell_injection() {
+        assert_snapshot!(
+            snapshot_lint("system(paste(\"cat\", file))"),
+            @r#"
+        warning: shell_injection
+         --> <test>:1:1
+          |
+        1 | system(paste("cat", file))
+          | -------------------------- `system()` runs a command built with `paste()` through the shell, which can run arbitrary code.
+          |
+          = help: Pass the arguments as a vector to `system2()` and quote them with `shQuote()`.
+        Found 1 error.
+        "#
+        );
+        assert_snapshot!(
+            snapshot_lint("system2(\"cat\", args = paste(\"-n\", file))"),
+            @r#"
+        warning: shell_injection
+         --> <test>:1:1
+          |
+        1 | system2("cat", args = paste("-n", file))
+          | ---------------------------------------- `system2()` runs a command built with `paste()` through the shell, which can run arbitrary code.
+          |
+          = help: Pass the arguments as a vector to `system2()` and quote them with `shQuote()`.
+        Found 1 error.
+        "#
+        );
+        assert_snapshot!(
+            snapshot_lint("base::system(sprintf(\"ls %s\", dir), intern = TRUE)"),
+            @r#"
+        warning: shell_injection
+         --> <test>:1:1
+          |
+        1 | base::system(sprintf("ls %s", dir), intern = TRUE)
+          | -------------------------------------------------- `system()` runs a command built with `sprintf()` through the shell, which can run arbitrary code.
+          |
+          = help: Pass the arguments as a vector to `system2()` and quote them with `shQuote()`.
+        Found 1 error.
+        "#
+        );
+        assert_snapshot!(
+            snapshot_lint("shell(cmd = paste0(\"dir \", path))"),
+            @r#"
+        warning: shell_injection
+         --> <test>:1:1
+          |
+        1 | shell(cmd = paste0("dir ", path))
+          | --------------------------------- `shell()` runs a command built with `paste0()` through the shell, which can run arbitrary code.
+          |
+          = help: Pass the arguments as a vector to `system2()` and quote them with `shQuote()`.
+        Found 1 error.
+        "#
+        );
+    }
+
+    #[test]
+    fn test_shell_injection_options() {
+        let settings = settings_with_options(ShellInjectionOptions {
+            extend_functions: Some(vec!["run".to_string()]),
+            ..Default::default()
+        });
+        assert_snapshot!(
+            snapshot_lint_with_settings("run(paste(\"git log\", ref))", settings),
+            @r#"
+        warning: shell_injection
+         --> <test>:1:1
+          |
+        1 | run(paste("git log", ref))
+          | -------------------------- `run()` runs a command built with `paste()` through the shell, which can run arbitrary code.
+          |
+          = help: Pass the arguments as a vector to `system2()` and quote them with `shQuote()`.
+        Found 1 error.
+        "#
+        );
+
+        let settings = settings_with_options(ShellInjectionOptions {
+            functions: Some(vec!["system2".to_string()]),
+            ..Default::default()
+        });
+        expect_no_lint_with_settings(
+            "system(paste('cat', file))",
+            "shell_injection",
+            None,
+            settings,
+        );
+    }
+}
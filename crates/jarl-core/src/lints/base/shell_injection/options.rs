@@ -0,0 +1,40 @@
+use std::collections::HashSet;
+
+use crate::rule_options::resolve_with_extend;
+
+/// Default functions that run a command through the shell.
+const DEFAULT_FUNCTIONS: &[&str] = &["shell", "system", "system2"];
+
+/// TOML options for `[lint.shell_injection]`.
+///
+/// Use `functions` to fully replace the default list of functions that run a
+/// command through the shell. Use `extend-functions` to add to the default
+/// list. Specifying both is an error.
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct ShellInjectionOptions {
+    pub functions: Option<Vec<String>>,
+    pub extend_functions: Option<Vec<String>>,
+}
+
+/// Resolved options for the `shell_injection` rule, ready for use during
+/// linting.
+#[derive(Clone, Debug)]
+pub struct ResolvedShellInjectionOptions {
+    pub functions: HashSet<String>,
+}
+
+impl ResolvedShellInjectionOptions {
+    pub fn resolve(options: Option<&ShellInjectionOptions>) -> anyhow::Result<Self> {
+        let functions = resolve_with_extend(
+            options.and_then(|opts| opts.functions.as_ref()),
+            options.and_then(|opts| opts.extend_functions.as_ref()),
+            DEFAULT_FUNCTIONS,
+            "shell_injection",
+            "functions",
+        )?;
+
+        Ok(Self { functions })
+    }
+}
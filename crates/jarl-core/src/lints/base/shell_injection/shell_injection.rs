@@ -0,0 +1,156 @@
+use crate::checker::Checker;
+use crate::diagnostic::*;
+use crate::utils::{get_arg_by_name, get_function_name, get_unnamed_arg_by_position};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Functions that build a string by concatenating their inputs.
+const STRING_BUILDERS: &[&str] = &["paste", "paste0", "sprintf"];
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for calls to `system()`, `system2()`, and `shell()` whose command is
+/// built by concatenating strings and variables with `paste()`, `paste0()`, or
+/// `sprintf()`.
+///
+/// ## Why is this bad?
+///
+/// Those functions pass the command to the shell. If one of the variables
+/// pasted in the command comes from user input, it can contain characters that
+/// are interpreted by the shell (such as `;`, `|`, or `$()`) and run arbitrary
+/// commands. For instance, `system(paste("cat", file))` also deletes all files
+/// in the current directory if `file` is `"x; rm -rf ."`.
+///
+/// It is safer to pass the arguments as a vector to `system2()` and to quote
+/// each of them with `shQuote()`. Inputs that are already wrapped in
+/// `shQuote()` are not reported.
+///
+/// This rule is disabled by default and doesn't have an automatic fix.
+///
+/// ## Configuration
+///
+/// By default, `shell()`, `system()`, and `system2()` are checked. You can
+/// customise the list in `jarl.toml`:
+///
+/// ```toml
+/// [lint.shell_injection]
+/// # Replace the default list entirely:
+/// functions = ["system", "system2"]
+///
+/// # Or add to the defaults:
+/// extend-functions = ["run_command"]
+/// ```
+///
+/// ## Example
+///
+/// ```r
+/// system(paste("cat", file))
+/// system2("cat", args = paste("-n", file))
+/// ```
+///
+/// Use instead:
+/// ```r
+/// system2("cat", args = shQuote(file))
+/// system2("cat", args = c("-n", shQuote(file)))
+/// ```
+pub fn shell_injection(
+    ast: &RCall,
+    fn_name: &str,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if !checker
+        .rule_options
+        .shell_injection
+        .functions
+        .contains(fn_name)
+    {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+
+    // The command is named `command` in `system()` and `system2()`, and `cmd`
+    // in `shell()`. The arguments of `system2()` are in `args`.
+    let command = get_arg_by_name(&args, "command")
+        .or_else(|| get_arg_by_name(&args, "cmd"))
+        .or_else(|| get_unnamed_arg_by_position(&args, 1));
+    let system2_args = get_arg_by_name(&args, "args");
+
+    let builder = [command, system2_args]
+        .into_iter()
+        .flatten()
+        .filter_map(|arg| arg.value())
+        .find_map(|value| pasted_with_variables(&value));
+    let builder = unwrap_or_return_none!(builder);
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "shell_injection".to_string(),
+            format!(
+                "`{fn_name}()` runs a command built with `{builder}()` through the shell, which can run arbitrary code."
+            ),
+            Some(
+                "Pass the arguments as a vector to `system2()` and quote them with `shQuote()`."
+                    .to_string(),
+            ),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// If `value` is a call to `paste()`, `paste0()`, or `sprintf()` with at least
+/// one input that isn't a literal or quoted with `shQuote()`, return the name
+/// of this function.
+fn pasted_with_variables(value: &AnyRExpression) -> Option<String> {
+    let call = value.as_r_call()?;
+    let name = get_function_name(call.function().ok()?);
+    if !STRING_BUILDERS.contains(&name.as_str()) {
+        return None;
+    }
+
+    let has_variable = call
+        .arguments()
+        .ok()?
+        .items()
+        .iter()
+        .filter_map(|arg| arg.ok())
+        // `sep` and `collapse` are not part of the pasted inputs.
+        .filter(|arg| arg.name_clause().is_none())
+        .filter_map(|arg| arg.value())
+        .any(|value| !is_quoted_input(&value));
+
+    has_variable.then_some(name)
+}
+
+/// Whether `value` can safely be pasted in a shell command: a literal, a call
+/// to `shQuote()`, or a vector of those.
+fn is_quoted_input(value: &AnyRExpression) -> bool {
+    if value.as_any_r_value().is_some() {
+        return true;
+    }
+
+    let Some(call) = value.as_r_call() else {
+        return false;
+    };
+    let Ok(function) = call.function() else {
+        return false;
+    };
+
+    match get_function_name(function).as_str() {
+        "shQuote" => true,
+        "c" => call.arguments().is_ok_and(|args| {
+            args.items()
+                .iter()
+                .filter_map(|arg| arg.ok())
+                .filter_map(|arg| arg.value())
+                .all(|value| is_quoted_input(&value))
+        }),
+        _ => false,
+    }
+}
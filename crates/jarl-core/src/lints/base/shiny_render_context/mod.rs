@@ -0,0 +1,157 @@
+pub(crate) mod shiny_render_context;
+
+#[cfg(test)]
+mod tests {
+    use crate::check::check;
+    use crate::config::{ArgsConfig, build_config};
+    use crate::file_system::MemoryFileSystem;
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "shiny_render_context", None)
+    }
+
+    /// Messages of the diagnostics of `path` in a file tree made of `files`.
+    fn lint_in_memory(files: &[(&str, &str)], path: &str) -> Vec<String> {
+        let fs = files
+            .iter()
+            .fold(MemoryFileSystem::new(), |fs, (path, contents)| {
+                fs.with_file(path, contents)
+            });
+        let args = ArgsConfig {
+            files: vec![],
+            fix: false,
+            unsafe_fixes: false,
+            unsafe_fixes_rules: String::new(),
+            fix_only: false,
+            select: "shiny_render_context".to_string(),
+            extend_select: String::new(),
+            ignore: String::new(),
+            min_r_version: None,
+            allow_dirty: false,
+            allow_no_vcs: true,
+            respect_staged: false,
+            assignment: None,
+            preview: true,
+            profile: None,
+        };
+        let mut config = build_config(&args, None, vec![PathBuf::from(path)]).unwrap();
+        config.file_system = Arc::new(fs);
+
+        check(config)
+            .into_iter()
+            .flat_map(|(_, result)| result.unwrap())
+            .map(|diagnostic| diagnostic.message.body)
+            .collect()
+    }
+
+    #[test]
+    fn test_no_lint_shiny_render_context() {
+        expect_no_lint(
+            "server <- function(input, output) {\n  output$plot <- renderPlot(hist(x))\n}",
+            "shiny_render_context",
+            None,
+        );
+        expect_no_lint(
+            "output[[id]] <- renderText('a')",
+            "shiny_render_context",
+            None,
+        );
+        // Returned by the function or the reactive expression
+        expect_no_lint(
+            "plot_output <- function(x) {\n  x <- sort(x)\n  renderPlot(hist(x))\n}",
+            "shiny_render_context",
+            None,
+        );
+        expect_no_lint(
+            "r <- reactive({\n  renderPlot(hist(x))\n})",
+            "shiny_render_context",
+            None,
+        );
+        // Displayed in interactive documents
+        expect_no_lint("renderPlot(hist(x))", "shiny_render_context", None);
+        // Not a render function
+        expect_no_lint(
+            "f <- function() {\n  render('report.Rmd')\n  renderer(x)\n  1\n}",
+            "shiny_render_context",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_shiny_render_context() {
+        assert_snapshot!(
+            snapshot_lint("server <- function(input, output) {\n  renderPlot(hist(x))\n  output$a <- renderText('a')\n}"),
+            @"
+        warning: shiny_render_context
+         --> <test>:2:3
+          |
+        2 |   renderPlot(hist(x))
+          |   ------------------- The value of `renderPlot()` is discarded, so its output is never displayed.
+          |
+          = help: Assign it to an element of `output`, e.g. `output$x <- renderPlot(...)`.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("observeEvent(input$go, {\n  shiny::renderTable(head(x))\n})"),
+            @"
+        warning: shiny_render_context
+         --> <test>:2:3
+          |
+        2 |   shiny::renderTable(head(x))
+          |   --------------------------- The value of `renderTable()` is discarded, so its output is never displayed.
+          |
+          = help: Assign it to an element of `output`, e.g. `output$x <- renderTable(...)`.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("for (id in ids) {\n  DT::renderDataTable(x)\n}"),
+            @"
+        warning: shiny_render_context
+         --> <test>:2:3
+          |
+        2 |   DT::renderDataTable(x)
+          |   ---------------------- The value of `renderDataTable()` is discarded, so its output is never displayed.
+          |
+          = help: Assign it to an element of `output`, e.g. `output$x <- renderDataTable(...)`.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_shiny_render_context_in_app() {
+        let app = "\
+library(shiny)
+ui <- fluidPage(plotOutput('plot'))
+output$plot <- renderPlot(hist(x))
+server <- function(input, output) {
+  output$text <- renderText('a')
+}
+shinyApp(ui, server)
+";
+        assert_eq!(
+            lint_in_memory(&[("shinyapp/app.R", app)], "shinyapp/app.R"),
+            vec!["`renderPlot()` is called outside of the server function of the Shiny app."]
+        );
+
+        // Files of the `R/` folder are sourced when the app starts.
+        let files = [
+            ("shinysplit/ui.R", "fluidPage()"),
+            ("shinysplit/server.R", "function(input, output) {}"),
+            ("shinysplit/R/outputs.R", "output$a <- renderText('a')"),
+            ("shinysplit/helpers.R", "output$a <- renderText('a')"),
+        ];
+        assert_eq!(
+            lint_in_memory(&files, "shinysplit/R/outputs.R"),
+            vec!["`renderText()` is called outside of the server function of the Shiny app."]
+        );
+        // Other files can be sourced in the server function.
+        assert!(lint_in_memory(&files, "shinysplit/helpers.R").is_empty());
+    }
+}
@@ -0,0 +1,139 @@
+use crate::checker::Checker;
+use crate::diagnostic::*;
+use crate::utils::is_observer_handler;
+use crate::utils_ast::AstNodeExt;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for calls to Shiny render functions, such as `renderPlot()` or
+/// `renderText()`, whose output can never be displayed:
+///
+/// - calls outside of a function in the files that Shiny sources when the app
+///   runs (`app.R`, `ui.R`, `server.R`, `global.R`, and the files in `R/`),
+///   since the `output` of a session only exists in the server function;
+/// - calls whose value is discarded, e.g. because they are not the last
+///   expression of a block or because they are the last expression of an
+///   `observe()` or `observeEvent()` handler, whose value is ignored.
+///
+/// A folder is considered to be a Shiny app if it contains `app.R`, or both
+/// `ui.R` and `server.R`.
+///
+/// This rule is in preview, so it is only used with `--preview` or
+/// `preview = true` in `jarl.toml`.
+///
+/// ## Why is this bad?
+///
+/// A render function only creates the recipe of an output. The output is
+/// displayed only when this recipe is assigned to an element of `output` in
+/// the server function. Otherwise, the output is silently missing from the
+/// app, or the app fails when it starts because `output` doesn't exist.
+///
+/// ## Example
+///
+/// ```r
+/// # app.R
+/// output$histogram <- renderPlot(hist(faithful$eruptions))
+///
+/// server <- function(input, output, session) {
+///   observeEvent(input$refresh, {
+///     renderText(format(Sys.time()))
+///   })
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// # app.R
+/// server <- function(input, output, session) {
+///   output$histogram <- renderPlot(hist(faithful$eruptions))
+///
+///   output$time <- renderText({
+///     input$refresh
+///     format(Sys.time())
+///   })
+/// }
+/// ```
+///
+/// ## References
+///
+/// See [https://mastering-shiny.org/basic-reactivity.html](https://mastering-shiny.org/basic-reactivity.html)
+pub fn shiny_render_context(
+    ast: &RCall,
+    fn_name: &str,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if !is_render_function(fn_name) {
+        return Ok(None);
+    }
+
+    let node = ast.syntax();
+    let (message, help) = if checker.is_shiny_app_file && !is_in_function(node) {
+        (
+            format!("`{fn_name}()` is called outside of the server function of the Shiny app."),
+            format!(
+                "Move it to the server function and assign it to an element of `output`, e.g. `output$x <- {fn_name}(...)`."
+            ),
+        )
+    } else if is_discarded(node) {
+        (
+            format!("The value of `{fn_name}()` is discarded, so its output is never displayed."),
+            format!("Assign it to an element of `output`, e.g. `output$x <- {fn_name}(...)`."),
+        )
+    } else {
+        return Ok(None);
+    };
+
+    let diagnostic = Diagnostic::new(
+        ViolationData::new("shiny_render_context".to_string(), message, Some(help)),
+        node.text_trimmed_range(),
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Whether `fn_name` is a render function, e.g. `renderPlot` or
+/// `renderDataTable`.
+fn is_render_function(fn_name: &str) -> bool {
+    fn_name
+        .strip_prefix("render")
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| c.is_ascii_uppercase())
+}
+
+fn is_in_function(node: &RSyntaxNode) -> bool {
+    node.ancestors()
+        .any(|ancestor| ancestor.kind() == RSyntaxKind::R_FUNCTION_DEFINITION)
+}
+
+/// Whether the value of `node` is discarded: it is a statement of a block that
+/// is not the last one, or the last one of a loop body or of an observer.
+///
+/// Top-level statements are not considered since they are displayed in
+/// interactive documents, e.g. R Markdown documents with `runtime: shiny`.
+fn is_discarded(node: &RSyntaxNode) -> bool {
+    let Some(list) = node.parent().and_then(RExpressionList::cast) else {
+        return false;
+    };
+    let Some(block) = list.syntax().parent().and_then(RBracedExpressions::cast) else {
+        return false;
+    };
+
+    let is_last = list.iter().last().is_some_and(|last| last.syntax() == node);
+    if !is_last {
+        return true;
+    }
+
+    block.parent_is_for_body()
+        || block.parent_is_while_body()
+        || block
+            .syntax()
+            .parent()
+            .and_then(RRepeatStatement::cast)
+            .is_some()
+        || is_observer_handler(block.syntax())
+}
@@ -0,0 +1,179 @@
+pub(crate) mod options;
+pub(crate) mod split_apply_combine;
+
+#[cfg(test)]
+mod tests {
+    use crate::lints::base::split_apply_combine::options::{
+        PreferredBackend, ResolvedSplitApplyCombineOptions,
+    };
+    use crate::rule_options::ResolvedRuleOptions;
+    use crate::settings::{LinterSettings, Settings};
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "split_apply_combine", None)
+    }
+
+    fn settings_with_backend(backend: PreferredBackend) -> Settings {
+        Settings {
+            linter: LinterSettings {
+                rule_options: ResolvedRuleOptions {
+                    split_apply_combine: ResolvedSplitApplyCombineOptions { backend },
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_lint_split_apply_combine() {
+        expect_no_lint("lapply(split(df, df$g), f)", "split_apply_combine", None);
+        expect_no_lint("do.call(rbind, lapply(x, f))", "split_apply_combine", None);
+        expect_no_lint("do.call(rbind, res)", "split_apply_combine", None);
+        expect_no_lint(
+            "do.call(cbind, lapply(split(df, df$g), f))",
+            "split_apply_combine",
+            None,
+        );
+        expect_no_lint(
+            "do.call(rbind, Map(f, split(df, df$g)))",
+            "split_apply_combine",
+            None,
+        );
+        expect_no_lint(
+            "foo::do.call(rbind, lapply(split(df, df$g), f))",
+            "split_apply_combine",
+            None,
+        );
+        expect_no_lint(
+            "do.call(rbind, lapply(foo::split(df, df$g), f))",
+            "split_apply_combine",
+            None,
+        );
+        // The variables must be defined before `do.call()`, in the same scope.
+        expect_no_lint(
+            "do.call(rbind, res)\nres <- lapply(split(df, df$g), f)",
+            "split_apply_combine",
+            None,
+        );
+        expect_no_lint(
+            "g <- function() res <- lapply(split(df, df$g), f)\ndo.call(rbind, res)",
+            "split_apply_combine",
+            None,
+        );
+        expect_no_lint(
+            "res <- lapply(split(df, df$g), f)\nres <- lapply(x, f)\ndo.call(rbind, res)",
+            "split_apply_combine",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_split_apply_combine() {
+        assert_snapshot!(
+            snapshot_lint("do.call(rbind, lapply(split(df, df$g), f))"),
+            @"
+        warning: split_apply_combine
+         --> <test>:1:1
+          |
+        1 | do.call(rbind, lapply(split(df, df$g), f))
+          | ------------------------------------------ `do.call(rbind, ...)` combines the output of `lapply()` over the groups created by `split()`.
+          |
+          = help: Compute the result of all groups at once with `aggregate(. ~ g, data = df, FUN = ...)` or `tapply()` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("do.call('rbind', lapply(split(df, list(df$a, df$b)), f))"),
+            @"
+        warning: split_apply_combine
+         --> <test>:1:1
+          |
+        1 | do.call('rbind', lapply(split(df, list(df$a, df$b)), f))
+          | -------------------------------------------------------- `do.call(rbind, ...)` combines the output of `lapply()` over the groups created by `split()`.
+          |
+          = help: Compute the result of all groups at once with `aggregate(df, by = list(df$a, df$b), FUN = ...)` or `tapply()` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint(
+                "base::do.call(what = rbind, args = base::lapply(X = split(x = df, f = ~g), FUN = f))"
+            ),
+            @"
+        warning: split_apply_combine
+         --> <test>:1:1
+          |
+        1 | base::do.call(what = rbind, args = base::lapply(X = split(x = df, f = ~g), FUN = f))
+          | ------------------------------------------------------------------------------------ `do.call(rbind, ...)` combines the output of `lapply()` over the groups created by `split()`.
+          |
+          = help: Compute the result of all groups at once with `aggregate(. ~ g, data = df, FUN = ...)` or `tapply()` instead.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_lint_split_apply_combine_with_variables() {
+        assert_snapshot!(
+            snapshot_lint(
+                "f <- function(df) {\n  groups <- split(df, df$g)\n  res <- lapply(groups, summarize_group)\n  do.call(rbind, res)\n}"
+            ),
+            @"
+        warning: split_apply_combine
+         --> <test>:4:3
+          |
+        4 |   do.call(rbind, res)
+          |   ------------------- `do.call(rbind, ...)` combines the output of `lapply()` over the groups created by `split()`.
+          |
+          = help: Compute the result of all groups at once with `aggregate(. ~ g, data = df, FUN = ...)` or `tapply()` instead.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_lint_split_apply_combine_backend() {
+        let code = "do.call(rbind, lapply(split(df, df$g), f))";
+
+        assert_snapshot!(
+            format_diagnostics_with_settings(
+                code,
+                "split_apply_combine",
+                None,
+                Some(settings_with_backend(PreferredBackend::Dplyr)),
+            ),
+            @"
+        warning: split_apply_combine
+         --> <test>:1:1
+          |
+        1 | do.call(rbind, lapply(split(df, df$g), f))
+          | ------------------------------------------ `do.call(rbind, ...)` combines the output of `lapply()` over the groups created by `split()`.
+          |
+          = help: Compute the result of all groups at once with `dplyr::summarise(df, ..., .by = g)` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            format_diagnostics_with_settings(
+                code,
+                "split_apply_combine",
+                None,
+                Some(settings_with_backend(PreferredBackend::DataTable)),
+            ),
+            @"
+        warning: split_apply_combine
+         --> <test>:1:1
+          |
+        1 | do.call(rbind, lapply(split(df, df$g), f))
+          | ------------------------------------------ `do.call(rbind, ...)` combines the output of `lapply()` over the groups created by `split()`.
+          |
+          = help: Compute the result of all groups at once with `data.table::as.data.table(df)[, ..., by = g]` instead.
+        Found 1 error.
+        "
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use serde::Deserialize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PreferredBackend {
+    /// `aggregate()` or `tapply()`
+    Base,
+    /// `dplyr::summarise(.by = )`
+    Dplyr,
+    /// `DT[, j, by = ]`
+    DataTable,
+}
+
+/// TOML options for `[lint.split_apply_combine]`.
+///
+/// Use `backend` to specify which grouped operation is recommended. Valid
+/// values are `"base"` (the default), `"dplyr"`, and `"data.table"`.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct SplitApplyCombineOptions {
+    pub backend: Option<String>,
+}
+
+/// Resolved options for the `split_apply_combine` rule.
+#[derive(Clone, Debug)]
+pub struct ResolvedSplitApplyCombineOptions {
+    pub backend: PreferredBackend,
+}
+
+impl ResolvedSplitApplyCombineOptions {
+    pub fn resolve(options: Option<&SplitApplyCombineOptions>) -> anyhow::Result<Self> {
+        let backend = match options {
+            Some(opts) => match opts.backend.as_deref() {
+                Some("base") | None => PreferredBackend::Base,
+                Some("dplyr") => PreferredBackend::Dplyr,
+                Some("data.table") => PreferredBackend::DataTable,
+                Some(other) => {
+                    return Err(anyhow::anyhow!(
+                        "Invalid value for `backend` in `[lint.split_apply_combine]`: \"{other}\". \
+                         Expected \"base\", \"dplyr\", or \"data.table\"."
+                    ));
+                }
+            },
+            None => PreferredBackend::Base,
+        };
+
+        Ok(Self { backend })
+    }
+}
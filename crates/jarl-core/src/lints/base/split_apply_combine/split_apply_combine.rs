@@ -0,0 +1,247 @@
+use crate::diagnostic::*;
+use crate::lints::base::split_apply_combine::options::PreferredBackend;
+use crate::utils::{
+    enclosing_scope, get_arg_by_name_then_position, get_function_name,
+    get_function_namespace_prefix,
+};
+use air_r_syntax::*;
+use biome_rowan::{AstNode, TextSize};
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for data frames that are split by group with `split()`, processed
+/// with `lapply()`, and combined again with `do.call(rbind, ...)`. The three
+/// steps can be in a single expression or use intermediate variables defined
+/// in the same function.
+///
+/// This rule is disabled by default.
+///
+/// ## Why is this bad?
+///
+/// This "split-apply-combine" pattern creates one data frame per group and
+/// binds them one by one, which is slow when there are many groups. Grouped
+/// operations, such as `aggregate()`, `dplyr::summarise(.by = )`, or the `by`
+/// argument of `data.table`, compute the result of all groups at once.
+///
+/// The recommended alternative can be chosen with the `backend` option in
+/// `[lint.split_apply_combine]`: `"base"` (the default), `"dplyr"`, or
+/// `"data.table"`.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Example
+///
+/// ```r
+/// do.call(rbind, lapply(split(df, df$g), function(d) {
+///   data.frame(g = d$g[1], mean_x = mean(d$x))
+/// }))
+///
+/// f <- function(df) {
+///   groups <- split(df, df$g)
+///   res <- lapply(groups, summarize_group)
+///   do.call("rbind", res)
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// aggregate(x ~ g, data = df, FUN = mean)
+///
+/// # or with `backend = "dplyr"`
+/// dplyr::summarise(df, mean_x = mean(x), .by = g)
+///
+/// # or with `backend = "data.table"`
+/// data.table::as.data.table(df)[, list(mean_x = mean(x)), by = g]
+/// ```
+///
+/// ## References
+///
+/// See `?split`, `?aggregate`, `?dplyr::summarise`, and `?data.table::data.table`
+pub fn split_apply_combine(
+    ast: &RCall,
+    fn_name: &str,
+    ns_prefix: Option<&str>,
+    backend: PreferredBackend,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if fn_name != "do.call" || ns_prefix.is_some_and(|ns| ns != "base::") {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+    let what = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "what", 1));
+    let what = unwrap_or_return_none!(what.value());
+    if !is_rbind(&what) {
+        return Ok(None);
+    }
+    let list = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "args", 2));
+    let list = unwrap_or_return_none!(list.value());
+
+    let scope = enclosing_scope(ast.syntax());
+    let start = ast.syntax().text_trimmed_range().start();
+
+    let lapply_call = unwrap_or_return_none!(resolve_call(&list, "lapply", &scope, start)?);
+    let lapply_args = lapply_call.arguments()?.items();
+    let x = unwrap_or_return_none!(get_arg_by_name_then_position(&lapply_args, "X", 1));
+    let x = unwrap_or_return_none!(x.value());
+    let split_call = unwrap_or_return_none!(resolve_call(&x, "split", &scope, start)?);
+
+    let split_args = split_call.arguments()?.items();
+    let data = unwrap_or_return_none!(get_arg_by_name_then_position(&split_args, "x", 1));
+    let data = unwrap_or_return_none!(data.value()).to_trimmed_string();
+    let groups = unwrap_or_return_none!(get_arg_by_name_then_position(&split_args, "f", 2));
+    let groups = unwrap_or_return_none!(groups.value()).to_trimmed_string();
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "split_apply_combine".to_string(),
+            "`do.call(rbind, ...)` combines the output of `lapply()` over the groups created \
+             by `split()`."
+                .to_string(),
+            Some(help(&data, &groups, backend)),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Whether `what` is `rbind`, `"rbind"`, or `rbind.data.frame`.
+fn is_rbind(what: &AnyRExpression) -> bool {
+    let name = match what {
+        AnyRExpression::AnyRValue(value) => match value.as_r_string_value() {
+            Some(string) => {
+                let text = string.syntax().text_trimmed().to_string();
+                text[1..text.len() - 1].to_string()
+            }
+            None => return false,
+        },
+        _ => {
+            if get_function_namespace_prefix(what.clone()).is_some_and(|ns| ns != "base::") {
+                return false;
+            }
+            get_function_name(what.clone())
+        }
+    };
+    name == "rbind" || name == "rbind.data.frame"
+}
+
+/// Return the call to `base::<name>()` that `expr` refers to. This is either
+/// `expr` itself or, if `expr` is a variable, the value of its last assignment
+/// in `scope` before `before`.
+fn resolve_call(
+    expr: &AnyRExpression,
+    name: &str,
+    scope: &RSyntaxNode,
+    before: TextSize,
+) -> anyhow::Result<Option<RCall>> {
+    let expr = match expr {
+        AnyRExpression::RIdentifier(ident) => {
+            let variable = ident.syntax().text_trimmed().to_string();
+            let mut value = None;
+            find_last_assignment(scope, &variable, before, &mut value);
+            unwrap_or_return_none!(value)
+        }
+        _ => expr.clone(),
+    };
+
+    let call = unwrap_or_return_none!(expr.as_r_call());
+    let function = call.function()?;
+    if get_function_name(function.clone()) != name
+        || get_function_namespace_prefix(function).is_some_and(|ns| ns != "base::")
+    {
+        return Ok(None);
+    }
+    Ok(Some(call.clone()))
+}
+
+/// Store in `value` the value of the last assignment to `variable` that ends
+/// before `before`. Assignments in nested functions are ignored.
+pub(crate) fn find_last_assignment(
+    node: &RSyntaxNode,
+    variable: &str,
+    before: TextSize,
+    value: &mut Option<AnyRExpression>,
+) {
+    for child in node.children() {
+        let range = child.text_trimmed_range();
+        if range.start() >= before {
+            break;
+        }
+        if child.kind() == RSyntaxKind::R_FUNCTION_DEFINITION {
+            continue;
+        }
+        if range.end() <= before
+            && let Some(assigned) = assigned_value(&child, variable)
+        {
+            *value = Some(assigned);
+        }
+        find_last_assignment(&child, variable, before, value);
+    }
+}
+
+/// Value assigned to `variable` by `node`, e.g. `f(x)` in `variable <- f(x)`.
+fn assigned_value(node: &RSyntaxNode, variable: &str) -> Option<AnyRExpression> {
+    let binary = RBinaryExpression::cast_ref(node)?;
+    let RBinaryExpressionFields { left, operator, right } = binary.as_fields();
+    let (target, value) = match operator.ok()?.kind() {
+        RSyntaxKind::ASSIGN | RSyntaxKind::EQUAL => (left.ok()?, right.ok()?),
+        RSyntaxKind::ASSIGN_RIGHT => (right.ok()?, left.ok()?),
+        _ => return None,
+    };
+    let target = target.as_r_identifier()?;
+    (target.syntax().text_trimmed() == variable).then_some(value)
+}
+
+/// Suggest a grouped operation with the preferred backend, e.g.
+/// `dplyr::summarise(df, ..., .by = g)` for `split(df, df$g)`.
+fn help(data: &str, groups: &str, backend: PreferredBackend) -> String {
+    let data = if data.contains('\n') { "data" } else { data };
+    let groups = if groups.contains('\n') {
+        "groups"
+    } else {
+        groups
+    };
+    // `split(df, df$g)` and `split(df, ~g)` split by the column `g` of `df`.
+    let column = groups
+        .strip_prefix(&format!("{data}$"))
+        .or_else(|| groups.strip_prefix('~'))
+        .map(str::trim)
+        .filter(|column| {
+            !column.is_empty()
+                && column
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '.' || c == '_')
+        });
+
+    let suggestion = match (backend, column) {
+        (PreferredBackend::Base, Some(column)) => {
+            format!("`aggregate(. ~ {column}, data = {data}, FUN = ...)` or `tapply()`")
+        }
+        (PreferredBackend::Base, None) => {
+            let by = if groups.starts_with("list(") {
+                groups.to_string()
+            } else {
+                format!("list({groups})")
+            };
+            format!("`aggregate({data}, by = {by}, FUN = ...)` or `tapply()`")
+        }
+        (PreferredBackend::Dplyr, column) => {
+            format!(
+                "`dplyr::summarise({data}, ..., .by = {})`",
+                column.unwrap_or(groups)
+            )
+        }
+        (PreferredBackend::DataTable, column) => {
+            format!(
+                "`data.table::as.data.table({data})[, ..., by = {}]`",
+                column.unwrap_or(groups)
+            )
+        }
+    };
+
+    format!("Compute the result of all groups at once with {suggestion} instead.")
+}
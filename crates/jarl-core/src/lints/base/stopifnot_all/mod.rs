@@ -1,7 +1,12 @@
+pub(crate) mod options;
 pub(crate) mod stopifnot_all;
 
 #[cfg(test)]
 mod tests {
+    use crate::lints::base::stopifnot_all::options::ResolvedStopifnotAllOptions;
+    use crate::lints::base::stopifnot_all::options::StopifnotAllOptions;
+    use crate::rule_options::ResolvedRuleOptions;
+    use crate::settings::{LinterSettings, Settings};
     use crate::utils_test::*;
     use insta::assert_snapshot;
 
@@ -9,6 +14,21 @@ mod tests {
         format_diagnostics(code, "stopifnot_all", None)
     }
 
+    /// Build a `Settings` with `report-if-stop = true`.
+    fn settings_report_if_stop() -> Settings {
+        let options = StopifnotAllOptions { report_if_stop: Some(true) };
+        Settings {
+            linter: LinterSettings {
+                rule_options: ResolvedRuleOptions {
+                    stopifnot_all: ResolvedStopifnotAllOptions::resolve(Some(&options)).unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
     #[test]
     fn test_no_lint_stopifnot_all() {
         expect_no_lint("all(x)", "stopifnot_all", None);
@@ -132,4 +152,72 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_no_lint_stopifnot_all_if_stop() {
+        // Only reported with `report-if-stop = true`.
+        expect_no_lint("if (!is.numeric(x)) stop('a')", "stopifnot_all", None);
+
+        let no_lint = |code: &str, min_r_version: Option<&str>| {
+            expect_no_lint_with_settings(
+                code,
+                "stopifnot_all",
+                min_r_version,
+                settings_report_if_stop(),
+            )
+        };
+        no_lint("if (is.numeric(x)) stop('a')", None);
+        no_lint("if (!is.numeric(x)) stop('a') else 1", None);
+        no_lint("if (!is.numeric(x)) stop(msg)", None);
+        no_lint("if (!is.numeric(x)) stop('a', call. = FALSE)", None);
+        no_lint("if (!is.numeric(x)) stop('a', 'b')", None);
+        no_lint(
+            "if (!is.numeric(x)) {\n  message('a')\n  stop('b')\n}",
+            None,
+        );
+        no_lint("if (!is.numeric(x)) warning('a')", None);
+        no_lint("y <- if (!is.numeric(x)) stop('a')", None);
+        // Named arguments of `stopifnot()` require R 4.0.0.
+        no_lint("if (!is.numeric(x)) stop('a')", Some("3.6"));
+    }
+
+    #[test]
+    fn test_lint_stopifnot_all_if_stop() {
+        let snapshot_lint = |code: &str| {
+            format_diagnostics_with_settings(
+                code,
+                "stopifnot_all",
+                None,
+                Some(settings_report_if_stop()),
+            )
+        };
+        assert_snapshot!(
+            snapshot_lint("if (!is.numeric(x)) stop(\"x must be numeric.\")"),
+            @r#"
+        warning: stopifnot_all
+         --> <test>:1:1
+          |
+        1 | if (!is.numeric(x)) stop("x must be numeric.")
+          | ---------------------------------------------- `if (!cond) stop(...)` can be written with `stopifnot()`.
+          |
+          = help: Use `stopifnot("x must be numeric." = is.numeric(x))` instead.
+        Found 1 error.
+        "#
+        );
+        assert_snapshot!(
+            snapshot_lint("if (!(x > 0)) {\n  base::stop()\n}"),
+            @"
+        warning: stopifnot_all
+         --> <test>:1:1
+          |
+        1 | / if (!(x > 0)) {
+        2 | |   base::stop()
+        3 | | }
+          | |_- `if (!cond) stop(...)` can be written with `stopifnot()`.
+          |
+          = help: Use `stopifnot(x > 0)` instead.
+        Found 1 error.
+        "
+        );
+    }
 }
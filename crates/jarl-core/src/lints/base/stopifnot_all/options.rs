@@ -0,0 +1,27 @@
+/// TOML options for `[lint.stopifnot_all]`.
+///
+/// Use `report-if-stop` to also report `if (!cond) stop("msg")`, which can be
+/// written `stopifnot("msg" = cond)`.
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct StopifnotAllOptions {
+    pub report_if_stop: Option<bool>,
+}
+
+/// Resolved options for the `stopifnot_all` rule, ready for use during
+/// linting.
+#[derive(Clone, Debug)]
+pub struct ResolvedStopifnotAllOptions {
+    pub report_if_stop: bool,
+}
+
+impl ResolvedStopifnotAllOptions {
+    pub fn resolve(options: Option<&StopifnotAllOptions>) -> anyhow::Result<Self> {
+        let report_if_stop = options
+            .and_then(|opts| opts.report_if_stop)
+            .unwrap_or(false);
+
+        Ok(Self { report_if_stop })
+    }
+}
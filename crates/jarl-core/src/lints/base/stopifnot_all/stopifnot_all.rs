@@ -1,6 +1,7 @@
+use crate::checker::Checker;
 use crate::diagnostic::*;
 use crate::utils::{get_arg_by_name, get_function_name, node_contains_comments};
-use air_r_syntax::{RArgument, RCall};
+use air_r_syntax::*;
 use biome_rowan::AstNode;
 
 pub struct StopifnotAll;
@@ -22,15 +23,27 @@ pub struct StopifnotAll;
 /// passing `--unsafe-fixes`. This is because `all()` coerces its arguments to
 /// logical vectors, so removing it can change runtime behavior.
 ///
+/// Use `report-if-stop = true` in `[lint.stopifnot_all]` to also report
+/// `if (!cond) stop("msg")`, which can be written `stopifnot("msg" = cond)`
+/// (or `stopifnot(cond)` when `stop()` has no message). Naming the arguments
+/// of `stopifnot()` requires R 4.0.0, so those statements are not reported
+/// with a message when the minimum R version is lower. They don't have an
+/// automatic fix.
+///
 /// ## Example
 ///
 /// ```r
 /// stopifnot(all(x > 0))
+///
+/// # With `report-if-stop = true`
+/// if (!is.numeric(x)) stop("`x` must be numeric.")
 /// ```
 ///
 /// Use instead:
 /// ```r
 /// stopifnot(x > 0)
+///
+/// stopifnot("`x` must be numeric." = is.numeric(x))
 /// ```
 ///
 /// ## References
@@ -86,3 +99,85 @@ pub fn stopifnot_all(ast: &RCall, fn_name: &str) -> anyhow::Result<Option<Diagno
         },
     )))
 }
+
+/// `if (!cond) stop("msg")`, only reported with `report-if-stop = true`.
+pub fn stopifnot_all_if(
+    ast: &RIfStatement,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if !checker.rule_options.stopifnot_all.report_if_stop || ast.else_clause().is_some() {
+        return Ok(None);
+    }
+    // Only statements, not values as in `x <- if (!cond) stop()`.
+    if ast
+        .syntax()
+        .parent()
+        .and_then(RExpressionList::cast)
+        .is_none()
+    {
+        return Ok(None);
+    }
+
+    let AnyRExpression::RUnaryExpression(negation) = ast.condition()? else {
+        return Ok(None);
+    };
+    if negation.operator()?.kind() != RSyntaxKind::BANG {
+        return Ok(None);
+    }
+    let mut condition = negation.argument()?;
+    while let AnyRExpression::RParenthesizedExpression(parenthesized) = condition {
+        condition = parenthesized.body()?;
+    }
+
+    let mut consequence = ast.consequence()?;
+    if let AnyRExpression::RBracedExpressions(braced) = &consequence {
+        let mut expressions = braced.expressions().iter();
+        let (Some(expression), None) = (expressions.next(), expressions.next()) else {
+            return Ok(None);
+        };
+        consequence = expression;
+    }
+    let AnyRExpression::RCall(stop_call) = consequence else {
+        return Ok(None);
+    };
+    if get_function_name(stop_call.function()?) != "stop" {
+        return Ok(None);
+    }
+
+    // `stopifnot()` can only use a fixed message, given as the name of the
+    // condition.
+    let mut stop_args = stop_call.arguments()?.items().iter();
+    let condition = condition.to_trimmed_string();
+    let replacement = match (stop_args.next(), stop_args.next()) {
+        (None, _) => format!("stopifnot({condition})"),
+        (Some(arg), None) => {
+            let arg = arg?;
+            let is_string = arg.value().is_some_and(|value| {
+                value
+                    .as_any_r_value()
+                    .is_some_and(|value| value.as_r_string_value().is_some())
+            });
+            let before_r_4 = checker
+                .minimum_r_version
+                .is_some_and(|version| version < (4, 0, 0));
+            if arg.name_clause().is_some() || !is_string || before_r_4 {
+                return Ok(None);
+            }
+            format!("stopifnot({} = {condition})", arg.to_trimmed_string())
+        }
+        _ => return Ok(None),
+    };
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "stopifnot_all".to_string(),
+            "`if (!cond) stop(...)` can be written with `stopifnot()`.".to_string(),
+            Some(format!("Use `{replacement}` instead.")),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
@@ -0,0 +1,134 @@
+pub(crate) mod suspicious_rounding;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "suspicious_rounding", None)
+    }
+
+    #[test]
+    fn test_no_lint_suspicious_rounding() {
+        expect_no_lint("round(x) == 3", "suspicious_rounding", None);
+        expect_no_lint("floor(x) == y", "suspicious_rounding", None);
+        expect_no_lint("ceiling(x / 2) == n", "suspicious_rounding", None);
+        expect_no_lint("round(x, 0) == 3", "suspicious_rounding", None);
+        expect_no_lint("round(x, digits = -2) == 1200", "suspicious_rounding", None);
+        expect_no_lint("floor(x) == 2.0", "suspicious_rounding", None);
+        // Exact for whole numbers
+        expect_no_lint("floor(x / 2) == x / 2", "suspicious_rounding", None);
+        expect_no_lint("sqrt(n) == round(sqrt(n))", "suspicious_rounding", None);
+        expect_no_lint("round(x, 2) < 0.3", "suspicious_rounding", None);
+        expect_no_lint("abs(round(x, 2) - 0.3) < 1e-8", "suspicious_rounding", None);
+        expect_no_lint("foo(x, 2) == 0.3", "suspicious_rounding", None);
+        expect_no_lint("trunc(x)", "suspicious_rounding", None);
+        expect_no_lint("trunc(x * 2)", "suspicious_rounding", None);
+        expect_no_lint("a %/% b", "suspicious_rounding", None);
+        expect_no_lint("floor(a / b)", "suspicious_rounding", None);
+    }
+
+    #[test]
+    fn test_lint_suspicious_rounding_decimal_places() {
+        assert_snapshot!(
+            snapshot_lint("round(x, 2) == 0.3"),
+            @"
+        warning: suspicious_rounding
+         --> <test>:1:1
+          |
+        1 | round(x, 2) == 0.3
+          | ------------------ Comparing the result of `round()` with decimal places using `==` can be wrong because of floating point errors.
+          |
+          = help: Compare with a tolerance instead, e.g. `abs(x - y) < 1e-8`.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("y != round(x, digits = n)"),
+            @"
+        warning: suspicious_rounding
+         --> <test>:1:1
+          |
+        1 | y != round(x, digits = n)
+          | ------------------------- Comparing the result of `round()` with decimal places using `!=` can be wrong because of floating point errors.
+          |
+          = help: Compare with a tolerance instead, e.g. `abs(x - y) < 1e-8`.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_lint_suspicious_rounding_floating_value() {
+        assert_snapshot!(
+            snapshot_lint("floor(x / 0.1) == x / 0.1"),
+            @"
+        warning: suspicious_rounding
+         --> <test>:1:1
+          |
+        1 | floor(x / 0.1) == x / 0.1
+          | ------------------------- Comparing the result of `floor()` to a floating point value using `==` can be wrong because of floating point errors.
+          |
+          = help: Compare with a tolerance instead, e.g. `abs(x - y) < 1e-8`.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("log(x, 10) == ceiling(log(x, 10))"),
+            @"
+        warning: suspicious_rounding
+         --> <test>:1:1
+          |
+        1 | log(x, 10) == ceiling(log(x, 10))
+          | --------------------------------- Comparing the result of `ceiling()` to a floating point value using `==` can be wrong because of floating point errors.
+          |
+          = help: Compare with a tolerance instead, e.g. `abs(x - y) < 1e-8`.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("round(x) != (y + 0.5)"),
+            @"
+        warning: suspicious_rounding
+         --> <test>:1:1
+          |
+        1 | round(x) != (y + 0.5)
+          | --------------------- Comparing the result of `round()` to a floating point value using `!=` can be wrong because of floating point errors.
+          |
+          = help: Compare with a tolerance instead, e.g. `abs(x - y) < 1e-8`.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_lint_suspicious_trunc() {
+        assert_snapshot!(
+            snapshot_lint("n_pages <- trunc(n_items / page_size)"),
+            @"
+        warning: suspicious_rounding
+         --> <test>:1:12
+          |
+        1 | n_pages <- trunc(n_items / page_size)
+          |            -------------------------- `trunc()` rounds towards zero, so `trunc(a / b)` differs from `a %/% b` when `a / b` is negative.
+          |
+          = help: Use `a %/% b` for an integer division, or `floor(a / b)` to round down.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("trunc(x = (a - b) / 2)"),
+            @"
+        warning: suspicious_rounding
+         --> <test>:1:1
+          |
+        1 | trunc(x = (a - b) / 2)
+          | ---------------------- `trunc()` rounds towards zero, so `trunc(a / b)` differs from `a %/% b` when `a / b` is negative.
+          |
+          = help: Use `a %/% b` for an integer division, or `floor(a / b)` to round down.
+        Found 1 error.
+        "
+        );
+    }
+}
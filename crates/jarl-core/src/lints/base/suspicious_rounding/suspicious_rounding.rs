@@ -0,0 +1,258 @@
+use crate::diagnostic::*;
+use crate::utils::{get_arg_by_name_then_position, get_function_name};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for:
+///
+/// - comparisons with `==` or `!=` of the result of `round()` with decimal
+///   places, such as `round(x, 2) == 0.3`;
+/// - comparisons with `==` or `!=` of the result of `round()`, `floor()`, or
+///   `ceiling()` with a floating point value, such as
+///   `log(x, 10) == floor(log(x, 10))`;
+/// - `trunc(a / b)`, which is often used as an integer division.
+///
+/// This rule has no automatic fix.
+///
+/// ## Why is this bad?
+///
+/// Most decimal numbers can't be represented exactly as floating point
+/// numbers. `round(x, 2)` returns the floating point number that is the
+/// closest to the rounded value, which is not necessarily equal to the result
+/// of another computation:
+///
+/// ```r
+/// round(0.1 + 0.2, 1) == 0.1 * 3
+/// #> [1] FALSE
+/// ```
+///
+/// `round()`, `floor()`, and `ceiling()` without decimal places return whole
+/// numbers, but the value they are compared with may not be exactly a whole
+/// number because of floating point errors. For instance,
+/// `log(x, 10) == floor(log(x, 10))` is `FALSE` for `x <- 1000` since
+/// `log(1000, 10)` is slightly lower than 3.
+///
+/// Finally, `trunc()` rounds towards zero while `floor()` and `%/%` round
+/// down, so `trunc(a / b)` and `a %/% b` give different results when `a / b`
+/// is negative:
+///
+/// ```r
+/// trunc(-7 / 2)
+/// #> [1] -3
+/// -7 %/% 2
+/// #> [1] -4
+/// ```
+///
+/// This rule is in preview, so it is only used with `--preview` or
+/// `preview = true` in `jarl.toml`.
+///
+/// ## Example
+///
+/// ```r
+/// if (round(total, 2) == expected) {
+///   message("Balanced")
+/// }
+///
+/// n_pages <- trunc(n_items / page_size)
+/// ```
+///
+/// Use instead:
+/// ```r
+/// if (abs(total - expected) < 1e-8) {
+///   message("Balanced")
+/// }
+///
+/// n_pages <- n_items %/% page_size
+/// ```
+///
+/// ## References
+///
+/// See:
+///
+/// - `?Round`
+/// - [https://cran.r-project.org/doc/FAQ/R-FAQ.html#Why-doesn_0027t-R-think-these-numbers-are-equal_003f](https://cran.r-project.org/doc/FAQ/R-FAQ.html#Why-doesn_0027t-R-think-these-numbers-are-equal_003f)
+pub fn suspicious_rounding(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>> {
+    let RBinaryExpressionFields { left, operator, right } = ast.as_fields();
+
+    let operator = operator?;
+    let left = left?;
+    let right = right?;
+
+    if operator.kind() != RSyntaxKind::EQUAL2 && operator.kind() != RSyntaxKind::NOT_EQUAL {
+        return Ok(None);
+    }
+    let operator = operator.text_trimmed();
+
+    let msg = if let Some(fn_name) = [&left, &right]
+        .into_iter()
+        .find_map(rounding_with_decimal_places)
+    {
+        format!(
+            "Comparing the result of `{fn_name}()` with decimal places using `{operator}` can be wrong because of floating point errors."
+        )
+    } else if let Some(fn_name) = rounding_function(&left).filter(|_| is_floating_value(&right)) {
+        format!(
+            "Comparing the result of `{fn_name}()` to a floating point value using `{operator}` can be wrong because of floating point errors."
+        )
+    } else if let Some(fn_name) = rounding_function(&right).filter(|_| is_floating_value(&left)) {
+        format!(
+            "Comparing the result of `{fn_name}()` to a floating point value using `{operator}` can be wrong because of floating point errors."
+        )
+    } else {
+        return Ok(None);
+    };
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "suspicious_rounding".to_string(),
+            msg,
+            Some("Compare with a tolerance instead, e.g. `abs(x - y) < 1e-8`.".to_string()),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+pub fn suspicious_trunc(ast: &RCall, fn_name: &str) -> anyhow::Result<Option<Diagnostic>> {
+    if fn_name != "trunc" {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+    let Some(value) = get_arg_by_name_then_position(&args, "x", 1).and_then(|arg| arg.value())
+    else {
+        return Ok(None);
+    };
+    if !is_division(&value) {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "suspicious_rounding".to_string(),
+            "`trunc()` rounds towards zero, so `trunc(a / b)` differs from `a %/% b` when `a / b` is negative.".to_string(),
+            Some("Use `a %/% b` for an integer division, or `floor(a / b)` to round down.".to_string()),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Return the name of the function if `expr` is a call to `round()`,
+/// `floor()`, or `ceiling()`.
+fn rounding_function(expr: &AnyRExpression) -> Option<&'static str> {
+    let call = expr.as_r_call()?;
+    let fn_name = get_function_name(call.function().ok()?);
+    ["round", "floor", "ceiling"]
+        .into_iter()
+        .find(|name| *name == fn_name)
+}
+
+/// Return `"round"` if `expr` is a call to `round()` with a number of decimal
+/// places that is not known to be zero or negative, e.g. `round(x, 2)`.
+fn rounding_with_decimal_places(expr: &AnyRExpression) -> Option<&'static str> {
+    let call = expr.as_r_call()?;
+    if get_function_name(call.function().ok()?) != "round" {
+        return None;
+    }
+    let args = call.arguments().ok()?.items();
+    let digits = get_arg_by_name_then_position(&args, "digits", 2)?.value()?;
+
+    // `round(x, 0)` and `round(x, -2)` return whole numbers.
+    let is_whole = match &digits {
+        AnyRExpression::AnyRValue(value) => literal_value(value) == Some(0.0),
+        AnyRExpression::RUnaryExpression(unary) => {
+            unary
+                .operator()
+                .is_ok_and(|operator| operator.text_trimmed() == "-")
+                && unary
+                    .argument()
+                    .ok()
+                    .and_then(|argument| argument.as_any_r_value().and_then(literal_value))
+                    .is_some()
+        }
+        _ => false,
+    };
+
+    (!is_whole).then_some("round")
+}
+
+/// Whether `expr` is likely to be inexact because it contains a decimal number
+/// or a call to a function like `log()`, e.g. `x / 0.1` or `log(x, 10)`.
+fn is_floating_value(expr: &AnyRExpression) -> bool {
+    match expr {
+        AnyRExpression::RParenthesizedExpression(parenthesized) => parenthesized
+            .body()
+            .is_ok_and(|body| is_floating_value(&body)),
+        AnyRExpression::RUnaryExpression(unary) => unary
+            .argument()
+            .is_ok_and(|argument| is_floating_value(&argument)),
+        AnyRExpression::RBinaryExpression(binary) => {
+            let Ok(operator) = binary.operator() else {
+                return false;
+            };
+            match operator.text_trimmed() {
+                "+" | "-" | "*" | "/" | "^" => {
+                    binary.left().is_ok_and(|left| is_floating_value(&left))
+                        || binary.right().is_ok_and(|right| is_floating_value(&right))
+                }
+                _ => false,
+            }
+        }
+        AnyRExpression::AnyRValue(value) => {
+            value.as_r_double_value().is_some()
+                && literal_value(value).is_some_and(|value| value.fract() != 0.0)
+        }
+        AnyRExpression::RCall(call) => {
+            let Ok(function) = call.function() else {
+                return false;
+            };
+            matches!(
+                get_function_name(function).as_str(),
+                "exp" | "log" | "log2" | "log10" | "log1p" | "sin" | "cos" | "tan"
+            )
+        }
+        _ => false,
+    }
+}
+
+/// Whether `expr` is a division, possibly in parentheses, e.g. `(a / b)`.
+fn is_division(expr: &AnyRExpression) -> bool {
+    match expr {
+        AnyRExpression::RParenthesizedExpression(parenthesized) => {
+            parenthesized.body().is_ok_and(|body| is_division(&body))
+        }
+        AnyRExpression::RBinaryExpression(binary) => binary
+            .operator()
+            .is_ok_and(|operator| operator.text_trimmed() == "/"),
+        _ => false,
+    }
+}
+
+/// The value of a numeric literal, e.g. `2`, `2L`, `0.5`, or `.5`.
+fn literal_value(value: &AnyRValue) -> Option<f64> {
+    let text = if let Some(double) = value.as_r_double_value() {
+        double.value_token().ok()?.text_trimmed().to_string()
+    } else if let Some(int) = value.as_r_integer_value() {
+        let token = int.value_token().ok()?;
+        let text = token.text_trimmed();
+        text.strip_suffix('L').unwrap_or(text).to_string()
+    } else {
+        return None;
+    };
+    if text.starts_with('.') {
+        format!("0{text}").parse().ok()
+    } else {
+        text.parse().ok()
+    }
+}
@@ -0,0 +1,83 @@
+pub(crate) mod sys_setlocale;
+
+#[cfg(test)]
+mod tests {
+    use crate::check::check;
+    use crate::config::{ArgsConfig, build_config};
+    use crate::file_system::MemoryFileSystem;
+    use crate::utils_test::*;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    /// Number of diagnostics in each file made of `files`.
+    fn lint_files(files: &[(&str, &str)]) -> Vec<(String, usize)> {
+        let fs = files
+            .iter()
+            .fold(MemoryFileSystem::new(), |fs, (path, contents)| {
+                fs.with_file(path, contents)
+            });
+        let paths: Vec<PathBuf> = files
+            .iter()
+            .filter(|(path, _)| path.ends_with(".R"))
+            .map(|(path, _)| PathBuf::from(path))
+            .collect();
+        let args = ArgsConfig {
+            files: vec![],
+            fix: false,
+            unsafe_fixes: false,
+            unsafe_fixes_rules: String::new(),
+            fix_only: false,
+            select: "sys_setlocale".to_string(),
+            extend_select: String::new(),
+            ignore: String::new(),
+            min_r_version: None,
+            allow_dirty: false,
+            allow_no_vcs: true,
+            respect_staged: false,
+            assignment: None,
+            preview: false,
+            profile: None,
+        };
+        let mut config = build_config(&args, None, paths).unwrap();
+        config.file_system = Arc::new(fs);
+
+        let mut results: Vec<(String, usize)> = check(config)
+            .into_iter()
+            .map(|(path, result)| (path, result.unwrap().len()))
+            .collect();
+        results.sort();
+        results
+    }
+
+    #[test]
+    fn test_no_lint_sys_setlocale_outside_package() {
+        expect_no_lint("Sys.setlocale('LC_COLLATE', 'C')", "sys_setlocale", None);
+    }
+
+    #[test]
+    fn test_sys_setlocale() {
+        let results = lint_files(&[
+            ("localepkg/DESCRIPTION", "Package: localepkg"),
+            (
+                "localepkg/R/sort.R",
+                "f <- function(x) {\n  Sys.setlocale('LC_COLLATE', 'C')\n  base::Sys.setlocale(locale = 'C')\n  sort(x)\n}\n",
+            ),
+            (
+                "localepkg/R/get.R",
+                "Sys.getlocale()\nfoo::Sys.setlocale()\n",
+            ),
+            (
+                "localepkg/tests/testthat/test-sort.R",
+                "Sys.setlocale('LC_COLLATE', 'C')\n",
+            ),
+        ]);
+        assert_eq!(
+            results,
+            vec![
+                ("localepkg/R/get.R".to_string(), 0),
+                ("localepkg/R/sort.R".to_string(), 2),
+                ("localepkg/tests/testthat/test-sort.R".to_string(), 0),
+            ]
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use crate::checker::Checker;
+use crate::diagnostic::*;
+use crate::package::FileScope;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for calls to `Sys.setlocale()` in the `R/` folder of a package.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Why is this bad?
+///
+/// The locale controls how strings are sorted and compared, how their case is
+/// changed, and how numbers and dates are formatted. Changing it in a package
+/// changes the behavior of all the code of the user that runs afterwards, and
+/// the locales that are available differ between operating systems, so the
+/// same call may fail or do something else on another machine.
+///
+/// Functions that depend on the locale often have arguments to avoid this,
+/// e.g. `method = "radix"` in `sort()` and `order()`, or `format` in
+/// `strftime()`.
+///
+/// This rule is in preview, so it is only used with `--preview` or
+/// `preview = true` in `jarl.toml`.
+///
+/// ## Example
+///
+/// ```r
+/// sort_names <- function(x) {
+///   Sys.setlocale("LC_COLLATE", "C")
+///   sort(x)
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// sort_names <- function(x) {
+///   sort(x, method = "radix")
+/// }
+/// ```
+///
+/// ## References
+///
+/// See `?Sys.setlocale` and `?Comparison`
+pub fn sys_setlocale(
+    ast: &RCall,
+    fn_name: &str,
+    ns_prefix: Option<&str>,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if fn_name != "Sys.setlocale" || !matches!(ns_prefix, None | Some("base::")) {
+        return Ok(None);
+    }
+    if checker.file_scope != Some(FileScope::R) {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "sys_setlocale".to_string(),
+            "`Sys.setlocale()` changes the locale of the whole R session.".to_string(),
+            Some(
+                "Use the arguments of the functions that depend on the locale instead, e.g. `method = \"radix\"` in `sort()`."
+                    .to_string(),
+            ),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
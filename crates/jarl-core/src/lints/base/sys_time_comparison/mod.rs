@@ -0,0 +1,64 @@
+pub(crate) mod sys_time_comparison;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "sys_time_comparison", None)
+    }
+
+    #[test]
+    fn test_no_lint_sys_time_comparison() {
+        expect_no_lint(
+            "Sys.time() > as.POSIXct('2024-01-01 10:00', tz = 'UTC')",
+            "sys_time_comparison",
+            None,
+        );
+        expect_no_lint("Sys.time() > deadline", "sys_time_comparison", None);
+        expect_no_lint("Sys.time() - start", "sys_time_comparison", None);
+        expect_no_lint("x > '2024-01-01'", "sys_time_comparison", None);
+        expect_no_lint("Sys.Date() > '2024-01-01'", "sys_time_comparison", None);
+        expect_no_lint(
+            "foo::Sys.time() > '2024-01-01'",
+            "sys_time_comparison",
+            None,
+        );
+        expect_no_lint(
+            "format(Sys.time(), '%Y') == '2024'",
+            "sys_time_comparison",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_sys_time_comparison() {
+        assert_snapshot!(
+            snapshot_lint("Sys.time() > '2024-01-01 10:00'"),
+            @r"
+        warning: sys_time_comparison
+         --> <test>:1:1
+          |
+        1 | Sys.time() > '2024-01-01 10:00'
+          | ------------------------------- Comparing `Sys.time()` to a string depends on the timezone of the machine.
+          |
+          = help: Convert the string with `as.POSIXct()` and an explicit `tz`.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("if ('2024-01-01' <= base::Sys.time()) 1"),
+            @r"
+        warning: sys_time_comparison
+         --> <test>:1:5
+          |
+        1 | if ('2024-01-01' <= base::Sys.time()) 1
+          |     -------------------------------- Comparing `Sys.time()` to a string depends on the timezone of the machine.
+          |
+          = help: Convert the string with `as.POSIXct()` and an explicit `tz`.
+        Found 1 error.
+        "
+        );
+    }
+}
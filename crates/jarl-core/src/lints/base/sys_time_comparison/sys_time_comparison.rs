@@ -0,0 +1,107 @@
+use crate::diagnostic::*;
+use crate::utils::{get_function_name, get_function_namespace_prefix};
+use air_r_syntax::*;
+use biome_rowan::{AstNode, AstSeparatedList};
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for comparisons between `Sys.time()` and a string, such as
+/// `Sys.time() > "2024-01-01 10:00"`.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Why is this bad?
+///
+/// To compare `Sys.time()` to a string, R converts the string with
+/// `as.POSIXct()` in the timezone of the machine. `"2024-01-01 10:00"` is
+/// therefore a different point in time in Paris and in New York, and the
+/// result of the comparison depends on where (and with which `TZ` environment
+/// variable) the code runs.
+///
+/// Converting the string with `as.POSIXct()` and an explicit `tz` makes the
+/// comparison independent of the machine.
+///
+/// This rule is in preview, so it is only used with `--preview` or
+/// `preview = true` in `jarl.toml`.
+///
+/// ## Example
+///
+/// ```r
+/// if (Sys.time() > "2024-01-01 10:00") {
+///   message("The deadline has passed")
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// if (Sys.time() > as.POSIXct("2024-01-01 10:00", tz = "UTC")) {
+///   message("The deadline has passed")
+/// }
+/// ```
+///
+/// ## References
+///
+/// See `?Sys.time` and `?as.POSIXct`
+pub fn sys_time_comparison(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>> {
+    let RBinaryExpressionFields { left, operator, right } = ast.as_fields();
+
+    let operator = operator?;
+    let left = left?;
+    let right = right?;
+    if !matches!(
+        operator.kind(),
+        RSyntaxKind::EQUAL2
+            | RSyntaxKind::NOT_EQUAL
+            | RSyntaxKind::LESS_THAN
+            | RSyntaxKind::LESS_THAN_OR_EQUAL_TO
+            | RSyntaxKind::GREATER_THAN
+            | RSyntaxKind::GREATER_THAN_OR_EQUAL_TO
+    ) {
+        return Ok(None);
+    }
+
+    let is_reported =
+        (is_sys_time(&left) && is_string(&right)) || (is_string(&left) && is_sys_time(&right));
+    if !is_reported {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "sys_time_comparison".to_string(),
+            "Comparing `Sys.time()` to a string depends on the timezone of the machine."
+                .to_string(),
+            Some("Convert the string with `as.POSIXct()` and an explicit `tz`.".to_string()),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Whether `expr` is a call to `Sys.time()` or `base::Sys.time()`.
+fn is_sys_time(expr: &AnyRExpression) -> bool {
+    let Some(call) = expr.as_r_call() else {
+        return false;
+    };
+    let Ok(function) = call.function() else {
+        return false;
+    };
+    if !matches!(
+        get_function_namespace_prefix(function.clone()).as_deref(),
+        None | Some("base::")
+    ) {
+        return false;
+    }
+    get_function_name(function) == "Sys.time"
+        && call.arguments().is_ok_and(|args| args.items().is_empty())
+}
+
+fn is_string(expr: &AnyRExpression) -> bool {
+    expr.as_any_r_value()
+        .is_some_and(|value| value.as_r_string_value().is_some())
+}
@@ -0,0 +1,162 @@
+pub(crate) mod options;
+pub(crate) mod tibble_rownames;
+
+#[cfg(test)]
+mod tests {
+    use crate::lints::base::tibble_rownames::options::ResolvedTibbleRownamesOptions;
+    use crate::lints::base::tibble_rownames::options::TibbleRownamesOptions;
+    use crate::rule_options::ResolvedRuleOptions;
+    use crate::settings::{LinterSettings, Settings};
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "tibble_rownames", None)
+    }
+
+    /// Build a `Settings` with custom `TibbleRownamesOptions`.
+    fn settings_with_options(options: TibbleRownamesOptions) -> Settings {
+        Settings {
+            linter: LinterSettings {
+                rule_options: ResolvedRuleOptions {
+                    tibble_rownames: ResolvedTibbleRownamesOptions::resolve(Some(&options))
+                        .unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_lint_tibble_rownames() {
+        expect_no_lint("rownames(df)", "tibble_rownames", None);
+        expect_no_lint("rownames(data.frame(x = 1))", "tibble_rownames", None);
+        expect_no_lint(
+            "df <- as.data.frame(tbl)\nrownames(df) <- ids",
+            "tibble_rownames",
+            None,
+        );
+        // Removing the row names
+        expect_no_lint(
+            "df <- tibble(x = 1)\nrownames(df) <- NULL",
+            "tibble_rownames",
+            None,
+        );
+        // Only the last step of the pipeline matters
+        expect_no_lint(
+            "read_csv(f) |> as.data.frame() |> rownames()",
+            "tibble_rownames",
+            None,
+        );
+        // Assignments after the call or in other functions are ignored
+        expect_no_lint("rownames(df)\ndf <- tibble(x = 1)", "tibble_rownames", None);
+        expect_no_lint(
+            "f <- function() df <- tibble(x = 1)\nrownames(df)",
+            "tibble_rownames",
+            None,
+        );
+        expect_no_lint(
+            "df <- tibble(x = 1)\ndf <- as.data.frame(df)\nrownames(df) <- ids",
+            "tibble_rownames",
+            None,
+        );
+        expect_no_lint("colnames(tibble(x = 1))", "tibble_rownames", None);
+    }
+
+    #[test]
+    fn test_lint_tibble_rownames() {
+        assert_snapshot!(
+            snapshot_lint("rownames(tibble::tibble(x = 1))"),
+            @"
+        warning: tibble_rownames
+         --> <test>:1:1
+          |
+        1 | rownames(tibble::tibble(x = 1))
+          | ------------------------------- `rownames()` is used on the output of `tibble()`, which doesn't have row names.
+          |
+          = help: Store the row identifiers in a column, e.g. with `tibble::rownames_to_column()` before the pipeline.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("df |> left_join(other, by = 'id') |> row.names()"),
+            @"
+        warning: tibble_rownames
+         --> <test>:1:38
+          |
+        1 | df |> left_join(other, by = 'id') |> row.names()
+          |                                      ----------- `row.names()` is used on the output of `left_join()`, which doesn't have row names.
+          |
+          = help: Store the row identifiers in a column, e.g. with `tibble::rownames_to_column()` before the pipeline.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("df <- read_csv(f) %>% as_tibble\nrownames(df) <- df$id"),
+            @"
+        warning: tibble_rownames
+         --> <test>:2:1
+          |
+        2 | rownames(df) <- df$id
+          | ------------ `rownames<-` is used on the output of `as_tibble()`, which doesn't support row names.
+          |
+          = help: Store the row identifiers in a column instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("f <- function(x) {\n  out <- (summarise(x, n = n()))\n  rownames(out)\n}"),
+            @"
+        warning: tibble_rownames
+         --> <test>:3:3
+          |
+        3 |   rownames(out)
+          |   ------------- `rownames()` is used on the output of `summarise()`, which doesn't have row names.
+          |
+          = help: Store the row identifiers in a column, e.g. with `tibble::rownames_to_column()` before the pipeline.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_tibble_rownames_options() {
+        let settings = settings_with_options(TibbleRownamesOptions {
+            extend_functions: Some(vec!["filter".to_string()]),
+            ..Default::default()
+        });
+        assert_snapshot!(
+            format_diagnostics_with_settings(
+                "df |> filter(x > 1) |> rownames()",
+                "tibble_rownames",
+                None,
+                Some(settings),
+            ),
+            @"
+        warning: tibble_rownames
+         --> <test>:1:24
+          |
+        1 | df |> filter(x > 1) |> rownames()
+          |                        ---------- `rownames()` is used on the output of `filter()`, which doesn't have row names.
+          |
+          = help: Store the row identifiers in a column, e.g. with `tibble::rownames_to_column()` before the pipeline.
+        Found 1 error.
+        "
+        );
+
+        // `functions` replaces the default list.
+        let settings = settings_with_options(TibbleRownamesOptions {
+            functions: Some(vec!["filter".to_string()]),
+            ..Default::default()
+        });
+        expect_no_lint_with_settings("rownames(tibble(x = 1))", "tibble_rownames", None, settings);
+
+        let options = TibbleRownamesOptions {
+            functions: Some(vec!["filter".to_string()]),
+            extend_functions: Some(vec!["arrange".to_string()]),
+        };
+        assert!(ResolvedTibbleRownamesOptions::resolve(Some(&options)).is_err());
+    }
+}
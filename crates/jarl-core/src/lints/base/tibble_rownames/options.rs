@@ -0,0 +1,55 @@
+use std::collections::HashSet;
+
+use crate::rule_options::resolve_with_extend;
+
+/// Default functions whose output doesn't have row names.
+const DEFAULT_FUNCTIONS: &[&str] = &[
+    "as_tibble",
+    "count",
+    "full_join",
+    "inner_join",
+    "left_join",
+    "read_csv",
+    "read_csv2",
+    "read_delim",
+    "read_tsv",
+    "right_join",
+    "summarise",
+    "summarize",
+    "tibble",
+    "tribble",
+];
+
+/// TOML options for `[lint.tibble_rownames]`.
+///
+/// Use `functions` to fully replace the default list of functions whose
+/// output doesn't have row names. Use `extend-functions` to add to the
+/// default list. Specifying both is an error.
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct TibbleRownamesOptions {
+    pub functions: Option<Vec<String>>,
+    pub extend_functions: Option<Vec<String>>,
+}
+
+/// Resolved options for the `tibble_rownames` rule, ready for use during
+/// linting.
+#[derive(Clone, Debug)]
+pub struct ResolvedTibbleRownamesOptions {
+    pub functions: HashSet<String>,
+}
+
+impl ResolvedTibbleRownamesOptions {
+    pub fn resolve(options: Option<&TibbleRownamesOptions>) -> anyhow::Result<Self> {
+        let functions = resolve_with_extend(
+            options.and_then(|opts| opts.functions.as_ref()),
+            options.and_then(|opts| opts.extend_functions.as_ref()),
+            DEFAULT_FUNCTIONS,
+            "tibble_rownames",
+            "functions",
+        )?;
+
+        Ok(Self { functions })
+    }
+}
@@ -0,0 +1,161 @@
+use std::collections::HashSet;
+
+use crate::checker::Checker;
+use crate::diagnostic::*;
+use crate::lints::base::split_apply_combine::split_apply_combine::find_last_assignment;
+use crate::utils::{
+    enclosing_scope, get_arg_by_name_then_position, get_function_name, is_pipe, piped_input,
+};
+use air_r_syntax::*;
+use biome_rowan::{AstNode, TextSize};
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for calls to `rownames()` and `row.names()`, or to their
+/// replacement forms `rownames<-` and `row.names<-`, on objects created by
+/// functions that return a tibble, such as `tibble()`, `read_csv()` or the
+/// `dplyr` joins.
+///
+/// The object can be passed directly (`rownames(tibble(x = 1))`), piped
+/// (`df |> left_join(other) |> rownames()`), or stored in a variable that is
+/// assigned in the same scope. Only the last function applied to the object is
+/// considered. The list of functions whose output doesn't have row names can
+/// be changed with `functions` or `extend-functions` in
+/// `[lint.tibble_rownames]`.
+///
+/// This rule is disabled by default and doesn't have an automatic fix.
+///
+/// ## Why is this bad?
+///
+/// Tibbles don't support row names: `tibble()` and `as_tibble()` drop them,
+/// and so do many `dplyr` verbs even when their input is a `data.frame`.
+/// Reading the row names of such an object only returns the row numbers, and
+/// setting them is deprecated and lost at the next step of the pipeline, so
+/// code relying on them silently works on the wrong identifiers.
+///
+/// ## Example
+///
+/// ```r
+/// scores <- left_join(students, grades, by = "id")
+/// rownames(scores) <- scores$id
+/// scores["alice", ]
+/// ```
+///
+/// Use instead:
+/// ```r
+/// scores <- left_join(students, grades, by = "id")
+/// scores[scores$id == "alice", ]
+/// ```
+pub fn tibble_rownames(
+    ast: &RCall,
+    fn_name: &str,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if fn_name != "rownames" && fn_name != "row.names" {
+        return Ok(None);
+    }
+
+    let node = ast.syntax();
+    let assigned = assigned_value(node);
+    // Removing the row names is always fine.
+    if let Some(AnyRExpression::RNullExpression(_)) = assigned {
+        return Ok(None);
+    }
+
+    let object = match piped_input(node) {
+        Some(input) => input,
+        None => {
+            let args = ast.arguments()?.items();
+            let x = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "x", 1));
+            unwrap_or_return_none!(x.value())
+        }
+    };
+
+    let functions = &checker.rule_options.tibble_rownames.functions;
+    let scope = enclosing_scope(node);
+    let start = node.text_trimmed_range().start();
+    let producer = unwrap_or_return_none!(producer(&object, functions, &scope, start));
+
+    let (message, help) = if assigned.is_some() {
+        (
+            format!(
+                "`{fn_name}<-` is used on the output of `{producer}()`, which doesn't support row names."
+            ),
+            "Store the row identifiers in a column instead.",
+        )
+    } else {
+        (
+            format!(
+                "`{fn_name}()` is used on the output of `{producer}()`, which doesn't have row names."
+            ),
+            "Store the row identifiers in a column, e.g. with `tibble::rownames_to_column()` before the pipeline.",
+        )
+    };
+
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "tibble_rownames".to_string(),
+            message,
+            Some(help.to_string()),
+        ),
+        node.text_trimmed_range(),
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// The value assigned by `rownames(x) <- value` if `node` is the target of an
+/// assignment.
+fn assigned_value(node: &RSyntaxNode) -> Option<AnyRExpression> {
+    let binary = RBinaryExpression::cast(node.parent()?)?;
+    let RBinaryExpressionFields { left, operator, right } = binary.as_fields();
+    let (target, value) = match operator.ok()?.kind() {
+        RSyntaxKind::ASSIGN | RSyntaxKind::SUPER_ASSIGN | RSyntaxKind::EQUAL => {
+            (left.ok()?, right.ok()?)
+        }
+        RSyntaxKind::ASSIGN_RIGHT | RSyntaxKind::SUPER_ASSIGN_RIGHT => (right.ok()?, left.ok()?),
+        _ => return None,
+    };
+    (target.syntax() == node).then_some(value)
+}
+
+/// Name of the function in `functions` that created `expr`. If `expr` is a
+/// variable, this looks for its last assignment in `scope` before `before`.
+fn producer(
+    expr: &AnyRExpression,
+    functions: &HashSet<String>,
+    scope: &RSyntaxNode,
+    before: TextSize,
+) -> Option<String> {
+    match expr {
+        AnyRExpression::RParenthesizedExpression(expr) => {
+            producer(&expr.body().ok()?, functions, scope, before)
+        }
+        AnyRExpression::RCall(call) => {
+            let name = get_function_name(call.function().ok()?);
+            functions.contains(&name).then_some(name)
+        }
+        AnyRExpression::RBinaryExpression(binary) if is_pipe(&binary.operator().ok()?) => {
+            match binary.right().ok()? {
+                // `df %>% as_tibble`
+                AnyRExpression::RIdentifier(ident) => {
+                    let name = ident.syntax().text_trimmed().to_string();
+                    functions.contains(&name).then_some(name)
+                }
+                right => producer(&right, functions, scope, before),
+            }
+        }
+        AnyRExpression::RIdentifier(ident) => {
+            let variable = ident.syntax().text_trimmed().to_string();
+            let mut value = None;
+            find_last_assignment(scope, &variable, before, &mut value);
+            let value = value?;
+            let before = value.syntax().text_trimmed_range().start();
+            producer(&value, functions, scope, before)
+        }
+        _ => None,
+    }
+}
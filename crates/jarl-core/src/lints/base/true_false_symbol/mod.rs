@@ -8,6 +8,7 @@ mod tests {
     use crate::rule_options::ResolvedRuleOptions;
     use crate::settings::{LinterSettings, Settings};
     use crate::utils_test::*;
+    use insta::assert_snapshot;
 
     /// Build a `Settings` with custom `TrueFalseSymbolOptions`.
     fn settings_with_options(options: TrueFalseSymbolOptions) -> Settings {
@@ -20,6 +21,7 @@ mod tests {
                 },
                 ..Default::default()
             },
+            ..Default::default()
         }
     }
 
@@ -95,6 +97,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_fix_true_false_symbol() {
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "x <- T",
+                    "c(T, F)",
+                    "sum(x, na.rm = T)",
+                    "f <- function(x = F) if (x) T",
+                    "f <- function() {\n  g <- function(T) T\n  F\n}",
+                    "f <- function() {\n  T <- 1\n}\nx <- T",
+                ],
+                "true_false_symbol",
+                None
+            )
+        );
+    }
+
+    #[test]
+    fn test_no_fix_true_false_symbol_masked() {
+        // `T` and `F` are still reported but not fixed when they are defined
+        // in the same scope or in a parent scope.
+        assert_snapshot!(
+            "no_fix_output",
+            get_fixed_text(
+                vec![
+                    "T <- 1\nx <- T",
+                    "x <- F\nF = 1",
+                    "2 -> T\nT",
+                    "T[1] <- 1\nT",
+                    "assign('F', 1)\nF",
+                    "for (T in 1:3) print(T)",
+                    "f <- function(T) T",
+                    "f <- function(F) {\n  g <- function() F\n}",
+                    "f <- function() {\n  T <<- 1\n}\nx <- T",
+                ],
+                "true_false_symbol",
+                None
+            )
+        );
+    }
+
     // TODO
     // #[test]
     // fn test_true_false_symbol_in_function_args() {
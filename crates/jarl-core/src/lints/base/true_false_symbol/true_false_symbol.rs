@@ -1,6 +1,6 @@
 use crate::check::Checker;
 use crate::diagnostic::*;
-use crate::utils::get_function_name;
+use crate::utils::{assigned_name, get_function_name};
 use air_r_syntax::*;
 use biome_rowan::AstNode;
 
@@ -15,6 +15,12 @@ pub struct TrueFalseSymbol;
 /// something else, such as an object or a variable name, then no automatic
 /// fixes are applied.
 ///
+/// `T` (or `F`) is considered to be something else when it is assigned, used
+/// as a loop variable, or used as a parameter name in the function that
+/// contains it or in any function around it (or at the top level of the
+/// file). Assignments with `<<-` and `->>` are considered anywhere in the
+/// file.
+///
 /// ## Why is this bad?
 ///
 /// `T` and `F` are not reserved symbols (like `break`) and therefore can be
@@ -92,11 +98,11 @@ pub fn true_false_symbol(
     }
 
     let range = ast.syntax().text_trimmed_range();
-    let diagnostic = Diagnostic::new(
-        TrueFalseSymbol,
-        range,
+    let fix = if is_masked(ast.syntax(), name) {
+        Fix::empty()
+    } else {
         Fix {
-            content: if ast.syntax().text_trimmed() == "T" {
+            content: if name == "T" {
                 "TRUE".to_string()
             } else {
                 "FALSE".to_string()
@@ -104,8 +110,79 @@ pub fn true_false_symbol(
             start: range.start().into(),
             end: range.end().into(),
             to_skip: false,
-        },
-    );
+        }
+    };
+    let diagnostic = Diagnostic::new(TrueFalseSymbol, range, fix);
 
     Ok(Some(diagnostic))
 }
+
+/// Whether `name` is defined in one of the scopes containing `node`: the
+/// functions that contain it and the top level of the file.
+fn is_masked(node: &RSyntaxNode, name: &str) -> bool {
+    node.ancestors()
+        .filter(|ancestor| {
+            ancestor.kind() == RSyntaxKind::R_FUNCTION_DEFINITION || ancestor.parent().is_none()
+        })
+        .any(|scope| defines_name(&scope, name, false))
+}
+
+/// Whether `name` is defined in the scope of `node`. Definitions in nested
+/// functions only count if they use `<<-` or `->>` since they can modify the
+/// parent scopes.
+fn defines_name(node: &RSyntaxNode, name: &str, nested: bool) -> bool {
+    node.children().any(|child| {
+        if is_definition_of(&child, name, nested) {
+            return true;
+        }
+        let nested = nested || child.kind() == RSyntaxKind::R_FUNCTION_DEFINITION;
+        defines_name(&child, name, nested)
+    })
+}
+
+fn is_definition_of(node: &RSyntaxNode, name: &str, nested: bool) -> bool {
+    if let Some(binary) = RBinaryExpression::cast_ref(node) {
+        let Ok(operator) = binary.operator() else {
+            return false;
+        };
+        let target = match operator.kind() {
+            RSyntaxKind::SUPER_ASSIGN => binary.left(),
+            RSyntaxKind::SUPER_ASSIGN_RIGHT => binary.right(),
+            RSyntaxKind::ASSIGN | RSyntaxKind::EQUAL if !nested => binary.left(),
+            RSyntaxKind::ASSIGN_RIGHT if !nested => binary.right(),
+            _ => return false,
+        };
+        return target.is_ok_and(|target| assigned_name(&target).as_deref() == Some(name));
+    }
+
+    if nested {
+        return false;
+    }
+
+    if let Some(for_loop) = RForStatement::cast_ref(node) {
+        return for_loop
+            .variable()
+            .is_ok_and(|variable| variable.syntax().text_trimmed() == name);
+    }
+
+    // Parameters of the function, e.g. `function(T) T`.
+    if node.kind() == RSyntaxKind::R_PARAMETER {
+        return node.first_child().is_some_and(|param_name| {
+            param_name.kind() == RSyntaxKind::R_IDENTIFIER && param_name.text_trimmed() == name
+        });
+    }
+
+    // `assign("T", value)`.
+    if let Some(call) = RCall::cast_ref(node)
+        && let Ok(function) = call.function()
+        && get_function_name(function) == "assign"
+        && let Ok(arguments) = call.arguments()
+        && let Some(first) = arguments.items().iter().next().and_then(|arg| arg.ok())
+        && let Some(value) = first.value()
+    {
+        let text = value.to_trimmed_string();
+        return text == format!("\"{name}\"") || text == format!("'{name}'");
+    }
+
+    false
+}
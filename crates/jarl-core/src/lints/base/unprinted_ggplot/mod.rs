@@ -0,0 +1,185 @@
+pub(crate) mod unprinted_ggplot;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "unprinted_ggplot", None)
+    }
+
+    #[test]
+    fn test_no_lint_unprinted_ggplot() {
+        // Printed automatically at the top level
+        expect_no_lint("ggplot(d, aes(x)) + geom_bar()", "unprinted_ggplot", None);
+        expect_no_lint(
+            "{\n  ggplot(d, aes(x)) + geom_bar()\n  1\n}",
+            "unprinted_ggplot",
+            None,
+        );
+        // Returned by the function
+        expect_no_lint(
+            "f <- function(d) {\n  ggplot(d, aes(x)) + geom_bar()\n}",
+            "unprinted_ggplot",
+            None,
+        );
+        expect_no_lint(
+            "f <- function(d) ggplot(d, aes(x)) + geom_bar()",
+            "unprinted_ggplot",
+            None,
+        );
+        // Already printed or stored
+        expect_no_lint(
+            "for (i in 1:3) {\n  print(ggplot(d, aes(x)) + geom_bar())\n}",
+            "unprinted_ggplot",
+            None,
+        );
+        expect_no_lint(
+            "for (i in 1:3) {\n  p <- ggplot(d, aes(x)) + geom_bar()\n}",
+            "unprinted_ggplot",
+            None,
+        );
+        expect_no_lint(
+            "for (i in 1:3) {\n  plots[[i]] <- ggplot(d, aes(x)) + geom_bar()\n}",
+            "unprinted_ggplot",
+            None,
+        );
+        // Not a ggplot
+        expect_no_lint("for (i in 1:3) {\n  x + 1\n}", "unprinted_ggplot", None);
+        expect_no_lint(
+            "for (i in 1:3) {\n  foo::ggplot(d) + geom_bar()\n}",
+            "unprinted_ggplot",
+            None,
+        );
+        expect_no_lint(
+            "for (i in 1:3) {\n  ggplot(d) * geom_bar()\n}",
+            "unprinted_ggplot",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_unprinted_ggplot() {
+        assert_snapshot!(
+            snapshot_lint("for (v in vars) {\n  ggplot(d, aes(.data[[v]])) + geom_bar()\n}"),
+            @"
+        warning: unprinted_ggplot
+         --> <test>:2:3
+          |
+        2 |   ggplot(d, aes(.data[[v]])) + geom_bar()
+          |   --------------------------------------- This plot is created but not printed, so it is not rendered.
+          |
+          = help: Wrap it in `print()` to render it.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("f <- function(d) {\n  ggplot(d) + geom_bar()\n  summary(d)\n}"),
+            @"
+        warning: unprinted_ggplot
+         --> <test>:2:3
+          |
+        2 |   ggplot(d) + geom_bar()
+          |   ---------------------- This plot is created but not printed, so it is not rendered.
+          |
+          = help: Wrap it in `print()` to render it.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("while (TRUE) d |> ggplot2::ggplot() + geom_bar() + theme_bw()"),
+            @"
+        warning: unprinted_ggplot
+         --> <test>:1:14
+          |
+        1 | while (TRUE) d |> ggplot2::ggplot() + geom_bar() + theme_bw()
+          |              ------------------------------------------------ This plot is created but not printed, so it is not rendered.
+          |
+          = help: Wrap it in `print()` to render it.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("repeat {\n  d %>% ggplot() + geom_bar()\n  break\n}"),
+            @"
+        warning: unprinted_ggplot
+         --> <test>:2:3
+          |
+        2 |   d %>% ggplot() + geom_bar()
+          |   --------------------------- This plot is created but not printed, so it is not rendered.
+          |
+          = help: Wrap it in `print()` to render it.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_lint_unprinted_ggplot_in_nested_block() {
+        assert_snapshot!(
+            snapshot_lint("f <- function(d) {\n  if (show) {\n    ggplot(d) + geom_bar()\n    TRUE\n  }\n}"),
+            @"
+        warning: unprinted_ggplot
+         --> <test>:3:5
+          |
+        3 |     ggplot(d) + geom_bar()
+          |     ---------------------- This plot is created but not printed, so it is not rendered.
+          |
+          = help: Wrap it in `print()` to render it.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_lint_unprinted_ggplot_in_observer() {
+        assert_snapshot!(
+            snapshot_lint("observeEvent(input$go, {\n  ggplot(d) + geom_bar()\n})"),
+            @"
+        warning: unprinted_ggplot
+         --> <test>:2:3
+          |
+        2 |   ggplot(d) + geom_bar()
+          |   ---------------------- This plot is created but not printed, so it is not rendered.
+          |
+          = help: Wrap it in `print()` to render it.
+        Found 1 error.
+        "
+        );
+        // The value of reactive expressions and render functions is used.
+        expect_no_lint(
+            "renderPlot({\n  ggplot(d) + geom_bar()\n})",
+            "unprinted_ggplot",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_fix_unprinted_ggplot() {
+        assert_snapshot!(
+            get_unsafe_fixed_text(
+                vec![
+                    "for (i in 1:3) ggplot(d) + geom_bar()",
+                    "function(d) { ggplot(d) + geom_bar(); 1 }",
+                ],
+                "unprinted_ggplot",
+            ),
+            @"
+        OLD:
+        ====
+        for (i in 1:3) ggplot(d) + geom_bar()
+        NEW:
+        ====
+        for (i in 1:3) print(ggplot(d) + geom_bar())
+
+        OLD:
+        ====
+        function(d) { ggplot(d) + geom_bar(); 1 }
+        NEW:
+        ====
+        function(d) { print(ggplot(d) + geom_bar()); 1 }
+        "
+        );
+    }
+}
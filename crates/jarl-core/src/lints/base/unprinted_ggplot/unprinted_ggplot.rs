@@ -0,0 +1,159 @@
+use crate::diagnostic::*;
+use crate::utils::{
+    get_function_name, get_function_namespace_prefix, is_observer_handler, node_contains_comments,
+};
+use crate::utils_ast::AstNodeExt;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for plots built with `ggplot(...) + ...` whose value is discarded
+/// inside a loop or a function, so that the plot is never rendered.
+///
+/// ## Why is this bad?
+///
+/// A ggplot object is only rendered when it is printed. At the top level of a
+/// script, this is done automatically, but this is not the case in the body of
+/// a `for`, `while`, or `repeat` loop, for a statement that is not the last
+/// one in the body of a function, or for the last statement of a Shiny
+/// observer such as `observe()`. The plot is then silently not shown.
+///
+/// This rule has an unsafe automatic fix that wraps the plot in `print()`. It
+/// is unsafe because it changes the output of the code.
+///
+/// This rule is in preview, so it is only used with `--preview` or
+/// `preview = true` in `jarl.toml`.
+///
+/// ## Example
+///
+/// ```r
+/// for (var in c("cyl", "gear")) {
+///   ggplot(mtcars, aes(.data[[var]])) + geom_bar()
+/// }
+///
+/// plot_and_summarize <- function(data) {
+///   ggplot(data, aes(x, y)) + geom_point()
+///   summary(data)
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// for (var in c("cyl", "gear")) {
+///   print(ggplot(mtcars, aes(.data[[var]])) + geom_bar())
+/// }
+///
+/// plot_and_summarize <- function(data) {
+///   print(ggplot(data, aes(x, y)) + geom_point())
+///   summary(data)
+/// }
+/// ```
+///
+/// ## References
+///
+/// See [https://ggplot2.tidyverse.org/reference/print.ggplot.html](https://ggplot2.tidyverse.org/reference/print.ggplot.html)
+pub fn unprinted_ggplot(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>> {
+    if ast.operator()?.kind() != RSyntaxKind::PLUS {
+        return Ok(None);
+    }
+
+    if !is_discarded(ast.syntax()) || !starts_with_ggplot(ast)? {
+        return Ok(None);
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "unprinted_ggplot".to_string(),
+            "This plot is created but not printed, so it is not rendered.".to_string(),
+            Some("Wrap it in `print()` to render it.".to_string()),
+        ),
+        range,
+        Fix {
+            content: format!("print({})", ast.syntax().text_trimmed()),
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(ast.syntax()),
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Whether the value of the statement `node` is discarded: it is the body of a
+/// loop, the last statement in the braced body of a loop or of a Shiny observer
+/// (e.g. `observe({ ... })`), or any statement but the last one in a block
+/// that is in a loop or a function.
+///
+/// Non-final statements of top-level blocks are not considered since the
+/// plots are usually built interactively there.
+fn is_discarded(node: &RSyntaxNode) -> bool {
+    if is_loop_body(node) {
+        return true;
+    }
+
+    let Some(list) = node.parent().and_then(RExpressionList::cast) else {
+        return false;
+    };
+    let Some(block) = list.syntax().parent().and_then(RBracedExpressions::cast) else {
+        return false;
+    };
+
+    let is_last = list.iter().last().is_some_and(|last| last.syntax() == node);
+    if is_last {
+        return is_loop_body(block.syntax()) || is_observer_handler(block.syntax());
+    }
+
+    block.syntax().ancestors().any(|ancestor| {
+        matches!(
+            ancestor.kind(),
+            RSyntaxKind::R_FUNCTION_DEFINITION
+                | RSyntaxKind::R_FOR_STATEMENT
+                | RSyntaxKind::R_WHILE_STATEMENT
+                | RSyntaxKind::R_REPEAT_STATEMENT
+        )
+    })
+}
+
+/// Whether `node` is the body of a `for`, `while`, or `repeat` loop.
+fn is_loop_body(node: &RSyntaxNode) -> bool {
+    let Some(expr) = AnyRExpression::cast(node.clone()) else {
+        return false;
+    };
+    if expr.parent_is_for_body() || expr.parent_is_while_body() {
+        return true;
+    }
+    node.parent()
+        .and_then(RRepeatStatement::cast)
+        .and_then(|repeat| repeat.body().ok())
+        .is_some_and(|body| body.syntax() == node)
+}
+
+/// Whether the leftmost operand of the chain of `+` is a call to `ggplot()`,
+/// possibly at the end of a pipe, e.g. `data |> ggplot(aes(x, y))`.
+fn starts_with_ggplot(ast: &RBinaryExpression) -> anyhow::Result<bool> {
+    let mut left = ast.left()?;
+    while let Some(binary) = left.as_r_binary_expression() {
+        let operator = binary.operator()?;
+        let is_pipe = operator.kind() == RSyntaxKind::PIPE
+            || (operator.kind() == RSyntaxKind::SPECIAL && operator.text_trimmed() == "%>%");
+        if is_pipe {
+            left = binary.right()?;
+            break;
+        }
+        if operator.kind() != RSyntaxKind::PLUS {
+            return Ok(false);
+        }
+        left = binary.left()?;
+    }
+
+    let Some(call) = left.as_r_call() else {
+        return Ok(false);
+    };
+    let function = call.function()?;
+    Ok(get_function_name(function.clone()) == "ggplot"
+        && get_function_namespace_prefix(function).is_none_or(|ns| ns == "ggplot2::"))
+}
@@ -658,6 +658,25 @@ pub fn build_cfg(
     builder.build(func)
 }
 
+/// Build a control flow graph for the body of a loop
+///
+/// Only the body is built: `next` jumps back to the entry block and `break`
+/// jumps to the exit block, so the loop can be left if the exit block or a
+/// `return`/`stop()` is reachable from the entry.
+pub fn build_cfg_loop_body(
+    body: &RSyntaxNode,
+    stopping_functions: &HashSet<String>,
+) -> ControlFlowGraph {
+    let mut builder = CfgBuilder::new(stopping_functions);
+    let entry = builder.cfg.entry;
+    let exit = builder.cfg.exit;
+    builder
+        .loop_stack
+        .push(LoopContext { continue_target: entry, break_target: exit });
+    builder.build_expression(body, entry, exit);
+    builder.cfg
+}
+
 /// Build a control flow graph for top-level R code
 pub fn build_cfg_top_level(
     expressions: &[RSyntaxNode],
@@ -2,5 +2,5 @@ mod builder;
 mod graph;
 pub mod reachability;
 
-pub use builder::{build_cfg, build_cfg_top_level};
-pub use reachability::{UnreachableReason, find_unreachable_code};
+pub use builder::{build_cfg, build_cfg_loop_body, build_cfg_top_level};
+pub use reachability::{UnreachableReason, can_exit, find_unreachable_code};
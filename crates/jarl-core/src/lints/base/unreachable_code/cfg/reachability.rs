@@ -295,6 +295,23 @@ fn find_terminator_in_path(
     result
 }
 
+/// Check whether the control flow can leave the graph, i.e. whether the exit
+/// block or a block ending with `return` or `stop()` (or equivalent) is
+/// reachable from the entry block.
+///
+/// This is used on graphs built with `build_cfg_loop_body()` to detect loops
+/// that never end.
+pub fn can_exit(cfg: &ControlFlowGraph) -> bool {
+    use super::graph::Terminator;
+
+    find_reachable_blocks(cfg).iter().any(|&block_id| {
+        block_id == cfg.exit
+            || cfg.block(block_id).is_some_and(|block| {
+                matches!(block.terminator, Terminator::Return | Terminator::Stop)
+            })
+    })
+}
+
 /// Find all blocks reachable from the entry block using BFS (Breadth-First Search)
 ///
 /// BFS is a graph traversal algorithm that explores nodes level by level:
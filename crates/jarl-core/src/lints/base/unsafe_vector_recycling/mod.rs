@@ -0,0 +1,70 @@
+pub(crate) mod unsafe_vector_recycling;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "unsafe_vector_recycling", None)
+    }
+
+    #[test]
+    fn test_no_lint_unsafe_vector_recycling() {
+        expect_no_lint("c(1, 2) == c(3, 4)", "unsafe_vector_recycling", None);
+        expect_no_lint("c(1, 2, 3) == 1", "unsafe_vector_recycling", None);
+        expect_no_lint("x == c(1, 2)", "unsafe_vector_recycling", None);
+        expect_no_lint("1:4 * c(1, -1)", "unsafe_vector_recycling", None);
+        expect_no_lint(
+            "c(TRUE, FALSE) & c(TRUE, TRUE, FALSE, FALSE)",
+            "unsafe_vector_recycling",
+            None,
+        );
+        expect_no_lint("c(1, 2) %in% c(1, 2, 3)", "unsafe_vector_recycling", None);
+        expect_no_lint("c(1, 2) == NULL", "unsafe_vector_recycling", None);
+        expect_no_lint("c(x, y) == c(1, 2, 3)", "unsafe_vector_recycling", None);
+    }
+
+    #[test]
+    fn test_lint_unsafe_vector_recycling() {
+        assert_snapshot!(
+            snapshot_lint("c(1, 2, 3) == c(1, 2)"),
+            @"
+        warning: unsafe_vector_recycling
+         --> <test>:1:1
+          |
+        1 | c(1, 2, 3) == c(1, 2)
+          | --------------------- The operands of `==` have lengths 3 and 2, and the longer length is not a multiple of the shorter one.
+          |
+          = help: The shorter vector is partially recycled, which is likely a mistake.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("1:3 + (1:2)"),
+            @"
+        warning: unsafe_vector_recycling
+         --> <test>:1:1
+          |
+        1 | 1:3 + (1:2)
+          | ----------- The operands of `+` have lengths 3 and 2, and the longer length is not a multiple of the shorter one.
+          |
+          = help: The shorter vector is partially recycled, which is likely a mistake.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint(r#"c("a", "b") != c("b", "a", "b", "c")"#),
+            @r#"
+        warning: unsafe_vector_recycling
+         --> <test>:1:1
+          |
+        1 | c("a", "b") != c("b", "a", "b", "c")
+          | ------------------------------------ The operands of `!=` have lengths 2 and 4, so the shorter vector is recycled.
+          |
+          = help: Use `%in%` to check whether the values are in a set of values.
+        Found 1 error.
+        "#
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use crate::diagnostic::*;
+use crate::utils::static_length;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for comparisons, arithmetic, and logical operations between vectors
+/// whose lengths are known without running the code and don't match, for
+/// instance `c(1, 2, 3) == c(1, 2)` or `1:3 + 1:2`.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Why is this bad?
+///
+/// R recycles the shorter vector to the length of the longer one. When the
+/// longer length is not a multiple of the shorter one, this only gives a
+/// warning and the result is rarely what was intended. Comparing vectors of
+/// different lengths is often a mistake for `%in%`: `c("a", "b") == c("b",
+/// "a", "b", "c")` compares the elements pairwise instead of checking whether
+/// each element of the left vector is in the right one.
+///
+/// Operations between vectors of length 1 and longer vectors are ignored since
+/// recycling them is common and intended. Arithmetic and logical operations
+/// are only reported when the longer length is not a multiple of the shorter
+/// one, while comparisons are reported for all lengths that differ.
+///
+/// This rule doesn't check whether the conditions of `if` and `while` have a
+/// single element, e.g. `if (c(1, 2) > 1)`: this is done by `vector_condition`,
+/// which uses the same lengths. A condition such as `if (1:3 == 1:2)` is
+/// reported by both rules.
+///
+/// This rule is in preview, so it is only used with `--preview` or
+/// `preview = true` in `jarl.toml`.
+///
+/// ## Example
+///
+/// ```r
+/// c("a", "b") == c("b", "a", "b", "c")
+/// c(1, 2, 3) * c(10, 100)
+/// ```
+///
+/// Use instead:
+/// ```r
+/// c("a", "b") %in% c("b", "a", "b", "c")
+/// c(1, 2, 3) * c(10, 100, 1000)
+/// ```
+///
+/// ## References
+///
+/// See the section "Recycling rules" in `?Arithmetic` and `?Comparison`
+pub fn unsafe_vector_recycling(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic>> {
+    let RBinaryExpressionFields { left, operator, right } = ast.as_fields();
+    let operator = operator?;
+
+    let is_comparison = matches!(
+        operator.kind(),
+        RSyntaxKind::EQUAL2
+            | RSyntaxKind::NOT_EQUAL
+            | RSyntaxKind::LESS_THAN
+            | RSyntaxKind::LESS_THAN_OR_EQUAL_TO
+            | RSyntaxKind::GREATER_THAN
+            | RSyntaxKind::GREATER_THAN_OR_EQUAL_TO
+    );
+    let is_elementwise = matches!(
+        operator.kind(),
+        RSyntaxKind::PLUS
+            | RSyntaxKind::MINUS
+            | RSyntaxKind::MULTIPLY
+            | RSyntaxKind::DIVIDE
+            | RSyntaxKind::AND
+            | RSyntaxKind::OR
+    );
+    if !is_comparison && !is_elementwise {
+        return Ok(None);
+    }
+
+    let left_length = unwrap_or_return_none!(static_length(&left?));
+    let right_length = unwrap_or_return_none!(static_length(&right?));
+    let shorter = left_length.min(right_length);
+    let longer = left_length.max(right_length);
+    // Recycling vectors of length 1 is intended, and empty vectors are
+    // reported by other rules, e.g. `equals_null`.
+    if shorter < 2 || shorter == longer {
+        return Ok(None);
+    }
+
+    let operator = operator.text_trimmed();
+    let (message, help) = if longer % shorter != 0 {
+        (
+            format!(
+                "The operands of `{operator}` have lengths {left_length} and {right_length}, and the longer length is not a multiple of the shorter one."
+            ),
+            "The shorter vector is partially recycled, which is likely a mistake.",
+        )
+    } else if is_comparison {
+        (
+            format!(
+                "The operands of `{operator}` have lengths {left_length} and {right_length}, so the shorter vector is recycled."
+            ),
+            "Use `%in%` to check whether the values are in a set of values.",
+        )
+    } else {
+        return Ok(None);
+    };
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "unsafe_vector_recycling".to_string(),
+            message,
+            Some(help.to_string()),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
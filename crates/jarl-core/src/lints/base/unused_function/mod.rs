@@ -580,6 +580,7 @@ mod tests {
             files: paths.iter().map(|p| p.to_path_buf()).collect(),
             fix: false,
             unsafe_fixes: false,
+            unsafe_fixes_rules: String::new(),
             fix_only: false,
             select: "unused_function".to_string(),
             extend_select: String::new(),
@@ -587,7 +588,10 @@ mod tests {
             min_r_version: None,
             allow_dirty: false,
             allow_no_vcs: true,
+            respect_staged: false,
             assignment: None,
+            preview: false,
+            profile: None,
         };
 
         let config = build_config(&args, None, paths).unwrap();
@@ -2,6 +2,7 @@ use biome_rowan::TextRange;
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
+use crate::file_system::FileSystem;
 use crate::namespace::parse_namespace_exports;
 use crate::package::{FileScope, SharedFileData};
 
@@ -107,17 +108,30 @@ pub fn scan_symbols(content: &str) -> HashMap<String, usize> {
 }
 
 /// Recursively collect files under `dir` that match `predicate`.
-pub(crate) fn collect_files(dir: &Path, predicate: fn(&Path) -> bool) -> Vec<PathBuf> {
+///
+/// Symlinked directories are followed, but each directory is only visited once
+/// so that symlink cycles don't make this loop forever.
+pub(crate) fn collect_files(
+    file_system: &dyn FileSystem,
+    dir: &Path,
+    predicate: fn(&Path) -> bool,
+) -> Vec<PathBuf> {
     let mut files = Vec::new();
+    let mut visited = HashSet::new();
     let mut stack = vec![dir.to_path_buf()];
     while let Some(current) = stack.pop() {
-        let entries = match std::fs::read_dir(&current) {
+        let canonical = file_system
+            .canonicalize(&current)
+            .unwrap_or_else(|_| current.clone());
+        if !visited.insert(canonical) {
+            continue;
+        }
+        let entries = match file_system.read_dir(&current) {
             Ok(entries) => entries,
             Err(_) => continue,
         };
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.is_dir() {
+        for path in entries {
+            if file_system.is_dir(&path) {
                 stack.push(path);
             } else if predicate(&path) {
                 files.push(path);
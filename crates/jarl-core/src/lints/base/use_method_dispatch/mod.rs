@@ -0,0 +1,99 @@
+pub(crate) mod use_method_dispatch;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "use_method_dispatch", None)
+    }
+
+    #[test]
+    fn test_no_lint_use_method_dispatch() {
+        expect_no_lint("UseMethod('f')", "use_method_dispatch", None);
+        expect_no_lint("function(x) UseMethod('f')", "use_method_dispatch", None);
+        expect_no_lint("function(x) UseMethod('f', x)", "use_method_dispatch", None);
+        expect_no_lint(
+            "function(x) UseMethod('f', object = x)",
+            "use_method_dispatch",
+            None,
+        );
+        expect_no_lint(
+            "function(...) UseMethod('f', ..1)",
+            "use_method_dispatch",
+            None,
+        );
+        expect_no_lint(
+            "function(x) {\n  y <- 1\n  UseMethod('f')\n}",
+            "use_method_dispatch",
+            None,
+        );
+        expect_no_lint(
+            "function(x) {\n  x$a\n  UseMethod('f')\n}",
+            "use_method_dispatch",
+            None,
+        );
+        // Assignments after `UseMethod()` are never run
+        expect_no_lint(
+            "function(x) {\n  UseMethod('f')\n  x <- 1\n}",
+            "use_method_dispatch",
+            None,
+        );
+        // Assignments in nested functions don't modify the arguments
+        expect_no_lint(
+            "function(x) {\n  g <- function(x) x <- 1\n  UseMethod('f')\n}",
+            "use_method_dispatch",
+            None,
+        );
+        // The branch modifying `x` never reaches `UseMethod()`
+        expect_no_lint(
+            "function(x) {\n  if (is.null(x)) return(x <- 1)\n  UseMethod('f')\n}",
+            "use_method_dispatch",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_use_method_dispatch() {
+        assert_snapshot!(
+            snapshot_lint("function(x, y) UseMethod('f', y)"),
+            @"
+        warning: use_method_dispatch
+         --> <test>:1:16
+          |
+        1 | function(x, y) UseMethod('f', y)
+          |                ----------------- `UseMethod()` dispatches on `y`, but the method receives `x` as first argument.
+          |
+          = help: Make `y` the first argument of the generic, or remove it from `UseMethod()`.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("function(x, ...) {\n  x <- as.list(x)\n  UseMethod('f')\n}"),
+            @"
+        warning: use_method_dispatch
+         --> <test>:3:3
+          |
+        3 |   UseMethod('f')
+          |   -------------- `x` is modified before `UseMethod()`, but the method receives its original value.
+          |
+          = help: Move the modification of `x` to the methods, or call `UseMethod()` first.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("function(x) {\n  if (is.null(x)) names(x) <- 'a'\n  UseMethod('f')\n}"),
+            @"
+        warning: use_method_dispatch
+         --> <test>:3:3
+          |
+        3 |   UseMethod('f')
+          |   -------------- `x` is modified before `UseMethod()`, but the method receives its original value.
+          |
+          = help: Move the modification of `x` to the methods, or call `UseMethod()` first.
+        Found 1 error.
+        "
+        );
+    }
+}
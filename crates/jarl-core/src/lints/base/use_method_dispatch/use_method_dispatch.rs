@@ -0,0 +1,192 @@
+use crate::checker::Checker;
+use crate::diagnostic::*;
+use crate::lints::base::unreachable_code::cfg::build_cfg;
+use crate::utils::{assigned_name, enclosing_scope, get_arg_by_name_then_position};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+use std::collections::HashSet;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for calls to `UseMethod()` that dispatch on something else than the
+/// first argument of the generic, or that come after code modifying the
+/// arguments of the generic.
+///
+/// ## Why is this bad?
+///
+/// `UseMethod()` calls the method with the arguments as they were passed to
+/// the generic. Passing an object other than the first argument to
+/// `UseMethod()` only changes the class used to select the method: the method
+/// still receives the original first argument, which is rarely what was
+/// intended.
+///
+/// Similarly, modifying an argument before `UseMethod()` has no effect on the
+/// method that is called: it receives the original values of the arguments,
+/// so the modification is silently lost.
+///
+/// This rule is in preview, so it is only used with `--preview` or
+/// `preview = true` in `jarl.toml`.
+///
+/// ## Example
+///
+/// ```r
+/// summarize <- function(x, data) {
+///   UseMethod("summarize", data)
+/// }
+///
+/// describe <- function(x, ...) {
+///   x <- as.data.frame(x)
+///   UseMethod("describe")
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// summarize <- function(data, x) {
+///   UseMethod("summarize")
+/// }
+///
+/// describe <- function(x, ...) {
+///   UseMethod("describe")
+/// }
+///
+/// describe.default <- function(x, ...) {
+///   x <- as.data.frame(x)
+///   # ...
+/// }
+/// ```
+///
+/// ## References
+///
+/// See `?UseMethod`
+pub fn use_method_dispatch(
+    ast: &RCall,
+    fn_name: &str,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if fn_name != "UseMethod" {
+        return Ok(None);
+    }
+
+    let scope = enclosing_scope(ast.syntax());
+    let function = unwrap_or_return_none!(RFunctionDefinition::cast(scope));
+    let parameters = function
+        .parameters()?
+        .items()
+        .into_iter()
+        .filter_map(|param| param.ok()?.name().ok())
+        .map(|param| param.syntax().text_trimmed().to_string())
+        .collect::<Vec<_>>();
+    let range = ast.syntax().text_trimmed_range();
+
+    // i.e. `UseMethod("f", y)` in `function(x, y)`
+    let args = ast.arguments()?.items();
+    if let Some(object) = get_arg_by_name_then_position(&args, "object", 2)
+        && let Some(first) = parameters.first()
+        && first != "..."
+    {
+        let object = unwrap_or_return_none!(object.value());
+        let object = object.syntax().text_trimmed().to_string();
+        if &object != first {
+            let diagnostic = Diagnostic::new(
+                ViolationData::new(
+                    "use_method_dispatch".to_string(),
+                    format!(
+                        "`UseMethod()` dispatches on `{object}`, but the method receives `{first}` as first argument."
+                    ),
+                    Some(format!(
+                        "Make `{object}` the first argument of the generic, or remove it from `UseMethod()`."
+                    )),
+                ),
+                range,
+                Fix::empty(),
+            );
+            return Ok(Some(diagnostic));
+        }
+    }
+
+    // i.e. `x <- as.list(x); UseMethod("f")`
+    let stopping = &checker.rule_options.unreachable_code.stopping_functions;
+    let parameters: HashSet<String> = parameters.into_iter().collect();
+    let modified = statements_before(&function, ast.syntax(), stopping)
+        .iter()
+        .find_map(|statement| modified_parameter(statement, &parameters));
+    let modified = unwrap_or_return_none!(modified);
+
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "use_method_dispatch".to_string(),
+            format!(
+                "`{modified}` is modified before `UseMethod()`, but the method receives its original value."
+            ),
+            Some(format!(
+                "Move the modification of `{modified}` to the methods, or call `UseMethod()` first."
+            )),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Statements that can run before `call` in `function`, using the control flow
+/// graph of the function.
+fn statements_before(
+    function: &RFunctionDefinition,
+    call: &RSyntaxNode,
+    stopping_functions: &HashSet<String>,
+) -> Vec<RSyntaxNode> {
+    let cfg = build_cfg(function, stopping_functions);
+    let call_range = call.text_trimmed_range();
+
+    let Some((block, position)) = cfg.blocks.iter().find_map(|block| {
+        block
+            .statements
+            .iter()
+            .position(|statement| statement.text_trimmed_range().contains_range(call_range))
+            .map(|position| (block, position))
+    }) else {
+        return Vec::new();
+    };
+
+    let mut statements = block.statements[..position].to_vec();
+    let mut visited = HashSet::from([block.id]);
+    let mut stack = block.predecessors.clone();
+    while let Some(id) = stack.pop() {
+        if !visited.insert(id) {
+            continue;
+        }
+        if let Some(predecessor) = cfg.block(id) {
+            statements.extend(predecessor.statements.iter().cloned());
+            stack.extend(predecessor.predecessors.iter().copied());
+        }
+    }
+
+    statements
+}
+
+/// Name of the parameter modified by an assignment in `statement`, if any.
+/// Assignments in nested functions are ignored since they don't modify the
+/// parameters of the generic.
+fn modified_parameter(statement: &RSyntaxNode, parameters: &HashSet<String>) -> Option<String> {
+    statement
+        .descendants()
+        .filter(|node| {
+            !node
+                .ancestors()
+                .take_while(|ancestor| ancestor != statement)
+                .any(|ancestor| ancestor.kind() == RSyntaxKind::R_FUNCTION_DEFINITION)
+        })
+        .filter_map(RBinaryExpression::cast)
+        .find_map(|assignment| {
+            let target = match assignment.operator().ok()?.kind() {
+                RSyntaxKind::ASSIGN | RSyntaxKind::EQUAL => assignment.left(),
+                RSyntaxKind::ASSIGN_RIGHT => assignment.right(),
+                _ => return None,
+            };
+            assigned_name(&target.ok()?).filter(|name| parameters.contains(name))
+        })
+}
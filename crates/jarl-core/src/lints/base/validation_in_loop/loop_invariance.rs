@@ -0,0 +1,133 @@
+//! Heuristics to find the loop in which an expression is run, and whether this
+//! expression depends on the iteration, i.e. whether it could be moved out of
+//! the loop.
+
+use crate::utils::{assigned_name, get_function_name};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+use std::collections::HashSet;
+
+/// Functions that call a function on each element of their inputs.
+const APPLY_FUNCTIONS: &[&str] = &[
+    "Filter", "Map", "apply", "imap", "lapply", "map", "map2", "map_chr", "map_dbl", "map_int",
+    "map_lgl", "mapply", "pmap", "sapply", "vapply", "walk",
+];
+
+/// The body of a loop, or of a function passed to an `*apply()` function.
+pub(crate) struct LoopBody {
+    pub(crate) body: RSyntaxNode,
+    /// Variables taking a new value at each iteration: the index of a `for`
+    /// loop or the parameters of the applied function.
+    pub(crate) variables: HashSet<String>,
+}
+
+impl LoopBody {
+    /// Whether `node` uses the index of the loop or the parameters of the
+    /// applied function.
+    pub(crate) fn uses_variables(&self, node: &RSyntaxNode) -> bool {
+        used_names(node).any(|name| self.variables.contains(&name))
+    }
+
+    /// Names used in `node` that are assigned in the loop body, e.g. `y` in
+    /// `y <- f(i); stopifnot(y > 0)`. Assignments containing `node`, e.g.
+    /// `x <- match.arg(x)`, and assignments in nested functions are ignored.
+    pub(crate) fn modified_names(&self, node: &RSyntaxNode) -> Vec<String> {
+        let range = node.text_trimmed_range();
+        let assigned: HashSet<String> = self
+            .body
+            .descendants()
+            .filter(|descendant| {
+                !descendant
+                    .ancestors()
+                    .take_while(|ancestor| ancestor != &self.body)
+                    .any(|ancestor| ancestor.kind() == RSyntaxKind::R_FUNCTION_DEFINITION)
+            })
+            .filter_map(|descendant| {
+                if let Some(for_loop) = RForStatement::cast_ref(&descendant) {
+                    return Some(for_loop.variable().ok()?.to_trimmed_string());
+                }
+                let assignment = RBinaryExpression::cast(descendant)?;
+                if assignment
+                    .syntax()
+                    .text_trimmed_range()
+                    .contains_range(range)
+                {
+                    return None;
+                }
+                let target = match assignment.operator().ok()?.kind() {
+                    RSyntaxKind::ASSIGN | RSyntaxKind::EQUAL => assignment.left(),
+                    RSyntaxKind::ASSIGN_RIGHT => assignment.right(),
+                    _ => return None,
+                };
+                assigned_name(&target.ok()?)
+            })
+            .collect();
+
+        let mut modified = Vec::new();
+        for name in used_names(node) {
+            if assigned.contains(&name) && !modified.contains(&name) {
+                modified.push(name);
+            }
+        }
+        modified
+    }
+}
+
+/// The innermost loop body containing `node` in the current function. The
+/// body of a function passed to an `*apply()` function, such as
+/// `lapply(x, function(el) ...)`, counts as a loop body.
+pub(crate) fn enclosing_loop_body(node: &RSyntaxNode) -> Option<LoopBody> {
+    let mut child = node.clone();
+    for ancestor in node.ancestors().skip(1) {
+        match ancestor.kind() {
+            RSyntaxKind::R_FUNCTION_DEFINITION => {
+                let function = RFunctionDefinition::cast(ancestor)?;
+                if function.body().ok()?.syntax() != &child || !is_applied(&function) {
+                    return None;
+                }
+                let variables = function
+                    .parameters()
+                    .ok()?
+                    .items()
+                    .into_iter()
+                    .filter_map(|param| param.ok()?.name().ok())
+                    .map(|param| param.syntax().text_trimmed().to_string())
+                    .collect();
+                return Some(LoopBody { body: child, variables });
+            }
+            RSyntaxKind::R_FOR_STATEMENT if child.index() == 6 => {
+                let variable = RForStatement::cast(ancestor)?.variable().ok()?;
+                let variables = HashSet::from([variable.to_trimmed_string()]);
+                return Some(LoopBody { body: child, variables });
+            }
+            RSyntaxKind::R_WHILE_STATEMENT if child.index() == 4 => {
+                return Some(LoopBody { body: child, variables: HashSet::new() });
+            }
+            RSyntaxKind::R_REPEAT_STATEMENT => {
+                return Some(LoopBody { body: child, variables: HashSet::new() });
+            }
+            _ => {}
+        }
+        child = ancestor;
+    }
+    None
+}
+
+/// Whether `function` is passed as argument to an `*apply()` function.
+fn is_applied(function: &RFunctionDefinition) -> bool {
+    let Some(argument) = function.syntax().parent().and_then(RArgument::cast) else {
+        return false;
+    };
+    argument
+        .syntax()
+        .ancestors()
+        .find_map(RCall::cast)
+        .and_then(|call| call.function().ok())
+        .is_some_and(|function| APPLY_FUNCTIONS.contains(&get_function_name(function).as_str()))
+}
+
+fn used_names(node: &RSyntaxNode) -> impl Iterator<Item = String> {
+    node.descendants()
+        .filter_map(RIdentifier::cast)
+        .map(|ident| ident.syntax().text_trimmed().to_string())
+}
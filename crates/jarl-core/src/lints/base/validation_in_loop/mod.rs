@@ -0,0 +1,111 @@
+pub(crate) mod loop_invariance;
+pub(crate) mod validation_in_loop;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "validation_in_loop", None)
+    }
+
+    #[test]
+    fn test_no_lint_validation_in_loop() {
+        expect_no_lint("stopifnot(is.numeric(x))", "validation_in_loop", None);
+        // Functions defined in the loop or not applied on each element
+        expect_no_lint(
+            "for (i in x) f <- function(y) stopifnot(y > 0)",
+            "validation_in_loop",
+            None,
+        );
+        expect_no_lint(
+            "do.call(function(x) stopifnot(x > 0), list(1))",
+            "validation_in_loop",
+            None,
+        );
+        // Values computed in the loop can't be validated before it
+        expect_no_lint(
+            "for (i in x) {\n  y <- f(i)\n  stopifnot(y > 0)\n}",
+            "validation_in_loop",
+            None,
+        );
+        expect_no_lint(
+            "while (TRUE) {\n  stopifnot(n > 0)\n  n <- n - 1\n}",
+            "validation_in_loop",
+            None,
+        );
+        expect_no_lint(
+            "for (i in x) {\n  for (j in y) NULL\n  stopifnot(j > 0)\n}",
+            "validation_in_loop",
+            None,
+        );
+        // Other packages
+        expect_no_lint(
+            "for (i in x) foo::stopifnot(i > 0)",
+            "validation_in_loop",
+            None,
+        );
+        expect_no_lint(
+            "for (i in x) foo::assert_number(i)",
+            "validation_in_loop",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_validation_in_loop() {
+        assert_snapshot!(
+            snapshot_lint("for (i in seq_along(x)) stopifnot(is.numeric(x[i]))"),
+            @"
+        warning: validation_in_loop
+         --> <test>:1:25
+          |
+        1 | for (i in seq_along(x)) stopifnot(is.numeric(x[i]))
+          |                         --------------------------- `stopifnot()` validates the elements one at a time, at each iteration.
+          |
+          = help: Validate the whole input once, before the loop.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("lapply(files, function(f) {\n  type <- match.arg(type, c('a', 'b'))\n  read(f, type)\n})"),
+            @"
+        warning: validation_in_loop
+         --> <test>:2:11
+          |
+        2 |   type <- match.arg(type, c('a', 'b'))
+          |           ---------------------------- `match.arg()` validates the same values at each iteration.
+          |
+          = help: Move the validation before the loop.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("while (i < n) {\n  checkmate::assert_number(tol)\n  i <- i + 1\n}"),
+            @"
+        warning: validation_in_loop
+         --> <test>:2:3
+          |
+        2 |   checkmate::assert_number(tol)
+          |   ----------------------------- `assert_number()` validates the same values at each iteration.
+          |
+          = help: Move the validation before the loop.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("purrr::map(x, \\(el) assert_string(el))"),
+            @"
+        warning: validation_in_loop
+         --> <test>:1:21
+          |
+        1 | purrr::map(x, \\(el) assert_string(el))
+          |                     ----------------- `assert_string()` validates the elements one at a time, at each iteration.
+          |
+          = help: Validate the whole input once, before the loop.
+        Found 1 error.
+        "
+        );
+    }
+}
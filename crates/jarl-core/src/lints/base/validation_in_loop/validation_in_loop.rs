@@ -0,0 +1,98 @@
+use crate::diagnostic::*;
+use crate::lints::base::validation_in_loop::loop_invariance::enclosing_loop_body;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for argument validation, i.e. calls to `stopifnot()`,
+/// `match.arg()`, or `checkmate::assert_*()`, in the body of a loop or of a
+/// function passed to `lapply()`, `vapply()`, `purrr::map()`, etc.
+///
+/// Validations that use a value computed in the loop, such as
+/// `y <- f(x[i]); stopifnot(y > 0)`, are not reported since they can't be
+/// done before the loop.
+///
+/// This rule is disabled by default.
+///
+/// ## Why is this bad?
+///
+/// The validation is run at each iteration, which adds a function call and
+/// its checks for every element. When the validation doesn't depend on the
+/// iteration, it checks the same values again and again. When it checks the
+/// elements one at a time, the whole input can usually be checked at once
+/// with a vectorized condition, before the loop.
+///
+/// ## Example
+///
+/// ```r
+/// for (i in seq_along(x)) {
+///   stopifnot(is.numeric(x[i]))
+///   out[i] <- x[i] * 2
+/// }
+///
+/// lapply(files, function(file) {
+///   type <- match.arg(type, c("csv", "tsv"))
+///   read_file(file, type)
+/// })
+/// ```
+///
+/// Use instead:
+/// ```r
+/// stopifnot(is.numeric(x))
+/// for (i in seq_along(x)) {
+///   out[i] <- x[i] * 2
+/// }
+///
+/// type <- match.arg(type, c("csv", "tsv"))
+/// lapply(files, function(file) {
+///   read_file(file, type)
+/// })
+/// ```
+pub fn validation_in_loop(
+    ast: &RCall,
+    fn_name: &str,
+    ns_prefix: Option<&str>,
+) -> anyhow::Result<Option<Diagnostic>> {
+    let is_validation = match ns_prefix {
+        None => matches!(fn_name, "stopifnot" | "match.arg") || fn_name.starts_with("assert_"),
+        Some("base::") => matches!(fn_name, "stopifnot" | "match.arg"),
+        Some("checkmate::") => fn_name.starts_with("assert_"),
+        _ => false,
+    };
+    if !is_validation {
+        return Ok(None);
+    }
+
+    let loop_body = unwrap_or_return_none!(enclosing_loop_body(ast.syntax()));
+    if !loop_body.modified_names(ast.syntax()).is_empty() {
+        return Ok(None);
+    }
+
+    let (body, suggestion) = if loop_body.uses_variables(ast.syntax()) {
+        (
+            format!("`{fn_name}()` validates the elements one at a time, at each iteration."),
+            "Validate the whole input once, before the loop.",
+        )
+    } else {
+        (
+            format!("`{fn_name}()` validates the same values at each iteration."),
+            "Move the validation before the loop.",
+        )
+    };
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "validation_in_loop".to_string(),
+            body,
+            Some(suggestion.to_string()),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
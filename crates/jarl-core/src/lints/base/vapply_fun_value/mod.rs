@@ -0,0 +1,88 @@
+pub(crate) mod vapply_fun_value;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "vapply_fun_value", None)
+    }
+
+    #[test]
+    fn test_no_lint_vapply_fun_value() {
+        expect_no_lint("vapply(x, length, integer(1))", "vapply_fun_value", None);
+        expect_no_lint("vapply(x, length, numeric(1))", "vapply_fun_value", None);
+        expect_no_lint("vapply(x, is.null, logical(1))", "vapply_fun_value", None);
+        expect_no_lint("vapply(x, is.null, NA)", "vapply_fun_value", None);
+        expect_no_lint("vapply(x, typeof, \"\")", "vapply_fun_value", None);
+        expect_no_lint("vapply(x, mean, 1)", "vapply_fun_value", None);
+        expect_no_lint(
+            "vapply(x, FUN.VALUE = character(1), FUN = typeof)",
+            "vapply_fun_value",
+            None,
+        );
+        // Unknown functions or lengths
+        expect_no_lint("vapply(x, f, character(2))", "vapply_fun_value", None);
+        expect_no_lint("vapply(x, range, numeric(2))", "vapply_fun_value", None);
+        expect_no_lint("vapply(x, length, integer(n))", "vapply_fun_value", None);
+        expect_no_lint("vapply(x, length, template)", "vapply_fun_value", None);
+        expect_no_lint("sapply(x, length, character(1))", "vapply_fun_value", None);
+    }
+
+    #[test]
+    fn test_lint_vapply_fun_value() {
+        assert_snapshot!(
+            snapshot_lint("vapply(x, length, character(1))"),
+            @"
+        warning: vapply_fun_value
+         --> <test>:1:19
+          |
+        1 | vapply(x, length, character(1))
+          |                   ------------ `length()` returns a value of type integer but `FUN.VALUE` is of type character.
+          |
+          = help: Use `integer(1)` as template instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("vapply(x, is.null, FUN.VALUE = logical(2))"),
+            @"
+        warning: vapply_fun_value
+         --> <test>:1:32
+          |
+        1 | vapply(x, is.null, FUN.VALUE = logical(2))
+          |                                ---------- `is.null()` returns a single value but `FUN.VALUE` has length 2.
+          |
+          = help: Use `logical(1)` as template instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("base::vapply(x, mean, integer(1))"),
+            @"
+        warning: vapply_fun_value
+         --> <test>:1:23
+          |
+        1 | base::vapply(x, mean, integer(1))
+          |                       ---------- `mean()` returns a value of type double but `FUN.VALUE` is of type integer.
+          |
+          = help: Use `double(1)` as template instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("vapply(x, f, T)"),
+            @"
+        warning: vapply_fun_value
+         --> <test>:1:14
+          |
+        1 | vapply(x, f, T)
+          |              - `T` is used as the template of `FUN.VALUE`.
+          |
+          = help: Use `logical(1)` instead.
+        Found 1 error.
+        "
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use crate::diagnostic::*;
+use crate::utils::{get_arg_by_name_then_position, get_function_name};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for calls to `vapply()` whose `FUN.VALUE` template can't match the
+/// output of `FUN`. This only covers functions that always return a single
+/// value of a known type, such as `length()`, `is.null()`, or `typeof()`.
+///
+/// It also reports `T` and `F` used as templates.
+///
+/// ## Why is this bad?
+///
+/// `vapply()` checks that the output of each call to `FUN` matches the type
+/// and length of `FUN.VALUE`. When they don't match, the error only appears
+/// when `X` isn't empty, so it can go unnoticed in tests.
+///
+/// The type of the output can be promoted (`logical` to `integer`, `integer`
+/// to `double`), so `vapply(x, length, numeric(1))` is valid, but it can't be
+/// demoted or converted to `character`.
+///
+/// `T` and `F` can be redefined, so they shouldn't be used instead of
+/// `logical(1)`.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Example
+///
+/// ```r
+/// vapply(x, length, character(1))
+/// vapply(x, is.null, logical(2))
+/// vapply(x, is.function, FUN.VALUE = T)
+/// ```
+///
+/// Use instead:
+/// ```r
+/// vapply(x, length, integer(1))
+/// vapply(x, is.null, logical(1))
+/// vapply(x, is.function, FUN.VALUE = logical(1))
+/// ```
+///
+/// ## References
+///
+/// See `?vapply`
+pub fn vapply_fun_value(ast: &RCall, fn_name: &str) -> anyhow::Result<Option<Diagnostic>> {
+    if fn_name != "vapply" {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+    let fun_value = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "FUN.VALUE", 3));
+    let fun_value = unwrap_or_return_none!(fun_value.value());
+
+    let range = fun_value.syntax().text_trimmed_range();
+    let template_text = fun_value.to_trimmed_string();
+    if fun_value.as_r_identifier().is_some() && (template_text == "T" || template_text == "F") {
+        let diagnostic = Diagnostic::new(
+            ViolationData::new(
+                "vapply_fun_value".to_string(),
+                format!("`{template_text}` is used as the template of `FUN.VALUE`."),
+                Some("Use `logical(1)` instead.".to_string()),
+            ),
+            range,
+            Fix::empty(),
+        );
+        return Ok(Some(diagnostic));
+    }
+
+    let fun = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "FUN", 2));
+    let fun = unwrap_or_return_none!(fun.value());
+    let fun = unwrap_or_return_none!(fun.as_r_identifier());
+    let fun_name = fun.to_trimmed_string();
+    let output_type = unwrap_or_return_none!(scalar_output_type(&fun_name));
+
+    let (template_type, template_length) = unwrap_or_return_none!(template(&fun_value)?);
+
+    let body = if template_length.is_some_and(|length| length != 1) {
+        format!(
+            "`{fun_name}()` returns a single value but `FUN.VALUE` has length {}.",
+            template_length.unwrap_or_default()
+        )
+    } else if !output_type.can_be_promoted_to(template_type) {
+        format!(
+            "`{fun_name}()` returns a value of type {} but `FUN.VALUE` is of type {}.",
+            output_type.as_str(),
+            template_type.as_str()
+        )
+    } else {
+        return Ok(None);
+    };
+
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "vapply_fun_value".to_string(),
+            body,
+            Some(format!(
+                "Use `{}(1)` as template instead.",
+                output_type.as_str()
+            )),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum VectorType {
+    Logical,
+    Integer,
+    Double,
+    Complex,
+    Character,
+}
+
+impl VectorType {
+    fn from_constructor(name: &str) -> Option<Self> {
+        match name {
+            "logical" => Some(Self::Logical),
+            "integer" => Some(Self::Integer),
+            "numeric" | "double" => Some(Self::Double),
+            "complex" => Some(Self::Complex),
+            "character" => Some(Self::Character),
+            _ => None,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Logical => "logical",
+            Self::Integer => "integer",
+            Self::Double => "double",
+            Self::Complex => "complex",
+            Self::Character => "character",
+        }
+    }
+
+    /// `vapply()` accepts outputs whose type can be promoted to the type of
+    /// the template: logical < integer < double < complex.
+    fn can_be_promoted_to(&self, other: Self) -> bool {
+        match (self, other) {
+            (Self::Character, other) => other == Self::Character,
+            (_, Self::Character) => false,
+            _ => (*self as u8) <= (other as u8),
+        }
+    }
+}
+
+/// Type of the output of functions that always return a single value.
+fn scalar_output_type(fun: &str) -> Option<VectorType> {
+    let out = match fun {
+        "length" | "nlevels" => VectorType::Integer,
+        "typeof" | "mode" | "storage.mode" => VectorType::Character,
+        "mean" => VectorType::Double,
+        "all" | "any" | "anyNA" | "exists" | "identical" | "inherits" | "is.atomic"
+        | "is.character" | "is.data.frame" | "is.double" | "is.environment" | "is.factor"
+        | "is.function" | "is.integer" | "is.list" | "is.logical" | "is.matrix" | "is.null"
+        | "is.numeric" | "is.vector" | "isFALSE" | "isTRUE" => VectorType::Logical,
+        _ => return None,
+    };
+    Some(out)
+}
+
+/// Type and length of a `FUN.VALUE` template, e.g. `integer(1)` or `""`.
+/// The length is `None` when it can't be determined statically.
+fn template(value: &AnyRExpression) -> anyhow::Result<Option<(VectorType, Option<usize>)>> {
+    match value {
+        AnyRExpression::RTrueExpression(_) | AnyRExpression::RFalseExpression(_) => {
+            Ok(Some((VectorType::Logical, Some(1))))
+        }
+        AnyRExpression::AnyRValue(value) => {
+            let vector_type = if value.as_r_string_value().is_some() {
+                VectorType::Character
+            } else if value.as_r_integer_value().is_some() {
+                VectorType::Integer
+            } else if value.as_r_double_value().is_some() {
+                VectorType::Double
+            } else if value.as_r_complex_value().is_some() {
+                VectorType::Complex
+            } else {
+                return Ok(None);
+            };
+            Ok(Some((vector_type, Some(1))))
+        }
+        AnyRExpression::RCall(call) => {
+            let name = get_function_name(call.function()?);
+            let Some(vector_type) = VectorType::from_constructor(&name) else {
+                return Ok(None);
+            };
+            let args = call.arguments()?.items();
+            let length = match get_arg_by_name_then_position(&args, "length", 1) {
+                None => Some(0),
+                Some(arg) => arg
+                    .value()
+                    .and_then(|v| v.to_trimmed_string().trim_end_matches('L').parse().ok()),
+            };
+            Ok(Some((vector_type, length)))
+        }
+        _ => Ok(None),
+    }
+}
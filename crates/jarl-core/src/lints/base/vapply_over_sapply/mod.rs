@@ -0,0 +1,114 @@
+pub(crate) mod options;
+pub(crate) mod vapply_over_sapply;
+
+#[cfg(test)]
+mod tests {
+    use crate::lints::base::vapply_over_sapply::options::ResolvedVapplyOverSapplyOptions;
+    use crate::lints::base::vapply_over_sapply::options::VapplyOverSapplyOptions;
+    use crate::rule_options::ResolvedRuleOptions;
+    use crate::settings::{LinterSettings, Settings};
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "vapply_over_sapply", None)
+    }
+
+    fn snapshot_lint_with_settings(code: &str, settings: Settings) -> String {
+        format_diagnostics_with_settings(code, "vapply_over_sapply", None, Some(settings))
+    }
+
+    /// Build a `Settings` with custom `VapplyOverSapplyOptions`.
+    fn settings_with_options(options: VapplyOverSapplyOptions) -> Settings {
+        Settings {
+            linter: LinterSettings {
+                rule_options: ResolvedRuleOptions {
+                    vapply_over_sapply: ResolvedVapplyOverSapplyOptions::resolve(Some(&options))
+                        .unwrap(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_no_lint_vapply_over_sapply() {
+        expect_no_lint("vapply(x, length, integer(1))", "vapply_over_sapply", None);
+        expect_no_lint("lapply(x, length)", "vapply_over_sapply", None);
+        expect_no_lint("foo::sapply(x, length)", "vapply_over_sapply", None);
+        expect_no_lint(
+            "sapply(x, length, simplify = FALSE)",
+            "vapply_over_sapply",
+            None,
+        );
+        expect_no_lint(
+            "sapply(x, length, simplify = F, USE.NAMES = FALSE)",
+            "vapply_over_sapply",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_vapply_over_sapply() {
+        assert_snapshot!(
+            snapshot_lint("sapply(x, length)"),
+            @"
+        warning: vapply_over_sapply
+         --> <test>:1:1
+          |
+        1 | sapply(x, length)
+          | ----------------- The output type of `sapply()` depends on its input.
+          |
+          = help: Use `vapply()` with an explicit `FUN.VALUE`, or `lapply()` to get a list.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("base::sapply(x, function(y) y$name, simplify = TRUE)"),
+            @"
+        warning: vapply_over_sapply
+         --> <test>:1:1
+          |
+        1 | base::sapply(x, function(y) y$name, simplify = TRUE)
+          | ---------------------------------------------------- The output type of `sapply()` depends on its input.
+          |
+          = help: Use `vapply()` with an explicit `FUN.VALUE`, or `lapply()` to get a list.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("sapply(x, length, simplify = simplify)"),
+            @"
+        warning: vapply_over_sapply
+         --> <test>:1:1
+          |
+        1 | sapply(x, length, simplify = simplify)
+          | -------------------------------------- The output type of `sapply()` depends on its input.
+          |
+          = help: Use `vapply()` with an explicit `FUN.VALUE`, or `lapply()` to get a list.
+        Found 1 error.
+        "
+        );
+    }
+
+    #[test]
+    fn test_vapply_over_sapply_skip_simplify_false() {
+        let settings =
+            settings_with_options(VapplyOverSapplyOptions { skip_simplify_false: Some(false) });
+        assert_snapshot!(
+            snapshot_lint_with_settings("sapply(x, length, simplify = FALSE)", settings),
+            @"
+        warning: vapply_over_sapply
+         --> <test>:1:1
+          |
+        1 | sapply(x, length, simplify = FALSE)
+          | ----------------------------------- The output type of `sapply()` depends on its input.
+          |
+          = help: Use `vapply()` with an explicit `FUN.VALUE`, or `lapply()` to get a list.
+        Found 1 error.
+        "
+        );
+    }
+}
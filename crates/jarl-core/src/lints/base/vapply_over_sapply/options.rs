@@ -0,0 +1,28 @@
+/// TOML options for `[lint.vapply_over_sapply]`.
+///
+/// Use `skip-simplify-false` to control whether `sapply(..., simplify = FALSE)`
+/// is allowed. Those calls always return a list, so they are not reported by
+/// default.
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct VapplyOverSapplyOptions {
+    pub skip_simplify_false: Option<bool>,
+}
+
+/// Resolved options for the `vapply_over_sapply` rule, ready for use during
+/// linting.
+#[derive(Clone, Debug)]
+pub struct ResolvedVapplyOverSapplyOptions {
+    pub skip_simplify_false: bool,
+}
+
+impl ResolvedVapplyOverSapplyOptions {
+    pub fn resolve(options: Option<&VapplyOverSapplyOptions>) -> anyhow::Result<Self> {
+        let skip_simplify_false = options
+            .and_then(|opts| opts.skip_simplify_false)
+            .unwrap_or(true);
+
+        Ok(Self { skip_simplify_false })
+    }
+}
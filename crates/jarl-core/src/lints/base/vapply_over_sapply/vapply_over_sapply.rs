@@ -0,0 +1,97 @@
+use crate::checker::Checker;
+use crate::diagnostic::*;
+use crate::utils::get_arg_by_name;
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for calls to `sapply()`.
+///
+/// ## Why is this bad?
+///
+/// The type of the output of `sapply()` depends on its input: it can return a
+/// vector, a matrix, or a list. For instance, `sapply(x, length)` returns an
+/// integer vector if `x` has at least one element, but an empty list if `x` is
+/// empty. This makes code harder to reason about and can lead to bugs that only
+/// appear with some inputs.
+///
+/// `vapply()` requires the type and length of the output of each call to be
+/// specified in `FUN.VALUE`, and fails if this isn't respected. `lapply()`
+/// always returns a list.
+///
+/// This rule is disabled by default. It is mostly useful in package code, so
+/// you may want to restrict it to the `R/` folder, for instance:
+///
+/// ```toml
+/// [lint]
+/// extend-select = ["vapply_over_sapply"]
+///
+/// [lint.vapply_over_sapply]
+/// include = ["R/"]
+/// ```
+///
+/// Calls with `simplify = FALSE` always return a list and are therefore not
+/// reported by default. This can be changed with the `skip-simplify-false`
+/// option in `[lint.vapply_over_sapply]`.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Example
+///
+/// ```r
+/// sapply(x, length)
+/// sapply(x, function(y) y$name)
+/// ```
+///
+/// Use instead:
+/// ```r
+/// vapply(x, length, integer(1))
+/// lapply(x, function(y) y$name)
+/// ```
+///
+/// ## References
+///
+/// See `?vapply`
+pub fn vapply_over_sapply(
+    ast: &RCall,
+    fn_name: &str,
+    ns_prefix: Option<&str>,
+    checker: &Checker,
+) -> anyhow::Result<Option<Diagnostic>> {
+    if fn_name != "sapply" {
+        return Ok(None);
+    }
+    if ns_prefix.is_some_and(|ns| ns != "base::") {
+        return Ok(None);
+    }
+
+    // `simplify` comes after `...` so it can only be matched by name.
+    if checker.rule_options.vapply_over_sapply.skip_simplify_false {
+        let args = ast.arguments()?.items();
+        if let Some(simplify) = get_arg_by_name(&args, "simplify")
+            && let Some(value) = simplify.value()
+            && matches!(value.to_trimmed_string().as_str(), "FALSE" | "F")
+        {
+            return Ok(None);
+        }
+    }
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "vapply_over_sapply".to_string(),
+            "The output type of `sapply()` depends on its input.".to_string(),
+            Some(
+                "Use `vapply()` with an explicit `FUN.VALUE`, or `lapply()` to get a list."
+                    .to_string(),
+            ),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
@@ -0,0 +1,82 @@
+pub(crate) mod vector_condition;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "vector_condition", None)
+    }
+
+    #[test]
+    fn test_no_lint_vector_condition() {
+        expect_no_lint("if (x == 'a') 1", "vector_condition", None);
+        expect_no_lint("if (x == y) 1", "vector_condition", None);
+        expect_no_lint("if (x %in% c('a', 'b')) 1", "vector_condition", None);
+        expect_no_lint("if (any(x == c('a', 'b'))) 1", "vector_condition", None);
+        expect_no_lint("if (c(1) == x) 1", "vector_condition", None);
+        expect_no_lint("if (length(c(1, 2))) 1", "vector_condition", None);
+        expect_no_lint("if (grepl('a', x)) 1", "vector_condition", None);
+        expect_no_lint("if (grepl(c('a', 'b'), x)) 1", "vector_condition", None);
+        expect_no_lint("if (is.na(c(x, y))) 1", "vector_condition", None);
+        expect_no_lint("while (i < 10) i <- i + 1", "vector_condition", None);
+        expect_no_lint("if (1:1 == x) 1", "vector_condition", None);
+    }
+
+    #[test]
+    fn test_lint_vector_condition() {
+        assert_snapshot!(
+            snapshot_lint(r#"if (x == c("a", "b")) 1"#),
+            @r#"
+        warning: vector_condition
+         --> <test>:1:5
+          |
+        1 | if (x == c("a", "b")) 1
+          |     ---------------- The condition of `if` has more than one element, which is an error since R 4.2.0.
+          |
+          = help: Use `any()` or `all()` to get a single `TRUE` or `FALSE`.
+        Found 1 error.
+        "#
+        );
+        assert_snapshot!(
+            snapshot_lint("while (i < 1:10) i <- i + 1"),
+            @"
+        warning: vector_condition
+         --> <test>:1:8
+          |
+        1 | while (i < 1:10) i <- i + 1
+          |        -------- The condition of `while` has more than one element, which is an error since R 4.2.0.
+          |
+          = help: Use `any()` or `all()` to get a single `TRUE` or `FALSE`.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint(r#"if (grepl("^a", c("apple", "banana"))) 1"#),
+            @r#"
+        warning: vector_condition
+         --> <test>:1:5
+          |
+        1 | if (grepl("^a", c("apple", "banana"))) 1
+          |     --------------------------------- The condition of `if` has more than one element, which is an error since R 4.2.0.
+          |
+          = help: Use `any()` or `all()` to get a single `TRUE` or `FALSE`.
+        Found 1 error.
+        "#
+        );
+        assert_snapshot!(
+            snapshot_lint("if (!is.na(c(1, NA))) 1"),
+            @"
+        warning: vector_condition
+         --> <test>:1:5
+          |
+        1 | if (!is.na(c(1, NA))) 1
+          |     ---------------- The condition of `if` has more than one element, which is an error since R 4.2.0.
+          |
+          = help: Use `any()` or `all()` to get a single `TRUE` or `FALSE`.
+        Found 1 error.
+        "
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use crate::diagnostic::*;
+use crate::utils::{get_arg_by_name_then_position, get_function_name, static_length};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for conditions of `if` and `while` statements that have more than
+/// one element, for instance:
+///
+/// - `if (x == c("a", "b"))`;
+/// - `if (grepl("^a", c("apple", "banana")))`;
+/// - `while (i < 1:10)`.
+///
+/// This rule only reports conditions whose length can be known without
+/// running the code: comparisons, arithmetic, and calls to `grepl()`,
+/// `is.na()`, `nzchar()`, `startsWith()`, and `endsWith()` on vectors created
+/// with `c()`, `:`, `seq_len()`, etc.
+///
+/// This rule doesn't have an automatic fix.
+///
+/// ## Why is this bad?
+///
+/// The condition of `if` and `while` must be a single `TRUE` or `FALSE`.
+/// Since R 4.2.0, a condition with more than one element is an error. Before
+/// that, it was only a warning and only the first element was used, which is
+/// rarely what was intended.
+///
+/// This rule complements `vector_logic`, which reports `&` and `|` in those
+/// conditions.
+///
+/// This rule is in preview, so it is only used with `--preview` or
+/// `preview = true` in `jarl.toml`.
+///
+/// ## Example
+///
+/// ```r
+/// if (x == c("a", "b")) {
+///   print("x is a or b")
+/// }
+/// ```
+///
+/// Use instead:
+/// ```r
+/// if (x %in% c("a", "b")) {
+///   print("x is a or b")
+/// }
+/// ```
+///
+/// ## References
+///
+/// See `?Control` and the NEWS of [R 4.2.0](https://cran.r-project.org/doc/manuals/r-release/NEWS.html).
+pub fn vector_condition_if(ast: &RIfStatement) -> anyhow::Result<Option<Diagnostic>> {
+    vector_condition(&ast.condition()?, "if")
+}
+
+pub fn vector_condition_while(ast: &RWhileStatement) -> anyhow::Result<Option<Diagnostic>> {
+    vector_condition(&ast.condition()?, "while")
+}
+
+fn vector_condition(
+    condition: &AnyRExpression,
+    statement: &str,
+) -> anyhow::Result<Option<Diagnostic>> {
+    let Some(length) = condition_length(condition) else {
+        return Ok(None);
+    };
+    if length < 2 {
+        return Ok(None);
+    }
+
+    let range = condition.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "vector_condition".to_string(),
+            format!(
+                "The condition of `{statement}` has more than one element, which is an error since R 4.2.0."
+            ),
+            Some("Use `any()` or `all()` to get a single `TRUE` or `FALSE`.".to_string()),
+        ),
+        range,
+        Fix::empty(),
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Length of a condition if it is known to be at least 2, or if it can be
+/// known from literals only.
+///
+/// When only one operand of a vectorized operator has a known length, the
+/// result has at least this length (or is empty, which is also an error in a
+/// condition).
+fn condition_length(expr: &AnyRExpression) -> Option<u64> {
+    if let Some(length) = static_length(expr) {
+        return Some(length);
+    }
+
+    match expr {
+        AnyRExpression::RParenthesizedExpression(expr) => condition_length(&expr.body().ok()?),
+        AnyRExpression::RUnaryExpression(unary) => {
+            let operator = unary.operator().ok()?;
+            if !matches!(operator.kind(), RSyntaxKind::BANG | RSyntaxKind::MINUS) {
+                return None;
+            }
+            condition_length(&unary.argument().ok()?)
+        }
+        AnyRExpression::RBinaryExpression(binary) => {
+            let RBinaryExpressionFields { left, operator, right } = binary.as_fields();
+            let operator = operator.ok()?;
+            let left = left.ok()?;
+            let right = right.ok()?;
+
+            // The result of `x %in% y` has the length of `x`.
+            if operator.text_trimmed() == "%in%" {
+                return condition_length(&left);
+            }
+            if !matches!(
+                operator.kind(),
+                RSyntaxKind::EQUAL2
+                    | RSyntaxKind::NOT_EQUAL
+                    | RSyntaxKind::LESS_THAN
+                    | RSyntaxKind::LESS_THAN_OR_EQUAL_TO
+                    | RSyntaxKind::GREATER_THAN
+                    | RSyntaxKind::GREATER_THAN_OR_EQUAL_TO
+                    | RSyntaxKind::PLUS
+                    | RSyntaxKind::MINUS
+                    | RSyntaxKind::MULTIPLY
+                    | RSyntaxKind::DIVIDE
+                    | RSyntaxKind::AND
+                    | RSyntaxKind::OR
+            ) {
+                return None;
+            }
+
+            match (condition_length(&left), condition_length(&right)) {
+                (Some(0), _) | (_, Some(0)) => Some(0),
+                (Some(left), Some(right)) => Some(left.max(right)),
+                (Some(length), None) | (None, Some(length)) if length >= 2 => Some(length),
+                _ => None,
+            }
+        }
+        AnyRExpression::RCall(call) => {
+            let position = match get_function_name(call.function().ok()?).as_str() {
+                "is.na" | "nzchar" | "startsWith" | "endsWith" => 1,
+                "grepl" => 2,
+                _ => return None,
+            };
+            let args = call.arguments().ok()?.items();
+            let x = get_arg_by_name_then_position(&args, "x", position)?.value()?;
+            condition_length(&x)
+        }
+        _ => None,
+    }
+}
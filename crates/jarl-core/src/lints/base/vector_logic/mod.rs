@@ -107,10 +107,30 @@ mod tests {
         "
         );
 
-        // No fixes because `&` and `|` can be S3 methods.
+        assert_snapshot!(
+            "fix_output",
+            get_fixed_text(
+                vec![
+                    "if (x & y) 1",
+                    "if (x | y) 1",
+                    "while (x & !y) 1",
+                    "if ((x == 1) | (y != 2)) 1",
+                    "if (a | b & c) 1",
+                ],
+                "vector_logic",
+                None
+            )
+        );
+
+        // No fixes when an operand contains a call, since `&` and `|` can be
+        // S3 methods or the function can rely on vectorization.
         assert_snapshot!(
             "no_fix_output",
-            get_fixed_text(vec!["if (x & y) 1",], "class_equals", None)
+            get_fixed_text(
+                vec!["if (is.na(x) & y) 1", "if (x | any(y)) 1", "if (x$a & y) 1"],
+                "vector_logic",
+                None
+            )
         );
     }
 }
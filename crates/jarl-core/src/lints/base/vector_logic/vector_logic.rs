@@ -26,7 +26,11 @@ use biome_rowan::AstNode;
 /// (otherwise R would error as of 4.3.0), so using `& / |` or `&& / ||`
 /// is equivalent.
 ///
-/// This rule doesn't have an automatic fix.
+/// This rule has a safe fix that replaces `&` by `&&` and `|` by `||`. The
+/// fix is only applied when both sides of the operator are simple
+/// expressions, such as variables, constants, or comparisons between them.
+/// It isn't applied when one side contains a function call, since `&` and `|`
+/// can be S3 methods or the function could rely on vectorization.
 ///
 /// ## Example
 ///
@@ -76,11 +80,28 @@ pub fn vector_logic(ast: &RBinaryExpression) -> anyhow::Result<Option<Diagnostic
         unreachable!()
     };
 
+    let fix = if is_simple_operand(&left) && is_simple_operand(&right) {
+        let op_range = operator.text_trimmed_range();
+        let new_operator = if operator.kind() == RSyntaxKind::AND {
+            "&&"
+        } else {
+            "||"
+        };
+        Fix {
+            content: new_operator.to_string(),
+            start: op_range.start().into(),
+            end: op_range.end().into(),
+            to_skip: false,
+        }
+    } else {
+        Fix::empty()
+    };
+
     let range = ast.syntax().text_trimmed_range();
     let diagnostic = Diagnostic::new(
         ViolationData::new("vector_logic".to_string(), msg.to_string(), None),
         range,
-        Fix::empty(),
+        fix,
     );
 
     Ok(Some(diagnostic))
@@ -107,3 +128,38 @@ fn is_bitwise_exception(expr: &AnyRExpression) -> bool {
 
     false
 }
+
+/// Check if an operand can't dispatch to a method or rely on vectorization:
+/// variables, constants, and negations, comparisons, or logical operations
+/// made only of those.
+fn is_simple_operand(expr: &AnyRExpression) -> bool {
+    match expr {
+        AnyRExpression::RIdentifier(_)
+        | AnyRExpression::AnyRValue(_)
+        | AnyRExpression::RTrueExpression(_)
+        | AnyRExpression::RFalseExpression(_)
+        | AnyRExpression::RNullExpression(_)
+        | AnyRExpression::RNaExpression(_)
+        | AnyRExpression::RInfExpression(_)
+        | AnyRExpression::RNanExpression(_) => true,
+        AnyRExpression::RParenthesizedExpression(paren) => {
+            paren.body().is_ok_and(|body| is_simple_operand(&body))
+        }
+        AnyRExpression::RUnaryExpression(unary) => {
+            unary.operator().is_ok_and(|op| op.text_trimmed() == "!")
+                && unary.argument().is_ok_and(|arg| is_simple_operand(&arg))
+        }
+        AnyRExpression::RBinaryExpression(binary) => {
+            let RBinaryExpressionFields { left, operator, right } = binary.as_fields();
+            let (Ok(left), Ok(operator), Ok(right)) = (left, operator, right) else {
+                return false;
+            };
+            matches!(
+                operator.text_trimmed(),
+                "==" | "!=" | "<" | "<=" | ">" | ">=" | "&" | "|" | "&&" | "||"
+            ) && is_simple_operand(&left)
+                && is_simple_operand(&right)
+        }
+        _ => false,
+    }
+}
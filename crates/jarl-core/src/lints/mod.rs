@@ -5,11 +5,12 @@ pub(crate) mod comments;
 pub(crate) mod dplyr;
 pub(crate) mod testthat;
 
-/// Get all rules enabled by default
-pub fn all_rules_enabled_by_default() -> Vec<String> {
+/// Get all rules enabled by default. In preview mode, this includes the
+/// preview rules.
+pub fn all_rules_enabled_by_default(preview: bool) -> Vec<String> {
     Rule::all()
         .iter()
-        .filter(|r| r.is_enabled_by_default())
+        .filter(|r| r.is_enabled_by_default() || (preview && r.is_preview()))
         .map(|r| r.name().to_string())
         .collect()
 }
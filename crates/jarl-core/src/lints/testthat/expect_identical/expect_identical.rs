@@ -0,0 +1,148 @@
+use crate::diagnostic::*;
+use crate::utils::{
+    get_arg_by_name, get_arg_by_name_then_position, get_function_name,
+    get_function_namespace_prefix, node_contains_comments,
+};
+use air_r_syntax::*;
+use biome_rowan::{AstNode, AstSeparatedList};
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for usage of `expect_equal(x, y)` where `y` is an integer, string,
+/// or logical literal (or a vector of such literals built with `c()`).
+///
+/// ## Why is this bad?
+///
+/// `expect_equal()` compares its inputs with a numeric tolerance, which is
+/// only useful when comparing doubles. When the expected value is an integer,
+/// a string, or a logical, there is nothing to tolerate and
+/// `expect_identical()` states the intent more precisely.
+///
+/// This rule has an unsafe fix that replaces `expect_equal()` by
+/// `expect_identical()`. It is only provided when `expect_equal()` has no
+/// arguments other than `object` and `expected`. The fix is unsafe because
+/// `expect_identical()` also compares the types: `expect_equal(x, 2L)` and
+/// `expect_equal(x, NA)` pass when `x` is the double `2` or `NA_real_`, but
+/// `expect_identical()` fails in this case. Calls passing `tolerance` are never
+/// reported.
+///
+/// Comparisons to a single `TRUE` or `FALSE` are left to the rule
+/// `expect_true_false`.
+///
+/// This rule is **disabled by default**. Select it either with the rule name
+/// `"expect_identical"` or with the rule group `"TESTTHAT"`.
+///
+/// ## Example
+///
+/// ```r
+/// expect_equal(nrow(x), 2L)
+/// expect_equal(class(x), "data.frame")
+/// expect_equal(x, c(TRUE, NA))
+/// ```
+///
+/// Use instead:
+/// ```r
+/// expect_identical(nrow(x), 2L)
+/// expect_identical(class(x), "data.frame")
+/// expect_identical(x, c(TRUE, NA))
+/// ```
+pub fn expect_identical(ast: &RCall, fn_name: &str) -> anyhow::Result<Option<Diagnostic>> {
+    if fn_name != "expect_equal" {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+
+    // An explicit tolerance means the user wants the tolerance semantics.
+    if get_arg_by_name(&args, "tolerance").is_some() {
+        return Ok(None);
+    }
+
+    let object = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "object", 1));
+    let expected = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "expected", 2));
+
+    // Missing values, e.g. `expect_equal(x, )`.
+    if object.value().is_none() {
+        return Ok(None);
+    }
+    let expected_value = unwrap_or_return_none!(expected.value());
+
+    // `expect_equal(x, TRUE)` and `expect_equal(x, FALSE)` are reported by
+    // `expect_true_false`.
+    if expected_value.as_r_true_expression().is_some()
+        || expected_value.as_r_false_expression().is_some()
+    {
+        return Ok(None);
+    }
+
+    if !is_exact_literal(&expected_value)? {
+        return Ok(None);
+    }
+
+    // Arguments like `info` or `label` are also accepted by
+    // `expect_identical()`, but we only rewrite the simplest form.
+    let has_extra_args = args.iter().count() > 2;
+
+    // Only the function name is replaced, the arguments are kept as-is.
+    let function = ast.function()?;
+    let namespace_prefix = get_function_namespace_prefix(function.clone()).unwrap_or_default();
+    let function_range = function.syntax().text_trimmed_range();
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "expect_identical".to_string(),
+            "`expect_identical(x, y)` is better than `expect_equal(x, y)` when `y` is not a double."
+                .to_string(),
+            Some("Use `expect_identical(x, y)` instead.".to_string()),
+        ),
+        range,
+        Fix {
+            content: format!("{namespace_prefix}expect_identical"),
+            start: function_range.start().into(),
+            end: function_range.end().into(),
+            to_skip: has_extra_args || node_contains_comments(ast.syntax()),
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// Whether `expr` is an integer, string or logical literal, or a call to `c()`
+/// whose elements are all such literals.
+fn is_exact_literal(expr: &AnyRExpression) -> anyhow::Result<bool> {
+    if let Some(value) = expr.as_any_r_value() {
+        return Ok(value.as_r_integer_value().is_some() || value.as_r_string_value().is_some());
+    }
+
+    if expr.as_r_true_expression().is_some()
+        || expr.as_r_false_expression().is_some()
+        || expr.as_r_na_expression().is_some()
+    {
+        return Ok(true);
+    }
+
+    if let Some(call) = expr.as_r_call() {
+        if get_function_name(call.function()?) != "c" {
+            return Ok(false);
+        }
+        let items = call.arguments()?.items();
+        if items.is_empty() {
+            return Ok(false);
+        }
+        for item in items.iter() {
+            let Some(value) = item?.value() else {
+                return Ok(false);
+            };
+            // Nested vectors are not considered, `c(1L, c(2L))` is unusual.
+            if value.as_r_call().is_some() || !is_exact_literal(&value)? {
+                return Ok(false);
+            }
+        }
+        return Ok(true);
+    }
+
+    Ok(false)
+}
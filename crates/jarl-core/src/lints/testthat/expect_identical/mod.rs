@@ -0,0 +1,241 @@
+pub(crate) mod expect_identical;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "expect_identical", None)
+    }
+
+    #[test]
+    fn test_no_lint_expect_identical() {
+        // Doubles benefit from the tolerance
+        expect_no_lint("expect_equal(x, 1)", "expect_identical", None);
+        expect_no_lint("expect_equal(x, 1.5)", "expect_identical", None);
+        expect_no_lint("expect_equal(x, c(1L, 2))", "expect_identical", None);
+
+        // Explicit tolerance
+        expect_no_lint(
+            "expect_equal(x, 1L, tolerance = 1e-6)",
+            "expect_identical",
+            None,
+        );
+
+        // Not literals
+        expect_no_lint("expect_equal(x, y)", "expect_identical", None);
+        expect_no_lint("expect_equal(x, foo(1L))", "expect_identical", None);
+        expect_no_lint("expect_equal(x, c(y, 1L))", "expect_identical", None);
+        expect_no_lint("expect_equal(x, c())", "expect_identical", None);
+        expect_no_lint("expect_equal(x, NULL)", "expect_identical", None);
+
+        // Handled by `expect_true_false`
+        expect_no_lint("expect_equal(x, TRUE)", "expect_identical", None);
+        expect_no_lint("expect_equal(x, FALSE)", "expect_identical", None);
+
+        // Not the functions we're looking for
+        expect_no_lint("expect_identical(x, 1L)", "expect_identical", None);
+        expect_no_lint("expect_equivalent(x, 1L)", "expect_identical", None);
+
+        // Wrong code but no panic
+        expect_no_lint("expect_equal(x)", "expect_identical", None);
+        expect_no_lint("expect_equal(, 1L)", "expect_identical", None);
+        expect_no_lint(
+            "expect_equal(object =, expected =)",
+            "expect_identical",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_expect_identical() {
+        assert_snapshot!(
+            snapshot_lint("expect_equal(nrow(x), 2L)"),
+            @"
+        warning: expect_identical
+         --> <test>:1:1
+          |
+        1 | expect_equal(nrow(x), 2L)
+          | ------------------------- `expect_identical(x, y)` is better than `expect_equal(x, y)` when `y` is not a double.
+          |
+          = help: Use `expect_identical(x, y)` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("expect_equal(class(x), 'data.frame')"),
+            @"
+        warning: expect_identical
+         --> <test>:1:1
+          |
+        1 | expect_equal(class(x), 'data.frame')
+          | ------------------------------------ `expect_identical(x, y)` is better than `expect_equal(x, y)` when `y` is not a double.
+          |
+          = help: Use `expect_identical(x, y)` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("expect_equal(x, c(TRUE, NA))"),
+            @"
+        warning: expect_identical
+         --> <test>:1:1
+          |
+        1 | expect_equal(x, c(TRUE, NA))
+          | ---------------------------- `expect_identical(x, y)` is better than `expect_equal(x, y)` when `y` is not a double.
+          |
+          = help: Use `expect_identical(x, y)` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("expect_equal(expected = NA, object = x)"),
+            @"
+        warning: expect_identical
+         --> <test>:1:1
+          |
+        1 | expect_equal(expected = NA, object = x)
+          | --------------------------------------- `expect_identical(x, y)` is better than `expect_equal(x, y)` when `y` is not a double.
+          |
+          = help: Use `expect_identical(x, y)` instead.
+        Found 1 error.
+        "
+        );
+
+        assert_snapshot!(
+            get_unsafe_fixed_text(
+                vec![
+                    "expect_equal(nrow(x), 2L)",
+                    "testthat::expect_equal(names(x), c('a', 'b'))",
+                    "expect_equal(expected = NA, object = x)",
+                    "expect_equal(x, 1L, info = 'some info')",
+                ],
+                "expect_identical",
+            ),
+            @"
+        OLD:
+        ====
+        expect_equal(nrow(x), 2L)
+        NEW:
+        ====
+        expect_identical(nrow(x), 2L)
+
+        OLD:
+        ====
+        testthat::expect_equal(names(x), c('a', 'b'))
+        NEW:
+        ====
+        testthat::expect_identical(names(x), c('a', 'b'))
+
+        OLD:
+        ====
+        expect_equal(expected = NA, object = x)
+        NEW:
+        ====
+        expect_identical(expected = NA, object = x)
+
+        OLD:
+        ====
+        expect_equal(x, 1L, info = 'some info')
+        NEW:
+        ====
+        expect_equal(x, 1L, info = 'some info')
+        "
+        );
+    }
+
+    #[test]
+    fn test_expect_identical_fix_is_unsafe() {
+        // `expect_equal(x, 2L)` and `expect_equal(x, NA)` pass when `x` is the
+        // double `2` or `NA_real_`, but `expect_identical()` doesn't.
+        assert_snapshot!(
+            snapshot_lint("expect_equal(x, 2L)"),
+            @"
+        warning: expect_identical
+         --> <test>:1:1
+          |
+        1 | expect_equal(x, 2L)
+          | ------------------- `expect_identical(x, y)` is better than `expect_equal(x, y)` when `y` is not a double.
+          |
+          = help: Use `expect_identical(x, y)` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("expect_equal(x, NA)"),
+            @"
+        warning: expect_identical
+         --> <test>:1:1
+          |
+        1 | expect_equal(x, NA)
+          | ------------------- `expect_identical(x, y)` is better than `expect_equal(x, y)` when `y` is not a double.
+          |
+          = help: Use `expect_identical(x, y)` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            get_fixed_text(
+                vec!["expect_equal(x, 2L)", "expect_equal(x, NA)"],
+                "expect_identical",
+                None,
+            ),
+            @"
+        OLD:
+        ====
+        expect_equal(x, 2L)
+        NEW:
+        ====
+        expect_equal(x, 2L)
+
+        OLD:
+        ====
+        expect_equal(x, NA)
+        NEW:
+        ====
+        expect_equal(x, NA)
+        "
+        );
+    }
+
+    #[test]
+    fn test_expect_identical_with_comments_no_fix() {
+        assert_snapshot!(
+            get_unsafe_fixed_text(
+                vec![
+                    "# leading comment\nexpect_equal(x, 1L)",
+                    "expect_equal(x, # comment\n1L)",
+                    "expect_equal(x, 1L) # trailing comment",
+                ],
+                "expect_identical",
+            ),
+            @"
+        OLD:
+        ====
+        # leading comment
+        expect_equal(x, 1L)
+        NEW:
+        ====
+        # leading comment
+        expect_identical(x, 1L)
+
+        OLD:
+        ====
+        expect_equal(x, # comment
+        1L)
+        NEW:
+        ====
+        expect_equal(x, # comment
+        1L)
+
+        OLD:
+        ====
+        expect_equal(x, 1L) # trailing comment
+        NEW:
+        ====
+        expect_identical(x, 1L) # trailing comment
+        "
+        );
+    }
+}
@@ -0,0 +1,109 @@
+use crate::diagnostic::*;
+use crate::utils::{
+    get_arg_by_name_then_position, get_function_name, get_function_namespace_prefix,
+    node_contains_comments,
+};
+use air_r_syntax::*;
+use biome_rowan::AstNode;
+
+/// Version added: 0.6.0
+///
+/// ## What it does
+///
+/// Checks for usage of `expect_equal(sort(x), sort(y))` and
+/// `expect_identical(sort(x), sort(y))`.
+///
+/// ## Why is this bad?
+///
+/// Sorting both sides is a common way to test that two vectors have the same
+/// elements regardless of their order. `expect_setequal(x, y)` states this
+/// intent directly and gives a better error message by listing the elements
+/// that only appear on one side.
+///
+/// This rule is **disabled by default**. Select it either with the rule name
+/// `"expect_setequal"` or with the rule group `"TESTTHAT"`.
+///
+/// ## Example
+///
+/// ```r
+/// expect_equal(sort(x), sort(y))
+/// expect_identical(sort(names(x)), sort(c("a", "b")))
+/// ```
+///
+/// Use instead:
+/// ```r
+/// expect_setequal(x, y)
+/// expect_setequal(names(x), c("a", "b"))
+/// ```
+pub fn expect_setequal(ast: &RCall, fn_name: &str) -> anyhow::Result<Option<Diagnostic>> {
+    // Only check expect_equal and expect_identical
+    if fn_name != "expect_equal" && fn_name != "expect_identical" {
+        return Ok(None);
+    }
+
+    let args = ast.arguments()?.items();
+
+    let object = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "object", 1));
+    let expected = unwrap_or_return_none!(get_arg_by_name_then_position(&args, "expected", 2));
+
+    let object_value = unwrap_or_return_none!(object.value());
+    let expected_value = unwrap_or_return_none!(expected.value());
+
+    // expect_setequal() doesn't support info=, label=, or expected.label= arguments
+    if args.iter().count() > 2 {
+        return Ok(None);
+    }
+
+    let x_value = unwrap_or_return_none!(get_sorted_value(&object_value)?);
+    let y_value = unwrap_or_return_none!(get_sorted_value(&expected_value)?);
+
+    let x_text = x_value.to_trimmed_text();
+    let y_text = y_value.to_trimmed_text();
+
+    // Preserve namespace prefix if present
+    let namespace_prefix = get_function_namespace_prefix(ast.function()?).unwrap_or_default();
+
+    let range = ast.syntax().text_trimmed_range();
+    let diagnostic = Diagnostic::new(
+        ViolationData::new(
+            "expect_setequal".to_string(),
+            format!(
+                "`expect_setequal(x, y)` is better than `{}(sort(x), sort(y))`.",
+                fn_name
+            ),
+            Some("Use `expect_setequal(x, y)` instead.".to_string()),
+        ),
+        range,
+        Fix {
+            content: format!(
+                "{}expect_setequal({}, {})",
+                namespace_prefix, x_text, y_text
+            ),
+            start: range.start().into(),
+            end: range.end().into(),
+            to_skip: node_contains_comments(ast.syntax()),
+        },
+    );
+
+    Ok(Some(diagnostic))
+}
+
+/// If `value` is `sort(x)` with no other argument, return `x`.
+fn get_sorted_value(value: &AnyRExpression) -> anyhow::Result<Option<AnyRExpression>> {
+    let Some(call) = value.as_r_call() else {
+        return Ok(None);
+    };
+    if get_function_name(call.function()?) != "sort" {
+        return Ok(None);
+    }
+
+    // Arguments like `decreasing` or `na.last` would make the rewrite less
+    // obvious, so we only handle `sort(x)`.
+    let sort_args = call.arguments()?.items();
+    if sort_args.iter().count() != 1 {
+        return Ok(None);
+    }
+
+    let x = unwrap_or_return_none!(get_arg_by_name_then_position(&sort_args, "x", 1));
+    Ok(x.value())
+}
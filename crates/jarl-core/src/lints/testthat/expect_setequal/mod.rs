@@ -0,0 +1,170 @@
+pub(crate) mod expect_setequal;
+
+#[cfg(test)]
+mod tests {
+    use crate::utils_test::*;
+    use insta::assert_snapshot;
+
+    fn snapshot_lint(code: &str) -> String {
+        format_diagnostics(code, "expect_setequal", None)
+    }
+
+    #[test]
+    fn test_no_lint_expect_setequal() {
+        expect_no_lint("expect_setequal(x, y)", "expect_setequal", None);
+        expect_no_lint("expect_equal(x, y)", "expect_setequal", None);
+
+        // Only one side is sorted
+        expect_no_lint("expect_equal(sort(x), y)", "expect_setequal", None);
+        expect_no_lint("expect_equal(x, sort(y))", "expect_setequal", None);
+
+        // Extra arguments to sort()
+        expect_no_lint(
+            "expect_equal(sort(x, decreasing = TRUE), sort(y, decreasing = TRUE))",
+            "expect_setequal",
+            None,
+        );
+        expect_no_lint(
+            "expect_equal(sort(x, na.last = TRUE), sort(y))",
+            "expect_setequal",
+            None,
+        );
+
+        // expect_setequal() doesn't have `info`, `label`, etc.
+        expect_no_lint(
+            "expect_equal(sort(x), sort(y), info = 'foo')",
+            "expect_setequal",
+            None,
+        );
+        expect_no_lint(
+            "expect_equal(sort(x), sort(y), tolerance = 1e-6)",
+            "expect_setequal",
+            None,
+        );
+
+        // Not the functions we're looking for
+        expect_no_lint("expect_true(sort(x), sort(y))", "expect_setequal", None);
+        expect_no_lint("expect_equal(order(x), order(y))", "expect_setequal", None);
+
+        // Wrong code but no panic
+        expect_no_lint("expect_equal(sort(x))", "expect_setequal", None);
+        expect_no_lint("expect_equal(sort(), sort())", "expect_setequal", None);
+        expect_no_lint(
+            "expect_equal(object =, expected =)",
+            "expect_setequal",
+            None,
+        );
+    }
+
+    #[test]
+    fn test_lint_expect_setequal() {
+        assert_snapshot!(
+            snapshot_lint("expect_equal(sort(x), sort(y))"),
+            @"
+        warning: expect_setequal
+         --> <test>:1:1
+          |
+        1 | expect_equal(sort(x), sort(y))
+          | ------------------------------ `expect_setequal(x, y)` is better than `expect_equal(sort(x), sort(y))`.
+          |
+          = help: Use `expect_setequal(x, y)` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            snapshot_lint("expect_identical(sort(names(x)), sort(c('a', 'b')))"),
+            @"
+        warning: expect_setequal
+         --> <test>:1:1
+          |
+        1 | expect_identical(sort(names(x)), sort(c('a', 'b')))
+          | --------------------------------------------------- `expect_setequal(x, y)` is better than `expect_identical(sort(x), sort(y))`.
+          |
+          = help: Use `expect_setequal(x, y)` instead.
+        Found 1 error.
+        "
+        );
+        assert_snapshot!(
+            get_fixed_text(
+                vec![
+                    "expect_equal(sort(x), sort(y))",
+                    "testthat::expect_identical(sort(names(x)), sort(c('a', 'b')))",
+                    "expect_equal(expected = sort(y), object = sort(x))",
+                    "expect_equal(base::sort(x$a), sort(unique(y)))",
+                ],
+                "expect_setequal",
+                None,
+            ),
+            @"
+        OLD:
+        ====
+        expect_equal(sort(x), sort(y))
+        NEW:
+        ====
+        expect_setequal(x, y)
+
+        OLD:
+        ====
+        testthat::expect_identical(sort(names(x)), sort(c('a', 'b')))
+        NEW:
+        ====
+        testthat::expect_setequal(names(x), c('a', 'b'))
+
+        OLD:
+        ====
+        expect_equal(expected = sort(y), object = sort(x))
+        NEW:
+        ====
+        expect_setequal(x, y)
+
+        OLD:
+        ====
+        expect_equal(base::sort(x$a), sort(unique(y)))
+        NEW:
+        ====
+        expect_setequal(x$a, unique(y))
+        "
+        );
+    }
+
+    #[test]
+    fn test_expect_setequal_with_comments_no_fix() {
+        assert_snapshot!(
+            get_fixed_text(
+                vec![
+                    "# leading comment\nexpect_equal(sort(x), sort(y))",
+                    "expect_equal(sort(x), # comment\nsort(y))",
+                    "expect_equal(sort(x), sort(y)) # trailing comment",
+                ],
+                "expect_setequal",
+                None
+            ),
+            @"
+        OLD:
+        ====
+        # leading comment
+        expect_equal(sort(x), sort(y))
+        NEW:
+        ====
+        # leading comment
+        expect_setequal(x, y)
+
+        OLD:
+        ====
+        expect_equal(sort(x), # comment
+        sort(y))
+        NEW:
+        ====
+        expect_equal(sort(x), # comment
+        sort(y))
+
+        OLD:
+        ====
+        expect_equal(sort(x), sort(y)) # trailing comment
+        NEW:
+        ====
+        expect_setequal(x, y) # trailing comment
+        "
+        );
+    }
+}
@@ -1,3 +1,4 @@
+pub(crate) mod expect_identical;
 pub(crate) mod expect_length;
 pub(crate) mod expect_match;
 pub(crate) mod expect_named;
@@ -6,5 +7,6 @@ pub(crate) mod expect_not;
 pub(crate) mod expect_null;
 pub(crate) mod expect_s3_class;
 pub(crate) mod expect_s4_class;
+pub(crate) mod expect_setequal;
 pub(crate) mod expect_true_false;
 pub(crate) mod expect_type;
@@ -90,6 +90,27 @@ fn join_continuation_lines(content: &str) -> Vec<String> {
 /// expansion (suitable for external packages where we don't have the full
 /// object list).
 pub fn parse_namespace_exports(content: &str, all_names: &[&str]) -> HashSet<String> {
+    parse_exports(
+        content,
+        all_names,
+        &[
+            "export",
+            "exportPattern",
+            "S3method",
+            "exportMethods",
+            "exportClasses",
+        ],
+    )
+}
+
+/// Same as [parse_namespace_exports] but only for the objects exported with
+/// `export()` and `exportPattern()`, i.e. without S3 methods and S4 classes
+/// and methods.
+pub fn parse_namespace_exported_objects(content: &str, all_names: &[&str]) -> HashSet<String> {
+    parse_exports(content, all_names, &["export", "exportPattern"])
+}
+
+fn parse_exports(content: &str, all_names: &[&str], directives: &[&str]) -> HashSet<String> {
     let mut exports = HashSet::new();
 
     // Join multi-line directives: a line starting a directive (e.g. `export(`)
@@ -103,15 +124,9 @@ pub fn parse_namespace_exports(content: &str, all_names: &[&str]) -> HashSet<Str
             continue;
         }
 
-        for directive in [
-            "export",
-            "exportPattern",
-            "S3method",
-            "exportMethods",
-            "exportClasses",
-        ] {
+        for directive in directives {
             if let Some(inner) = extract_directive(trimmed, directive) {
-                match directive {
+                match *directive {
                     "export" => {
                         for name in inner.split(',') {
                             let name = name.trim().trim_matches('"').trim_matches('\'');
@@ -283,4 +298,11 @@ export(my_fn)
         let result = parse_namespace_imports(ns);
         assert_eq!(result.blanket_imports, vec!["dplyr"]);
     }
+
+    #[test]
+    fn test_parse_exported_objects() {
+        let ns = "export(f)\nexportPattern(\"^g\")\nS3method(print, foo)\nexportClasses(bar)\n";
+        let result = parse_namespace_exported_objects(ns, &["g1", "h"]);
+        assert_eq!(result, HashSet::from(["f".to_string(), "g1".to_string()]));
+    }
 }
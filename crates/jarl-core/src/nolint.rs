@@ -0,0 +1,359 @@
+//! Conversion of lintr's `# nolint` comments to suppression comments
+//!
+//! lintr supports two kinds of exclusion comments:
+//!
+//! - `# nolint: x_linter, y_linter.` at the end of a line, which excludes this
+//!   line only;
+//! - `# nolint start: x_linter.` and `# nolint end`, which exclude all the
+//!   lines between them.
+//!
+//! They are converted to `# jarl-ignore` comments placed above the code, and
+//! to `# jarl-ignore-start` and `# jarl-ignore-end` comments. Since Jarl
+//! requires a rule name, comments that don't list any linter with an
+//! equivalent in Jarl are left unchanged.
+
+use air_r_parser::RParserOptions;
+use anyhow::anyhow;
+use biome_rowan::{AstNode, Direction, TextRange};
+use std::collections::BTreeMap;
+
+use crate::lintr_config::jarl_rules;
+use crate::suppression_edit::{compute_suppression_insert_point, format_suppression_comments};
+
+/// Result of the conversion of the `# nolint` comments of a file.
+#[derive(Debug, Default, PartialEq)]
+pub struct NolintConversion {
+    /// The contents of the file with the converted comments.
+    pub content: String,
+    /// Number of `# nolint` comments that were converted.
+    pub converted: usize,
+    /// Line (1-indexed) of the `# nolint` comments that were not converted,
+    /// and the reason why.
+    pub skipped: Vec<(usize, String)>,
+}
+
+#[derive(Debug, PartialEq)]
+enum NolintKind {
+    Line,
+    Start,
+    End,
+}
+
+/// A `# nolint` comment, possibly after some other text in the same comment.
+#[derive(Debug, PartialEq)]
+struct Nolint {
+    kind: NolintKind,
+    /// Offset of the `#` of `# nolint` in the comment.
+    offset: usize,
+    /// lintr linters listed after the colon, `None` if there is no list.
+    linters: Option<Vec<String>>,
+}
+
+impl Nolint {
+    fn parse(comment: &str) -> Option<Self> {
+        let (offset, rest) = comment.match_indices('#').find_map(|(offset, _)| {
+            let rest = comment[offset + 1..].trim_start().strip_prefix("nolint")?;
+            Some((offset, rest.trim_start()))
+        })?;
+
+        let (kind, rest) = if let Some(rest) = rest.strip_prefix("start") {
+            (NolintKind::Start, rest.trim_start())
+        } else if let Some(rest) = rest.strip_prefix("end") {
+            (NolintKind::End, rest.trim_start())
+        } else {
+            (NolintKind::Line, rest)
+        };
+
+        let linters = rest.strip_prefix(':').map(|list| {
+            let list = list.split('.').next().unwrap_or_default();
+            list.split(',')
+                .map(|linter| linter.trim().to_string())
+                .filter(|linter| !linter.is_empty())
+                .collect()
+        });
+
+        Some(Self { kind, offset, linters })
+    }
+
+    /// Jarl rules equivalent to the linters of the comment.
+    fn rules(&self) -> Vec<&'static str> {
+        let mut rules = Vec::new();
+        for linter in self.linters.iter().flatten() {
+            for rule in jarl_rules(linter) {
+                if !rules.contains(&rule) {
+                    rules.push(rule);
+                }
+            }
+        }
+        rules
+    }
+}
+
+/// Convert the `# nolint` comments of an R file, using `reason` as the
+/// explanation of the new suppression comments.
+pub fn convert_nolint_comments(source: &str, reason: &str) -> anyhow::Result<NolintConversion> {
+    let parsed = air_r_parser::parse(source, RParserOptions::default());
+    if parsed.has_error() {
+        return Err(anyhow!("Failed to parse the file."));
+    }
+
+    let mut comments: Vec<(TextRange, String)> = Vec::new();
+    for token in parsed.tree().syntax().descendants_tokens(Direction::Next) {
+        let pieces = token
+            .leading_trivia()
+            .pieces()
+            .chain(token.trailing_trivia().pieces());
+        for piece in pieces.filter(|piece| piece.is_comments()) {
+            comments.push((piece.text_range(), piece.text().to_string()));
+        }
+    }
+
+    let mut conversion = NolintConversion::default();
+    // Replacements of `(start, end)` ranges of the source.
+    let mut edits: Vec<(usize, usize, String)> = Vec::new();
+    // Suppressions of `# nolint` comments at the end of a line, by insertion
+    // offset, so that several lines of the same expression share comments.
+    let mut suppressions: BTreeMap<usize, (String, bool, Vec<&'static str>)> = BTreeMap::new();
+    // Rules of the `# nolint start` comments that are not closed yet.
+    let mut open_ranges: Vec<Option<Vec<&'static str>>> = Vec::new();
+
+    for (range, text) in comments {
+        let Some(nolint) = Nolint::parse(&text) else {
+            continue;
+        };
+
+        let comment_start: usize = range.start().into();
+        let comment_end: usize = range.end().into();
+        let line_start = source[..comment_start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[comment_end..]
+            .find('\n')
+            .map_or(source.len(), |i| comment_end + i);
+        let line_number = source[..comment_start].matches('\n').count() + 1;
+        let before = &source[line_start..comment_start];
+        let indent: String = before.chars().take_while(|c| c.is_whitespace()).collect();
+        let is_own_line = before.trim().is_empty();
+
+        // The `# nolint` part of the comment, and the whitespace before it.
+        let nolint_start = comment_start + nolint.offset;
+        let removal_start = source[..nolint_start].trim_end_matches([' ', '\t']).len();
+        let replaces_line = is_own_line && nolint.offset == 0;
+
+        let rules = nolint.rules();
+        match nolint.kind {
+            NolintKind::Line => {
+                if is_own_line {
+                    conversion.skipped.push((
+                        line_number,
+                        "`# nolint` on its own line doesn't exclude any code.".to_string(),
+                    ));
+                    continue;
+                }
+                if let Some(why) = skip_reason(&nolint, &rules) {
+                    conversion.skipped.push((line_number, why));
+                    continue;
+                }
+
+                let code_start = line_start + indent.len();
+                let code_end = source[..removal_start]
+                    .trim_end_matches([',', ' ', '\t'])
+                    .len();
+                let Some(insert_point) =
+                    compute_suppression_insert_point(source, code_start, code_end.max(code_start))
+                else {
+                    continue;
+                };
+                let entry = suppressions.entry(insert_point.offset).or_insert((
+                    insert_point.indent,
+                    insert_point.needs_leading_newline,
+                    Vec::new(),
+                ));
+                for rule in rules {
+                    if !entry.2.contains(&rule) {
+                        entry.2.push(rule);
+                    }
+                }
+                edits.push((removal_start, comment_end, String::new()));
+            }
+            NolintKind::Start => {
+                if let Some(why) = skip_reason(&nolint, &rules) {
+                    conversion.skipped.push((line_number, why));
+                    open_ranges.push(None);
+                    continue;
+                }
+
+                let block: String = rules
+                    .iter()
+                    .map(|rule| format!("{indent}# jarl-ignore-start {rule}: {reason}\n"))
+                    .collect();
+                if replaces_line {
+                    edits.push((line_start, (line_end + 1).min(source.len()), block));
+                } else {
+                    edits.push((line_start, line_start, block));
+                    edits.push((removal_start, comment_end, String::new()));
+                }
+                open_ranges.push(Some(rules));
+            }
+            NolintKind::End => {
+                let rules = match open_ranges.pop() {
+                    Some(Some(rules)) => rules,
+                    // The start comment was not converted either.
+                    Some(None) => continue,
+                    None => {
+                        conversion.skipped.push((
+                            line_number,
+                            "`# nolint end` doesn't have a matching `# nolint start`.".to_string(),
+                        ));
+                        continue;
+                    }
+                };
+
+                let block: String = rules
+                    .iter()
+                    .map(|rule| format!("{indent}# jarl-ignore-end {rule}\n"))
+                    .collect();
+                if replaces_line {
+                    edits.push((line_start, (line_end + 1).min(source.len()), block));
+                } else if line_end == source.len() {
+                    edits.push((line_end, line_end, format!("\n{block}")));
+                    edits.push((removal_start, comment_end, String::new()));
+                } else {
+                    edits.push((line_end + 1, line_end + 1, block));
+                    edits.push((removal_start, comment_end, String::new()));
+                }
+            }
+        }
+        conversion.converted += 1;
+    }
+
+    // Like lintr, a range that is not closed ends at the end of the file.
+    let mut unclosed: String = open_ranges
+        .into_iter()
+        .rev()
+        .flatten()
+        .flatten()
+        .map(|rule| format!("# jarl-ignore-end {rule}\n"))
+        .collect();
+    if !unclosed.is_empty() {
+        if !source.is_empty() && !source.ends_with('\n') {
+            unclosed.insert(0, '\n');
+        }
+        edits.push((source.len(), source.len(), unclosed));
+    }
+
+    for (offset, (indent, needs_leading_newline, rules)) in suppressions {
+        let text = format_suppression_comments(&rules, reason, &indent, needs_leading_newline);
+        edits.push((offset, offset, text));
+    }
+
+    // Apply the edits from the end so that the offsets remain valid. At the
+    // same offset, a replacement must be applied before an insertion.
+    edits.sort_by_key(|(start, end, _)| std::cmp::Reverse((*start, *end)));
+    let mut content = source.to_string();
+    for (start, end, text) in edits {
+        content.replace_range(start..end, &text);
+    }
+    conversion.content = content;
+
+    Ok(conversion)
+}
+
+fn skip_reason(nolint: &Nolint, rules: &[&str]) -> Option<String> {
+    if nolint.linters.is_none() {
+        Some(
+            "Jarl requires a rule name, but this `# nolint` comment doesn't list any linter."
+                .to_string(),
+        )
+    } else if rules.is_empty() {
+        Some("The linters of this `# nolint` comment have no equivalent in Jarl.".to_string())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(source: &str) -> NolintConversion {
+        convert_nolint_comments(source, "<reason>").unwrap()
+    }
+
+    #[test]
+    fn test_parse_nolint() {
+        assert_eq!(
+            Nolint::parse("# nolint: any_is_na_linter, seq_linter."),
+            Some(Nolint {
+                kind: NolintKind::Line,
+                offset: 0,
+                linters: Some(vec![
+                    "any_is_na_linter".to_string(),
+                    "seq_linter".to_string()
+                ]),
+            })
+        );
+        assert_eq!(
+            Nolint::parse("# some text #nolint start"),
+            Some(Nolint { kind: NolintKind::Start, offset: 12, linters: None })
+        );
+        assert_eq!(
+            Nolint::parse("# nolint end").map(|nolint| nolint.kind),
+            Some(NolintKind::End)
+        );
+        assert_eq!(Nolint::parse("# a regular comment"), None);
+    }
+
+    #[test]
+    fn test_convert_end_of_line_nolint() {
+        let source = "f <- function(x) {\n  y <- any(is.na(x)) # nolint: any_is_na_linter, line_length_linter.\n  y\n}\n";
+        let conversion = convert(source);
+        assert_eq!(conversion.converted, 1);
+        assert_eq!(
+            conversion.content,
+            "f <- function(x) {\n  # jarl-ignore any_is_na: <reason>\n  y <- any(is.na(x))\n  y\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_convert_nolint_range() {
+        let source = "# nolint start: T_and_F_symbol_linter, seq_linter.\nx <- T\ny <- 1:length(x)\n# nolint end\nz <- F\n";
+        let conversion = convert(source);
+        assert_eq!(conversion.converted, 2);
+        assert_eq!(
+            conversion.content,
+            "# jarl-ignore-start true_false_symbol: <reason>\n\
+             # jarl-ignore-start seq: <reason>\n\
+             # jarl-ignore-start seq2: <reason>\n\
+             x <- T\n\
+             y <- 1:length(x)\n\
+             # jarl-ignore-end true_false_symbol\n\
+             # jarl-ignore-end seq\n\
+             # jarl-ignore-end seq2\n\
+             z <- F\n"
+        );
+    }
+
+    #[test]
+    fn test_unclosed_range_ends_at_end_of_file() {
+        let conversion = convert("# nolint start: any_is_na_linter.\nany(is.na(x))");
+        assert_eq!(
+            conversion.content,
+            "# jarl-ignore-start any_is_na: <reason>\nany(is.na(x))\n# jarl-ignore-end any_is_na\n"
+        );
+    }
+
+    #[test]
+    fn test_unconverted_nolint() {
+        let source = "x <- 1 # nolint\ny <- 2 # nolint: object_name_linter.\n# nolint start\nz <- 3\n# nolint end\n";
+        let conversion = convert(source);
+        assert_eq!(conversion.converted, 0);
+        assert_eq!(conversion.content, source);
+        assert_eq!(
+            conversion
+                .skipped
+                .iter()
+                .map(|(line, _)| *line)
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+
+use crate::rule_options::{ResolvedRuleOptions, RuleOptions};
+use crate::rule_set::{Rule, RuleSet};
+use crate::toml::LinterTomlOptions;
+
+/// Rule selection and rule options of a single `[[lint.overrides]]` entry,
+/// before compiling its glob patterns.
+#[derive(Clone, Debug, Default)]
+pub struct OverrideSettings {
+    /// Glob patterns of the files the override applies to.
+    pub files: Vec<String>,
+    /// Rules that replace the selection in matching files.
+    pub select: Option<Vec<Rule>>,
+    /// Rules added to the selection in matching files.
+    pub extend_select: Vec<Rule>,
+    /// Rules removed from the selection in matching files.
+    pub ignore: Vec<Rule>,
+    /// `[lint.<rule>]` tables set in the override. Those replace the tables of
+    /// the same rules in `[lint]`.
+    pub rule_options: LinterTomlOptions,
+}
+
+/// A single compiled `[[lint.overrides]]` entry.
+#[derive(Clone, Debug)]
+struct Override {
+    /// Matcher built from the `files` patterns, rooted at the `jarl.toml`
+    /// directory.
+    matcher: Gitignore,
+    select: Option<HashSet<Rule>>,
+    extend_select: HashSet<Rule>,
+    ignore: HashSet<Rule>,
+    rule_options: LinterTomlOptions,
+    /// Whether the override sets the options of at least one rule.
+    has_rule_options: bool,
+}
+
+/// Resolved `[[lint.overrides]]` configuration. Each entry changes the rule
+/// selection and the rule options for the files matching its patterns.
+#[derive(Clone, Debug, Default)]
+pub struct Overrides {
+    /// Directory the patterns are resolved against (the `jarl.toml` directory).
+    root: PathBuf,
+    entries: Vec<Override>,
+}
+
+impl Overrides {
+    /// Build [Overrides] from already-resolved entries.
+    ///
+    /// Rule-name validation and group expansion are expected to have happened
+    /// before this point (see `crate::toml`). Patterns follow the format of the
+    /// global `include`/`exclude` options.
+    pub fn new(root: &Path, entries: Vec<OverrideSettings>) -> anyhow::Result<Self> {
+        let mut compiled = Vec::with_capacity(entries.len());
+
+        for entry in entries {
+            if entry.files.is_empty() {
+                return Err(anyhow::anyhow!(
+                    "`files` must contain at least one pattern in `[[lint.overrides]]`."
+                ));
+            }
+
+            let mut builder = GitignoreBuilder::new(root);
+            for pattern in &entry.files {
+                // Mirror the directory handling used for `include`/`exclude`:
+                // a trailing slash targets a directory's contents.
+                let glob = if pattern.ends_with('/') {
+                    format!("{pattern}**")
+                } else {
+                    pattern.clone()
+                };
+                builder.add_line(None, &glob).map_err(|e| {
+                    anyhow::anyhow!(
+                        "Invalid `files` pattern '{pattern}' in `[[lint.overrides]]`: {e}"
+                    )
+                })?;
+            }
+            let matcher = builder.build().map_err(|e| {
+                anyhow::anyhow!("Invalid `files` pattern in `[[lint.overrides]]`: {e}")
+            })?;
+
+            // Report invalid rule options when reading the configuration
+            // rather than when linting the first matching file.
+            let rule_options = entry.rule_options.rule_options();
+            let has_rule_options = rule_options.is_set();
+            ResolvedRuleOptions::default().apply(&rule_options)?;
+
+            compiled.push(Override {
+                matcher,
+                select: entry.select.map(HashSet::from_iter),
+                extend_select: HashSet::from_iter(entry.extend_select),
+                ignore: HashSet::from_iter(entry.ignore),
+                rule_options: entry.rule_options,
+                has_rule_options,
+            });
+        }
+
+        Ok(Self { root: root.to_path_buf(), entries: compiled })
+    }
+
+    /// Whether any override was configured.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Restrict the rules that overrides can enable to `allowed`, e.g. to
+    /// drop rules that aren't available for the minimum R version or that
+    /// were ignored in the CLI.
+    pub fn retain_rules(&mut self, allowed: &RuleSet) {
+        for entry in &mut self.entries {
+            if let Some(select) = &mut entry.select {
+                select.retain(|rule| allowed.contains(rule));
+            }
+            entry.extend_select.retain(|rule| allowed.contains(rule));
+        }
+    }
+
+    /// Apply the overrides matching `path`, in order, to the rules selected
+    /// in `[lint]`.
+    ///
+    /// `path` should be the file's absolute (normalized) path so that it can be
+    /// made relative to the configuration root before matching.
+    pub fn rules(&self, path: &Path, rules: &RuleSet) -> RuleSet {
+        let mut selected: HashSet<Rule> = rules.iter().copied().collect();
+        for entry in self.matching(path) {
+            if let Some(select) = &entry.select {
+                selected = select.clone();
+            }
+            selected.extend(entry.extend_select.iter().copied());
+            selected.retain(|rule| !entry.ignore.contains(rule));
+        }
+
+        // Keep the order of `Rule::all()` so that the output doesn't depend on
+        // the order of the overrides.
+        Rule::all()
+            .iter()
+            .filter(|rule| selected.contains(*rule))
+            .collect()
+    }
+
+    /// Apply the rule options of the overrides matching `path`, in order, to
+    /// the options resolved from `[lint]`.
+    pub fn rule_options(
+        &self,
+        path: &Path,
+        rule_options: &Arc<ResolvedRuleOptions>,
+    ) -> anyhow::Result<Arc<ResolvedRuleOptions>> {
+        let mut matching = self
+            .matching(path)
+            .filter(|entry| entry.has_rule_options)
+            .peekable();
+        if matching.peek().is_none() {
+            return Ok(Arc::clone(rule_options));
+        }
+
+        let mut resolved = ResolvedRuleOptions::clone(rule_options);
+        for entry in matching {
+            resolved.apply(&entry.rule_options.rule_options())?;
+        }
+        Ok(Arc::new(resolved))
+    }
+
+    fn matching(&self, path: &Path) -> impl Iterator<Item = &Override> {
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+        self.entries
+            .iter()
+            .filter(move |entry| entry.matcher.matched(relative, false).is_ignore())
+    }
+}
@@ -6,15 +6,20 @@ use std::path::{Path, PathBuf};
 use crate::checker::DEFAULT_PACKAGES;
 use crate::config::Config;
 use crate::description::Description;
+use crate::file_system::{FileSystem, OsFileSystem};
 use crate::fs::has_r_extension;
 pub use crate::lints::base::duplicated_function_definition::duplicated_function_definition::is_in_r_package;
 use crate::lints::base::duplicated_function_definition::duplicated_function_definition::{
     compute_duplicates_from_shared, scan_top_level_assignments,
 };
+use crate::lints::base::exported_function_missing_docs::exported_function_missing_docs::{
+    compute_missing_docs_from_shared, has_rd_extension, scan_rd_aliases,
+};
 use crate::lints::base::unused_function::unused_function::{
     collect_files, compute_unused_from_shared, has_cpp_extension, scan_symbols,
 };
 use crate::namespace::{parse_namespace_exports, parse_namespace_imports};
+use crate::roxygen::{RoxygenNames, scan_roxygen_names};
 use crate::rule_set::Rule;
 
 /// Scope of a file within an R package, determining how its definitions
@@ -38,6 +43,11 @@ pub struct PackageContext {
     pub namespace_exports: HashSet<String>,
     pub import_from: HashMap<String, String>,
     pub loaded_packages: Vec<String>,
+    /// Packages listed in the `Suggests` field of DESCRIPTION but not in
+    /// `Depends` or `Imports`.
+    pub suggested_packages: Vec<String>,
+    /// Name of the package, from the `Package` field of DESCRIPTION.
+    pub package_name: Option<String>,
     /// Raw NAMESPACE content, retained so `compute_unused_from_shared()` can
     /// call `parse_namespace_exports()` with the full `all_names` list.
     pub namespace_content: Option<String>,
@@ -54,6 +64,47 @@ pub enum FilePackageInfo {
     Script,
 }
 
+/// Kind of code contained in a file, used to apply
+/// `[lint.severity-by-kind]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CodeKind {
+    /// Files in the `R/`, `tests/`, `inst/tinytest/`, `inst/tests/`, or
+    /// `src/` folders of an R package.
+    Package,
+    /// Other R files, including those in `data-raw/` or `vignettes/`.
+    Script,
+    /// R Markdown and Quarto documents.
+    Notebook,
+}
+
+impl CodeKind {
+    pub const ALL: [CodeKind; 3] = [CodeKind::Package, CodeKind::Script, CodeKind::Notebook];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CodeKind::Package => "package",
+            CodeKind::Script => "script",
+            CodeKind::Notebook => "notebook",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        CodeKind::ALL.into_iter().find(|kind| kind.as_str() == name)
+    }
+
+    /// Classify `path` using the package information computed by
+    /// `summarize_package_info()`.
+    pub fn from_path(path: &Path, file_pkg_info: &HashMap<PathBuf, FilePackageInfo>) -> Self {
+        if crate::fs::has_rmd_extension(path) {
+            return CodeKind::Notebook;
+        }
+        match file_pkg_info.get(path) {
+            Some(FilePackageInfo::InPackage { .. }) => CodeKind::Package,
+            _ => CodeKind::Script,
+        }
+    }
+}
+
 /// Shared per-file data collected during the single parallel scan.
 pub(crate) struct SharedFileData {
     pub root_key: String,
@@ -63,6 +114,9 @@ pub(crate) struct SharedFileData {
     pub assignments: Vec<(String, TextRange, u32, u32)>,
     pub symbol_counts: HashMap<String, usize>,
     pub scope: FileScope,
+    /// Functions documented by the roxygen blocks of the file, only collected
+    /// for `R/` files when `exported_function_missing_docs` is enabled.
+    pub roxygen_names: RoxygenNames,
 }
 
 /// Pre-computed cross-file analysis results for an R package.
@@ -80,6 +134,10 @@ pub struct PackageAnalysis {
     /// help)` triples for functions that are defined but never called and not
     /// exported.
     pub unused_functions: HashMap<PathBuf, Vec<(String, TextRange, String)>>,
+    /// Per-file exported functions without documentation.
+    /// Keyed by relativized file path. Value is a list of `(name, lhs_range,
+    /// help)` triples.
+    pub missing_docs: HashMap<PathBuf, Vec<(String, TextRange, String)>>,
 }
 
 /// Classify every file and pre-compute per-package metadata in one pass.
@@ -90,6 +148,7 @@ pub struct PackageAnalysis {
 /// touching the filesystem again.
 pub fn summarize_package_info(
     paths: &[PathBuf],
+    file_system: &dyn FileSystem,
 ) -> (
     HashMap<PathBuf, PackageContext>,
     HashMap<PathBuf, FilePackageInfo>,
@@ -101,15 +160,11 @@ pub fn summarize_package_info(
         .filter_map(|p| p.parent().map(|d| d.to_path_buf()))
         .collect();
 
-    let dir_is_package: HashMap<PathBuf, bool> = r_dirs
+    let r_dir_roots: HashMap<PathBuf, Option<PathBuf>> = r_dirs
         .into_iter()
         .map(|dir| {
-            let in_pkg = dir
-                .file_name()
-                .and_then(|n| n.to_str())
-                .is_some_and(|n| n == "R")
-                && dir.parent().is_some_and(|p| p.join("DESCRIPTION").exists());
-            (dir, in_pkg)
+            let root = r_dir_package_root(&dir, file_system);
+            (dir, root)
         })
         .collect();
 
@@ -133,14 +188,13 @@ pub fn summarize_package_info(
         }
 
         // Check if this file is in an R/ directory inside a package.
-        let in_pkg = path
+        let pkg_root = path
             .parent()
-            .and_then(|d| dir_is_package.get(d))
-            .copied()
-            .unwrap_or(false);
+            .and_then(|d| r_dir_roots.get(d))
+            .cloned()
+            .flatten();
 
-        if in_pkg && let Some(pkg_root) = path.parent().and_then(|r| r.parent()) {
-            let pkg_root = pkg_root.to_path_buf();
+        if let Some(pkg_root) = pkg_root {
             package_roots.insert(pkg_root.clone());
             insert_info(
                 path,
@@ -153,9 +207,9 @@ pub fn summarize_package_info(
         // subdirectory (tests/, inst/tinytest, inst/tests, src/).
         // Files in other directories (data-raw/, vignettes/, etc.) are
         // treated as scripts so that their `library()` calls are scanned.
-        if let Some(pkg_root) = find_package_root(path) {
+        if let Some(pkg_root) = find_package_root_in(path, file_system) {
             let scope = file_scope_from_path(path);
-            if is_known_package_scope(path, &pkg_root) {
+            if is_known_package_scope(path, &pkg_root, file_system) {
                 package_roots.insert(pkg_root.clone());
                 insert_info(
                     path,
@@ -176,17 +230,24 @@ pub fn summarize_package_info(
         let mut import_from = HashMap::new();
         let mut namespace_exports = HashSet::new();
         let mut namespace_content = None;
+        let mut suggested_packages = Vec::new();
+        let mut package_name = None;
 
         let desc_path = root.join("DESCRIPTION");
-        if let Ok(desc) = std::fs::read_to_string(&desc_path) {
+        if let Ok(desc) = file_system.read_to_string(&desc_path) {
             packages.extend(Description::get_package_deps(
                 &desc,
                 &["Depends", "Imports"],
             ));
+            suggested_packages = Description::get_package_deps(&desc, &["Suggests"])
+                .into_iter()
+                .filter(|pkg| !packages.contains(pkg))
+                .collect();
+            package_name = Description::get_package_name(&desc);
         }
 
         let ns_path = root.join("NAMESPACE");
-        if let Ok(ns) = std::fs::read_to_string(&ns_path) {
+        if let Ok(ns) = file_system.read_to_string(&ns_path) {
             let imports = parse_namespace_imports(&ns);
             import_from = imports.import_from;
             for pkg in imports.blanket_imports {
@@ -204,6 +265,8 @@ pub fn summarize_package_info(
                 namespace_exports,
                 import_from,
                 loaded_packages: packages,
+                suggested_packages,
+                package_name,
                 namespace_content,
             },
         );
@@ -223,11 +286,13 @@ pub fn make_package_analysis(
     config: &Config,
     namespace_contents: &HashMap<PathBuf, String>,
 ) -> PackageAnalysis {
+    let file_system = config.file_system.as_ref();
     let rules = &config.rules_to_apply;
     let check_duplicates = rules.contains(&Rule::DuplicatedFunctionDefinition);
     let check_unused = rules.contains(&Rule::UnusedFunction);
+    let check_docs = rules.contains(&Rule::ExportedFunctionMissingDocs);
 
-    if !check_duplicates && !check_unused {
+    if !check_duplicates && !check_unused && !check_docs {
         return PackageAnalysis::default();
     }
 
@@ -239,15 +304,11 @@ pub fn make_package_analysis(
         .filter_map(|p| p.parent().map(|d| d.to_path_buf()))
         .collect();
 
-    let dir_is_package: HashMap<PathBuf, bool> = r_dirs
+    let r_dir_roots: HashMap<PathBuf, Option<PathBuf>> = r_dirs
         .into_iter()
         .map(|dir| {
-            let in_pkg = dir
-                .file_name()
-                .and_then(|n| n.to_str())
-                .is_some_and(|n| n == "R")
-                && dir.parent().is_some_and(|p| p.join("DESCRIPTION").exists());
-            (dir, in_pkg)
+            let root = r_dir_package_root(&dir, file_system);
+            (dir, root)
         })
         .collect();
 
@@ -257,9 +318,8 @@ pub fn make_package_analysis(
         .filter(|p| has_r_extension(p))
         .filter(|p| {
             p.parent()
-                .and_then(|d| dir_is_package.get(d))
-                .copied()
-                .unwrap_or(false)
+                .and_then(|d| r_dir_roots.get(d))
+                .is_some_and(Option::is_some)
         })
         .collect();
 
@@ -268,16 +328,14 @@ pub fn make_package_analysis(
     // Also collect extra files (tests/, inst/tinytest/, inst/tests/, src/).
     let mut extra_files: Vec<PathBuf> = Vec::new();
     let mut excluded_r_files: Vec<PathBuf> = Vec::new();
+    let mut rd_aliases: HashMap<PathBuf, HashSet<String>> = HashMap::new();
 
-    let package_roots: HashSet<PathBuf> = r_dir_files
-        .iter()
-        .filter_map(|p| p.parent().and_then(|r| r.parent()).map(|r| r.to_path_buf()))
-        .collect();
+    let package_roots: HashSet<PathBuf> = r_dir_roots.values().flatten().cloned().collect();
 
     // Collect the set of R/ files already in paths (canonicalized for comparison).
     let r_dir_file_set: HashSet<PathBuf> = r_dir_files
         .iter()
-        .filter_map(|p| std::fs::canonicalize(p).ok())
+        .filter_map(|p| file_system.canonicalize(p).ok())
         .collect();
 
     for root in &package_roots {
@@ -285,9 +343,9 @@ pub fn make_package_analysis(
         // contribute to the cross-file analysis. Diagnostics are only emitted
         // for files in config.paths, so excluded files won't produce warnings.
         let r_dir = root.join("R");
-        if r_dir.is_dir() {
-            for file in collect_files(&r_dir, has_r_extension) {
-                if let Ok(canon) = std::fs::canonicalize(&file)
+        if file_system.is_dir(&r_dir) {
+            for file in collect_files(file_system, &r_dir, has_r_extension) {
+                if let Ok(canon) = file_system.canonicalize(&file)
                     && !r_dir_file_set.contains(&canon)
                 {
                     excluded_r_files.push(file);
@@ -295,18 +353,31 @@ pub fn make_package_analysis(
             }
         }
 
+        if check_docs {
+            // Hand-written documentation in man/
+            let man_dir = root.join("man");
+            if file_system.is_dir(&man_dir) {
+                let aliases = collect_files(file_system, &man_dir, has_rd_extension)
+                    .iter()
+                    .filter_map(|file| file_system.read_to_string(file).ok())
+                    .flat_map(|content| scan_rd_aliases(&content))
+                    .collect();
+                rd_aliases.insert(root.clone(), aliases);
+            }
+        }
+
         if check_unused {
             // Collect test/tinytest R files
             for dir_name in &["inst/tinytest", "inst/tests", "tests"] {
                 let dir = root.join(dir_name);
-                if dir.is_dir() {
-                    extra_files.extend(collect_files(&dir, has_r_extension));
+                if file_system.is_dir(&dir) {
+                    extra_files.extend(collect_files(file_system, &dir, has_r_extension));
                 }
             }
             // Collect C/C++ files in src/
             let src_dir = root.join("src");
-            if src_dir.is_dir() {
-                extra_files.extend(collect_files(&src_dir, has_cpp_extension));
+            if file_system.is_dir(&src_dir) {
+                extra_files.extend(collect_files(file_system, &src_dir, has_cpp_extension));
             }
         }
     }
@@ -327,7 +398,7 @@ pub fn make_package_analysis(
     let shared_data: Vec<SharedFileData> = all_files
         .par_iter()
         .filter_map(|(path, scope)| {
-            let content = std::fs::read_to_string(path).ok()?;
+            let content = file_system.read_to_string(path).ok()?;
             let symbol_counts = if check_unused {
                 scan_symbols(&content)
             } else {
@@ -341,9 +412,22 @@ pub fn make_package_analysis(
 
             if *scope == FileScope::R {
                 let r_dir = path.parent()?;
-                let package_root = r_dir.parent()?.to_path_buf();
+                // Excluded files are not in `r_dir_roots` but were found in
+                // `package_root/R`.
+                let package_root = match r_dir_roots.get(r_dir) {
+                    Some(root) => root.clone()?,
+                    None => r_dir.parent()?.to_path_buf(),
+                };
                 let rel_path = PathBuf::from(crate::fs::relativize_path(path));
                 let root_key = crate::fs::relativize_path(r_dir);
+                let roxygen_names = if check_docs {
+                    let definitions = assignments
+                        .iter()
+                        .map(|(name, _, line, _)| (name.as_str(), *line));
+                    scan_roxygen_names(&content, definitions)
+                } else {
+                    RoxygenNames::default()
+                };
                 Some(SharedFileData {
                     root_key,
                     rel_path,
@@ -351,11 +435,12 @@ pub fn make_package_analysis(
                     assignments,
                     symbol_counts,
                     scope: FileScope::R,
+                    roxygen_names,
                 })
             } else {
                 // Extra file: figure out the package root. The file is
                 // somewhere under root/tests/, root/inst/, or root/src/.
-                let package_root = find_package_root(path)?;
+                let package_root = find_package_root_in(path, file_system)?;
                 let r_dir = package_root.join("R");
                 let rel_path = PathBuf::from(crate::fs::relativize_path(path));
                 let root_key = crate::fs::relativize_path(&r_dir);
@@ -366,6 +451,7 @@ pub fn make_package_analysis(
                     assignments,
                     symbol_counts,
                     scope: *scope,
+                    roxygen_names: RoxygenNames::default(),
                 })
             }
         })
@@ -387,7 +473,17 @@ pub fn make_package_analysis(
         HashMap::new()
     };
 
-    PackageAnalysis { duplicate_assignments, unused_functions }
+    let missing_docs = if check_docs {
+        compute_missing_docs_from_shared(&shared_data, namespace_contents, &rd_aliases)
+    } else {
+        HashMap::new()
+    };
+
+    PackageAnalysis {
+        duplicate_assignments,
+        unused_functions,
+        missing_docs,
+    }
 }
 
 /// Determine the `FileScope` for a non-R/ file based on its path.
@@ -416,8 +512,8 @@ pub(crate) fn file_scope_from_path(path: &Path) -> FileScope {
 
 /// Check whether a file is under a recognized package subdirectory
 /// (tests/, inst/tinytest, inst/tests, src/) relative to the package root.
-fn is_known_package_scope(path: &Path, package_root: &Path) -> bool {
-    let Ok(rel) = path.strip_prefix(package_root) else {
+fn is_known_package_scope(path: &Path, package_root: &Path, file_system: &dyn FileSystem) -> bool {
+    let Some(rel) = package_relative_path(path, package_root, file_system) else {
         return false;
     };
     let first = rel.components().next().and_then(|c| c.as_os_str().to_str());
@@ -425,16 +521,73 @@ fn is_known_package_scope(path: &Path, package_root: &Path) -> bool {
 }
 
 /// Walk up from a file path to find the package root (directory containing DESCRIPTION).
-pub(crate) fn find_package_root(path: &Path) -> Option<PathBuf> {
+pub fn find_package_root(path: &Path) -> Option<PathBuf> {
+    find_package_root_in(path, &OsFileSystem)
+}
+
+/// Same as [`find_package_root()`] but looks for DESCRIPTION in `file_system`.
+///
+/// If no parent of `path` contains DESCRIPTION, the parents of the target of
+/// `path` are searched too, so that a file reached through a symlinked folder
+/// (e.g. `project/R` linking to `pkg/R`) still belongs to its package. In this
+/// case, the root is a canonical path and [`package_relative_path()`] must be
+/// used to get the path of the file relative to it.
+pub fn find_package_root_in(path: &Path, file_system: &dyn FileSystem) -> Option<PathBuf> {
+    find_lexical_package_root(path, file_system).or_else(|| {
+        let canonical = file_system.canonicalize(path).ok()?;
+        find_lexical_package_root(&canonical, file_system)
+    })
+}
+
+fn find_lexical_package_root(path: &Path, file_system: &dyn FileSystem) -> Option<PathBuf> {
     let mut dir = path.parent()?;
     loop {
-        if dir.join("DESCRIPTION").exists() {
+        if file_system.is_file(&dir.join("DESCRIPTION")) {
             return Some(dir.to_path_buf());
         }
         dir = dir.parent()?;
     }
 }
 
+/// The path of `path` relative to `package_root`, as returned by
+/// [`find_package_root_in()`].
+pub(crate) fn package_relative_path(
+    path: &Path,
+    package_root: &Path,
+    file_system: &dyn FileSystem,
+) -> Option<PathBuf> {
+    if let Ok(relative) = path.strip_prefix(package_root) {
+        return Some(relative.to_path_buf());
+    }
+    let canonical = file_system.canonicalize(path).ok()?;
+    canonical
+        .strip_prefix(package_root)
+        .ok()
+        .map(Path::to_path_buf)
+}
+
+/// The root of the package whose `R/` folder is `dir`, if any.
+///
+/// As in [`find_package_root_in()`], a symlinked `R/` folder belongs to the
+/// package of its target.
+fn r_dir_package_root(dir: &Path, file_system: &dyn FileSystem) -> Option<PathBuf> {
+    let is_package_r_dir = |dir: &Path| {
+        dir.file_name().is_some_and(|name| name == "R")
+            && dir
+                .parent()
+                .is_some_and(|p| file_system.is_file(&p.join("DESCRIPTION")))
+    };
+    if is_package_r_dir(dir) {
+        return dir.parent().map(Path::to_path_buf);
+    }
+    let canonical = file_system.canonicalize(dir).ok()?;
+    if is_package_r_dir(&canonical) {
+        canonical.parent().map(Path::to_path_buf)
+    } else {
+        None
+    }
+}
+
 /// Scan paths into `SharedFileData`, reading each file once. Used by tests
 /// that need to call `compute_duplicates_from_shared` /
 /// `compute_unused_from_shared` directly.
@@ -463,6 +616,7 @@ pub(crate) fn scan_r_package_paths(paths: &[PathBuf], with_symbols: bool) -> Vec
                 assignments,
                 symbol_counts,
                 scope: FileScope::R,
+                roxygen_names: RoxygenNames::default(),
             })
         })
         .collect()
@@ -497,6 +651,7 @@ pub(crate) fn scan_extra_package_paths(
                 assignments,
                 symbol_counts,
                 scope,
+                roxygen_names: RoxygenNames::default(),
             })
         })
         .collect()
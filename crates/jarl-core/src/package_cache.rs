@@ -14,6 +14,7 @@ use std::sync::{Arc, RwLock};
 use std::time::SystemTime;
 
 use crate::checker::PackageOrigin;
+use crate::file_system::{FileSystem, OsFileSystem};
 
 /// Information about an installed R package.
 #[derive(Debug, Clone)]
@@ -102,7 +103,7 @@ impl PackageCache {
     /// re-fetched via Rscript.
     ///
     /// Returns the names of packages that were refreshed.
-    pub fn refresh_if_stale(&self, packages: &[&str]) -> Vec<String> {
+    pub fn refresh_if_stale(&self, packages: &[&str], file_system: &dyn FileSystem) -> Vec<String> {
         let mut stale = Vec::new();
         let mtimes = self.mtimes.read().unwrap();
 
@@ -110,7 +111,7 @@ impl PackageCache {
             let Some(recorded) = mtimes.get(pkg) else {
                 continue;
             };
-            let current = self.description_mtime(pkg);
+            let current = self.description_mtime(pkg, file_system);
             if current != *recorded {
                 stale.push(pkg.to_string());
             }
@@ -152,12 +153,12 @@ impl PackageCache {
     }
 
     /// Get the mtime of a package's DESCRIPTION file at its recorded install path.
-    fn description_mtime(&self, name: &str) -> Option<SystemTime> {
+    fn description_mtime(&self, name: &str, file_system: &dyn FileSystem) -> Option<SystemTime> {
         let cache = self.cache.read().unwrap();
         let info = cache.get(name)?.as_ref()?;
         let install_path = info.install_path.as_ref()?;
         let desc = install_path.join(name).join("DESCRIPTION");
-        std::fs::metadata(&desc).ok()?.modified().ok()
+        file_system.modified(&desc).ok()
     }
 }
 
@@ -249,7 +250,12 @@ impl PackageCacheMap {
 ///
 /// Returns `None` if no renv project is found.
 pub fn find_r_project_root(file_path: &Path) -> Option<PathBuf> {
-    let start = if file_path.is_file() {
+    find_r_project_root_in(file_path, &OsFileSystem)
+}
+
+/// Same as [`find_r_project_root()`] but looks for `renv.lock` in `file_system`.
+pub fn find_r_project_root_in(file_path: &Path, file_system: &dyn FileSystem) -> Option<PathBuf> {
+    let start = if file_system.is_file(file_path) {
         file_path.parent()?
     } else {
         file_path
@@ -257,7 +263,7 @@ pub fn find_r_project_root(file_path: &Path) -> Option<PathBuf> {
 
     let mut dir = start;
     loop {
-        if dir.join("renv.lock").exists() {
+        if file_system.exists(&dir.join("renv.lock")) {
             return Some(dir.to_path_buf());
         }
         dir = dir.parent()?;
@@ -274,6 +280,16 @@ pub fn find_r_project_root(file_path: &Path) -> Option<PathBuf> {
 /// This is used to skip expensive Rscript calls when no file in the batch
 /// actually uses any of the target packages.
 pub fn any_file_references_packages(paths: &[PathBuf], packages: &[&str]) -> bool {
+    any_file_references_packages_in(paths, packages, &OsFileSystem)
+}
+
+/// Same as [`any_file_references_packages()`] but reads the files from
+/// `file_system`.
+pub fn any_file_references_packages_in(
+    paths: &[PathBuf],
+    packages: &[&str],
+    file_system: &dyn FileSystem,
+) -> bool {
     // Build search patterns: "library(pkg)", "require(pkg)", "pkg::"
     let patterns: Vec<String> = packages
         .iter()
@@ -298,7 +314,7 @@ pub fn any_file_references_packages(paths: &[PathBuf], packages: &[&str]) -> boo
             let desc_path = pkg_root.join("DESCRIPTION");
             if !checked_descriptions.contains(&desc_path) {
                 checked_descriptions.insert(desc_path.clone());
-                if let Ok(desc_content) = std::fs::read_to_string(&desc_path) {
+                if let Ok(desc_content) = file_system.read_to_string(&desc_path) {
                     for pkg in packages {
                         if desc_content.contains(pkg) {
                             return true;
@@ -309,7 +325,7 @@ pub fn any_file_references_packages(paths: &[PathBuf], packages: &[&str]) -> boo
         }
 
         // Fast text scan of the file itself
-        if let Ok(content) = std::fs::read_to_string(path) {
+        if let Ok(content) = file_system.read_to_string(path) {
             for pattern in &patterns {
                 if content.contains(pattern.as_str()) {
                     return true;
@@ -1,9 +1,10 @@
-//! Extraction of R code chunks from R Markdown and Quarto documents.
+//! Extraction of R code chunks from R Markdown, Quarto, and Sweave documents.
 
 use air_r_parser::RParserOptions;
 use biome_rowan::TextRange;
 use biome_rowan::TextSize;
 use regex::Regex;
+use std::path::Path;
 use std::sync::LazyLock;
 
 use crate::directive::{
@@ -13,14 +14,29 @@ use crate::directive::{
 
 /// Matches the opening fence of an executable R code chunk.
 ///
-/// Captures group 1: the backtick sequence (e.g. "```").
+/// Captures group 1: the backtick sequence (e.g. "```"), and group 2: the
+/// chunk label and options.
 /// Accepts `{r}`, `{r label}`, `{r, options}`, etc.
 /// Leading spaces or tabs are allowed to support indented chunks (e.g. inside
 /// list items).
 static OPEN_FENCE: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^[ \t]*(`{3,})\{[rR][^}]*\}").unwrap());
+    LazyLock::new(|| Regex::new(r"^[ \t]*(`{3,})\{[rR]([^}]*)\}").unwrap());
 
-/// An R code chunk extracted from an Rmd/Qmd document.
+/// Matches the opening line of a Sweave code chunk, e.g. `<<label, echo=FALSE>>=`.
+///
+/// Captures group 1: the chunk options.
+static SWEAVE_OPEN: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[ \t]*<<(.*)>>=").unwrap());
+
+/// Matches the line closing a Sweave code chunk: `@`, optionally followed by a
+/// LaTeX comment.
+static SWEAVE_CLOSE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[ \t]*@[ \t]*(%.*)?$").unwrap());
+
+/// Matches the `engine` option of a Sweave chunk, e.g. `engine="python"`.
+static SWEAVE_ENGINE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"\bengine\s*=\s*["']?([A-Za-z0-9_]+)"#).unwrap());
+
+/// An R code chunk extracted from an Rmd/Qmd/Rnw document.
 #[derive(Debug)]
 pub struct RCodeChunk {
     /// The raw source code of the chunk (without fence lines).
@@ -28,6 +44,117 @@ pub struct RCodeChunk {
     /// Byte offset in the original file where the chunk code starts.
     /// This is the byte immediately after the opening fence line's newline.
     pub start_byte: usize,
+    /// The chunk options as `(name, value)` pairs, from the chunk header (e.g.
+    /// `{r, eval=FALSE}`) followed by the `#| name: value` lines at the top of
+    /// the chunk.
+    pub options: Vec<(String, String)>,
+}
+
+impl RCodeChunk {
+    fn new(code: String, start_byte: usize, header: &str) -> Self {
+        let mut options = parse_header_options(header);
+        options.extend(parse_pipe_options(&code));
+        Self { code, start_byte, options }
+    }
+
+    /// Whether the chunk sets `option`, e.g. `eval=FALSE` or `#| eval: false`.
+    /// When an option is set several times, the last value is used.
+    pub fn has_option(&self, option: &ChunkOption) -> bool {
+        self.options
+            .iter()
+            .rev()
+            .find(|(name, _)| *name == option.name)
+            .is_some_and(|(_, value)| normalize_option_value(value) == option.value)
+    }
+}
+
+/// A chunk option with its value, e.g. `eval=FALSE`, used to skip chunks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChunkOption {
+    pub name: String,
+    pub value: String,
+}
+
+impl ChunkOption {
+    /// Parse an option written as in a chunk header (`eval=FALSE`) or as in a
+    /// Quarto chunk (`eval: false`).
+    pub fn parse(option: &str) -> anyhow::Result<Self> {
+        let Some((name, value)) = option.split_once(['=', ':']) else {
+            return Err(anyhow::anyhow!(
+                "Invalid chunk option `{option}`. Expected an option and its value, e.g. `eval=FALSE`."
+            ));
+        };
+        let name = name.trim();
+        if name.is_empty() || value.trim().is_empty() {
+            return Err(anyhow::anyhow!(
+                "Invalid chunk option `{option}`. Expected an option and its value, e.g. `eval=FALSE`."
+            ));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            value: normalize_option_value(value),
+        })
+    }
+}
+
+/// Normalize the value of a chunk option so that the R and YAML spellings of
+/// booleans are equal, e.g. `FALSE`, `F`, and `false`.
+fn normalize_option_value(value: &str) -> String {
+    let value = value.trim().trim_matches(['"', '\'']);
+    match value {
+        "FALSE" | "F" | "false" | "no" => "false".to_string(),
+        "TRUE" | "T" | "true" | "yes" => "true".to_string(),
+        _ => value.to_string(),
+    }
+}
+
+/// Parse the options of a chunk header, e.g. ` label, eval=FALSE` in
+/// `{r label, eval=FALSE}`. The label and the options without value are
+/// skipped.
+fn parse_header_options(header: &str) -> Vec<(String, String)> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut quote = None;
+    let mut start = 0;
+    for (i, c) in header.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(' | '[' | '{') => depth += 1,
+            (None, ')' | ']' | '}') => depth -= 1,
+            (None, ',') if depth == 0 => {
+                parts.push(&header[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&header[start..]);
+
+    parts
+        .into_iter()
+        .filter_map(|part| {
+            let (name, value) = part.split_once('=')?;
+            Some((name.trim().to_string(), value.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Parse the `#| name: value` lines at the top of a chunk. YAML arrays, such
+/// as the items of `#| jarl-ignore-chunk:`, are skipped.
+fn parse_pipe_options(code: &str) -> Vec<(String, String)> {
+    code.lines()
+        .map_while(|line| line.trim_start().strip_prefix("#|"))
+        .filter_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            let name = name.trim();
+            if name.is_empty() || name.starts_with('-') {
+                return None;
+            }
+            Some((name.to_string(), value.trim().to_string()))
+        })
+        .collect()
 }
 
 /// Extract all executable R code chunks from Rmd/Qmd content.
@@ -40,19 +167,16 @@ pub fn extract_r_chunks(content: &str) -> Vec<RCodeChunk> {
     let mut chunks = Vec::new();
     let mut byte_offset: usize = 0;
 
-    // State: None = outside a chunk, Some((fence, code, start_byte)) = inside.
-    let mut current: Option<(String, String, usize)> = None;
+    // State: None = outside a chunk, Some((fence, header, code, start_byte)) = inside.
+    let mut current: Option<(String, String, String, usize)> = None;
 
     for line in content.split_inclusive('\n') {
         let mut finished = false;
 
-        if let Some((fence, code, start_byte)) = current.as_mut() {
+        if let Some((fence, header, code, start_byte)) = current.as_mut() {
             if line.trim() == fence.as_str() {
                 // Closing fence found — emit the chunk.
-                chunks.push(RCodeChunk {
-                    code: std::mem::take(code),
-                    start_byte: *start_byte,
-                });
+                chunks.push(RCodeChunk::new(std::mem::take(code), *start_byte, header));
                 finished = true;
             } else {
                 code.push_str(line);
@@ -60,9 +184,10 @@ pub fn extract_r_chunks(content: &str) -> Vec<RCodeChunk> {
         } else if let Some(caps) = OPEN_FENCE.captures(line) {
             // Opening fence found — start a new chunk.
             let fence = caps.get(1).unwrap().as_str().to_string();
+            let header = caps.get(2).map_or("", |m| m.as_str()).to_string();
             // The chunk code starts immediately after this line.
             let chunk_start_byte = byte_offset + line.len();
-            current = Some((fence, String::new(), chunk_start_byte));
+            current = Some((fence, header, String::new(), chunk_start_byte));
         }
 
         if finished {
@@ -75,6 +200,64 @@ pub fn extract_r_chunks(content: &str) -> Vec<RCodeChunk> {
     chunks
 }
 
+/// Extract all R code chunks from a Sweave (`.Rnw`) document.
+///
+/// A chunk starts with a `<<options>>=` line and ends with a line starting
+/// with `@`, or at the start of the next chunk. Chunks using another engine
+/// than R (e.g. `<<engine="python">>=`) are skipped, as well as the inline
+/// code of `\Sexpr{}`.
+pub fn extract_sweave_chunks(content: &str) -> Vec<RCodeChunk> {
+    let mut chunks = Vec::new();
+    let mut byte_offset: usize = 0;
+
+    // State: None = outside a chunk, Some((is_r, options, code, start_byte)) = inside.
+    let mut current: Option<(bool, String, String, usize)> = None;
+
+    for line in content.split_inclusive('\n') {
+        let opening = SWEAVE_OPEN.captures(line);
+
+        if let Some((is_r, options, code, start_byte)) = current.as_mut() {
+            // A new chunk also closes the current one.
+            if opening.is_some() || SWEAVE_CLOSE.is_match(line.trim_end()) {
+                if *is_r {
+                    chunks.push(RCodeChunk::new(std::mem::take(code), *start_byte, options));
+                }
+                current = None;
+            } else {
+                code.push_str(line);
+            }
+        }
+
+        if let Some(caps) = opening {
+            let options = caps.get(1).map_or("", |m| m.as_str());
+            let is_r = SWEAVE_ENGINE
+                .captures(options)
+                .is_none_or(|engine| engine[1].eq_ignore_ascii_case("r"));
+            // The chunk code starts immediately after this line.
+            current = Some((
+                is_r,
+                options.to_string(),
+                String::new(),
+                byte_offset + line.len(),
+            ));
+        }
+
+        byte_offset += line.len();
+    }
+
+    chunks
+}
+
+/// Extract the R code chunks of a document, using the Sweave syntax for
+/// `.Rnw` files and the Markdown syntax otherwise.
+pub fn extract_document_chunks(content: &str, path: &Path) -> Vec<RCodeChunk> {
+    if crate::fs::has_rnw_extension(path) {
+        extract_sweave_chunks(content)
+    } else {
+        extract_r_chunks(content)
+    }
+}
+
 /// A segment mapping virtual-string byte positions to original-file byte positions.
 #[derive(Debug, Clone)]
 struct Segment {
@@ -538,4 +721,105 @@ mod tests {
             "b <- 2"
         );
     }
+
+    // --- Sweave ---
+
+    #[test]
+    fn test_sweave_extraction() {
+        let header = "\\section{Intro}\n<<setup, echo=FALSE>>=\n";
+        let content = format!("{header}x <- 1\n@\nSome text with \\Sexpr{{x}}.\n");
+        let chunks = extract_sweave_chunks(&content);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].code, "x <- 1\n");
+        assert_eq!(chunks[0].start_byte, header.len());
+    }
+
+    #[test]
+    fn test_sweave_unnamed_chunk_and_comment_after_close() {
+        let content = "<<>>=\na <- 1\n@ % end of chunk\n<<>>=\nb <- 2\n@\n";
+        let chunks = extract_sweave_chunks(content);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].code, "a <- 1\n");
+        assert_eq!(chunks[1].code, "b <- 2\n");
+    }
+
+    #[test]
+    fn test_sweave_chunk_closed_by_next_chunk() {
+        let content = "<<a>>=\na <- 1\n<<b>>=\nb <- 2\n@\n";
+        let chunks = extract_sweave_chunks(content);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].code, "a <- 1\n");
+        assert_eq!(chunks[1].code, "b <- 2\n");
+    }
+
+    #[test]
+    fn test_sweave_other_engine_skipped() {
+        let content = "<<engine='python'>>=\nx = 1\n@\n<<engine=\"R\">>=\ny <- 2\n@\n";
+        let chunks = extract_sweave_chunks(content);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].code, "y <- 2\n");
+    }
+
+    #[test]
+    fn test_sweave_unclosed_chunk_produces_no_output() {
+        let content = "<<>>=\nany(is.na(x))\n";
+        let chunks = extract_sweave_chunks(content);
+        assert_eq!(chunks.len(), 0);
+    }
+
+    #[test]
+    fn test_document_chunks_by_extension() {
+        let content = "```{r}\na <- 1\n```\n<<>>=\nb <- 2\n@\n";
+        let rmd = extract_document_chunks(content, Path::new("doc.Rmd"));
+        assert_eq!(rmd.len(), 1);
+        assert_eq!(rmd[0].code, "a <- 1\n");
+        let rnw = extract_document_chunks(content, Path::new("doc.Rnw"));
+        assert_eq!(rnw.len(), 1);
+        assert_eq!(rnw[0].code, "b <- 2\n");
+    }
+
+    #[test]
+    fn test_chunk_options() {
+        let content = "```{r label, eval=FALSE, fig.cap=\"a, b\"}\nx\n```\n\
+                       ```{r}\n#| eval: false\n#| jarl-ignore-chunk:\n#|   - any_is_na: reason\nx\n```\n";
+        let chunks = extract_r_chunks(content);
+        assert_eq!(
+            chunks[0].options,
+            vec![
+                ("eval".to_string(), "FALSE".to_string()),
+                ("fig.cap".to_string(), "\"a, b\"".to_string()),
+            ]
+        );
+        assert_eq!(
+            chunks[1].options,
+            vec![
+                ("eval".to_string(), "false".to_string()),
+                ("jarl-ignore-chunk".to_string(), String::new()),
+            ]
+        );
+
+        let sweave = extract_sweave_chunks("<<label, purl=F>>=\nx\n@\n");
+        assert_eq!(
+            sweave[0].options,
+            vec![("purl".to_string(), "F".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_chunk_has_option() {
+        let eval = ChunkOption::parse("eval=FALSE").unwrap();
+        let content = "```{r, eval = F}\nx\n```\n\
+                       ```{r}\n#| eval: false\nx\n```\n\
+                       ```{r, eval=FALSE}\n#| eval: true\nx\n```\n\
+                       ```{r, echo=FALSE}\nx\n```\n";
+        let skipped: Vec<bool> = extract_r_chunks(content)
+            .iter()
+            .map(|chunk| chunk.has_option(&eval))
+            .collect();
+        assert_eq!(skipped, vec![true, true, false, false]);
+
+        assert_eq!(ChunkOption::parse("eval: false").unwrap(), eval);
+        assert!(ChunkOption::parse("eval").is_err());
+        assert!(ChunkOption::parse("=FALSE").is_err());
+    }
 }
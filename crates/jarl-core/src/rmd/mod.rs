@@ -1,2 +1,5 @@
 pub mod extraction;
-pub use extraction::{OffsetMap, RCodeChunk, build_virtual_r_source, extract_r_chunks};
+pub use extraction::{
+    ChunkOption, OffsetMap, RCodeChunk, build_virtual_r_source, extract_document_chunks,
+    extract_r_chunks, extract_sweave_chunks,
+};
@@ -1,13 +1,187 @@
-//! Extraction of R code from roxygen `@examples` and `@examplesIf` sections.
+//! Lightweight parsing of roxygen comments.
 //!
 //! Walks the parsed CST to find comment trivia tokens that form roxygen blocks
-//! (lines starting with `#'`), locates `@examples` / `@examplesIf` tags within
-//! those blocks, and extracts the subsequent R code lines with their `#' `
-//! prefix stripped.
+//! (lines starting with `#'`). This is used to:
+//!
+//! - locate `@examples` / `@examplesIf` tags within those blocks and extract
+//!   the subsequent R code lines with their `#' ` prefix stripped;
+//! - split the block documenting an object into its tags (`@param`,
+//!   `@return`, ...);
+//! - find the top-level functions of a file that are documented, without
+//!   parsing it (see [scan_roxygen_names]).
 
 use crate::diagnostic::Fix;
-use air_r_syntax::{RLanguage, RSyntaxNode};
-use biome_rowan::{SyntaxNode, TextSize};
+use air_r_syntax::{RLanguage, RSyntaxNode, RSyntaxToken};
+use biome_rowan::{SyntaxNode, TextRange, TextSize};
+use std::collections::HashSet;
+
+/// A tag of a roxygen block, e.g. `@param x A value.`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoxygenTag {
+    /// Name of the tag, without the `@`, e.g. `"param"`.
+    pub name: String,
+    /// Content of the tag, including its continuation lines, e.g.
+    /// `"x A value."`.
+    pub value: String,
+    /// Range of the comment line containing the tag.
+    pub range: TextRange,
+}
+
+/// The roxygen block documenting an object, i.e. the `#'` lines just before
+/// its definition.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoxygenBlock {
+    pub tags: Vec<RoxygenTag>,
+}
+
+impl RoxygenBlock {
+    /// Parse the roxygen block in the leading trivia of `token`, i.e. the
+    /// first token of the documented expression. Returns `None` if there is
+    /// no roxygen comment just before it.
+    ///
+    /// Roxygen comments separated from the token by a blank line and another
+    /// roxygen block are not part of this block.
+    pub fn from_leading_trivia(token: &RSyntaxToken) -> Option<Self> {
+        let mut tags: Vec<RoxygenTag> = Vec::new();
+        let mut has_lines = false;
+        let mut newlines = 0;
+
+        for piece in token.leading_trivia().pieces() {
+            if piece.is_newline() {
+                newlines += 1;
+                continue;
+            }
+            if !piece.is_comments() {
+                continue;
+            }
+
+            let text = piece.text();
+            if !is_roxygen_comment(text) {
+                // Like roxygen2, skip regular comments inside the block.
+                newlines = 0;
+                continue;
+            }
+
+            // A blank line starts a new block.
+            if newlines > 1 {
+                tags.clear();
+            }
+            newlines = 0;
+            has_lines = true;
+
+            let line = strip_roxygen_prefix(text).trim();
+            if let Some((name, value)) = roxygen_tag(line) {
+                tags.push(RoxygenTag {
+                    name: name.to_string(),
+                    value: value.to_string(),
+                    range: piece.text_range(),
+                });
+            } else if let Some(tag) = tags.last_mut()
+                && !line.is_empty()
+            {
+                if !tag.value.is_empty() {
+                    tag.value.push(' ');
+                }
+                tag.value.push_str(line);
+            }
+        }
+
+        // The block must end right before the token.
+        (has_lines && newlines <= 1).then_some(Self { tags })
+    }
+
+    /// Whether the block contains at least one of the tags in `names`.
+    pub fn has_any_tag(&self, names: &[&str]) -> bool {
+        self.tags
+            .iter()
+            .any(|tag| names.contains(&tag.name.as_str()))
+    }
+
+    /// All the tags named `name`.
+    pub fn tags<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a RoxygenTag> {
+        self.tags.iter().filter(move |tag| tag.name == name)
+    }
+}
+
+/// Split a roxygen line (without its `#'` prefix) into a tag name and its
+/// value, e.g. `"@param x A value"` gives `("param", "x A value")`.
+fn roxygen_tag(line: &str) -> Option<(&str, &str)> {
+    let rest = line.trim_start().strip_prefix('@')?;
+    let end = rest
+        .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .unwrap_or(rest.len());
+    if end == 0 {
+        return None;
+    }
+    Some((&rest[..end], rest[end..].trim()))
+}
+
+/// Names documented by the roxygen blocks of a file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RoxygenNames {
+    /// Top-level objects preceded by a roxygen block that doesn't use
+    /// `@noRd`.
+    pub documented: HashSet<String>,
+    /// Names of the `@name` and `@aliases` tags, which document objects
+    /// defined elsewhere.
+    pub aliases: HashSet<String>,
+}
+
+/// Find the documented objects of a file by scanning its lines.
+///
+/// `definitions` are the names of the top-level objects of the file with the
+/// line (starting at 1) where they are defined, as returned by
+/// `scan_top_level_assignments()`. Like roxygen2, blank lines and regular
+/// comments between a block and the object it documents are allowed.
+pub fn scan_roxygen_names<'a>(
+    content: &str,
+    definitions: impl Iterator<Item = (&'a str, u32)>,
+) -> RoxygenNames {
+    let lines: Vec<&str> = content.lines().map(str::trim_start).collect();
+    let mut names = RoxygenNames::default();
+
+    for line in lines.iter().filter(|line| is_roxygen_comment(line)) {
+        match roxygen_tag(strip_roxygen_prefix(line)) {
+            Some(("name", value)) => names
+                .aliases
+                .extend(value.split_whitespace().take(1).map(unquote_name)),
+            Some(("aliases", value)) => names
+                .aliases
+                .extend(value.split_whitespace().map(unquote_name)),
+            _ => {}
+        }
+    }
+
+    for (name, line) in definitions {
+        let before = lines
+            .get(..(line as usize).saturating_sub(1))
+            .unwrap_or_default();
+        let mut block = before
+            .iter()
+            .rev()
+            .skip_while(|line| {
+                line.is_empty() || (line.starts_with('#') && !is_roxygen_comment(line))
+            })
+            .take_while(|line| is_roxygen_comment(line))
+            .peekable();
+        if block.peek().is_none() {
+            continue;
+        }
+        let no_rd =
+            block.any(|line| matches!(roxygen_tag(strip_roxygen_prefix(line)), Some(("noRd", _))));
+        if !no_rd {
+            names.documented.insert(name.to_string());
+        }
+    }
+
+    names
+}
+
+/// Remove the backticks or quotes around a name, e.g. `` `%+%` ``.
+fn unquote_name(name: &str) -> String {
+    name.trim_matches(|c| matches!(c, '`' | '"' | '\''))
+        .to_string()
+}
 
 /// An R code chunk extracted from a roxygen `@examples` or `@examplesIf` section.
 #[derive(Debug)]
@@ -61,9 +235,9 @@ pub fn extract_roxygen_examples(syntax: &RSyntaxNode, contents: &str) -> Vec<Rox
             if is_roxygen_comment(text) {
                 in_block = true;
                 let stripped = strip_roxygen_prefix(text);
-                let trimmed = stripped.trim_start();
+                let tag = roxygen_tag(stripped).map(|(name, _)| name);
 
-                if trimmed.starts_with("@examples") || trimmed.starts_with("@examplesIf") {
+                if matches!(tag, Some("examples" | "examplesIf")) {
                     // Flush any previous examples section in this block
                     flush_chunk(
                         &mut chunks,
@@ -72,7 +246,7 @@ pub fn extract_roxygen_examples(syntax: &RSyntaxNode, contents: &str) -> Vec<Rox
                         &mut line_prefix_lengths,
                     );
                     in_examples = true;
-                } else if trimmed.starts_with('@') {
+                } else if tag.is_some() {
                     // A different @tag ends the examples section
                     flush_chunk(
                         &mut chunks,
@@ -308,6 +482,7 @@ fn remap_byte_offset(offset: usize, chunk: &RoxygenExamplesChunk) -> usize {
 mod tests {
     use super::*;
     use air_r_parser::RParserOptions;
+    use biome_rowan::AstNode;
 
     fn parse_and_extract(source: &str) -> Vec<RoxygenExamplesChunk> {
         let parsed = air_r_parser::parse(source, RParserOptions::default());
@@ -495,4 +670,83 @@ foo <- function(x) x
         assert_eq!(chunks.len(), 1);
         assert_eq!(chunks[0].code, "x <- 1");
     }
+
+    fn parse_block(source: &str) -> Option<RoxygenBlock> {
+        let parsed = air_r_parser::parse(source, RParserOptions::default());
+        let expr = parsed.tree().expressions().into_iter().last()?;
+        RoxygenBlock::from_leading_trivia(&expr.syntax().first_token()?)
+    }
+
+    #[test]
+    fn test_roxygen_block_tags() {
+        let source = r#"#' Title
+#'
+#' @param x,y Two values
+#'   on two lines.
+#' @returns NULL
+#' @export
+foo <- function(x, y) NULL
+"#;
+        let block = parse_block(source).unwrap();
+        let tags: Vec<(&str, &str)> = block
+            .tags
+            .iter()
+            .map(|tag| (tag.name.as_str(), tag.value.as_str()))
+            .collect();
+        assert_eq!(
+            tags,
+            [
+                ("param", "x,y Two values on two lines."),
+                ("returns", "NULL"),
+                ("export", "")
+            ]
+        );
+        assert!(block.has_any_tag(&["return", "returns"]));
+        assert_eq!(block.tags("param").count(), 1);
+    }
+
+    #[test]
+    fn test_roxygen_block_must_precede_expression() {
+        assert!(parse_block("foo <- function(x) x\n").is_none());
+        assert!(parse_block("#' @export\n\nfoo <- function(x) x\n").is_none());
+        assert!(parse_block("# A comment\nfoo <- function(x) x\n").is_none());
+    }
+
+    #[test]
+    fn test_roxygen_block_only_keeps_last_block() {
+        let source = "#' @param a A\n\n#' @param b B\nfoo <- function(b) b\n";
+        let block = parse_block(source).unwrap();
+        assert_eq!(block.tags.len(), 1);
+        assert_eq!(block.tags[0].value, "b B");
+    }
+
+    #[test]
+    fn test_scan_roxygen_names() {
+        let source = "\
+#' Title
+#' @aliases `%+%` plus
+#' @export
+f <- function() 1
+
+#' @export
+
+# Not roxygen
+g <- function() 1
+
+h <- function() 1
+
+#' @noRd
+i <- function() 1
+";
+        let definitions = [("f", 4), ("g", 9), ("h", 11), ("i", 14)].into_iter();
+        let names = scan_roxygen_names(source, definitions);
+        assert_eq!(
+            names.documented,
+            HashSet::from(["f".to_string(), "g".to_string()])
+        );
+        assert_eq!(
+            names.aliases,
+            HashSet::from(["%+%".to_string(), "plus".to_string()])
+        );
+    }
 }
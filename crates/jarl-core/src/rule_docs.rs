@@ -4,3 +4,190 @@
 //! in `docs/rules/`. See that build script for details.
 
 include!(concat!(env!("OUT_DIR"), "/rule_docs.rs"));
+
+/// The documentation of a rule, split into the sections of its markdown file
+/// (`## What it does`, `## Why is this bad?`, `## Example`, `## Options`, ...).
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct RuleDoc {
+    pub name: String,
+    /// Version of Jarl in which the rule was added, e.g. `"0.0.8"`.
+    pub added_in: Option<String>,
+    pub sections: Vec<RuleDocSection>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct RuleDocSection {
+    pub title: String,
+    pub body: String,
+}
+
+impl RuleDoc {
+    /// Returns the structured documentation of a rule, if available.
+    pub fn get(name: &str) -> Option<Self> {
+        rule_doc(name).map(|markdown| Self::from_markdown(name, markdown))
+    }
+
+    /// Parse the markdown produced by `cargo run -p xtask_codegen -- docgen`.
+    pub fn from_markdown(name: &str, markdown: &str) -> Self {
+        let mut added_in = None;
+        let mut sections: Vec<RuleDocSection> = Vec::new();
+        let mut in_code_block = false;
+
+        for line in markdown.lines() {
+            if line.starts_with("```") {
+                in_code_block = !in_code_block;
+            }
+
+            if !in_code_block {
+                // The title is the name of the rule.
+                if line.starts_with("# ") {
+                    continue;
+                }
+                if line.starts_with(":::") {
+                    if let Some(start) = line.find("title=\"Added in ") {
+                        let rest = &line[start + "title=\"Added in ".len()..];
+                        added_in = rest.split('"').next().map(str::to_string);
+                    }
+                    continue;
+                }
+                if let Some(title) = line.strip_prefix("## ") {
+                    sections.push(RuleDocSection { title: title.to_string(), body: String::new() });
+                    continue;
+                }
+            }
+
+            if let Some(section) = sections.last_mut() {
+                section.body.push_str(line);
+                section.body.push('\n');
+            }
+        }
+
+        for section in &mut sections {
+            section.body = section.body.trim_matches('\n').to_string();
+        }
+
+        Self { name: name.to_string(), added_in, sections }
+    }
+
+    /// The body of the section with this title. The trailing `?` of titles
+    /// such as "Why is this bad?" is optional.
+    pub fn section(&self, title: &str) -> Option<&str> {
+        let title = title.trim_end_matches('?');
+        self.sections
+            .iter()
+            .find(|section| section.title.trim_end_matches('?') == title)
+            .map(|section| section.body.as_str())
+    }
+
+    /// The first paragraph of the "What it does" section, on a single line.
+    pub fn summary(&self) -> Option<String> {
+        let what_it_does = self.section("What it does")?;
+        let paragraph = what_it_does.split("\n\n").next()?;
+        Some(paragraph.lines().collect::<Vec<_>>().join(" "))
+    }
+
+    /// Render the documentation as markdown for the terminal, without the
+    /// title (the name of the rule) and the Quarto callout.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        if let Some(added_in) = &self.added_in {
+            out.push_str(&format!("Added in {added_in}\n"));
+        }
+        for section in &self.sections {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(&format!("## {}\n\n{}\n", section.title, section.body));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MARKDOWN: &str = r#"# any_is_na
+::: {.callout-note title="Added in 0.0.8" .low-opacity}
+:::
+
+## What it does
+
+Checks for usage of `any(is.na(...))`,
+`NA %in% x`, and `NA %notin% x`.
+
+Second paragraph.
+
+## Why is this bad?
+
+`anyNA()` is more efficient.
+
+## Example
+
+```r
+# A comment, not a title
+any(is.na(x))
+```
+"#;
+
+    #[test]
+    fn test_parse_rule_doc() {
+        let doc = RuleDoc::from_markdown("any_is_na", MARKDOWN);
+        assert_eq!(doc.name, "any_is_na");
+        assert_eq!(doc.added_in.as_deref(), Some("0.0.8"));
+        let titles: Vec<&str> = doc.sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, ["What it does", "Why is this bad?", "Example"]);
+        assert_eq!(
+            doc.section("Why is this bad"),
+            Some("`anyNA()` is more efficient.")
+        );
+        assert_eq!(
+            doc.section("Example"),
+            Some("```r\n# A comment, not a title\nany(is.na(x))\n```")
+        );
+        assert_eq!(doc.section("Options"), None);
+        assert_eq!(
+            doc.summary().as_deref(),
+            Some("Checks for usage of `any(is.na(...))`, `NA %in% x`, and `NA %notin% x`.")
+        );
+    }
+
+    #[test]
+    fn test_render_rule_doc() {
+        let doc = RuleDoc::from_markdown("any_is_na", MARKDOWN);
+        assert_eq!(
+            doc.to_markdown(),
+            "Added in 0.0.8
+
+## What it does
+
+Checks for usage of `any(is.na(...))`,
+`NA %in% x`, and `NA %notin% x`.
+
+Second paragraph.
+
+## Why is this bad?
+
+`anyNA()` is more efficient.
+
+## Example
+
+```r
+# A comment, not a title
+any(is.na(x))
+```
+"
+        );
+    }
+
+    #[test]
+    fn test_embedded_rule_docs_have_summary() {
+        for rule in crate::rule_set::Rule::all().iter() {
+            let Some(doc) = RuleDoc::get(rule.name()) else {
+                continue;
+            };
+            assert!(doc.added_in.is_some(), "{}", rule.name());
+            assert!(doc.summary().is_some(), "{}", rule.name());
+        }
+    }
+}
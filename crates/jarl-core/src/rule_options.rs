@@ -1,21 +1,48 @@
 use std::collections::HashSet;
 
+use crate::fixture::FixtureFunctions;
 use crate::lints::base::assignment::options::AssignmentOptions;
 use crate::lints::base::assignment::options::ResolvedAssignmentOptions;
 use crate::lints::base::duplicated_arguments::options::DuplicatedArgumentsOptions;
 use crate::lints::base::duplicated_arguments::options::ResolvedDuplicatedArgumentsOptions;
+use crate::lints::base::embedded_data::options::EmbeddedDataOptions;
+use crate::lints::base::embedded_data::options::ResolvedEmbeddedDataOptions;
+use crate::lints::base::file_list_order::options::FileListOrderOptions;
+use crate::lints::base::file_list_order::options::ResolvedFileListOrderOptions;
 use crate::lints::base::if_not_else::options::IfNotElseOptions;
 use crate::lints::base::if_not_else::options::ResolvedIfNotElseOptions;
 use crate::lints::base::implicit_assignment::options::ImplicitAssignmentOptions;
 use crate::lints::base::implicit_assignment::options::ResolvedImplicitAssignmentOptions;
+use crate::lints::base::internal_function::options::InternalFunctionOptions;
+use crate::lints::base::internal_function::options::ResolvedInternalFunctionOptions;
+use crate::lints::base::length_zero::options::LengthZeroOptions;
+use crate::lints::base::length_zero::options::ResolvedLengthZeroOptions;
+use crate::lints::base::list_splice::options::ListSpliceOptions;
+use crate::lints::base::list_splice::options::ResolvedListSpliceOptions;
+use crate::lints::base::magic_numbers::options::MagicNumbersOptions;
+use crate::lints::base::magic_numbers::options::ResolvedMagicNumbersOptions;
 use crate::lints::base::missing_argument::options::MissingArgumentOptions;
 use crate::lints::base::missing_argument::options::ResolvedMissingArgumentOptions;
+use crate::lints::base::namespace_modification::options::NamespaceModificationOptions;
+use crate::lints::base::namespace_modification::options::ResolvedNamespaceModificationOptions;
 use crate::lints::base::nested_pipe::options::NestedPipeOptions;
 use crate::lints::base::nested_pipe::options::ResolvedNestedPipeOptions;
 use crate::lints::base::pipe_consistency::options::PipeConsistencyOptions;
 use crate::lints::base::pipe_consistency::options::ResolvedPipeConsistencyOptions;
 use crate::lints::base::quotes::options::QuotesOptions;
 use crate::lints::base::quotes::options::ResolvedQuotesOptions;
+use crate::lints::base::rlang_abort_call::options::ResolvedRlangAbortCallOptions;
+use crate::lints::base::rlang_abort_call::options::RlangAbortCallOptions;
+use crate::lints::base::scalar_logical_check::options::ResolvedScalarLogicalCheckOptions;
+use crate::lints::base::scalar_logical_check::options::ScalarLogicalCheckOptions;
+use crate::lints::base::shell_injection::options::ResolvedShellInjectionOptions;
+use crate::lints::base::shell_injection::options::ShellInjectionOptions;
+use crate::lints::base::split_apply_combine::options::ResolvedSplitApplyCombineOptions;
+use crate::lints::base::split_apply_combine::options::SplitApplyCombineOptions;
+use crate::lints::base::stopifnot_all::options::ResolvedStopifnotAllOptions;
+use crate::lints::base::stopifnot_all::options::StopifnotAllOptions;
+use crate::lints::base::tibble_rownames::options::ResolvedTibbleRownamesOptions;
+use crate::lints::base::tibble_rownames::options::TibbleRownamesOptions;
 use crate::lints::base::true_false_symbol::options::ResolvedTrueFalseSymbolOptions;
 use crate::lints::base::true_false_symbol::options::TrueFalseSymbolOptions;
 use crate::lints::base::undesirable_function::options::ResolvedUndesirableFunctionOptions;
@@ -24,6 +51,8 @@ use crate::lints::base::unreachable_code::options::ResolvedUnreachableCodeOption
 use crate::lints::base::unreachable_code::options::UnreachableCodeOptions;
 use crate::lints::base::unused_function::options::ResolvedUnusedFunctionOptions;
 use crate::lints::base::unused_function::options::UnusedFunctionOptions;
+use crate::lints::base::vapply_over_sapply::options::ResolvedVapplyOverSapplyOptions;
+use crate::lints::base::vapply_over_sapply::options::VapplyOverSapplyOptions;
 
 /// Resolve a pair of `field` / `extend-field` options against a set of defaults.
 ///
@@ -69,16 +98,62 @@ pub fn resolve_with_extend(
 pub struct RuleOptions<'a> {
     pub assignment: Option<&'a AssignmentOptions>,
     pub duplicated_arguments: Option<&'a DuplicatedArgumentsOptions>,
+    pub embedded_data: Option<&'a EmbeddedDataOptions>,
+    pub file_list_order: Option<&'a FileListOrderOptions>,
     pub if_not_else: Option<&'a IfNotElseOptions>,
     pub implicit_assignment: Option<&'a ImplicitAssignmentOptions>,
+    pub internal_function: Option<&'a InternalFunctionOptions>,
+    pub length_zero: Option<&'a LengthZeroOptions>,
+    pub list_splice: Option<&'a ListSpliceOptions>,
+    pub magic_numbers: Option<&'a MagicNumbersOptions>,
     pub missing_argument: Option<&'a MissingArgumentOptions>,
+    pub namespace_modification: Option<&'a NamespaceModificationOptions>,
     pub nested_pipe: Option<&'a NestedPipeOptions>,
     pub pipe_consistency: Option<&'a PipeConsistencyOptions>,
     pub quotes: Option<&'a QuotesOptions>,
+    pub rlang_abort_call: Option<&'a RlangAbortCallOptions>,
+    pub scalar_logical_check: Option<&'a ScalarLogicalCheckOptions>,
+    pub shell_injection: Option<&'a ShellInjectionOptions>,
+    pub split_apply_combine: Option<&'a SplitApplyCombineOptions>,
+    pub stopifnot_all: Option<&'a StopifnotAllOptions>,
+    pub tibble_rownames: Option<&'a TibbleRownamesOptions>,
     pub true_false_symbol: Option<&'a TrueFalseSymbolOptions>,
     pub undesirable_function: Option<&'a UndesirableFunctionOptions>,
     pub unreachable_code: Option<&'a UnreachableCodeOptions>,
     pub unused_function: Option<&'a UnusedFunctionOptions>,
+    pub vapply_over_sapply: Option<&'a VapplyOverSapplyOptions>,
+}
+
+impl RuleOptions<'_> {
+    /// Whether the options of at least one rule are set.
+    pub fn is_set(&self) -> bool {
+        self.assignment.is_some()
+            || self.duplicated_arguments.is_some()
+            || self.embedded_data.is_some()
+            || self.file_list_order.is_some()
+            || self.if_not_else.is_some()
+            || self.implicit_assignment.is_some()
+            || self.internal_function.is_some()
+            || self.length_zero.is_some()
+            || self.list_splice.is_some()
+            || self.magic_numbers.is_some()
+            || self.missing_argument.is_some()
+            || self.namespace_modification.is_some()
+            || self.nested_pipe.is_some()
+            || self.pipe_consistency.is_some()
+            || self.quotes.is_some()
+            || self.rlang_abort_call.is_some()
+            || self.scalar_logical_check.is_some()
+            || self.shell_injection.is_some()
+            || self.split_apply_combine.is_some()
+            || self.stopifnot_all.is_some()
+            || self.tibble_rownames.is_some()
+            || self.true_false_symbol.is_some()
+            || self.undesirable_function.is_some()
+            || self.unreachable_code.is_some()
+            || self.unused_function.is_some()
+            || self.vapply_over_sapply.is_some()
+    }
 }
 
 /// Resolved per-rule options, ready for use during linting.
@@ -86,24 +161,41 @@ pub struct RuleOptions<'a> {
 /// To add options for a new rule:
 /// 1. Create `lints/<group>/<rule_name>/options.rs` with the TOML and resolved
 ///    types, and declare `pub(crate) mod options;` in the rule's `mod.rs`.
-/// 2. Add a field to `RuleOptions` and `ResolvedRuleOptions`, and a resolve
-///    line in `resolve()`.
+/// 2. Add a field to `RuleOptions` and `ResolvedRuleOptions`, a resolve line
+///    in `resolve()` and `apply()`, and a check in `RuleOptions::is_set()`.
 /// 3. Add the TOML field to `LinterTomlOptions` in `toml.rs` and set it on the
-///    `RuleOptions` built in `into_settings()`.
+///    `RuleOptions` built in `LinterTomlOptions::rule_options()`.
 #[derive(Clone, Debug)]
 pub struct ResolvedRuleOptions {
     pub assignment: ResolvedAssignmentOptions,
     pub duplicated_arguments: ResolvedDuplicatedArgumentsOptions,
+    pub embedded_data: ResolvedEmbeddedDataOptions,
+    pub file_list_order: ResolvedFileListOrderOptions,
     pub if_not_else: ResolvedIfNotElseOptions,
     pub implicit_assignment: ResolvedImplicitAssignmentOptions,
+    pub internal_function: ResolvedInternalFunctionOptions,
+    pub length_zero: ResolvedLengthZeroOptions,
+    pub list_splice: ResolvedListSpliceOptions,
+    pub magic_numbers: ResolvedMagicNumbersOptions,
     pub missing_argument: ResolvedMissingArgumentOptions,
+    pub namespace_modification: ResolvedNamespaceModificationOptions,
     pub nested_pipe: ResolvedNestedPipeOptions,
     pub pipe_consistency: ResolvedPipeConsistencyOptions,
     pub quotes: ResolvedQuotesOptions,
+    pub rlang_abort_call: ResolvedRlangAbortCallOptions,
+    pub scalar_logical_check: ResolvedScalarLogicalCheckOptions,
+    pub shell_injection: ResolvedShellInjectionOptions,
+    pub split_apply_combine: ResolvedSplitApplyCombineOptions,
+    pub stopifnot_all: ResolvedStopifnotAllOptions,
+    pub tibble_rownames: ResolvedTibbleRownamesOptions,
     pub true_false_symbol: ResolvedTrueFalseSymbolOptions,
     pub undesirable_function: ResolvedUndesirableFunctionOptions,
     pub unreachable_code: ResolvedUnreachableCodeOptions,
     pub unused_function: ResolvedUnusedFunctionOptions,
+    pub vapply_over_sapply: ResolvedVapplyOverSapplyOptions,
+    /// Fixture functions from `fixture-functions` in `[lint]`. They are shared
+    /// by all rules and can't be changed per rule or per path.
+    pub fixture_functions: FixtureFunctions,
 }
 
 impl ResolvedRuleOptions {
@@ -113,22 +205,131 @@ impl ResolvedRuleOptions {
             duplicated_arguments: ResolvedDuplicatedArgumentsOptions::resolve(
                 options.duplicated_arguments,
             )?,
+            embedded_data: ResolvedEmbeddedDataOptions::resolve(options.embedded_data)?,
+            file_list_order: ResolvedFileListOrderOptions::resolve(options.file_list_order)?,
             if_not_else: ResolvedIfNotElseOptions::resolve(options.if_not_else)?,
             implicit_assignment: ResolvedImplicitAssignmentOptions::resolve(
                 options.implicit_assignment,
             )?,
+            internal_function: ResolvedInternalFunctionOptions::resolve(options.internal_function)?,
+            length_zero: ResolvedLengthZeroOptions::resolve(options.length_zero)?,
+            list_splice: ResolvedListSpliceOptions::resolve(options.list_splice)?,
+            magic_numbers: ResolvedMagicNumbersOptions::resolve(options.magic_numbers)?,
             missing_argument: ResolvedMissingArgumentOptions::resolve(options.missing_argument)?,
+            namespace_modification: ResolvedNamespaceModificationOptions::resolve(
+                options.namespace_modification,
+            )?,
             nested_pipe: ResolvedNestedPipeOptions::resolve(options.nested_pipe)?,
             pipe_consistency: ResolvedPipeConsistencyOptions::resolve(options.pipe_consistency)?,
             quotes: ResolvedQuotesOptions::resolve(options.quotes)?,
+            rlang_abort_call: ResolvedRlangAbortCallOptions::resolve(options.rlang_abort_call)?,
+            scalar_logical_check: ResolvedScalarLogicalCheckOptions::resolve(
+                options.scalar_logical_check,
+            )?,
+            shell_injection: ResolvedShellInjectionOptions::resolve(options.shell_injection)?,
+            split_apply_combine: ResolvedSplitApplyCombineOptions::resolve(
+                options.split_apply_combine,
+            )?,
+            stopifnot_all: ResolvedStopifnotAllOptions::resolve(options.stopifnot_all)?,
+            tibble_rownames: ResolvedTibbleRownamesOptions::resolve(options.tibble_rownames)?,
             true_false_symbol: ResolvedTrueFalseSymbolOptions::resolve(options.true_false_symbol)?,
             undesirable_function: ResolvedUndesirableFunctionOptions::resolve(
                 options.undesirable_function,
             )?,
             unreachable_code: ResolvedUnreachableCodeOptions::resolve(options.unreachable_code)?,
             unused_function: ResolvedUnusedFunctionOptions::resolve(options.unused_function)?,
+            vapply_over_sapply: ResolvedVapplyOverSapplyOptions::resolve(
+                options.vapply_over_sapply,
+            )?,
+            fixture_functions: FixtureFunctions::default(),
         })
     }
+
+    /// Re-resolve the options of the rules that are set in `options` and keep
+    /// the other ones unchanged. This is used for `[[lint.overrides]]`, where
+    /// a `[lint.<rule>]` table replaces the one set in `[lint]`.
+    pub fn apply(&mut self, options: &RuleOptions) -> anyhow::Result<()> {
+        if let Some(options) = options.assignment {
+            self.assignment = ResolvedAssignmentOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.duplicated_arguments {
+            self.duplicated_arguments = ResolvedDuplicatedArgumentsOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.embedded_data {
+            self.embedded_data = ResolvedEmbeddedDataOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.file_list_order {
+            self.file_list_order = ResolvedFileListOrderOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.if_not_else {
+            self.if_not_else = ResolvedIfNotElseOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.implicit_assignment {
+            self.implicit_assignment = ResolvedImplicitAssignmentOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.internal_function {
+            self.internal_function = ResolvedInternalFunctionOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.length_zero {
+            self.length_zero = ResolvedLengthZeroOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.list_splice {
+            self.list_splice = ResolvedListSpliceOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.magic_numbers {
+            self.magic_numbers = ResolvedMagicNumbersOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.missing_argument {
+            self.missing_argument = ResolvedMissingArgumentOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.namespace_modification {
+            self.namespace_modification =
+                ResolvedNamespaceModificationOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.nested_pipe {
+            self.nested_pipe = ResolvedNestedPipeOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.pipe_consistency {
+            self.pipe_consistency = ResolvedPipeConsistencyOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.quotes {
+            self.quotes = ResolvedQuotesOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.rlang_abort_call {
+            self.rlang_abort_call = ResolvedRlangAbortCallOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.scalar_logical_check {
+            self.scalar_logical_check = ResolvedScalarLogicalCheckOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.shell_injection {
+            self.shell_injection = ResolvedShellInjectionOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.split_apply_combine {
+            self.split_apply_combine = ResolvedSplitApplyCombineOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.stopifnot_all {
+            self.stopifnot_all = ResolvedStopifnotAllOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.tibble_rownames {
+            self.tibble_rownames = ResolvedTibbleRownamesOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.true_false_symbol {
+            self.true_false_symbol = ResolvedTrueFalseSymbolOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.undesirable_function {
+            self.undesirable_function = ResolvedUndesirableFunctionOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.unreachable_code {
+            self.unreachable_code = ResolvedUnreachableCodeOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.unused_function {
+            self.unused_function = ResolvedUnusedFunctionOptions::resolve(Some(options))?;
+        }
+        if let Some(options) = options.vapply_over_sapply {
+            self.vapply_over_sapply = ResolvedVapplyOverSapplyOptions::resolve(Some(options))?;
+        }
+        Ok(())
+    }
 }
 
 impl Default for ResolvedRuleOptions {
@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+
+use crate::rule_set::Rule;
+
+/// TOML `include`/`exclude` fields accepted in any `[lint.<rule>]` table.
+///
+/// These are extracted from the rule tables before the rest of `jarl.toml` is
+/// deserialized (see `crate::toml`), so that rules without any other option
+/// can also be restricted to some paths.
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct RulePathOptions {
+    pub include: Option<Vec<String>>,
+    pub exclude: Option<Vec<String>>,
+}
+
+/// Compiled `include`/`exclude` matchers for a single rule.
+#[derive(Clone, Debug)]
+struct RulePathFilter {
+    rule: Rule,
+    /// `None` means the rule is not restricted to some files.
+    include: Option<Gitignore>,
+    exclude: Option<Gitignore>,
+}
+
+impl RulePathFilter {
+    /// Whether the rule should run on `relative`, i.e. the file matches at
+    /// least one `include` pattern (if any) and no `exclude` pattern.
+    fn applies_to(&self, relative: &Path) -> bool {
+        let included = self
+            .include
+            .as_ref()
+            .is_none_or(|matcher| matcher.matched(relative, false).is_ignore());
+        let excluded = self
+            .exclude
+            .as_ref()
+            .is_some_and(|matcher| matcher.matched(relative, false).is_ignore());
+        included && !excluded
+    }
+}
+
+/// Resolved per-rule `include`/`exclude` configuration. This is finer-grained
+/// than the global `include`/`exclude` options: files are still checked, but
+/// only by the rules whose patterns they match.
+#[derive(Clone, Debug, Default)]
+pub struct RulePaths {
+    /// Directory the patterns are resolved against (the `jarl.toml` directory).
+    root: PathBuf,
+    filters: Vec<RulePathFilter>,
+}
+
+impl RulePaths {
+    /// Build a [RulePaths] from `(rule, options)` pairs.
+    ///
+    /// Patterns follow the format of the global `include`/`exclude` options
+    /// (gitignore-style, relative to `root`). An empty `include` list means
+    /// that the rule isn't restricted to some files.
+    pub fn new(root: &Path, entries: Vec<(Rule, RulePathOptions)>) -> anyhow::Result<Self> {
+        let mut filters = Vec::with_capacity(entries.len());
+
+        for (rule, options) in entries {
+            let include = match options.include {
+                Some(patterns) if !patterns.is_empty() => {
+                    Some(build_matcher(root, &patterns, rule, "include")?)
+                }
+                _ => None,
+            };
+            let exclude = match options.exclude {
+                Some(patterns) if !patterns.is_empty() => {
+                    Some(build_matcher(root, &patterns, rule, "exclude")?)
+                }
+                _ => None,
+            };
+
+            if include.is_none() && exclude.is_none() {
+                continue;
+            }
+            filters.push(RulePathFilter { rule, include, exclude });
+        }
+
+        Ok(Self { root: root.to_path_buf(), filters })
+    }
+
+    /// Whether any rule has `include` or `exclude` patterns.
+    pub fn is_empty(&self) -> bool {
+        self.filters.is_empty()
+    }
+
+    /// Return the set of rules that must not run on `path` because of their
+    /// `include`/`exclude` patterns.
+    ///
+    /// `path` should be the file's absolute (normalized) path so that it can be
+    /// made relative to the configuration root before matching.
+    pub fn skipped_rules(&self, path: &Path) -> HashSet<Rule> {
+        let relative = path.strip_prefix(&self.root).unwrap_or(path);
+
+        self.filters
+            .iter()
+            .filter(|filter| !filter.applies_to(relative))
+            .map(|filter| filter.rule)
+            .collect()
+    }
+}
+
+fn build_matcher(
+    root: &Path,
+    patterns: &[String],
+    rule: Rule,
+    field: &str,
+) -> anyhow::Result<Gitignore> {
+    let invalid = |pattern: &str, e: ignore::Error| {
+        anyhow::anyhow!(
+            "Invalid `{field}` pattern '{pattern}' in `[lint.{}]`: {e}",
+            rule.name()
+        )
+    };
+
+    let mut builder = GitignoreBuilder::new(root);
+    for pattern in patterns {
+        // Same directory handling as `per-file-ignores`: a trailing slash
+        // targets a directory's contents.
+        let glob = if pattern.ends_with('/') {
+            format!("{pattern}**")
+        } else {
+            pattern.clone()
+        };
+        builder
+            .add_line(None, &glob)
+            .map_err(|e| invalid(pattern, e))?;
+    }
+    builder
+        .build()
+        .map_err(|e| invalid(&patterns.join(", "), e))
+}
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
 
@@ -14,6 +15,9 @@ pub enum Category {
     Perf,
     /// Readability: code is correct but can be written more clearly
     Read,
+    /// Portability: code whose result depends on the locale or timezone of the
+    /// machine
+    Port,
     /// Testthat-specific rules
     Testthat,
     /// dplyr-specific rules (opt-in)
@@ -28,6 +32,7 @@ impl Category {
             Self::Susp => "SUSP",
             Self::Perf => "PERF",
             Self::Read => "READ",
+            Self::Port => "PORT",
             Self::Testthat => "TESTTHAT",
             Self::Dplyr => "DPLYR",
         }
@@ -39,6 +44,7 @@ impl Category {
         Category::Susp,
         Category::Perf,
         Category::Read,
+        Category::Port,
         Category::Testthat,
         Category::Dplyr,
     ];
@@ -54,6 +60,7 @@ impl Category {
             && !matches!(self, Self::Corr)
             && !matches!(self, Self::Perf)
             && !matches!(self, Self::Read)
+            && !matches!(self, Self::Port)
             && !matches!(self, Self::Susp)
             && !matches!(self, Self::Testthat)
     }
@@ -75,6 +82,7 @@ impl FromStr for Category {
             "SUSP" => Ok(Self::Susp),
             "PERF" => Ok(Self::Perf),
             "READ" => Ok(Self::Read),
+            "PORT" => Ok(Self::Port),
             "TESTTHAT" => Ok(Self::Testthat),
             "DPLYR" => Ok(Self::Dplyr),
             _ => Err(format!("Unknown category: {}", s)),
@@ -87,6 +95,10 @@ pub enum DefaultStatus {
     #[default]
     Enabled,
     Disabled,
+    /// New rule that is only available in preview mode (`--preview` or
+    /// `preview = true` in `[lint]`), where it is enabled by default. It can
+    /// then be promoted to `Enabled` once it has received enough feedback.
+    Preview,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -104,6 +116,85 @@ pub struct DeprecationInfo {
     pub replacement: &'static str,
 }
 
+/// Curated set of rules, selected with `profile` in `jarl.toml` or
+/// `--profile` in the CLI.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum Profile {
+    /// Rules enabled by default that report code that is wrong or most
+    /// likely wrong: the `COMM`, `CORR`, and `SUSP` groups.
+    Minimal,
+    /// Rules enabled by default.
+    Recommended,
+    /// All rules that are not deprecated, except the package-specific ones
+    /// (e.g. `DPLYR`).
+    Strict,
+}
+
+impl Profile {
+    pub const ALL: [Profile; 3] = [Profile::Minimal, Profile::Recommended, Profile::Strict];
+
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Minimal => "minimal",
+            Self::Recommended => "recommended",
+            Self::Strict => "strict",
+        }
+    }
+
+    /// Check if the profile includes `rule`. In preview mode, this includes
+    /// the preview rules of the profile.
+    pub fn includes(self, rule: Rule, preview: bool) -> bool {
+        let is_default = rule.is_enabled_by_default() || (preview && rule.is_preview());
+        match self {
+            Self::Minimal => {
+                is_default
+                    && !rule.is_deprecated()
+                    && [Category::Comm, Category::Corr, Category::Susp]
+                        .iter()
+                        .any(|category| rule.has_category(*category))
+            }
+            Self::Recommended => is_default,
+            Self::Strict => {
+                (is_default || rule.is_disabled_by_default())
+                    && !rule.is_deprecated()
+                    && !rule.categories().iter().any(|c| c.is_package_specific())
+            }
+        }
+    }
+
+    /// Names of the rules of the profile.
+    pub fn rules(self, preview: bool) -> Vec<String> {
+        Rule::all()
+            .iter()
+            .filter(|rule| self.includes(**rule, preview))
+            .map(|rule| rule.name().to_string())
+            .collect()
+    }
+}
+
+impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Profile {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "minimal" => Ok(Self::Minimal),
+            "recommended" => Ok(Self::Recommended),
+            "strict" => Ok(Self::Strict),
+            _ => Err(format!(
+                "Invalid profile `{s}`. Expected one of: `minimal`, `recommended`, `strict`."
+            )),
+        }
+    }
+}
+
 macro_rules! declare_rules {
     // Internal helper: expand deprecation info when present
     (@deprecation $ver:literal, $repl:literal) => {
@@ -205,6 +296,11 @@ macro_rules! declare_rules {
                 matches!(self.default_status(), DefaultStatus::Disabled)
             }
 
+            /// Check if the rule is only available in preview mode
+            pub const fn is_preview(self) -> bool {
+                matches!(self.default_status(), DefaultStatus::Preview)
+            }
+
             /// Check if the rule belongs to a specific category
             pub fn has_category(self, category: Category) -> bool {
                 self.categories().contains(&category)
@@ -264,6 +360,13 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    AsDateFormat => {
+        name: "as_date_format",
+        categories: [Port],
+        default: Preview,
+        fix: None,
+        min_r_version: None,
+    },
     Assignment => {
         name: "assignment",
         categories: [Read],
@@ -279,6 +382,13 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    CaseComparison => {
+        name: "case_comparison",
+        categories: [Port],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
     ClassEquals => {
         name: "class_equals",
         categories: [Susp],
@@ -293,6 +403,13 @@ declare_rules! {
         fix: Safe,
         min_r_version: Some((4, 4, 0)),
     },
+    ComparisonChain => {
+        name: "comparison_chain",
+        categories: [Corr],
+        default: Enabled,
+        fix: Unsafe,
+        min_r_version: None,
+    },
     ComparisonNegation => {
         name: "comparison_negation",
         categories: [Read],
@@ -335,6 +452,13 @@ declare_rules! {
         fix: None,
         min_r_version: None,
     },
+    EmbeddedData => {
+        name: "embedded_data",
+        categories: [Susp],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
     EmptyAssignment => {
         name: "empty_assignment",
         categories: [Read],
@@ -370,6 +494,20 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    ExportedFunctionMissingDocs => {
+        name: "exported_function_missing_docs",
+        categories: [Corr],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
+    FileListOrder => {
+        name: "file_list_order",
+        categories: [Susp],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
     FixedRegex => {
         name: "fixed_regex",
         categories: [Perf],
@@ -391,6 +529,13 @@ declare_rules! {
         fix: None,
         min_r_version: None,
     },
+    ForLoopIndexAssignment => {
+        name: "for_loop_index_assignment",
+        categories: [Susp],
+        default: Preview,
+        fix: None,
+        min_r_version: None,
+    },
     Glue => {
         name: "glue",
         categories: [Corr, Susp],
@@ -423,6 +568,13 @@ declare_rules! {
         name: "implicit_assignment",
         categories: [Read],
         default: Enabled,
+        fix: Unsafe,
+        min_r_version: None,
+    },
+    InfiniteLoop => {
+        name: "infinite_loop",
+        categories: [Corr],
+        default: Enabled,
         fix: None,
         min_r_version: None,
     },
@@ -433,6 +585,13 @@ declare_rules! {
         fix: None,
         min_r_version: None,
     },
+    InvisibleReturn => {
+        name: "invisible_return",
+        categories: [Corr],
+        default: Preview,
+        fix: Safe,
+        min_r_version: None,
+    },
     IsNumeric => {
         name: "is_numeric",
         categories: [Read],
@@ -454,6 +613,13 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    LengthZero => {
+        name: "length_zero",
+        categories: [Read],
+        default: Disabled,
+        fix: Safe,
+        min_r_version: None,
+    },
     Lengths => {
         name: "lengths",
         categories: [Perf, Read],
@@ -468,6 +634,13 @@ declare_rules! {
         fix: Safe,
         min_r_version: Some((4, 0, 0)),
     },
+    ListSplice => {
+        name: "list_splice",
+        categories: [Susp],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
     LiteralCoercion => {
         name: "literal_coercion",
         categories: [Read],
@@ -475,6 +648,20 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    MagicNumbers => {
+        name: "magic_numbers",
+        categories: [Read],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
+    MapLengthMismatch => {
+        name: "map_length_mismatch",
+        categories: [Corr],
+        default: Enabled,
+        fix: None,
+        min_r_version: None,
+    },
     MatrixApply => {
         name: "matrix_apply",
         categories: [Perf],
@@ -489,6 +676,20 @@ declare_rules! {
         fix: None,
         min_r_version: None,
     },
+    NaUnsafeStringCheck => {
+        name: "na_unsafe_string_check",
+        categories: [Susp],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
+    NamespaceModification => {
+        name: "namespace_modification",
+        categories: [Corr],
+        default: Preview,
+        fix: None,
+        min_r_version: None,
+    },
     NestedPipe => {
         name: "nested_pipe",
         categories: [Read],
@@ -517,6 +718,13 @@ declare_rules! {
         fix: Unsafe,
         min_r_version: None,
     },
+    OptionsReset => {
+        name: "options_reset",
+        categories: [Susp],
+        default: Preview,
+        fix: None,
+        min_r_version: None,
+    },
     OuterNegation => {
         name: "outer_negation",
         categories: [Perf, Read],
@@ -524,6 +732,27 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    PackageDependency => {
+        name: "package_dependency",
+        categories: [Susp],
+        default: Enabled,
+        fix: None,
+        min_r_version: None,
+    },
+    PasteFormula => {
+        name: "paste_formula",
+        categories: [Susp],
+        default: Disabled,
+        fix: Safe,
+        min_r_version: None,
+    },
+    PasteToPaste0 => {
+        name: "paste_to_paste0",
+        categories: [Read, Susp],
+        default: Enabled,
+        fix: Safe,
+        min_r_version: None,
+    },
     PipeConsistency => {
         name: "pipe_consistency",
         categories: [Read],
@@ -573,6 +802,27 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    ReturnAssignment => {
+        name: "return_assignment",
+        categories: [Read],
+        default: Disabled,
+        fix: Safe,
+        min_r_version: None,
+    },
+    RlangAbortCall => {
+        name: "rlang_abort_call",
+        categories: [Susp],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
+    RoxygenCompleteness => {
+        name: "roxygen_completeness",
+        categories: [Corr],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
     SampleInt => {
         name: "sample_int",
         categories: [Read],
@@ -580,6 +830,13 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    ScalarLogicalCheck => {
+        name: "scalar_logical_check",
+        categories: [Read],
+        default: Enabled,
+        fix: Safe,
+        min_r_version: None,
+    },
     Seq => {
         name: "seq",
         categories: [Susp],
@@ -594,6 +851,20 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    ShellInjection => {
+        name: "shell_injection",
+        categories: [Susp],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
+    ShinyRenderContext => {
+        name: "shiny_render_context",
+        categories: [Corr],
+        default: Preview,
+        fix: None,
+        min_r_version: None,
+    },
     Sort => {
         name: "sort",
         categories: [Perf, Read],
@@ -601,6 +872,13 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    SplitApplyCombine => {
+        name: "split_apply_combine",
+        categories: [Perf],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
     Sprintf => {
         name: "sprintf",
         categories: [Corr, Susp],
@@ -629,6 +907,27 @@ declare_rules! {
         fix: None,
         min_r_version: None,
     },
+    SuspiciousRounding => {
+        name: "suspicious_rounding",
+        categories: [Susp],
+        default: Preview,
+        fix: None,
+        min_r_version: None,
+    },
+    SysSetlocale => {
+        name: "sys_setlocale",
+        categories: [Port],
+        default: Preview,
+        fix: None,
+        min_r_version: None,
+    },
+    SysTimeComparison => {
+        name: "sys_time_comparison",
+        categories: [Port],
+        default: Preview,
+        fix: None,
+        min_r_version: None,
+    },
     SystemFile => {
         name: "system_file",
         categories: [Read],
@@ -636,11 +935,18 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    TibbleRownames => {
+        name: "tibble_rownames",
+        categories: [Susp],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
     TrueFalseSymbol => {
         name: "true_false_symbol",
         categories: [Read],
         default: Enabled,
-        fix: None,
+        fix: Safe,
         min_r_version: None,
     },
     UndesirableFunction => {
@@ -664,6 +970,13 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    UnprintedGgplot => {
+        name: "unprinted_ggplot",
+        categories: [Susp],
+        default: Preview,
+        fix: Unsafe,
+        min_r_version: None,
+    },
     UnreachableCode => {
         name: "unreachable_code",
         categories: [Read, Susp],
@@ -671,6 +984,13 @@ declare_rules! {
         fix: None,
         min_r_version: None,
     },
+    UnsafeVectorRecycling => {
+        name: "unsafe_vector_recycling",
+        categories: [Corr],
+        default: Preview,
+        fix: None,
+        min_r_version: None,
+    },
     UnusedFunction => {
         name: "unused_function",
         categories: [Corr],
@@ -678,11 +998,46 @@ declare_rules! {
         fix: None,
         min_r_version: None,
     },
+    UseMethodDispatch => {
+        name: "use_method_dispatch",
+        categories: [Corr],
+        default: Preview,
+        fix: None,
+        min_r_version: None,
+    },
+    ValidationInLoop => {
+        name: "validation_in_loop",
+        categories: [Perf],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
+    VapplyFunValue => {
+        name: "vapply_fun_value",
+        categories: [Corr],
+        default: Enabled,
+        fix: None,
+        min_r_version: None,
+    },
+    VapplyOverSapply => {
+        name: "vapply_over_sapply",
+        categories: [Susp],
+        default: Disabled,
+        fix: None,
+        min_r_version: None,
+    },
+    VectorCondition => {
+        name: "vector_condition",
+        categories: [Corr],
+        default: Preview,
+        fix: None,
+        min_r_version: None,
+    },
     VectorLogic => {
         name: "vector_logic",
         categories: [Perf],
         default: Enabled,
-        fix: None,
+        fix: Safe,
         min_r_version: None,
     },
     WhichGrepl => {
@@ -774,6 +1129,13 @@ declare_rules! {
     //
     // ------------- TESTTHAT -------------
     //
+    TestthatExpectIdentical => {
+        name: "expect_identical",
+        categories: [Testthat],
+        default: Disabled,
+        fix: Unsafe,
+        min_r_version: None,
+    },
     TestthatExpectLength => {
         name: "expect_length",
         categories: [Testthat],
@@ -830,6 +1192,13 @@ declare_rules! {
         fix: Safe,
         min_r_version: None,
     },
+    TestthatExpectSetequal => {
+        name: "expect_setequal",
+        categories: [Testthat],
+        default: Disabled,
+        fix: Safe,
+        min_r_version: None,
+    },
     TestthatExpectTrueFalse => {
         name: "expect_true_false",
         categories: [Testthat],
@@ -4,13 +4,30 @@
 //
 // MIT License - Posit PBC
 
+use std::collections::HashMap;
+
+use crate::diagnostic::Severity;
+use crate::overrides::Overrides;
+use crate::package::CodeKind;
 use crate::per_file_ignores::PerFileIgnores;
+use crate::rmd::ChunkOption;
 use crate::rule_options::ResolvedRuleOptions;
+use crate::rule_paths::RulePaths;
+use crate::rule_set::Profile;
+use crate::toml::UnsafeFixesConfig;
 
 /// Resolved configuration settings used within jarl
 #[derive(Clone, Debug, Default)]
 pub struct Settings {
     pub linter: LinterSettings,
+    pub fix: FixSettings,
+}
+
+/// Settings resolved from the `[fix]` table.
+#[derive(Clone, Debug, Default)]
+pub struct FixSettings {
+    /// Command run on every file modified by fixes, e.g. `air format {file}`.
+    pub post_command: Option<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -21,16 +38,45 @@ pub struct LinterSettings {
     pub include: Option<Vec<String>>,
     pub exclude: Option<Vec<String>>,
     pub default_exclude: Option<bool>,
+    /// Whether files ignored by `.gitignore` are skipped, from
+    /// `respect-gitignore` in `[lint]`.
+    pub respect_gitignore: Option<bool>,
+    /// Whether files ignored by the `.Rbuildignore` of their package are
+    /// skipped, from `respect-rbuildignore` in `[lint]`.
+    pub respect_rbuildignore: Option<bool>,
+    /// Whether the R Markdown and Quarto documents of R packages are checked,
+    /// from `include-vignettes` in `[lint]`.
+    pub include_vignettes: Option<bool>,
     pub check_roxygen: Option<bool>,
     pub fix_roxygen: Option<bool>,
+    /// Chunk options of the R Markdown, Quarto, and Sweave chunks that are
+    /// not checked, from `skip-chunks` in `[lint]`.
+    pub skip_chunks: Vec<ChunkOption>,
+    pub preview: Option<bool>,
+    /// Set of rules used when `select` isn't set, from `profile` in `[lint]`.
+    pub profile: Option<Profile>,
+    /// Maximum number of violations of a rule printed in a single file, from
+    /// `max-per-rule-per-file` in `[lint]`.
+    pub max_per_rule_per_file: Option<usize>,
     pub fixable: Option<Vec<String>>,
     pub unfixable: Option<Vec<String>>,
+    /// Rules whose unsafe fixes are applied with `--fix`, from `unsafe-fixes`
+    /// in `[lint]`.
+    pub unsafe_fixes: Option<UnsafeFixesConfig>,
     /// Whether the deprecated `assignment = "<-"` top-level string form was
     /// used in `[lint]`. When `true`, a deprecation warning should be emitted.
     pub deprecated_assignment_syntax: bool,
     pub rule_options: ResolvedRuleOptions,
     /// Per-file rule ignores resolved from `[lint.per-file-ignores]`.
     pub per_file_ignores: PerFileIgnores,
+    /// Per-rule `include`/`exclude` patterns resolved from `[lint.<rule>]`.
+    pub rule_paths: RulePaths,
+    /// Per-rule severity resolved from `[lint.severity]`, by rule name.
+    pub severity: HashMap<String, Severity>,
+    /// Severity by kind of file resolved from `[lint.severity-by-kind]`.
+    pub severity_by_kind: HashMap<CodeKind, Severity>,
+    /// Per-path rule selection and options resolved from `[[lint.overrides]]`.
+    pub overrides: Overrides,
 }
 
 impl Default for LinterSettings {
@@ -45,13 +91,25 @@ impl Default for LinterSettings {
             include: None,
             exclude: None,
             default_exclude: None,
+            respect_gitignore: None,
+            respect_rbuildignore: None,
+            include_vignettes: None,
             check_roxygen: None,
             fix_roxygen: None,
+            skip_chunks: Vec::new(),
+            preview: None,
+            profile: None,
+            max_per_rule_per_file: None,
             fixable: None,
             unfixable: None,
+            unsafe_fixes: None,
             deprecated_assignment_syntax: false,
             rule_options: ResolvedRuleOptions::default(),
             per_file_ignores: PerFileIgnores::default(),
+            rule_paths: RulePaths::default(),
+            severity: HashMap::new(),
+            severity_by_kind: HashMap::new(),
+            overrides: Overrides::default(),
         }
     }
 }
@@ -0,0 +1,81 @@
+//! Detection of Shiny apps.
+//!
+//! A directory is a Shiny app if it contains `app.R`, or `ui.R` and
+//! `server.R`. When the app runs, Shiny sources these files, `global.R`, and
+//! the files of the `R/` folder of the app. Rules can then check that the code
+//! of these files is valid for a Shiny app.
+
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+use crate::file_system::{FileSystem, OsFileSystem};
+
+/// Files that Shiny sources from the root of an app.
+const APP_FILES: &[&str] = &["app.R", "ui.R", "server.R", "global.R"];
+
+/// Root of the Shiny app that sources `path` when it runs, if any.
+pub fn find_shiny_app_root(path: &Path) -> Option<PathBuf> {
+    find_shiny_app_root_in(path, &OsFileSystem)
+}
+
+/// Same as [`find_shiny_app_root()`] but looks for the app files in
+/// `file_system`.
+pub fn find_shiny_app_root_in(path: &Path, file_system: &dyn FileSystem) -> Option<PathBuf> {
+    let dir = path.parent()?;
+    let file_name = path.file_name().and_then(OsStr::to_str)?;
+
+    let root = if APP_FILES.contains(&file_name) {
+        dir
+    } else if dir.file_name() == Some(OsStr::new("R")) {
+        // The `R/` folder of a package is not the one of an app, even if the
+        // package contains e.g. `R/app.R`.
+        let root = dir.parent()?;
+        if file_system.is_file(&root.join("DESCRIPTION")) {
+            return None;
+        }
+        root
+    } else {
+        return None;
+    };
+
+    is_shiny_app(root, file_system).then(|| root.to_path_buf())
+}
+
+/// Whether `dir` contains the files of a Shiny app.
+fn is_shiny_app(dir: &Path, file_system: &dyn FileSystem) -> bool {
+    file_system.is_file(&dir.join("app.R"))
+        || (file_system.is_file(&dir.join("ui.R")) && file_system.is_file(&dir.join("server.R")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file_system::MemoryFileSystem;
+
+    #[test]
+    fn test_find_shiny_app_root() {
+        let fs = MemoryFileSystem::new()
+            .with_file("single/app.R", "")
+            .with_file("single/global.R", "")
+            .with_file("single/R/plots.R", "")
+            .with_file("single/helpers.R", "")
+            .with_file("split/ui.R", "")
+            .with_file("split/server.R", "")
+            .with_file("only_ui/ui.R", "")
+            .with_file("pkg/DESCRIPTION", "Package: pkg")
+            .with_file("pkg/R/app.R", "");
+
+        let root = |path: &str| find_shiny_app_root_in(Path::new(path), &fs);
+
+        assert_eq!(root("single/app.R"), Some(PathBuf::from("single")));
+        assert_eq!(root("single/global.R"), Some(PathBuf::from("single")));
+        assert_eq!(root("single/R/plots.R"), Some(PathBuf::from("single")));
+        assert_eq!(root("split/server.R"), Some(PathBuf::from("split")));
+        assert_eq!(root("split/ui.R"), Some(PathBuf::from("split")));
+
+        // Only sourced if the app sources it explicitly.
+        assert_eq!(root("single/helpers.R"), None);
+        assert_eq!(root("only_ui/ui.R"), None);
+        assert_eq!(root("pkg/R/app.R"), None);
+    }
+}
@@ -8,6 +8,7 @@
 use air_r_parser::RParserOptions;
 use air_r_syntax::RSyntaxKind;
 use biome_rowan::{AstNode, SyntaxNode, TextRange, TextSize};
+use std::path::Path;
 
 /// Information about where to insert a suppression comment
 #[derive(Debug, Clone)]
@@ -409,7 +410,8 @@ pub fn parse_existing_suppression(line: &str) -> Option<(String, Option<Vec<Stri
     None
 }
 
-/// Create a suppression edit for a diagnostic located inside an Rmd/Qmd file.
+/// Create a suppression edit for a diagnostic located inside an Rmd/Qmd/Rnw
+/// file at `path`.
 ///
 /// Finds the R chunk that contains the diagnostic (using its file-level byte
 /// range), computes the insertion point within that chunk, and remaps the
@@ -422,13 +424,14 @@ pub fn parse_existing_suppression(line: &str) -> Option<(String, Option<Vec<Stri
 /// Returns `None` if no chunk contains the diagnostic range or if the chunk
 /// code cannot be parsed.
 pub fn create_suppression_edit_in_rmd(
+    path: &Path,
     file_content: &str,
     diagnostic_start: usize,
     diagnostic_end: usize,
     rule_name: &str,
     explanation: &str,
 ) -> Option<SuppressionEdit> {
-    let chunks = crate::rmd::extract_r_chunks(file_content);
+    let chunks = crate::rmd::extract_document_chunks(file_content, path);
     for chunk in &chunks {
         let chunk_end = chunk.start_byte + chunk.code.len();
         if diagnostic_start >= chunk.start_byte && diagnostic_start <= chunk_end {
@@ -450,6 +453,87 @@ pub fn create_suppression_edit_in_rmd(
     None
 }
 
+/// Compute where to insert a `# jarl-ignore-file` comment.
+///
+/// File suppressions are only applied when they are at the top of the file,
+/// i.e. in the comments that precede the first expression. The comment is
+/// therefore inserted at the start of the line containing the first token of
+/// code, after any existing leading comments (license header, existing
+/// suppressions, etc.). If the file contains only comments, it is inserted at
+/// the end of the file.
+pub fn compute_file_suppression_insert_point(source: &str) -> Option<SuppressionInsertPoint> {
+    let parsed = air_r_parser::parse(source, RParserOptions::default());
+    let root = parsed.tree();
+
+    let code_start = root
+        .syntax()
+        .first_token()
+        .map(|token| usize::from(token.text_trimmed_range().start()))
+        .unwrap_or(source.len())
+        .min(source.len());
+
+    let (line_start_offset, _) = find_line_start_and_indent(source, code_start);
+    let (offset, needs_leading_newline) =
+        if code_start == source.len() && !source.is_empty() && !source.ends_with('\n') {
+            // Only comments and no trailing newline: add the comment on a new line
+            (source.len(), true)
+        } else {
+            (line_start_offset, false)
+        };
+
+    Some(SuppressionInsertPoint {
+        offset,
+        indent: String::new(),
+        line: count_lines_to(source, offset),
+        needs_leading_newline,
+    })
+}
+
+/// Format a `# jarl-ignore-file` comment for a rule.
+///
+/// If `needs_leading_newline` is true, the comment is preceded by a newline.
+pub fn format_file_suppression_comment(
+    rule_name: &str,
+    explanation: &str,
+    needs_leading_newline: bool,
+) -> String {
+    let comment = format!("# jarl-ignore-file {rule_name}: {explanation}\n");
+    if needs_leading_newline {
+        format!("\n{comment}")
+    } else {
+        comment
+    }
+}
+
+/// Create a `# jarl-ignore-file` edit suppressing a rule in the whole file.
+///
+/// In Rmd/Qmd/Rnw files, the comment is inserted in the first R chunk, since
+/// this is where Jarl looks for file suppressions. Returns `None` if such a
+/// file doesn't have any R chunk.
+pub fn create_file_suppression_edit(
+    path: &Path,
+    source: &str,
+    rule_name: &str,
+    explanation: &str,
+) -> Option<SuppressionEdit> {
+    let insert_point = if crate::fs::has_rmd_extension(path) {
+        let chunks = crate::rmd::extract_document_chunks(source, path);
+        let first_chunk = chunks.first()?;
+        let mut insert_point = compute_file_suppression_insert_point(&first_chunk.code)?;
+        // Remap chunk-local offset and line to file-level ones.
+        insert_point.offset += first_chunk.start_byte;
+        insert_point.line = count_lines_to(source, insert_point.offset);
+        insert_point
+    } else {
+        compute_file_suppression_insert_point(source)?
+    };
+
+    let comment_text =
+        format_file_suppression_comment(rule_name, explanation, insert_point.needs_leading_newline);
+
+    Some(SuppressionEdit { insert_point, comment_text })
+}
+
 /// Create a complete suppression edit for a diagnostic.
 ///
 /// This is the main entry point for creating suppression comments.
@@ -538,6 +622,44 @@ mod tests {
         assert_eq!(insert.indent, "  ");
     }
 
+    #[test]
+    fn test_compute_file_insert_point() {
+        // No leading comments: top of the file
+        let insert = compute_file_suppression_insert_point("x <- 1\nany(is.na(x))").unwrap();
+        assert_eq!(insert.offset, 0);
+        assert_eq!(insert.line, 0);
+        assert!(!insert.needs_leading_newline);
+
+        // After the leading comments and blank lines
+        let source = "# Author: me\n\n# jarl-ignore-file browser: reason\nx <- 1\n";
+        let insert = compute_file_suppression_insert_point(source).unwrap();
+        assert_eq!(insert.offset, source.find("x <- 1").unwrap());
+        assert_eq!(insert.line, 3);
+        assert!(!insert.needs_leading_newline);
+
+        // Only comments without trailing newline
+        let insert = compute_file_suppression_insert_point("# a comment").unwrap();
+        assert_eq!(insert.offset, 11);
+        assert!(insert.needs_leading_newline);
+    }
+
+    #[test]
+    fn test_create_file_suppression_edit_in_rmd() {
+        let source =
+            "---\ntitle: test\n---\n\n```{r}\n# setup\nx <- 1\n```\n\n```{r}\nany(is.na(x))\n```\n";
+        let path = Path::new("test.Rmd");
+        let edit = create_file_suppression_edit(path, source, "any_is_na", "<reason>").unwrap();
+        assert_eq!(edit.insert_point.offset, source.find("x <- 1").unwrap());
+        assert_eq!(edit.insert_point.line, 6);
+        assert_eq!(
+            edit.comment_text,
+            "# jarl-ignore-file any_is_na: <reason>\n"
+        );
+
+        // No R chunk
+        assert!(create_file_suppression_edit(path, "# Title\n", "any_is_na", "<reason>").is_none());
+    }
+
     #[test]
     fn test_parse_existing_suppression() {
         // Blanket suppression
@@ -9,35 +9,87 @@
 use std::collections::HashMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
-use std::fs;
 use std::io;
 use std::path::Path;
 use std::path::PathBuf;
 
-use crate::config::{get_invalid_rules, replace_group_rules, unknown_rules_error};
+use crate::config::{
+    did_you_mean, get_invalid_rules, replace_group_rules, suggest_names, unknown_rules_error,
+};
+use crate::diagnostic::Severity;
+use crate::file_system::{FileSystem, OsFileSystem};
+use crate::fixture::FixtureFunctions;
 use crate::lints::base::assignment::options::AssignmentConfig;
 use crate::lints::base::assignment::options::AssignmentOptions;
 use crate::lints::base::duplicated_arguments::options::DuplicatedArgumentsOptions;
+use crate::lints::base::embedded_data::options::EmbeddedDataOptions;
+use crate::lints::base::file_list_order::options::FileListOrderOptions;
 use crate::lints::base::if_not_else::options::IfNotElseOptions;
 use crate::lints::base::implicit_assignment::options::ImplicitAssignmentOptions;
+use crate::lints::base::internal_function::options::InternalFunctionOptions;
+use crate::lints::base::length_zero::options::LengthZeroOptions;
+use crate::lints::base::list_splice::options::ListSpliceOptions;
+use crate::lints::base::magic_numbers::options::MagicNumbersOptions;
 use crate::lints::base::missing_argument::options::MissingArgumentOptions;
+use crate::lints::base::namespace_modification::options::NamespaceModificationOptions;
 use crate::lints::base::nested_pipe::options::NestedPipeOptions;
 use crate::lints::base::pipe_consistency::options::PipeConsistencyOptions;
 use crate::lints::base::quotes::options::QuotesOptions;
+use crate::lints::base::rlang_abort_call::options::RlangAbortCallOptions;
+use crate::lints::base::scalar_logical_check::options::ScalarLogicalCheckOptions;
+use crate::lints::base::shell_injection::options::ShellInjectionOptions;
+use crate::lints::base::split_apply_combine::options::SplitApplyCombineOptions;
+use crate::lints::base::stopifnot_all::options::StopifnotAllOptions;
+use crate::lints::base::tibble_rownames::options::TibbleRownamesOptions;
 use crate::lints::base::true_false_symbol::options::TrueFalseSymbolOptions;
 use crate::lints::base::undesirable_function::options::UndesirableFunctionOptions;
 use crate::lints::base::unreachable_code::options::UnreachableCodeOptions;
 use crate::lints::base::unused_function::options::UnusedFunctionOptions;
+use crate::lints::base::vapply_over_sapply::options::VapplyOverSapplyOptions;
+use crate::overrides::{OverrideSettings, Overrides};
+use crate::package::CodeKind;
 use crate::per_file_ignores::PerFileIgnores;
+use crate::rmd::ChunkOption;
 use crate::rule_options::{ResolvedRuleOptions, RuleOptions};
-use crate::rule_set::Rule;
+use crate::rule_paths::{RulePathOptions, RulePaths};
+use crate::rule_set::{Profile, Rule};
+use crate::settings::FixSettings;
 use crate::settings::LinterSettings;
 use crate::settings::Settings;
 
+/// The fields of `[lint]`, other than the rule tables.
+const LINT_FIELDS: &[&str] = &[
+    "profile",
+    "select",
+    "extend-select",
+    "ignore",
+    "fixable",
+    "unfixable",
+    "unsafe-fixes",
+    "exclude",
+    "extend-exclude",
+    "default-exclude",
+    "respect-gitignore",
+    "respect-rbuildignore",
+    "include-vignettes",
+    "include",
+    "per-file-ignores",
+    "severity",
+    "severity-by-kind",
+    "overrides",
+    "check-roxygen",
+    "fix-roxygen",
+    "skip-chunks",
+    "fixture-functions",
+    "preview",
+    "max-per-rule-per-file",
+];
+
 #[derive(Debug)]
 pub enum ParseTomlError {
     Read(PathBuf, io::Error),
     Deserialize(PathBuf, toml::de::Error),
+    Extends(PathBuf, String),
 }
 
 impl std::error::Error for ParseTomlError {}
@@ -53,13 +105,219 @@ impl Display for ParseTomlError {
             Self::Deserialize(path, err) => {
                 write!(f, "Failed to parse {path}:\n{err}", path = path.display())
             }
+            Self::Extends(path, err) => {
+                write!(
+                    f,
+                    "Failed to resolve `extends` in {path}:\n{err}",
+                    path = path.display()
+                )
+            }
         }
     }
 }
 
 pub fn parse_jarl_toml(path: &Path) -> Result<TomlOptions, ParseTomlError> {
-    let toml = fs::read_to_string(path).unwrap();
-    toml::from_str(&toml).map_err(|err| ParseTomlError::Deserialize(path.to_path_buf(), err))
+    parse_jarl_toml_in(path, &OsFileSystem)
+}
+
+/// Same as [parse_jarl_toml()] but reads the file, and the files it `extends`,
+/// from `file_system`.
+pub fn parse_jarl_toml_in(
+    path: &Path,
+    file_system: &dyn FileSystem,
+) -> Result<TomlOptions, ParseTomlError> {
+    let toml = file_system
+        .read_to_string(path)
+        .map_err(|err| ParseTomlError::Read(path.to_path_buf(), err))?;
+    let deserialize_error = |err| ParseTomlError::Deserialize(path.to_path_buf(), err);
+
+    let mut table: toml::Table = toml::from_str(&toml).map_err(deserialize_error)?;
+
+    // Merge this file into the configuration it extends, if any.
+    let extends = table.contains_key("extends");
+    if extends {
+        table = resolve_extends(
+            table,
+            path,
+            &mut vec![crate::fs::normalize_path(path)],
+            file_system,
+        )?;
+    }
+
+    // `include` and `exclude` can appear in any `[lint.<rule>]` table, even for
+    // rules that don't have a dedicated options struct, so we take them out
+    // before deserializing the rest of the file.
+    let rule_paths = take_rule_path_options(&mut table);
+
+    if !extends && rule_paths.is_empty() {
+        // Deserialize from the source text to keep line numbers in errors.
+        return toml::from_str(&toml).map_err(deserialize_error);
+    }
+
+    let mut options: TomlOptions = toml::Value::Table(table)
+        .try_into()
+        .map_err(deserialize_error)?;
+    options.lint.get_or_insert_default().rule_paths = rule_paths;
+    Ok(options)
+}
+
+/// Load the configuration extended by `table`, which was read from `path`, and
+/// merge `table` into it.
+///
+/// `visited` contains the configuration files that are already being resolved
+/// and is used to detect circular `extends`.
+fn resolve_extends(
+    table: toml::Table,
+    path: &Path,
+    visited: &mut Vec<PathBuf>,
+    file_system: &dyn FileSystem,
+) -> Result<toml::Table, ParseTomlError> {
+    let extends_error = |err: String| ParseTomlError::Extends(path.to_path_buf(), err);
+
+    let extends = match table.get("extends") {
+        None => return Ok(table),
+        Some(toml::Value::String(extends)) => extends.clone(),
+        Some(_) => return Err(extends_error("`extends` must be a string.".to_string())),
+    };
+
+    let mut parent = if let Some(name) = extends.strip_prefix("jarl:") {
+        // The built-in configurations select the rules of a profile.
+        let Some(profile) = Profile::ALL.into_iter().find(|p| p.as_str() == name) else {
+            let names = Profile::ALL
+                .iter()
+                .map(|profile| format!("`jarl:{}`", profile.as_str()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(extends_error(format!(
+                "Unknown configuration `{extends}`. Expected a path or one of: {names}."
+            )));
+        };
+        let mut lint = toml::Table::new();
+        lint.insert("profile".to_string(), profile.as_str().into());
+        toml::Table::from_iter([("lint".to_string(), toml::Value::Table(lint))])
+    } else {
+        // Paths are relative to the directory of the file that extends them.
+        let parent_path =
+            crate::fs::normalize_path(path.parent().unwrap_or(Path::new("")).join(&extends));
+        if visited.contains(&parent_path) {
+            return Err(extends_error(format!(
+                "Circular `extends` with {}.",
+                parent_path.display()
+            )));
+        }
+        visited.push(parent_path.clone());
+
+        let contents = file_system
+            .read_to_string(&parent_path)
+            .map_err(|err| ParseTomlError::Read(parent_path.clone(), err))?;
+        let parent: toml::Table = toml::from_str(&contents)
+            .map_err(|err| ParseTomlError::Deserialize(parent_path.clone(), err))?;
+        resolve_extends(parent, &parent_path, visited, file_system)?
+    };
+
+    parent.remove("extends");
+    merge_toml_tables(&mut parent, table);
+    Ok(parent)
+}
+
+/// Merge the `child` configuration into `parent`, the child taking precedence.
+///
+/// The fields of `[lint]`, `[lint.per-file-ignores]`, `[lint.severity]`, and
+/// `[lint.severity-by-kind]` are merged one by one, and the patterns of
+/// `extend-exclude` are concatenated. Other values, including rule tables such
+/// as `[lint.quotes]` and lists such as `select`, are replaced.
+fn merge_toml_tables(parent: &mut toml::Table, child: toml::Table) {
+    for (key, value) in child {
+        match value {
+            toml::Value::Table(child_lint) if key == "lint" => match parent.get_mut(&key) {
+                Some(toml::Value::Table(parent_lint)) => merge_lint_tables(parent_lint, child_lint),
+                _ => {
+                    parent.insert(key, toml::Value::Table(child_lint));
+                }
+            },
+            value => {
+                parent.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Merge the `[lint]` table of a child configuration into the one of its
+/// parent, see [merge_toml_tables].
+fn merge_lint_tables(parent: &mut toml::Table, child: toml::Table) {
+    for (field, value) in child {
+        match value {
+            toml::Value::Table(child_map)
+                if matches!(
+                    field.as_str(),
+                    "per-file-ignores" | "severity" | "severity-by-kind"
+                ) =>
+            {
+                match parent.get_mut(&field) {
+                    Some(toml::Value::Table(parent_map)) => parent_map.extend(child_map),
+                    _ => {
+                        parent.insert(field, toml::Value::Table(child_map));
+                    }
+                }
+            }
+            // Patterns are added to the ones of the parent, see
+            // `extend-exclude`.
+            toml::Value::Array(child_patterns) if field == "extend-exclude" => {
+                match parent.get_mut(&field) {
+                    Some(toml::Value::Array(parent_patterns)) => {
+                        parent_patterns.extend(child_patterns)
+                    }
+                    _ => {
+                        parent.insert(field, toml::Value::Array(child_patterns));
+                    }
+                }
+            }
+            value => {
+                parent.insert(field, value);
+            }
+        }
+    }
+}
+
+/// Remove the `include` and `exclude` fields of every `[lint.<rule>]` table and
+/// return them by rule name. Rule tables that are empty afterwards are removed
+/// so that they aren't reported as unknown fields.
+fn take_rule_path_options(table: &mut toml::Table) -> HashMap<String, toml::Table> {
+    let mut rule_paths = HashMap::new();
+
+    let Some(toml::Value::Table(lint)) = table.get_mut("lint") else {
+        return rule_paths;
+    };
+
+    let mut emptied = Vec::new();
+    for (key, value) in lint.iter_mut() {
+        let toml::Value::Table(rule_table) = value else {
+            continue;
+        };
+        if Rule::from_name(key).is_none() {
+            continue;
+        }
+
+        let mut paths = toml::Table::new();
+        for field in ["include", "exclude"] {
+            if let Some(patterns) = rule_table.remove(field) {
+                paths.insert(field.to_string(), patterns);
+            }
+        }
+        if paths.is_empty() {
+            continue;
+        }
+        if rule_table.is_empty() {
+            emptied.push(key.clone());
+        }
+        rule_paths.insert(key.clone(), paths);
+    }
+
+    for key in emptied {
+        lint.remove(&key);
+    }
+
+    rule_paths
 }
 
 #[derive(Clone, Debug, Default, serde::Deserialize)]
@@ -69,17 +327,107 @@ pub struct TomlOptions {
     #[serde(flatten)]
     pub global: GlobalTomlOptions,
     pub lint: Option<LinterTomlOptions>,
+    pub fix: Option<FixTomlOptions>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
-pub struct GlobalTomlOptions {}
+pub struct GlobalTomlOptions {
+    /// # Configuration to extend
+    ///
+    /// Either the path to another configuration file, relative to this one
+    /// (e.g. `"../jarl.toml"`), or the name of a built-in configuration:
+    /// `"jarl:minimal"`, `"jarl:recommended"`, or `"jarl:strict"`, which use the
+    /// rules of the corresponding `profile`.
+    ///
+    /// The options of this file take precedence over the ones of the extended
+    /// configuration. The options of `[lint]`, `[lint.per-file-ignores]`,
+    /// `[lint.severity]`, and `[lint.severity-by-kind]` are merged one by one,
+    /// while rule tables such as `[lint.quotes]` and lists such as `select`
+    /// are replaced. The patterns of `extend-exclude` are added to the ones of
+    /// the extended configuration.
+    pub extends: Option<String>,
+}
+
+/// Accepts either a boolean (`unsafe-fixes = true`) or a list of rules
+/// (`unsafe-fixes = ["all_equal"]`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schemars", schemars(untagged))]
+pub enum UnsafeFixesConfig {
+    Enabled(bool),
+    Rules(Vec<String>),
+}
+
+impl<'de> serde::Deserialize<'de> for UnsafeFixesConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(UnsafeFixesConfigVisitor)
+    }
+}
+
+struct UnsafeFixesConfigVisitor;
+
+impl<'de> serde::de::Visitor<'de> for UnsafeFixesConfigVisitor {
+    type Value = UnsafeFixesConfig;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a boolean or a list of rules")
+    }
+
+    fn visit_bool<E: serde::de::Error>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(UnsafeFixesConfig::Enabled(value))
+    }
+
+    fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let rules = <Vec<String> as serde::Deserialize>::deserialize(
+            serde::de::value::SeqAccessDeserializer::new(seq),
+        )?;
+        Ok(UnsafeFixesConfig::Rules(rules))
+    }
+}
+
+/// Options of the `[fix]` table, used when fixes are written to files.
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub struct FixTomlOptions {
+    /// # Command to run on each fixed file
+    ///
+    /// Command run on every file modified by `jarl check --fix` or
+    /// `jarl fix`, after the fixes are written, for instance to format it
+    /// with `"air format {file}"`. `{file}` is replaced by the path to the
+    /// file, and the path is added at the end of the command if `{file}` is
+    /// missing.
+    ///
+    /// The command is split on whitespace and isn't run in a shell.
+    pub post_command: Option<String>,
+}
 
 #[derive(Clone, Debug, Default, serde::Deserialize)]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "kebab-case")]
 pub struct LinterTomlOptions {
+    /// # Set of rules to use
+    ///
+    /// A curated set of rules, one of:
+    /// - `"minimal"`: the rules enabled by default that report code that is
+    ///   wrong or most likely wrong (groups `COMM`, `CORR`, and `SUSP`);
+    /// - `"recommended"`: the rules enabled by default;
+    /// - `"strict"`: all rules that are not deprecated, except the
+    ///   package-specific ones such as `DPLYR`.
+    ///
+    /// `extend-select` and `ignore` are applied on top of the profile. This is
+    /// ignored if `select` is set. This is the same as passing `--profile` on
+    /// the command line, which takes precedence.
+    pub profile: Option<Profile>,
+
     /// # Rules to select
     ///
     /// If this is empty, then all rules that are provided by `jarl` are used,
@@ -129,6 +477,17 @@ pub struct LinterTomlOptions {
     /// `--fix` in the CLI.
     pub unfixable: Option<Vec<String>>,
 
+    /// # Rules whose unsafe fixes are applied
+    ///
+    /// Either `true` to apply the unsafe fixes of all rules, or a list of
+    /// rules (or groups of rules) whose unsafe fixes are applied, e.g.
+    /// `["all_equal", "download_file"]`. This is like `--unsafe-fixes` in the
+    /// CLI, except that fixes are only applied if you pass `--fix`.
+    /// `--unsafe-fixes` takes precedence over this field.
+    ///
+    /// Defaults to `false`.
+    pub unsafe_fixes: Option<UnsafeFixesConfig>,
+
     /// # Patterns to include in checking
     ///
     /// By default, jarl checks all files with a `.R`, `.qmd`, `.Rmd`, or `.rmd`
@@ -191,6 +550,15 @@ pub struct LinterTomlOptions {
     /// all of the patterns you can provide.
     pub exclude: Option<Vec<String>>,
 
+    /// # Additional patterns to exclude from checking
+    ///
+    /// A list of patterns to exclude in addition to `exclude`. They follow the
+    /// same format as `exclude`.
+    ///
+    /// This is useful with `extends`: `exclude` replaces the patterns of the
+    /// configuration that is extended, while `extend-exclude` is added to them.
+    pub extend_exclude: Option<Vec<String>>,
+
     /// # Whether or not to use default exclude patterns
     ///
     /// Jarl automatically excludes a default set of folders and files. If this option is
@@ -199,6 +567,7 @@ pub struct LinterTomlOptions {
     /// The default set of excluded patterns are:
     /// - `.git/`
     /// - `renv/`
+    /// - `packrat/`
     /// - `revdep/`
     /// - `cpp11.R`
     /// - `RcppExports.R`
@@ -206,6 +575,35 @@ pub struct LinterTomlOptions {
     /// - `import-standalone-*.R`
     pub default_exclude: Option<bool>,
 
+    /// # Whether to skip the files ignored by Git
+    ///
+    /// By default, files that are ignored by Git, i.e. listed in `.gitignore`,
+    /// `.git/info/exclude`, or the global Git ignore file, are not checked. Set
+    /// this option to `false` to check them too.
+    ///
+    /// This only applies to the `jarl.toml` at the root of the checked paths.
+    pub respect_gitignore: Option<bool>,
+
+    /// # Whether to skip the files ignored by `R CMD build`
+    ///
+    /// If `true`, files matched by the `.Rbuildignore` of their package are not
+    /// checked, e.g. the scripts in `data-raw/` if `.Rbuildignore` contains
+    /// `^data-raw$`. This is `false` by default.
+    ///
+    /// As in `R CMD build`, each line of `.Rbuildignore` is a case-insensitive
+    /// regular expression that is matched against the paths relative to the
+    /// root of the package.
+    pub respect_rbuildignore: Option<bool>,
+
+    /// # Whether to check the R Markdown and Quarto documents of R packages
+    ///
+    /// By default, `.Rmd` and `.qmd` files are checked like R files, including
+    /// the documents of R packages: vignettes (in `vignettes/`), documents in
+    /// `inst/`, and `README.Rmd`. Set this option to `false` to skip these
+    /// documents when checking a directory. They are still checked when they
+    /// are passed directly, e.g. with `jarl check vignettes/intro.Rmd`.
+    pub include_vignettes: Option<bool>,
+
     /// # Per-file rule ignores
     ///
     /// A mapping of glob patterns to lists of rules that should be ignored in
@@ -228,6 +626,72 @@ pub struct LinterTomlOptions {
     /// ```
     pub per_file_ignores: Option<HashMap<String, Vec<String>>>,
 
+    /// # Severity of rule violations
+    ///
+    /// A mapping of rule names to the severity of their violations, one of
+    /// `"error"`, `"warning"`, or `"info"`. Rules that are not listed report
+    /// warnings. Rule groups (e.g. `PERF`) are also accepted, in which case
+    /// the severity of a rule listed individually takes precedence.
+    ///
+    /// For example:
+    ///
+    /// ```toml
+    /// [lint.severity]
+    /// any_is_na = "error"
+    /// PERF = "info"
+    /// ```
+    ///
+    /// Use `--exit-nonzero-on` in the CLI to choose which severities make
+    /// Jarl exit with a non-zero code.
+    pub severity: Option<HashMap<String, Severity>>,
+
+    /// # Severity of rule violations by kind of file
+    ///
+    /// A mapping of kinds of files to the severity of all violations reported
+    /// in them, one of `"error"`, `"warning"`, or `"info"`. The kinds are
+    /// `package` (files in the `R/`, `tests/`, `inst/tinytest/`,
+    /// `inst/tests/`, or `src/` folders of an R package), `notebook` (R
+    /// Markdown and Quarto documents), and `script` (other R files).
+    ///
+    /// This is applied after `[lint.severity]` and takes precedence over it.
+    ///
+    /// For example:
+    ///
+    /// ```toml
+    /// [lint.severity-by-kind]
+    /// package = "error"
+    /// script = "warning"
+    /// notebook = "info"
+    /// ```
+    pub severity_by_kind: Option<HashMap<String, Severity>>,
+
+    /// # Per-path rule configuration
+    ///
+    /// A list of overrides that change the rule selection and the rule
+    /// options for some files only. Each override must have a `files` field
+    /// listing glob patterns. Patterns are gitignore-style and resolved
+    /// relative to the directory containing `jarl.toml` (the same format used
+    /// by `include` and `exclude`).
+    ///
+    /// In the matching files, `select` replaces the rules selected in
+    /// `[lint]`, `extend-select` adds rules to this selection, and `ignore`
+    /// removes rules from it. An override can also contain rule tables, such
+    /// as `[lint.overrides.quotes]`, that replace the corresponding
+    /// `[lint.<rule>]` table. When several overrides match a file, they are
+    /// applied in order.
+    ///
+    /// For example:
+    ///
+    /// ```toml
+    /// [[lint.overrides]]
+    /// files = ["tests/**"]
+    /// ignore = ["implicit_assignment"]
+    ///
+    /// [lint.overrides.undesirable_function]
+    /// extend-functions = ["print"]
+    /// ```
+    pub overrides: Option<Vec<OverrideTomlOptions>>,
+
     /// # Whether to lint R code in roxygen `@examples` and `@examplesIf` sections
     ///
     /// When enabled, Jarl parses and checks R code found in roxygen2
@@ -235,6 +699,10 @@ pub struct LinterTomlOptions {
     /// files inside an R package (i.e. in the `R/` directory with a
     /// `DESCRIPTION` file in the parent).
     ///
+    /// Rules that need the rest of the file or package, such as
+    /// `unused_function`, or that report code usually found in examples, such
+    /// as `magic_numbers`, are not applied to this code.
+    ///
     /// Defaults to `true`.
     pub check_roxygen: Option<bool>,
 
@@ -246,6 +714,55 @@ pub struct LinterTomlOptions {
     ///
     /// Defaults to `false`.
     pub fix_roxygen: Option<bool>,
+
+    /// # Chunk options of the R Markdown, Quarto, and Sweave chunks to skip
+    ///
+    /// A list of chunk options with their value, such as `"eval=FALSE"` or
+    /// `"purl=FALSE"`. Chunks that set one of these options, either in the
+    /// chunk header (`{r, eval=FALSE}`) or in a `#| eval: false` line, are
+    /// not checked. This is useful for chunks that contain code that is not
+    /// meant to be run, such as pseudo-code.
+    ///
+    /// Values are compared after removing quotes, and `FALSE`, `F`, and
+    /// `false` (resp. `TRUE`, `T`, and `true`) are equivalent.
+    ///
+    /// Defaults to `[]`, i.e. all R chunks are checked.
+    pub skip_chunks: Option<Vec<String>>,
+
+    /// # Test fixture functions
+    ///
+    /// A list of functions that set up some state until the end of the
+    /// current function or test, such as `withr::local_options()`. Some rules
+    /// don't report code in the arguments of these functions, e.g.
+    /// `implicit_assignment` in `local_mocked_bindings(f = function() x <- 1)`.
+    ///
+    /// `*` matches any sequence of characters. A pattern with a namespace,
+    /// like `withr::local_*`, also matches calls without namespace, such as
+    /// `local_tempfile()`.
+    ///
+    /// This replaces the default list, which is `["rlang::local_*",
+    /// "testthat::local_*", "withr::local_*"]`.
+    pub fixture_functions: Option<Vec<String>>,
+
+    /// # Whether to enable preview rules
+    ///
+    /// New rules are sometimes added in preview first. They are only used in
+    /// preview mode, where they are enabled by default like the other rules.
+    /// This is the same as passing `--preview` on the command line.
+    ///
+    /// Defaults to `false`.
+    pub preview: Option<bool>,
+
+    /// # Maximum number of violations of a rule printed per file
+    ///
+    /// When a rule reports more violations than this in a single file, only
+    /// the first ones are printed, followed by the number of violations that
+    /// were hidden. This avoids flooding the output with identical violations
+    /// in generated files. The summary still counts all violations. Only
+    /// applies to the `full`, `concise`, and `grouped` output formats.
+    ///
+    /// By default, all violations are printed.
+    pub max_per_rule_per_file: Option<usize>,
     /// # Assignment operator to use
     ///
     /// Accepts either the legacy form `assignment = "<-"` (deprecated) or the
@@ -261,6 +778,21 @@ pub struct LinterTomlOptions {
     #[serde(rename = "duplicated_arguments")]
     pub duplicated_arguments: Option<DuplicatedArgumentsOptions>,
 
+    /// # Options for the `embedded_data` rule
+    ///
+    /// Use `max-size` to set the maximum size (in bytes) of string literals.
+    /// Larger strings are reported. Defaults to 10000.
+    #[serde(rename = "embedded_data")]
+    pub embedded_data: Option<EmbeddedDataOptions>,
+
+    /// # Options for the `file_list_order` rule
+    ///
+    /// Use `require-full-names` to choose whether the output of `list.files()`
+    /// must also use `full.names = TRUE` to be considered sorted by `sort()`.
+    /// Defaults to `false`.
+    #[serde(rename = "file_list_order")]
+    pub file_list_order: Option<FileListOrderOptions>,
+
     /// # Options for the `if_not_else` rule
     ///
     /// Use `skipped-functions` to fully replace the default list of functions
@@ -279,6 +811,42 @@ pub struct LinterTomlOptions {
     #[serde(rename = "implicit_assignment")]
     pub implicit_assignment: Option<ImplicitAssignmentOptions>,
 
+    /// # Options for the `internal_function` rule
+    ///
+    /// Use `allow-own-package` to choose whether `:::` is allowed on the
+    /// namespace of the package being checked, e.g. `mypkg:::helper()` in the
+    /// tests of `mypkg`. Defaults to `false`.
+    #[serde(rename = "internal_function")]
+    pub internal_function: Option<InternalFunctionOptions>,
+
+    /// # Options for the `length_zero` rule
+    ///
+    /// Use `idiom` to choose the preferred way to check if a vector is empty.
+    /// Valid values are `"length(x) == 0"` (default) and `"!length(x)"`.
+    #[serde(rename = "length_zero")]
+    pub length_zero: Option<LengthZeroOptions>,
+
+    /// # Options for the `list_splice` rule
+    ///
+    /// Use `list-functions` to fully replace the default list of functions
+    /// that are known to return a list. Use `extend-list-functions` to add to
+    /// the default list.
+    /// Specifying both is an error.
+    ///
+    /// Use `only-in-loops` to choose whether only `c()` calls in the body of a
+    /// loop are reported. Defaults to `true`.
+    #[serde(rename = "list_splice")]
+    pub list_splice: Option<ListSpliceOptions>,
+
+    /// # Options for the `magic_numbers` rule
+    ///
+    /// Use `allowed` to fully replace the default list of numbers that are not
+    /// reported (`-1`, `0`, `1`, `2`, and `100`). Use `extend-allowed` to add
+    /// to the default list.
+    /// Specifying both is an error.
+    #[serde(rename = "magic_numbers")]
+    pub magic_numbers: Option<MagicNumbersOptions>,
+
     /// # Options for the `missing_argument` rule
     ///
     /// Use `skipped-functions` to fully replace the default list of functions
@@ -288,6 +856,13 @@ pub struct LinterTomlOptions {
     #[serde(rename = "missing_argument")]
     pub missing_argument: Option<MissingArgumentOptions>,
 
+    /// # Options for the `namespace_modification` rule
+    ///
+    /// Use `allow-in-tests` to allow modifying namespaces in test files, e.g.
+    /// when a mocking framework requires it.
+    #[serde(rename = "namespace_modification")]
+    pub namespace_modification: Option<NamespaceModificationOptions>,
+
     /// # Options for the `nested_pipe` rule
     ///
     /// Use `skipped-functions` to fully replace the default list of outer calls
@@ -311,6 +886,55 @@ pub struct LinterTomlOptions {
     #[serde(rename = "quotes")]
     pub quotes: Option<QuotesOptions>,
 
+    /// # Options for the `rlang_abort_call` rule
+    ///
+    /// Use `require` to choose which arguments `abort()` and `cli_abort()`
+    /// must have. Valid values are `"call"` and `"class"` (default: both).
+    #[serde(rename = "rlang_abort_call")]
+    pub rlang_abort_call: Option<RlangAbortCallOptions>,
+
+    /// # Options for the `scalar_logical_check` rule
+    ///
+    /// Use `target` to choose the functions recommended instead of
+    /// `is.logical(x) && length(x) == 1 && !is.na(x)`. Valid values are
+    /// `"base"` (default) and `"rlang"`.
+    #[serde(rename = "scalar_logical_check")]
+    pub scalar_logical_check: Option<ScalarLogicalCheckOptions>,
+
+    /// # Options for the `shell_injection` rule
+    ///
+    /// Use `functions` to fully replace the default list of functions that
+    /// run a command through the shell. Use `extend-functions` to add to the
+    /// default list.
+    /// Specifying both is an error.
+    #[serde(rename = "shell_injection")]
+    pub shell_injection: Option<ShellInjectionOptions>,
+
+    /// # Options for the `split_apply_combine` rule
+    ///
+    /// Use `backend` to choose the grouped operation recommended instead of
+    /// `split()`, `lapply()`, and `do.call(rbind, ...)`. Valid values are
+    /// `"base"` (default), `"dplyr"`, and `"data.table"`.
+    #[serde(rename = "split_apply_combine")]
+    pub split_apply_combine: Option<SplitApplyCombineOptions>,
+
+    /// # Options for the `stopifnot_all` rule
+    ///
+    /// Use `report-if-stop` to choose whether `if (!cond) stop("msg")` is also
+    /// reported, since it can be written `stopifnot("msg" = cond)`. Defaults to
+    /// `false`.
+    #[serde(rename = "stopifnot_all")]
+    pub stopifnot_all: Option<StopifnotAllOptions>,
+
+    /// # Options for the `tibble_rownames` rule
+    ///
+    /// Use `functions` to fully replace the default list of functions whose
+    /// output doesn't have row names. Use `extend-functions` to add to the
+    /// default list.
+    /// Specifying both is an error.
+    #[serde(rename = "tibble_rownames")]
+    pub tibble_rownames: Option<TibbleRownamesOptions>,
+
     /// # Options for the `true_false_symbol` rule
     ///
     /// Use `skipped-functions` to list functions whose arguments are allowed to
@@ -346,24 +970,99 @@ pub struct LinterTomlOptions {
     #[serde(rename = "unused_function")]
     pub unused_function: Option<UnusedFunctionOptions>,
 
+    /// # Options for the `vapply_over_sapply` rule
+    ///
+    /// Use `skip-simplify-false` to control whether calls to `sapply()` with
+    /// `simplify = FALSE` are allowed. Defaults to `true`.
+    #[serde(rename = "vapply_over_sapply")]
+    pub vapply_over_sapply: Option<VapplyOverSapplyOptions>,
+
     /// Catch any unknown fields so we can produce a clean error message that
     /// only lists the primary `[lint]` options (not every rule sub-table).
     #[serde(flatten)]
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub(crate) unknown_fields: HashMap<String, toml::Value>,
+
+    /// `include` and `exclude` fields of the `[lint.<rule>]` tables, by rule
+    /// name. Those are set by [parse_jarl_toml] and not deserialized directly.
+    #[serde(skip)]
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub(crate) rule_paths: HashMap<String, toml::Table>,
+}
+
+/// A single `[[lint.overrides]]` entry.
+///
+/// Only `files`, `select`, `extend-select`, `ignore`, and rule tables are
+/// accepted, the other fields of `[lint]` are rejected in
+/// [TomlOptions::into_settings].
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case")]
+pub struct OverrideTomlOptions {
+    /// # Files the override applies to
+    ///
+    /// Gitignore-style patterns, resolved relative to the directory
+    /// containing `jarl.toml`.
+    pub files: Vec<String>,
+
+    #[serde(flatten)]
+    pub lint: LinterTomlOptions,
+}
+
+impl LinterTomlOptions {
+    /// Borrow the rule tables set in these options.
+    ///
+    /// The deprecated `assignment = "<-"` form is not included, it is handled
+    /// separately by the callers.
+    pub fn rule_options(&self) -> RuleOptions<'_> {
+        RuleOptions {
+            assignment: match &self.assignment {
+                Some(AssignmentConfig::Options(options)) => Some(options),
+                _ => None,
+            },
+            duplicated_arguments: self.duplicated_arguments.as_ref(),
+            embedded_data: self.embedded_data.as_ref(),
+            file_list_order: self.file_list_order.as_ref(),
+            if_not_else: self.if_not_else.as_ref(),
+            implicit_assignment: self.implicit_assignment.as_ref(),
+            internal_function: self.internal_function.as_ref(),
+            length_zero: self.length_zero.as_ref(),
+            list_splice: self.list_splice.as_ref(),
+            magic_numbers: self.magic_numbers.as_ref(),
+            missing_argument: self.missing_argument.as_ref(),
+            namespace_modification: self.namespace_modification.as_ref(),
+            nested_pipe: self.nested_pipe.as_ref(),
+            pipe_consistency: self.pipe_consistency.as_ref(),
+            quotes: self.quotes.as_ref(),
+            rlang_abort_call: self.rlang_abort_call.as_ref(),
+            scalar_logical_check: self.scalar_logical_check.as_ref(),
+            shell_injection: self.shell_injection.as_ref(),
+            split_apply_combine: self.split_apply_combine.as_ref(),
+            stopifnot_all: self.stopifnot_all.as_ref(),
+            tibble_rownames: self.tibble_rownames.as_ref(),
+            true_false_symbol: self.true_false_symbol.as_ref(),
+            undesirable_function: self.undesirable_function.as_ref(),
+            unreachable_code: self.unreachable_code.as_ref(),
+            unused_function: self.unused_function.as_ref(),
+            vapply_over_sapply: self.vapply_over_sapply.as_ref(),
+        }
+    }
 }
 
 /// Return the path to the `jarl.toml` or `.jarl.toml` file in a given directory.
-pub fn find_jarl_toml_in_directory<P: AsRef<Path>>(path: P) -> Option<PathBuf> {
+pub fn find_jarl_toml_in_directory<P: AsRef<Path>>(
+    path: P,
+    file_system: &dyn FileSystem,
+) -> Option<PathBuf> {
     // Check for `jarl.toml` first, as we prioritize the "visible" one.
     let toml = path.as_ref().join("jarl.toml");
-    if toml.is_file() {
+    if file_system.is_file(&toml) {
         return Some(toml);
     }
 
     // Now check for `.jarl.toml` as well
     let toml = path.as_ref().join(".jarl.toml");
-    if toml.is_file() {
+    if file_system.is_file(&toml) {
         return Some(toml);
     }
 
@@ -374,7 +1073,7 @@ pub fn find_jarl_toml_in_directory<P: AsRef<Path>>(path: P) -> Option<PathBuf> {
 /// Find the path to the closest `jarl.toml` or `.jarl.toml` if one exists, walking up the filesystem
 pub fn find_jarl_toml<P: AsRef<Path>>(path: P) -> Option<PathBuf> {
     for directory in path.as_ref().ancestors() {
-        if let Some(toml) = find_jarl_toml_in_directory(directory) {
+        if let Some(toml) = find_jarl_toml_in_directory(directory, &OsFileSystem) {
             return Some(toml);
         }
     }
@@ -388,15 +1087,43 @@ impl TomlOptions {
         // Reject unknown fields in `[lint]` with a clean error message that
         // only lists the primary options (not every rule sub-table name).
         if let Some(field) = linter.unknown_fields.keys().next() {
+            // `[lint.<rule>]` tables of rules without options can only contain
+            // `include` and `exclude`, which were already taken out.
+            if Rule::from_name(field).is_some() {
+                return Err(anyhow::anyhow!(
+                    "Unknown options in `[lint.{field}]`. This rule only accepts \
+                     `include` and `exclude`."
+                ));
+            }
+            let expected = LINT_FIELDS
+                .iter()
+                .map(|field| format!("`{field}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(unknown_field_error(
+                format!("Unknown field `{field}` in `[lint]`. Expected one of: {expected}."),
+                field,
+                LINT_FIELDS,
+            ));
+        }
+
+        if linter.max_per_rule_per_file == Some(0) {
             return Err(anyhow::anyhow!(
-                "Unknown field `{field}` in `[lint]`. Expected one of: \
-                 `select`, `extend-select`, `ignore`, `fixable`, `unfixable`, \
-                 `exclude`, `default-exclude`, `include`, `per-file-ignores`, \
-                 `check-roxygen`, `fix-roxygen`."
+                "`max-per-rule-per-file` in `[lint]` must be at least 1."
             ));
         }
 
         let per_file_ignores = resolve_per_file_ignores(linter.per_file_ignores.as_ref(), root)?;
+        let rule_paths = resolve_rule_paths(&linter.rule_paths, root)?;
+        let severity = resolve_severity(linter.severity.as_ref())?;
+        let severity_by_kind = resolve_severity_by_kind(linter.severity_by_kind.as_ref())?;
+        let overrides = resolve_overrides(linter.overrides.as_ref(), root)?;
+        let skip_chunks = linter
+            .skip_chunks
+            .iter()
+            .flatten()
+            .map(|option| ChunkOption::parse(option))
+            .collect::<anyhow::Result<Vec<_>>>()?;
 
         // Resolve the assignment config: extract the AssignmentOptions and
         // track whether the deprecated top-level string form was used.
@@ -408,37 +1135,56 @@ impl TomlOptions {
             Some(AssignmentConfig::Options(opts)) => (Some(opts.clone()), false),
             None => (None, false),
         };
+        let mut rule_options = ResolvedRuleOptions::resolve(&RuleOptions {
+            assignment: assignment_options.as_ref(),
+            ..linter.rule_options()
+        })?;
+        rule_options.fixture_functions =
+            FixtureFunctions::resolve(linter.fixture_functions.as_ref())?;
 
         let linter = LinterSettings {
             select: linter.select,
             extend_select: linter.extend_select,
             ignore: linter.ignore,
             include: linter.include,
-            exclude: linter.exclude,
+            exclude: match (linter.exclude, linter.extend_exclude) {
+                (Some(mut exclude), Some(extend_exclude)) => {
+                    exclude.extend(extend_exclude);
+                    Some(exclude)
+                }
+                (exclude, extend_exclude) => exclude.or(extend_exclude),
+            },
             default_exclude: linter.default_exclude,
+            respect_gitignore: linter.respect_gitignore,
+            respect_rbuildignore: linter.respect_rbuildignore,
+            include_vignettes: linter.include_vignettes,
             check_roxygen: linter.check_roxygen,
             fix_roxygen: linter.fix_roxygen,
+            skip_chunks,
+            preview: linter.preview,
+            profile: linter.profile,
+            max_per_rule_per_file: linter.max_per_rule_per_file,
             fixable: linter.fixable,
             unfixable: linter.unfixable,
+            unsafe_fixes: linter.unsafe_fixes,
             deprecated_assignment_syntax,
-            rule_options: ResolvedRuleOptions::resolve(&RuleOptions {
-                assignment: assignment_options.as_ref(),
-                duplicated_arguments: linter.duplicated_arguments.as_ref(),
-                if_not_else: linter.if_not_else.as_ref(),
-                implicit_assignment: linter.implicit_assignment.as_ref(),
-                missing_argument: linter.missing_argument.as_ref(),
-                nested_pipe: linter.nested_pipe.as_ref(),
-                pipe_consistency: linter.pipe_consistency.as_ref(),
-                quotes: linter.quotes.as_ref(),
-                true_false_symbol: linter.true_false_symbol.as_ref(),
-                undesirable_function: linter.undesirable_function.as_ref(),
-                unreachable_code: linter.unreachable_code.as_ref(),
-                unused_function: linter.unused_function.as_ref(),
-            })?,
+            rule_options,
             per_file_ignores,
+            rule_paths,
+            severity,
+            severity_by_kind,
+            overrides,
         };
 
-        Ok(Settings { linter })
+        let post_command = self.fix.and_then(|fix| fix.post_command);
+        if post_command
+            .as_ref()
+            .is_some_and(|command| command.trim().is_empty())
+        {
+            return Err(anyhow::anyhow!("`post-command` in `[fix]` can't be empty."));
+        }
+
+        Ok(Settings { linter, fix: FixSettings { post_command } })
     }
 }
 
@@ -478,3 +1224,226 @@ fn resolve_per_file_ignores(
 
     PerFileIgnores::new(root, entries)
 }
+
+/// Validate and compile the `include`/`exclude` fields taken from the
+/// `[lint.<rule>]` tables into [RulePaths].
+fn resolve_rule_paths(
+    rule_paths: &HashMap<String, toml::Table>,
+    root: &Path,
+) -> anyhow::Result<RulePaths> {
+    let mut entries = Vec::with_capacity(rule_paths.len());
+
+    for (rule_name, paths) in rule_paths {
+        // Keys were already checked against the rule names when extracting
+        // the patterns.
+        let Some(rule) = Rule::from_name(rule_name) else {
+            continue;
+        };
+        let options: RulePathOptions =
+            toml::Value::Table(paths.clone()).try_into().map_err(|e| {
+                anyhow::anyhow!("Invalid `include` or `exclude` in `[lint.{rule_name}]`: {e}")
+            })?;
+        entries.push((rule, options));
+    }
+
+    RulePaths::new(root, entries)
+}
+
+/// Validate the `[lint.severity]` map and expand rule groups. Severities set
+/// for individual rules take precedence over the ones set for their group.
+fn resolve_severity(
+    severity: Option<&HashMap<String, Severity>>,
+) -> anyhow::Result<HashMap<String, Severity>> {
+    let Some(severity) = severity else {
+        return Ok(HashMap::new());
+    };
+
+    let all_rules = Rule::all();
+    let mut passed_by_user: Vec<&str> = severity.keys().map(|s| s.as_str()).collect();
+    passed_by_user.sort();
+    let expanded_rules = replace_group_rules(&passed_by_user, all_rules);
+    if let Some(invalid) = get_invalid_rules(all_rules, &expanded_rules) {
+        return Err(unknown_rules_error(
+            format!(
+                "Unknown rules in `[lint.severity]`: {}",
+                invalid.names.join(", ")
+            ),
+            invalid.help,
+        ));
+    }
+
+    let mut resolved = HashMap::new();
+    let (rules, groups): (Vec<_>, Vec<_>) = severity
+        .iter()
+        .partition(|(name, _)| Rule::from_name(name).is_some());
+    for (group, level) in groups {
+        for rule in replace_group_rules(&vec![group.as_str()], all_rules) {
+            resolved.insert(rule, *level);
+        }
+    }
+    for (rule, level) in rules {
+        resolved.insert(rule.clone(), *level);
+    }
+
+    Ok(resolved)
+}
+
+/// Error for an unknown `field` of a table, with a "did you mean" suggestion
+/// among the known `fields` and the rule names (for rule tables, such as
+/// `[lint.unreachable_code]`).
+fn unknown_field_error(message: String, field: &str, fields: &[&str]) -> anyhow::Error {
+    let candidates: Vec<&str> = fields
+        .iter()
+        .copied()
+        .chain(Rule::all().iter().map(|rule| rule.name()))
+        .collect();
+    let help = did_you_mean(&suggest_names(field, &candidates))
+        .into_iter()
+        .collect();
+    unknown_rules_error(message, help)
+}
+
+/// Validate the kinds of files in the `[lint.severity-by-kind]` map.
+fn resolve_severity_by_kind(
+    severity_by_kind: Option<&HashMap<String, Severity>>,
+) -> anyhow::Result<HashMap<CodeKind, Severity>> {
+    let Some(severity_by_kind) = severity_by_kind else {
+        return Ok(HashMap::new());
+    };
+
+    let mut invalid: Vec<&str> = severity_by_kind
+        .keys()
+        .filter(|kind| CodeKind::from_name(kind).is_none())
+        .map(|kind| kind.as_str())
+        .collect();
+    if !invalid.is_empty() {
+        invalid.sort();
+        return Err(anyhow::anyhow!(
+            "Unknown kinds of files in `[lint.severity-by-kind]`: {}. \
+             Expected one of: `package`, `script`, `notebook`.",
+            invalid.join(", ")
+        ));
+    }
+
+    Ok(severity_by_kind
+        .iter()
+        .filter_map(|(kind, level)| Some((CodeKind::from_name(kind)?, *level)))
+        .collect())
+}
+
+/// Validate the `[[lint.overrides]]` entries and compile them into
+/// [Overrides], expanding rule groups and checking rule names just like
+/// `select`/`ignore`.
+fn resolve_overrides(
+    overrides: Option<&Vec<OverrideTomlOptions>>,
+    root: &Path,
+) -> anyhow::Result<Overrides> {
+    let Some(overrides) = overrides else {
+        return Ok(Overrides::default());
+    };
+
+    let mut entries = Vec::with_capacity(overrides.len());
+
+    for entry in overrides {
+        let lint = &entry.lint;
+
+        // Only the rule selection and the rule options can be changed per
+        // path, the other options apply to the whole project.
+        let unsupported = [
+            ("fixable", lint.fixable.is_some()),
+            ("unfixable", lint.unfixable.is_some()),
+            ("unsafe-fixes", lint.unsafe_fixes.is_some()),
+            ("include", lint.include.is_some()),
+            ("exclude", lint.exclude.is_some()),
+            ("extend-exclude", lint.extend_exclude.is_some()),
+            ("default-exclude", lint.default_exclude.is_some()),
+            ("respect-gitignore", lint.respect_gitignore.is_some()),
+            ("respect-rbuildignore", lint.respect_rbuildignore.is_some()),
+            ("include-vignettes", lint.include_vignettes.is_some()),
+            ("per-file-ignores", lint.per_file_ignores.is_some()),
+            ("severity", lint.severity.is_some()),
+            ("severity-by-kind", lint.severity_by_kind.is_some()),
+            ("overrides", lint.overrides.is_some()),
+            ("check-roxygen", lint.check_roxygen.is_some()),
+            ("fix-roxygen", lint.fix_roxygen.is_some()),
+            ("skip-chunks", lint.skip_chunks.is_some()),
+            ("fixture-functions", lint.fixture_functions.is_some()),
+            ("preview", lint.preview.is_some()),
+            ("profile", lint.profile.is_some()),
+            (
+                "max-per-rule-per-file",
+                lint.max_per_rule_per_file.is_some(),
+            ),
+        ]
+        .into_iter()
+        .find_map(|(field, is_set)| is_set.then_some(field))
+        .or_else(|| {
+            lint.unknown_fields
+                .keys()
+                .next()
+                .map(|field| field.as_str())
+        });
+        if let Some(field) = unsupported {
+            let message = format!(
+                "Unknown field `{field}` in `[[lint.overrides]]`. Expected one of: \
+                 `files`, `select`, `extend-select`, `ignore`, or the options of a rule."
+            );
+            // Only suggest a field name for typos, not for `[lint]` options
+            // that can't be used in overrides.
+            if lint.unknown_fields.contains_key(field) {
+                return Err(unknown_field_error(
+                    message,
+                    field,
+                    &["files", "select", "extend-select", "ignore"],
+                ));
+            }
+            return Err(anyhow::anyhow!(message));
+        }
+
+        let mut rule_options = lint.clone();
+        if let Some(AssignmentConfig::Legacy(operator)) = &lint.assignment {
+            rule_options.assignment = Some(AssignmentConfig::Options(AssignmentOptions {
+                operator: Some(operator.clone()),
+            }));
+        }
+
+        entries.push(OverrideSettings {
+            files: entry.files.clone(),
+            select: lint
+                .select
+                .as_ref()
+                .map(|names| resolve_override_rules(names, "select"))
+                .transpose()?,
+            extend_select: resolve_override_rules(
+                lint.extend_select.as_deref().unwrap_or_default(),
+                "extend-select",
+            )?,
+            ignore: resolve_override_rules(lint.ignore.as_deref().unwrap_or_default(), "ignore")?,
+            rule_options,
+        });
+    }
+
+    Overrides::new(root, entries)
+}
+
+/// Expand rule groups and check the rule names passed to `field` in a
+/// `[[lint.overrides]]` entry.
+fn resolve_override_rules(names: &[String], field: &str) -> anyhow::Result<Vec<Rule>> {
+    let all_rules = Rule::all();
+    let passed_by_user = names.iter().map(|s| s.as_str()).collect();
+    let expanded_rules = replace_group_rules(&passed_by_user, all_rules);
+    if let Some(invalid) = get_invalid_rules(all_rules, &expanded_rules) {
+        return Err(unknown_rules_error(
+            format!(
+                "Unknown rules in `{field}` in `[[lint.overrides]]`: {}",
+                invalid.names.join(", ")
+            ),
+            invalid.help,
+        ));
+    }
+
+    Ok(expanded_rules
+        .iter()
+        .filter_map(|name| Rule::from_name(name))
+        .collect())
+}
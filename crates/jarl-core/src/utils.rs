@@ -2,7 +2,7 @@ use crate::diagnostic::Diagnostic;
 use crate::location::Location;
 use air_r_syntax::{
     AnyRExpression, RArgument, RArgumentList, RBinaryExpression, RBinaryExpressionFields, RCall,
-    RExtractExpressionFields, RSyntaxKind, RSyntaxNode,
+    RExtractExpressionFields, RSyntaxKind, RSyntaxNode, RSyntaxToken,
 };
 use anyhow::{Result, anyhow};
 use biome_rowan::{AstNode, AstSeparatedList, Direction};
@@ -402,3 +402,192 @@ pub fn node_contains_comments(node: &RSyntaxNode) -> bool {
         has_internal_leading || has_internal_trailing
     })
 }
+
+/// The closest function definition containing `node`, or the root of the file.
+pub fn enclosing_scope(node: &RSyntaxNode) -> RSyntaxNode {
+    node.ancestors()
+        .skip(1)
+        .find(|ancestor| {
+            ancestor.kind() == RSyntaxKind::R_FUNCTION_DEFINITION || ancestor.parent().is_none()
+        })
+        .unwrap_or_else(|| node.clone())
+}
+
+/// Length of `expr` if it can be known from literals only, e.g. `3` for
+/// `1:3`, `c("a", "b", "c")`, or `seq_len(3)`.
+pub fn static_length(expr: &AnyRExpression) -> Option<u64> {
+    match expr {
+        AnyRExpression::AnyRValue(value) => {
+            // `""` and `1` have length 1 but complex and other values are skipped.
+            if value.as_r_string_value().is_some()
+                || value.as_r_integer_value().is_some()
+                || value.as_r_double_value().is_some()
+            {
+                Some(1)
+            } else {
+                None
+            }
+        }
+        AnyRExpression::RTrueExpression(_)
+        | AnyRExpression::RFalseExpression(_)
+        | AnyRExpression::RNaExpression(_) => Some(1),
+        AnyRExpression::RNullExpression(_) => Some(0),
+        AnyRExpression::RParenthesizedExpression(expr) => static_length(&expr.body().ok()?),
+        AnyRExpression::RBinaryExpression(binary) => {
+            let RBinaryExpressionFields { left, operator, right } = binary.as_fields();
+            if operator.ok()?.kind() != RSyntaxKind::COLON {
+                return None;
+            }
+            let from = numeric_value(&left.ok()?)?;
+            let to = numeric_value(&right.ok()?)?;
+            Some((to - from).abs().floor() as u64 + 1)
+        }
+        AnyRExpression::RCall(call) => {
+            let function = call.function().ok()?;
+            if get_function_namespace_prefix(function.clone()).is_some_and(|ns| ns != "base::") {
+                return None;
+            }
+            let args = call.arguments().ok()?.items();
+            let name = get_function_name(function);
+            match name.as_str() {
+                "c" => {
+                    let mut length = 0;
+                    for arg in args.iter() {
+                        let arg = arg.ok()?;
+                        // An empty argument as in `c(1, )` is an error.
+                        length += static_length(&arg.value()?)?;
+                    }
+                    Some(length)
+                }
+                "list" => {
+                    let mut length = 0;
+                    for arg in args.iter() {
+                        let arg = arg.ok()?;
+                        if arg.value()?.to_trimmed_string() == "..." {
+                            return None;
+                        }
+                        length += 1;
+                    }
+                    Some(length)
+                }
+                "seq_len" | "character" | "numeric" | "double" | "integer" | "logical" => {
+                    let mut args = args.iter();
+                    let length = match args.next() {
+                        Some(arg) => {
+                            let arg = arg.ok()?;
+                            // `seq_len(length.out = 3)` and `numeric(length = 3)`.
+                            if let Some(name_clause) = arg.name_clause()
+                                && !matches!(
+                                    name_clause.name().ok()?.to_trimmed_string().as_str(),
+                                    "length" | "length.out"
+                                )
+                            {
+                                return None;
+                            }
+                            let length = numeric_value(&arg.value()?)?;
+                            if length < 0.0 || length.fract() != 0.0 {
+                                return None;
+                            }
+                            length as u64
+                        }
+                        // `seq_len()` is an error but `character()` is empty.
+                        None if name == "seq_len" => return None,
+                        None => 0,
+                    };
+                    if args.next().is_some() {
+                        return None;
+                    }
+                    Some(length)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Value of a numeric literal, possibly preceded by `-` or `+`.
+pub fn numeric_value(expr: &AnyRExpression) -> Option<f64> {
+    match expr {
+        AnyRExpression::AnyRValue(value) => {
+            let text = if let Some(integer) = value.as_r_integer_value() {
+                integer.value_token().ok()?.text_trimmed().to_string()
+            } else if let Some(double) = value.as_r_double_value() {
+                double.value_token().ok()?.text_trimmed().to_string()
+            } else {
+                return None;
+            };
+            let text = text.trim_end_matches('L');
+            let value: f64 = text.parse().ok()?;
+            value.is_finite().then_some(value)
+        }
+        AnyRExpression::RUnaryExpression(unary) => {
+            let operator = unary.operator().ok()?;
+            let value = numeric_value(&unary.argument().ok()?)?;
+            match operator.text_trimmed() {
+                "-" => Some(-value),
+                "+" => Some(value),
+                _ => None,
+            }
+        }
+        AnyRExpression::RParenthesizedExpression(expr) => numeric_value(&expr.body().ok()?),
+        _ => None,
+    }
+}
+
+/// The left-hand side of the pipe if `node` is its right-hand side, e.g. `df`
+/// in `df |> rownames()`.
+pub fn piped_input(node: &RSyntaxNode) -> Option<AnyRExpression> {
+    let binary = RBinaryExpression::cast(node.parent()?)?;
+    if !is_pipe(&binary.operator().ok()?) || binary.right().ok()?.syntax() != node {
+        return None;
+    }
+    binary.left().ok()
+}
+
+/// Whether `operator` is the native pipe `|>` or the magrittr pipe `%>%`.
+pub fn is_pipe(operator: &RSyntaxToken) -> bool {
+    operator.kind() == RSyntaxKind::PIPE
+        || (operator.kind() == RSyntaxKind::SPECIAL && operator.text_trimmed() == "%>%")
+}
+
+/// Name of the object modified by an assignment, e.g. `x` in `x <- 1`,
+/// `"x" <- 1`, `x$a <- 1`, or `names(x) <- "a"`.
+pub fn assigned_name(target: &AnyRExpression) -> Option<String> {
+    match target {
+        AnyRExpression::RIdentifier(ident) => Some(ident.syntax().text_trimmed().to_string()),
+        AnyRExpression::AnyRValue(value) => {
+            let string = value.as_r_string_value()?;
+            let text = string.syntax().text_trimmed().to_string();
+            Some(text[1..text.len() - 1].to_string())
+        }
+        AnyRExpression::RSubset(subset) => assigned_name(&subset.function().ok()?),
+        AnyRExpression::RSubset2(subset) => assigned_name(&subset.function().ok()?),
+        AnyRExpression::RExtractExpression(extract) => assigned_name(&extract.left().ok()?),
+        AnyRExpression::RCall(call) => {
+            let args = call.arguments().ok()?.items();
+            assigned_name(&get_unnamed_arg_by_position(&args, 1)?.value()?)
+        }
+        _ => None,
+    }
+}
+
+/// Whether `node` is the code of an observer, e.g. `{ ... }` in
+/// `observe({ ... })` or `observeEvent(input$x, { ... })`. The value of this
+/// code is discarded.
+pub fn is_observer_handler(node: &RSyntaxNode) -> bool {
+    let Some(argument) = node.parent().and_then(RArgument::cast) else {
+        return false;
+    };
+    argument
+        .syntax()
+        .ancestors()
+        .find_map(RCall::cast)
+        .and_then(|call| call.function().ok())
+        .is_some_and(|function| {
+            matches!(
+                get_function_name(function).as_str(),
+                "observe" | "observeEvent"
+            )
+        })
+}
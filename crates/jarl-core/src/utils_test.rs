@@ -78,6 +78,7 @@ fn run_check(
         files: vec![temp_file.path().to_path_buf()],
         fix: false,
         unsafe_fixes: false,
+        unsafe_fixes_rules: String::new(),
         fix_only: false,
         select: rule.to_string(),
         extend_select: String::new(),
@@ -85,7 +86,10 @@ fn run_check(
         min_r_version: min_r_version.map(|s| s.to_string()),
         allow_dirty: false,
         allow_no_vcs: true,
+        respect_staged: false,
         assignment: None,
+        preview: true,
+        profile: None,
     };
 
     let resolver = setup_resolver(temp_file.path(), settings);
@@ -105,7 +109,10 @@ fn run_check(
     let results = check(config);
 
     for (_, result) in results {
-        if let Ok(diagnostics) = result {
+        if let Ok(mut diagnostics) = result {
+            // Syntax errors are reported whatever the rule, only keep the
+            // diagnostics of the rule under test.
+            diagnostics.retain(|d| !d.is_syntax_error());
             return diagnostics;
         }
     }
@@ -134,6 +141,7 @@ fn apply_fixes(
         files: vec![temp_file.path().to_path_buf()],
         fix: true,
         unsafe_fixes,
+        unsafe_fixes_rules: String::new(),
         fix_only: false,
         select: rule.to_string(),
         extend_select: String::new(),
@@ -141,7 +149,10 @@ fn apply_fixes(
         min_r_version: min_r_version.map(|s| s.to_string()),
         allow_dirty: false,
         allow_no_vcs: true,
+        respect_staged: false,
         assignment: None,
+        preview: true,
+        profile: None,
     };
 
     let resolver = setup_resolver(temp_file.path(), settings);
@@ -1,8 +1,9 @@
 use crate::config::Config;
-use anyhow::{Result, bail};
+use anyhow::{Context, Result, bail};
 use std::collections::HashMap;
-use std::path::Path;
-use std::process::Command;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
 
 /// Try to find the git repository root for a given file path.
 /// Returns `Some(repo_root)` if found, `None` otherwise (e.g. if git isn't used
@@ -89,7 +90,9 @@ pub fn check_version_control(paths: &[String], config: &Config) -> Result<()> {
         )
     }
 
-    if config.allow_dirty {
+    // With `--respect-staged`, the unstaged changes are expected and are kept
+    // in the working tree.
+    if config.allow_dirty || config.respect_staged {
         return Ok(());
     }
 
@@ -122,3 +125,150 @@ pub fn check_version_control(paths: &[String], config: &Config) -> Result<()> {
 
     Ok(())
 }
+
+/// Run `git` with `args` in `dir` and return its stdout, or an error with its
+/// stderr if it failed.
+fn git(dir: &Path, args: &[&str], stdin: Option<&str>) -> Result<Vec<u8>> {
+    let mut child = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .stdin(if stdin.is_some() {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to run `git`")?;
+
+    if let Some(input) = stdin
+        && let Some(mut pipe) = child.stdin.take()
+    {
+        pipe.write_all(input.as_bytes())?;
+    }
+
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        bail!(
+            "`git {}` failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(output.stdout)
+}
+
+/// The content of a file in the Git index, i.e. the version of the file that
+/// will be committed. Used by `--respect-staged`.
+pub struct StagedFile {
+    repo_root: PathBuf,
+    /// Path of the file relative to the root of the repository.
+    path_in_repo: String,
+    /// File mode in the index, e.g. `100644`.
+    mode: String,
+    pub content: String,
+}
+
+impl StagedFile {
+    /// Read the staged content of `path`. Returns `None` if `path` is not in
+    /// a Git repository or not in its index, e.g. if it's untracked.
+    pub fn read(path: &Path) -> Result<Option<Self>> {
+        let Some(repo_root) = discover_repo(&path.to_string_lossy()) else {
+            return Ok(None);
+        };
+        let (dir, file_name) = match (path.parent(), path.file_name()) {
+            (Some(dir), Some(file_name)) if !dir.as_os_str().is_empty() => (dir, file_name),
+            (_, Some(file_name)) => (Path::new("."), file_name),
+            _ => return Ok(None),
+        };
+
+        let output = git(
+            dir,
+            &[
+                "ls-files",
+                "--stage",
+                "--full-name",
+                "--",
+                &file_name.to_string_lossy(),
+            ],
+            None,
+        )?;
+        let output = String::from_utf8_lossy(&output);
+
+        // Format: "<mode> <object> <stage>\t<path>", with one line per stage
+        // if the file has merge conflicts.
+        let mut entries = output.lines().filter_map(|line| {
+            let (info, path_in_repo) = line.split_once('\t')?;
+            let mut info = info.split(' ');
+            Some((info.next()?, info.next()?, info.next()?, path_in_repo))
+        });
+        let Some((mode, object, stage, path_in_repo)) = entries.next() else {
+            return Ok(None);
+        };
+        if stage != "0" {
+            bail!(
+                "{} has merge conflicts, resolve them before applying the fixes.",
+                path.display()
+            );
+        }
+
+        let content = git(Path::new(&repo_root), &["cat-file", "blob", object], None)?;
+        let content = String::from_utf8(content)
+            .with_context(|| format!("The staged content of {} is not UTF-8", path.display()))?;
+
+        Ok(Some(Self {
+            repo_root: PathBuf::from(repo_root),
+            path_in_repo: path_in_repo.to_string(),
+            mode: mode.to_string(),
+            content,
+        }))
+    }
+
+    /// Replace the staged content of the file by `content`, without modifying
+    /// the working tree.
+    pub fn stage(&self, content: &str) -> Result<()> {
+        let object = git(
+            &self.repo_root,
+            &["hash-object", "-w", "--stdin", "--path", &self.path_in_repo],
+            Some(content),
+        )?;
+        let object = String::from_utf8_lossy(&object);
+        git(
+            &self.repo_root,
+            &[
+                "update-index",
+                "--cacheinfo",
+                &format!("{},{},{}", self.mode, object.trim(), self.path_in_repo),
+            ],
+            None,
+        )?;
+        Ok(())
+    }
+}
+
+/// Merge the changes from `base` to `other` into `current`, like a three-way
+/// merge in Git. Returns `None` if the changes conflict.
+pub fn merge_contents(current: &str, base: &str, other: &str) -> Result<Option<String>> {
+    let dir = tempfile::tempdir()?;
+    let files = [("current", current), ("base", base), ("other", other)];
+    for (name, content) in files {
+        std::fs::write(dir.path().join(name), content)?;
+    }
+
+    let output = Command::new("git")
+        .args(["merge-file", "-p", "--quiet", "current", "base", "other"])
+        .current_dir(dir.path())
+        .output()
+        .context("Failed to run `git merge-file`")?;
+
+    // The exit code is the number of conflicts, or negative on error.
+    match output.status.code() {
+        Some(0) => Ok(Some(String::from_utf8(output.stdout)?)),
+        Some(code) if code > 0 => Ok(None),
+        _ => bail!(
+            "`git merge-file` failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+    }
+}
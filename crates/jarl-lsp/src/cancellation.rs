@@ -0,0 +1,188 @@
+//! Cancellation of lint tasks that are outdated
+//!
+//! Linting a document, and especially the other files of its package, can
+//! take a while. When the document is saved again before the lint is done,
+//! its result is already outdated, so the worker threads should stop as soon
+//! as possible instead of publishing stale diagnostics and keeping the CPU
+//! busy.
+//!
+//! Editing a document doesn't cancel its lint: documents are only linted on
+//! save, so the lint of the last save would never be replaced.
+
+use lsp_types::Url;
+use rustc_hash::FxHashMap;
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+/// Time to wait before starting a lint task, so that a burst of saves (e.g.
+/// "Save All" or auto-save) only lints each document once.
+pub const LINT_DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// Flag shared between the main loop and a worker thread. The main loop
+/// cancels it, the worker checks it between the steps of the task.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Whether both tokens belong to the same task.
+    fn is_same(&self, other: &CancellationToken) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Tokens of the lint tasks in progress. Starting a new task for a document
+/// cancels the previous one.
+#[derive(Default)]
+pub struct LintTasks {
+    /// Lint of each open document
+    documents: FxHashMap<Url, CancellationToken>,
+    /// Lint of the other files of a package, by `R/` directory. It is shared
+    /// by all the documents of the package since each save lints all of them.
+    packages: FxHashMap<PathBuf, CancellationToken>,
+}
+
+impl LintTasks {
+    /// Cancel the lint in progress for `uri`, if any, and return the token of
+    /// the new one.
+    pub fn start_document(&mut self, uri: &Url) -> CancellationToken {
+        let token = CancellationToken::default();
+        if let Some(previous) = self.documents.insert(uri.clone(), token.clone()) {
+            previous.cancel();
+        }
+        token
+    }
+
+    /// Cancel the lint in progress of the other files of the package
+    /// containing `uri`, if any, and return the token of the new one.
+    pub fn start_package(&mut self, uri: &Url) -> CancellationToken {
+        let token = CancellationToken::default();
+        let Some(dir) = package_dir(uri) else {
+            return token;
+        };
+        if let Some(previous) = self.packages.insert(dir, token.clone()) {
+            previous.cancel();
+        }
+        token
+    }
+
+    /// Forget the tokens of a task that is done, unless a newer task was
+    /// started for the same document or package in the meantime.
+    pub fn finish(
+        &mut self,
+        uri: &Url,
+        cancel: &CancellationToken,
+        cancel_package: &CancellationToken,
+    ) {
+        if self
+            .documents
+            .get(uri)
+            .is_some_and(|token| token.is_same(cancel))
+        {
+            self.documents.remove(uri);
+        }
+        if let Some(dir) = package_dir(uri)
+            && self
+                .packages
+                .get(&dir)
+                .is_some_and(|token| token.is_same(cancel_package))
+        {
+            self.packages.remove(&dir);
+        }
+    }
+
+    /// Cancel the lint in progress for `uri`, e.g. because it was closed.
+    pub fn cancel_document(&mut self, uri: &Url) {
+        if let Some(previous) = self.documents.remove(uri) {
+            previous.cancel();
+        }
+    }
+}
+
+/// Key of the package tasks: the directory containing `uri`.
+fn package_dir(uri: &Url) -> Option<PathBuf> {
+    uri.to_file_path()
+        .ok()
+        .and_then(|path| path.parent().map(PathBuf::from))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(path: &str) -> Url {
+        Url::from_file_path(std::env::temp_dir().join(path)).unwrap()
+    }
+
+    #[test]
+    fn test_start_document_cancels_previous_task() {
+        let mut tasks = LintTasks::default();
+        let first = tasks.start_document(&url("pkg/R/a.R"));
+        let other = tasks.start_document(&url("pkg/R/b.R"));
+        let second = tasks.start_document(&url("pkg/R/a.R"));
+
+        assert!(first.is_cancelled());
+        assert!(!second.is_cancelled());
+        assert!(!other.is_cancelled());
+    }
+
+    #[test]
+    fn test_start_package_cancels_task_of_same_package() {
+        let mut tasks = LintTasks::default();
+        let first = tasks.start_package(&url("pkg/R/a.R"));
+        let other_package = tasks.start_package(&url("other/R/a.R"));
+        let second = tasks.start_package(&url("pkg/R/b.R"));
+
+        assert!(first.is_cancelled());
+        assert!(!second.is_cancelled());
+        assert!(!other_package.is_cancelled());
+    }
+
+    #[test]
+    fn test_cancel_document() {
+        let mut tasks = LintTasks::default();
+        let token = tasks.start_document(&url("pkg/R/a.R"));
+        tasks.cancel_document(&url("pkg/R/b.R"));
+        assert!(!token.is_cancelled());
+        tasks.cancel_document(&url("pkg/R/a.R"));
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn test_finish_forgets_done_tasks() {
+        let mut tasks = LintTasks::default();
+        let cancel = tasks.start_document(&url("pkg/R/a.R"));
+        let cancel_package = tasks.start_package(&url("pkg/R/a.R"));
+        tasks.finish(&url("pkg/R/a.R"), &cancel, &cancel_package);
+
+        assert!(tasks.documents.is_empty());
+        assert!(tasks.packages.is_empty());
+        assert!(!cancel.is_cancelled());
+    }
+
+    #[test]
+    fn test_finish_keeps_newer_tasks() {
+        let mut tasks = LintTasks::default();
+        let old = tasks.start_document(&url("pkg/R/a.R"));
+        let old_package = tasks.start_package(&url("pkg/R/a.R"));
+        let new = tasks.start_document(&url("pkg/R/a.R"));
+        let new_package = tasks.start_package(&url("pkg/R/b.R"));
+        tasks.finish(&url("pkg/R/a.R"), &old, &old_package);
+
+        // The newer tasks can still be cancelled.
+        tasks.cancel_document(&url("pkg/R/a.R"));
+        assert!(new.is_cancelled());
+        tasks.start_package(&url("pkg/R/a.R"));
+        assert!(new_package.is_cancelled());
+    }
+}
@@ -0,0 +1,173 @@
+//! Settings sent by the editor
+//!
+//! Editors can configure Jarl without a `jarl.toml` file, either with the
+//! `settings` field of the initialization options or with
+//! `workspace/didChangeConfiguration`. These settings are merged with the
+//! `jarl.toml` discovered for each document in the same way as the CLI
+//! arguments: `select`, `extendSelect`, and `ignore` behave like `--select`,
+//! `--extend-select`, and `--ignore`, and `ruleOptions` replaces the
+//! `[lint.<rule>]` tables of `jarl.toml`.
+
+use anyhow::{Result, anyhow};
+use serde::Deserialize;
+
+use jarl_core::config::parse_rules_cli;
+use jarl_core::rule_options::ResolvedRuleOptions;
+use jarl_core::rule_set::Rule;
+use jarl_core::toml::LinterTomlOptions;
+
+/// Settings sent by the editor
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ClientSettings {
+    /// Rules to select, like `--select`
+    #[serde(default)]
+    pub select: Vec<String>,
+    /// Rules to add to the selection, like `--extend-select`
+    #[serde(default)]
+    pub extend_select: Vec<String>,
+    /// Rules to ignore, like `--ignore`
+    #[serde(default)]
+    pub ignore: Vec<String>,
+    /// Whether the "Fix all" action also applies unsafe fixes
+    #[serde(default)]
+    pub unsafe_fixes: bool,
+    /// Options of the rules, with the same format as the `[lint.<rule>]`
+    /// tables of `jarl.toml`
+    #[serde(default, deserialize_with = "deserialize_rule_options")]
+    pub rule_options: Option<LinterTomlOptions>,
+}
+
+impl ClientSettings {
+    /// Parse and validate the settings sent by the editor. They can be
+    /// wrapped in a `jarl` object, as sent by VS Code for instance.
+    pub fn from_value(value: serde_json::Value) -> Result<Self> {
+        let value = match value {
+            serde_json::Value::Object(mut object) if object.contains_key("jarl") => {
+                object.remove("jarl").unwrap_or_default()
+            }
+            serde_json::Value::Null => return Ok(Self::default()),
+            value => value,
+        };
+
+        let settings: Self = serde_json::from_value(value)?;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    /// Check that the rules and the rule options are valid, so that an error
+    /// is reported once instead of every time a document is linted.
+    fn validate(&self) -> Result<()> {
+        parse_rules_cli(&self.select(), &self.extend_select(), &self.ignore())?;
+        if let Some(rule_options) = &self.rule_options {
+            ResolvedRuleOptions::default().apply(&rule_options.rule_options())?;
+        }
+        Ok(())
+    }
+
+    /// Rules to select, in the format of `--select`
+    pub fn select(&self) -> String {
+        self.select.join(",")
+    }
+
+    /// Rules to add to the selection, in the format of `--extend-select`
+    pub fn extend_select(&self) -> String {
+        self.extend_select.join(",")
+    }
+
+    /// Rules to ignore, in the format of `--ignore`
+    pub fn ignore(&self) -> String {
+        self.ignore.join(",")
+    }
+}
+
+/// Only accept rule names as keys of `ruleOptions`, since the other fields
+/// of `[lint]` (e.g. `select`) have their own setting.
+fn deserialize_rule_options<'de, D>(deserializer: D) -> Result<Option<LinterTomlOptions>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    let Some(object) =
+        Option::<serde_json::Map<String, serde_json::Value>>::deserialize(deserializer)?
+    else {
+        return Ok(None);
+    };
+    if let Some(key) = object.keys().find(|key| Rule::from_name(key).is_none()) {
+        return Err(D::Error::custom(anyhow!(
+            "Unknown rule in `ruleOptions`: `{key}`"
+        )));
+    }
+    serde_json::from_value(serde_json::Value::Object(object))
+        .map(Some)
+        .map_err(D::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_settings() {
+        let settings = ClientSettings::from_value(serde_json::Value::Null).unwrap();
+        assert!(settings.select.is_empty());
+        assert!(!settings.unsafe_fixes);
+        assert!(settings.rule_options.is_none());
+    }
+
+    #[test]
+    fn test_settings_in_jarl_object() {
+        let settings = ClientSettings::from_value(serde_json::json!({
+            "jarl": {
+                "select": ["any_is_na", "PERF"],
+                "ignore": ["sort"],
+                "unsafeFixes": true,
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(settings.select(), "any_is_na,PERF");
+        assert_eq!(settings.extend_select(), "");
+        assert_eq!(settings.ignore(), "sort");
+        assert!(settings.unsafe_fixes);
+    }
+
+    #[test]
+    fn test_settings_rule_options() {
+        let settings = ClientSettings::from_value(serde_json::json!({
+            "ruleOptions": {
+                "assignment": { "operator": "=" }
+            }
+        }))
+        .unwrap();
+
+        let rule_options = settings.rule_options.unwrap();
+        assert!(rule_options.rule_options().assignment.is_some());
+        assert!(rule_options.rule_options().quotes.is_none());
+    }
+
+    #[test]
+    fn test_invalid_settings() {
+        let unknown_setting = ClientSettings::from_value(serde_json::json!({ "foo": true }));
+        assert!(unknown_setting.is_err());
+
+        let unknown_rule = ClientSettings::from_value(serde_json::json!({ "select": ["foo"] }));
+        assert!(unknown_rule.is_err());
+
+        let not_a_rule = ClientSettings::from_value(serde_json::json!({
+            "ruleOptions": { "select": ["any_is_na"] }
+        }));
+        assert!(
+            not_a_rule
+                .unwrap_err()
+                .to_string()
+                .contains("Unknown rule in `ruleOptions`: `select`")
+        );
+
+        let invalid_option = ClientSettings::from_value(serde_json::json!({
+            "ruleOptions": { "split_apply_combine": { "backend": "polars" } }
+        }));
+        assert!(invalid_option.is_err());
+    }
+}
@@ -0,0 +1,208 @@
+//! Hot-reload of `jarl.toml` files
+//!
+//! The server asks the client to watch configuration files and re-lints open
+//! documents when one of them changes. This module contains the pieces that
+//! don't depend on the session state: the watcher registration, the resolution
+//! of the rules enabled by a configuration file, and the summary shown to the
+//! user after a reload.
+
+use anyhow::Result;
+use lsp_types::{
+    DidChangeWatchedFilesRegistrationOptions, FileSystemWatcher, GlobPattern, MessageType,
+    Registration, notification::DidChangeWatchedFiles, notification::Notification as _,
+};
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use jarl_core::config::build_config;
+use jarl_core::toml::parse_jarl_toml;
+
+use crate::client_settings::ClientSettings;
+use crate::lint::lsp_args_config;
+
+/// Identifier of the `workspace/didChangeWatchedFiles` registration.
+pub const CONFIG_WATCHER_ID: &str = "jarl-config-watcher";
+
+/// Names of the configuration files that Jarl looks for.
+const CONFIG_FILE_NAMES: &[&str] = &["jarl.toml", ".jarl.toml"];
+
+/// Build the registration asking the client to watch all configuration files
+/// in the workspace.
+pub fn config_watcher_registration() -> Result<Registration> {
+    let watchers = CONFIG_FILE_NAMES
+        .iter()
+        .map(|name| FileSystemWatcher {
+            glob_pattern: GlobPattern::String(format!("**/{name}")),
+            kind: None, // Create, change, and delete
+        })
+        .collect();
+
+    Ok(Registration {
+        id: CONFIG_WATCHER_ID.to_string(),
+        method: DidChangeWatchedFiles::METHOD.to_string(),
+        register_options: Some(serde_json::to_value(
+            DidChangeWatchedFilesRegistrationOptions { watchers },
+        )?),
+    })
+}
+
+/// Whether `path` is a Jarl configuration file.
+pub fn is_config_file(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| CONFIG_FILE_NAMES.contains(&name))
+}
+
+/// Names of the rules enabled by the configuration file at `config_path`.
+pub fn enabled_rules(config_path: &Path) -> Result<BTreeSet<String>> {
+    let root = config_path.parent().unwrap_or(config_path);
+    let settings = parse_jarl_toml(config_path)?.into_settings(root)?;
+    let config = build_config(
+        &lsp_args_config(root, &ClientSettings::default()),
+        Some(&settings),
+        vec![root.to_path_buf()],
+    )?;
+
+    Ok(config
+        .rules
+        .iter()
+        .map(|rule| rule.name().to_string())
+        .collect())
+}
+
+/// Outcome of reloading a configuration file after it changed on disk.
+#[derive(Debug, PartialEq, Eq)]
+pub enum ConfigReload {
+    /// The file wasn't used before (e.g. it was just created).
+    Loaded,
+    /// The file was reloaded, possibly with a different set of rules.
+    Changed {
+        enabled: Vec<String>,
+        disabled: Vec<String>,
+    },
+    /// The file was deleted.
+    Removed,
+    /// The file couldn't be parsed, the error message is included.
+    Invalid(String),
+}
+
+impl ConfigReload {
+    /// Compare the rules enabled before and after the reload.
+    pub fn from_rules(before: &BTreeSet<String>, after: &BTreeSet<String>) -> Self {
+        Self::Changed {
+            enabled: after.difference(before).cloned().collect(),
+            disabled: before.difference(after).cloned().collect(),
+        }
+    }
+
+    /// Message summarizing the reload, to be shown in the editor.
+    pub fn message(&self, config_path: &Path) -> (String, MessageType) {
+        let path = config_path.display();
+        match self {
+            Self::Loaded => (
+                format!("Jarl loaded the configuration from '{path}'"),
+                MessageType::INFO,
+            ),
+            Self::Changed { enabled, disabled } => {
+                let mut changes = Vec::new();
+                if !enabled.is_empty() {
+                    changes.push(format!("enabled {}", format_rules(enabled)));
+                }
+                if !disabled.is_empty() {
+                    changes.push(format!("disabled {}", format_rules(disabled)));
+                }
+                let message = if changes.is_empty() {
+                    format!("Jarl reloaded the configuration from '{path}'")
+                } else {
+                    format!(
+                        "Jarl reloaded the configuration from '{path}': {}",
+                        changes.join("; ")
+                    )
+                };
+                (message, MessageType::INFO)
+            }
+            Self::Removed => (
+                format!("Jarl no longer uses the configuration from '{path}' (file removed)"),
+                MessageType::INFO,
+            ),
+            Self::Invalid(error) => (
+                format!("Jarl couldn't reload the configuration from '{path}': {error}"),
+                MessageType::ERROR,
+            ),
+        }
+    }
+}
+
+fn format_rules(rules: &[String]) -> String {
+    rules
+        .iter()
+        .map(|rule| format!("`{rule}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn rules(names: &[&str]) -> BTreeSet<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_is_config_file() {
+        assert!(is_config_file(Path::new("/project/jarl.toml")));
+        assert!(is_config_file(Path::new("/project/.jarl.toml")));
+        assert!(!is_config_file(Path::new("/project/air.toml")));
+        assert!(!is_config_file(Path::new("/project/jarl.toml.bak")));
+    }
+
+    #[test]
+    fn test_enabled_rules() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("jarl.toml");
+
+        fs::write(&config_path, "[lint]\nselect = [\"any_is_na\", \"seq\"]\n").unwrap();
+        assert_eq!(
+            enabled_rules(&config_path).unwrap(),
+            rules(&["any_is_na", "seq"])
+        );
+
+        fs::write(&config_path, "[lint]\nselect = [\"not_a_rule\"]\n").unwrap();
+        assert!(enabled_rules(&config_path).is_err());
+    }
+
+    #[test]
+    fn test_reload_message() {
+        let path = Path::new("jarl.toml");
+
+        let reload = ConfigReload::from_rules(
+            &rules(&["any_is_na", "seq"]),
+            &rules(&["any_duplicated", "any_is_na", "browser"]),
+        );
+        assert_eq!(
+            reload.message(path),
+            (
+                "Jarl reloaded the configuration from 'jarl.toml': enabled `any_duplicated`, \
+                 `browser`; disabled `seq`"
+                    .to_string(),
+                MessageType::INFO
+            )
+        );
+
+        let reload = ConfigReload::from_rules(&rules(&["seq"]), &rules(&["seq"]));
+        assert_eq!(
+            reload.message(path),
+            (
+                "Jarl reloaded the configuration from 'jarl.toml'".to_string(),
+                MessageType::INFO
+            )
+        );
+
+        let reload = ConfigReload::Invalid("unknown field".to_string());
+        assert_eq!(reload.message(path).1, MessageType::ERROR);
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! A minimal LSP server focused on providing real-time lint diagnostics and code actions.
 //! This implementation handles document management, diagnostic publishing, and quick fixes
-//! for automatic issue resolution.
+//! for automatic issue resolution. It also provides document symbols and folding ranges
+//! computed from the syntax tree.
 
 use anyhow::{Context, Result};
 use std::num::NonZeroUsize;
@@ -12,9 +13,13 @@ pub use document::{DocumentKey, PositionEncoding, TextDocument};
 pub use server::Server;
 pub use session::{DocumentSnapshot, Session};
 
+pub mod cancellation;
 pub mod client;
+pub mod client_settings;
+pub mod config_watch;
 pub mod document;
 pub mod lint;
+pub mod outline;
 pub mod server;
 pub mod session;
 pub mod utils;
@@ -22,6 +27,8 @@ pub mod utils;
 #[allow(dead_code)]
 pub(crate) const SERVER_NAME: &str = "jarl";
 pub(crate) const DIAGNOSTIC_SOURCE: &str = "Jarl";
+/// Kind of the code action that applies all safe fixes of a document
+pub(crate) const FIX_ALL_CODE_ACTION_KIND: &str = "source.fixAll.jarl";
 
 /// Common result type used throughout the LSP implementation
 pub(crate) type LspResult<T> = anyhow::Result<T>;
@@ -29,8 +36,9 @@ pub(crate) type LspResult<T> = anyhow::Result<T>;
 /// Main entry point for running the Jarl LSP server
 ///
 /// This function sets up a minimal LSP server that provides real-time
-/// lint diagnostics and code actions as you type in your editor.
-pub fn run() -> Result<()> {
+/// lint diagnostics and code actions as you type in your editor. If
+/// `read_only` is true, code actions that modify files are disabled.
+pub fn run(read_only: bool) -> Result<()> {
     tracing::info!("Starting Jarl Language Server v{}", version());
 
     // Set up worker threads for background linting
@@ -44,8 +52,8 @@ pub fn run() -> Result<()> {
     let (connection, io_threads) = lsp_server::Connection::stdio();
 
     // Start the server
-    let server =
-        Server::new(worker_threads, connection).context("Failed to create Jarl LSP server")?;
+    let server = Server::new(worker_threads, connection, read_only)
+        .context("Failed to create Jarl LSP server")?;
 
     let server_result = server.run();
 
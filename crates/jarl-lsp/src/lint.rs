@@ -4,23 +4,32 @@
 //! It handles diagnostics, code actions, and fixes for automatic issue resolution.
 
 use anyhow::{Result, anyhow};
-use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range, Url};
 use serde::{Deserialize, Serialize};
 
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use crate::DIAGNOSTIC_SOURCE;
-use crate::document::PositionEncoding;
+use crate::cancellation::CancellationToken;
+use crate::client_settings::ClientSettings;
+use crate::document::{DocumentVersion, PositionEncoding};
 use crate::session::DocumentSnapshot;
 use crate::utils::should_exclude_file_based_on_settings;
 
 use air_workspace::resolve::PathResolver;
 use jarl_core::check::get_checks;
-use jarl_core::config::{ArgsConfig, build_config};
-use jarl_core::diagnostic::Diagnostic as JarlDiagnostic;
+use jarl_core::config::{ArgsConfig, Config, build_config};
+use jarl_core::diagnostic::{Diagnostic as JarlDiagnostic, Severity};
 use jarl_core::discovery::{DiscoveredSettings, discover_settings};
+use jarl_core::fix::{MAX_FIX_ITERATIONS, apply_fixes};
 use jarl_core::fs::{has_r_extension, relativize_path};
-use jarl_core::package::{is_in_r_package, make_package_analysis, summarize_package_info};
+use jarl_core::package::{
+    FilePackageInfo, PackageAnalysis, PackageContext, is_in_r_package, make_package_analysis,
+    summarize_package_info,
+};
+use jarl_core::rule_set::Rule;
 use jarl_core::settings::Settings;
 
 /// Fix information that can be attached to a diagnostic for code actions
@@ -52,6 +61,9 @@ pub struct LintOutput {
     /// Package names whose cached metadata was refreshed because they changed
     /// on disk (e.g. after `install.packages()`). Empty most of the time.
     pub refreshed_packages: Vec<String>,
+    /// Diagnostics of the other files of the package. Only filled by
+    /// [lint_document_and_package].
+    pub package_files: Vec<PackageFileDiagnostics>,
 }
 
 /// Main entry point for linting a document
@@ -60,16 +72,48 @@ pub struct LintOutput {
 /// for highlighting issues in the editor. The diagnostics include fix information
 /// that can be used for code actions if needed.
 pub fn lint_document(snapshot: &DocumentSnapshot) -> Result<LintOutput> {
+    run_lint(snapshot, None)
+}
+
+/// Lint a document and the other files of the R package containing it
+///
+/// Rules such as `duplicated_function_definition` and `unused_function`
+/// depend on all the files of the package, so a change in one file can add or
+/// remove diagnostics in the others. In addition to linting the document, this
+/// lints every other `.R` file of the `R/` folder so that their diagnostics are
+/// up to date even if they are not open in the editor. Files in
+/// `open_documents` are linted with their content in the editor, the other
+/// ones with their content on disk.
+///
+/// The other files are only linted if the document is in the `R/` folder of a
+/// package and if one of the package-wide rules is enabled. Linting them stops
+/// as soon as `cancel` is cancelled, in which case `package_files` is empty.
+pub fn lint_document_and_package(
+    snapshot: &DocumentSnapshot,
+    open_documents: &[DocumentSnapshot],
+    cancel: &CancellationToken,
+) -> Result<LintOutput> {
+    run_lint(snapshot, Some((open_documents, cancel)))
+}
+
+fn run_lint(
+    snapshot: &DocumentSnapshot,
+    package: Option<(&[DocumentSnapshot], &CancellationToken)>,
+) -> Result<LintOutput> {
     let content = snapshot.content();
-    let file_path = snapshot.file_path();
     let encoding = snapshot.position_encoding();
 
+    let Some(context) = LintContext::new(snapshot)? else {
+        return Ok(LintOutput {
+            diagnostics: Vec::new(),
+            unused_fn_hidden_count: 0,
+            refreshed_packages: Vec::new(),
+            package_files: Vec::new(),
+        });
+    };
+
     // Run the actual linting
-    let LintInternalOutput {
-        diagnostics: jarl_diagnostics,
-        unused_fn_hidden_count,
-        refreshed_packages,
-    } = run_jarl_linting(content, file_path.as_deref(), snapshot)?;
+    let jarl_diagnostics = context.lint(content, &context.file_path)?;
 
     // Convert to LSP diagnostics with fix information
     let mut lsp_diagnostics = Vec::new();
@@ -78,161 +122,312 @@ pub fn lint_document(snapshot: &DocumentSnapshot) -> Result<LintOutput> {
         lsp_diagnostics.push(lsp_diagnostic);
     }
 
+    let package_files = match package {
+        Some((open_documents, cancel)) if context.has_package_wide_rules() => {
+            context.lint_package_files(open_documents, encoding, cancel)?
+        }
+        _ => Vec::new(),
+    };
+
     Ok(LintOutput {
         diagnostics: lsp_diagnostics,
-        unused_fn_hidden_count,
-        refreshed_packages,
+        unused_fn_hidden_count: context.unused_fn_hidden_count,
+        refreshed_packages: context.refreshed_packages,
+        package_files,
     })
 }
 
-struct LintInternalOutput {
-    diagnostics: Vec<JarlDiagnostic>,
-    unused_fn_hidden_count: usize,
-    refreshed_packages: Vec<String>,
-}
-
-/// Run the Jarl linting engine on the given content
-fn run_jarl_linting(
-    content: &str,
-    file_path: Option<&Path>,
-    snapshot: &DocumentSnapshot,
-) -> Result<LintInternalOutput> {
-    let empty = LintInternalOutput {
-        diagnostics: Vec::new(),
-        unused_fn_hidden_count: 0,
-        refreshed_packages: Vec::new(),
+/// Apply all safe fixes to the document until there is nothing left to fix
+///
+/// Fixes can overlap or create new violations, so the content is linted and
+/// fixed again until it doesn't change, like `jarl check --fix`. Unsafe fixes
+/// are also applied if the editor settings enable `unsafeFixes`. Returns the
+/// fixed content, or `None` if there was nothing to fix.
+pub fn fix_all(snapshot: &DocumentSnapshot) -> Result<Option<String>> {
+    let Some(context) = LintContext::new(snapshot)? else {
+        return Ok(None);
     };
 
-    let file_path = match file_path {
-        Some(path) => path,
-        None => {
-            tracing::warn!("No file path provided for linting");
-            return Ok(empty);
+    let original = snapshot.content();
+    let mut content = original.to_string();
+    for _ in 0..MAX_FIX_ITERATIONS {
+        let fixes: Vec<JarlDiagnostic> = context
+            .lint(&content, &context.file_path)?
+            .into_iter()
+            .filter(|diagnostic| {
+                diagnostic.has_safe_fix()
+                    || (context.config.apply_unsafe_fixes && diagnostic.has_unsafe_fix())
+            })
+            .collect();
+        if fixes.is_empty() {
+            break;
         }
-    };
 
-    if file_path.to_str().is_none() {
-        tracing::warn!("File path contains invalid UTF-8: {:?}", file_path);
-        return Ok(empty);
+        let (fixed, _) = apply_fixes(&fixes, &content);
+        // No progress was made (e.g. all fixes overlap), stop to avoid an
+        // infinite loop.
+        if fixed == content {
+            break;
+        }
+        content = fixed;
     }
 
-    // Discover settings from the actual file path.
-    let actual_file_path = vec![file_path.to_string_lossy().to_string()];
-    let mut resolver = PathResolver::new(Settings::default());
-    for DiscoveredSettings { directory, settings, .. } in discover_settings(&actual_file_path)? {
-        resolver.add(&directory, settings);
-        tracing::debug!("Discovered settings from directory: {:?}", directory);
-    }
+    Ok((content != original).then_some(content))
+}
 
-    // Check if the file should be excluded based on settings in jarl.toml
-    // (`exclude` or `default-exclude`).
-    if should_exclude_file_based_on_settings(file_path, &resolver) {
-        tracing::debug!("Skipping linting for excluded file: {:?}", file_path);
-        return Ok(empty);
-    }
+/// Diagnostics of another file of the package, computed by
+/// [lint_document_and_package].
+pub struct PackageFileDiagnostics {
+    pub uri: Url,
+    /// Version of the document if it is open in the editor, `None` if it was
+    /// linted from the disk.
+    pub version: Option<DocumentVersion>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Everything needed to lint the files of a directory: the settings, the
+/// linter config, and the package-level analysis. Files of the same package
+/// share the same context, so it is only computed once by
+/// [lint_document_and_package].
+struct LintContext {
+    file_path: PathBuf,
+    resolver: PathResolver<Settings>,
+    config: Config,
+    pkg: PackageAnalysis,
+    pkg_contexts: HashMap<PathBuf, PackageContext>,
+    file_pkg_info: HashMap<PathBuf, FilePackageInfo>,
+    /// Number of unused_function diagnostics hidden because the package-wide
+    /// count exceeded `threshold-ignore`. Zero if none were hidden.
+    unused_fn_hidden_count: usize,
+    refreshed_packages: Vec<String>,
+}
 
-    let check_config = ArgsConfig {
+/// Arguments used to build the linter [Config] in the LSP. There are no CLI
+/// arguments, but the settings sent by the editor play the same role.
+pub(crate) fn lsp_args_config(file_path: &Path, settings: &ClientSettings) -> ArgsConfig {
+    ArgsConfig {
         files: vec![file_path.to_path_buf()],
         fix: false,
-        unsafe_fixes: false,
+        unsafe_fixes: settings.unsafe_fixes,
+        unsafe_fixes_rules: String::new(),
         fix_only: false,
-        select: "".to_string(),
-        extend_select: "".to_string(),
-        ignore: "".to_string(),
+        select: settings.select(),
+        extend_select: settings.extend_select(),
+        ignore: settings.ignore(),
         min_r_version: None,
         allow_dirty: false,
         allow_no_vcs: false,
+        respect_staged: false,
         assignment: None,
-    };
+        preview: false,
+        profile: None,
+    }
+}
+
+impl LintContext {
+    /// Build the context used to lint the document. Returns `None` if the
+    /// document can't or shouldn't be linted (no file path, excluded file).
+    fn new(snapshot: &DocumentSnapshot) -> Result<Option<Self>> {
+        let file_path = match snapshot.file_path() {
+            Some(path) => path,
+            None => {
+                tracing::warn!("No file path provided for linting");
+                return Ok(None);
+            }
+        };
 
-    let toml_settings = resolver.items().first().map(|item| item.value());
-    let mut config = build_config(&check_config, toml_settings, vec![file_path.to_path_buf()])?;
-
-    let mut refreshed_packages = Vec::new();
-    if config.rules_to_apply.has_package_specific_rules() {
-        let pkgs = config.rules_to_apply.pkg_names_from_category();
-        // Get or create a per-project-root cache (spawns Rscript once per root).
-        let package_cache = snapshot.get_or_create_package_cache(&pkgs);
-        // Check if any tracked packages have changed on disk (cheap stat()).
-        if let Some(ref cache) = package_cache {
-            refreshed_packages = cache.refresh_if_stale(&pkgs);
+        if file_path.to_str().is_none() {
+            tracing::warn!("File path contains invalid UTF-8: {:?}", file_path);
+            return Ok(None);
         }
-        config.package_cache = package_cache;
-    }
-
-    // Compute package-level analysis using the real file's sibling R files.
-    let analysis_paths =
-        collect_sibling_r_files(file_path).unwrap_or_else(|| vec![file_path.to_path_buf()]);
-    let (pkg_contexts, file_pkg_info) = summarize_package_info(&analysis_paths);
-    let namespace_contents: std::collections::HashMap<PathBuf, String> = pkg_contexts
-        .iter()
-        .filter_map(|(root, ctx)| {
-            ctx.namespace_content
-                .as_ref()
-                .map(|c| (root.clone(), c.clone()))
-        })
-        .collect();
-    let pkg = make_package_analysis(&analysis_paths, &config, &namespace_contents);
-
-    // Call get_checks directly with the in-memory content and the real
-    // (relativized) file path, avoiding the old tempfile round-trip.
-    // `get_checks` resolves `[lint.per-file-ignores]` for the file itself.
-    let rel_path = PathBuf::from(relativize_path(file_path));
-    let mut diagnostics = get_checks(
-        content,
-        &rel_path,
-        &config,
-        &pkg,
-        &pkg_contexts,
-        &file_pkg_info,
-    )?;
-
-    // Hide unused_function diagnostics when the package-wide count exceeds
-    // the threshold, matching the CLI behaviour. The LSP never passes
-    // `--select unused_function` so we only check the toml settings.
-    let unused_fn_hidden_count = {
-        let explicitly_selected = resolver.items().iter().any(|item| {
-            let linter = &item.value().linter;
-            linter
-                .select
-                .iter()
-                .chain(linter.extend_select.iter())
-                .flatten()
-                .any(|s| s == "unused_function")
-        });
 
-        if explicitly_selected {
-            0
-        } else {
-            let total_unused: usize = pkg.unused_functions.values().map(|v| v.len()).sum();
-            let threshold = resolver
-                .items()
-                .iter()
-                .map(|item| {
-                    item.value()
-                        .linter
-                        .rule_options
-                        .unused_function
-                        .threshold_ignore
-                })
-                .min()
-                .unwrap_or(50);
-
-            if total_unused > threshold {
-                diagnostics.retain(|d| d.message.name != "unused_function");
-                total_unused
-            } else {
+        // Discover settings from the actual file path.
+        let actual_file_path = vec![file_path.to_string_lossy().to_string()];
+        let mut resolver = PathResolver::new(Settings::default());
+        for DiscoveredSettings { directory, settings, .. } in discover_settings(&actual_file_path)?
+        {
+            resolver.add(&directory, settings);
+            tracing::debug!("Discovered settings from directory: {:?}", directory);
+        }
+
+        // Check if the file should be excluded based on settings in jarl.toml
+        // (`exclude` or `default-exclude`).
+        if should_exclude_file_based_on_settings(&file_path, &resolver) {
+            tracing::debug!("Skipping linting for excluded file: {:?}", file_path);
+            return Ok(None);
+        }
+
+        let client_settings = snapshot.client_settings();
+        let check_config = lsp_args_config(&file_path, client_settings);
+
+        let toml_settings = resolver.items().first().map(|item| item.value());
+        let mut config = build_config(&check_config, toml_settings, vec![file_path.clone()])?;
+        if let Some(rule_options) = &client_settings.rule_options {
+            Arc::make_mut(&mut config.rule_options).apply(&rule_options.rule_options())?;
+        }
+
+        let mut refreshed_packages = Vec::new();
+        if config.rules_to_apply.has_package_specific_rules() {
+            let pkgs = config.rules_to_apply.pkg_names_from_category();
+            // Get or create a per-project-root cache (spawns Rscript once per root).
+            let package_cache = snapshot.get_or_create_package_cache(&pkgs);
+            // Check if any tracked packages have changed on disk (cheap stat()).
+            if let Some(ref cache) = package_cache {
+                refreshed_packages = cache.refresh_if_stale(&pkgs, config.file_system.as_ref());
+            }
+            config.package_cache = package_cache;
+        }
+
+        // Compute package-level analysis using the real file's sibling R files.
+        let analysis_paths =
+            collect_sibling_r_files(&file_path).unwrap_or_else(|| vec![file_path.clone()]);
+        let (pkg_contexts, file_pkg_info) =
+            summarize_package_info(&analysis_paths, config.file_system.as_ref());
+        let namespace_contents: HashMap<PathBuf, String> = pkg_contexts
+            .iter()
+            .filter_map(|(root, ctx)| {
+                ctx.namespace_content
+                    .as_ref()
+                    .map(|c| (root.clone(), c.clone()))
+            })
+            .collect();
+        let pkg = make_package_analysis(&analysis_paths, &config, &namespace_contents);
+
+        // Hide unused_function diagnostics when the package-wide count exceeds
+        // the threshold, matching the CLI behaviour. The LSP never passes
+        // `--select unused_function` so we only check the toml settings.
+        let unused_fn_hidden_count = {
+            let explicitly_selected = resolver.items().iter().any(|item| {
+                let linter = &item.value().linter;
+                linter
+                    .select
+                    .iter()
+                    .chain(linter.extend_select.iter())
+                    .flatten()
+                    .any(|s| s == "unused_function")
+            });
+
+            if explicitly_selected {
                 0
+            } else {
+                let total_unused: usize = pkg.unused_functions.values().map(|v| v.len()).sum();
+                let threshold = resolver
+                    .items()
+                    .iter()
+                    .map(|item| {
+                        item.value()
+                            .linter
+                            .rule_options
+                            .unused_function
+                            .threshold_ignore
+                    })
+                    .min()
+                    .unwrap_or(50);
+
+                if total_unused > threshold {
+                    total_unused
+                } else {
+                    0
+                }
             }
+        };
+
+        Ok(Some(Self {
+            file_path,
+            resolver,
+            config,
+            pkg,
+            pkg_contexts,
+            file_pkg_info,
+            unused_fn_hidden_count,
+            refreshed_packages,
+        }))
+    }
+
+    /// Whether a rule that depends on all the files of a package is enabled.
+    fn has_package_wide_rules(&self) -> bool {
+        let rules = &self.config.rules_to_apply;
+        rules.contains(&Rule::DuplicatedFunctionDefinition) || rules.contains(&Rule::UnusedFunction)
+    }
+
+    /// Lint the `.R` files of the package other than the document. Returns
+    /// nothing if `cancel` is cancelled before all of them are linted.
+    fn lint_package_files(
+        &self,
+        open_documents: &[DocumentSnapshot],
+        encoding: PositionEncoding,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<PackageFileDiagnostics>> {
+        let Some(package_files) = collect_sibling_r_files(&self.file_path) else {
+            return Ok(Vec::new());
+        };
+
+        let mut output = Vec::new();
+        for path in package_files {
+            if cancel.is_cancelled() {
+                tracing::debug!("Cancelled the lint of the other files of the package");
+                return Ok(Vec::new());
+            }
+            if path == self.file_path {
+                continue;
+            }
+            let Ok(uri) = Url::from_file_path(&path) else {
+                continue;
+            };
+
+            let open_document = open_documents.iter().find(|doc| doc.uri() == &uri);
+            let (content, version) = match open_document {
+                Some(doc) => (doc.content().to_string(), Some(doc.version())),
+                None => match std::fs::read_to_string(&path) {
+                    Ok(content) => (content, None),
+                    Err(e) => {
+                        tracing::debug!("Failed to read {}: {}", path.display(), e);
+                        continue;
+                    }
+                },
+            };
+
+            let diagnostics = self
+                .lint(&content, &path)?
+                .iter()
+                .map(|diagnostic| convert_to_lsp_diagnostic(diagnostic, &content, encoding))
+                .collect::<Result<Vec<_>>>()?;
+            output.push(PackageFileDiagnostics { uri, version, diagnostics });
         }
-    };
 
-    tracing::debug!("Found {} diagnostics for file", diagnostics.len());
-    Ok(LintInternalOutput {
-        diagnostics,
-        unused_fn_hidden_count,
-        refreshed_packages,
-    })
+        tracing::debug!("Linted {} other files of the package", output.len());
+        Ok(output)
+    }
+
+    /// Run the Jarl linting engine on the content of a file
+    fn lint(&self, content: &str, file_path: &Path) -> Result<Vec<JarlDiagnostic>> {
+        if file_path != self.file_path
+            && should_exclude_file_based_on_settings(file_path, &self.resolver)
+        {
+            tracing::debug!("Skipping linting for excluded file: {:?}", file_path);
+            return Ok(Vec::new());
+        }
+
+        // Call get_checks directly with the in-memory content and the real
+        // (relativized) file path, avoiding the old tempfile round-trip.
+        // `get_checks` resolves `[lint.per-file-ignores]` for the file itself.
+        let rel_path = PathBuf::from(relativize_path(file_path));
+        let mut diagnostics = get_checks(
+            content,
+            &rel_path,
+            &self.config,
+            &self.pkg,
+            &self.pkg_contexts,
+            &self.file_pkg_info,
+        )?;
+
+        if self.unused_fn_hidden_count > 0 {
+            diagnostics.retain(|d| d.message.name != "unused_function");
+        }
+
+        tracing::debug!("Found {} diagnostics for file", diagnostics.len());
+        Ok(diagnostics)
+    }
 }
 
 /// If `file_path` lives inside an R package's `R/` directory, return all
@@ -267,12 +462,12 @@ fn convert_to_lsp_diagnostic(
 
     let range = Range::new(start_pos, end_pos);
 
-    // TODO-etienne: don't have that
-    // let severity = convert_severity(jarl_diag.severity);
-    let severity = DiagnosticSeverity::WARNING;
+    let severity = convert_severity(jarl_diag.severity);
 
     // Extract fix information if available
-    // Always include fix_data even if there's no actual fix, so we can access the rule_name
+    // Always include fix_data even if there's no actual fix, so we can access the rule_name.
+    // Syntax errors have no fix and can't be suppressed, so they don't get any
+    // code action.
     let diagnostic_fix = DiagnosticFix {
         content: jarl_diag.fix.content.clone(),
         start: jarl_diag.fix.start,
@@ -282,7 +477,8 @@ fn convert_to_lsp_diagnostic(
         diagnostic_start: start_offset,
         diagnostic_end: end_offset,
     };
-    let fix_data = Some(serde_json::to_value(diagnostic_fix).unwrap_or_default());
+    let fix_data = (!jarl_diag.is_syntax_error())
+        .then(|| serde_json::to_value(diagnostic_fix).unwrap_or_default());
 
     // Build the LSP diagnostic with fix information
     // Combine body and suggestion for the message
@@ -378,15 +574,14 @@ pub fn byte_offset_to_lsp_position(
     Ok(Position::new(line as u32, lsp_character))
 }
 
-// /// Convert Jarl severity to LSP diagnostic severity
-// fn convert_severity(severity: JarlSeverity) -> DiagnosticSeverity {
-//     match severity {
-//         JarlSeverity::Error => DiagnosticSeverity::ERROR,
-//         JarlSeverity::Warning => DiagnosticSeverity::WARNING,
-//         JarlSeverity::Info => DiagnosticSeverity::INFORMATION,
-//         JarlSeverity::Hint => DiagnosticSeverity::HINT,
-//     }
-// }
+/// Convert Jarl severity to LSP diagnostic severity
+fn convert_severity(severity: Severity) -> DiagnosticSeverity {
+    match severity {
+        Severity::Error => DiagnosticSeverity::ERROR,
+        Severity::Warning => DiagnosticSeverity::WARNING,
+        Severity::Info => DiagnosticSeverity::INFORMATION,
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -853,6 +1048,109 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_package_reports_duplicate_in_other_file() {
+        let (_dir, r_dir) = create_test_package();
+
+        // Saving aaa.R also updates the diagnostics of bbb.R, which isn't open.
+        let content = "foo <- function() 1\n";
+        let file = r_dir.join("aaa.R");
+        std::fs::write(&file, content).unwrap();
+        std::fs::write(r_dir.join("bbb.R"), "foo <- function() 2\n").unwrap();
+
+        let snapshot = create_snapshot_for_file(&file, content);
+        let output =
+            lint_document_and_package(&snapshot, &[], &CancellationToken::default()).unwrap();
+
+        assert!(
+            diagnostics_for_rule(&output.diagnostics, "duplicated_function_definition").is_empty()
+        );
+        assert_eq!(output.package_files.len(), 1);
+        let other = &output.package_files[0];
+        assert_eq!(other.uri, Url::from_file_path(r_dir.join("bbb.R")).unwrap());
+        assert_eq!(other.version, None);
+        let hits = diagnostics_for_rule(&other.diagnostics, "duplicated_function_definition");
+        assert_eq!(
+            hits.len(),
+            1,
+            "expected one duplicate diagnostic on bbb.R, got: {hits:?}"
+        );
+    }
+
+    #[test]
+    fn test_package_uses_version_of_open_documents() {
+        let (_dir, r_dir) = create_test_package();
+
+        let content = "foo <- function() 1\n";
+        let file = r_dir.join("aaa.R");
+        std::fs::write(&file, content).unwrap();
+        let other_content = "foo <- function() 2\n";
+        let other_file = r_dir.join("bbb.R");
+        std::fs::write(&other_file, other_content).unwrap();
+
+        let snapshot = create_snapshot_for_file(&file, content);
+        let other_snapshot = create_snapshot_for_file(&other_file, other_content);
+        let output =
+            lint_document_and_package(&snapshot, &[other_snapshot], &CancellationToken::default())
+                .unwrap();
+
+        assert_eq!(output.package_files.len(), 1);
+        assert_eq!(output.package_files[0].version, Some(1));
+    }
+
+    #[test]
+    fn test_package_files_not_linted_outside_package() {
+        // No DESCRIPTION so not a package, other files are not linted
+        let dir = TempDir::new().unwrap();
+        let r_dir = dir.path().join("R");
+        std::fs::create_dir(&r_dir).unwrap();
+
+        let content = "foo <- function() 1\n";
+        let file = r_dir.join("aaa.R");
+        std::fs::write(&file, content).unwrap();
+        std::fs::write(r_dir.join("bbb.R"), "foo <- function() 2\n").unwrap();
+
+        let snapshot = create_snapshot_for_file(&file, content);
+        let output =
+            lint_document_and_package(&snapshot, &[], &CancellationToken::default()).unwrap();
+        assert!(output.package_files.is_empty());
+    }
+
+    #[test]
+    fn test_package_files_not_linted_when_cancelled() {
+        let (_dir, r_dir) = create_test_package();
+
+        let content = "foo <- function() 1\nfoo <- function() 2\n";
+        let file = r_dir.join("aaa.R");
+        std::fs::write(&file, content).unwrap();
+        std::fs::write(r_dir.join("bbb.R"), "foo <- function() 3\n").unwrap();
+
+        let cancel = CancellationToken::default();
+        cancel.cancel();
+        let snapshot = create_snapshot_for_file(&file, content);
+        let output = lint_document_and_package(&snapshot, &[], &cancel).unwrap();
+
+        // The document itself is still linted, only the rest of the package
+        // is skipped.
+        let hits = diagnostics_for_rule(&output.diagnostics, "duplicated_function_definition");
+        assert_eq!(hits.len(), 1);
+        assert!(output.package_files.is_empty());
+    }
+
+    #[test]
+    fn test_lint_document_does_not_lint_package() {
+        let (_dir, r_dir) = create_test_package();
+
+        let content = "foo <- function() 1\n";
+        let file = r_dir.join("aaa.R");
+        std::fs::write(&file, content).unwrap();
+        std::fs::write(r_dir.join("bbb.R"), "foo <- function() 2\n").unwrap();
+
+        let snapshot = create_snapshot_for_file(&file, content);
+        let output = lint_document(&snapshot).unwrap();
+        assert!(output.package_files.is_empty());
+    }
+
     #[test]
     fn test_exclusion_with_custom_exclude_pattern() -> Result<(), Box<dyn std::error::Error>> {
         let directory = TempDir::new()?;
@@ -893,4 +1191,52 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_client_settings_select() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("test.R");
+        let content = "any(is.na(x))\nx = 1\n";
+        std::fs::write(&file_path, content).unwrap();
+
+        let snapshot = create_test_snapshot(&file_path, content);
+        assert_eq!(lint_document(&snapshot).unwrap().diagnostics.len(), 1);
+
+        let settings = ClientSettings::from_value(serde_json::json!({
+            "select": ["assignment"]
+        }))
+        .unwrap();
+        let snapshot = create_test_snapshot(&file_path, content).with_client_settings(settings);
+        let diagnostics = lint_document(&snapshot).unwrap().diagnostics;
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("<-"));
+    }
+
+    #[test]
+    fn test_client_settings_merged_with_toml() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("jarl.toml"),
+            r#"
+[lint]
+extend-select = ["assignment"]
+"#,
+        )
+        .unwrap();
+        let file_path = temp_dir.path().join("test.R");
+        let content = "any(is.na(x))\nx = 1\n";
+        std::fs::write(&file_path, content).unwrap();
+
+        let snapshot = create_test_snapshot(&file_path, content);
+        assert_eq!(lint_document(&snapshot).unwrap().diagnostics.len(), 2);
+
+        // `ruleOptions` replaces the options of `jarl.toml`.
+        let settings = ClientSettings::from_value(serde_json::json!({
+            "ignore": ["any_is_na"],
+            "ruleOptions": { "assignment": { "operator": "=" } }
+        }))
+        .unwrap();
+        let snapshot = create_test_snapshot(&file_path, content).with_client_settings(settings);
+        assert!(lint_document(&snapshot).unwrap().diagnostics.is_empty());
+    }
 }
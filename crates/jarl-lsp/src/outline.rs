@@ -0,0 +1,478 @@
+//! Document symbols and folding ranges for the Jarl LSP server
+//!
+//! Both are computed from the syntax tree of the document, so that editors
+//! without an R language server still get an outline of the file and can fold
+//! its blocks. For R Markdown, Quarto, and Sweave documents, only the R code
+//! chunks are used.
+
+use air_r_parser::RParserOptions;
+use air_r_syntax::{
+    AnyRExpression, RBinaryExpression, RBracedExpressions, RCall, RCallArguments, RParameters,
+    RSyntaxKind, RSyntaxNode,
+};
+use biome_rowan::{AstNode, AstNodeList, Direction, TextRange};
+use jarl_core::fs::has_rmd_extension;
+use jarl_core::rmd::extract_document_chunks;
+use jarl_core::utils::{get_arg_by_name_then_position, get_function_name};
+use lsp_types::{DocumentSymbol, FoldingRange, FoldingRangeKind, Range, SymbolKind};
+
+use crate::session::DocumentSnapshot;
+
+/// Functions defining a test, with the name of their description argument.
+const TEST_FUNCTIONS: &[(&str, &str)] = &[
+    ("test_that", "desc"),
+    ("describe", "description"),
+    ("it", "description"),
+];
+
+/// Symbols of the document: functions and top-level assignments, and test
+/// blocks named after their description. Functions and tests contain the
+/// functions and tests defined in their body.
+pub fn document_symbols(snapshot: &DocumentSnapshot) -> Vec<DocumentSymbol> {
+    let mut symbols = Vec::new();
+    for (code, offset) in r_sources(snapshot) {
+        let parsed = air_r_parser::parse(&code, RParserOptions::default());
+        let outline = Outline { snapshot, offset };
+        symbols.extend(outline.symbols(parsed.tree().expressions().iter(), true));
+    }
+    symbols
+}
+
+/// Folding ranges of the document: braced expressions, arguments and
+/// parameters spanning several lines, and blocks of comments.
+pub fn folding_ranges(snapshot: &DocumentSnapshot) -> Vec<FoldingRange> {
+    let mut ranges: Vec<FoldingRange> = Vec::new();
+    for (code, offset) in r_sources(snapshot) {
+        let parsed = air_r_parser::parse(&code, RParserOptions::default());
+        let outline = Outline { snapshot, offset };
+        let syntax = parsed.syntax();
+
+        for node in syntax.descendants() {
+            let kind = node.kind();
+            if RBracedExpressions::can_cast(kind)
+                || RCallArguments::can_cast(kind)
+                || RParameters::can_cast(kind)
+            {
+                outline.push_folding_range(&mut ranges, node.text_trimmed_range(), None);
+            }
+        }
+
+        // Consecutive comment lines are folded together.
+        let mut block: Option<TextRange> = None;
+        for token in syntax.descendants_tokens(Direction::Next) {
+            for piece in token.leading_trivia().pieces() {
+                if piece.is_newline() || piece.is_whitespace() {
+                    continue;
+                }
+                if !piece.is_comments() {
+                    block = None;
+                    continue;
+                }
+                let range = piece.text_range();
+                block = match block {
+                    Some(current) if outline.is_next_line(current, range) => {
+                        Some(current.cover(range))
+                    }
+                    Some(current) => {
+                        outline.push_folding_range(
+                            &mut ranges,
+                            current,
+                            Some(FoldingRangeKind::Comment),
+                        );
+                        Some(range)
+                    }
+                    None => Some(range),
+                };
+            }
+            if let Some(current) = block.take() {
+                outline.push_folding_range(&mut ranges, current, Some(FoldingRangeKind::Comment));
+            }
+        }
+    }
+
+    ranges.sort_by_key(|range| (range.start_line, std::cmp::Reverse(range.end_line)));
+    // Nested calls on the same line, e.g. `f(g(`, are folded only once.
+    ranges.dedup_by_key(|range| range.start_line);
+    ranges
+}
+
+/// R code of the document, with the byte offset where it starts.
+fn r_sources(snapshot: &DocumentSnapshot) -> Vec<(String, usize)> {
+    match snapshot.file_path() {
+        Some(path) if has_rmd_extension(&path) => {
+            extract_document_chunks(snapshot.content(), &path)
+                .into_iter()
+                .map(|chunk| (chunk.code, chunk.start_byte))
+                .collect()
+        }
+        _ => vec![(snapshot.content().to_string(), 0)],
+    }
+}
+
+/// Converts the ranges of a piece of R code to positions in the document.
+struct Outline<'a> {
+    snapshot: &'a DocumentSnapshot,
+    offset: usize,
+}
+
+impl Outline<'_> {
+    fn range(&self, range: TextRange) -> Option<Range> {
+        let start = self.offset + usize::from(range.start());
+        let end = self.offset + usize::from(range.end());
+        self.snapshot.range_of_span(start, end).ok()
+    }
+
+    fn line(&self, offset: usize) -> Option<u32> {
+        let position = self
+            .snapshot
+            .offset_to_position(self.offset + offset)
+            .ok()?;
+        Some(position.line)
+    }
+
+    /// Whether `next` starts on the line following the end of `current`.
+    fn is_next_line(&self, current: TextRange, next: TextRange) -> bool {
+        match (
+            self.line(current.end().into()),
+            self.line(next.start().into()),
+        ) {
+            (Some(current), Some(next)) => next == current + 1,
+            _ => false,
+        }
+    }
+
+    fn push_folding_range(
+        &self,
+        ranges: &mut Vec<FoldingRange>,
+        range: TextRange,
+        kind: Option<FoldingRangeKind>,
+    ) {
+        let (Some(start_line), Some(end_line)) = (
+            self.line(range.start().into()),
+            self.line(range.end().into()),
+        ) else {
+            return;
+        };
+        if start_line < end_line {
+            ranges.push(FoldingRange { start_line, end_line, kind, ..Default::default() });
+        }
+    }
+
+    fn symbols(
+        &self,
+        expressions: impl Iterator<Item = AnyRExpression>,
+        top_level: bool,
+    ) -> Vec<DocumentSymbol> {
+        expressions
+            .filter_map(|expr| match &expr {
+                AnyRExpression::RBinaryExpression(binary) => {
+                    self.assignment_symbol(binary, top_level)
+                }
+                AnyRExpression::RCall(call) => self.test_symbol(call),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Symbol of `f <- function(x) ...`. Other assignments are only symbols
+    /// at the top level, local variables are not.
+    fn assignment_symbol(
+        &self,
+        binary: &RBinaryExpression,
+        top_level: bool,
+    ) -> Option<DocumentSymbol> {
+        let (target, value) = match binary.operator().ok()?.kind() {
+            RSyntaxKind::ASSIGN | RSyntaxKind::SUPER_ASSIGN | RSyntaxKind::EQUAL => {
+                (binary.left().ok()?, binary.right().ok()?)
+            }
+            RSyntaxKind::ASSIGN_RIGHT | RSyntaxKind::SUPER_ASSIGN_RIGHT => {
+                (binary.right().ok()?, binary.left().ok()?)
+            }
+            _ => return None,
+        };
+        let name = match &target {
+            AnyRExpression::RIdentifier(_) | AnyRExpression::AnyRValue(_) => {
+                unquote(&target.syntax().text_trimmed().to_string())
+            }
+            _ => return None,
+        };
+
+        if let AnyRExpression::RFunctionDefinition(function) = &value {
+            let parameters = function
+                .parameters()
+                .ok()?
+                .syntax()
+                .text_trimmed()
+                .to_string();
+            let detail = format!(
+                "function{}",
+                parameters.split_whitespace().collect::<Vec<_>>().join(" ")
+            );
+            let children = self.symbols(body_expressions(function.body().ok()?), false);
+            return self.symbol(
+                name,
+                Some(detail),
+                SymbolKind::FUNCTION,
+                binary.syntax(),
+                target.syntax(),
+                children,
+            );
+        }
+
+        if !top_level {
+            return None;
+        }
+        self.symbol(
+            name,
+            None,
+            SymbolKind::VARIABLE,
+            binary.syntax(),
+            target.syntax(),
+            Vec::new(),
+        )
+    }
+
+    /// Symbol of `test_that("description", { ... })`.
+    fn test_symbol(&self, call: &RCall) -> Option<DocumentSymbol> {
+        let fn_name = get_function_name(call.function().ok()?);
+        let (fn_name, description_arg) = TEST_FUNCTIONS
+            .iter()
+            .find(|(function, _)| *function == fn_name)?;
+
+        let args = call.arguments().ok()?.items();
+        let description = get_arg_by_name_then_position(&args, description_arg, 1)?.value()?;
+        let name = unquote(&description.syntax().text_trimmed().to_string());
+        let children =
+            match get_arg_by_name_then_position(&args, "code", 2).and_then(|code| code.value()) {
+                Some(code) => self.symbols(body_expressions(code), false),
+                None => Vec::new(),
+            };
+
+        self.symbol(
+            name,
+            Some(fn_name.to_string()),
+            SymbolKind::FUNCTION,
+            call.syntax(),
+            description.syntax(),
+            children,
+        )
+    }
+
+    // `deprecated` is itself deprecated in favor of `tags`.
+    #[allow(deprecated)]
+    fn symbol(
+        &self,
+        name: String,
+        detail: Option<String>,
+        kind: SymbolKind,
+        node: &RSyntaxNode,
+        selection: &RSyntaxNode,
+        children: Vec<DocumentSymbol>,
+    ) -> Option<DocumentSymbol> {
+        // Clients reject symbols without a name.
+        if name.is_empty() {
+            return None;
+        }
+        Some(DocumentSymbol {
+            name,
+            detail,
+            kind,
+            tags: None,
+            deprecated: None,
+            range: self.range(node.text_trimmed_range())?,
+            selection_range: self.range(selection.text_trimmed_range())?,
+            children: (!children.is_empty()).then_some(children),
+        })
+    }
+}
+
+/// Expressions of the body of a function or a test, e.g. the statements of
+/// `{ x <- 1; x }` or `x + 1` in `function(x) x + 1`.
+fn body_expressions(body: AnyRExpression) -> impl Iterator<Item = AnyRExpression> {
+    match body {
+        AnyRExpression::RBracedExpressions(braced) => {
+            braced.expressions().iter().collect::<Vec<_>>()
+        }
+        body => vec![body],
+    }
+    .into_iter()
+}
+
+/// Remove the quotes or backticks around a name, e.g. `"a b"` or `` `a b` ``.
+fn unquote(text: &str) -> String {
+    for quote in ['"', '\'', '`'] {
+        if let Some(inner) = text
+            .strip_prefix(quote)
+            .and_then(|text| text.strip_suffix(quote))
+        {
+            return inner.to_string();
+        }
+    }
+    text.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::{DocumentKey, PositionEncoding, TextDocument};
+    use lsp_types::{ClientCapabilities, Url};
+
+    fn create_test_snapshot(file_name: &str, content: &str) -> DocumentSnapshot {
+        let path = std::env::temp_dir().join(file_name);
+        let uri = Url::from_file_path(path).unwrap();
+        DocumentSnapshot::new(
+            TextDocument::new(content.to_string(), 1),
+            DocumentKey::from(uri),
+            PositionEncoding::UTF8,
+            ClientCapabilities::default(),
+        )
+    }
+
+    /// Symbols as `name (detail) [start line-end line]`, indented by depth.
+    fn format_symbols(symbols: &[DocumentSymbol], depth: usize, out: &mut Vec<String>) {
+        for symbol in symbols {
+            out.push(format!(
+                "{}{} ({}) [{}-{}]",
+                "  ".repeat(depth),
+                symbol.name,
+                symbol.detail.as_deref().unwrap_or("-"),
+                symbol.range.start.line,
+                symbol.range.end.line
+            ));
+            format_symbols(
+                symbol.children.as_deref().unwrap_or_default(),
+                depth + 1,
+                out,
+            );
+        }
+    }
+
+    fn symbols(file_name: &str, content: &str) -> Vec<String> {
+        let snapshot = create_test_snapshot(file_name, content);
+        let mut out = Vec::new();
+        format_symbols(&document_symbols(&snapshot), 0, &mut out);
+        out
+    }
+
+    fn folds(file_name: &str, content: &str) -> Vec<(u32, u32, Option<FoldingRangeKind>)> {
+        let snapshot = create_test_snapshot(file_name, content);
+        folding_ranges(&snapshot)
+            .into_iter()
+            .map(|range| (range.start_line, range.end_line, range.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_document_symbols() {
+        let content = "\
+x <- 1
+`my fn` <- function(a,
+                    b = 2) {
+  y <- a + b
+  helper <- function(z) z
+  helper(y)
+}
+\"z\" = \\(x) x
+3 -> w
+";
+        assert_eq!(
+            symbols("a.R", content),
+            vec![
+                "x (-) [0-0]",
+                "my fn (function(a, b = 2)) [1-6]",
+                "  helper (function(z)) [4-4]",
+                "z (function(x)) [7-7]",
+                "w (-) [8-8]",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_document_symbols_tests() {
+        let content = "\
+test_that(\"addition works\", {
+  expect_equal(1 + 1, 2)
+})
+testthat::test_that(code = {
+  f <- function() 1
+}, desc = 'with a helper')
+describe(\"my_fn()\", {
+  it(\"returns 1\", {
+    expect_equal(my_fn(), 1)
+  })
+})
+";
+        assert_eq!(
+            symbols("test-a.R", content),
+            vec![
+                "addition works (test_that) [0-2]",
+                "with a helper (test_that) [3-5]",
+                "  f (function()) [4-4]",
+                "my_fn() (describe) [6-10]",
+                "  returns 1 (it) [7-9]",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_document_symbols_rmd() {
+        let content = "\
+# Title
+
+```{r}
+f <- function(x) x
+```
+
+Text
+
+```{r}
+test_that(\"f works\", expect_equal(f(1), 1))
+```
+";
+        assert_eq!(
+            symbols("doc.Rmd", content),
+            vec!["f (function(x)) [3-3]", "f works (test_that) [9-9]"]
+        );
+    }
+
+    #[test]
+    fn test_folding_ranges() {
+        let content = "\
+# A comment
+# on two lines
+f <- function(x) {
+  if (x) {
+    1
+  }
+  list(
+    a = 1, b = list(
+      c = 2
+    )
+  )
+}
+g(1) # Not folded
+";
+        assert_eq!(
+            folds("a.R", content),
+            vec![
+                (0, 1, Some(FoldingRangeKind::Comment)),
+                (2, 11, None),
+                (3, 5, None),
+                (6, 10, None),
+                (7, 9, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_folding_ranges_rmd() {
+        let content = "\
+```{r}
+f <- function(x) {
+  x
+}
+```
+";
+        assert_eq!(folds("doc.qmd", content), vec![(1, 3, None)]);
+    }
+}
@@ -1,27 +1,34 @@
 //! Main LSP server implementation for Jarl
 //!
 //! This module contains the core server logic that handles the LSP protocol,
-//! providing diagnostic (linting) capabilities and code actions for quick fixes.
+//! providing diagnostic (linting) capabilities and code actions for quick fixes,
+//! as well as document symbols and folding ranges.
 
 use anyhow::{Context, Result, anyhow};
 use crossbeam::channel;
 use lsp_server::{Connection, Message, Notification, Request, RequestId, Response};
 use lsp_types::{self as types, notification::Notification as _, request::Request as _};
 
+use std::collections::VecDeque;
 use std::num::NonZeroUsize;
 use std::thread;
 use std::time::Instant;
 
-use crate::LspResult;
+use crate::cancellation::{CancellationToken, LINT_DEBOUNCE};
 use crate::client::{Client, ToLspError};
+use crate::config_watch::is_config_file;
 use crate::document::TextDocument;
 use crate::lint;
+use crate::outline;
 use crate::session::{DocumentSnapshot, Session, negotiate_position_encoding};
+use crate::{FIX_ALL_CODE_ACTION_KIND, LspResult};
 
 /// Main LSP server
 pub struct Server {
     connection: Connection,
     worker_threads: NonZeroUsize,
+    /// Whether code actions that modify files are disabled (`--read-only`)
+    read_only: bool,
 }
 
 /// Events that can be processed by the main loop
@@ -32,6 +39,12 @@ pub enum Event {
     Message(Message),
     /// Internal task to send a response
     SendResponse(Response),
+    /// A lint task is done, so its cancellation tokens can be forgotten
+    LintTaskDone {
+        uri: types::Url,
+        cancel: CancellationToken,
+        cancel_package: CancellationToken,
+    },
     /// Shutdown the server
     Shutdown,
 }
@@ -41,6 +54,16 @@ pub enum Task {
     /// Lint a document and publish diagnostics
     LintDocument {
         snapshot: Box<DocumentSnapshot>,
+        /// The other open documents, used to also lint the rest of the R
+        /// package. `None` to only lint the document.
+        open_documents: Option<Vec<DocumentSnapshot>>,
+        /// Cancelled when the document is edited, closed, or linted again
+        cancel: CancellationToken,
+        /// Cancelled when another file of the package is saved, since the
+        /// other files will be linted again anyway
+        cancel_package: CancellationToken,
+        /// When the task was created, used to debounce consecutive saves
+        created: Instant,
         client: Client,
     },
     /// Handle a code action request
@@ -50,12 +73,30 @@ pub enum Task {
         params: Box<types::CodeActionParams>,
         client: Client,
     },
+    /// Handle a request for the symbols or the folding ranges of a document
+    HandleOutlineRequest {
+        snapshot: Box<DocumentSnapshot>,
+        request_id: RequestId,
+        request: OutlineRequest,
+        client: Client,
+    },
+}
+
+/// Requests answered from the syntax tree of a document
+#[derive(Debug, Clone, Copy)]
+pub enum OutlineRequest {
+    DocumentSymbols,
+    FoldingRanges,
 }
 
 impl Server {
     /// Create a new server instance
-    pub fn new(worker_threads: NonZeroUsize, connection: Connection) -> Result<Self> {
-        Ok(Self { connection, worker_threads })
+    pub fn new(
+        worker_threads: NonZeroUsize,
+        connection: Connection,
+        read_only: bool,
+    ) -> Result<Self> {
+        Ok(Self { connection, worker_threads, read_only })
     }
 
     /// Run the main server loop
@@ -93,6 +134,7 @@ impl Server {
             vec![], // Will be populated from init_params
             client.clone(),
         );
+        session.set_read_only(self.read_only);
 
         // Initialize session and get initialize result
         let initialize_result = session
@@ -109,10 +151,14 @@ impl Server {
             .context("Failed to finish LSP initialization")?;
         tracing::info!("LSP server initialized successfully");
 
-        // Create worker thread pool
-        let (task_sender, task_receiver) = channel::bounded::<Task>(100);
+        // Create worker thread pool. Tasks go through the scheduler thread,
+        // which delays the lint tasks before passing them to the workers.
+        let (task_sender, scheduler_receiver) = channel::bounded::<Task>(100);
+        let (worker_sender, task_receiver) = channel::bounded::<Task>(100);
         let (event_sender, event_receiver) = channel::bounded::<Event>(100);
 
+        thread::spawn(move || Self::scheduler_thread(scheduler_receiver, worker_sender));
+
         // Spawn worker threads
         tracing::debug!("Spawning {} worker threads", self.worker_threads.get());
         for i in 0..self.worker_threads.get() {
@@ -168,6 +214,9 @@ impl Server {
                                 tracing::error!("Error sending response: {}", e);
                             }
                         }
+                        Ok(Event::LintTaskDone { uri, cancel, cancel_package }) => {
+                            session.lint_tasks().finish(&uri, &cancel, &cancel_package);
+                        }
                         Ok(Event::Shutdown) => {
                             tracing::info!("Shutdown event received");
                             break;
@@ -226,6 +275,12 @@ impl Server {
             // Pull diagnostics are disabled: diagnostics are only published on save.
             // This avoids showing stale or partial diagnostics while typing.
             types::request::CodeActionRequest::METHOD => {
+                // All code actions modify files, so there are none in read-only mode.
+                if session.is_read_only() {
+                    client.send_response(request.id, Vec::<types::CodeActionOrCommand>::new())?;
+                    return Ok(());
+                }
+
                 let params: types::CodeActionParams = serde_json::from_value(request.params)?;
                 let uri = params.text_document.uri.clone();
 
@@ -244,6 +299,26 @@ impl Server {
                 }
                 Ok(())
             }
+            types::request::DocumentSymbolRequest::METHOD => {
+                let params: types::DocumentSymbolParams = serde_json::from_value(request.params)?;
+                Self::send_outline_task(
+                    session,
+                    task_sender,
+                    request.id,
+                    params.text_document.uri,
+                    OutlineRequest::DocumentSymbols,
+                )
+            }
+            types::request::FoldingRangeRequest::METHOD => {
+                let params: types::FoldingRangeParams = serde_json::from_value(request.params)?;
+                Self::send_outline_task(
+                    session,
+                    task_sender,
+                    request.id,
+                    params.text_document.uri,
+                    OutlineRequest::FoldingRanges,
+                )
+            }
             _ => {
                 tracing::debug!(
                     "Unhandled request method: {} (not supported in diagnostics-only mode)",
@@ -259,6 +334,29 @@ impl Server {
         }
     }
 
+    /// Send the computation of the symbols or the folding ranges of a document
+    /// to the worker threads
+    fn send_outline_task(
+        session: &Session,
+        task_sender: &channel::Sender<Task>,
+        request_id: RequestId,
+        uri: types::Url,
+        request: OutlineRequest,
+    ) -> LspResult<()> {
+        let client = session.client().clone();
+        if let Some(snapshot) = session.take_snapshot(uri) {
+            task_sender.send(Task::HandleOutlineRequest {
+                snapshot: Box::new(snapshot),
+                request_id,
+                request,
+                client,
+            })?;
+        } else {
+            client.send_error_response(request_id, anyhow!("Document not found").to_lsp_error())?;
+        }
+        Ok(())
+    }
+
     /// Handle a notification from the client
     fn handle_notification(
         notification: Notification,
@@ -275,6 +373,10 @@ impl Server {
                 }
                 std::process::exit(0);
             }
+            types::notification::Initialized::METHOD => {
+                session.register_config_watcher()?;
+                Ok(())
+            }
             types::notification::DidOpenTextDocument::METHOD => {
                 let params: types::DidOpenTextDocumentParams =
                     serde_json::from_value(notification.params)?;
@@ -290,6 +392,7 @@ impl Server {
                 // Check and notify about config file location (once per session, only if not in CWD)
                 if let Ok(file_path) = params.text_document.uri.to_file_path() {
                     session.check_and_notify_config(&file_path);
+                    session.track_config(&file_path);
                 }
 
                 // Don't trigger linting on open, only on save
@@ -314,6 +417,9 @@ impl Server {
                 let params: types::DidCloseTextDocumentParams =
                     serde_json::from_value(notification.params)?;
 
+                session
+                    .lint_tasks()
+                    .cancel_document(&params.text_document.uri);
                 session.close_document(params.text_document.uri.clone())?;
 
                 // Clear diagnostics for the closed document
@@ -328,14 +434,73 @@ impl Server {
 
                 tracing::debug!("Document saved: {}", params.text_document.uri);
 
-                if let Some(snapshot) = session.take_snapshot(params.text_document.uri) {
+                let uri = params.text_document.uri;
+                if let Some(snapshot) = session.take_snapshot(uri.clone()) {
+                    // Package-wide rules depend on all files of the package, so
+                    // saving a file can also change the diagnostics of the others.
+                    let open_documents = session
+                        .open_documents()
+                        .filter(|other| **other != uri)
+                        .filter_map(|other| session.take_snapshot(other.clone()))
+                        .collect();
                     task_sender.send(Task::LintDocument {
                         snapshot: Box::new(snapshot),
+                        open_documents: Some(open_documents),
+                        cancel: session.lint_tasks().start_document(&uri),
+                        cancel_package: session.lint_tasks().start_package(&uri),
+                        created: Instant::now(),
                         client: session.client().clone(),
                     })?;
                 }
                 Ok(())
             }
+            types::notification::DidChangeWatchedFiles::METHOD => {
+                let params: types::DidChangeWatchedFilesParams =
+                    serde_json::from_value(notification.params)?;
+
+                let mut config_changed = false;
+                for change in params.changes {
+                    let Ok(config_path) = change.uri.to_file_path() else {
+                        continue;
+                    };
+                    if !is_config_file(&config_path) {
+                        continue;
+                    }
+
+                    tracing::debug!("Configuration file changed: {}", config_path.display());
+                    let deleted = change.typ == types::FileChangeType::DELETED;
+                    let (message, message_type) = session
+                        .reload_config(&config_path, deleted)
+                        .message(&config_path);
+                    session.client().show_message(&message, message_type)?;
+                    config_changed = true;
+                }
+
+                // Settings are discovered every time a document is linted, so
+                // re-linting open documents is enough to apply the new config.
+                if config_changed {
+                    Self::lint_open_documents(session, task_sender)?;
+                }
+                Ok(())
+            }
+            types::notification::DidChangeConfiguration::METHOD => {
+                let params: types::DidChangeConfigurationParams =
+                    serde_json::from_value(notification.params)?;
+
+                // Keep the previous settings if the new ones are invalid, so
+                // that a typo doesn't reset the selected rules.
+                if let Err(e) = session.update_client_settings(params.settings) {
+                    tracing::warn!("Invalid settings: {}", e);
+                    session.client().show_message(
+                        &format!("Jarl: invalid settings, keeping the previous ones.\n{e}"),
+                        types::MessageType::ERROR,
+                    )?;
+                    return Ok(());
+                }
+
+                Self::lint_open_documents(session, task_sender)?;
+                Ok(())
+            }
             _ => {
                 tracing::debug!("Unhandled notification: {}", notification.method);
                 Ok(())
@@ -343,30 +508,153 @@ impl Server {
         }
     }
 
+    /// Lint all open documents again, e.g. after a change of configuration
+    fn lint_open_documents(
+        session: &mut Session,
+        task_sender: &channel::Sender<Task>,
+    ) -> LspResult<()> {
+        let uris: Vec<_> = session.open_documents().cloned().collect();
+        for uri in uris {
+            if let Some(snapshot) = session.take_snapshot(uri.clone()) {
+                task_sender.send(Task::LintDocument {
+                    snapshot: Box::new(snapshot),
+                    open_documents: None,
+                    cancel: session.lint_tasks().start_document(&uri),
+                    cancel_package: CancellationToken::default(),
+                    created: Instant::now(),
+                    client: session.client().clone(),
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Thread that passes the tasks to the worker threads
+    ///
+    /// Lint tasks are held for `LINT_DEBOUNCE` so that a task queued right
+    /// after one of them for the same document can cancel it before it starts.
+    /// Waiting here rather than in the workers keeps them available for the
+    /// other requests in the meantime.
+    fn scheduler_thread(
+        task_receiver: channel::Receiver<Task>,
+        worker_sender: channel::Sender<Task>,
+    ) {
+        let mut pending: VecDeque<(Instant, Task)> = VecDeque::new();
+
+        loop {
+            let timeout = match pending.front() {
+                Some((deadline, _)) => channel::at(*deadline),
+                None => channel::never(),
+            };
+
+            crossbeam::select! {
+                recv(task_receiver) -> task => {
+                    let Ok(task) = task else {
+                        break;
+                    };
+                    if let Task::LintDocument { created, .. } = &task {
+                        pending.push_back((*created + LINT_DEBOUNCE, task));
+                    } else if worker_sender.send(task).is_err() {
+                        break;
+                    }
+                }
+                recv(timeout) -> _ => {
+                    let now = Instant::now();
+                    while pending.front().is_some_and(|(deadline, _)| *deadline <= now) {
+                        let Some((_, task)) = pending.pop_front() else {
+                            break;
+                        };
+                        if let Task::LintDocument { snapshot, cancel, .. } = &task
+                            && cancel.is_cancelled()
+                        {
+                            tracing::debug!("Skipping outdated lint of {}", snapshot.uri());
+                            continue;
+                        }
+                        if worker_sender.send(task).is_err() {
+                            return;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     /// Worker thread that processes background tasks
     fn worker_thread(
         _id: usize,
         task_receiver: channel::Receiver<Task>,
-        _event_sender: channel::Sender<Event>,
+        event_sender: channel::Sender<Event>,
     ) {
         while let Ok(task) = task_receiver.recv() {
             match task {
-                Task::LintDocument { snapshot, client } => {
-                    if let Err(e) = Self::handle_lint_task(*snapshot, client) {
+                Task::LintDocument {
+                    snapshot,
+                    open_documents,
+                    cancel,
+                    cancel_package,
+                    created: _,
+                    client,
+                } => {
+                    if cancel.is_cancelled() {
+                        tracing::debug!("Skipping outdated lint of {}", snapshot.uri());
+                        continue;
+                    }
+                    let uri = snapshot.uri().clone();
+                    if let Err(e) = Self::handle_lint_task(
+                        *snapshot,
+                        open_documents,
+                        &cancel,
+                        &cancel_package,
+                        client,
+                    ) {
                         tracing::error!("Error in lint task: {}", e);
                     }
+                    // Don't block if the main loop is busy: forgetting the
+                    // tokens is only an optimization.
+                    let _ =
+                        event_sender.try_send(Event::LintTaskDone { uri, cancel, cancel_package });
                 }
                 Task::HandleCodeActionRequest { snapshot, request_id, params, client } => {
                     Self::handle_code_action_request(*snapshot, request_id, *params, client);
                 }
+                Task::HandleOutlineRequest { snapshot, request_id, request, client } => {
+                    let result = match request {
+                        OutlineRequest::DocumentSymbols => client.send_response(
+                            request_id,
+                            types::DocumentSymbolResponse::Nested(outline::document_symbols(
+                                &snapshot,
+                            )),
+                        ),
+                        OutlineRequest::FoldingRanges => {
+                            client.send_response(request_id, outline::folding_ranges(&snapshot))
+                        }
+                    };
+                    if let Err(e) = result {
+                        tracing::error!("Failed to send {:?}: {}", request, e);
+                    }
+                }
             }
         }
     }
 
     /// Handle linting a document and publishing diagnostics
-    fn handle_lint_task(snapshot: DocumentSnapshot, client: Client) -> LspResult<()> {
+    ///
+    /// Diagnostics are not published if the task was cancelled in the
+    /// meantime, since a newer task will publish them.
+    fn handle_lint_task(
+        snapshot: DocumentSnapshot,
+        open_documents: Option<Vec<DocumentSnapshot>>,
+        cancel: &CancellationToken,
+        cancel_package: &CancellationToken,
+        client: Client,
+    ) -> LspResult<()> {
         let start = Instant::now();
-        let output = lint::lint_document(&snapshot)?;
+        let output = match open_documents {
+            Some(open_documents) => {
+                lint::lint_document_and_package(&snapshot, &open_documents, cancel_package)?
+            }
+            None => lint::lint_document(&snapshot)?,
+        };
         let elapsed = start.elapsed();
 
         tracing::debug!(
@@ -388,11 +676,20 @@ impl Server {
             );
         }
 
-        client.publish_diagnostics(
-            snapshot.uri().clone(),
-            output.diagnostics,
-            Some(snapshot.version()),
-        )?;
+        if cancel.is_cancelled() {
+            tracing::debug!("Discarding outdated diagnostics of {}", snapshot.uri());
+        } else {
+            client.publish_diagnostics(
+                snapshot.uri().clone(),
+                output.diagnostics,
+                Some(snapshot.version()),
+            )?;
+        }
+        if !cancel_package.is_cancelled() {
+            for file in output.package_files {
+                client.publish_diagnostics(file.uri, file.diagnostics, file.version)?;
+            }
+        }
         Ok(())
     }
 
@@ -429,6 +726,21 @@ impl Server {
 
         let mut actions = Vec::new();
 
+        // Clients can restrict the kinds of actions they want, e.g. only
+        // `source.fixAll` when running actions on save.
+        let only = params.context.only.as_deref();
+        let fix_all_kind = types::CodeActionKind::from(FIX_ALL_CODE_ACTION_KIND.to_string());
+
+        if is_kind_requested(only, &fix_all_kind)
+            && let Some(action) = Self::fix_all_code_action(&diagnostics, snapshot)
+        {
+            actions.push(types::CodeActionOrCommand::CodeAction(action));
+        }
+
+        if !is_kind_requested(only, &types::CodeActionKind::QUICKFIX) {
+            return Ok(actions);
+        }
+
         // Filter diagnostics that intersect with the requested range
         for diagnostic in diagnostics {
             if ranges_overlap(&diagnostic.range, &params.range) {
@@ -450,6 +762,13 @@ impl Server {
                 {
                     actions.push(types::CodeActionOrCommand::CodeAction(action));
                 }
+
+                // Add file-level ignore action
+                if let Some(action) =
+                    Self::diagnostic_to_jarl_ignore_file_action(&diagnostic, snapshot)
+                {
+                    actions.push(types::CodeActionOrCommand::CodeAction(action));
+                }
             }
         }
 
@@ -465,22 +784,12 @@ impl Server {
         let fix_data = diagnostic.data.as_ref()?;
         let fix: crate::lint::DiagnosticFix = serde_json::from_value(fix_data.clone()).ok()?;
 
-        if fix.content.is_empty() && fix.start == fix.end {
+        if !has_fix(&fix) {
             return None; // No fix available
         }
 
-        // Convert byte offsets to LSP positions
-        let content = snapshot.content();
-        let encoding = snapshot.position_encoding();
-
-        let start_pos =
-            crate::lint::byte_offset_to_lsp_position(fix.start, content, encoding).ok()?;
-        let end_pos = crate::lint::byte_offset_to_lsp_position(fix.end, content, encoding).ok()?;
-
-        let edit_range = types::Range::new(start_pos, end_pos);
-
         // Create the text edit for this single file
-        let text_edit = types::TextEdit { range: edit_range, new_text: fix.content.clone() };
+        let text_edit = Self::fix_to_text_edit(&fix, snapshot)?;
 
         // Create workspace edit with just this file's changes
         let mut changes = std::collections::HashMap::new();
@@ -496,7 +805,7 @@ impl Server {
         };
 
         Some(types::CodeAction {
-            title: format!("Fix: {}", diagnostic.message),
+            title: format!("Fix: {}", fix.rule_name),
             kind: Some(kind),
             diagnostics: Some(vec![diagnostic.clone()]),
             edit: Some(workspace_edit),
@@ -507,6 +816,80 @@ impl Server {
         })
     }
 
+    /// Create a source action applying the safe fixes of all diagnostics in
+    /// the document.
+    ///
+    /// LSP clients reject workspace edits whose ranges overlap, so when two
+    /// fixes overlap only the first one is applied. The remaining violation is
+    /// reported again after the edit and can be fixed by running the action
+    /// once more.
+    fn fix_all_code_action(
+        diagnostics: &[types::Diagnostic],
+        snapshot: &DocumentSnapshot,
+    ) -> Option<types::CodeAction> {
+        // Whether the fixes are applied depends on their safety and on the
+        // `unsafeFixes` setting, which is checked by `lint::fix_all()`.
+        let has_any_fix = diagnostics
+            .iter()
+            .filter_map(|diagnostic| diagnostic.data.clone())
+            .filter_map(|data| serde_json::from_value(data).ok())
+            .any(|fix: crate::lint::DiagnosticFix| has_fix(&fix));
+        if !has_any_fix {
+            return None;
+        }
+
+        // Fixes are applied until there is nothing left to fix, so the result
+        // is returned as a single edit replacing the whole document.
+        let content = snapshot.content();
+        let fixed = crate::lint::fix_all(snapshot).ok()??;
+        let end = crate::lint::byte_offset_to_lsp_position(
+            content.len(),
+            content,
+            snapshot.position_encoding(),
+        )
+        .ok()?;
+        let text_edit = types::TextEdit {
+            range: types::Range { start: types::Position::new(0, 0), end },
+            new_text: fixed,
+        };
+
+        let mut changes = std::collections::HashMap::new();
+        changes.insert(snapshot.uri().clone(), vec![text_edit]);
+
+        let workspace_edit = types::WorkspaceEdit { changes: Some(changes), ..Default::default() };
+
+        Some(types::CodeAction {
+            title: "Fix all auto-fixable problems".to_string(),
+            kind: Some(types::CodeActionKind::from(
+                FIX_ALL_CODE_ACTION_KIND.to_string(),
+            )),
+            diagnostics: None,
+            edit: Some(workspace_edit),
+            command: None,
+            is_preferred: None,
+            disabled: None,
+            data: None,
+        })
+    }
+
+    /// Convert the byte offsets of a fix to a text edit in the document
+    fn fix_to_text_edit(
+        fix: &crate::lint::DiagnosticFix,
+        snapshot: &DocumentSnapshot,
+    ) -> Option<types::TextEdit> {
+        let content = snapshot.content();
+        let encoding = snapshot.position_encoding();
+
+        let start_pos =
+            crate::lint::byte_offset_to_lsp_position(fix.start, content, encoding).ok()?;
+        let end_pos = crate::lint::byte_offset_to_lsp_position(fix.end, content, encoding).ok()?;
+
+        Some(types::TextEdit {
+            range: types::Range::new(start_pos, end_pos),
+            new_text: fix.content.clone(),
+        })
+    }
+
     /// Create a code action to add a jarl-ignore comment for a specific rule.
     /// Uses the hoisting infrastructure from jarl-core to find the correct insertion point.
     fn diagnostic_to_jarl_ignore_rule_action(
@@ -531,6 +914,7 @@ impl Server {
 
         let insert_point = if is_rmd {
             suppression_edit::create_suppression_edit_in_rmd(
+                &snapshot.file_path().unwrap_or_default(),
                 content,
                 fix.diagnostic_start,
                 fix.diagnostic_end,
@@ -596,10 +980,7 @@ impl Server {
         let workspace_edit = types::WorkspaceEdit { changes: Some(changes), ..Default::default() };
 
         Some(types::CodeAction {
-            title: format!(
-                "Suppress `{}` violation with jarl-ignore comment.",
-                rule_name
-            ),
+            title: format!("Suppress `{rule_name}` for this expression"),
             kind: Some(types::CodeActionKind::QUICKFIX),
             diagnostics: Some(vec![diagnostic.clone()]),
             edit: Some(workspace_edit),
@@ -676,6 +1057,49 @@ impl Server {
         })
     }
 
+    /// Create a code action to add a `# jarl-ignore-file` comment for a rule.
+    ///
+    /// The comment is inserted after the comments at the top of the file (or
+    /// of the first chunk in Rmd/Qmd files) so that it is applied by Jarl.
+    fn diagnostic_to_jarl_ignore_file_action(
+        diagnostic: &types::Diagnostic,
+        snapshot: &DocumentSnapshot,
+    ) -> Option<types::CodeAction> {
+        use jarl_core::suppression_edit;
+
+        let content = snapshot.content();
+
+        let fix_data = diagnostic.data.as_ref()?;
+        let fix: crate::lint::DiagnosticFix = serde_json::from_value(fix_data.clone()).ok()?;
+        let rule_name = &fix.rule_name;
+
+        let path = snapshot.file_path().unwrap_or_default();
+        let edit =
+            suppression_edit::create_file_suppression_edit(&path, content, rule_name, "<reason>")?;
+
+        let insert_pos = Self::offset_to_position(content, edit.insert_point.offset);
+        let text_edit = types::TextEdit {
+            range: types::Range::new(insert_pos, insert_pos),
+            new_text: edit.comment_text,
+        };
+
+        let mut changes = std::collections::HashMap::new();
+        changes.insert(snapshot.uri().clone(), vec![text_edit]);
+
+        let workspace_edit = types::WorkspaceEdit { changes: Some(changes), ..Default::default() };
+
+        Some(types::CodeAction {
+            title: format!("Suppress `{rule_name}` for this file"),
+            kind: Some(types::CodeActionKind::QUICKFIX),
+            diagnostics: Some(vec![diagnostic.clone()]),
+            edit: Some(workspace_edit),
+            command: None,
+            is_preferred: Some(false),
+            disabled: None,
+            data: None,
+        })
+    }
+
     /// Convert a byte offset to an LSP Position
     fn offset_to_position(content: &str, offset: usize) -> types::Position {
         let before = &content[..offset.min(content.len())];
@@ -696,9 +1120,31 @@ fn ranges_overlap(a: &types::Range, b: &types::Range) -> bool {
     a.start <= b.end && b.start <= a.end
 }
 
+/// Check if a fix attached to a diagnostic modifies the document
+fn has_fix(fix: &crate::lint::DiagnosticFix) -> bool {
+    !(fix.content.is_empty() && fix.start == fix.end)
+}
+
+/// Check if actions of this kind were requested. Kinds are hierarchical, so
+/// requesting `source` also returns `source.fixAll.jarl`.
+fn is_kind_requested(only: Option<&[types::CodeActionKind]>, kind: &types::CodeActionKind) -> bool {
+    let Some(only) = only else {
+        return true;
+    };
+    only.iter().any(|requested| {
+        let requested = requested.as_str();
+        kind.as_str() == requested
+            || kind
+                .as_str()
+                .strip_prefix(requested)
+                .is_some_and(|rest| rest.starts_with('.'))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::client_settings::ClientSettings;
     use crate::document::PositionEncoding;
     use crate::document::{DocumentKey, TextDocument};
     use crate::lint;
@@ -892,6 +1338,30 @@ select = ["ALL"]
         Some(result)
     }
 
+    /// Apply the "Fix all" source action to the whole document.
+    fn apply_fix_all(content: &str) -> Option<String> {
+        let env = TestEnv::new(content);
+        let snapshot = env.create_snapshot(content);
+
+        // Run the linter to get real diagnostics
+        let diagnostics = lint::lint_document(&snapshot).ok()?.diagnostics;
+
+        let action = Server::fix_all_code_action(&diagnostics, &snapshot)?;
+        let edit = action.edit?;
+        let changes = edit.changes?;
+        let text_edits = changes.values().next()?;
+
+        // Apply edits
+        let mut result = content.to_string();
+        for text_edit in text_edits.iter().rev() {
+            let start = position_to_offset(&result, text_edit.range.start);
+            let end = position_to_offset(&result, text_edit.range.end);
+            result.replace_range(start..end, &text_edit.new_text);
+        }
+
+        Some(result)
+    }
+
     /// Apply a jarl-ignore-chunk action at the cursor position for an Rmd file.
     fn apply_jarl_ignore_chunk_at_cursor(source_with_cursor: &str) -> Option<String> {
         let cursor_pos = source_with_cursor.find(CURSOR)?;
@@ -926,6 +1396,44 @@ select = ["ALL"]
         Some(result)
     }
 
+    /// Apply a jarl-ignore-file action at the cursor position.
+    fn apply_jarl_ignore_file_at_cursor(source_with_cursor: &str, is_rmd: bool) -> Option<String> {
+        let cursor_pos = source_with_cursor.find(CURSOR)?;
+        let content = source_with_cursor.replace(CURSOR, "");
+
+        let env = if is_rmd {
+            TestEnv::new_rmd(&content)
+        } else {
+            TestEnv::new(&content)
+        };
+        let snapshot = env.create_snapshot(&content);
+
+        // Run the linter to get real diagnostics
+        let diagnostics = lint::lint_document(&snapshot).ok()?.diagnostics;
+
+        // Find the diagnostic at cursor position
+        let cursor_lsp_pos = offset_to_position(&content, cursor_pos);
+        let diagnostic = diagnostics
+            .iter()
+            .find(|d| position_in_range(cursor_lsp_pos, &d.range))?;
+
+        // Get the file-ignore action
+        let action = Server::diagnostic_to_jarl_ignore_file_action(diagnostic, &snapshot)?;
+        let edit = action.edit?;
+        let changes = edit.changes?;
+        let text_edits = changes.values().next()?;
+
+        // Apply edits
+        let mut result = content.clone();
+        for text_edit in text_edits.iter().rev() {
+            let start = position_to_offset(&result, text_edit.range.start);
+            let end = position_to_offset(&result, text_edit.range.end);
+            result.replace_range(start..end, &text_edit.new_text);
+        }
+
+        Some(result)
+    }
+
     // =========================================================================
     // Server creation test
     // =========================================================================
@@ -935,7 +1443,7 @@ select = ["ALL"]
         let (connection, _io_threads) = Connection::memory();
         let worker_threads = NonZeroUsize::new(1).unwrap();
 
-        let result = Server::new(worker_threads, connection);
+        let result = Server::new(worker_threads, connection, false);
         assert!(result.is_ok());
     }
 
@@ -1529,6 +2037,103 @@ x |>
         );
     }
 
+    // =========================================================================
+    // jarl-ignore-file action tests
+    // =========================================================================
+
+    #[test]
+    fn test_ignore_file_action_inserts_at_top_of_file() {
+        let result = apply_jarl_ignore_file_at_cursor(
+            "x <- 1\nf <- function() {\n  <CURS>any(is.na(x))\n}\n",
+            false,
+        )
+        .unwrap();
+
+        insta::assert_snapshot!(result, @"
+        # jarl-ignore-file any_is_na: <reason>
+        x <- 1
+        f <- function() {
+          any(is.na(x))
+        }
+        ");
+    }
+
+    #[test]
+    fn test_ignore_file_action_inserts_after_leading_comments() {
+        let result = apply_jarl_ignore_file_at_cursor(
+            concat!(
+                "# Author: me\n",
+                "# jarl-ignore-file browser: <reason>\n",
+                "\n",
+                "x <- 1\n",
+                "<CURS>any(is.na(x))\n",
+            ),
+            false,
+        )
+        .unwrap();
+
+        insta::assert_snapshot!(result, @"
+        # Author: me
+        # jarl-ignore-file browser: <reason>
+
+        # jarl-ignore-file any_is_na: <reason>
+        x <- 1
+        any(is.na(x))
+        ");
+    }
+
+    #[test]
+    fn test_ignore_file_action_inserts_in_first_chunk() {
+        let result = apply_jarl_ignore_file_at_cursor(
+            concat!(
+                "---\n",
+                "title: test\n",
+                "---\n",
+                "\n",
+                "```{r}\n",
+                "#| label: setup\n",
+                "x <- 1\n",
+                "```\n",
+                "\n",
+                "```{r}\n",
+                "<CURS>any(is.na(x))\n",
+                "```\n",
+            ),
+            true,
+        )
+        .unwrap();
+
+        insta::assert_snapshot!(result, @"
+        ---
+        title: test
+        ---
+
+        ```{r}
+        #| label: setup
+        # jarl-ignore-file any_is_na: <reason>
+        x <- 1
+        ```
+
+        ```{r}
+        any(is.na(x))
+        ```
+        ");
+    }
+
+    #[test]
+    fn test_ignore_file_action_suppresses_violation() {
+        let result =
+            apply_jarl_ignore_file_at_cursor("x <- 1\n<CURS>any(is.na(x))\n", false).unwrap();
+
+        let env = TestEnv::new(&result);
+        let snapshot = env.create_snapshot(&result);
+        let diagnostics = lint::lint_document(&snapshot).unwrap().diagnostics;
+        assert!(
+            diagnostics.is_empty(),
+            "the inserted comment should suppress the violation"
+        );
+    }
+
     // =========================================================================
     // Action properties tests (non-snapshot)
     // =========================================================================
@@ -1560,12 +2165,165 @@ x |>
 
         let action = Server::diagnostic_to_jarl_ignore_rule_action(diagnostic, &snapshot).unwrap();
 
-        assert!(action.title.contains("assignment"));
-        assert!(action.title.contains("jarl-ignore"));
+        assert_eq!(action.title, "Suppress `assignment` for this expression");
         assert_eq!(action.kind, Some(types::CodeActionKind::QUICKFIX));
         assert!(!action.is_preferred.unwrap_or(true));
     }
 
+    #[test]
+    fn test_file_suppression_action_properties() {
+        let content = "x = 1\n";
+        let env = TestEnv::new(content);
+        let snapshot = env.create_snapshot(content);
+
+        let diagnostics = lint::lint_document(&snapshot).unwrap().diagnostics;
+        let diagnostic = diagnostics.first().unwrap();
+
+        let action = Server::diagnostic_to_jarl_ignore_file_action(diagnostic, &snapshot).unwrap();
+
+        assert_eq!(action.title, "Suppress `assignment` for this file");
+        assert_eq!(action.kind, Some(types::CodeActionKind::QUICKFIX));
+        assert!(!action.is_preferred.unwrap_or(true));
+    }
+
+    #[test]
+    fn test_fix_action_title_uses_rule_name() {
+        let content = "x = 1\n";
+        let env = TestEnv::new(content);
+        let snapshot = env.create_snapshot(content);
+
+        let diagnostics = lint::lint_document(&snapshot).unwrap().diagnostics;
+        let diagnostic = diagnostics.first().unwrap();
+
+        let action = Server::diagnostic_to_code_action(diagnostic, &snapshot).unwrap();
+
+        assert_eq!(action.title, "Fix: assignment");
+    }
+
+    // =========================================================================
+    // Fix all tests (using real linter)
+    // =========================================================================
+
+    #[test]
+    fn test_fix_all_applies_all_safe_fixes() {
+        let result = apply_fix_all("x = 1\nany(is.na(y))\nz = 2\n");
+        assert_eq!(result.as_deref(), Some("x <- 1\nanyNA(y)\nz <- 2\n"));
+    }
+
+    #[test]
+    fn test_fix_all_skips_unsafe_fixes() {
+        // `comparison_chain` only has an unsafe fix.
+        assert!(apply_fix_all("a < b < c\n").is_none());
+        assert_eq!(
+            apply_fix_all("a < b < c\nx = 1\n").as_deref(),
+            Some("a < b < c\nx <- 1\n")
+        );
+    }
+
+    #[test]
+    fn test_fix_all_applies_unsafe_fixes_with_settings() {
+        let content = "a < b < c\n";
+        let env = TestEnv::new(content);
+        let settings =
+            ClientSettings::from_value(serde_json::json!({ "unsafeFixes": true })).unwrap();
+        let snapshot = env.create_snapshot(content).with_client_settings(settings);
+
+        let diagnostics = lint::lint_document(&snapshot).unwrap().diagnostics;
+        let action = Server::fix_all_code_action(&diagnostics, &snapshot).unwrap();
+        let changes = action.edit.unwrap().changes.unwrap();
+        let text_edits = changes.values().next().unwrap();
+
+        assert_eq!(text_edits.len(), 1);
+        assert_eq!(text_edits[0].new_text, "a < b & b < c\n");
+    }
+
+    #[test]
+    fn test_fix_all_applies_fixes_until_fixpoint() {
+        // The inner fix overlaps the outer one so it needs a second pass.
+        let result = apply_fix_all("any(is.na(any(is.na(x))))\n");
+        assert_eq!(result.as_deref(), Some("anyNA(anyNA(x))\n"));
+    }
+
+    #[test]
+    fn test_fix_all_returns_single_edit() {
+        let content = "x = 1\nany(is.na(y))\nz = 2\n";
+        let env = TestEnv::new(content);
+        let snapshot = env.create_snapshot(content);
+
+        let diagnostics = lint::lint_document(&snapshot).unwrap().diagnostics;
+        let action = Server::fix_all_code_action(&diagnostics, &snapshot).unwrap();
+        let changes = action.edit.unwrap().changes.unwrap();
+        let text_edits = changes.values().next().unwrap();
+
+        assert_eq!(text_edits.len(), 1);
+        assert_eq!(text_edits[0].range.start, types::Position::new(0, 0));
+        assert_eq!(text_edits[0].range.end, types::Position::new(3, 0));
+    }
+
+    #[test]
+    fn test_fix_all_not_offered_without_fixes() {
+        assert!(apply_fix_all("x <- 1\n").is_none());
+    }
+
+    #[test]
+    fn test_fix_all_action_properties() {
+        let content = "x = 1\n";
+        let env = TestEnv::new(content);
+        let snapshot = env.create_snapshot(content);
+
+        let diagnostics = lint::lint_document(&snapshot).unwrap().diagnostics;
+        let action = Server::fix_all_code_action(&diagnostics, &snapshot).unwrap();
+
+        assert_eq!(action.title, "Fix all auto-fixable problems");
+        assert_eq!(
+            action.kind,
+            Some(types::CodeActionKind::from(
+                "source.fixAll.jarl".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_code_actions_filtered_by_requested_kind() {
+        let content = "x = 1\n";
+        let env = TestEnv::new(content);
+        let snapshot = env.create_snapshot(content);
+
+        let params = |only: Option<Vec<types::CodeActionKind>>| types::CodeActionParams {
+            text_document: types::TextDocumentIdentifier {
+                uri: Url::from_file_path(&env.file_path).unwrap(),
+            },
+            range: Range::new(Position::new(0, 0), Position::new(0, 5)),
+            context: types::CodeActionContext { diagnostics: vec![], only, trigger_kind: None },
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+        let kinds = |only: Option<Vec<types::CodeActionKind>>| -> Vec<String> {
+            Server::generate_code_actions(&snapshot, &params(only))
+                .unwrap()
+                .into_iter()
+                .filter_map(|action| match action {
+                    types::CodeActionOrCommand::CodeAction(action) => action.kind,
+                    types::CodeActionOrCommand::Command(_) => None,
+                })
+                .map(|kind| kind.as_str().to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            kinds(None),
+            vec!["source.fixAll.jarl", "quickfix", "quickfix"]
+        );
+        assert_eq!(
+            kinds(Some(vec![types::CodeActionKind::SOURCE_FIX_ALL])),
+            vec!["source.fixAll.jarl"]
+        );
+        assert_eq!(
+            kinds(Some(vec![types::CodeActionKind::QUICKFIX])),
+            vec!["quickfix", "quickfix"]
+        );
+    }
+
     // =========================================================================
     // Unicode tests (using real linter)
     // =========================================================================
@@ -1610,4 +2368,31 @@ x |>
         assert!(!ranges_overlap(&range1, &range4));
         assert!(ranges_overlap(&range1, &range1));
     }
+
+    #[test]
+    fn test_is_kind_requested() {
+        let fix_all = types::CodeActionKind::from("source.fixAll.jarl".to_string());
+
+        assert!(is_kind_requested(None, &fix_all));
+        assert!(is_kind_requested(
+            Some([types::CodeActionKind::SOURCE].as_slice()),
+            &fix_all
+        ));
+        assert!(is_kind_requested(
+            Some([types::CodeActionKind::SOURCE_FIX_ALL].as_slice()),
+            &fix_all
+        ));
+        assert!(is_kind_requested(
+            Some([fix_all.clone()].as_slice()),
+            &fix_all
+        ));
+        assert!(!is_kind_requested(
+            Some([types::CodeActionKind::QUICKFIX].as_slice()),
+            &fix_all
+        ));
+        assert!(!is_kind_requested(
+            Some([types::CodeActionKind::from("source.fix".to_string())].as_slice()),
+            &fix_all
+        ));
+    }
 }
@@ -6,21 +6,26 @@
 use anyhow::{Result, anyhow};
 use lsp_types::{
     ClientCapabilities, CodeActionKind, CodeActionOptions, CodeActionProviderCapability,
-    InitializeParams, InitializeResult, SaveOptions, ServerCapabilities, ServerInfo,
-    TextDocumentSyncCapability, TextDocumentSyncKind, TextDocumentSyncOptions, Url,
-    WorkDoneProgressOptions,
+    FoldingRangeProviderCapability, InitializeParams, InitializeResult, OneOf, SaveOptions,
+    ServerCapabilities, ServerInfo, TextDocumentSyncCapability, TextDocumentSyncKind,
+    TextDocumentSyncOptions, Url, WorkDoneProgressOptions,
 };
 use rustc_hash::FxHashMap;
 use serde::Deserialize;
 
-use std::path::PathBuf;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use jarl_core::package_cache::PackageCacheMap;
+use jarl_core::toml::find_jarl_toml;
 
-use crate::LspResult;
+use crate::cancellation::LintTasks;
 use crate::client::Client;
+use crate::client_settings::ClientSettings;
+use crate::config_watch::{ConfigReload, config_watcher_registration, enabled_rules};
 use crate::document::{DocumentKey, DocumentVersion, PositionEncoding, TextDocument};
+use crate::{FIX_ALL_CODE_ACTION_KIND, LspResult};
 
 /// Initialization options sent by the client
 #[derive(Debug, Deserialize, Default)]
@@ -30,6 +35,11 @@ pub struct InitializationOptions {
     pub log_level: Option<String>,
     /// Log levels for dependencies
     pub dependency_log_levels: Option<String>,
+    /// Only provide diagnostics, without code actions that modify files
+    #[serde(default)]
+    pub read_only: bool,
+    /// Settings merged with `jarl.toml`, see [ClientSettings]
+    pub settings: Option<serde_json::Value>,
 }
 
 /// Main session state for the LSP server
@@ -51,6 +61,16 @@ pub struct Session {
     /// Per-project package caches for package-specific rules. Keyed by R
     /// project root so that renv and system projects get separate caches.
     package_cache_map: Arc<PackageCacheMap>,
+    /// Rules enabled by each configuration file used by an open document, so
+    /// that we can report what changed when one of them is modified.
+    config_rules: FxHashMap<PathBuf, BTreeSet<String>>,
+    /// Whether code actions that modify files are disabled, either with
+    /// `jarl server --read-only` or the `readOnly` initialization option.
+    read_only: bool,
+    /// Settings sent by the editor, merged with `jarl.toml`
+    client_settings: Arc<ClientSettings>,
+    /// Lint tasks in progress, cancelled when they become outdated
+    lint_tasks: LintTasks,
 }
 
 /// Immutable snapshot of a document and its context
@@ -66,6 +86,8 @@ pub struct DocumentSnapshot {
     /// Shared reference to the session-level cache map. The lint code
     /// creates per-project caches on first use.
     package_cache_map: Arc<PackageCacheMap>,
+    /// Settings sent by the editor, merged with `jarl.toml`
+    client_settings: Arc<ClientSettings>,
 }
 
 impl Session {
@@ -85,6 +107,10 @@ impl Session {
             client,
             config_notification_shown: false,
             package_cache_map: Arc::new(PackageCacheMap::new()),
+            config_rules: FxHashMap::default(),
+            read_only: false,
+            client_settings: Arc::new(ClientSettings::default()),
+            lint_tasks: LintTasks::default(),
         }
     }
 
@@ -107,6 +133,23 @@ impl Session {
             self.workspace_roots = vec![PathBuf::from(root_path)];
         }
 
+        if let Some(options) = params.initialization_options {
+            match serde_json::from_value::<InitializationOptions>(options) {
+                Ok(options) => {
+                    self.read_only |= options.read_only;
+                    if let Some(settings) = options.settings
+                        && let Err(e) = self.update_client_settings(settings)
+                    {
+                        tracing::warn!("Invalid settings: {}", e);
+                    }
+                }
+                Err(e) => tracing::warn!("Invalid initialization options: {}", e),
+            }
+        }
+        if self.read_only {
+            tracing::info!("Read-only mode: code actions are disabled");
+        }
+
         tracing::info!(
             "Initialized Jarl LSP with {} workspace roots (diagnostics only)",
             self.workspace_roots.len()
@@ -138,11 +181,20 @@ impl Session {
             // Add code action support for quick fixes
             hover_provider: None,
             completion_provider: None,
-            code_action_provider: Some(CodeActionProviderCapability::Options(CodeActionOptions {
-                code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
-                resolve_provider: Some(false),
-                work_done_progress_options: WorkDoneProgressOptions::default(),
-            })),
+            // All code actions modify files, so none are advertised in read-only mode.
+            code_action_provider: (!self.read_only).then(|| {
+                CodeActionProviderCapability::Options(CodeActionOptions {
+                    code_action_kinds: Some(vec![
+                        CodeActionKind::QUICKFIX,
+                        CodeActionKind::from(FIX_ALL_CODE_ACTION_KIND.to_string()),
+                    ]),
+                    resolve_provider: Some(false),
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                })
+            }),
+            // Outline and folding of the document, computed from the syntax tree
+            document_symbol_provider: Some(OneOf::Left(true)),
+            folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
             workspace: None,
             ..Default::default()
         }
@@ -211,6 +263,7 @@ impl Session {
             position_encoding: self.position_encoding,
             client_capabilities: self.client_capabilities.clone(),
             package_cache_map: Arc::clone(&self.package_cache_map),
+            client_settings: Arc::clone(&self.client_settings),
         })
     }
 
@@ -219,6 +272,11 @@ impl Session {
         &self.package_cache_map
     }
 
+    /// Get the lint tasks in progress
+    pub fn lint_tasks(&mut self) -> &mut LintTasks {
+        &mut self.lint_tasks
+    }
+
     /// Get all open document URIs
     pub fn open_documents(&self) -> impl Iterator<Item = &Url> {
         self.documents.keys().map(|key| key.uri())
@@ -234,6 +292,11 @@ impl Session {
         &self.client_capabilities
     }
 
+    /// Get the settings sent by the editor
+    pub fn client_settings(&self) -> &ClientSettings {
+        &self.client_settings
+    }
+
     /// Get the workspace roots
     pub fn workspace_roots(&self) -> &[PathBuf] {
         &self.workspace_roots
@@ -250,6 +313,23 @@ impl Session {
         self.shutdown_requested
     }
 
+    /// Disable code actions that modify files
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only = read_only;
+    }
+
+    /// Check if code actions that modify files are disabled
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Replace the settings sent by the editor. Invalid settings are
+    /// rejected and the previous ones are kept.
+    pub fn update_client_settings(&mut self, settings: serde_json::Value) -> Result<()> {
+        self.client_settings = Arc::new(ClientSettings::from_value(settings)?);
+        Ok(())
+    }
+
     /// Get the client for sending messages
     pub fn client(&self) -> &Client {
         &self.client
@@ -260,6 +340,76 @@ impl Session {
         self.documents.len()
     }
 
+    /// Ask the client to notify us when a configuration file changes, if it
+    /// supports registering file watchers dynamically.
+    pub fn register_config_watcher(&self) -> LspResult<()> {
+        let supported = self
+            .client_capabilities
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.did_change_watched_files.as_ref())
+            .and_then(|watched_files| watched_files.dynamic_registration)
+            .unwrap_or(false);
+
+        if !supported {
+            tracing::info!(
+                "Client doesn't support watching files, jarl.toml won't be hot-reloaded"
+            );
+            return Ok(());
+        }
+
+        self.client
+            .send_request::<lsp_types::request::RegisterCapability>(
+                lsp_types::RegistrationParams {
+                    registrations: vec![config_watcher_registration()?],
+                },
+                |_| {},
+            )
+    }
+
+    /// Remember the rules enabled by the configuration file that applies to
+    /// `file_path`, if any.
+    pub fn track_config(&mut self, file_path: &Path) {
+        let Some(config_path) = file_path.parent().and_then(find_jarl_toml) else {
+            return;
+        };
+        if self.config_rules.contains_key(&config_path) {
+            return;
+        }
+
+        match enabled_rules(&config_path) {
+            Ok(rules) => {
+                self.config_rules.insert(config_path, rules);
+            }
+            Err(e) => {
+                tracing::debug!("Failed to resolve rules of {}: {e}", config_path.display());
+            }
+        }
+    }
+
+    /// Re-resolve the configuration file at `config_path` after it was changed
+    /// or `deleted` on disk.
+    ///
+    /// If the new configuration is invalid, the previous rules are kept so that
+    /// the next valid change is compared to the last valid configuration.
+    pub fn reload_config(&mut self, config_path: &Path, deleted: bool) -> ConfigReload {
+        if deleted {
+            self.config_rules.remove(config_path);
+            return ConfigReload::Removed;
+        }
+
+        match enabled_rules(config_path) {
+            Ok(after) => match self
+                .config_rules
+                .insert(config_path.to_path_buf(), after.clone())
+            {
+                Some(before) => ConfigReload::from_rules(&before, &after),
+                None => ConfigReload::Loaded,
+            },
+            Err(e) => ConfigReload::Invalid(format!("{e:#}")),
+        }
+    }
+
     /// Check and notify about config file location if needed
     /// Returns true if notification was shown, false otherwise
     pub fn check_and_notify_config(&mut self, file_path: &std::path::Path) -> bool {
@@ -345,9 +495,16 @@ impl DocumentSnapshot {
             position_encoding,
             client_capabilities,
             package_cache_map: Arc::new(PackageCacheMap::new()),
+            client_settings: Arc::new(ClientSettings::default()),
         }
     }
 
+    /// Use the settings sent by the editor instead of the default ones
+    pub fn with_client_settings(mut self, client_settings: ClientSettings) -> Self {
+        self.client_settings = Arc::new(client_settings);
+        self
+    }
+
     /// Get the document content
     pub fn content(&self) -> &str {
         self.document.content()
@@ -549,6 +706,78 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_server_capabilities_read_only() {
+        let mut session = create_test_session();
+        assert!(session.server_capabilities().code_action_provider.is_some());
+
+        session.set_read_only(true);
+        let caps = session.server_capabilities();
+        assert!(caps.code_action_provider.is_none());
+        assert!(caps.text_document_sync.is_some());
+        // Symbols and folding ranges don't modify files.
+        assert!(caps.document_symbol_provider.is_some());
+        assert!(caps.folding_range_provider.is_some());
+    }
+
+    #[test]
+    fn test_read_only_initialization_option() {
+        let mut session = create_test_session();
+        let params = InitializeParams {
+            initialization_options: Some(serde_json::json!({ "readOnly": true })),
+            ..Default::default()
+        };
+        let result = session.initialize(params).unwrap();
+
+        assert!(session.is_read_only());
+        assert!(result.capabilities.code_action_provider.is_none());
+    }
+
+    #[test]
+    fn test_reload_config() {
+        use std::fs;
+
+        let mut session = create_test_session();
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("jarl.toml");
+        fs::write(&config_path, "[lint]\nselect = [\"any_is_na\"]\n").unwrap();
+        let test_file = temp_dir.path().join("test.R");
+        fs::write(&test_file, "x <- 1\n").unwrap();
+
+        session.track_config(&test_file);
+
+        fs::write(&config_path, "[lint]\nselect = [\"any_duplicated\"]\n").unwrap();
+        assert_eq!(
+            session.reload_config(&config_path, false),
+            ConfigReload::Changed {
+                enabled: vec!["any_duplicated".to_string()],
+                disabled: vec!["any_is_na".to_string()],
+            }
+        );
+
+        // An invalid configuration doesn't replace the last valid one.
+        fs::write(&config_path, "[lint]\nselect = [\"not_a_rule\"]\n").unwrap();
+        assert!(matches!(
+            session.reload_config(&config_path, false),
+            ConfigReload::Invalid(_)
+        ));
+        fs::write(&config_path, "[lint]\nselect = [\"any_duplicated\"]\n").unwrap();
+        assert_eq!(
+            session.reload_config(&config_path, false),
+            ConfigReload::Changed { enabled: vec![], disabled: vec![] }
+        );
+
+        assert_eq!(
+            session.reload_config(&config_path, true),
+            ConfigReload::Removed
+        );
+        assert_eq!(
+            session.reload_config(&config_path, false),
+            ConfigReload::Loaded
+        );
+    }
+
     #[test]
     fn test_config_notification_shown_for_parent_config() {
         use std::fs;
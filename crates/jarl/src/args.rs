@@ -2,7 +2,9 @@ use crate::logging::LogLevel;
 use crate::output_format::OutputFormat;
 use clap::builder::Styles;
 use clap::builder::styling::{AnsiColor, Effects};
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
+use jarl_core::diagnostic::Severity;
+use jarl_core::rule_set::Profile;
 
 // Configures Clap v3-style help menu colors
 const STYLES: Styles = Styles::styled()
@@ -32,18 +34,43 @@ pub(crate) enum Command {
     /// Check a set of files or directories
     Check(Box<CheckCommand>),
 
+    /// Apply the automatic fixes to a set of files or directories
+    Fix(Box<FixCommand>),
+
+    /// Print the resolved configuration for a file or directory
+    Config(ConfigCommand),
+
+    /// Browse the violations in an interactive terminal interface
+    Tui(Box<TuiCommand>),
+
+    /// Create a starter `jarl.toml` for a project
+    Init(InitCommand),
+
+    /// Convert a `.lintr` file to a `jarl.toml`
+    MigrateLintr(MigrateLintrCommand),
+
     /// Print the documentation of a rule
     Rule(RuleCommand),
 
     /// Start a language server
     Server(ServerCommand),
+
+    /// Generate shell completions
+    Completions(CompletionsCommand),
+
+    /// Generate a manpage
+    Man(ManCommand),
+
+    /// Manage the Jarl executable
+    #[command(name = "self")]
+    Self_(SelfNamespace),
 }
 
 #[derive(Clone, Debug, Parser)]
 #[command(arg_required_else_help(true), disable_help_flag = true)]
 pub struct CheckCommand {
     #[arg(
-        required = true,
+        required_unless_present = "explain",
         help = "List of files or directories to check or fix lints, for example `jarl check .`."
     )]
     pub files: Vec<String>,
@@ -56,6 +83,15 @@ pub struct CheckCommand {
         help = "List of file patterns to exclude from linting, separated by a comma (no spaces). Must be passed with an equals sign, e.g. `--exclude=R/*.R`, so the shell does not expand glob patterns."
     )]
     pub exclude: Vec<String>,
+    #[arg(
+        long,
+        value_name = "FILES",
+        value_delimiter = ',',
+        require_equals = true,
+        help_heading = "File selection",
+        help = "Like `--exclude`, but the patterns are added to `exclude` in `jarl.toml` and resolved relative to its directory."
+    )]
+    pub extend_exclude: Vec<String>,
     #[arg(
         long,
         default_value = "false",
@@ -63,6 +99,25 @@ pub struct CheckCommand {
         help = "Do not apply the default set of file patterns that should be excluded."
     )]
     pub no_default_exclude: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        help_heading = "File selection",
+        help = "Follow symbolic links to files and directories. Symbolic links that form a cycle are skipped."
+    )]
+    pub follow_symlinks: bool,
+    #[arg(
+        long,
+        value_name = "FILENAME",
+        conflicts_with = "fix",
+        conflicts_with = "unsafe_fixes",
+        conflicts_with = "fix_only",
+        conflicts_with = "diff",
+        conflicts_with = "add_jarl_ignore",
+        help_heading = "File selection",
+        help = "Read the code to check from stdin, passed as `-`, and report it as the content of this file, e.g. `jarl check --stdin-filename R/foo.R -`. The settings are found from the directory of this file, which doesn't need to exist."
+    )]
+    pub stdin_filename: Option<String>,
     #[arg(
         short,
         long,
@@ -90,6 +145,21 @@ pub struct CheckCommand {
         help = "Names of rules to exclude, separated by a comma (no spaces). This also accepts names of groups of rules, such as \"PERF\"."
     )]
     pub ignore: String,
+    #[arg(
+        long,
+        value_name = "PROFILE",
+        conflicts_with = "select",
+        help_heading = "Rule selection",
+        help = "Set of rules to use instead of the default one: `minimal`, `recommended`, or `strict`. Same as `profile` in `jarl.toml`."
+    )]
+    pub profile: Option<Profile>,
+    #[arg(
+        long,
+        default_value = "false",
+        help_heading = "Rule selection",
+        help = "Enable the rules in preview, which are not used otherwise. Same as `preview = true` in `jarl.toml`."
+    )]
+    pub preview: bool,
     #[arg(
         short,
         long,
@@ -101,11 +171,14 @@ pub struct CheckCommand {
     #[arg(
         short,
         long,
-        default_value = "false",
+        value_name = "RULES",
+        default_missing_value = "",
+        num_args = 0..=1,
+        require_equals = true,
         help_heading = "Other options",
-        help = "Include fixes that may not retain the original intent of the  code."
+        help = "Include fixes that may not retain the original intent of the  code. Pass names of rules separated by a comma (no spaces) to only include their fixes, e.g. `--unsafe-fixes=all_equal,download_file`."
     )]
-    pub unsafe_fixes: bool,
+    pub unsafe_fixes: Option<String>,
     #[arg(
         long,
         default_value = "false",
@@ -113,6 +186,16 @@ pub struct CheckCommand {
         help = "Apply fixes to resolve lint violations, but don't report on leftover violations. Implies `--fix`."
     )]
     pub fix_only: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        conflicts_with = "fix_only",
+        conflicts_with = "statistics",
+        conflicts_with = "add_jarl_ignore",
+        help_heading = "Other options",
+        help = "Print the fixes as a unified diff instead of applying them. Files are not modified. Implies `--fix`."
+    )]
+    pub diff: bool,
     #[arg(
         long,
         default_value = "false",
@@ -127,6 +210,17 @@ pub struct CheckCommand {
         help = "Apply fixes even if there is no version control system."
     )]
     pub allow_no_vcs: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        conflicts_with = "diff",
+        conflicts_with = "allow_no_vcs",
+        conflicts_with = "add_jarl_ignore",
+        conflicts_with = "stdin_filename",
+        help_heading = "Other options",
+        help = "Apply the fixes to the content staged in Git, e.g. in a pre-commit hook, and stage the result. Unstaged changes are kept in the working tree. Implies `--fix`."
+    )]
+    pub respect_staged: bool,
     #[arg(
         short,
         long,
@@ -150,6 +244,37 @@ pub struct CheckCommand {
         help="Output serialization format for violations."
     )]
     pub output_format: OutputFormat,
+    #[arg(
+        long,
+        help_heading = "Other options",
+        help = "Maximum width of the output. Long lines of code are truncated to fit in this width. Defaults to the width of the terminal."
+    )]
+    pub max_width: Option<usize>,
+    #[arg(
+        long,
+        value_name = "N",
+        conflicts_with = "statistics",
+        help_heading = "Other options",
+        help = "Maximum number of violations to print with `--output-format full`, `concise`, or `grouped`. The summary still counts all violations."
+    )]
+    pub max_diagnostics: Option<usize>,
+    #[arg(
+        short,
+        long,
+        default_value = "false",
+        conflicts_with = "statistics",
+        conflicts_with = "max_diagnostics",
+        help_heading = "Other options",
+        help = "Only print the summary with `--output-format full`, `concise`, or `grouped`, without the violations."
+    )]
+    pub quiet: bool,
+    #[arg(
+        long,
+        value_name = "RULE",
+        help_heading = "Other options",
+        help = "Print the documentation of a rule instead of checking files, e.g. `jarl check --explain any_is_na`."
+    )]
+    pub explain: Option<String>,
     #[arg(
         long,
         value_enum,
@@ -167,6 +292,14 @@ pub struct CheckCommand {
         help = "Show counts for every rule with at least one violation."
     )]
     pub statistics: bool,
+    #[arg(
+        long,
+        value_name = "SEVERITIES",
+        value_delimiter = ',',
+        help_heading = "Other options",
+        help = "Severities of violations that lead to a non-zero exit code, separated by a comma (no spaces), for example `--exit-nonzero-on error`. Defaults to all severities: `error,warning,info`."
+    )]
+    pub exit_nonzero_on: Vec<Severity>,
     #[arg(
         long,
         value_name = "REASON",
@@ -193,6 +326,380 @@ pub struct CheckCommand {
     )]
     pub help: Option<bool>,
 }
+
+#[derive(Clone, Debug, Parser)]
+#[command(arg_required_else_help(true), disable_help_flag = true)]
+pub struct FixCommand {
+    #[arg(
+        required = true,
+        help = "List of files or directories to fix, for example `jarl fix .`."
+    )]
+    pub files: Vec<String>,
+    #[arg(
+        long,
+        value_name = "FILES",
+        value_delimiter = ',',
+        require_equals = true,
+        help_heading = "File selection",
+        help = "List of file patterns to exclude from fixing, separated by a comma (no spaces). Must be passed with an equals sign, e.g. `--exclude=R/*.R`, so the shell does not expand glob patterns."
+    )]
+    pub exclude: Vec<String>,
+    #[arg(
+        long,
+        value_name = "FILES",
+        value_delimiter = ',',
+        require_equals = true,
+        help_heading = "File selection",
+        help = "Like `--exclude`, but the patterns are added to `exclude` in `jarl.toml`, as in `jarl check`."
+    )]
+    pub extend_exclude: Vec<String>,
+    #[arg(
+        long,
+        default_value = "false",
+        help_heading = "File selection",
+        help = "Do not apply the default set of file patterns that should be excluded."
+    )]
+    pub no_default_exclude: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        help_heading = "File selection",
+        help = "Follow symbolic links to files and directories, as in `jarl check`."
+    )]
+    pub follow_symlinks: bool,
+    #[arg(
+        short,
+        long,
+        value_name = "RULES",
+        default_value = "",
+        help_heading = "Rule selection",
+        help = "Names of rules to fix, separated by a comma (no spaces). This also accepts names of groups of rules, such as \"PERF\"."
+    )]
+    pub select: String,
+    #[arg(
+        short,
+        long,
+        value_name = "RULES",
+        default_value = "",
+        help_heading = "Rule selection",
+        help = "Like `--select` but adds additional rules in addition to those already specified."
+    )]
+    pub extend_select: String,
+    #[arg(
+        short,
+        long,
+        value_name = "RULES",
+        default_value = "",
+        help_heading = "Rule selection",
+        help = "Names of rules not to fix, separated by a comma (no spaces). This also accepts names of groups of rules, such as \"PERF\"."
+    )]
+    pub ignore: String,
+    #[arg(
+        long,
+        value_name = "PROFILE",
+        conflicts_with = "select",
+        help_heading = "Rule selection",
+        help = "Set of rules to fix instead of the default one, as in `jarl check`."
+    )]
+    pub profile: Option<Profile>,
+    #[arg(
+        long,
+        default_value = "false",
+        help_heading = "Rule selection",
+        help = "Enable the rules in preview, as in `jarl check`."
+    )]
+    pub preview: bool,
+    #[arg(
+        short,
+        long = "unsafe",
+        default_value = "false",
+        help_heading = "Other options",
+        help = "Include fixes that may not retain the original intent of the code."
+    )]
+    pub unsafe_fixes: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        help_heading = "Other options",
+        help = "Show which fixes would be applied without modifying the files."
+    )]
+    pub dry_run: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        help_heading = "Other options",
+        help = "Apply fixes even if the Git branch is not clean, meaning that there are uncommitted files."
+    )]
+    pub allow_dirty: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        help_heading = "Other options",
+        help = "Apply fixes even if there is no version control system."
+    )]
+    pub allow_no_vcs: bool,
+    #[arg(
+        short,
+        long,
+        help_heading = "Other options",
+        help = "The mimimum R version to be used by the linter. Some rules only work starting from a specific version."
+    )]
+    pub min_r_version: Option<String>,
+    // Same as in `CheckCommand`, so that it lands in the "Other options" group.
+    #[arg(
+        short,
+        long,
+        action = clap::ArgAction::Help,
+        help_heading = "Other options",
+        help = "Print help (see a summary with '-h')"
+    )]
+    pub help: Option<bool>,
+}
+
+#[derive(Clone, Debug, Parser)]
+pub struct ConfigCommand {
+    #[arg(
+        default_value = ".",
+        help = "File or directory for which to print the configuration, for example `jarl config R/utils.R`."
+    )]
+    pub path: String,
+    #[arg(
+        short,
+        long,
+        value_name = "RULES",
+        default_value = "",
+        help = "Names of rules to include, separated by a comma (no spaces), as in `jarl check`."
+    )]
+    pub select: String,
+    #[arg(
+        short,
+        long,
+        value_name = "RULES",
+        default_value = "",
+        help = "Like `--select` but adds additional rules in addition to those already specified."
+    )]
+    pub extend_select: String,
+    #[arg(
+        short,
+        long,
+        value_name = "RULES",
+        default_value = "",
+        help = "Names of rules to exclude, separated by a comma (no spaces), as in `jarl check`."
+    )]
+    pub ignore: String,
+    #[arg(
+        long,
+        value_name = "PROFILE",
+        conflicts_with = "select",
+        help = "Set of rules to use instead of the default one, as in `jarl check`."
+    )]
+    pub profile: Option<Profile>,
+    #[arg(
+        long,
+        default_value = "false",
+        help = "Enable the rules in preview, as in `jarl check`."
+    )]
+    pub preview: bool,
+    #[arg(
+        short,
+        long,
+        help = "The mimimum R version to be used by the linter. Some rules only work starting from a specific version."
+    )]
+    pub min_r_version: Option<String>,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = ConfigFormat::default(),
+        help = "Serialization format of the configuration."
+    )]
+    pub output_format: ConfigFormat,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ConfigFormat {
+    #[default]
+    /// Print the configuration as TOML
+    Toml,
+    /// Print the configuration as JSON
+    Json,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[command(disable_help_flag = true)]
+pub struct TuiCommand {
+    #[arg(
+        default_value = ".",
+        help = "List of files or directories to check, for example `jarl tui R/`."
+    )]
+    pub files: Vec<String>,
+    #[arg(
+        long,
+        value_name = "FILES",
+        value_delimiter = ',',
+        require_equals = true,
+        help_heading = "File selection",
+        help = "List of file patterns to exclude from linting, as in `jarl check`."
+    )]
+    pub exclude: Vec<String>,
+    #[arg(
+        long,
+        value_name = "FILES",
+        value_delimiter = ',',
+        require_equals = true,
+        help_heading = "File selection",
+        help = "Like `--exclude`, but the patterns are added to `exclude` in `jarl.toml`, as in `jarl check`."
+    )]
+    pub extend_exclude: Vec<String>,
+    #[arg(
+        long,
+        default_value = "false",
+        help_heading = "File selection",
+        help = "Do not apply the default set of file patterns that should be excluded."
+    )]
+    pub no_default_exclude: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        help_heading = "File selection",
+        help = "Follow symbolic links to files and directories, as in `jarl check`."
+    )]
+    pub follow_symlinks: bool,
+    #[arg(
+        short,
+        long,
+        value_name = "RULES",
+        default_value = "",
+        help_heading = "Rule selection",
+        help = "Names of rules to include, separated by a comma (no spaces), as in `jarl check`."
+    )]
+    pub select: String,
+    #[arg(
+        short,
+        long,
+        value_name = "RULES",
+        default_value = "",
+        help_heading = "Rule selection",
+        help = "Like `--select` but adds additional rules in addition to those already specified."
+    )]
+    pub extend_select: String,
+    #[arg(
+        short,
+        long,
+        value_name = "RULES",
+        default_value = "",
+        help_heading = "Rule selection",
+        help = "Names of rules to exclude, separated by a comma (no spaces), as in `jarl check`."
+    )]
+    pub ignore: String,
+    #[arg(
+        long,
+        value_name = "PROFILE",
+        conflicts_with = "select",
+        help_heading = "Rule selection",
+        help = "Set of rules to use instead of the default one, as in `jarl check`."
+    )]
+    pub profile: Option<Profile>,
+    #[arg(
+        long,
+        default_value = "false",
+        help_heading = "Rule selection",
+        help = "Enable the rules in preview, as in `jarl check`."
+    )]
+    pub preview: bool,
+    #[arg(
+        short,
+        long = "unsafe",
+        default_value = "false",
+        help_heading = "Other options",
+        help = "Allow applying fixes that may not retain the original intent of the code."
+    )]
+    pub unsafe_fixes: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        help_heading = "Other options",
+        help = "Apply fixes even if the Git branch is not clean, meaning that there are uncommitted files."
+    )]
+    pub allow_dirty: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        help_heading = "Other options",
+        help = "Apply fixes even if there is no version control system."
+    )]
+    pub allow_no_vcs: bool,
+    #[arg(
+        short,
+        long,
+        help_heading = "Other options",
+        help = "The mimimum R version to be used by the linter. Some rules only work starting from a specific version."
+    )]
+    pub min_r_version: Option<String>,
+    #[arg(
+        long,
+        value_name = "REASON",
+        default_value = "<reason>",
+        help_heading = "Other options",
+        help = "Reason written in the `# jarl-ignore` comments inserted from the interface."
+    )]
+    pub reason: String,
+    // Same as in `CheckCommand`, so that it lands in the "Other options" group.
+    #[arg(
+        short,
+        long,
+        action = clap::ArgAction::Help,
+        help_heading = "Other options",
+        help = "Print help (see a summary with '-h')"
+    )]
+    pub help: Option<bool>,
+}
+
+#[derive(Clone, Debug, Parser)]
+pub struct InitCommand {
+    #[arg(
+        default_value = ".",
+        help = "Directory in which to create `jarl.toml`, for example `jarl init path/to/project`."
+    )]
+    pub path: String,
+    #[arg(
+        long,
+        default_value = "false",
+        help = "Also create a Github Actions workflow in `.github/workflows/jarl.yml` that runs `jarl check`."
+    )]
+    pub github_action: bool,
+    #[arg(
+        long,
+        default_value = "false",
+        help = "Overwrite `jarl.toml` and the workflow if they already exist."
+    )]
+    pub force: bool,
+}
+
+#[derive(Clone, Debug, Parser)]
+pub struct MigrateLintrCommand {
+    #[arg(
+        default_value = ".",
+        help = "Directory containing the `.lintr` file, for example `jarl migrate-lintr path/to/project`. `jarl.toml` is created in the same directory. With `--convert-nolint`, directory containing the R files to modify."
+    )]
+    pub path: String,
+    #[arg(
+        long,
+        default_value = "false",
+        help = "Overwrite `jarl.toml` if it already exists."
+    )]
+    pub force: bool,
+    #[arg(
+        long,
+        value_name = "REASON",
+        default_missing_value = "<reason>",
+        num_args = 0..=1,
+        require_equals = true,
+        conflicts_with = "force",
+        help = "Instead of converting `.lintr`, convert the `# nolint` comments of the R files to `# jarl-ignore` comments.\nThe default reason can be customized with `--convert-nolint=\"my_reason\"`."
+    )]
+    pub convert_nolint: Option<String>,
+}
+
 #[derive(Clone, Debug, Parser)]
 #[command(arg_required_else_help(true))]
 pub struct RuleCommand {
@@ -204,7 +711,59 @@ pub struct RuleCommand {
 }
 
 #[derive(Clone, Debug, Parser)]
-pub(crate) struct ServerCommand {}
+pub(crate) struct ServerCommand {
+    #[arg(
+        long,
+        default_value = "false",
+        help = "Only provide diagnostics, without code actions that modify files (quick fixes, suppression comments)."
+    )]
+    pub read_only: bool,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[command(arg_required_else_help(true))]
+pub struct CompletionsCommand {
+    #[arg(
+        required = true,
+        value_enum,
+        help = "Shell to generate completions for, for example `jarl completions bash > jarl.bash`."
+    )]
+    pub shell: clap_complete::Shell,
+}
+
+#[derive(Clone, Debug, Parser)]
+pub struct ManCommand {}
+
+#[derive(Clone, Debug, clap::Args)]
+pub struct SelfNamespace {
+    #[command(subcommand)]
+    pub command: SelfCommand,
+}
+
+#[derive(Clone, Debug, Subcommand)]
+pub enum SelfCommand {
+    /// Update Jarl to the latest release
+    Update(SelfUpdateCommand),
+
+    /// Check that the Jarl executable matches the published release
+    Verify(SelfVerifyCommand),
+}
+
+#[derive(Clone, Debug, Parser)]
+pub struct SelfUpdateCommand {
+    #[arg(
+        help = "Version to install, for example `jarl self update 0.5.0`. Defaults to the latest release."
+    )]
+    pub target_version: Option<String>,
+    #[arg(
+        long,
+        help = "Download and verify the release without replacing the executable."
+    )]
+    pub dry_run: bool,
+}
+
+#[derive(Clone, Debug, Parser)]
+pub struct SelfVerifyCommand {}
 
 /// All configuration options that can be passed "globally"
 #[derive(Debug, Default, clap::Args)]
@@ -1,3 +1,11 @@
 pub(crate) mod check;
+pub(crate) mod completions;
+pub(crate) mod config;
+pub(crate) mod fix;
+pub(crate) mod init;
+pub(crate) mod man;
+pub(crate) mod migrate_lintr;
 pub(crate) mod rule;
+pub(crate) mod self_update;
 pub(crate) mod server;
+pub(crate) mod tui;
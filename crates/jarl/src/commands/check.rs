@@ -1,103 +1,70 @@
 use air_workspace::resolve::PathResolver;
-use jarl_core::discovery::{discover_r_file_paths, discover_settings, validate_exclude_patterns};
+use jarl_core::discovery::{
+    DEFAULT_EXCLUDE_PATTERNS, discover_r_file_paths, discover_settings, validate_exclude_patterns,
+};
 use jarl_core::library_paths::is_r_available;
-use jarl_core::package_cache::{PackageCache, any_file_references_packages, find_r_project_root};
+use jarl_core::package::find_package_root;
+use jarl_core::package_cache::{
+    PackageCache, any_file_references_packages_in, find_r_project_root_in,
+};
 use jarl_core::rule_set::Rule;
 use jarl_core::{
     config::ArgsConfig,
+    config::Config,
     config::build_config,
-    diagnostic::Diagnostic,
-    fs::has_rmd_extension,
+    diagnostic::{Diagnostic, Severity},
+    fs::{has_rmd_extension, normalize_path, relativize_path},
     settings::Settings,
     suppression_edit::{
         create_suppression_edit, create_suppression_edit_in_rmd, format_suppression_comments,
     },
 };
+use jarl_lsp::utils::{should_exclude_file, should_exclude_file_based_on_settings};
 
 use anyhow::Result;
 use colored::Colorize;
+use similar::TextDiff;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::env;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Instant;
 
 use crate::args::CheckCommand;
-use crate::output_format::{self, GithubEmitter, print_notes, print_summary, print_warnings};
+use crate::commands::rule::explain;
+use crate::output_format::{
+    self, GithubEmitter, print_hidden_diagnostics, print_hidden_rule_diagnostics, print_notes,
+    print_summary, print_warnings,
+};
 use crate::statistics::print_statistics;
 use crate::status::ExitStatus;
 
 use output_format::{
-    ConciseEmitter, Emitter, FullEmitter, JsonEmitter, OutputFormat, SarifEmitter,
+    CheckstyleEmitter, CompactEmitter, ConciseEmitter, Emitter, FullEmitter, GroupedEmitter,
+    JsonEmitter, JunitEmitter, OutputFormat, PrometheusEmitter, RdjsonEmitter, SarifEmitter,
+    TeamcityEmitter,
 };
 
 pub fn check(args: CheckCommand) -> Result<ExitStatus> {
-    let start = if args.with_timing {
-        Some(Instant::now())
-    } else {
-        None
-    };
-
-    // Fail fast on invalid `--exclude` glob patterns instead of silently
-    // ignoring them during discovery.
-    validate_exclude_patterns(&args.exclude)?;
-
-    let mut resolver = PathResolver::new(Settings::default());
-
-    // Track if we're using a config from a parent directory
-    let mut parent_config_path: Option<PathBuf> = None;
-    let cwd = env::current_dir().ok();
-
-    // Load discovered settings. If the user passed `--no-default-exclude`,
-    // override each discovered settings' `default_exclude` to `false` so the
-    // default patterns from `DEFAULT_EXCLUDE_PATTERNS` are not applied during
-    // discovery.
-    let discovered = discover_settings(&args.files)?;
-    let single_config = discovered.len() == 1;
-
-    for mut ds in discovered {
-        if args.no_default_exclude {
-            ds.settings.linter.default_exclude = Some(false);
-        }
-
-        // Only track parent config path when there's a single config (informative for that case)
-        if single_config
-            && let (Some(config_path), Some(current_dir)) = (&ds.config_path, &cwd)
-            && let Some(config_dir) = config_path.parent()
-            && config_dir != current_dir
-        {
-            parent_config_path = Some(config_path.clone());
-        }
-
-        resolver.add(&ds.directory, ds.settings);
+    if let Some(rule) = &args.explain {
+        return explain(rule);
     }
 
-    let paths = discover_r_file_paths(
-        &args.files,
-        &args.exclude,
-        &resolver,
-        true,
-        args.no_default_exclude,
-    )
-    .into_iter()
-    .filter_map(Result::ok)
-    .collect::<Vec<_>>();
-
-    if paths.is_empty() {
-        println!(
-            "{}: {}",
-            "Warning".yellow().bold(),
-            "No R files found under the given path(s).".white().bold()
-        );
-        return Ok(ExitStatus::Success);
-    }
+    let start = Instant::now();
 
     let check_config = ArgsConfig {
-        files: args.files.iter().map(|s| s.into()).collect(),
-        fix: args.fix,
-        unsafe_fixes: args.unsafe_fixes,
+        files: match &args.stdin_filename {
+            Some(stdin_filename) => vec![stdin_filename.into()],
+            None => args.files.iter().map(|s| s.into()).collect(),
+        },
+        // `--diff` computes the same fixes as `--fix` but doesn't write them.
+        fix: args.fix || args.diff || args.respect_staged,
+        unsafe_fixes: args.unsafe_fixes.is_some(),
+        unsafe_fixes_rules: args.unsafe_fixes.clone().unwrap_or_default(),
         fix_only: args.fix_only,
         select: args.select.clone(),
         extend_select: args.extend_select.clone(),
@@ -105,124 +72,93 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
         min_r_version: args.min_r_version.clone(),
         allow_dirty: args.allow_dirty,
         allow_no_vcs: args.allow_no_vcs,
+        respect_staged: args.respect_staged,
         assignment: args.assignment.clone(),
+        preview: args.preview,
+        profile: args.profile,
     };
 
-    // Group paths by their closest resolved config directory, so each file is
-    // checked with the settings from the nearest jarl.toml.
-    let mut groups: HashMap<Option<PathBuf>, Vec<PathBuf>> = HashMap::new();
-    for path in paths {
-        let key = resolver
-            .resolve(&path)
-            .map(|item| item.path().to_path_buf());
-        groups.entry(key).or_default().push(path);
-    }
-
-    // Track whether we've already verified R is available (avoid repeated checks).
-    let mut r_available_checked = false;
-    // Cache of project root - PackageCache to avoid duplicate Rscript calls.
-    let mut root_caches: HashMap<Option<PathBuf>, Option<Arc<PackageCache>>> = HashMap::new();
-
-    let mut file_results = Vec::new();
-    for (dir_key, group_paths) in groups {
-        let settings = dir_key
-            .as_deref()
-            .and_then(|dir| resolver.items().iter().find(|item| item.path() == dir))
-            .map(|item| item.value());
-
-        let config = build_config(&check_config, settings, group_paths.clone())?;
-
-        if !config.rules_to_apply.has_package_specific_rules() {
-            file_results.extend(jarl_core::check::check(config));
-            continue;
-        }
-
-        // Package-specific rules are enabled — need per-project-root caches.
-        if !r_available_checked {
-            if !is_r_available() {
-                let pkg_categories: Vec<_> = config
-                    .rules_to_apply
-                    .package_specific_categories()
-                    .into_iter()
-                    .map(|c| c.as_str())
-                    .collect();
-                return Err(anyhow::anyhow!(
-                    "Package-specific rules are enabled ({}) but R is not available.\n\n\
-                     These rules require R and installed packages to resolve function origins.\n\n\
-                     If running in CI with `setup-jarl`, uncomment (or add yourself) the R setup steps in your workflow:\n\n\
-                     \x20 - uses: r-lib/actions/setup-r@v2\n\
-                     \x20 - uses: r-lib/actions/setup-r-dependencies@v2\n\n\
-                     You can also disable these rules instead.",
-                    pkg_categories.join(", "),
-                ));
-            }
-            r_available_checked = true;
-        }
-
-        let r_pkg_names = config.rules_to_apply.pkg_names_from_category();
-        drop(config);
-
-        // Skip the expensive Rscript call if no file in this group actually
-        // references any of the target packages. In that case, strip the
-        // package-specific rules since they can't produce meaningful results
-        // without a PackageCache.
-        if !any_file_references_packages(&group_paths, &r_pkg_names) {
-            let mut config = build_config(&check_config, settings, group_paths)?;
-            config.rules_to_apply = config
-                .rules_to_apply
-                .filter(|r| !r.categories().iter().any(|c| c.is_package_specific()));
-            file_results.extend(jarl_core::check::check(config));
-            continue;
+    // With `--stdin-filename`, the code is read from stdin and checked as if
+    // it was the content of this file.
+    let stdin_contents = match &args.stdin_filename {
+        Some(_) if args.files != ["-"] => {
+            return Err(anyhow::anyhow!(
+                "`--stdin-filename` requires `-` as the only path to check, e.g. `jarl check --stdin-filename R/foo.R -`."
+            ));
         }
-
-        // Sub-group files by R project root so each renv/system project
-        // gets its own PackageCache.
-        let mut by_root: HashMap<Option<PathBuf>, Vec<PathBuf>> = HashMap::new();
-        for path in &group_paths {
-            let root = find_r_project_root(path);
-            by_root.entry(root).or_default().push(path.clone());
+        Some(_) => Some(std::io::read_to_string(std::io::stdin())?),
+        None if args.files.iter().any(|file| file == "-") => {
+            return Err(anyhow::anyhow!(
+                "Checking code from stdin with `-` requires `--stdin-filename`, e.g. `jarl check --stdin-filename R/foo.R -`."
+            ));
         }
+        None => None,
+    };
 
-        for (root, sub_paths) in by_root {
-            let mut config = build_config(&check_config, settings, sub_paths)?;
-
-            let cache = root_caches
-                .entry(root.clone())
-                .or_insert_with(|| {
-                    PackageCache::from_rscript(&r_pkg_names, root.as_deref()).map(Arc::new)
-                })
-                .clone();
-
-            config.package_cache = cache;
-            file_results.extend(jarl_core::check::check(config));
-        }
+    let workspace = match &args.stdin_filename {
+        Some(stdin_filename) => resolve_stdin_workspace(
+            stdin_filename,
+            &args.exclude,
+            &args.extend_exclude,
+            args.no_default_exclude,
+            &check_config,
+        )?,
+        None => resolve_workspace(
+            &args.files,
+            &args.exclude,
+            &args.extend_exclude,
+            args.no_default_exclude,
+            args.follow_symlinks,
+            &check_config,
+        )?,
+    };
+    let Some(workspace) = workspace else {
+        return Ok(ExitStatus::Success);
+    };
+    let Workspace {
+        resolver,
+        parent_config_path,
+        configs,
+        files_checked,
+    } = workspace;
+
+    if args.diff {
+        return print_fix_diffs(configs);
     }
 
+    let file_results: Vec<_> = match &stdin_contents {
+        Some(contents) => configs
+            .into_iter()
+            .flat_map(|config| jarl_core::check::check_contents(config, contents))
+            .collect(),
+        None => jarl_core::check::check_configs(configs),
+    };
+
     let mut all_errors = Vec::new();
     let mut all_diagnostics = Vec::new();
 
+    // The same file can be checked several times when it is reached with
+    // different paths, e.g. `jarl check R R/foo.R` or through a symlink. Report
+    // each violation and each error only once.
+    let mut seen_diagnostics = HashSet::new();
+    let mut seen_errors = HashSet::new();
+
     for (path, result) in file_results {
+        let canonical_path = std::fs::canonicalize(&path).unwrap_or_else(|_| PathBuf::from(&path));
         match result {
-            Ok(diagnostics) => {
+            Ok(mut diagnostics) => {
+                diagnostics.retain(|diagnostic| {
+                    seen_diagnostics.insert(diagnostic.fingerprint(&canonical_path))
+                });
                 if !diagnostics.is_empty() {
                     all_diagnostics.push((path, diagnostics));
                 }
             }
-            // The parser recovers from syntax errors, so a file that fails to
-            // parse still carries the diagnostics found in its valid code:
-            // report those alongside the error.
-            Err(e) => match e.downcast::<jarl_core::error::ParseError>() {
-                Ok(mut parse_error) => {
-                    let diagnostics = std::mem::take(&mut parse_error.diagnostics);
-                    if !diagnostics.is_empty() {
-                        all_diagnostics.push((path.clone(), diagnostics));
-                    }
-                    all_errors.push((path, parse_error.into()));
-                }
-                Err(e) => {
+            Err(e) => {
+                if seen_errors.insert(canonical_path) {
                     all_errors.push((path, e));
                 }
-            },
+            }
         }
     }
 
@@ -242,15 +178,46 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
 
     all_diagnostics_flat.sort();
 
+    let status = exit_status(&all_diagnostics_flat, &args.exit_nonzero_on);
+
     if args.statistics {
-        return print_statistics(&all_diagnostics_flat, parent_config_path);
+        print_statistics(&all_diagnostics_flat, parent_config_path)?;
+        return Ok(status);
     }
 
     let mut stdout = std::io::stdout();
 
+    // For human-readable formats, only print the first `--max-diagnostics`
+    // diagnostics, or none with `--quiet`. The summary still counts all of them.
+    let is_human_format = matches!(
+        args.output_format,
+        OutputFormat::Full | OutputFormat::Concise | OutputFormat::Grouped
+    );
+    // `max-per-rule-per-file` is applied before `--max-diagnostics`.
+    let (capped_diagnostics, hidden_by_rule) = if is_human_format {
+        cap_diagnostics_per_rule_per_file(&all_diagnostics_flat, &resolver)
+    } else {
+        (all_diagnostics_flat.clone(), Vec::new())
+    };
+    let n_shown = if args.quiet {
+        0
+    } else {
+        args.max_diagnostics.unwrap_or(usize::MAX)
+    };
+    let shown_diagnostics = &capped_diagnostics[..n_shown.min(capped_diagnostics.len())];
+
     match args.output_format {
         OutputFormat::Concise => {
-            ConciseEmitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
+            ConciseEmitter.emit(&mut stdout, shown_diagnostics, &all_errors)?;
+        }
+        OutputFormat::Grouped => {
+            GroupedEmitter.emit(&mut stdout, shown_diagnostics, &all_errors)?;
+        }
+        OutputFormat::Compact => {
+            CompactEmitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
+        }
+        OutputFormat::Checkstyle => {
+            CheckstyleEmitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
         }
         OutputFormat::Json => {
             JsonEmitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
@@ -258,24 +225,45 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
         OutputFormat::Github => {
             GithubEmitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
         }
+        OutputFormat::Junit => {
+            JunitEmitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
+        }
+        OutputFormat::Prometheus => {
+            let emitter = PrometheusEmitter { files_checked, duration: start.elapsed() };
+            emitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
+        }
+        OutputFormat::Rdjson => {
+            let emitter = RdjsonEmitter { stdin_contents, staged: args.respect_staged };
+            emitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
+        }
         OutputFormat::Sarif => {
-            SarifEmitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
+            let emitter = SarifEmitter { stdin_contents, staged: args.respect_staged };
+            emitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
+        }
+        OutputFormat::Teamcity => {
+            TeamcityEmitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
         }
         OutputFormat::Full => {
-            FullEmitter.emit(&mut stdout, &all_diagnostics_flat, &all_errors)?;
+            let max_width = args.max_width.or_else(output_format::terminal_width);
+            let emitter = FullEmitter {
+                max_width,
+                stdin_contents,
+                staged: args.respect_staged,
+            };
+            emitter.emit(&mut stdout, shown_diagnostics, &all_errors)?;
         }
     }
 
     // For human-readable formats, print sections (summary, warnings, notes).
     // Skip for JSON/GitHub to avoid corrupting structured output.
-    let is_human_format = matches!(
-        args.output_format,
-        OutputFormat::Full | OutputFormat::Concise
-    );
-
     if is_human_format {
+        if !args.quiet {
+            print_hidden_rule_diagnostics(&hidden_by_rule);
+            print_hidden_diagnostics(capped_diagnostics.len() - shown_diagnostics.len());
+        }
+
         // ── Summary ──
-        print_summary(&all_diagnostics_flat, !all_errors.is_empty());
+        print_summary(&all_diagnostics_flat, !all_errors.is_empty(), args.quiet);
 
         // ── Warnings ──
         let mut warnings: Vec<String> = Vec::new();
@@ -344,7 +332,7 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
         // ── Notes ──
         let mut notes: Vec<String> = Vec::new();
 
-        if let Some(start) = start {
+        if args.with_timing {
             let duration = start.elapsed();
             notes.push(format!("Checked files in: {duration:?}"));
         }
@@ -360,11 +348,346 @@ pub fn check(args: CheckCommand) -> Result<ExitStatus> {
         return Ok(ExitStatus::Error);
     }
 
-    if all_diagnostics.is_empty() {
-        return Ok(ExitStatus::Success);
+    Ok(status)
+}
+
+/// The R files to check, with the configurations that apply to them.
+pub(crate) struct Workspace {
+    pub(crate) resolver: PathResolver<Settings>,
+    /// The `jarl.toml` used, if it comes from a parent directory.
+    pub(crate) parent_config_path: Option<PathBuf>,
+    /// One config per group of files sharing the same `jarl.toml` (and R
+    /// project root if package-specific rules are enabled).
+    pub(crate) configs: Vec<Config>,
+    pub(crate) files_checked: usize,
+}
+
+/// Discover the R files under `files` and build the config of each of them.
+///
+/// Return `None` (after printing a warning) if no R file was found.
+pub(crate) fn resolve_workspace(
+    files: &[String],
+    exclude: &[String],
+    extend_exclude: &[String],
+    no_default_exclude: bool,
+    follow_symlinks: bool,
+    check_config: &ArgsConfig,
+) -> Result<Option<Workspace>> {
+    // Fail fast on invalid `--exclude` glob patterns instead of silently
+    // ignoring them during discovery.
+    validate_exclude_patterns(exclude, "--exclude")?;
+    validate_exclude_patterns(extend_exclude, "--extend-exclude")?;
+
+    let (resolver, parent_config_path) =
+        resolve_settings(files, no_default_exclude, extend_exclude)?;
+    let exclude = cli_exclude_patterns(exclude, extend_exclude, &resolver);
+
+    let paths = discover_r_file_paths(
+        files,
+        &exclude,
+        &resolver,
+        true,
+        no_default_exclude,
+        follow_symlinks,
+    )
+    .into_iter()
+    .filter_map(Result::ok)
+    .collect::<Vec<_>>();
+
+    if paths.is_empty() {
+        println!(
+            "{}: {}",
+            "Warning".yellow().bold(),
+            "No R files found under the given path(s).".white().bold()
+        );
+        return Ok(None);
+    }
+
+    let files_checked = paths.len();
+    let configs = build_configs(paths, &resolver, check_config)?;
+
+    Ok(Some(Workspace {
+        resolver,
+        parent_config_path,
+        configs,
+        files_checked,
+    }))
+}
+
+/// Resolve the settings for code read from stdin, which is checked as if it
+/// was the content of `path`. The file doesn't need to exist.
+///
+/// Return `None` if `path` is excluded, either by `exclude` or by the settings.
+pub(crate) fn resolve_stdin_workspace(
+    path: &str,
+    exclude: &[String],
+    extend_exclude: &[String],
+    no_default_exclude: bool,
+    check_config: &ArgsConfig,
+) -> Result<Option<Workspace>> {
+    validate_exclude_patterns(exclude, "--exclude")?;
+    validate_exclude_patterns(extend_exclude, "--extend-exclude")?;
+
+    let path = normalize_path(path);
+    let (resolver, parent_config_path) = resolve_settings(
+        std::slice::from_ref(&path),
+        no_default_exclude,
+        extend_exclude,
+    )?;
+    let exclude = cli_exclude_patterns(exclude, extend_exclude, &resolver);
+
+    // Without any `jarl.toml`, the default patterns are not part of the
+    // settings so they must be added here.
+    let mut patterns: Vec<&str> = exclude.iter().map(String::as_str).collect();
+    if resolver.items().is_empty() && !no_default_exclude {
+        patterns.extend_from_slice(DEFAULT_EXCLUDE_PATTERNS);
+    }
+    if should_exclude_file(&path, &patterns)
+        || should_exclude_file_based_on_settings(&path, &resolver)
+    {
+        return Ok(None);
     }
 
-    Ok(ExitStatus::Failure)
+    let configs = build_configs(vec![path], &resolver, check_config)?;
+
+    Ok(Some(Workspace {
+        resolver,
+        parent_config_path,
+        configs,
+        files_checked: 1,
+    }))
+}
+
+/// Patterns of `--exclude`, which are resolved relative to the current
+/// directory. Without any `jarl.toml`, the patterns of `--extend-exclude` are
+/// resolved the same way.
+fn cli_exclude_patterns(
+    exclude: &[String],
+    extend_exclude: &[String],
+    resolver: &PathResolver<Settings>,
+) -> Vec<String> {
+    if resolver.items().is_empty() {
+        [exclude, extend_exclude].concat()
+    } else {
+        exclude.to_vec()
+    }
+}
+
+/// Load the `jarl.toml` files that apply to `paths`.
+///
+/// The patterns of `--extend-exclude` are added to the `exclude` of each
+/// `jarl.toml`, so they are resolved relative to its directory.
+///
+/// Also return the path of the `jarl.toml` if there is a single one and it
+/// comes from a parent directory.
+pub(crate) fn resolve_settings<P: AsRef<Path>>(
+    paths: &[P],
+    no_default_exclude: bool,
+    extend_exclude: &[String],
+) -> Result<(PathResolver<Settings>, Option<PathBuf>)> {
+    let mut resolver = PathResolver::new(Settings::default());
+
+    // Track if we're using a config from a parent directory
+    let mut parent_config_path: Option<PathBuf> = None;
+    let cwd = env::current_dir().ok();
+
+    // Load discovered settings. If the user passed `--no-default-exclude`,
+    // override each discovered settings' `default_exclude` to `false` so the
+    // default patterns from `DEFAULT_EXCLUDE_PATTERNS` are not applied during
+    // discovery.
+    let discovered = discover_settings(paths)?;
+    let single_config = discovered.len() == 1;
+
+    for mut ds in discovered {
+        if no_default_exclude {
+            ds.settings.linter.default_exclude = Some(false);
+        }
+        if !extend_exclude.is_empty() {
+            ds.settings
+                .linter
+                .exclude
+                .get_or_insert_default()
+                .extend_from_slice(extend_exclude);
+        }
+
+        // Only track parent config path when there's a single config (informative for that case)
+        if single_config
+            && let (Some(config_path), Some(current_dir)) = (&ds.config_path, &cwd)
+            && let Some(config_dir) = config_path.parent()
+            && config_dir != current_dir
+        {
+            parent_config_path = Some(config_path.clone());
+        }
+
+        resolver.add(&ds.directory, ds.settings);
+    }
+
+    Ok((resolver, parent_config_path))
+}
+
+/// Build the config of each group of `paths` sharing the same settings (and R
+/// project root if package-specific rules are enabled).
+fn build_configs(
+    paths: Vec<PathBuf>,
+    resolver: &PathResolver<Settings>,
+    check_config: &ArgsConfig,
+) -> Result<Vec<Config>> {
+    // Group paths by their closest resolved config directory, so each file is
+    // checked with the settings from the nearest jarl.toml, and then by R
+    // package, so that each package of a monorepo is checked separately (e.g.
+    // with the minimum R version of its own DESCRIPTION).
+    let mut groups: HashMap<(Option<PathBuf>, Option<PathBuf>), Vec<PathBuf>> = HashMap::new();
+    for path in paths {
+        let dir_key = resolver
+            .resolve(&path)
+            .map(|item| item.path().to_path_buf());
+        let package_root = find_package_root(&path);
+        groups
+            .entry((dir_key, package_root))
+            .or_default()
+            .push(path);
+    }
+
+    // Track whether we've already verified R is available (avoid repeated checks).
+    let mut r_available_checked = false;
+    // Cache of project root - PackageCache to avoid duplicate Rscript calls.
+    let mut root_caches: HashMap<Option<PathBuf>, Option<Arc<PackageCache>>> = HashMap::new();
+
+    let mut configs = Vec::new();
+    for ((dir_key, _package_root), group_paths) in groups {
+        let settings = dir_key
+            .as_deref()
+            .and_then(|dir| resolver.items().iter().find(|item| item.path() == dir))
+            .map(|item| item.value());
+
+        let config = build_config(check_config, settings, group_paths.clone())?;
+
+        if !config.rules_to_apply.has_package_specific_rules() {
+            configs.push(config);
+            continue;
+        }
+
+        // Package-specific rules are enabled — need per-project-root caches.
+        if !r_available_checked {
+            if !is_r_available() {
+                let pkg_categories: Vec<_> = config
+                    .rules_to_apply
+                    .package_specific_categories()
+                    .into_iter()
+                    .map(|c| c.as_str())
+                    .collect();
+                return Err(anyhow::anyhow!(
+                    "Package-specific rules are enabled ({}) but R is not available.\n\n\
+                     These rules require R and installed packages to resolve function origins.\n\n\
+                     If running in CI with `setup-jarl`, uncomment (or add yourself) the R setup steps in your workflow:\n\n\
+                     \x20 - uses: r-lib/actions/setup-r@v2\n\
+                     \x20 - uses: r-lib/actions/setup-r-dependencies@v2\n\n\
+                     You can also disable these rules instead.",
+                    pkg_categories.join(", "),
+                ));
+            }
+            r_available_checked = true;
+        }
+
+        let r_pkg_names = config.rules_to_apply.pkg_names_from_category();
+        let file_system = Arc::clone(&config.file_system);
+        drop(config);
+
+        // Skip the expensive Rscript call if no file in this group actually
+        // references any of the target packages. In that case, strip the
+        // package-specific rules since they can't produce meaningful results
+        // without a PackageCache.
+        if !any_file_references_packages_in(&group_paths, &r_pkg_names, file_system.as_ref()) {
+            let mut config = build_config(check_config, settings, group_paths)?;
+            config.rules_to_apply = config
+                .rules_to_apply
+                .filter(|r| !r.categories().iter().any(|c| c.is_package_specific()));
+            configs.push(config);
+            continue;
+        }
+
+        // Sub-group files by R project root so each renv/system project
+        // gets its own PackageCache.
+        let mut by_root: HashMap<Option<PathBuf>, Vec<PathBuf>> = HashMap::new();
+        for path in &group_paths {
+            let root = find_r_project_root_in(path, file_system.as_ref());
+            by_root.entry(root).or_default().push(path.clone());
+        }
+
+        for (root, sub_paths) in by_root {
+            let mut config = build_config(check_config, settings, sub_paths)?;
+
+            let cache = root_caches
+                .entry(root.clone())
+                .or_insert_with(|| {
+                    PackageCache::from_rscript(&r_pkg_names, root.as_deref()).map(Arc::new)
+                })
+                .clone();
+
+            config.package_cache = cache;
+            configs.push(config);
+        }
+    }
+
+    Ok(configs)
+}
+
+/// Print the automatic fixes of all files as a unified diff, without modifying
+/// the files. The diff can be applied with `git apply`.
+///
+/// Exit with a failure if at least one file would be modified.
+fn print_fix_diffs(configs: Vec<Config>) -> Result<ExitStatus> {
+    let mut results: Vec<_> = configs
+        .into_iter()
+        .flat_map(jarl_core::check::preview_fixes)
+        .collect();
+    results.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut stdout = std::io::stdout().lock();
+    let mut status = ExitStatus::Success;
+    for (path, result) in results {
+        match result {
+            Ok(Some(file)) => {
+                let path = path.replace('\\', "/");
+                let diff = TextDiff::from_lines(&file.original, &file.fixed);
+                write!(
+                    stdout,
+                    "{}",
+                    diff.unified_diff()
+                        .header(&format!("a/{path}"), &format!("b/{path}"))
+                )?;
+                if status == ExitStatus::Success {
+                    status = ExitStatus::Failure;
+                }
+            }
+            Ok(None) => {}
+            Err(err) => {
+                eprintln!("{}: {}", "Error".red().bold(), err);
+                status = ExitStatus::Error;
+            }
+        }
+    }
+
+    Ok(status)
+}
+
+/// Exit with a failure if at least one diagnostic has one of the severities
+/// passed in `--exit-nonzero-on` (all of them by default).
+fn exit_status(diagnostics: &[&Diagnostic], exit_nonzero_on: &[Severity]) -> ExitStatus {
+    let exit_nonzero_on = if exit_nonzero_on.is_empty() {
+        &Severity::ALL[..]
+    } else {
+        exit_nonzero_on
+    };
+
+    if diagnostics
+        .iter()
+        .any(|diagnostic| exit_nonzero_on.contains(&diagnostic.severity))
+    {
+        ExitStatus::Failure
+    } else {
+        ExitStatus::Success
+    }
 }
 
 /// Insert `# jarl-ignore` comments for all diagnostics in the given files.
@@ -414,71 +737,11 @@ fn add_jarl_ignore_comments(
             }
         };
 
-        // Compute suppression edits for each diagnostic
-        // Store (offset, indent, needs_leading_newline, rule_name) to merge rules at same offset
-        let mut raw_edits: Vec<(usize, String, bool, String)> = Vec::new();
-        let is_rmd = has_rmd_extension(&path);
-        for diagnostic in &diagnostics {
-            let start: usize = diagnostic.range.start().into();
-            let end: usize = diagnostic.range.end().into();
-            let rule_name = &diagnostic.message.name;
-
-            let edit = if is_rmd {
-                create_suppression_edit_in_rmd(&content, start, end, rule_name, reason)
-            } else {
-                create_suppression_edit(&content, start, end, rule_name, reason)
-            };
-
-            if let Some(edit) = edit {
-                raw_edits.push((
-                    edit.insert_point.offset,
-                    edit.insert_point.indent,
-                    edit.insert_point.needs_leading_newline,
-                    rule_name.clone(),
-                ));
-            }
-        }
-
-        if raw_edits.is_empty() {
+        let Some((modified_content, num_suppressions)) =
+            insert_suppression_comments(&path, &content, &diagnostics, reason)
+        else {
             continue;
-        }
-
-        // Sort by offset ascending to group edits at the same offset
-        raw_edits.sort_by_key(|a| a.0);
-
-        // Merge edits at the same offset: collect all rule names for each offset
-        let mut merged_edits: Vec<(usize, String, bool, Vec<String>)> = Vec::new();
-        for (offset, indent, needs_leading_newline, rule_name) in raw_edits {
-            if let Some(last) = merged_edits.last_mut()
-                && last.0 == offset
-            {
-                // Same offset - add rule if not already present
-                if !last.3.contains(&rule_name) {
-                    last.3.push(rule_name);
-                }
-                continue;
-            }
-            // New offset
-            merged_edits.push((offset, indent, needs_leading_newline, vec![rule_name]));
-        }
-
-        // Sort by offset in descending order so we can apply edits without shifting positions
-        merged_edits.sort_by_key(|b| std::cmp::Reverse(b.0));
-
-        // Apply edits to the content
-        let mut modified_content = content.clone();
-        for (offset, indent, needs_leading_newline, rule_names) in &merged_edits {
-            let rule_refs: Vec<&str> = rule_names.iter().map(|s| s.as_str()).collect();
-            let comment_text =
-                format_suppression_comments(&rule_refs, reason, indent, *needs_leading_newline);
-            modified_content.insert_str(*offset, &comment_text);
-        }
-
-        // Count total suppression comments (one per rule)
-        let num_suppressions: usize = merged_edits
-            .iter()
-            .map(|(_, _, _, rules)| rules.len())
-            .sum();
+        };
 
         // Write the modified content back
         match std::fs::write(&path, &modified_content) {
@@ -521,13 +784,99 @@ fn add_jarl_ignore_comments(
     Ok(ExitStatus::Success)
 }
 
-/// Hide `unused_function` diagnostics when they exceed the configured
-/// threshold (likely false positives). Suppression is skipped when the
-/// rule is explicitly listed in `--select` / `--extend-select` (CLI) or
+/// Insert `# jarl-ignore` comments in `content`, the content of `path`, to
+/// suppress all `diagnostics`. Return the new content and the number of
+/// comments inserted, or `None` if there is nothing to suppress.
+pub(crate) fn insert_suppression_comments(
+    path: &Path,
+    content: &str,
+    diagnostics: &[&Diagnostic],
+    reason: &str,
+) -> Option<(String, usize)> {
+    // Compute suppression edits for each diagnostic
+    // Store (offset, indent, needs_leading_newline, rule_name) to merge rules at same offset
+    let mut raw_edits: Vec<(usize, String, bool, String)> = Vec::new();
+    let is_rmd = has_rmd_extension(path);
+    for diagnostic in diagnostics {
+        // Syntax errors can't be suppressed.
+        if diagnostic.is_syntax_error() {
+            continue;
+        }
+        let start: usize = diagnostic.range.start().into();
+        let end: usize = diagnostic.range.end().into();
+        let rule_name = &diagnostic.message.name;
+
+        let edit = if is_rmd {
+            create_suppression_edit_in_rmd(path, content, start, end, rule_name, reason)
+        } else {
+            create_suppression_edit(content, start, end, rule_name, reason)
+        };
+
+        if let Some(edit) = edit {
+            raw_edits.push((
+                edit.insert_point.offset,
+                edit.insert_point.indent,
+                edit.insert_point.needs_leading_newline,
+                rule_name.clone(),
+            ));
+        }
+    }
+
+    if raw_edits.is_empty() {
+        return None;
+    }
+
+    // Sort by offset ascending to group edits at the same offset
+    raw_edits.sort_by_key(|a| a.0);
+
+    // Merge edits at the same offset: collect all rule names for each offset
+    let mut merged_edits: Vec<(usize, String, bool, Vec<String>)> = Vec::new();
+    for (offset, indent, needs_leading_newline, rule_name) in raw_edits {
+        if let Some(last) = merged_edits.last_mut()
+            && last.0 == offset
+        {
+            // Same offset - add rule if not already present
+            if !last.3.contains(&rule_name) {
+                last.3.push(rule_name);
+            }
+            continue;
+        }
+        // New offset
+        merged_edits.push((offset, indent, needs_leading_newline, vec![rule_name]));
+    }
+
+    // Sort by offset in descending order so we can apply edits without shifting positions
+    merged_edits.sort_by_key(|b| std::cmp::Reverse(b.0));
+
+    // Apply edits to the content
+    let mut modified_content = content.to_string();
+    for (offset, indent, needs_leading_newline, rule_names) in &merged_edits {
+        let rule_refs: Vec<&str> = rule_names.iter().map(|s| s.as_str()).collect();
+        let comment_text =
+            format_suppression_comments(&rule_refs, reason, indent, *needs_leading_newline);
+        modified_content.insert_str(*offset, &comment_text);
+    }
+
+    // Count total suppression comments (one per rule)
+    let num_suppressions: usize = merged_edits
+        .iter()
+        .map(|(_, _, _, rules)| rules.len())
+        .sum();
+
+    Some((modified_content, num_suppressions))
+}
+
+/// Hide `unused_function` diagnostics of a package when they exceed the
+/// configured threshold (likely false positives). Suppression is skipped when
+/// the rule is explicitly listed in `--select` / `--extend-select` (CLI) or
 /// in the corresponding TOML fields.
 ///
-/// Returns `(hidden, total_count)` where `hidden` is `true` when the
-/// diagnostics were removed and `total_count` is the original number of
+/// The threshold applies to each package separately, so that a package with
+/// many unused functions doesn't hide the diagnostics of the other packages of
+/// a monorepo.
+///
+/// Returns `(hidden, hidden_count)` where `hidden` is `true` when some
+/// diagnostics were removed and `hidden_count` is the number of removed
 /// `unused_function` diagnostics.
 fn hide_unused_function_if_needed(
     all_diagnostics: &mut Vec<(String, Vec<Diagnostic>)>,
@@ -562,19 +911,79 @@ fn hide_unused_function_if_needed(
         .min()
         .unwrap_or(50);
 
-    let unused_fn_count = all_diagnostics
-        .iter()
-        .flat_map(|(_path, diagnostics)| diagnostics.iter())
-        .filter(|d| d.message.name == "unused_function")
-        .count();
+    if explicitly_selected {
+        return (false, 0);
+    }
 
-    let hidden = !explicitly_selected && unused_fn_count > threshold_ignore;
-    if hidden {
-        for (_path, diagnostics) in all_diagnostics.iter_mut() {
-            diagnostics.retain(|d| d.message.name != "unused_function");
+    let mut counts_by_package: HashMap<Option<PathBuf>, usize> = HashMap::new();
+    for (path, diagnostics) in all_diagnostics.iter() {
+        let count = diagnostics
+            .iter()
+            .filter(|d| d.message.name == "unused_function")
+            .count();
+        if count > 0 {
+            *counts_by_package
+                .entry(find_package_root(Path::new(path)))
+                .or_default() += count;
+        }
+    }
+
+    let mut hidden_count = 0;
+    for (path, diagnostics) in all_diagnostics.iter_mut() {
+        let package_root = find_package_root(Path::new(path));
+        if counts_by_package[&package_root] <= threshold_ignore {
+            continue;
         }
-        all_diagnostics.retain(|(_path, diagnostics)| !diagnostics.is_empty());
+        let n_before = diagnostics.len();
+        diagnostics.retain(|d| d.message.name != "unused_function");
+        hidden_count += n_before - diagnostics.len();
     }
+    all_diagnostics.retain(|(_path, diagnostics)| !diagnostics.is_empty());
+
+    (hidden_count > 0, hidden_count)
+}
+
+/// Keep at most `max-per-rule-per-file` violations of each rule in each file,
+/// using the settings of the closest `jarl.toml`. `diagnostics` must be sorted
+/// so that the first violations of a file are kept.
+///
+/// Also returns the number of violations that were removed, by file and rule.
+fn cap_diagnostics_per_rule_per_file<'a>(
+    diagnostics: &[&'a Diagnostic],
+    resolver: &PathResolver<Settings>,
+) -> (Vec<&'a Diagnostic>, Vec<(String, String, usize)>) {
+    let mut limits: HashMap<&Path, Option<usize>> = HashMap::new();
+    let mut counts: HashMap<(&Path, &str), usize> = HashMap::new();
+    let mut hidden: BTreeMap<(&Path, &str), usize> = BTreeMap::new();
+
+    let kept = diagnostics
+        .iter()
+        .filter(|diagnostic| {
+            let path = diagnostic.filename.as_path();
+            let limit = *limits.entry(path).or_insert_with(|| {
+                resolver
+                    .resolve(path)
+                    .and_then(|item| item.value().linter.max_per_rule_per_file)
+            });
+            let Some(limit) = limit else {
+                return true;
+            };
+            let key = (path, diagnostic.message.name.as_str());
+            let count = counts.entry(key).or_default();
+            *count += 1;
+            if *count > limit {
+                *hidden.entry(key).or_default() += 1;
+                return false;
+            }
+            true
+        })
+        .copied()
+        .collect();
+
+    let hidden = hidden
+        .into_iter()
+        .map(|((path, rule), n)| (relativize_path(path), rule.to_string(), n))
+        .collect();
 
-    (hidden, unused_fn_count)
+    (kept, hidden)
 }
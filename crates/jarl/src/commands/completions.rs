@@ -0,0 +1,14 @@
+use anyhow::Result;
+use clap::CommandFactory;
+
+use crate::args::{Args, CompletionsCommand};
+use crate::status::ExitStatus;
+
+/// Print the completion script for the given shell, built from the definition
+/// of the CLI.
+pub fn completions(args: CompletionsCommand) -> Result<ExitStatus> {
+    let mut command = Args::command();
+    clap_complete::generate(args.shell, &mut command, "jarl", &mut std::io::stdout());
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,154 @@
+use air_workspace::resolve::PathResolver;
+use anyhow::Result;
+use jarl_core::check::effective_rules_for_file;
+use jarl_core::config::{ArgsConfig, build_config};
+use jarl_core::diagnostic::Severity;
+use jarl_core::discovery::discover_settings;
+use jarl_core::fs::{normalize_path, relativize_path};
+use jarl_core::package::{CodeKind, summarize_package_info};
+use jarl_core::rule_set::FixStatus;
+use jarl_core::settings::Settings;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::args::{ConfigCommand, ConfigFormat};
+use crate::status::ExitStatus;
+
+/// The configuration used when checking a file or directory.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct ResolvedConfig {
+    path: String,
+    /// Path to the `jarl.toml` that applies to `path`, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    config_file: Option<String>,
+    /// Kind of file, only for files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kind: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    minimum_r_version: Option<String>,
+    check_roxygen: bool,
+    fix_roxygen: bool,
+    /// Chunk options of the R Markdown, Quarto, and Sweave chunks to skip.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    skip_chunks: Vec<String>,
+    /// Rules that are enabled for `path`.
+    rules: BTreeMap<&'static str, ResolvedRule>,
+}
+
+#[derive(Debug, Serialize)]
+struct ResolvedRule {
+    severity: Severity,
+    /// One of `safe`, `unsafe`, `none` (the rule has no fix), or `disabled`
+    /// (the fix is excluded by `fixable` or `unfixable`).
+    fix: &'static str,
+}
+
+/// Print the configuration that `jarl check` would use for `args.path`, after
+/// discovering the closest `jarl.toml`, resolving `extends`, and applying the
+/// rule selection passed in the command line.
+///
+/// For files, this also applies `[[lint.overrides]]`, `[lint.per-file-ignores]`,
+/// the per-rule `include`/`exclude` patterns, and `[lint.severity-by-kind]`.
+/// Those depend on the file, so they are not applied for directories.
+pub fn config(args: ConfigCommand) -> Result<ExitStatus> {
+    let path = normalize_path(&args.path);
+    if !path.exists() {
+        return Err(anyhow::anyhow!("Path does not exist: {}", args.path));
+    }
+
+    let mut resolver = PathResolver::new(Settings::default());
+    let mut config_paths = BTreeMap::new();
+    for ds in discover_settings(std::slice::from_ref(&args.path))? {
+        if let Some(config_path) = ds.config_path {
+            config_paths.insert(ds.directory.clone(), config_path);
+        }
+        resolver.add(&ds.directory, ds.settings);
+    }
+
+    let item = resolver.resolve(&path);
+    let settings = item.map(|item| item.value());
+    let config_file = item
+        .and_then(|item| config_paths.get(item.path()))
+        .map(|config_path| config_path.display().to_string());
+
+    let args_config = ArgsConfig {
+        files: vec![path.clone()],
+        fix: false,
+        unsafe_fixes: false,
+        unsafe_fixes_rules: String::new(),
+        fix_only: false,
+        select: args.select,
+        extend_select: args.extend_select,
+        ignore: args.ignore,
+        min_r_version: args.min_r_version,
+        allow_dirty: false,
+        allow_no_vcs: false,
+        respect_staged: false,
+        assignment: None,
+        preview: args.preview,
+        profile: args.profile,
+    };
+    let config = build_config(&args_config, settings, vec![path.clone()])?;
+
+    let (rules, kind) = if path.is_file() {
+        let (_, file_pkg_info) =
+            summarize_package_info(std::slice::from_ref(&path), config.file_system.as_ref());
+        let kind = CodeKind::from_path(&path, &file_pkg_info);
+        // `jarl check` matches the file against the per-path settings using
+        // its path relative to the working directory.
+        let rules = effective_rules_for_file(&config, Path::new(&relativize_path(&path)));
+        (rules, Some(kind))
+    } else {
+        (config.rules_to_apply.clone(), None)
+    };
+
+    let kind_severity = kind.and_then(|kind| config.severity_by_kind.get(&kind));
+    let rules = rules
+        .iter()
+        .map(|rule| {
+            let name = rule.name();
+            let severity = kind_severity
+                .or_else(|| config.severity.get(name))
+                .copied()
+                .unwrap_or_default();
+            let fix_disabled = config.unfixable.contains(name)
+                || config
+                    .fixable
+                    .as_ref()
+                    .is_some_and(|fixable| !fixable.contains(name));
+            let fix = match rule.fix_status() {
+                FixStatus::None => "none",
+                _ if fix_disabled => "disabled",
+                FixStatus::Safe => "safe",
+                FixStatus::Unsafe => "unsafe",
+            };
+            (name, ResolvedRule { severity, fix })
+        })
+        .collect();
+
+    let resolved = ResolvedConfig {
+        path: args.path,
+        config_file,
+        kind: kind.map(|kind| kind.as_str()),
+        minimum_r_version: config
+            .minimum_r_version
+            .map(|(major, minor, patch)| format!("{major}.{minor}.{patch}")),
+        check_roxygen: config.check_roxygen,
+        fix_roxygen: config.fix_roxygen,
+        skip_chunks: config
+            .skip_chunks
+            .iter()
+            .map(|option| format!("{}={}", option.name, option.value))
+            .collect(),
+        rules,
+    };
+
+    match args.output_format {
+        ConfigFormat::Toml => print!("{}", toml::to_string(&resolved)?),
+        ConfigFormat::Json => println!("{}", serde_json::to_string_pretty(&resolved)?),
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,132 @@
+use anyhow::Result;
+use colored::Colorize;
+use jarl_core::check::FixedFile;
+use jarl_core::config::{ArgsConfig, Config};
+use std::collections::BTreeMap;
+
+use crate::args::FixCommand;
+use crate::commands::check::{Workspace, resolve_workspace};
+use crate::status::ExitStatus;
+
+type FixResults = Vec<(String, Result<Option<FixedFile>, anyhow::Error>)>;
+
+pub fn fix(args: FixCommand) -> Result<ExitStatus> {
+    let fix_config = ArgsConfig {
+        files: args.files.iter().map(|s| s.into()).collect(),
+        fix: true,
+        unsafe_fixes: args.unsafe_fixes,
+        unsafe_fixes_rules: String::new(),
+        fix_only: false,
+        select: args.select.clone(),
+        extend_select: args.extend_select.clone(),
+        ignore: args.ignore.clone(),
+        min_r_version: args.min_r_version.clone(),
+        allow_dirty: args.allow_dirty,
+        allow_no_vcs: args.allow_no_vcs,
+        respect_staged: false,
+        assignment: None,
+        preview: args.preview,
+        profile: args.profile,
+    };
+
+    let Some(Workspace { configs, .. }) = resolve_workspace(
+        &args.files,
+        &args.exclude,
+        &args.extend_exclude,
+        args.no_default_exclude,
+        args.follow_symlinks,
+        &fix_config,
+    )?
+    else {
+        return Ok(ExitStatus::Success);
+    };
+
+    // `--dry-run` computes the same fixes but doesn't write them.
+    let run: fn(Config) -> FixResults = if args.dry_run {
+        jarl_core::check::preview_fixes
+    } else {
+        jarl_core::check::fix
+    };
+    let mut results: FixResults = configs.into_iter().flat_map(run).collect();
+    results.sort_by(|a, b| a.0.cmp(&b.0));
+
+    // Number of fixes applied by rule, and by file for each rule.
+    let mut fixes_by_rule: BTreeMap<String, BTreeMap<String, usize>> = BTreeMap::new();
+    let mut n_files = 0;
+    let mut has_errors = false;
+
+    for (path, result) in results {
+        match result {
+            Ok(Some(file)) => {
+                n_files += 1;
+                let path = path.replace('\\', "/");
+                for rule in file.applied_fixes {
+                    *fixes_by_rule
+                        .entry(rule)
+                        .or_default()
+                        .entry(path.clone())
+                        .or_default() += 1;
+                }
+            }
+            Ok(None) => {}
+            Err(err) => {
+                eprintln!("{}: {}", "Error".red().bold(), err);
+                has_errors = true;
+            }
+        }
+    }
+
+    // Files with errors are not counted, so "No fixes to apply" could be
+    // wrong.
+    if n_files > 0 || !has_errors {
+        print_fix_summary(&fixes_by_rule, n_files, args.dry_run);
+    }
+
+    if has_errors {
+        return Ok(ExitStatus::Error);
+    }
+    // Like `check --diff`, a dry run fails if some files would be modified.
+    if args.dry_run && n_files > 0 {
+        return Ok(ExitStatus::Failure);
+    }
+    Ok(ExitStatus::Success)
+}
+
+/// Print the number of fixes applied by each rule, and in which files.
+fn print_fix_summary(
+    fixes_by_rule: &BTreeMap<String, BTreeMap<String, usize>>,
+    n_files: usize,
+    dry_run: bool,
+) {
+    if n_files == 0 {
+        println!("No fixes to apply.");
+        return;
+    }
+
+    let n_fixes: usize = fixes_by_rule
+        .values()
+        .flat_map(|files| files.values())
+        .sum();
+    let verb = if dry_run { "Would fix" } else { "Fixed" };
+    println!(
+        "{verb} {} in {}:",
+        pluralize(n_fixes, "violation"),
+        pluralize(n_files, "file")
+    );
+
+    for (rule, files) in fixes_by_rule {
+        let n_rule_fixes: usize = files.values().sum();
+        println!("\n{} ({})", rule.bold(), pluralize(n_rule_fixes, "fix"));
+        for (file, n) in files {
+            println!("  {file}: {n}");
+        }
+    }
+}
+
+pub(crate) fn pluralize(n: usize, word: &str) -> String {
+    match (n, word) {
+        (1, _) => format!("1 {word}"),
+        (_, "fix") => format!("{n} fixes"),
+        _ => format!("{n} {word}s"),
+    }
+}
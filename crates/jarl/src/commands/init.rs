@@ -0,0 +1,162 @@
+use anyhow::{Result, anyhow};
+use jarl_core::description::Description;
+use jarl_core::fs::has_r_extension;
+use std::path::Path;
+
+use crate::args::InitCommand;
+use crate::status::ExitStatus;
+
+/// Github Actions workflow created with `--github-action`, the same as in the
+/// "Continuous integration" page of the docs.
+const GITHUB_WORKFLOW: &str = "on:
+  push:
+    branches:
+      - main
+  pull_request:
+
+name: jarl-check
+
+permissions: read-all
+
+jobs:
+  jarl-check:
+    name: jarl-check
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - uses: etiennebacher/setup-jarl@v0.1.0
+";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ProjectKind {
+    Package,
+    ShinyApp,
+    Project,
+}
+
+impl ProjectKind {
+    fn detect(dir: &Path) -> Self {
+        let is_package = std::fs::read_to_string(dir.join("DESCRIPTION"))
+            .is_ok_and(|description| description.lines().any(|l| l.starts_with("Package:")));
+        if is_package {
+            Self::Package
+        } else if dir.join("app.R").is_file()
+            || (dir.join("ui.R").is_file() && dir.join("server.R").is_file())
+        {
+            Self::ShinyApp
+        } else {
+            Self::Project
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Package => "an R package",
+            Self::ShinyApp => "a Shiny app",
+            Self::Project => "an R project",
+        }
+    }
+}
+
+/// Create a `jarl.toml` in `args.path` with a selection of rules that depends
+/// on the project: the `TESTTHAT` rules are only added if there is a
+/// `tests/testthat` folder, and the `DPLYR` rules only if the project uses
+/// dplyr.
+pub fn init(args: InitCommand) -> Result<ExitStatus> {
+    let dir = Path::new(&args.path);
+    if !dir.is_dir() {
+        return Err(anyhow!("Directory does not exist: {}", args.path));
+    }
+
+    let config_path = dir.join("jarl.toml");
+    let workflow_path = dir.join(".github").join("workflows").join("jarl.yml");
+    for path in std::iter::once(&config_path).chain(args.github_action.then_some(&workflow_path)) {
+        if path.exists() && !args.force {
+            return Err(anyhow!(
+                "{} already exists. Use `--force` to overwrite it.",
+                path.display()
+            ));
+        }
+    }
+
+    let kind = ProjectKind::detect(dir);
+    std::fs::write(&config_path, starter_config(dir, kind))?;
+    println!("Created {} for {}.", config_path.display(), kind.as_str());
+
+    if args.github_action {
+        if let Some(parent) = workflow_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&workflow_path, GITHUB_WORKFLOW)?;
+        println!("Created {}.", workflow_path.display());
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+fn starter_config(dir: &Path, kind: ProjectKind) -> String {
+    let mut groups = Vec::new();
+    if dir.join("tests").join("testthat").is_dir() {
+        groups.push((
+            "TESTTHAT",
+            "the rules for testthat, since there is a `tests/testthat` folder",
+        ));
+    }
+    if uses_dplyr(dir, kind) {
+        groups.push(("DPLYR", "the rules for dplyr, since it is used"));
+    }
+
+    let mut config = format!(
+        "# Configuration of Jarl for {}. All the options are described in\n\
+         # https://jarl.etiennebacher.com/reference/config-file.\n\n\
+         [lint]\n",
+        kind.as_str()
+    );
+
+    if groups.is_empty() {
+        config.push_str(
+            "# Use the rules enabled by default. Rules that are disabled by default\n\
+             # can be added with `extend-select`, e.g. `extend-select = [\"TESTTHAT\"]`.\n\
+             extend-select = []\n",
+        );
+    } else {
+        config.push_str("# Use the rules enabled by default, as well as:\n");
+        for (_, reason) in &groups {
+            config.push_str(&format!("# - {reason};\n"));
+        }
+        let names = groups
+            .iter()
+            .map(|(name, _)| format!("\"{name}\""))
+            .collect::<Vec<_>>()
+            .join(", ");
+        config.push_str(&format!("extend-select = [{names}]\n"));
+    }
+
+    config
+}
+
+/// Whether the project uses dplyr: for packages, dplyr must be in `Depends`
+/// or `Imports`. For other projects, an R file at the root or in `R/` must
+/// load dplyr or call one of its functions with `dplyr::`.
+fn uses_dplyr(dir: &Path, kind: ProjectKind) -> bool {
+    if kind == ProjectKind::Package {
+        return std::fs::read_to_string(dir.join("DESCRIPTION")).is_ok_and(|description| {
+            Description::get_package_deps(&description, &["Depends", "Imports"])
+                .iter()
+                .any(|package| package == "dplyr")
+        });
+    }
+
+    [dir.to_path_buf(), dir.join("R")]
+        .iter()
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && has_r_extension(path))
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .any(|content| {
+            content.contains("library(dplyr)")
+                || content.contains("require(dplyr)")
+                || content.contains("dplyr::")
+        })
+}
@@ -0,0 +1,14 @@
+use anyhow::Result;
+use clap::CommandFactory;
+
+use crate::args::{Args, ManCommand};
+use crate::status::ExitStatus;
+
+/// Print the manpage of Jarl in the roff format, built from the definition of
+/// the CLI.
+pub fn man(_args: ManCommand) -> Result<ExitStatus> {
+    let man = clap_mangen::Man::new(Args::command());
+    man.render(&mut std::io::stdout())?;
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,141 @@
+use anyhow::{Result, anyhow};
+use colored::Colorize;
+use jarl_core::discovery::discover_r_file_paths;
+use jarl_core::fs::{has_r_extension, relativize_path, write_atomic};
+use jarl_core::lintr_config::LintrMigration;
+use jarl_core::nolint::convert_nolint_comments;
+use std::path::Path;
+
+use crate::args::MigrateLintrCommand;
+use crate::commands::check::resolve_settings;
+use crate::status::ExitStatus;
+
+/// Create a `jarl.toml` from the `.lintr` file in `args.path`, and report the
+/// linters and options that couldn't be converted.
+pub fn migrate_lintr(args: MigrateLintrCommand) -> Result<ExitStatus> {
+    let dir = Path::new(&args.path);
+    if let Some(reason) = &args.convert_nolint {
+        return convert_nolint(dir, reason);
+    }
+
+    let lintr_path = dir.join(".lintr");
+    if !lintr_path.is_file() {
+        return Err(anyhow!("No `.lintr` file found in `{}`.", dir.display()));
+    }
+
+    let config_path = dir.join("jarl.toml");
+    if config_path.exists() && !args.force {
+        return Err(anyhow!(
+            "{} already exists. Use `--force` to overwrite it.",
+            config_path.display()
+        ));
+    }
+
+    let contents = std::fs::read_to_string(&lintr_path)?;
+    let migration = LintrMigration::from_lintr(&contents)?;
+    std::fs::write(&config_path, migration.to_toml())?;
+    println!(
+        "Created {} from {}.",
+        config_path.display(),
+        lintr_path.display()
+    );
+
+    if !migration.unmatched_linters.is_empty() {
+        println!("\nThe following linters have no equivalent in Jarl:");
+        for linter in &migration.unmatched_linters {
+            println!("  - {linter}");
+        }
+    }
+    if !migration.notes.is_empty() {
+        println!("\nSome settings were not converted:");
+        for note in &migration.notes {
+            println!("  - {note}");
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Convert the `# nolint` comments of the R files in `dir` to suppression
+/// comments, and report the ones that couldn't be converted.
+fn convert_nolint(dir: &Path, reason: &str) -> Result<ExitStatus> {
+    // Newlines would break comment format
+    if reason.contains(['\n', '\r']) {
+        return Err(anyhow!(
+            "--convert-nolint=<reason> cannot contain newline characters."
+        ));
+    }
+    if !dir.is_dir() {
+        return Err(anyhow!("Directory does not exist: {}", dir.display()));
+    }
+
+    let (resolver, _) = resolve_settings(&[dir], false, &[])?;
+    let mut paths = discover_r_file_paths(&[dir], &[], &resolver, true, false, false)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|path| has_r_extension(path))
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    let mut total_converted = 0;
+    let mut files_modified = 0;
+    for path in paths {
+        let display_path = relativize_path(&path);
+        let conversion = std::fs::read_to_string(&path)
+            .map_err(anyhow::Error::from)
+            .and_then(|content| {
+                if content.contains("nolint") {
+                    convert_nolint_comments(&content, reason).map(Some)
+                } else {
+                    Ok(None)
+                }
+            });
+        let conversion = match conversion {
+            Ok(Some(conversion)) => conversion,
+            Ok(None) => continue,
+            Err(e) => {
+                eprintln!(
+                    "{}: Could not convert {display_path}: {e}",
+                    "Error".red().bold()
+                );
+                continue;
+            }
+        };
+
+        for (line, why) in &conversion.skipped {
+            println!(
+                "{}: {display_path}:{line}: {why}",
+                "Skipped".yellow().bold()
+            );
+        }
+        if conversion.converted == 0 {
+            continue;
+        }
+
+        write_atomic(&path, &conversion.content)?;
+        total_converted += conversion.converted;
+        files_modified += 1;
+        println!(
+            "{}: Converted {} `# nolint` comment(s) in {display_path}",
+            "Modified".green().bold(),
+            conversion.converted
+        );
+    }
+
+    if total_converted > 0 {
+        println!(
+            "\n{}: Converted {} `# nolint` comment(s) across {} file(s).",
+            "Summary".cyan().bold(),
+            total_converted,
+            files_modified
+        );
+    } else {
+        println!(
+            "{}: {}",
+            "Info".cyan().bold(),
+            "No `# nolint` comments converted.".white()
+        );
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -2,16 +2,22 @@ use anyhow::Result;
 use colored::Colorize;
 
 use jarl_core::config::suggest_rules;
-use jarl_core::rule_docs::rule_doc;
+use jarl_core::rule_docs::RuleDoc;
 use jarl_core::rule_set::{DefaultStatus, FixStatus, Rule};
 
 use crate::args::RuleCommand;
 use crate::status::ExitStatus;
 
 pub fn rule(args: RuleCommand) -> Result<ExitStatus> {
-    let Some(rule) = Rule::from_name(&args.name) else {
-        eprintln!("{}: unknown rule `{}`.", "error".red().bold(), args.name);
-        for suggestion in suggest_rules(&args.name) {
+    explain(&args.name)
+}
+
+/// Print the documentation of the rule `name`. This is shared by `jarl rule`
+/// and `jarl check --explain`.
+pub(crate) fn explain(name: &str) -> Result<ExitStatus> {
+    let Some(rule) = Rule::from_name(name) else {
+        eprintln!("{}: unknown rule `{}`.", "error".red().bold(), name);
+        for suggestion in suggest_rules(name) {
             eprintln!("  Did you mean `{suggestion}`?");
         }
         eprintln!("Run `jarl check --help` for how to select rules.");
@@ -40,6 +46,7 @@ fn format_rule(rule: Rule) -> String {
     let enabled_by_default = match rule.default_status() {
         DefaultStatus::Enabled => "yes",
         DefaultStatus::Disabled => "no",
+        DefaultStatus::Preview => "no (preview rule, requires `--preview`)",
     };
     out.push_str(&format!(
         "{} {enabled_by_default}\n",
@@ -69,10 +76,10 @@ fn format_rule(rule: Rule) -> String {
         ));
     }
 
-    match rule_doc(rule.name()) {
+    match RuleDoc::get(rule.name()) {
         Some(doc) => {
             out.push('\n');
-            out.push_str(&strip_quarto(doc));
+            out.push_str(&doc.to_markdown());
         }
         None => {
             out.push_str("\nNo detailed documentation is available for this rule yet.\n");
@@ -81,43 +88,3 @@ fn format_rule(rule: Rule) -> String {
 
     out
 }
-
-/// Strip the Quarto-specific parts of a generated rule doc so it reads cleanly
-/// in a terminal: the leading `# <name>` title (shown in the header already)
-/// and the `::: {.callout-note …}` "Added in" fence.
-fn strip_quarto(doc: &str) -> String {
-    let mut lines = Vec::new();
-
-    for line in doc.lines() {
-        let trimmed = line.trim_start();
-
-        // Drop the top-level title line (the header already prints the name).
-        if line.starts_with("# ") {
-            continue;
-        }
-
-        // Turn the callout fence into a plain "Added in X" line, dropping the
-        // `:::` delimiters.
-        if trimmed.starts_with(":::") {
-            if let Some(start) = line.find("title=\"") {
-                let rest = &line[start + "title=\"".len()..];
-                if let Some(end) = rest.find('"') {
-                    lines.push(rest[..end].to_string());
-                }
-            }
-            continue;
-        }
-
-        lines.push(line.to_string());
-    }
-
-    // Collapse the blank lines that surrounded the stripped title/callout.
-    let mut result = lines.join("\n");
-    while result.starts_with('\n') {
-        result.remove(0);
-    }
-    if !result.ends_with('\n') {
-        result.push('\n');
-    }
-    result
-}
@@ -0,0 +1,433 @@
+//! `jarl self update` and `jarl self verify`.
+//!
+//! Releases are published on GitHub by `dist`, with one archive per platform.
+//! Each archive comes with a SHA-256 checksum (`<archive>.sha256`) and a
+//! minisign signature (`<archive>.minisig`). The public key used to check the
+//! signatures is embedded in the binary at build time with the
+//! `JARL_RELEASE_PUBLIC_KEY` environment variable, so a compromised release
+//! page can't serve both a modified archive and a matching checksum.
+//!
+//! This requires the `self-update` feature, which is only enabled in the
+//! binaries built for the releases.
+
+use anyhow::Result;
+
+use crate::args::{SelfCommand, SelfNamespace};
+use crate::status::ExitStatus;
+
+pub(crate) fn self_command(args: SelfNamespace) -> Result<ExitStatus> {
+    match args.command {
+        SelfCommand::Update(command) => imp::update(command),
+        SelfCommand::Verify(command) => imp::verify(command),
+    }
+}
+
+#[cfg(not(feature = "self-update"))]
+mod imp {
+    use anyhow::{Result, anyhow};
+
+    use crate::args::{SelfUpdateCommand, SelfVerifyCommand};
+    use crate::status::ExitStatus;
+
+    pub(super) fn update(_args: SelfUpdateCommand) -> Result<ExitStatus> {
+        Err(not_available())
+    }
+
+    pub(super) fn verify(_args: SelfVerifyCommand) -> Result<ExitStatus> {
+        Err(not_available())
+    }
+
+    fn not_available() -> anyhow::Error {
+        anyhow!(
+            "This version of Jarl was built without `jarl self`. Use the tool that installed \
+             Jarl (e.g. `pip` or `cargo`) to update it, or install it with the standalone \
+             installer."
+        )
+    }
+}
+
+#[cfg(feature = "self-update")]
+mod imp {
+    use std::io::Read;
+
+    use anyhow::{Context, Result, anyhow, bail};
+    use colored::Colorize;
+    use sha2::{Digest, Sha256};
+
+    use crate::args::{SelfUpdateCommand, SelfVerifyCommand};
+    use crate::status::ExitStatus;
+
+    const RELEASES_URL: &str = "https://github.com/etiennebacher/jarl/releases";
+    const LATEST_RELEASE_API_URL: &str =
+        "https://api.github.com/repos/etiennebacher/jarl/releases/latest";
+    const RELEASE_PUBLIC_KEY: Option<&str> = option_env!("JARL_RELEASE_PUBLIC_KEY");
+    const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+    /// Archives are a few megabytes, this only protects against a broken server.
+    const MAX_DOWNLOAD_SIZE: u64 = 200 * 1024 * 1024;
+
+    pub(super) fn update(args: SelfUpdateCommand) -> Result<ExitStatus> {
+        let version = match args.target_version {
+            Some(version) => version.trim_start_matches('v').to_string(),
+            None => {
+                let latest = latest_version()?;
+                if !is_newer(&latest, CURRENT_VERSION) {
+                    println!("Jarl is up to date ({CURRENT_VERSION}).");
+                    return Ok(ExitStatus::Success);
+                }
+                latest
+            }
+        };
+        if version == CURRENT_VERSION {
+            println!("Jarl {CURRENT_VERSION} is already installed.");
+            return Ok(ExitStatus::Success);
+        }
+
+        let target = release_target()?;
+        let binary = download_verified_binary(&version, target)?;
+
+        if args.dry_run {
+            println!(
+                "Would update Jarl from {CURRENT_VERSION} to {version}. The release was \
+                 verified but the executable was not replaced."
+            );
+            return Ok(ExitStatus::Success);
+        }
+
+        // Write the new binary next to the current one so that it can be
+        // renamed over it.
+        let current_exe = std::env::current_exe()?;
+        let directory = current_exe
+            .parent()
+            .ok_or_else(|| anyhow!("Can't find the directory of {}", current_exe.display()))?;
+        let mut new_exe = tempfile::Builder::new()
+            .prefix(".jarl-update-")
+            .tempfile_in(directory)
+            .with_context(|| {
+                format!(
+                    "Failed to write the new executable in {}",
+                    directory.display()
+                )
+            })?;
+        std::io::Write::write_all(&mut new_exe, &binary)?;
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            new_exe
+                .as_file()
+                .set_permissions(std::fs::Permissions::from_mode(0o755))?;
+        }
+        self_replace::self_replace(new_exe.path())
+            .with_context(|| format!("Failed to replace {}", current_exe.display()))?;
+
+        println!(
+            "{} Jarl from {CURRENT_VERSION} to {version}.",
+            "Updated".green().bold()
+        );
+        Ok(ExitStatus::Success)
+    }
+
+    pub(super) fn verify(_args: SelfVerifyCommand) -> Result<ExitStatus> {
+        let target = release_target()?;
+        let binary = download_verified_binary(CURRENT_VERSION, target)?;
+
+        let current_exe = std::env::current_exe()?;
+        let installed = std::fs::read(&current_exe)
+            .with_context(|| format!("Failed to read {}", current_exe.display()))?;
+
+        if sha256(&installed) == sha256(&binary) {
+            println!(
+                "{}: {} matches the release {CURRENT_VERSION} for {target}.",
+                "Verified".green().bold(),
+                current_exe.display()
+            );
+            Ok(ExitStatus::Success)
+        } else {
+            eprintln!(
+                "{}: {} doesn't match the release {CURRENT_VERSION} for {target}.",
+                "Error".red().bold(),
+                current_exe.display()
+            );
+            Ok(ExitStatus::Failure)
+        }
+    }
+
+    /// Download the archive of a release, check its signature and checksum,
+    /// and return the executable it contains.
+    fn download_verified_binary(version: &str, target: &str) -> Result<Vec<u8>> {
+        let public_key = release_public_key()?;
+        let archive_name = archive_name(target);
+        let base_url = format!("{RELEASES_URL}/download/{version}");
+
+        let archive = download(&format!("{base_url}/{archive_name}"))?;
+        let signature = download_text(&format!("{base_url}/{archive_name}.minisig"))?;
+        let checksum = download_text(&format!("{base_url}/{archive_name}.sha256"))?;
+
+        let signature = minisign_verify::Signature::decode(&signature)
+            .map_err(|err| anyhow!("Invalid signature for {archive_name}: {err}"))?;
+        public_key
+            .verify(&archive, &signature, false)
+            .map_err(|err| anyhow!("The signature of {archive_name} is not valid: {err}"))?;
+
+        let expected = parse_checksum(&checksum)
+            .ok_or_else(|| anyhow!("Invalid checksum file for {archive_name}"))?;
+        if sha256(&archive) != expected {
+            bail!("The checksum of {archive_name} doesn't match the published one.");
+        }
+
+        extract_binary(&archive, target)
+            .with_context(|| format!("Failed to extract Jarl from {archive_name}"))
+    }
+
+    fn release_public_key() -> Result<minisign_verify::PublicKey> {
+        let key = RELEASE_PUBLIC_KEY.ok_or_else(|| {
+            anyhow!(
+                "This version of Jarl was built without the key used to sign releases, so \
+                 the downloaded files can't be verified."
+            )
+        })?;
+        minisign_verify::PublicKey::from_base64(key)
+            .map_err(|err| anyhow!("Invalid release public key: {err}"))
+    }
+
+    /// Target triple of the release archive for the current platform. This
+    /// must match `targets` in `dist-workspace.toml`.
+    fn release_target() -> Result<&'static str> {
+        let target = if cfg!(all(target_os = "macos", target_arch = "aarch64")) {
+            "aarch64-apple-darwin"
+        } else if cfg!(all(target_os = "macos", target_arch = "x86_64")) {
+            "x86_64-apple-darwin"
+        } else if cfg!(all(target_os = "windows", target_arch = "x86_64")) {
+            "x86_64-pc-windows-msvc"
+        } else if cfg!(all(
+            target_os = "linux",
+            target_arch = "aarch64",
+            target_env = "musl"
+        )) {
+            "aarch64-unknown-linux-musl"
+        } else if cfg!(all(
+            target_os = "linux",
+            target_arch = "x86_64",
+            target_env = "musl"
+        )) {
+            "x86_64-unknown-linux-musl"
+        } else if cfg!(all(target_os = "linux", target_arch = "aarch64")) {
+            "aarch64-unknown-linux-gnu"
+        } else if cfg!(all(target_os = "linux", target_arch = "x86_64")) {
+            "x86_64-unknown-linux-gnu"
+        } else {
+            bail!("Jarl doesn't publish releases for this platform.");
+        };
+        Ok(target)
+    }
+
+    fn archive_name(target: &str) -> String {
+        if target.contains("windows") {
+            format!("jarl-{target}.zip")
+        } else {
+            format!("jarl-{target}.tar.gz")
+        }
+    }
+
+    /// Extract the executable from a release archive: `jarl.exe` at the root
+    /// of the zip file on Windows, and `jarl-<target>/jarl` otherwise.
+    fn extract_binary(archive: &[u8], target: &str) -> Result<Vec<u8>> {
+        let mut binary = Vec::new();
+        if target.contains("windows") {
+            let mut zip = zip::ZipArchive::new(std::io::Cursor::new(archive))?;
+            zip.by_name("jarl.exe")?.read_to_end(&mut binary)?;
+            return Ok(binary);
+        }
+
+        let mut tar = tar::Archive::new(flate2::read::GzDecoder::new(archive));
+        for entry in tar.entries()? {
+            let mut entry = entry?;
+            if entry.path()?.file_name().is_some_and(|name| name == "jarl") {
+                entry.read_to_end(&mut binary)?;
+                return Ok(binary);
+            }
+        }
+        bail!("The archive doesn't contain the `jarl` executable.")
+    }
+
+    fn latest_version() -> Result<String> {
+        #[derive(serde::Deserialize)]
+        struct Release {
+            tag_name: String,
+        }
+
+        let body = download(LATEST_RELEASE_API_URL)?;
+        let release: Release =
+            serde_json::from_slice(&body).context("Failed to read the latest release of Jarl")?;
+        Ok(release.tag_name.trim_start_matches('v').to_string())
+    }
+
+    fn download(url: &str) -> Result<Vec<u8>> {
+        let mut response = ureq::get(url)
+            .header("User-Agent", concat!("jarl/", env!("CARGO_PKG_VERSION")))
+            .call()
+            .with_context(|| format!("Failed to download {url}"))?;
+        response
+            .body_mut()
+            .with_config()
+            .limit(MAX_DOWNLOAD_SIZE)
+            .read_to_vec()
+            .with_context(|| format!("Failed to download {url}"))
+    }
+
+    fn download_text(url: &str) -> Result<String> {
+        String::from_utf8(download(url)?).with_context(|| format!("Invalid content in {url}"))
+    }
+
+    fn sha256(content: &[u8]) -> String {
+        Sha256::digest(content)
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    /// Read the digest in a checksum file such as `<digest> *<file>`.
+    fn parse_checksum(content: &str) -> Option<String> {
+        let digest = content.split_whitespace().next()?.to_ascii_lowercase();
+        (digest.len() == 64 && digest.chars().all(|c| c.is_ascii_hexdigit())).then_some(digest)
+    }
+
+    /// Whether `version` is more recent than `current`. A pre-release such as
+    /// `0.6.0-alpha.1` is older than `0.6.0`.
+    fn is_newer(version: &str, current: &str) -> bool {
+        fn parse(version: &str) -> (Vec<u64>, Option<&str>) {
+            let (numbers, pre_release) = match version.split_once('-') {
+                Some((numbers, pre_release)) => (numbers, Some(pre_release)),
+                None => (version, None),
+            };
+            let numbers = numbers
+                .split('.')
+                .map(|number| number.parse().unwrap_or(0))
+                .collect();
+            (numbers, pre_release)
+        }
+
+        let (version_numbers, version_pre) = parse(version);
+        let (current_numbers, current_pre) = parse(current);
+        match version_numbers.cmp(&current_numbers) {
+            std::cmp::Ordering::Greater => true,
+            std::cmp::Ordering::Less => false,
+            std::cmp::Ordering::Equal => match (version_pre, current_pre) {
+                (None, Some(_)) => true,
+                (Some(version_pre), Some(current_pre)) => {
+                    compare_pre_release(version_pre, current_pre).is_gt()
+                }
+                _ => false,
+            },
+        }
+    }
+
+    /// Compare two pre-release tags with the precedence of semantic
+    /// versioning: identifiers are compared one by one, numerically if both
+    /// are numbers (so `alpha.10` is more recent than `alpha.9`), and numbers
+    /// come before other identifiers.
+    fn compare_pre_release(version: &str, current: &str) -> std::cmp::Ordering {
+        use std::cmp::Ordering;
+
+        let mut version_ids = version.split('.');
+        let mut current_ids = current.split('.');
+        loop {
+            let ordering = match (version_ids.next(), current_ids.next()) {
+                (None, None) => return Ordering::Equal,
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(version_id), Some(current_id)) => {
+                    match (version_id.parse::<u64>(), current_id.parse::<u64>()) {
+                        (Ok(version_id), Ok(current_id)) => version_id.cmp(&current_id),
+                        (Ok(_), Err(_)) => Ordering::Less,
+                        (Err(_), Ok(_)) => Ordering::Greater,
+                        (Err(_), Err(_)) => version_id.cmp(current_id),
+                    }
+                }
+            };
+            if ordering.is_ne() {
+                return ordering;
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_archive_name() {
+            assert_eq!(
+                archive_name("x86_64-pc-windows-msvc"),
+                "jarl-x86_64-pc-windows-msvc.zip"
+            );
+            assert_eq!(
+                archive_name("aarch64-apple-darwin"),
+                "jarl-aarch64-apple-darwin.tar.gz"
+            );
+        }
+
+        #[test]
+        fn test_parse_checksum() {
+            let digest = "a".repeat(64);
+            assert_eq!(
+                parse_checksum(&format!("{digest} *jarl-x86_64-unknown-linux-gnu.tar.gz\n")),
+                Some(digest.clone())
+            );
+            assert_eq!(parse_checksum(&digest.to_uppercase()), Some(digest));
+            assert_eq!(parse_checksum("not a checksum"), None);
+            assert_eq!(parse_checksum(""), None);
+        }
+
+        #[test]
+        fn test_is_newer() {
+            assert!(is_newer("0.6.0", "0.5.0"));
+            assert!(is_newer("0.10.0", "0.9.1"));
+            assert!(is_newer("0.6.0", "0.6.0-alpha.4"));
+            assert!(is_newer("0.6.0-alpha.5", "0.6.0-alpha.4"));
+            assert!(!is_newer("0.5.0", "0.6.0-alpha.4"));
+            assert!(!is_newer("0.6.0", "0.6.0"));
+            assert!(!is_newer("0.6.0-alpha.4", "0.6.0"));
+        }
+
+        #[test]
+        fn test_is_newer_pre_release_precedence() {
+            assert!(is_newer("0.6.0-alpha.10", "0.6.0-alpha.9"));
+            assert!(!is_newer("0.6.0-alpha.9", "0.6.0-alpha.10"));
+            assert!(is_newer("0.6.0-beta.1", "0.6.0-alpha.10"));
+            assert!(is_newer("0.6.0-alpha.1", "0.6.0-alpha"));
+            assert!(is_newer("0.6.0-alpha.beta", "0.6.0-alpha.1"));
+            assert!(!is_newer("0.6.0-alpha.10", "0.6.0-alpha.10"));
+        }
+
+        #[test]
+        fn test_extract_binary_from_tar_gz() {
+            let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+                Vec::new(),
+                flate2::Compression::default(),
+            ));
+            let content = b"binary content";
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o755);
+            builder
+                .append_data(
+                    &mut header,
+                    "jarl-x86_64-unknown-linux-gnu/jarl",
+                    &content[..],
+                )
+                .unwrap();
+            let archive = builder.into_inner().unwrap().finish().unwrap();
+
+            let binary = extract_binary(&archive, "x86_64-unknown-linux-gnu").unwrap();
+            assert_eq!(binary, content);
+        }
+
+        #[test]
+        fn test_sha256() {
+            assert_eq!(
+                sha256(b"jarl"),
+                "ef3393ab98e60ec7b83ff5a37cc5d4a5a9f2b476d556022f2e9bf1e48d431025"
+            );
+        }
+    }
+}
@@ -1,10 +1,10 @@
 // use crate::args::LanguageServerCommand;
 use crate::{args::ServerCommand, status::ExitStatus};
 
-pub(crate) fn server(_command: ServerCommand) -> anyhow::Result<ExitStatus> {
+pub(crate) fn server(command: ServerCommand) -> anyhow::Result<ExitStatus> {
     eprintln!("JARL CLI: Starting server command");
 
-    match jarl_lsp::run() {
+    match jarl_lsp::run(command.read_only) {
         Ok(()) => {
             eprintln!("JARL CLI: LSP server completed successfully");
             Ok(ExitStatus::Success)
@@ -0,0 +1,614 @@
+//! `jarl tui`, an interactive interface to browse the violations of a project,
+//! preview and apply their fixes, and insert suppression comments.
+//!
+//! The violations are found with the same functions as `jarl check`, and the
+//! fixes are applied with the same functions as `jarl fix`, so the interface
+//! doesn't require an editor integration.
+//!
+//! This requires the `tui` feature, which is only enabled in the binaries built
+//! for the releases.
+
+use anyhow::Result;
+
+use crate::args::TuiCommand;
+use crate::status::ExitStatus;
+
+pub(crate) fn tui(args: TuiCommand) -> Result<ExitStatus> {
+    imp::tui(args)
+}
+
+#[cfg(not(feature = "tui"))]
+mod imp {
+    use anyhow::{Result, anyhow};
+
+    use crate::args::TuiCommand;
+    use crate::status::ExitStatus;
+
+    pub(super) fn tui(_args: TuiCommand) -> Result<ExitStatus> {
+        Err(anyhow!(
+            "This version of Jarl was built without `jarl tui`. It is available in the binaries \
+             of the releases, installed with the standalone installer."
+        ))
+    }
+}
+
+#[cfg(feature = "tui")]
+mod imp {
+    use std::collections::{BTreeMap, HashSet};
+    use std::path::{Path, PathBuf};
+
+    use anyhow::{Context, Result, bail};
+    use jarl_core::config::{ArgsConfig, Config};
+    use jarl_core::diagnostic::Diagnostic;
+    use jarl_core::fix::{apply_fixes, run_post_fix_command};
+    use jarl_core::fs::{has_rmd_extension, write_atomic};
+    use jarl_core::vcs::check_version_control;
+    use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+    use ratatui::layout::{Constraint, Layout};
+    use ratatui::style::{Style, Stylize};
+    use ratatui::text::{Line, Span};
+    use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph, Wrap};
+    use ratatui::{DefaultTerminal, Frame};
+    use similar::{ChangeTag, TextDiff};
+
+    use crate::args::TuiCommand;
+    use crate::commands::check::{Workspace, insert_suppression_comments, resolve_workspace};
+    use crate::commands::fix::pluralize;
+    use crate::status::ExitStatus;
+
+    const HELP: &str = "↑/↓ move  space mark  a mark all  g group by rule/file  f fix  \
+                        i suppress  r check again  q quit";
+
+    pub(super) fn tui(args: TuiCommand) -> Result<ExitStatus> {
+        // Newlines would break comment format
+        if args.reason.contains(['\n', '\r']) {
+            bail!("--reason cannot contain newline characters.");
+        }
+
+        // Fixes are applied from the interface, not when checking the files.
+        let check_config = ArgsConfig {
+            files: args.files.iter().map(|s| s.into()).collect(),
+            fix: false,
+            unsafe_fixes: false,
+            unsafe_fixes_rules: String::new(),
+            fix_only: false,
+            select: args.select.clone(),
+            extend_select: args.extend_select.clone(),
+            ignore: args.ignore.clone(),
+            min_r_version: args.min_r_version.clone(),
+            allow_dirty: args.allow_dirty,
+            allow_no_vcs: args.allow_no_vcs,
+            respect_staged: false,
+            assignment: None,
+            preview: args.preview,
+            profile: args.profile,
+        };
+
+        let Some(Workspace { configs, .. }) = resolve_workspace(
+            &args.files,
+            &args.exclude,
+            &args.extend_exclude,
+            args.no_default_exclude,
+            args.follow_symlinks,
+            &check_config,
+        )?
+        else {
+            return Ok(ExitStatus::Success);
+        };
+
+        let mut app = App::new(args, configs);
+        app.check();
+
+        let mut terminal = ratatui::init();
+        let result = app.run(&mut terminal);
+        ratatui::restore();
+        result?;
+
+        Ok(ExitStatus::Success)
+    }
+
+    /// A violation found by the last check.
+    struct Entry {
+        path: String,
+        diagnostic: Diagnostic,
+        /// Index of the config used to check the file in `App::configs`.
+        config: usize,
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum GroupBy {
+        Rule,
+        File,
+    }
+
+    /// A line in the list of violations.
+    enum Row {
+        /// Name of a rule or of a file, with the indices of its entries.
+        Group(String, Vec<usize>),
+        Entry(usize),
+    }
+
+    struct App {
+        args: TuiCommand,
+        configs: Vec<Config>,
+        entries: Vec<Entry>,
+        errors: Vec<String>,
+        group_by: GroupBy,
+        rows: Vec<Row>,
+        list_state: ListState,
+        /// Entries marked with `space`. Actions apply to them if there are
+        /// some, and to the row under the cursor otherwise.
+        marked: HashSet<usize>,
+        /// Result of the last action.
+        status: String,
+    }
+
+    impl App {
+        fn new(args: TuiCommand, configs: Vec<Config>) -> Self {
+            Self {
+                args,
+                configs,
+                entries: Vec::new(),
+                errors: Vec::new(),
+                group_by: GroupBy::Rule,
+                rows: Vec::new(),
+                list_state: ListState::default(),
+                marked: HashSet::new(),
+                status: String::new(),
+            }
+        }
+
+        fn run(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+            loop {
+                terminal.draw(|frame| self.draw(frame))?;
+
+                let Event::Key(key) = event::read()? else {
+                    continue;
+                };
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Up | KeyCode::Char('k') => self.move_cursor(-1),
+                    KeyCode::Down | KeyCode::Char('j') => self.move_cursor(1),
+                    KeyCode::PageUp => self.move_cursor(-10),
+                    KeyCode::PageDown => self.move_cursor(10),
+                    KeyCode::Home => self.move_cursor(isize::MIN),
+                    KeyCode::End => self.move_cursor(isize::MAX),
+                    KeyCode::Char(' ') => self.toggle_mark(),
+                    KeyCode::Char('a') => self.toggle_mark_all(),
+                    KeyCode::Char('g') | KeyCode::Tab => self.toggle_group_by(),
+                    KeyCode::Char('f') => self.apply_fixes(),
+                    KeyCode::Char('i') => self.insert_suppressions(),
+                    KeyCode::Char('r') => {
+                        self.check();
+                        self.status = "Checked the files again.".to_string();
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        /// Check all files and replace the list of violations.
+        fn check(&mut self) {
+            self.entries.clear();
+            self.errors.clear();
+            self.marked.clear();
+
+            // Like in `jarl check`, report each violation only once if a file
+            // is reached with different paths.
+            let mut seen_diagnostics = HashSet::new();
+            for (config_index, config) in self.configs.iter().enumerate() {
+                for (path, result) in jarl_core::check::check(config.clone()) {
+                    match result {
+                        Ok(diagnostics) => {
+                            let canonical_path = std::fs::canonicalize(&path)
+                                .unwrap_or_else(|_| PathBuf::from(&path));
+                            for diagnostic in diagnostics {
+                                if seen_diagnostics.insert(diagnostic.fingerprint(&canonical_path))
+                                {
+                                    self.entries.push(Entry {
+                                        path: path.clone(),
+                                        diagnostic,
+                                        config: config_index,
+                                    });
+                                }
+                            }
+                        }
+                        Err(e) => self.errors.push(format!("{path}: {e}")),
+                    }
+                }
+            }
+            self.entries.sort_by(|a, b| {
+                (&a.path, a.diagnostic.range.start()).cmp(&(&b.path, b.diagnostic.range.start()))
+            });
+
+            self.build_rows();
+        }
+
+        fn build_rows(&mut self) {
+            let mut groups: BTreeMap<String, Vec<usize>> = BTreeMap::new();
+            for (i, entry) in self.entries.iter().enumerate() {
+                let name = match self.group_by {
+                    GroupBy::Rule => entry.diagnostic.message.name.clone(),
+                    GroupBy::File => display_path(&entry.path),
+                };
+                groups.entry(name).or_default().push(i);
+            }
+
+            self.rows.clear();
+            for (name, indices) in groups {
+                self.rows.push(Row::Group(name, indices.clone()));
+                self.rows.extend(indices.into_iter().map(Row::Entry));
+            }
+
+            let selected = self.list_state.selected().unwrap_or(0);
+            self.list_state
+                .select((!self.rows.is_empty()).then(|| selected.min(self.rows.len() - 1)));
+        }
+
+        fn current_row(&self) -> Option<&Row> {
+            self.list_state.selected().and_then(|i| self.rows.get(i))
+        }
+
+        fn move_cursor(&mut self, delta: isize) {
+            if self.rows.is_empty() {
+                return;
+            }
+            let current = self.list_state.selected().unwrap_or(0);
+            let selected = current
+                .saturating_add_signed(delta)
+                .min(self.rows.len() - 1);
+            self.list_state.select(Some(selected));
+        }
+
+        fn toggle_mark(&mut self) {
+            let indices = match self.current_row() {
+                Some(Row::Group(_, indices)) => indices.clone(),
+                Some(Row::Entry(i)) => vec![*i],
+                None => return,
+            };
+            self.set_marked(&indices);
+        }
+
+        fn toggle_mark_all(&mut self) {
+            let indices: Vec<usize> = (0..self.entries.len()).collect();
+            self.set_marked(&indices);
+        }
+
+        /// Unmark `indices` if they are all marked, and mark them otherwise.
+        fn set_marked(&mut self, indices: &[usize]) {
+            if indices.iter().all(|i| self.marked.contains(i)) {
+                for i in indices {
+                    self.marked.remove(i);
+                }
+            } else {
+                self.marked.extend(indices);
+            }
+        }
+
+        fn toggle_group_by(&mut self) {
+            self.group_by = match self.group_by {
+                GroupBy::Rule => GroupBy::File,
+                GroupBy::File => GroupBy::Rule,
+            };
+            self.list_state.select(Some(0));
+            self.build_rows();
+        }
+
+        /// Indices of the entries on which actions apply.
+        fn targets(&self) -> Vec<usize> {
+            if !self.marked.is_empty() {
+                let mut marked: Vec<usize> = self.marked.iter().copied().collect();
+                marked.sort_unstable();
+                return marked;
+            }
+            match self.current_row() {
+                Some(Row::Group(_, indices)) => indices.clone(),
+                Some(Row::Entry(i)) => vec![*i],
+                None => Vec::new(),
+            }
+        }
+
+        /// Whether the fix of `entry` can be applied, given `--unsafe`.
+        fn is_fixable(&self, entry: &Entry) -> bool {
+            // Rmd/Qmd files never get autofixes applied.
+            if has_rmd_extension(Path::new(&entry.path)) {
+                return false;
+            }
+            entry.diagnostic.has_safe_fix()
+                || (self.args.unsafe_fixes && entry.diagnostic.has_unsafe_fix())
+        }
+
+        fn apply_fixes(&mut self) {
+            let mut by_file: BTreeMap<String, (usize, Vec<Diagnostic>)> = BTreeMap::new();
+            for i in self.targets() {
+                let entry = &self.entries[i];
+                if self.is_fixable(entry) {
+                    by_file
+                        .entry(entry.path.clone())
+                        .or_insert_with(|| (entry.config, Vec::new()))
+                        .1
+                        .push(entry.diagnostic.clone());
+                }
+            }
+            if by_file.is_empty() {
+                self.status = "No fixes to apply.".to_string();
+                return;
+            }
+
+            // Same requirements as `jarl fix`. The options related to version
+            // control are the same in all configs.
+            let paths: Vec<String> = by_file.keys().cloned().collect();
+            if let Err(e) = check_version_control(&paths, &self.configs[0]) {
+                self.status = format!("Error: {}", e.to_string().replace('\n', " "));
+                return;
+            }
+
+            let n_selected: usize = by_file.values().map(|(_, fixes)| fixes.len()).sum();
+            let mut n_fixes = 0;
+            let mut n_files = 0;
+            let mut errors = Vec::new();
+            for (path, (config, diagnostics)) in &by_file {
+                match fix_file(path, diagnostics, &self.configs[*config]) {
+                    Ok(0) => {}
+                    Ok(n) => {
+                        n_fixes += n;
+                        n_files += 1;
+                    }
+                    Err(e) => errors.push(e),
+                }
+            }
+
+            self.check();
+            self.status = match errors.first() {
+                Some(e) => format!("Error: {e}"),
+                None => format!(
+                    "Fixed {} in {}.",
+                    pluralize(n_fixes, "violation"),
+                    pluralize(n_files, "file")
+                ),
+            };
+            // Fixes that overlap are skipped, as in a single pass of `jarl fix`.
+            if errors.is_empty() && n_fixes < n_selected {
+                self.status
+                    .push_str(" Some fixes overlap, apply them again to fix the rest.");
+            }
+        }
+
+        fn insert_suppressions(&mut self) {
+            let mut by_file: BTreeMap<&str, Vec<&Diagnostic>> = BTreeMap::new();
+            for i in self.targets() {
+                let entry = &self.entries[i];
+                by_file
+                    .entry(entry.path.as_str())
+                    .or_default()
+                    .push(&entry.diagnostic);
+            }
+
+            let mut n_suppressions = 0;
+            let mut n_files = 0;
+            let mut errors = Vec::new();
+            for (path, diagnostics) in by_file {
+                let content = match std::fs::read_to_string(path) {
+                    Ok(content) => content,
+                    Err(e) => {
+                        errors.push(format!("Could not read {path}: {e}"));
+                        continue;
+                    }
+                };
+                let Some((modified_content, n)) = insert_suppression_comments(
+                    Path::new(path),
+                    &content,
+                    &diagnostics,
+                    &self.args.reason,
+                ) else {
+                    continue;
+                };
+                match write_atomic(path, &modified_content) {
+                    Ok(()) => {
+                        n_suppressions += n;
+                        n_files += 1;
+                    }
+                    Err(e) => errors.push(format!("Could not write {path}: {e}")),
+                }
+            }
+
+            self.check();
+            self.status = match errors.first() {
+                Some(e) => format!("Error: {e}"),
+                None => format!(
+                    "Added {} in {}.",
+                    pluralize(n_suppressions, "suppression comment"),
+                    pluralize(n_files, "file")
+                ),
+            };
+        }
+
+        fn draw(&mut self, frame: &mut Frame) {
+            let [main, footer] =
+                Layout::vertical([Constraint::Min(0), Constraint::Length(3)]).areas(frame.area());
+            let [list_area, preview_area] =
+                Layout::horizontal([Constraint::Percentage(45), Constraint::Percentage(55)])
+                    .areas(main);
+
+            let group_by = match self.group_by {
+                GroupBy::Rule => "rule",
+                GroupBy::File => "file",
+            };
+            let title = format!(
+                " {} by {group_by} ",
+                pluralize(self.entries.len(), "violation")
+            );
+            let items: Vec<ListItem> = self.rows.iter().map(|row| self.list_item(row)).collect();
+            let list = List::new(items)
+                .block(Block::bordered().title(title))
+                .highlight_style(Style::new().reversed());
+            frame.render_stateful_widget(list, list_area, &mut self.list_state);
+
+            let preview = Paragraph::new(self.preview())
+                .block(Block::bordered().title(" Preview "))
+                .wrap(Wrap { trim: false });
+            frame.render_widget(preview, preview_area);
+
+            let errors = match self.errors.first() {
+                Some(error) => Line::from(format!(
+                    "{} could not be checked, e.g. {error}",
+                    pluralize(self.errors.len(), "file")
+                ))
+                .red(),
+                None => Line::default(),
+            };
+            let lines = vec![
+                Line::from(self.status.clone()),
+                errors,
+                Line::from(HELP).dark_gray(),
+            ];
+            frame.render_widget(Paragraph::new(lines), footer);
+        }
+
+        fn list_item(&self, row: &Row) -> ListItem<'static> {
+            match row {
+                Row::Group(name, indices) => ListItem::new(Line::from(vec![
+                    Span::from(name.clone()).bold(),
+                    Span::from(format!(" ({})", indices.len())).dark_gray(),
+                ])),
+                Row::Entry(i) => {
+                    let entry = &self.entries[*i];
+                    let mark = if self.marked.contains(i) {
+                        "[x]"
+                    } else {
+                        "[ ]"
+                    };
+                    let label = match self.group_by {
+                        GroupBy::Rule => format!(
+                            "{}:{}",
+                            display_path(&entry.path),
+                            position(&entry.diagnostic)
+                        ),
+                        GroupBy::File => format!(
+                            "{} {}",
+                            position(&entry.diagnostic),
+                            entry.diagnostic.message.name
+                        ),
+                    };
+                    let fix = if self.is_fixable(entry) { " (fix)" } else { "" };
+                    ListItem::new(Line::from(vec![
+                        Span::from(format!("  {mark} {label}")),
+                        Span::from(fix).green(),
+                    ]))
+                }
+            }
+        }
+
+        fn preview(&self) -> Vec<Line<'static>> {
+            match self.current_row() {
+                None => vec![Line::from("No violations found.")],
+                Some(Row::Group(name, indices)) => {
+                    let n_fixable = indices
+                        .iter()
+                        .filter(|i| self.is_fixable(&self.entries[**i]))
+                        .count();
+                    vec![
+                        Line::from(name.clone()).bold(),
+                        Line::default(),
+                        Line::from(format!(
+                            "{}, {n_fixable} with a fix that can be applied.",
+                            pluralize(indices.len(), "violation")
+                        )),
+                    ]
+                }
+                Some(Row::Entry(i)) => self.entry_preview(&self.entries[*i]),
+            }
+        }
+
+        fn entry_preview(&self, entry: &Entry) -> Vec<Line<'static>> {
+            let diagnostic = &entry.diagnostic;
+            let mut lines = vec![
+                Line::from(vec![
+                    Span::from(format!(
+                        "{}:{} ",
+                        display_path(&entry.path),
+                        position(diagnostic)
+                    )),
+                    Span::from(format!("[{}]", diagnostic.message.name)).bold(),
+                ]),
+                Line::default(),
+                Line::from(diagnostic.message.body.clone()),
+            ];
+            if let Some(suggestion) = &diagnostic.message.suggestion {
+                lines.push(Line::from(format!("help: {suggestion}")).cyan());
+            }
+            lines.push(Line::default());
+
+            if self.is_fixable(entry) {
+                lines.extend(fix_diff(entry));
+            } else if has_rmd_extension(Path::new(&entry.path)) && !diagnostic.has_no_fix() {
+                lines.push(Line::from(
+                    "Fixes are not applied to R Markdown and Quarto files.",
+                ));
+            } else if diagnostic.has_unsafe_fix() {
+                lines.push(Line::from(
+                    "This violation has an unsafe fix, restart with `--unsafe` to apply it.",
+                ));
+            } else {
+                lines.push(Line::from("No automatic fix available."));
+            }
+            lines
+        }
+    }
+
+    /// Apply the fixes of `diagnostics` to the file `path` and return the number
+    /// of fixes applied.
+    fn fix_file(path: &str, diagnostics: &[Diagnostic], config: &Config) -> Result<usize> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read file: {path}"))?;
+        let (fixed_text, applied) = apply_fixes(diagnostics, &contents);
+        if fixed_text == contents {
+            return Ok(0);
+        }
+
+        write_atomic(path, &fixed_text).with_context(|| format!("Failed to write file: {path}"))?;
+        if let Some(command) = &config.post_fix_command {
+            run_post_fix_command(command, path)?;
+        }
+        Ok(applied.len())
+    }
+
+    /// The fix of `entry` as a diff with two lines of context.
+    fn fix_diff(entry: &Entry) -> Vec<Line<'static>> {
+        let Ok(contents) = std::fs::read_to_string(&entry.path) else {
+            return vec![Line::from(format!("Could not read {}.", entry.path)).red()];
+        };
+        let (fixed_text, _) = apply_fixes(std::slice::from_ref(&entry.diagnostic), &contents);
+
+        let mut lines = vec![Line::from("Fix:").bold()];
+        let diff = TextDiff::from_lines(&contents, &fixed_text);
+        for hunk in diff.unified_diff().context_radius(2).iter_hunks() {
+            lines.push(Line::from(hunk.header().to_string()).dark_gray());
+            for change in hunk.iter_changes() {
+                let value = change.value().trim_end_matches(['\n', '\r']);
+                let line = match change.tag() {
+                    ChangeTag::Delete => Line::from(format!("-{value}")).red(),
+                    ChangeTag::Insert => Line::from(format!("+{value}")).green(),
+                    ChangeTag::Equal => Line::from(format!(" {value}")),
+                };
+                lines.push(line);
+            }
+        }
+        lines
+    }
+
+    fn display_path(path: &str) -> String {
+        path.replace('\\', "/")
+    }
+
+    /// 1-based row and column of the violation.
+    fn position(diagnostic: &Diagnostic) -> String {
+        match diagnostic.location {
+            Some(location) => format!("{}:{}", location.row(), location.column() + 1),
+            None => String::new(),
+        }
+    }
+}
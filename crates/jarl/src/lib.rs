@@ -10,7 +10,10 @@ pub mod statistics;
 pub mod status;
 
 pub use args::CheckCommand;
-pub use output_format::{ConciseEmitter, JsonEmitter, OutputFormat, SarifEmitter};
+pub use output_format::{
+    CheckstyleEmitter, CompactEmitter, ConciseEmitter, GroupedEmitter, JsonEmitter, JunitEmitter,
+    OutputFormat, PrometheusEmitter, RdjsonEmitter, SarifEmitter, TeamcityEmitter,
+};
 
 pub fn run(args: Args) -> anyhow::Result<ExitStatus> {
     if !matches!(args.command, Command::Server(_)) {
@@ -20,7 +23,15 @@ pub fn run(args: Args) -> anyhow::Result<ExitStatus> {
 
     match args.command {
         Command::Check(command) => commands::check::check(*command),
+        Command::Fix(command) => commands::fix::fix(*command),
+        Command::Config(command) => commands::config::config(command),
+        Command::Tui(command) => commands::tui::tui(*command),
+        Command::Init(command) => commands::init::init(command),
+        Command::MigrateLintr(command) => commands::migrate_lintr::migrate_lintr(command),
         Command::Rule(command) => commands::rule::rule(command),
         Command::Server(command) => commands::server::server(command),
+        Command::Completions(command) => commands::completions::completions(command),
+        Command::Man(command) => commands::man::man(command),
+        Command::Self_(command) => commands::self_update::self_command(command),
     }
 }
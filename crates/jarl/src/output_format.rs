@@ -7,16 +7,24 @@ use std::borrow::Cow;
 use std::fs;
 use std::io::{BufWriter, Write};
 
+/// Base URL of the documentation of each rule.
+const RULES_URL_BASE: &str = "https://jarl.etiennebacher.com/rules/";
+
 /// Creates a terminal hyperlink using OSC 8 escape sequences
 /// Format: \x1b]8;;<URL>\x1b\\<TEXT>\x1b]8;;\x1b\\
 fn make_hyperlink(text: &str) -> String {
+    // Syntax errors don't have a documentation page.
+    if text == SYNTAX_ERROR {
+        return text.to_string();
+    }
     format!(
         "\x1b]8;;{}{}\x1b\\{}\x1b]8;;\x1b\\",
-        "https://jarl.etiennebacher.com/rules/", text, text
+        RULES_URL_BASE, text, text
     )
 }
 
-use jarl_core::diagnostic::{Diagnostic, render_diagnostic};
+use jarl_core::diagnostic::{Diagnostic, SYNTAX_ERROR, Severity, render_diagnostic};
+use jarl_core::vcs::StagedFile;
 
 /// Prints a section header like `── Summary ──────────────────────────────────`
 /// padded to 57 characters total.
@@ -29,15 +37,43 @@ pub fn print_section_header(title: &str) {
     println!("{prefix}{padding}");
 }
 
+/// Prints the number of diagnostics that were not printed because of
+/// `--max-diagnostics`, if any.
+pub fn print_hidden_diagnostics(n_hidden: usize) {
+    match n_hidden {
+        0 => {}
+        1 => println!("… and 1 more diagnostic."),
+        _ => println!("… and {n_hidden} more diagnostics."),
+    }
+}
+
+/// Prints the number of violations of each rule that were not printed because
+/// of `max-per-rule-per-file`, by file.
+pub fn print_hidden_rule_diagnostics(hidden: &[(String, String, usize)]) {
+    for (path, rule, n_hidden) in hidden {
+        let violations = if *n_hidden == 1 {
+            "violation"
+        } else {
+            "violations"
+        };
+        println!("… and {n_hidden} more {violations} of `{rule}` in {path}.");
+    }
+}
+
 /// Prints the summary section with error counts and fix info.
-/// Only call for human-readable formats (Full, Concise).
-pub fn print_summary(diagnostics: &[&Diagnostic], has_errors: bool) {
+/// Only call for human-readable formats (Full, Concise, Grouped).
+///
+/// With `quiet`, the diagnostics were not printed so the summary isn't
+/// separated from them by a blank line.
+pub fn print_summary(diagnostics: &[&Diagnostic], has_errors: bool, quiet: bool) {
     let total: i32 = diagnostics.len() as i32;
     let n_safe_fixes = diagnostics.iter().filter(|d| d.has_safe_fix()).count();
     let n_unsafe_fixes = diagnostics.iter().filter(|d| d.has_unsafe_fix()).count();
 
     if total > 0 {
-        println!();
+        if !quiet {
+            println!();
+        }
         print_section_header("Summary");
 
         if total > 1 {
@@ -127,12 +163,26 @@ pub enum OutputFormat {
     Full,
     /// Print diagnostics in a concise format, one per line
     Concise,
+    /// Print diagnostics grouped by file, with one header per file
+    Grouped,
+    /// Print diagnostics as `path:line:col: rule message`, one per line
+    Compact,
+    /// Print diagnostics as Checkstyle XML
+    Checkstyle,
     /// Print diagnostics as GitHub format
     Github,
     /// Print diagnostics as JSON
     Json,
+    /// Print diagnostics as JUnit XML
+    Junit,
+    /// Print aggregate metrics in Prometheus text format
+    Prometheus,
+    /// Print diagnostics as Reviewdog Diagnostic Format JSON
+    Rdjson,
     /// Print diagnostics as SARIF 2.1.0 JSON
     Sarif,
+    /// Print diagnostics as TeamCity service messages
+    Teamcity,
 }
 
 /// Takes the diagnostics and parsing errors in each file and then displays
@@ -210,6 +260,136 @@ impl Emitter for ConciseEmitter {
     }
 }
 
+/// An emitter printing the path of each file once, followed by one
+/// `line:col rule message` line per diagnostic in this file.
+///
+/// This relies on diagnostics being sorted, so that the diagnostics of a file
+/// are next to each other.
+pub struct GroupedEmitter;
+
+impl Emitter for GroupedEmitter {
+    fn emit<W: Write>(
+        &self,
+        writer: &mut W,
+        diagnostics: &[&Diagnostic],
+        errors: &[(String, anyhow::Error)],
+    ) -> anyhow::Result<()> {
+        let mut writer = BufWriter::new(writer);
+
+        // First, print all parsing errors
+        if !errors.is_empty() {
+            writer.flush()?; // Flush before writing to stderr
+            for (_path, err) in errors {
+                eprintln!("{}: {}", "Error".red().bold(), err);
+            }
+        }
+
+        let use_colors = std::env::var("NO_COLOR").is_err();
+
+        for (i, file_diagnostics) in diagnostics
+            .chunk_by(|a, b| a.filename == b.filename)
+            .enumerate()
+        {
+            if i > 0 {
+                writeln!(writer)?;
+            }
+            let relative_path = relativize_path(file_diagnostics[0].filename.clone());
+            writeln!(writer, "{}:", relative_path.bold())?;
+
+            let locations: Vec<String> = file_diagnostics
+                .iter()
+                .map(|diagnostic| match diagnostic.location {
+                    // Convert to 1-based for display
+                    Some(loc) => format!("{}:{}", loc.row(), loc.column() + 1),
+                    None => {
+                        unreachable!("Row/col locations must have been parsed successfully before.")
+                    }
+                })
+                .collect();
+            // Align the rule names of all diagnostics in the file.
+            let width = locations.iter().map(String::len).max().unwrap_or(0);
+
+            for (diagnostic, location) in file_diagnostics.iter().zip(&locations) {
+                let message = if let Some(suggestion) = &diagnostic.message.suggestion {
+                    format!("{} {}", diagnostic.message.body, suggestion)
+                } else {
+                    diagnostic.message.body.clone()
+                };
+                let rule_name = if use_colors {
+                    &make_hyperlink(&diagnostic.message.name)
+                } else {
+                    &diagnostic.message.name
+                };
+                writeln!(
+                    writer,
+                    "  {:<width$} {} {}",
+                    location,
+                    rule_name.red(),
+                    message
+                )?;
+            }
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// An emitter producing one `path:line:col: rule message` line per
+/// diagnostic, without colors or hyperlinks.
+///
+/// This is the GNU error format, which can be parsed by editors (e.g. Vim's
+/// quickfix list or Emacs' `compilation-mode`) without extra configuration.
+pub struct CompactEmitter;
+
+impl Emitter for CompactEmitter {
+    fn emit<W: Write>(
+        &self,
+        writer: &mut W,
+        diagnostics: &[&Diagnostic],
+        errors: &[(String, anyhow::Error)],
+    ) -> anyhow::Result<()> {
+        let mut writer = BufWriter::new(writer);
+
+        // Parsing errors go to stderr so that stdout only contains diagnostics.
+        if !errors.is_empty() {
+            writer.flush()?;
+            for (_path, err) in errors {
+                eprintln!("Error: {err}");
+            }
+        }
+
+        let mut path_cache = std::collections::HashMap::new();
+
+        for diagnostic in diagnostics {
+            let (row, col) = match diagnostic.location {
+                Some(loc) => (loc.row(), loc.column() + 1), // Convert to 1-based for display
+                None => {
+                    unreachable!("Row/col locations must have been parsed successfully before.")
+                }
+            };
+
+            let relative_path = path_cache
+                .entry(&diagnostic.filename)
+                .or_insert_with(|| relativize_path(diagnostic.filename.clone()));
+
+            let message = if let Some(suggestion) = &diagnostic.message.suggestion {
+                format!("{} {}", diagnostic.message.body, suggestion)
+            } else {
+                diagnostic.message.body.clone()
+            };
+            writeln!(
+                writer,
+                "{}:{}:{}: {} {}",
+                relative_path, row, col, diagnostic.message.name, message
+            )?;
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+}
+
 pub struct JsonEmitter;
 
 impl Emitter for JsonEmitter {
@@ -265,9 +445,14 @@ impl Emitter for GithubEmitter {
             // - one after the "::" marker: this is so that the workflow shows
             //   the location of diagnostics when we inspect the workflow itself,
             //   without the Github annotations.
+            let command = match diagnostic.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Info => "notice",
+            };
             write!(
                 writer,
-                "::warning title=Jarl ({}),file={file},line={row},col={col}::{file}:{row}:{col} ",
+                "::{command} title=Jarl ({}),file={file},line={row},col={col}::{file}:{row}:{col} ",
                 diagnostic.message.name,
                 file = diagnostic.filename.to_string_lossy()
             )?;
@@ -285,14 +470,406 @@ impl Emitter for GithubEmitter {
     }
 }
 
+/// An emitter producing JUnit XML output.
+///
+/// Each diagnostic is a failed test case, and test cases are grouped in one
+/// test suite per file. This format is displayed natively by CI services such
+/// as GitLab CI, Jenkins, or Azure Pipelines.
+pub struct JunitEmitter;
+
+/// Escape the characters that can't appear as-is in XML text and attributes.
+fn xml_escape(text: &str) -> Cow<'_, str> {
+    if !text.contains(['&', '<', '>', '"', '\'']) {
+        return Cow::Borrowed(text);
+    }
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+impl Emitter for JunitEmitter {
+    fn emit<W: Write>(
+        &self,
+        writer: &mut W,
+        diagnostics: &[&Diagnostic],
+        _errors: &[(String, anyhow::Error)],
+    ) -> anyhow::Result<()> {
+        let mut writer = BufWriter::new(writer);
+
+        // Group diagnostics by file, sorted by path for a stable output.
+        let mut diagnostics_by_file: std::collections::BTreeMap<String, Vec<&Diagnostic>> =
+            std::collections::BTreeMap::new();
+        for diagnostic in diagnostics {
+            diagnostics_by_file
+                .entry(relativize_path(diagnostic.filename.clone()))
+                .or_default()
+                .push(diagnostic);
+        }
+
+        let total = diagnostics.len();
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            writer,
+            r#"<testsuites name="jarl" tests="{total}" failures="{total}">"#
+        )?;
+
+        for (file, file_diagnostics) in &diagnostics_by_file {
+            let file = xml_escape(file);
+            let n = file_diagnostics.len();
+            writeln!(
+                writer,
+                r#"  <testsuite name="{file}" tests="{n}" failures="{n}">"#
+            )?;
+
+            for diagnostic in file_diagnostics {
+                let (row, col) = match diagnostic.location {
+                    Some(loc) => (loc.row(), loc.column() + 1), // Convert to 1-based for display
+                    None => {
+                        unreachable!("Row/col locations must have been parsed successfully before.")
+                    }
+                };
+
+                let name = &diagnostic.message.name;
+                let message = if let Some(suggestion) = &diagnostic.message.suggestion {
+                    format!("{} {}", diagnostic.message.body, suggestion)
+                } else {
+                    diagnostic.message.body.clone()
+                };
+                let message = xml_escape(&message);
+
+                writeln!(
+                    writer,
+                    r#"    <testcase name="{file}:{row}:{col}" classname="jarl.{name}" file="{file}" line="{row}">"#
+                )?;
+                writeln!(
+                    writer,
+                    r#"      <failure message="{message}" type="{}">{file}:{row}:{col}: {name} {message}</failure>"#,
+                    diagnostic.severity
+                )?;
+                writeln!(writer, "    </testcase>")?;
+            }
+
+            writeln!(writer, "  </testsuite>")?;
+        }
+
+        writeln!(writer, "</testsuites>")?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// An emitter producing Checkstyle XML output.
+///
+/// Diagnostics are grouped in one `<file>` element per file. This format is
+/// consumed by many tools, such as reviewdog or the CI plugins for Checkstyle.
+pub struct CheckstyleEmitter;
+
+impl Emitter for CheckstyleEmitter {
+    fn emit<W: Write>(
+        &self,
+        writer: &mut W,
+        diagnostics: &[&Diagnostic],
+        _errors: &[(String, anyhow::Error)],
+    ) -> anyhow::Result<()> {
+        let mut writer = BufWriter::new(writer);
+
+        // Group diagnostics by file, sorted by path for a stable output.
+        let mut diagnostics_by_file: std::collections::BTreeMap<String, Vec<&Diagnostic>> =
+            std::collections::BTreeMap::new();
+        for diagnostic in diagnostics {
+            diagnostics_by_file
+                .entry(relativize_path(diagnostic.filename.clone()))
+                .or_default()
+                .push(diagnostic);
+        }
+
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(writer, r#"<checkstyle version="4.3">"#)?;
+
+        for (file, file_diagnostics) in &diagnostics_by_file {
+            writeln!(writer, r#"  <file name="{}">"#, xml_escape(file))?;
+
+            for diagnostic in file_diagnostics {
+                let (row, col) = match diagnostic.location {
+                    Some(loc) => (loc.row(), loc.column() + 1), // Convert to 1-based for display
+                    None => {
+                        unreachable!("Row/col locations must have been parsed successfully before.")
+                    }
+                };
+
+                let message = if let Some(suggestion) = &diagnostic.message.suggestion {
+                    format!("{} {}", diagnostic.message.body, suggestion)
+                } else {
+                    diagnostic.message.body.clone()
+                };
+
+                // Checkstyle severities are `error`, `warning`, `info`, and
+                // `ignore`, so they match ours.
+                writeln!(
+                    writer,
+                    r#"    <error line="{row}" column="{col}" severity="{}" message="{}" source="jarl.{}"/>"#,
+                    diagnostic.severity,
+                    xml_escape(&message),
+                    diagnostic.message.name
+                )?;
+            }
+
+            writeln!(writer, "  </file>")?;
+        }
+
+        writeln!(writer, "</checkstyle>")?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// An emitter producing aggregate metrics in the Prometheus text exposition
+/// format.
+///
+/// Individual diagnostics are not printed. This is meant for scheduled jobs
+/// that push the metrics to a Pushgateway to follow the number of violations
+/// over time.
+pub struct PrometheusEmitter {
+    pub files_checked: usize,
+    pub duration: std::time::Duration,
+}
+
+impl Emitter for PrometheusEmitter {
+    fn emit<W: Write>(
+        &self,
+        writer: &mut W,
+        diagnostics: &[&Diagnostic],
+        _errors: &[(String, anyhow::Error)],
+    ) -> anyhow::Result<()> {
+        let mut writer = BufWriter::new(writer);
+
+        // Sorted by rule name for a stable output.
+        let mut diagnostics_by_rule: std::collections::BTreeMap<&str, usize> =
+            std::collections::BTreeMap::new();
+        for diagnostic in diagnostics {
+            *diagnostics_by_rule
+                .entry(diagnostic.message.name.as_str())
+                .or_default() += 1;
+        }
+
+        writeln!(
+            writer,
+            "# HELP jarl_diagnostics_total Number of diagnostics reported, by rule."
+        )?;
+        writeln!(writer, "# TYPE jarl_diagnostics_total counter")?;
+        for (rule, count) in &diagnostics_by_rule {
+            writeln!(writer, r#"jarl_diagnostics_total{{rule="{rule}"}} {count}"#)?;
+        }
+
+        writeln!(
+            writer,
+            "# HELP jarl_files_checked Number of R files checked."
+        )?;
+        writeln!(writer, "# TYPE jarl_files_checked gauge")?;
+        writeln!(writer, "jarl_files_checked {}", self.files_checked)?;
+
+        writeln!(
+            writer,
+            "# HELP jarl_duration_seconds Time taken to check the files, in seconds."
+        )?;
+        writeln!(writer, "# TYPE jarl_duration_seconds gauge")?;
+        writeln!(
+            writer,
+            "jarl_duration_seconds {}",
+            self.duration.as_secs_f64()
+        )?;
+
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// An emitter producing JSON in the Reviewdog Diagnostic Format (RDFormat).
+///
+/// Fixes are included as suggestions so that reviewdog can post them as code
+/// suggestions in pull requests. See
+/// [RDFormat](https://github.com/reviewdog/reviewdog/tree/master/proto/rdf).
+pub struct RdjsonEmitter {
+    /// The code read from stdin, if any. See [`read_source()`].
+    pub stdin_contents: Option<String>,
+    /// Whether the code was checked from the Git index. See [`read_source()`].
+    pub staged: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct RdjsonOutput<'a> {
+    source: RdjsonSource,
+    diagnostics: Vec<RdjsonDiagnostic<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct RdjsonSource {
+    name: &'static str,
+    url: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct RdjsonDiagnostic<'a> {
+    message: String,
+    location: RdjsonLocation,
+    severity: &'static str,
+    code: RdjsonCode<'a>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suggestions: Vec<RdjsonSuggestion<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct RdjsonLocation {
+    path: String,
+    range: RdjsonRange,
+}
+
+#[derive(Debug, Serialize)]
+struct RdjsonRange {
+    start: RdjsonPosition,
+    end: RdjsonPosition,
+}
+
+#[derive(Debug, Serialize)]
+struct RdjsonPosition {
+    line: usize,
+    column: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct RdjsonCode<'a> {
+    value: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct RdjsonSuggestion<'a> {
+    range: RdjsonRange,
+    text: &'a str,
+}
+
+/// Convert a byte offset into a 1-indexed RDFormat position. Unlike SARIF,
+/// RDFormat columns are counted in UTF-8 bytes.
+fn offset_to_rdjson_position(content: &str, offset: usize) -> RdjsonPosition {
+    let before = &content[..offset];
+    let line = before.bytes().filter(|&b| b == b'\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |p| p + 1);
+    RdjsonPosition { line, column: offset - line_start + 1 }
+}
+
+fn rdjson_range(content: &str, start: usize, end: usize) -> RdjsonRange {
+    RdjsonRange {
+        start: offset_to_rdjson_position(content, start),
+        end: offset_to_rdjson_position(content, end),
+    }
+}
+
+impl Emitter for RdjsonEmitter {
+    fn emit<W: Write>(
+        &self,
+        writer: &mut W,
+        diagnostics: &[&Diagnostic],
+        _errors: &[(String, anyhow::Error)],
+    ) -> anyhow::Result<()> {
+        let mut writer = BufWriter::new(writer);
+
+        // Cache each file's contents so ranges can be converted to positions
+        // without re-reading the source.
+        let mut content_cache: std::collections::HashMap<std::path::PathBuf, String> =
+            std::collections::HashMap::new();
+
+        let mut rdjson_diagnostics = Vec::with_capacity(diagnostics.len());
+        for diagnostic in diagnostics {
+            let content = match content_cache.entry(diagnostic.filename.clone()) {
+                std::collections::hash_map::Entry::Occupied(entry) => entry.into_mut(),
+                std::collections::hash_map::Entry::Vacant(entry) => {
+                    let Ok(content) = read_source(
+                        &diagnostic.filename,
+                        self.stdin_contents.as_deref(),
+                        self.staged,
+                    ) else {
+                        continue;
+                    };
+                    entry.insert(content)
+                }
+            };
+
+            let message = if let Some(suggestion) = &diagnostic.message.suggestion {
+                format!("{} {}", diagnostic.message.body, suggestion)
+            } else {
+                diagnostic.message.body.clone()
+            };
+
+            let fix = &diagnostic.fix;
+            let suggestions = if diagnostic.has_safe_fix() || diagnostic.has_unsafe_fix() {
+                vec![RdjsonSuggestion {
+                    range: rdjson_range(content, fix.start, fix.end),
+                    text: &fix.content,
+                }]
+            } else {
+                Vec::new()
+            };
+
+            rdjson_diagnostics.push(RdjsonDiagnostic {
+                message,
+                location: RdjsonLocation {
+                    path: relativize_path(diagnostic.filename.clone()).replace('\\', "/"),
+                    range: rdjson_range(
+                        content,
+                        diagnostic.range.start().into(),
+                        diagnostic.range.end().into(),
+                    ),
+                },
+                severity: match diagnostic.severity {
+                    Severity::Error => "ERROR",
+                    Severity::Warning => "WARNING",
+                    Severity::Info => "INFO",
+                },
+                code: RdjsonCode {
+                    value: &diagnostic.message.name,
+                    // Syntax errors don't have a documentation page.
+                    url: (diagnostic.message.name != SYNTAX_ERROR)
+                        .then(|| format!("{RULES_URL_BASE}{}", diagnostic.message.name)),
+                },
+                suggestions,
+            });
+        }
+
+        let output = RdjsonOutput {
+            source: RdjsonSource {
+                name: "jarl",
+                url: "https://github.com/etiennebacher/jarl",
+            },
+            diagnostics: rdjson_diagnostics,
+        };
+
+        serde_json::to_writer_pretty(&mut writer, &output)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
 /// An emitter producing SARIF 2.1.0-compliant JSON output.
 ///
 /// Static Analysis Results Interchange Format (SARIF) is a standard format for
 /// static analysis results, consumed by tools such as GitHub Code Scanning. See
 /// [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html).
-pub struct SarifEmitter;
-
-const SARIF_HELP_URI_BASE: &str = "https://jarl.etiennebacher.com/rules/";
+pub struct SarifEmitter {
+    /// The code read from stdin, if any. See [`read_source()`].
+    pub stdin_contents: Option<String>,
+    /// Whether the code was checked from the Git index. See [`read_source()`].
+    pub staged: bool,
+}
 
 #[derive(Debug, Serialize)]
 struct SarifOutput<'a> {
@@ -442,6 +1019,15 @@ fn offset_to_line_column(content: &str, offset: usize) -> (usize, usize) {
     (line, column)
 }
 
+/// SARIF level corresponding to a diagnostic severity.
+fn sarif_level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "note",
+    }
+}
+
 /// Convert a byte range into a 1-indexed SARIF region (UTF-16 columns).
 fn range_to_region(content: &str, start: usize, end: usize) -> SarifRegion {
     let (start_line, start_column) = offset_to_line_column(content, start);
@@ -466,21 +1052,21 @@ impl Emitter for SarifEmitter {
         // Collect unique rules (sorted by name) using the first diagnostic body
         // we see as the rule's short description, since Jarl has no static
         // per-rule description text.
-        let mut rule_bodies: std::collections::BTreeMap<&str, &str> =
+        let mut rule_bodies: std::collections::BTreeMap<&str, (&str, Severity)> =
             std::collections::BTreeMap::new();
         for diagnostic in diagnostics {
             rule_bodies
                 .entry(&diagnostic.message.name)
-                .or_insert(&diagnostic.message.body);
+                .or_insert((&diagnostic.message.body, diagnostic.severity));
         }
         let rules: Vec<SarifRule> = rule_bodies
             .into_iter()
-            .map(|(name, body)| SarifRule {
+            .map(|(name, (body, severity))| SarifRule {
                 id: name,
                 short_description: SarifMessage { text: Cow::Borrowed(body) },
                 help: SarifMessage { text: Cow::Borrowed(body) },
-                help_uri: format!("{SARIF_HELP_URI_BASE}{name}"),
-                default_configuration: SarifDefaultConfiguration { level: "warning" },
+                help_uri: format!("{RULES_URL_BASE}{name}"),
+                default_configuration: SarifDefaultConfiguration { level: sarif_level(severity) },
             })
             .collect();
 
@@ -497,7 +1083,11 @@ impl Emitter for SarifEmitter {
             let content = match content_cache.entry(diagnostic.filename.clone()) {
                 std::collections::hash_map::Entry::Occupied(entry) => entry.into_mut(),
                 std::collections::hash_map::Entry::Vacant(entry) => {
-                    let Ok(content) = fs::read_to_string(&diagnostic.filename) else {
+                    let Ok(content) = read_source(
+                        &diagnostic.filename,
+                        self.stdin_contents.as_deref(),
+                        self.staged,
+                    ) else {
                         continue;
                     };
                     entry.insert(content)
@@ -542,7 +1132,7 @@ impl Emitter for SarifEmitter {
             results.push(SarifResult {
                 rule_id: &diagnostic.message.name,
                 rule_index: rule_indices[diagnostic.message.name.as_str()],
-                level: "warning",
+                level: sarif_level(diagnostic.severity),
                 message: SarifMessage { text: Cow::Owned(message) },
                 locations: [SarifLocation {
                     physical_location: SarifPhysicalLocation {
@@ -586,7 +1176,129 @@ impl Emitter for SarifEmitter {
     }
 }
 
-pub struct FullEmitter;
+/// An emitter producing TeamCity service messages.
+///
+/// Each rule is registered once with an `inspectionType` message, and each
+/// diagnostic is reported with an `inspection` message, so that results
+/// appear in the "Inspections" tab of TeamCity builds.
+pub struct TeamcityEmitter;
+
+/// Escape the characters that have a special meaning in the values of
+/// TeamCity service messages.
+fn teamcity_escape(text: &str) -> Cow<'_, str> {
+    if !text.contains(['|', '\'', '\n', '\r', '[', ']']) {
+        return Cow::Borrowed(text);
+    }
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '|' => escaped.push_str("||"),
+            '\'' => escaped.push_str("|'"),
+            '\n' => escaped.push_str("|n"),
+            '\r' => escaped.push_str("|r"),
+            '[' => escaped.push_str("|["),
+            ']' => escaped.push_str("|]"),
+            _ => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+impl Emitter for TeamcityEmitter {
+    fn emit<W: Write>(
+        &self,
+        writer: &mut W,
+        diagnostics: &[&Diagnostic],
+        _errors: &[(String, anyhow::Error)],
+    ) -> anyhow::Result<()> {
+        let mut writer = BufWriter::new(writer);
+
+        // Inspection types must be registered before they are used, so we
+        // collect the unique rules first (sorted by name for a stable output).
+        let rules: std::collections::BTreeSet<&str> = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.message.name.as_str())
+            .collect();
+        for rule in rules {
+            writeln!(
+                writer,
+                "##teamcity[inspectionType id='{rule}' name='{rule}' description='{RULES_URL_BASE}{rule}' category='jarl']"
+            )?;
+        }
+
+        for diagnostic in diagnostics {
+            let row = match diagnostic.location {
+                Some(loc) => loc.row(),
+                None => {
+                    unreachable!("Row/col locations must have been parsed successfully before.")
+                }
+            };
+
+            let message = if let Some(suggestion) = &diagnostic.message.suggestion {
+                format!("{} {}", diagnostic.message.body, suggestion)
+            } else {
+                diagnostic.message.body.clone()
+            };
+            let file = relativize_path(diagnostic.filename.clone()).replace('\\', "/");
+            let severity = match diagnostic.severity {
+                Severity::Error => "ERROR",
+                Severity::Warning => "WARNING",
+                Severity::Info => "INFO",
+            };
+
+            writeln!(
+                writer,
+                "##teamcity[inspection typeId='{}' message='{}' file='{}' line='{row}' SEVERITY='{severity}']",
+                diagnostic.message.name,
+                teamcity_escape(&message),
+                teamcity_escape(&file)
+            )?;
+        }
+
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// An emitter printing each diagnostic with the annotated code snippet.
+///
+/// Lines of code longer than `max_width` are truncated around the annotation.
+/// When `max_width` is `None`, annotate-snippets uses its default width.
+pub struct FullEmitter {
+    pub max_width: Option<usize>,
+    /// The code read from stdin, if any. See [`read_source()`].
+    pub stdin_contents: Option<String>,
+    /// Whether the code was checked from the Git index. See [`read_source()`].
+    pub staged: bool,
+}
+
+/// Read the source code of the file where a diagnostic was found.
+///
+/// With `--stdin-filename`, the file name is only used to resolve settings
+/// and the code comes from stdin, so `stdin_contents` is used instead of the
+/// file on disk (which may not exist or may have different contents).
+///
+/// With `--respect-staged`, the code comes from the Git index, which differs
+/// from the file on disk if only some of its changes are staged.
+fn read_source(
+    path: &std::path::Path,
+    stdin_contents: Option<&str>,
+    staged: bool,
+) -> std::io::Result<String> {
+    if let Some(contents) = stdin_contents {
+        return Ok(contents.to_string());
+    }
+    if staged && let Ok(Some(file)) = StagedFile::read(path) {
+        return Ok(file.content);
+    }
+    fs::read_to_string(path)
+}
+
+/// Width of the terminal, or `None` if stdout is not a terminal (e.g. when the
+/// output is redirected to a file).
+pub fn terminal_width() -> Option<usize> {
+    terminal_size::terminal_size().map(|(terminal_size::Width(width), _)| width as usize)
+}
 
 impl Emitter for FullEmitter {
     fn emit<W: Write>(
@@ -598,11 +1310,14 @@ impl Emitter for FullEmitter {
         let mut writer = BufWriter::new(writer);
         // Use plain renderer when NO_COLOR is set or in snapshots
         let use_colors = std::env::var("NO_COLOR").is_err();
-        let renderer = if use_colors {
+        let mut renderer = if use_colors {
             Renderer::styled()
         } else {
             Renderer::plain()
         };
+        if let Some(max_width) = self.max_width {
+            renderer = renderer.term_width(max_width);
+        }
 
         // First, print all parsing errors
         if !errors.is_empty() {
@@ -634,7 +1349,11 @@ impl Emitter for FullEmitter {
         // Pre-load all files into cache
         for diagnostic in diagnostics {
             if !file_cache.contains_key(diagnostic.filename.as_path()) {
-                match fs::read_to_string(&diagnostic.filename) {
+                match read_source(
+                    &diagnostic.filename,
+                    self.stdin_contents.as_deref(),
+                    self.staged,
+                ) {
                     Ok(content) => {
                         file_cache.insert(diagnostic.filename.as_path(), content);
                     }
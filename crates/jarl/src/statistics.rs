@@ -2,15 +2,13 @@ use colored::Colorize;
 use jarl_core::diagnostic::Diagnostic;
 use std::{collections::HashMap, path::PathBuf};
 
-use crate::status::ExitStatus;
-
 pub fn print_statistics(
     diagnostics: &[&Diagnostic],
     parent_config_path: Option<PathBuf>,
-) -> anyhow::Result<ExitStatus> {
+) -> anyhow::Result<()> {
     if diagnostics.is_empty() {
         println!("All checks passed!");
-        return Ok(ExitStatus::Success);
+        return Ok(());
     }
 
     // Hashmap with rule name as key, and (number of occurrences, has_fix, has_unsafe_fix) as
@@ -57,5 +55,5 @@ pub fn print_statistics(
         println!("\nUsed '{}'", config_path.display());
     }
 
-    Ok(ExitStatus::Failure)
+    Ok(())
 }
@@ -0,0 +1,61 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_completions() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+
+    for (shell, expected) in [
+        ("bash", "_jarl()"),
+        ("zsh", "#compdef jarl"),
+        ("fish", "complete -c jarl"),
+        ("powershell", "Register-ArgumentCompleter"),
+    ] {
+        let output = case.command().arg("completions").arg(shell).run();
+        assert!(output.status.success(), "{shell}: {}", output.stderr);
+        assert!(output.stdout.contains(expected));
+        // Subcommands and their options are completed.
+        assert!(output.stdout.contains("check"));
+        assert!(output.stdout.contains("--select"));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_completions_unknown_shell() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+
+    insta::assert_snapshot!(
+        case.command()
+            .arg("completions")
+            .arg("tcsh")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'tcsh' for '<SHELL>'
+      [possible values: bash, elvish, fish, powershell, zsh]
+
+    For more information, try '--help'.
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_man() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+
+    let output = case.command().arg("man").run();
+    assert!(output.status.success(), "{}", output.stderr);
+    assert!(output.stdout.contains(".TH jarl 1"));
+    assert!(output.stdout.contains("Just Another R Linter"));
+
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use crate::helpers::{CliTest, CommandExt};
+
+/// The rules of a file account for `[[lint.overrides]]`, `[lint.severity]`,
+/// and `unfixable`.
+#[test]
+fn test_config_file() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("tests/test-foo.R", "any(is.na(x))\n"),
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_is_na", "any_duplicated", "class_equals"]
+unfixable = ["class_equals"]
+
+[lint.severity]
+any_is_na = "error"
+
+[[lint.overrides]]
+files = ["tests/"]
+ignore = ["any_duplicated"]
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("config")
+            .arg("tests/test-foo.R")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @r#"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    path = "tests/test-foo.R"
+    config-file = "[TEMP_DIR]/jarl.toml"
+    kind = "script"
+    check-roxygen = true
+    fix-roxygen = false
+
+    [rules.any_is_na]
+    severity = "error"
+    fix = "safe"
+
+    [rules.class_equals]
+    severity = "warning"
+    fix = "disabled"
+
+    ----- stderr -----
+    "#
+    );
+
+    Ok(())
+}
+
+/// `[lint.severity-by-kind]` takes precedence over `[lint.severity]`.
+#[test]
+fn test_config_severity_by_kind() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("DESCRIPTION", "Package: mypkg\n"),
+        ("R/foo.R", "any(is.na(x))\n"),
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_is_na"]
+
+[lint.severity]
+any_is_na = "info"
+
+[lint.severity-by-kind]
+package = "error"
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("config")
+            .arg("R/foo.R")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @r#"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    path = "R/foo.R"
+    config-file = "[TEMP_DIR]/jarl.toml"
+    kind = "package"
+    check-roxygen = true
+    fix-roxygen = false
+
+    [rules.any_is_na]
+    severity = "error"
+    fix = "safe"
+
+    ----- stderr -----
+    "#
+    );
+
+    Ok(())
+}
+
+/// Without a `jarl.toml`, the rules only come from the command line.
+#[test]
+fn test_config_directory_json() -> anyhow::Result<()> {
+    let case = CliTest::with_files([("test.R", "any(is.na(x))\n")])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("config")
+            .arg("--select")
+            .arg("any_is_na,all_equal")
+            .arg("--min-r-version")
+            .arg("4.3.0")
+            .arg("--output-format")
+            .arg("json")
+            .run()
+            .normalize_os_executable_name(),
+        @r#"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "path": ".",
+      "minimum-r-version": "4.3.0",
+      "check-roxygen": true,
+      "fix-roxygen": false,
+      "rules": {
+        "all_equal": {
+          "severity": "warning",
+          "fix": "unsafe"
+        },
+        "any_is_na": {
+          "severity": "warning",
+          "fix": "safe"
+        }
+      }
+    }
+
+    ----- stderr -----
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_config_path_does_not_exist() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("config")
+            .arg("foo.R")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Path does not exist: foo.R
+    "
+    );
+
+    Ok(())
+}
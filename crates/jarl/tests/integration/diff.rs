@@ -0,0 +1,96 @@
+use crate::helpers::{CliTest, CommandExt};
+
+/// `--diff` prints the fixes without modifying the files, and doesn't require
+/// a VCS.
+#[test]
+fn test_diff() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))\nx <- 1\n"),
+        ("test2.R", "any(duplicated(x))\n"),
+        ("test3.R", "x <- 1\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--diff")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    --- a/test.R
+    +++ b/test.R
+    @@ -1,2 +1,2 @@
+    -any(is.na(x))
+    +anyNA(x)
+     x <- 1
+    --- a/test2.R
+    +++ b/test2.R
+    @@ -1 +1 @@
+    -any(duplicated(x))
+    +anyDuplicated(x) > 0
+
+    ----- stderr -----
+    "
+    );
+
+    assert_eq!(case.read_file("test.R")?, "any(is.na(x))\nx <- 1\n");
+    assert_eq!(case.read_file("test2.R")?, "any(duplicated(x))\n");
+
+    Ok(())
+}
+
+/// Unsafe fixes are only included with `--unsafe-fixes`.
+#[test]
+fn test_diff_unsafe_fixes() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "!all.equal(x, y)\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--diff")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--diff")
+            .arg("--unsafe-fixes")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    --- a/test.R
+    +++ b/test.R
+    @@ -1 +1 @@
+    -!all.equal(x, y)
+    +!isTRUE(all.equal(x, y))
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
@@ -112,3 +112,113 @@ fn test_jarl_with_tabs() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+// A file reached with several paths is only reported once.
+#[test]
+fn test_jarl_file_passed_twice() -> anyhow::Result<()> {
+    let case = CliTest::with_file("R/foo.R", "any(is.na(x))\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("R")
+            .arg("R/foo.R")
+            .arg("./R/foo.R")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    warning: any_is_na
+     --> R/foo.R:1:1
+      |
+    1 | any(is.na(x))
+      | ------------- `any(is.na(...))` is inefficient.
+      |
+      = help: Use `anyNA(...)` instead.
+
+
+    ── Summary ──────────────────────────────────────
+    Found 1 error.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+// On case-insensitive file systems, paths that only differ by their casing
+// point to the same file, which is only reported once.
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+#[test]
+fn test_jarl_file_passed_with_different_casing() -> anyhow::Result<()> {
+    let case = CliTest::with_file("R/foo.R", "any(is.na(x))\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("R/foo.R")
+            .arg("r/FOO.R")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=R/foo.R,line=1,col=1::R/foo.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+// A file in a symlinked `R/` folder belongs to the package of the target of
+// the link.
+#[cfg(unix)]
+#[test]
+fn test_jarl_package_root_through_symlink() -> anyhow::Result<()> {
+    use std::os::unix::fs::symlink;
+
+    let case = CliTest::with_files([
+        ("pkg/DESCRIPTION", "Package: pkg\n"),
+        ("pkg/R/foo.R", "f <- function(dir) {\n  setwd(dir)\n}\n"),
+    ])?;
+    std::fs::create_dir(case.root().join("project"))?;
+    symlink(case.root().join("pkg/R"), case.root().join("project/R"))?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("project/R/foo.R")
+            .arg("--select")
+            .arg("options_reset")
+            .arg("--preview")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (options_reset),file=project/R/foo.R,line=2,col=3::project/R/foo.R:2:3 [options_reset] `setwd()` changes the working directory without restoring it with `on.exit()`. Store the previous value with `old <- setwd(...)` and restore it with `on.exit(setwd(old), add = TRUE)`.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use crate::helpers::{CliTest, CommandExt};
+use crate::helpers::{CliTest, CommandExt, git_init};
 
 /// Excluded files should still contribute symbol usages for cross-file
 /// analysis (e.g. unused_function). If `foo.R` calls `f()` and `foo2.R`
@@ -762,3 +762,388 @@ fn test_must_start_with_generated_by_to_be_ignored() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// `extend-exclude` is added to the `exclude` of the extended config, which
+/// would be replaced by `exclude`.
+#[test]
+fn test_extend_exclude_toml() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("shared.toml", "[lint]\nexclude = [\"a.R\"]\n"),
+        (
+            "jarl.toml",
+            "extends = \"shared.toml\"\n\n[lint]\nextend-exclude = [\"b.R\"]\n",
+        ),
+        ("a.R", "any(is.na(x))\n"),
+        ("b.R", "any(is.na(x))\n"),
+        ("c.R", "any(is.na(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=c.R,line=1,col=1::c.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+/// The patterns of `--extend-exclude` are resolved relative to the directory
+/// of `jarl.toml`, while the ones of `--exclude` are resolved relative to the
+/// current directory.
+#[test]
+fn test_extend_exclude_cli() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("pkg/jarl.toml", "[lint]\n"),
+        ("pkg/a.R", "any(is.na(x))\n"),
+        ("pkg/R/a.R", "any(is.na(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("pkg")
+            .arg("--exclude=/a.R")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=pkg/R/a.R,line=1,col=1::pkg/R/a.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::warning title=Jarl (any_is_na),file=pkg/a.R,line=1,col=1::pkg/a.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("pkg")
+            .arg("--extend-exclude=/a.R")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=pkg/R/a.R,line=1,col=1::pkg/R/a.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_default_exclude_packrat() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("packrat/init.R", "any(is.na(x))\n"),
+        ("R/a.R", "any(is.na(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=R/a.R,line=1,col=1::R/a.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_respect_gitignore() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (".gitignore", "ignored.R\n"),
+        ("ignored.R", "any(is.na(x))\n"),
+        ("kept.R", "any(is.na(x))\n"),
+    ])?;
+    git_init(case.root())?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=kept.R,line=1,col=1::kept.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    case.write_file("jarl.toml", "[lint]\nrespect-gitignore = false\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=ignored.R,line=1,col=1::ignored.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::warning title=Jarl (any_is_na),file=kept.R,line=1,col=1::kept.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_respect_rbuildignore() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("DESCRIPTION", "Package: foo\nVersion: 0.1.0\n"),
+        (".Rbuildignore", "^data-raw$\n^.*\\.Rproj$\n"),
+        ("R/a.R", "any(is.na(x))\n"),
+        ("data-raw/clean.R", "any(is.na(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=R/a.R,line=1,col=1::R/a.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::warning title=Jarl (any_is_na),file=data-raw/clean.R,line=1,col=1::data-raw/clean.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    case.write_file("jarl.toml", "[lint]\nrespect-rbuildignore = true\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=R/a.R,line=1,col=1::R/a.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_follow_symlinks() -> anyhow::Result<()> {
+    use std::os::unix::fs::symlink;
+
+    let case = CliTest::with_files([
+        ("project/main.R", "any(is.na(x))\n"),
+        ("cache/lib/vendored.R", "any(is.na(x))\n"),
+    ])?;
+    symlink(case.root().join("cache"), case.root().join("project/cache"))?;
+    symlink(
+        case.root().join("project"),
+        case.root().join("project/cycle"),
+    )?;
+    symlink(
+        case.root().join("cache/lib/vendored.R"),
+        case.root().join("project/linked.R"),
+    )?;
+
+    // Symlinks are skipped by default.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("project")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=project/main.R,line=1,col=1::project/main.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    // With `--follow-symlinks`, the cycle is skipped and `project/linked.R`,
+    // which points to a file that is already checked, is only reported once.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("project")
+            .arg("--follow-symlinks")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=project/cache/lib/vendored.R,line=1,col=1::project/cache/lib/vendored.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::warning title=Jarl (any_is_na),file=project/main.R,line=1,col=1::project/main.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_include_vignettes() -> anyhow::Result<()> {
+    let chunk = "```{r}\nany(is.na(x))\n```\n";
+    let case = CliTest::with_files([
+        ("DESCRIPTION", "Package: mypkg\n"),
+        ("R/foo.R", "any(is.na(x))\n"),
+        ("README.Rmd", chunk),
+        ("notes.Rmd", chunk),
+        ("vignettes/intro.Rmd", chunk),
+        ("inst/doc/report.qmd", chunk),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=R/foo.R,line=1,col=1::R/foo.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::warning title=Jarl (any_is_na),file=README.Rmd,line=2,col=1::README.Rmd:2:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::warning title=Jarl (any_is_na),file=inst/doc/report.qmd,line=2,col=1::inst/doc/report.qmd:2:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::warning title=Jarl (any_is_na),file=notes.Rmd,line=2,col=1::notes.Rmd:2:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::warning title=Jarl (any_is_na),file=vignettes/intro.Rmd,line=2,col=1::vignettes/intro.Rmd:2:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    case.write_file("jarl.toml", "[lint]\ninclude-vignettes = false\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=R/foo.R,line=1,col=1::R/foo.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::warning title=Jarl (any_is_na),file=notes.Rmd,line=2,col=1::notes.Rmd:2:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    // Documents passed directly are still checked.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("vignettes/intro.Rmd")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=vignettes/intro.Rmd,line=2,col=1::vignettes/intro.Rmd:2:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
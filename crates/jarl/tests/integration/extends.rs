@@ -0,0 +1,166 @@
+use crate::helpers::{CliTest, CommandExt};
+
+/// The options of the child config are merged with the ones of the config it
+/// extends, the child taking precedence.
+#[test]
+fn test_extends_parent_config() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "project/test.R",
+            "any(is.na(x))\nany(duplicated(x))\nx <- 'a'\n",
+        ),
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_is_na", "any_duplicated"]
+ignore = ["any_is_na"]
+
+[lint.severity]
+any_duplicated = "error"
+"#,
+        ),
+        (
+            "project/jarl.toml",
+            r#"
+extends = "../jarl.toml"
+
+[lint]
+extend-select = ["quotes"]
+ignore = []
+
+[lint.severity]
+quotes = "info"
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .current_dir(case.root().join("project"))
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=test.R,line=1,col=1::test.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::error title=Jarl (any_duplicated),file=test.R,line=2,col=1::test.R:2:1 [any_duplicated] `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+    ::notice title=Jarl (quotes),file=test.R,line=3,col=6::test.R:3:6 [quotes] Prefer double quotes for string delimiters.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+/// The built-in configurations use the rules of a profile, here `quotes` that
+/// is disabled by default.
+#[test]
+fn test_extends_builtin_config() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))\ny <- 'a'\n"),
+        (
+            "jarl.toml",
+            r#"
+extends = "jarl:strict"
+
+[lint]
+ignore = ["any_is_na"]
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (quotes),file=test.R,line=2,col=6::test.R:2:6 [quotes] Prefer double quotes for string delimiters.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_extends_unknown_builtin_config_errors() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))\n"),
+        ("jarl.toml", "extends = \"jarl:pedantic\"\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Failed to resolve `extends` in [TEMP_DIR]/jarl.toml:
+    Unknown configuration `jarl:pedantic`. Expected a path or one of: `jarl:minimal`, `jarl:recommended`, `jarl:strict`.
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_extends_circular_errors() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("project/test.R", "any(is.na(x))\n"),
+        ("jarl.toml", "extends = \"project/jarl.toml\"\n"),
+        ("project/jarl.toml", "extends = \"../jarl.toml\"\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .current_dir(case.root().join("project"))
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Failed to resolve `extends` in [TEMP_DIR]/jarl.toml:
+    Circular `extends` with [TEMP_DIR]/project/jarl.toml.
+    "
+    );
+
+    Ok(())
+}
@@ -0,0 +1,284 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_fix() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))\nany(is.na(y))\n"),
+        ("test2.R", "any(is.na(x))\nany(duplicated(x))\n"),
+        ("test3.R", "x <- 1\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("fix")
+            .arg(".")
+            .arg("--allow-no-vcs")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Fixed 4 violations in 2 files:
+
+    any_duplicated (1 fix)
+      test2.R: 1
+
+    any_is_na (3 fixes)
+      test.R: 2
+      test2.R: 1
+
+    ----- stderr -----
+    "
+    );
+
+    assert_eq!(case.read_file("test.R")?, "anyNA(x)\nanyNA(y)\n");
+    assert_eq!(
+        case.read_file("test2.R")?,
+        "anyNA(x)\nanyDuplicated(x) > 0\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_fix_select_and_dry_run() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "any(is.na(x))\nany(duplicated(x))\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("fix")
+            .arg(".")
+            .arg("--select")
+            .arg("any_is_na")
+            .arg("--dry-run")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    Would fix 1 violation in 1 file:
+
+    any_is_na (1 fix)
+      test.R: 1
+
+    ----- stderr -----
+    "
+    );
+
+    // `--dry-run` doesn't modify files and doesn't require a VCS.
+    assert_eq!(
+        case.read_file("test.R")?,
+        "any(is.na(x))\nany(duplicated(x))\n"
+    );
+
+    Ok(())
+}
+
+/// Unsafe fixes are only applied with `--unsafe`.
+#[test]
+fn test_fix_unsafe() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "!all.equal(x, y)\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("fix")
+            .arg(".")
+            .arg("--allow-no-vcs")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    No fixes to apply.
+
+    ----- stderr -----
+    "
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("fix")
+            .arg(".")
+            .arg("--allow-no-vcs")
+            .arg("--unsafe")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Fixed 1 violation in 1 file:
+
+    all_equal (1 fix)
+      test.R: 1
+
+    ----- stderr -----
+    "
+    );
+
+    assert_eq!(case.read_file("test.R")?, "!isTRUE(all.equal(x, y))\n");
+
+    Ok(())
+}
+
+#[test]
+fn test_fix_overlapping_fixes_in_one_run() -> anyhow::Result<()> {
+    // `redundant_equals` and `any_is_na` both have a fix on `any(is.na(x))`,
+    // they are applied one after the other in a single run.
+    let case = CliTest::with_file("test.R", "any(is.na(x)) == TRUE\nany(is.na(y))\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("fix")
+            .arg(".")
+            .arg("--allow-no-vcs")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Fixed 3 violations in 1 file:
+
+    any_is_na (2 fixes)
+      test.R: 2
+
+    redundant_equals (1 fix)
+      test.R: 1
+
+    ----- stderr -----
+    "
+    );
+
+    assert_eq!(case.read_file("test.R")?, "anyNA(x)\nanyNA(y)\n");
+
+    let case = CliTest::with_file("test.R", "any(is.na(x)) == TRUE\n")?;
+    let output = case
+        .command()
+        .arg("check")
+        .arg(".")
+        .arg("--fix")
+        .arg("--allow-no-vcs")
+        .run();
+    assert!(output.status.success());
+    assert_eq!(case.read_file("test.R")?, "anyNA(x)\n");
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_fix_writes_only_modified_files() -> anyhow::Result<()> {
+    use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+    let case = CliTest::with_files([("fixed.R", "any(is.na(x))\n"), ("unchanged.R", "x <- 1\n")])?;
+    let fixed_path = case.root().join("fixed.R");
+    let unchanged_path = case.root().join("unchanged.R");
+    std::fs::set_permissions(&fixed_path, std::fs::Permissions::from_mode(0o755))?;
+    let unchanged_before = std::fs::metadata(&unchanged_path)?;
+
+    let output = case
+        .command()
+        .arg("check")
+        .arg(".")
+        .arg("--fix")
+        .arg("--allow-no-vcs")
+        .run();
+    assert!(output.status.success());
+
+    // The fixed file is replaced but keeps its permissions, and no temporary
+    // file is left behind.
+    assert_eq!(case.read_file("fixed.R")?, "anyNA(x)\n");
+    let mode = std::fs::metadata(&fixed_path)?.permissions().mode();
+    assert_eq!(mode & 0o777, 0o755);
+    let mut entries: Vec<String> = std::fs::read_dir(case.root())?
+        .map(|e| Ok(e?.file_name().to_string_lossy().to_string()))
+        .collect::<std::io::Result<_>>()?;
+    entries.sort();
+    assert_eq!(entries, vec!["fixed.R", "unchanged.R"]);
+
+    // Files without fixes are not written at all.
+    let unchanged_after = std::fs::metadata(&unchanged_path)?;
+    assert_eq!(unchanged_before.ino(), unchanged_after.ino());
+    assert_eq!(unchanged_before.modified()?, unchanged_after.modified()?);
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_fix_post_command() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            "[fix]\npost-command = \"touch {file}.formatted\"\n",
+        ),
+        ("fixed.R", "any(is.na(x))\n"),
+        ("unchanged.R", "x <- 1\n"),
+    ])?;
+
+    for command in ["fix", "check"] {
+        let mut cmd = case.command();
+        cmd.arg(command).arg(".").arg("--allow-no-vcs");
+        if command == "check" {
+            case.write_file("fixed.R", "any(is.na(x))\n")?;
+            std::fs::remove_file(case.root().join("fixed.R.formatted"))?;
+            cmd.arg("--fix");
+        }
+        let output = cmd.run();
+        assert!(output.status.success(), "{}", output.stderr);
+
+        // The command only runs on files that were modified.
+        assert_eq!(case.read_file("fixed.R")?, "anyNA(x)\n");
+        assert!(case.root().join("fixed.R.formatted").exists());
+        assert!(!case.root().join("unchanged.R.formatted").exists());
+    }
+
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_fix_post_command_error() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("jarl.toml", "[fix]\npost-command = \"false\"\n"),
+        ("test.R", "any(is.na(x))\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("fix")
+            .arg(".")
+            .arg("--allow-no-vcs")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: `false` failed on test.R after the fixes were written (exit status: 1).
+    "
+    );
+
+    // The fixes are kept even if the command fails.
+    assert_eq!(case.read_file("test.R")?, "anyNA(x)\n");
+
+    Ok(())
+}
@@ -18,10 +18,18 @@ fn test_help() -> anyhow::Result<()> {
     Usage: jarl [OPTIONS] <COMMAND>
 
     Commands:
-      check   Check a set of files or directories
-      rule    Print the documentation of a rule
-      server  Start a language server
-      help    Print this message or the help of the given subcommand(s)
+      check          Check a set of files or directories
+      fix            Apply the automatic fixes to a set of files or directories
+      config         Print the resolved configuration for a file or directory
+      tui            Browse the violations in an interactive terminal interface
+      init           Create a starter `jarl.toml` for a project
+      migrate-lintr  Convert a `.lintr` file to a `jarl.toml`
+      rule           Print the documentation of a rule
+      server         Start a language server
+      completions    Generate shell completions
+      man            Generate a manpage
+      self           Manage the Jarl executable
+      help           Print this message or the help of the given subcommand(s)
 
     Options:
       -h, --help     Print help
@@ -50,10 +58,18 @@ fn test_help() -> anyhow::Result<()> {
     Usage: jarl [OPTIONS] <COMMAND>
 
     Commands:
-      check   Check a set of files or directories
-      rule    Print the documentation of a rule
-      server  Start a language server
-      help    Print this message or the help of the given subcommand(s)
+      check          Check a set of files or directories
+      fix            Apply the automatic fixes to a set of files or directories
+      config         Print the resolved configuration for a file or directory
+      tui            Browse the violations in an interactive terminal interface
+      init           Create a starter `jarl.toml` for a project
+      migrate-lintr  Convert a `.lintr` file to a `jarl.toml`
+      rule           Print the documentation of a rule
+      server         Start a language server
+      completions    Generate shell completions
+      man            Generate a manpage
+      self           Manage the Jarl executable
+      help           Print this message or the help of the given subcommand(s)
 
     Options:
       -h, --help     Print help
@@ -82,10 +98,18 @@ fn test_help() -> anyhow::Result<()> {
     Usage: jarl [OPTIONS] <COMMAND>
 
     Commands:
-      check   Check a set of files or directories
-      rule    Print the documentation of a rule
-      server  Start a language server
-      help    Print this message or the help of the given subcommand(s)
+      check          Check a set of files or directories
+      fix            Apply the automatic fixes to a set of files or directories
+      config         Print the resolved configuration for a file or directory
+      tui            Browse the violations in an interactive terminal interface
+      init           Create a starter `jarl.toml` for a project
+      migrate-lintr  Convert a `.lintr` file to a `jarl.toml`
+      rule           Print the documentation of a rule
+      server         Start a language server
+      completions    Generate shell completions
+      man            Generate a manpage
+      self           Manage the Jarl executable
+      help           Print this message or the help of the given subcommand(s)
 
     Options:
       -h, --help     Print help
@@ -118,19 +142,28 @@ fn test_help_check() -> anyhow::Result<()> {
     ----- stdout -----
     Check a set of files or directories
 
-    Usage: jarl check [OPTIONS] <FILES>...
+    Usage: jarl check [OPTIONS] [FILES]...
 
     Arguments:
-      <FILES>...
+      [FILES]...
               List of files or directories to check or fix lints, for example `jarl check .`.
 
     File selection:
           --exclude=<FILES>
               List of file patterns to exclude from linting, separated by a comma (no spaces). Must be passed with an equals sign, e.g. `--exclude=R/*.R`, so the shell does not expand glob patterns.
 
+          --extend-exclude=<FILES>
+              Like `--exclude`, but the patterns are added to `exclude` in `jarl.toml` and resolved relative to its directory.
+
           --no-default-exclude
               Do not apply the default set of file patterns that should be excluded.
 
+          --follow-symlinks
+              Follow symbolic links to files and directories. Symbolic links that form a cycle are skipped.
+
+          --stdin-filename <FILENAME>
+              Read the code to check from stdin, passed as `-`, and report it as the content of this file, e.g. `jarl check --stdin-filename R/foo.R -`. The settings are found from the directory of this file, which doesn't need to exist.
+
     Rule selection:
       -s, --select <RULES>
               Names of rules to include, separated by a comma (no spaces). This also accepts names of groups of rules, such as "PERF".
@@ -147,22 +180,34 @@ fn test_help_check() -> anyhow::Result<()> {
               
               [default: ""]
 
+          --profile <PROFILE>
+              Set of rules to use instead of the default one: `minimal`, `recommended`, or `strict`. Same as `profile` in `jarl.toml`.
+
+          --preview
+              Enable the rules in preview, which are not used otherwise. Same as `preview = true` in `jarl.toml`.
+
     Other options:
       -f, --fix
               Automatically fix issues detected by the linter.
 
-      -u, --unsafe-fixes
-              Include fixes that may not retain the original intent of the  code.
+      -u, --unsafe-fixes[=<RULES>]
+              Include fixes that may not retain the original intent of the  code. Pass names of rules separated by a comma (no spaces) to only include their fixes, e.g. `--unsafe-fixes=all_equal,download_file`.
 
           --fix-only
               Apply fixes to resolve lint violations, but don't report on leftover violations. Implies `--fix`.
 
+          --diff
+              Print the fixes as a unified diff instead of applying them. Files are not modified. Implies `--fix`.
+
           --allow-dirty
               Apply fixes even if the Git branch is not clean, meaning that there are uncommitted files.
 
           --allow-no-vcs
               Apply fixes even if there is no version control system.
 
+          --respect-staged
+              Apply the fixes to the content staged in Git, e.g. in a pre-commit hook, and stage the result. Unstaged changes are kept in the working tree. Implies `--fix`.
+
       -w, --with-timing
               Show the time taken by the function.
 
@@ -173,20 +218,42 @@ fn test_help_check() -> anyhow::Result<()> {
               Output serialization format for violations.
 
               Possible values:
-              - full:    Print diagnostics with full context using annotated code snippets
-              - concise: Print diagnostics in a concise format, one per line
-              - github:  Print diagnostics as GitHub format
-              - json:    Print diagnostics as JSON
-              - sarif:   Print diagnostics as SARIF 2.1.0 JSON
+              - full:       Print diagnostics with full context using annotated code snippets
+              - concise:    Print diagnostics in a concise format, one per line
+              - grouped:    Print diagnostics grouped by file, with one header per file
+              - compact:    Print diagnostics as `path:line:col: rule message`, one per line
+              - checkstyle: Print diagnostics as Checkstyle XML
+              - github:     Print diagnostics as GitHub format
+              - json:       Print diagnostics as JSON
+              - junit:      Print diagnostics as JUnit XML
+              - prometheus: Print aggregate metrics in Prometheus text format
+              - rdjson:     Print diagnostics as Reviewdog Diagnostic Format JSON
+              - sarif:      Print diagnostics as SARIF 2.1.0 JSON
+              - teamcity:   Print diagnostics as TeamCity service messages
               
               [default: full]
 
+          --max-width <MAX_WIDTH>
+              Maximum width of the output. Long lines of code are truncated to fit in this width. Defaults to the width of the terminal.
+
+          --max-diagnostics <N>
+              Maximum number of violations to print with `--output-format full`, `concise`, or `grouped`. The summary still counts all violations.
+
+      -q, --quiet
+              Only print the summary with `--output-format full`, `concise`, or `grouped`, without the violations.
+
+          --explain <RULE>
+              Print the documentation of a rule instead of checking files, e.g. `jarl check --explain any_is_na`.
+
           --assignment <ASSIGNMENT>
               [DEPRECATED: use `[lint.assignment]` in jarl.toml] Assignment operator to use, can be either `<-` or `=`.
 
           --statistics
               Show counts for every rule with at least one violation.
 
+          --exit-nonzero-on <SEVERITIES>
+              Severities of violations that lead to a non-zero exit code, separated by a comma (no spaces), for example `--exit-nonzero-on error`. Defaults to all severities: `error,warning,info`.
+
           --add-jarl-ignore[=<REASON>]
               Automatically insert a `# jarl-ignore` comment to suppress all violations.
               The default reason can be customized with `--add-jarl-ignore="my_reason"`.
@@ -214,31 +281,43 @@ fn test_help_check() -> anyhow::Result<()> {
     ----- stdout -----
     Check a set of files or directories
 
-    Usage: jarl check [OPTIONS] <FILES>...
+    Usage: jarl check [OPTIONS] [FILES]...
 
     Arguments:
-      <FILES>...  List of files or directories to check or fix lints, for example `jarl check .`.
+      [FILES]...  List of files or directories to check or fix lints, for example `jarl check .`.
 
     File selection:
-          --exclude=<FILES>     List of file patterns to exclude from linting, separated by a comma (no spaces). Must be passed with an equals sign, e.g. `--exclude=R/*.R`, so the shell does not expand glob patterns.
-          --no-default-exclude  Do not apply the default set of file patterns that should be excluded.
+          --exclude=<FILES>            List of file patterns to exclude from linting, separated by a comma (no spaces). Must be passed with an equals sign, e.g. `--exclude=R/*.R`, so the shell does not expand glob patterns.
+          --extend-exclude=<FILES>     Like `--exclude`, but the patterns are added to `exclude` in `jarl.toml` and resolved relative to its directory.
+          --no-default-exclude         Do not apply the default set of file patterns that should be excluded.
+          --follow-symlinks            Follow symbolic links to files and directories. Symbolic links that form a cycle are skipped.
+          --stdin-filename <FILENAME>  Read the code to check from stdin, passed as `-`, and report it as the content of this file, e.g. `jarl check --stdin-filename R/foo.R -`. The settings are found from the directory of this file, which doesn't need to exist.
 
     Rule selection:
       -s, --select <RULES>         Names of rules to include, separated by a comma (no spaces). This also accepts names of groups of rules, such as "PERF". [default: ""]
       -e, --extend-select <RULES>  Like `--select` but adds additional rules in addition to those already specified. [default: ""]
       -i, --ignore <RULES>         Names of rules to exclude, separated by a comma (no spaces). This also accepts names of groups of rules, such as "PERF". [default: ""]
+          --profile <PROFILE>      Set of rules to use instead of the default one: `minimal`, `recommended`, or `strict`. Same as `profile` in `jarl.toml`.
+          --preview                Enable the rules in preview, which are not used otherwise. Same as `preview = true` in `jarl.toml`.
 
     Other options:
       -f, --fix                            Automatically fix issues detected by the linter.
-      -u, --unsafe-fixes                   Include fixes that may not retain the original intent of the  code.
+      -u, --unsafe-fixes[=<RULES>]         Include fixes that may not retain the original intent of the  code. Pass names of rules separated by a comma (no spaces) to only include their fixes, e.g. `--unsafe-fixes=all_equal,download_file`.
           --fix-only                       Apply fixes to resolve lint violations, but don't report on leftover violations. Implies `--fix`.
+          --diff                           Print the fixes as a unified diff instead of applying them. Files are not modified. Implies `--fix`.
           --allow-dirty                    Apply fixes even if the Git branch is not clean, meaning that there are uncommitted files.
           --allow-no-vcs                   Apply fixes even if there is no version control system.
+          --respect-staged                 Apply the fixes to the content staged in Git, e.g. in a pre-commit hook, and stage the result. Unstaged changes are kept in the working tree. Implies `--fix`.
       -w, --with-timing                    Show the time taken by the function.
       -m, --min-r-version <MIN_R_VERSION>  The mimimum R version to be used by the linter. Some rules only work starting from a specific version.
-          --output-format <OUTPUT_FORMAT>  Output serialization format for violations. [default: full] [possible values: full, concise, github, json, sarif]
+          --output-format <OUTPUT_FORMAT>  Output serialization format for violations. [default: full] [possible values: full, concise, grouped, compact, checkstyle, github, json, junit, prometheus, rdjson, sarif, teamcity]
+          --max-width <MAX_WIDTH>          Maximum width of the output. Long lines of code are truncated to fit in this width. Defaults to the width of the terminal.
+          --max-diagnostics <N>            Maximum number of violations to print with `--output-format full`, `concise`, or `grouped`. The summary still counts all violations.
+      -q, --quiet                          Only print the summary with `--output-format full`, `concise`, or `grouped`, without the violations.
+          --explain <RULE>                 Print the documentation of a rule instead of checking files, e.g. `jarl check --explain any_is_na`.
           --assignment <ASSIGNMENT>        [DEPRECATED: use `[lint.assignment]` in jarl.toml] Assignment operator to use, can be either `<-` or `=`.
           --statistics                     Show counts for every rule with at least one violation.
+          --exit-nonzero-on <SEVERITIES>   Severities of violations that lead to a non-zero exit code, separated by a comma (no spaces), for example `--exit-nonzero-on error`. Defaults to all severities: `error,warning,info`.
           --add-jarl-ignore[=<REASON>]     Automatically insert a `# jarl-ignore` comment to suppress all violations.
                                            The default reason can be customized with `--add-jarl-ignore="my_reason"`.
       -h, --help                           Print help (see a summary with '-h')
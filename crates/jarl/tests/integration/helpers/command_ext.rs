@@ -1,6 +1,8 @@
 use std::fmt::Display;
+use std::io::Write;
 use std::process::Command;
 use std::process::ExitStatus;
+use std::process::Stdio;
 
 pub trait CommandExt {
     /// Executes the command as a child process, waiting for it to finish and collecting all of its output.
@@ -9,6 +11,10 @@ pub trait CommandExt {
     ///
     /// The [Output] has a suitable [Display] method for capturing with insta
     fn run(&mut self) -> Output;
+
+    /// Like [CommandExt::run], but writes `input` to the stdin of the child
+    /// process
+    fn run_with_stdin(&mut self, input: &str) -> Output;
 }
 
 /// Like [std::process::Output], but augmented with `arguments` and a few extra methods
@@ -59,20 +65,40 @@ impl Output {
 impl CommandExt for Command {
     fn run(&mut self) -> Output {
         let output = self.output().unwrap();
+        to_output(self, output)
+    }
+
+    fn run_with_stdin(&mut self, input: &str) -> Output {
+        let mut child = self
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(input.as_bytes())
+            .unwrap();
+        let output = child.wait_with_output().unwrap();
+        to_output(self, output)
+    }
+}
 
-        // Go ahead and turn these into `String`
-        let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
-        let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+fn to_output(command: &Command, output: std::process::Output) -> Output {
+    // Go ahead and turn these into `String`
+    let stdout = String::from_utf8_lossy(&output.stdout).into_owned();
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
 
-        let arguments: Vec<String> = self
-            .get_args()
-            .map(|x| x.to_string_lossy().into_owned())
-            .collect();
+    let arguments: Vec<String> = command
+        .get_args()
+        .map(|x| x.to_string_lossy().into_owned())
+        .collect();
 
-        let arguments = arguments.join(" ");
+    let arguments = arguments.join(" ");
 
-        Output { status: output.status, stdout, stderr, arguments }
-    }
+    Output { status: output.status, stdout, stderr, arguments }
 }
 
 impl Display for Output {
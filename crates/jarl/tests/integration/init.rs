@@ -0,0 +1,160 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_init_package() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "DESCRIPTION",
+            "Package: mypkg\nVersion: 0.1.0\nImports:\n    dplyr (>= 1.0.0),\n    rlang\n",
+        ),
+        ("R/foo.R", "foo <- function() 1\n"),
+        ("tests/testthat/test-foo.R", "test_that('foo', {})\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("init")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Created ./jarl.toml for an R package.
+
+    ----- stderr -----
+    "
+    );
+
+    insta::assert_snapshot!(case.read_file("jarl.toml")?, @r#"
+    # Configuration of Jarl for an R package. All the options are described in
+    # https://jarl.etiennebacher.com/reference/config-file.
+
+    [lint]
+    # Use the rules enabled by default, as well as:
+    # - the rules for testthat, since there is a `tests/testthat` folder;
+    # - the rules for dplyr, since it is used;
+    extend-select = ["TESTTHAT", "DPLYR"]
+    "#);
+
+    Ok(())
+}
+
+#[test]
+fn test_init_shiny_app() -> anyhow::Result<()> {
+    let case = CliTest::with_files([(
+        "app.R",
+        "library(shiny)\nlibrary(dplyr)\nshinyApp(ui, server)\n",
+    )])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("init")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Created ./jarl.toml for a Shiny app.
+
+    ----- stderr -----
+    "
+    );
+
+    let config = case.read_file("jarl.toml")?;
+    assert!(config.contains("extend-select = [\"DPLYR\"]"));
+
+    Ok(())
+}
+
+#[test]
+fn test_init_project() -> anyhow::Result<()> {
+    let case = CliTest::with_files([("analysis.R", "x <- 1\n")])?;
+
+    case.command().arg("init").run();
+
+    insta::assert_snapshot!(case.read_file("jarl.toml")?, @r#"
+    # Configuration of Jarl for an R project. All the options are described in
+    # https://jarl.etiennebacher.com/reference/config-file.
+
+    [lint]
+    # Use the rules enabled by default. Rules that are disabled by default
+    # can be added with `extend-select`, e.g. `extend-select = ["TESTTHAT"]`.
+    extend-select = []
+    "#);
+
+    // The generated file is a valid configuration.
+    let output = case.command().arg("check").arg(".").run();
+    assert!(output.status.success());
+
+    Ok(())
+}
+
+#[test]
+fn test_init_github_action() -> anyhow::Result<()> {
+    let case = CliTest::with_files([("analysis.R", "x <- 1\n")])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("init")
+            .arg("--github-action")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Created ./jarl.toml for an R project.
+    Created ./.github/workflows/jarl.yml.
+
+    ----- stderr -----
+    "
+    );
+
+    let workflow = case.read_file(".github/workflows/jarl.yml")?;
+    assert!(workflow.contains("uses: etiennebacher/setup-jarl@"));
+
+    Ok(())
+}
+
+#[test]
+fn test_init_does_not_overwrite() -> anyhow::Result<()> {
+    let case = CliTest::with_files([("jarl.toml", "[lint]\nselect = [\"any_is_na\"]\n")])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("init")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: ./jarl.toml already exists. Use `--force` to overwrite it.
+    "
+    );
+    assert_eq!(
+        case.read_file("jarl.toml")?,
+        "[lint]\nselect = [\"any_is_na\"]\n"
+    );
+
+    case.command().arg("init").arg("--force").run();
+    assert!(
+        case.read_file("jarl.toml")?
+            .starts_with("# Configuration of Jarl")
+    );
+
+    Ok(())
+}
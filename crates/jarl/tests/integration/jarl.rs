@@ -18,31 +18,43 @@ fn test_must_pass_path() -> anyhow::Result<()> {
     ----- stderr -----
     Check a set of files or directories
 
-    Usage: jarl check [OPTIONS] <FILES>...
+    Usage: jarl check [OPTIONS] [FILES]...
 
     Arguments:
-      <FILES>...  List of files or directories to check or fix lints, for example `jarl check .`.
+      [FILES]...  List of files or directories to check or fix lints, for example `jarl check .`.
 
     File selection:
-          --exclude=<FILES>     List of file patterns to exclude from linting, separated by a comma (no spaces). Must be passed with an equals sign, e.g. `--exclude=R/*.R`, so the shell does not expand glob patterns.
-          --no-default-exclude  Do not apply the default set of file patterns that should be excluded.
+          --exclude=<FILES>            List of file patterns to exclude from linting, separated by a comma (no spaces). Must be passed with an equals sign, e.g. `--exclude=R/*.R`, so the shell does not expand glob patterns.
+          --extend-exclude=<FILES>     Like `--exclude`, but the patterns are added to `exclude` in `jarl.toml` and resolved relative to its directory.
+          --no-default-exclude         Do not apply the default set of file patterns that should be excluded.
+          --follow-symlinks            Follow symbolic links to files and directories. Symbolic links that form a cycle are skipped.
+          --stdin-filename <FILENAME>  Read the code to check from stdin, passed as `-`, and report it as the content of this file, e.g. `jarl check --stdin-filename R/foo.R -`. The settings are found from the directory of this file, which doesn't need to exist.
 
     Rule selection:
       -s, --select <RULES>         Names of rules to include, separated by a comma (no spaces). This also accepts names of groups of rules, such as "PERF". [default: ""]
       -e, --extend-select <RULES>  Like `--select` but adds additional rules in addition to those already specified. [default: ""]
       -i, --ignore <RULES>         Names of rules to exclude, separated by a comma (no spaces). This also accepts names of groups of rules, such as "PERF". [default: ""]
+          --profile <PROFILE>      Set of rules to use instead of the default one: `minimal`, `recommended`, or `strict`. Same as `profile` in `jarl.toml`.
+          --preview                Enable the rules in preview, which are not used otherwise. Same as `preview = true` in `jarl.toml`.
 
     Other options:
       -f, --fix                            Automatically fix issues detected by the linter.
-      -u, --unsafe-fixes                   Include fixes that may not retain the original intent of the  code.
+      -u, --unsafe-fixes[=<RULES>]         Include fixes that may not retain the original intent of the  code. Pass names of rules separated by a comma (no spaces) to only include their fixes, e.g. `--unsafe-fixes=all_equal,download_file`.
           --fix-only                       Apply fixes to resolve lint violations, but don't report on leftover violations. Implies `--fix`.
+          --diff                           Print the fixes as a unified diff instead of applying them. Files are not modified. Implies `--fix`.
           --allow-dirty                    Apply fixes even if the Git branch is not clean, meaning that there are uncommitted files.
           --allow-no-vcs                   Apply fixes even if there is no version control system.
+          --respect-staged                 Apply the fixes to the content staged in Git, e.g. in a pre-commit hook, and stage the result. Unstaged changes are kept in the working tree. Implies `--fix`.
       -w, --with-timing                    Show the time taken by the function.
       -m, --min-r-version <MIN_R_VERSION>  The mimimum R version to be used by the linter. Some rules only work starting from a specific version.
-          --output-format <OUTPUT_FORMAT>  Output serialization format for violations. [default: full] [possible values: full, concise, github, json, sarif]
+          --output-format <OUTPUT_FORMAT>  Output serialization format for violations. [default: full] [possible values: full, concise, grouped, compact, checkstyle, github, json, junit, prometheus, rdjson, sarif, teamcity]
+          --max-width <MAX_WIDTH>          Maximum width of the output. Long lines of code are truncated to fit in this width. Defaults to the width of the terminal.
+          --max-diagnostics <N>            Maximum number of violations to print with `--output-format full`, `concise`, or `grouped`. The summary still counts all violations.
+      -q, --quiet                          Only print the summary with `--output-format full`, `concise`, or `grouped`, without the violations.
+          --explain <RULE>                 Print the documentation of a rule instead of checking files, e.g. `jarl check --explain any_is_na`.
           --assignment <ASSIGNMENT>        [DEPRECATED: use `[lint.assignment]` in jarl.toml] Assignment operator to use, can be either `<-` or `=`.
           --statistics                     Show counts for every rule with at least one violation.
+          --exit-nonzero-on <SEVERITIES>   Severities of violations that lead to a non-zero exit code, separated by a comma (no spaces), for example `--exit-nonzero-on error`. Defaults to all severities: `error,warning,info`.
           --add-jarl-ignore[=<REASON>]     Automatically insert a `# jarl-ignore` comment to suppress all violations.
                                            The default reason can be customized with `--add-jarl-ignore="my_reason"`.
       -h, --help                           Print help (see a summary with '-h')
@@ -92,11 +104,21 @@ fn test_parsing_error() -> anyhow::Result<()> {
         @"
 
     success: false
-    exit_code: 255
+    exit_code: 1
     ----- stdout -----
+    error: syntax_error
+     --> test.R:1:1
+      |
+    1 | f <-
+      | ^^^^ This code couldn't be parsed.
+      |
+      = help: Fixes are disabled in this file until the syntax error is fixed.
+
+
+    ── Summary ──────────────────────────────────────
+    Found 1 error.
 
     ----- stderr -----
-    Error: Failed to parse test.R due to syntax errors.
     "
     );
 
@@ -117,8 +139,16 @@ fn test_parsing_error_for_some_files() -> anyhow::Result<()> {
         @"
 
     success: false
-    exit_code: 255
+    exit_code: 1
     ----- stdout -----
+    error: syntax_error
+     --> test.R:1:1
+      |
+    1 | f <-
+      | ^^^^ This code couldn't be parsed.
+      |
+      = help: Fixes are disabled in this file until the syntax error is fixed.
+
     warning: any_is_na
      --> test2.R:1:1
       |
@@ -129,11 +159,10 @@ fn test_parsing_error_for_some_files() -> anyhow::Result<()> {
 
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 2 errors.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
-    Error: Failed to parse test.R due to syntax errors.
     "
     );
 
@@ -154,7 +183,7 @@ fn test_parsing_error_with_valid_code_in_same_file() -> anyhow::Result<()> {
         @"
 
     success: false
-    exit_code: 255
+    exit_code: 1
     ----- stdout -----
     warning: any_is_na
      --> test.R:1:1
@@ -164,12 +193,19 @@ fn test_parsing_error_with_valid_code_in_same_file() -> anyhow::Result<()> {
       |
       = help: Use `anyNA(...)` instead.
 
+    error: syntax_error
+     --> test.R:2:1
+      |
+    2 | f <-
+      | ^^^^ This code couldn't be parsed.
+      |
+      = help: Fixes are disabled in this file until the syntax error is fixed.
+
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 2 errors.
 
     ----- stderr -----
-    Error: Failed to parse test.R due to syntax errors.
     "
     );
 
@@ -618,6 +654,137 @@ fn test_fix_options() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_fix_only_skips_rules_without_applicable_fix() -> anyhow::Result<()> {
+    // The unsafe fix of `class_equals` is not applied without `--unsafe-fixes`,
+    // and `duplicated_arguments` has no fix, so neither is reported.
+    let case = CliTest::with_file(
+        "test.R",
+        "any(is.na(x))\nclass(x) == 'foo'\nlist(x = 1, x = 2)\n",
+    )?;
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--fix")
+            .arg("--fix-only")
+            .arg("--allow-no-vcs")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ── Summary ──────────────────────────────────────
+    All checks passed!
+
+    ----- stderr -----
+    "
+    );
+    assert_eq!(
+        case.read_file("test.R")?,
+        "anyNA(x)\nclass(x) == 'foo'\nlist(x = 1, x = 2)\n"
+    );
+
+    // Same for the rules whose fixes are disabled in `jarl.toml`.
+    case.write_file("test.R", "any(is.na(x))\n")?;
+    case.write_file("jarl.toml", "[lint]\nunfixable = [\"any_is_na\"]\n")?;
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--fix")
+            .arg("--fix-only")
+            .arg("--allow-no-vcs")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ── Summary ──────────────────────────────────────
+    All checks passed!
+
+    ----- stderr -----
+    "
+    );
+    assert_eq!(case.read_file("test.R")?, "any(is.na(x))\n");
+
+    Ok(())
+}
+
+#[test]
+fn test_unsafe_fixes_for_some_rules() -> anyhow::Result<()> {
+    // Both rules have unsafe fixes, only the ones of `all_equal` are applied.
+    let case = CliTest::with_file("test.R", "!all.equal(x, y)\nclass(x) == 'foo'\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--fix")
+            .arg("--unsafe-fixes=all_equal")
+            .arg("--allow-no-vcs")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ── Summary ──────────────────────────────────────
+    All checks passed!
+
+    ----- stderr -----
+    "
+    );
+    assert_eq!(
+        case.read_file("test.R")?,
+        "!isTRUE(all.equal(x, y))\nclass(x) == 'foo'\n"
+    );
+
+    // Same with `unsafe-fixes` in `jarl.toml`.
+    case.write_file("test.R", "!all.equal(x, y)\nclass(x) == 'foo'\n")?;
+    case.write_file("jarl.toml", "[lint]\nunsafe-fixes = [\"class_equals\"]\n")?;
+    case.command()
+        .arg("check")
+        .arg(".")
+        .arg("--fix")
+        .arg("--allow-no-vcs")
+        .run();
+    assert_eq!(
+        case.read_file("test.R")?,
+        "!all.equal(x, y)\ninherits(x, 'foo')\n"
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--unsafe-fixes=foo")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Unknown rules in `--unsafe-fixes`: foo
+    "
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_safe_and_unsafe_lints() -> anyhow::Result<()> {
     let case = CliTest::with_files([("test.R", "any(is.na(x))"), ("test2.R", "!all.equal(x, y)")])?;
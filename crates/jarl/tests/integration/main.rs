@@ -11,21 +11,40 @@ mod allow_dirty;
 mod allow_no_vcs;
 mod assignment;
 mod comments;
+mod completions;
+mod config;
+mod diff;
 mod edge_cases;
 mod exclude;
+mod extends;
+mod fix;
 mod help;
 mod helpers;
 mod incompatible_args;
+mod init;
 mod jarl;
+mod migrate_lintr;
 mod min_r_version;
+mod monorepo;
 mod no_default_exclude;
 mod output_format;
+mod overrides;
+mod package_dependency;
 mod per_file_ignores;
+mod preview;
+mod profile;
+mod respect_staged;
 mod rmd;
 mod roxygen;
+mod roxygen_completeness;
 mod rule;
+mod rule_paths;
 mod rules;
+mod self_update;
+mod severity;
 mod statistics;
+mod stdin;
 mod toml;
 mod toml_hierarchical;
 mod toml_rule_args;
+mod tui;
@@ -0,0 +1,213 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_migrate_lintr() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            ".lintr",
+            r#"linters: linters_with_defaults(
+    line_length_linter(120),
+    any_is_na_linter(),
+    undesirable_function_linter(fun = c(browser = NULL)),
+    quotes_linter = NULL,
+    object_name_linter = NULL
+  )
+exclusions: list(
+    "R/RcppExports.R",
+    "R/utils.R" = list(T_and_F_symbol_linter = Inf),
+    "R/other.R" = 1:10
+  )
+encoding: "UTF-8"
+"#,
+        ),
+        ("R/utils.R", "x <- T\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("migrate-lintr")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Created ./jarl.toml from ./.lintr.
+
+    The following linters have no equivalent in Jarl:
+      - line_length_linter
+
+    Some settings were not converted:
+      - The exclusion of specific lines in `R/other.R` was not converted, use `# jarl-ignore` comments instead.
+      - The arguments of `undesirable_function_linter()` were not converted, see the options of the corresponding rules in `jarl.toml`.
+
+    ----- stderr -----
+    "
+    );
+
+    insta::assert_snapshot!(case.read_file("jarl.toml")?, @r#"
+    # Converted from `.lintr` with `jarl migrate-lintr`. All the options are
+    # described in https://jarl.etiennebacher.com/reference/config-file.
+
+    [lint]
+    extend-select = ["any_is_na", "undesirable_function"]
+    ignore = ["quotes"]
+    exclude = ["R/RcppExports.R"]
+
+    [lint.per-file-ignores]
+    "R/utils.R" = ["true_false_symbol"]
+    "#);
+
+    // The converted config is valid.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ── Summary ──────────────────────────────────────
+    All checks passed!
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_migrate_lintr_no_lintr_file() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("migrate-lintr")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: No `.lintr` file found in `.`.
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_migrate_lintr_does_not_overwrite() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (".lintr", "linters: list(any_is_na_linter())\n"),
+        ("jarl.toml", "[lint]\nselect = [\"seq\"]\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("migrate-lintr")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: ./jarl.toml already exists. Use `--force` to overwrite it.
+    "
+    );
+    assert_eq!(case.read_file("jarl.toml")?, "[lint]\nselect = [\"seq\"]\n");
+
+    case.command().arg("migrate-lintr").arg("--force").run();
+    assert!(
+        case.read_file("jarl.toml")?
+            .contains("select = [\"any_is_na\"]")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_migrate_lintr_convert_nolint() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "R/foo.R",
+            "x <- any(is.na(y)) # nolint: any_is_na_linter, line_length_linter.
+# nolint start: T_and_F_symbol_linter.
+z <- T
+# nolint end
+",
+        ),
+        ("R/bar.R", "x <- 1 # nolint\n"),
+        ("R/baz.R", "x <- 1\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("migrate-lintr")
+            .arg("--convert-nolint=converted from lintr")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Skipped: R/bar.R:1: Jarl requires a rule name, but this `# nolint` comment doesn't list any linter.
+    Modified: Converted 3 `# nolint` comment(s) in R/foo.R
+
+    Summary: Converted 3 `# nolint` comment(s) across 1 file(s).
+
+    ----- stderr -----
+    "
+    );
+
+    insta::assert_snapshot!(case.read_file("R/foo.R")?, @"
+    # jarl-ignore any_is_na: converted from lintr
+    x <- any(is.na(y))
+    # jarl-ignore-start true_false_symbol: converted from lintr
+    z <- T
+    # jarl-ignore-end true_false_symbol
+    ");
+    assert_eq!(case.read_file("R/bar.R")?, "x <- 1 # nolint\n");
+
+    // The new comments are valid and suppress the violations.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ── Summary ──────────────────────────────────────
+    All checks passed!
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use crate::helpers::{CliTest, CommandExt};
+
+/// Each package of a monorepo uses the minimum R version of its own
+/// DESCRIPTION.
+#[test]
+fn test_monorepo_min_r_version_per_package() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "pkgA/DESCRIPTION",
+            "Package: pkgA\nVersion: 1.0.0\nDepends: R (>= 4.5.0)",
+        ),
+        ("pkgA/R/foo.R", "grep('a', x, value = TRUE)\n"),
+        (
+            "pkgB/DESCRIPTION",
+            "Package: pkgB\nVersion: 1.0.0\nDepends: R (>= 4.1.0)",
+        ),
+        ("pkgB/R/foo.R", "grep('a', x, value = TRUE)\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--select")
+            .arg("grepv")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    pkgA/R/foo.R [1:1] grepv `grep(..., value = TRUE)` can be simplified. Use `grepv(...)` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 error.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+/// `threshold-ignore` of `unused_function` applies to each package separately.
+#[test]
+fn test_monorepo_unused_function_threshold_per_package() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("pkgA/DESCRIPTION", "Package: pkgA\nVersion: 1.0.0"),
+        ("pkgA/NAMESPACE", "export(main)\n"),
+        (
+            "pkgA/R/main.R",
+            "main <- function() 1\nhelper1 <- function() 1\nhelper2 <- function() 2\n",
+        ),
+        ("pkgB/DESCRIPTION", "Package: pkgB\nVersion: 1.0.0"),
+        ("pkgB/NAMESPACE", "export(main)\n"),
+        (
+            "pkgB/R/main.R",
+            "main <- function() 1\nhelper <- function() 1\n",
+        ),
+        (
+            "jarl.toml",
+            r#"
+[lint.unused_function]
+threshold-ignore = 1
+"#,
+        ),
+    ])?;
+
+    let output = case
+        .command()
+        .arg("check")
+        .arg(".")
+        .arg("--output-format")
+        .arg("concise")
+        .run();
+
+    assert!(
+        output
+            .stdout
+            .contains("`helper` is defined but never called in this package.")
+    );
+    assert!(!output.stdout.contains("helper1"));
+    assert!(!output.stdout.contains("helper2"));
+    assert!(
+        output
+            .stdout
+            .contains("2 `unused_function` diagnostics hidden (likely false positives).")
+    );
+
+    Ok(())
+}
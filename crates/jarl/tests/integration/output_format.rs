@@ -82,6 +82,118 @@ fn test_output_concise() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_output_grouped() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "R/test.R",
+            "any(is.na(x))\n\n\n\n\n\n\n\n\nany(duplicated(x))",
+        ),
+        ("test2.R", "any(duplicated(x))"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("grouped")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    R/test.R:
+      1:1  any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+      10:1 any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+
+    test2.R:
+      1:1 any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 3 errors.
+    3 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_output_compact() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))"),
+        ("test2.R", "any(duplicated(x))"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("compact")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    test.R:1:1: any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test2.R:1:1: any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_output_checkstyle() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))\nany(duplicated(x))"),
+        ("test2.R", "any(duplicated(x))"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("checkstyle")
+            .run()
+            .normalize_os_executable_name(),
+        @r#"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    <?xml version="1.0" encoding="UTF-8"?>
+    <checkstyle version="4.3">
+      <file name="test.R">
+        <error line="1" column="1" severity="warning" message="`any(is.na(...))` is inefficient. Use `anyNA(...)` instead." source="jarl.any_is_na"/>
+        <error line="2" column="1" severity="warning" message="`any(duplicated(...))` is inefficient. Use `anyDuplicated(...) &gt; 0` instead." source="jarl.any_duplicated"/>
+      </file>
+      <file name="test2.R">
+        <error line="1" column="1" severity="warning" message="`any(duplicated(...))` is inefficient. Use `anyDuplicated(...) &gt; 0` instead." source="jarl.any_duplicated"/>
+      </file>
+    </checkstyle>
+
+    ----- stderr -----
+    "#
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_output_full() -> anyhow::Result<()> {
     let case = CliTest::with_files([
@@ -131,6 +243,264 @@ fn test_output_full() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Long lines of code are truncated around the diagnostic to fit in
+/// `--max-width`.
+#[test]
+fn test_output_full_max_width() -> anyhow::Result<()> {
+    let padding = "a".repeat(100);
+    let case = CliTest::with_file("test.R", &format!("list({padding}, any(is.na(x)))"))?;
+
+    let output = case.command().arg("check").arg(".").run();
+    assert!(output.stdout.contains(&padding));
+
+    let output = case
+        .command()
+        .arg("check")
+        .arg(".")
+        .arg("--max-width")
+        .arg("60")
+        .run();
+    assert!(!output.stdout.contains(&padding));
+    assert!(output.stdout.contains("..."));
+    assert!(output.stdout.contains("any(is.na(x))"));
+
+    Ok(())
+}
+
+#[test]
+fn test_output_max_diagnostics() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))\nany(is.na(y))"),
+        ("test2.R", "any(duplicated(x))"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .arg("--max-diagnostics")
+            .arg("1")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    test.R [1:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    … and 2 more diagnostics.
+
+    ── Summary ──────────────────────────────────────
+    Found 3 errors.
+    3 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--max-diagnostics")
+            .arg("2")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    warning: any_is_na
+     --> test.R:1:1
+      |
+    1 | any(is.na(x))
+      | ------------- `any(is.na(...))` is inefficient.
+      |
+      = help: Use `anyNA(...)` instead.
+
+    warning: any_is_na
+     --> test.R:2:1
+      |
+    2 | any(is.na(y))
+      | ------------- `any(is.na(...))` is inefficient.
+      |
+      = help: Use `anyNA(...)` instead.
+
+    … and 1 more diagnostic.
+
+    ── Summary ──────────────────────────────────────
+    Found 3 errors.
+    3 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    // No trailing line when all diagnostics are printed.
+    let output = case
+        .command()
+        .arg("check")
+        .arg(".")
+        .arg("--max-diagnostics")
+        .arg("3")
+        .run();
+    assert!(!output.stdout.contains("more diagnostic"));
+
+    Ok(())
+}
+
+#[test]
+fn test_output_max_per_rule_per_file() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("jarl.toml", "[lint]\nmax-per-rule-per-file = 1\n"),
+        (
+            "test.R",
+            "any(is.na(x))\nany(is.na(y))\nany(is.na(z))\nany(duplicated(x))",
+        ),
+        ("test2.R", "any(is.na(x))"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    test.R [1:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test.R [4:1] any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+    test2.R [1:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    … and 2 more violations of `any_is_na` in test.R.
+
+    ── Summary ──────────────────────────────────────
+    Found 5 errors.
+    5 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    // The JSON output contains all violations.
+    let output = case
+        .command()
+        .arg("check")
+        .arg(".")
+        .arg("--output-format")
+        .arg("json")
+        .run();
+    assert_eq!(output.stdout.matches("\"any_is_na\"").count(), 4);
+
+    Ok(())
+}
+
+#[test]
+fn test_max_per_rule_per_file_must_be_positive() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("jarl.toml", "[lint]\nmax-per-rule-per-file = 0\n"),
+        ("test.R", "any(is.na(x))"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    `max-per-rule-per-file` in `[lint]` must be at least 1.
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_output_quiet() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))"),
+        ("test2.R", "any(duplicated(x))"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--quiet")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ── Summary ──────────────────────────────────────
+    Found 2 errors.
+    2 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("-q")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ── Summary ──────────────────────────────────────
+    Found 2 errors.
+    2 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    // Structured formats are not affected.
+    let output = case
+        .command()
+        .arg("check")
+        .arg(".")
+        .arg("--quiet")
+        .arg("--output-format")
+        .arg("json")
+        .run();
+    assert!(output.stdout.contains("any_is_na"));
+
+    Ok(())
+}
+
 #[test]
 fn test_output_json() -> anyhow::Result<()> {
     let case = CliTest::with_files([
@@ -160,6 +530,7 @@ fn test_output_json() -> anyhow::Result<()> {
             "body": "`any(is.na(...))` is inefficient.",
             "suggestion": "Use `anyNA(...)` instead."
           },
+          "severity": "warning",
           "filename": "test.R",
           "range": [
             0,
@@ -182,6 +553,7 @@ fn test_output_json() -> anyhow::Result<()> {
             "body": "`any(duplicated(...))` is inefficient.",
             "suggestion": "Use `anyDuplicated(...) > 0` instead."
           },
+          "severity": "warning",
           "filename": "test2.R",
           "range": [
             0,
@@ -229,6 +601,7 @@ fn test_output_json() -> anyhow::Result<()> {
             "body": "`any(is.na(...))` is inefficient.",
             "suggestion": "Use `anyNA(...)` instead."
           },
+          "severity": "warning",
           "filename": "test.R",
           "range": [
             0,
@@ -251,6 +624,7 @@ fn test_output_json() -> anyhow::Result<()> {
             "body": "`any(duplicated(...))` is inefficient.",
             "suggestion": "Use `anyDuplicated(...) > 0` instead."
           },
+          "severity": "warning",
           "filename": "test2.R",
           "range": [
             0,
@@ -331,6 +705,173 @@ fn test_output_github() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_output_junit() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))\nany(duplicated(x))"),
+        ("test2.R", "any(duplicated(x))"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("junit")
+            .run()
+            .normalize_os_executable_name(),
+        @r#"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    <?xml version="1.0" encoding="UTF-8"?>
+    <testsuites name="jarl" tests="3" failures="3">
+      <testsuite name="test.R" tests="2" failures="2">
+        <testcase name="test.R:1:1" classname="jarl.any_is_na" file="test.R" line="1">
+          <failure message="`any(is.na(...))` is inefficient. Use `anyNA(...)` instead." type="warning">test.R:1:1: any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.</failure>
+        </testcase>
+        <testcase name="test.R:2:1" classname="jarl.any_duplicated" file="test.R" line="2">
+          <failure message="`any(duplicated(...))` is inefficient. Use `anyDuplicated(...) &gt; 0` instead." type="warning">test.R:2:1: any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) &gt; 0` instead.</failure>
+        </testcase>
+      </testsuite>
+      <testsuite name="test2.R" tests="1" failures="1">
+        <testcase name="test2.R:1:1" classname="jarl.any_duplicated" file="test2.R" line="1">
+          <failure message="`any(duplicated(...))` is inefficient. Use `anyDuplicated(...) &gt; 0` instead." type="warning">test2.R:1:1: any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) &gt; 0` instead.</failure>
+        </testcase>
+      </testsuite>
+    </testsuites>
+
+    ----- stderr -----
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_output_junit_no_violations() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "any(x)")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("junit")
+            .run()
+            .normalize_os_executable_name(),
+        @r#"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    <?xml version="1.0" encoding="UTF-8"?>
+    <testsuites name="jarl" tests="0" failures="0">
+    </testsuites>
+
+    ----- stderr -----
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_output_rdjson() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "x <- 1\nany(is.na(x))"),
+        ("test2.R", "for (x in x) 1"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("rdjson")
+            .run()
+            .normalize_os_executable_name(),
+        @r#"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    {
+      "source": {
+        "name": "jarl",
+        "url": "https://github.com/etiennebacher/jarl"
+      },
+      "diagnostics": [
+        {
+          "message": "`any(is.na(...))` is inefficient. Use `anyNA(...)` instead.",
+          "location": {
+            "path": "test.R",
+            "range": {
+              "start": {
+                "line": 2,
+                "column": 1
+              },
+              "end": {
+                "line": 2,
+                "column": 14
+              }
+            }
+          },
+          "severity": "WARNING",
+          "code": {
+            "value": "any_is_na",
+            "url": "https://jarl.etiennebacher.com/rules/any_is_na"
+          },
+          "suggestions": [
+            {
+              "range": {
+                "start": {
+                  "line": 2,
+                  "column": 1
+                },
+                "end": {
+                  "line": 2,
+                  "column": 14
+                }
+              },
+              "text": "anyNA(x)"
+            }
+          ]
+        },
+        {
+          "message": "Don't re-use any sequence symbols as the index symbol in a for loop.",
+          "location": {
+            "path": "test2.R",
+            "range": {
+              "start": {
+                "line": 1,
+                "column": 6
+              },
+              "end": {
+                "line": 1,
+                "column": 12
+              }
+            }
+          },
+          "severity": "WARNING",
+          "code": {
+            "value": "for_loop_index",
+            "url": "https://jarl.etiennebacher.com/rules/for_loop_index"
+          }
+        }
+      ]
+    }
+    ----- stderr -----
+    "#
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_output_sarif() -> anyhow::Result<()> {
     let case = CliTest::with_files([
@@ -539,7 +1080,7 @@ fn test_with_parsing_error() -> anyhow::Result<()> {
         @"
 
     success: false
-    exit_code: 255
+    exit_code: 1
     ----- stdout -----
     warning: any_is_na
      --> test.R:1:1
@@ -549,13 +1090,20 @@ fn test_with_parsing_error() -> anyhow::Result<()> {
       |
       = help: Use `anyNA(...)` instead.
 
+    error: syntax_error
+     --> test2.R:1:1
+      |
+    1 | any(
+      | ^^^^ This code couldn't be parsed.
+      |
+      = help: Fixes are disabled in this file until the syntax error is fixed.
+
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 2 errors.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
-    Error: Failed to parse test2.R due to syntax errors.
     "
     );
 
@@ -571,16 +1119,16 @@ fn test_with_parsing_error() -> anyhow::Result<()> {
         @"
 
     success: false
-    exit_code: 255
+    exit_code: 1
     ----- stdout -----
     test.R [1:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    test2.R [1:1] syntax_error This code couldn't be parsed. Fixes are disabled in this file until the syntax error is fixed.
 
     ── Summary ──────────────────────────────────────
-    Found 1 error.
+    Found 2 errors.
     1 fixable with the `--fix` option.
 
     ----- stderr -----
-    Error: Failed to parse test2.R due to syntax errors.
     "
     );
 
@@ -596,7 +1144,7 @@ fn test_with_parsing_error() -> anyhow::Result<()> {
         @r#"
 
     success: false
-    exit_code: 255
+    exit_code: 1
     ----- stdout -----
     {
       "diagnostics": [
@@ -606,6 +1154,7 @@ fn test_with_parsing_error() -> anyhow::Result<()> {
             "body": "`any(is.na(...))` is inefficient.",
             "suggestion": "Use `anyNA(...)` instead."
           },
+          "severity": "warning",
           "filename": "test.R",
           "range": [
             0,
@@ -621,14 +1170,32 @@ fn test_with_parsing_error() -> anyhow::Result<()> {
             "end": 13,
             "to_skip": false
           }
-        }
-      ],
-      "errors": [
+        },
         {
-          "file": "test2.R",
-          "error": "Failed to parse test2.R due to syntax errors."
+          "message": {
+            "name": "syntax_error",
+            "body": "This code couldn't be parsed.",
+            "suggestion": "Fixes are disabled in this file until the syntax error is fixed."
+          },
+          "severity": "error",
+          "filename": "test2.R",
+          "range": [
+            0,
+            4
+          ],
+          "location": {
+            "row": 1,
+            "column": 0
+          },
+          "fix": {
+            "content": "",
+            "start": 0,
+            "end": 0,
+            "to_skip": true
+          }
         }
-      ]
+      ],
+      "errors": []
     }
     ----- stderr -----
     "#
@@ -646,9 +1213,79 @@ fn test_with_parsing_error() -> anyhow::Result<()> {
         @"
 
     success: false
-    exit_code: 255
+    exit_code: 1
     ----- stdout -----
     ::warning title=Jarl (any_is_na),file=test.R,line=1,col=1::test.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::error title=Jarl (syntax_error),file=test2.R,line=1,col=1::test2.R:1:1 [syntax_error] This code couldn't be parsed. Fixes are disabled in this file until the syntax error is fixed.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_output_prometheus() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))\nany(is.na(y))"),
+        ("test2.R", "any(duplicated(x))"),
+        ("test3.R", "x <- 1"),
+    ])?;
+
+    let output = case
+        .command()
+        .arg("check")
+        .arg(".")
+        .arg("--output-format")
+        .arg("prometheus")
+        .run();
+
+    // The duration varies between runs so it isn't in a snapshot.
+    let (metrics, duration) = output.stdout.split_once("jarl_duration_seconds ").unwrap();
+    insta::assert_snapshot!(metrics, @r#"
+    # HELP jarl_diagnostics_total Number of diagnostics reported, by rule.
+    # TYPE jarl_diagnostics_total counter
+    jarl_diagnostics_total{rule="any_duplicated"} 1
+    jarl_diagnostics_total{rule="any_is_na"} 2
+    # HELP jarl_files_checked Number of R files checked.
+    # TYPE jarl_files_checked gauge
+    jarl_files_checked 3
+    # HELP jarl_duration_seconds Time taken to check the files, in seconds.
+    # TYPE jarl_duration_seconds gauge
+    "#);
+    assert!(duration.trim().parse::<f64>().is_ok());
+    assert!(!output.status.success());
+
+    Ok(())
+}
+
+#[test]
+fn test_output_teamcity() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))\nany(duplicated(x))"),
+        ("test2.R", "any(duplicated(x))"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("teamcity")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ##teamcity[inspectionType id='any_duplicated' name='any_duplicated' description='https://jarl.etiennebacher.com/rules/any_duplicated' category='jarl']
+    ##teamcity[inspectionType id='any_is_na' name='any_is_na' description='https://jarl.etiennebacher.com/rules/any_is_na' category='jarl']
+    ##teamcity[inspection typeId='any_is_na' message='`any(is.na(...))` is inefficient. Use `anyNA(...)` instead.' file='test.R' line='1' SEVERITY='WARNING']
+    ##teamcity[inspection typeId='any_duplicated' message='`any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.' file='test.R' line='2' SEVERITY='WARNING']
+    ##teamcity[inspection typeId='any_duplicated' message='`any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.' file='test2.R' line='1' SEVERITY='WARNING']
 
     ----- stderr -----
     "
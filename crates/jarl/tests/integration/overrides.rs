@@ -0,0 +1,276 @@
+use crate::helpers::{CliTest, CommandExt};
+
+/// `ignore` and `extend-select` only change the rules used in the files
+/// matching `files`.
+#[test]
+fn test_overrides_rule_selection() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("R/foo.R", "any(is.na(x))\nany(duplicated(x))\n"),
+        ("tests/test-foo.R", "any(is.na(x))\nany(duplicated(x))\n"),
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_is_na"]
+
+[[lint.overrides]]
+files = ["tests/"]
+ignore = ["any_is_na"]
+extend-select = ["any_duplicated"]
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    R/foo.R [1:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    tests/test-foo.R [2:1] any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 2 errors.
+    2 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+/// `select` replaces the selection of `[lint]`, and overrides that match the
+/// same file are applied in order.
+#[test]
+fn test_overrides_applied_in_order() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("R/foo.R", "any(is.na(x))\nany(duplicated(x))\n"),
+        ("tests/test-foo.R", "any(is.na(x))\nany(duplicated(x))\n"),
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_is_na"]
+
+[[lint.overrides]]
+files = ["**/*.R"]
+select = ["any_duplicated"]
+
+[[lint.overrides]]
+files = ["tests/**"]
+extend-select = ["any_is_na"]
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    R/foo.R [2:1] any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+    tests/test-foo.R [1:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    tests/test-foo.R [2:1] any_duplicated `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 3 errors.
+    3 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+/// Rule tables in an override replace the ones of `[lint]` in matching files.
+#[test]
+fn test_overrides_rule_options() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("R/foo.R", "x <- 'a'\ny <- \"b\"\n"),
+        ("tests/test-foo.R", "x <- 'a'\ny <- \"b\"\n"),
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["quotes"]
+
+[[lint.overrides]]
+files = ["tests/**"]
+
+[lint.overrides.quotes]
+quote = "single"
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    R/foo.R [1:6] quotes Prefer double quotes for string delimiters.
+    tests/test-foo.R [2:6] quotes Prefer single-quotes for string delimiters.
+
+    ── Summary ──────────────────────────────────────
+    Found 2 errors.
+    2 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+/// Rules ignored in the CLI can't be enabled back by an override.
+#[test]
+fn test_overrides_dont_enable_rules_ignored_in_cli() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("tests/test-foo.R", "any(is.na(x))\nany(duplicated(x))\n"),
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_is_na"]
+
+[[lint.overrides]]
+files = ["tests/**"]
+extend-select = ["any_duplicated"]
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--ignore")
+            .arg("any_duplicated")
+            .arg("--output-format")
+            .arg("concise")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    tests/test-foo.R [1:1] any_is_na `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ── Summary ──────────────────────────────────────
+    Found 1 error.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_overrides_unknown_rule_name_errors() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("foo.R", "any(is.na(x))\n"),
+        (
+            "jarl.toml",
+            r#"
+[[lint.overrides]]
+files = ["foo.R"]
+ignore = ["not_a_real_rule"]
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    Unknown rules in `ignore` in `[[lint.overrides]]`: not_a_real_rule
+    "
+    );
+
+    Ok(())
+}
+
+/// Only the rule selection and the rule options can be changed per path.
+#[test]
+fn test_overrides_unsupported_field_errors() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("foo.R", "any(is.na(x))\n"),
+        (
+            "jarl.toml",
+            r#"
+[[lint.overrides]]
+files = ["foo.R"]
+exclude = ["bar.R"]
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    Unknown field `exclude` in `[[lint.overrides]]`. Expected one of: `files`, `select`, `extend-select`, `ignore`, or the options of a rule.
+    "
+    );
+
+    Ok(())
+}
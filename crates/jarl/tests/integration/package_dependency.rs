@@ -0,0 +1,64 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_package_dependency() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "DESCRIPTION",
+            "Package: mypkg\nImports: dplyr\nSuggests: ggplot2, dplyr, knitr\n",
+        ),
+        (
+            "R/foo.R",
+            r#"library(tidyverse)
+f <- function(x) {
+  ggplot2::ggplot(x)
+}
+g <- function(x) {
+  if (requireNamespace("ggplot2", quietly = TRUE)) {
+    ggplot2::ggplot(x)
+  }
+}
+h <- function(x) {
+  rlang::check_installed(c("knitr", "ggplot2"))
+  ggplot2::ggplot(x)
+}
+i <- function(x) {
+  dplyr::filter(x)
+  knitr::kable(x)
+}
+"#,
+        ),
+        // Only code in `R/` is checked.
+        (
+            "tests/testthat/test-foo.R",
+            "library(tidyverse)\nggplot2::ggplot(x)\n",
+        ),
+        ("scripts/bar.R", "library(tidyverse)\nggplot2::ggplot(x)\n"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--select")
+            .arg("package_dependency")
+            .arg("--output-format")
+            .arg("compact")
+            .run()
+            .normalize_os_executable_name(),
+        @r#"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    R/foo.R:1:1: package_dependency `library(tidyverse)` attaches many packages in package code. List the packages that are used in the `Imports` field of DESCRIPTION instead.
+    R/foo.R:3:3: package_dependency `ggplot2` is only in `Suggests` but is used without checking that it is installed. Call `rlang::check_installed("ggplot2")` first, or move `ggplot2` to `Imports`.
+    R/foo.R:16:3: package_dependency `knitr` is only in `Suggests` but is used without checking that it is installed. Call `rlang::check_installed("knitr")` first, or move `knitr` to `Imports`.
+
+    ----- stderr -----
+    "#
+    );
+
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use crate::helpers::{CliTest, CommandExt};
+
+// `unprinted_ggplot` is a preview rule.
+const CODE: &str = "for (i in 1:3) ggplot(d) + geom_bar()\n";
+
+#[test]
+fn test_preview_rules_disabled_by_default() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", CODE)?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ── Summary ──────────────────────────────────────
+    All checks passed!
+
+    ----- stderr -----
+    "
+    );
+
+    // Selecting them explicitly is not enough.
+    for select in ["unprinted_ggplot", "SUSP", "ALL"] {
+        insta::assert_snapshot!(
+            &mut case
+                .command()
+                .arg("check")
+                .arg(".")
+                .arg("--select")
+                .arg(select)
+                .run()
+                .normalize_os_executable_name(),
+            @"
+
+        success: true
+        exit_code: 0
+        ----- stdout -----
+        ── Summary ──────────────────────────────────────
+        All checks passed!
+
+        ----- stderr -----
+        "
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_preview_flag() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", CODE)?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--preview")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    warning: unprinted_ggplot
+     --> test.R:1:16
+      |
+    1 | for (i in 1:3) ggplot(d) + geom_bar()
+      |                ---------------------- This plot is created but not printed, so it is not rendered.
+      |
+      = help: Wrap it in `print()` to render it.
+
+
+    ── Summary ──────────────────────────────────────
+    Found 1 error.
+    1 fix is available with the `--fix --unsafe-fixes` option.
+
+    ----- stderr -----
+    "
+    );
+
+    // Preview rules can still be ignored.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--preview")
+            .arg("--ignore")
+            .arg("unprinted_ggplot")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ── Summary ──────────────────────────────────────
+    All checks passed!
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_preview_toml() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", CODE),
+        (
+            "jarl.toml",
+            r#"
+[lint]
+preview = true
+select = ["any_is_na", "unprinted_ggplot"]
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    warning: unprinted_ggplot
+     --> test.R:1:16
+      |
+    1 | for (i in 1:3) ggplot(d) + geom_bar()
+      |                ---------------------- This plot is created but not printed, so it is not rendered.
+      |
+      = help: Wrap it in `print()` to render it.
+
+
+    ── Summary ──────────────────────────────────────
+    Found 1 error.
+    1 fix is available with the `--fix --unsafe-fixes` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_preview_in_overrides() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", CODE),
+        (
+            "jarl.toml",
+            r#"
+[[lint.overrides]]
+files = ["*.R"]
+preview = true
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    Unknown field `preview` in `[[lint.overrides]]`. Expected one of: `files`, `select`, `extend-select`, `ignore`, or the options of a rule.
+    "
+    );
+
+    Ok(())
+}
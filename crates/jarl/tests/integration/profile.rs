@@ -0,0 +1,247 @@
+use crate::helpers::{CliTest, CommandExt};
+
+// `any_is_na` is enabled by default, `class_equals` is enabled by default and
+// in `SUSP`, and `quotes` is disabled by default.
+const CODE: &str = "any(is.na(x))\nif (class(x) == \"lm\") 1\ny <- 'a'\n";
+
+#[test]
+fn test_profile_cli() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", CODE)?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--profile")
+            .arg("minimal")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (class_equals),file=test.R,line=2,col=5::test.R:2:5 [class_equals] Comparing `class(x)` with `==` or `%in%` can be problematic.
+
+    ----- stderr -----
+    "
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--profile")
+            .arg("recommended")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=test.R,line=1,col=1::test.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::warning title=Jarl (class_equals),file=test.R,line=2,col=5::test.R:2:5 [class_equals] Comparing `class(x)` with `==` or `%in%` can be problematic.
+
+    ----- stderr -----
+    "
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--profile")
+            .arg("strict")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=test.R,line=1,col=1::test.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::warning title=Jarl (class_equals),file=test.R,line=2,col=5::test.R:2:5 [class_equals] Comparing `class(x)` with `==` or `%in%` can be problematic.
+    ::warning title=Jarl (quotes),file=test.R,line=3,col=6::test.R:3:6 [quotes] Prefer double quotes for string delimiters.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+/// `extend-select` and `ignore` are applied on top of the profile, and
+/// `--profile` takes precedence over `select` in `jarl.toml`.
+#[test]
+fn test_profile_toml() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", CODE),
+        (
+            "jarl.toml",
+            r#"
+[lint]
+profile = "minimal"
+extend-select = ["quotes"]
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (class_equals),file=test.R,line=2,col=5::test.R:2:5 [class_equals] Comparing `class(x)` with `==` or `%in%` can be problematic.
+    ::warning title=Jarl (quotes),file=test.R,line=3,col=6::test.R:3:6 [quotes] Prefer double quotes for string delimiters.
+
+    ----- stderr -----
+    "
+    );
+
+    case.write_file(
+        "jarl.toml",
+        r#"
+[lint]
+select = ["quotes"]
+"#,
+    )?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--profile")
+            .arg("strict")
+            .arg("--ignore")
+            .arg("quotes")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=test.R,line=1,col=1::test.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::warning title=Jarl (class_equals),file=test.R,line=2,col=5::test.R:2:5 [class_equals] Comparing `class(x)` with `==` or `%in%` can be problematic.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+/// `select` in `jarl.toml` replaces the profile.
+#[test]
+fn test_profile_toml_with_select() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", CODE),
+        (
+            "jarl.toml",
+            r#"
+[lint]
+profile = "strict"
+select = ["any_is_na"]
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=test.R,line=1,col=1::test.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_profile_invalid() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", CODE)?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--profile")
+            .arg("pedantic")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'pedantic' for '--profile <PROFILE>': Invalid profile `pedantic`. Expected one of: `minimal`, `recommended`, `strict`.
+
+    For more information, try '--help'.
+    "
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--profile")
+            .arg("strict")
+            .arg("--select")
+            .arg("any_is_na")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--profile <PROFILE>' cannot be used with '--select <RULES>'
+
+    Usage: jarl check --profile <PROFILE> <FILES>...
+
+    For more information, try '--help'.
+    "
+    );
+
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use std::path::Path;
+use std::process::Command;
+
+use crate::helpers::{CliTest, CommandExt, create_commit, git_init};
+
+fn git(dir: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("git").args(args).current_dir(dir).output()?;
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+#[test]
+fn test_respect_staged_partially_staged_file() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "a <- 1\nb <- 2\nc <- 3\nd <- 4\n")?;
+    git_init(case.root())?;
+    create_commit(&case.root().join("test.R"), case.root())?;
+
+    // The first line is staged, the last one isn't.
+    case.write_file("test.R", "any(is.na(a))\nb <- 2\nc <- 3\nd <- 4\n")?;
+    git(case.root(), &["add", "test.R"])?;
+    case.write_file(
+        "test.R",
+        "any(is.na(a))\nb <- 2\nc <- 3\nd <- 4\nany(is.na(e))\n",
+    )?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--respect-staged")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ── Summary ──────────────────────────────────────
+    All checks passed!
+
+    ----- stderr -----
+    "
+    );
+
+    // The fix is staged and also applied to the working tree, where the
+    // unstaged line is left untouched.
+    assert_eq!(
+        git(case.root(), &["show", ":test.R"])?,
+        "anyNA(a)\nb <- 2\nc <- 3\nd <- 4\n"
+    );
+    assert_eq!(
+        case.read_file("test.R")?,
+        "anyNA(a)\nb <- 2\nc <- 3\nd <- 4\nany(is.na(e))\n"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_respect_staged_conflict_with_unstaged_changes() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "a <- 1\n")?;
+    git_init(case.root())?;
+    create_commit(&case.root().join("test.R"), case.root())?;
+
+    case.write_file("test.R", "any(is.na(a))\n")?;
+    git(case.root(), &["add", "test.R"])?;
+    case.write_file("test.R", "any(is.na(a)) # unstaged\n")?;
+
+    let output = case
+        .command()
+        .arg("check")
+        .arg(".")
+        .arg("--respect-staged")
+        .run();
+    assert!(output.status.success());
+
+    // The fix conflicts with the unstaged change, so it is only staged.
+    assert_eq!(git(case.root(), &["show", ":test.R"])?, "anyNA(a)\n");
+    assert_eq!(case.read_file("test.R")?, "any(is.na(a)) # unstaged\n");
+
+    Ok(())
+}
+
+#[test]
+fn test_respect_staged_reports_staged_content() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "x <- 1\n")?;
+    git_init(case.root())?;
+    create_commit(&case.root().join("test.R"), case.root())?;
+
+    // The violation is only in the staged content.
+    case.write_file("test.R", "if (TRUE) 1\n")?;
+    git(case.root(), &["add", "test.R"])?;
+    case.write_file("test.R", "x <- 1\n")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--respect-staged")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    warning: if_always_true
+     --> test.R:1:5
+      |
+    1 | if (TRUE) 1
+      |     ---- `if` condition always evaluates to `TRUE`.
+      |
+      = help: Modify the `if` condition, or keep only the body.
+
+
+    ── Summary ──────────────────────────────────────
+    Found 1 error.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
@@ -99,6 +99,53 @@ any(is.na(x))
     Ok(())
 }
 
+#[test]
+fn test_rnw_basic_lint() -> anyhow::Result<()> {
+    let case = CliTest::with_file(
+        "test.Rnw",
+        "
+\\documentclass{article}
+\\begin{document}
+
+<<setup, echo=FALSE>>=
+any(is.na(x))
+@
+
+\\end{document}
+",
+    )?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    warning: any_is_na
+     --> test.Rnw:6:1
+      |
+    6 | any(is.na(x))
+      | ------------- `any(is.na(...))` is inefficient.
+      |
+      = help: Use `anyNA(...)` instead.
+
+
+    ── Summary ──────────────────────────────────────
+    Found 1 error.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Chunk suppression
 // ---------------------------------------------------------------------------
@@ -681,6 +728,67 @@ any(is.na(x))
     Ok(())
 }
 
+/// Chunks with one of the options of `skip-chunks` are not linted.
+#[test]
+fn test_rmd_skip_chunks() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[lint]
+skip-chunks = ["eval=FALSE"]
+"#,
+        ),
+        (
+            "test.Rmd",
+            "
+```{r, eval=FALSE}
+any(is.na(x))
+```
+
+```{r}
+#| eval: false
+any(is.na(x))
+```
+
+```{r}
+any(is.na(y))
+```
+",
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    warning: any_is_na
+      --> test.Rmd:12:1
+       |
+    12 | any(is.na(y))
+       | ------------- `any(is.na(...))` is inefficient.
+       |
+       = help: Use `anyNA(...)` instead.
+
+
+    ── Summary ──────────────────────────────────────
+    Found 1 error.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Parse errors
 // ---------------------------------------------------------------------------
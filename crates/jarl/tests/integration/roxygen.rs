@@ -308,6 +308,57 @@ check-roxygen = false
     Ok(())
 }
 
+#[test]
+fn test_roxygen_examples_skipped_rules() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "DESCRIPTION",
+            "Package: testpkg\nTitle: Test\nVersion: 0.0.1\n",
+        ),
+        (
+            "R/test.R",
+            "\
+#' Title
+#' @examples
+#' head(letters, 37)
+#' any(is.na(x))
+foo <- function(x) head(x, 37)
+",
+        ),
+        (
+            "jarl.toml",
+            "\
+[lint]
+select = [\"any_is_na\", \"magic_numbers\"]
+",
+        ),
+    ])?;
+
+    // `magic_numbers` is not applied to examples.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::warning title=Jarl (any_is_na),file=R/test.R,line=4,col=4::R/test.R:4:4 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::warning title=Jarl (magic_numbers),file=R/test.R,line=5,col=28::R/test.R:5:28 [magic_numbers] `37` is a magic number. Store it in a constant with a descriptive name.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // Roxygen linting skipped for files outside an R package
 // ---------------------------------------------------------------------------
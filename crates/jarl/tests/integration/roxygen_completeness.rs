@@ -0,0 +1,84 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_roxygen_completeness() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("DESCRIPTION", "Package: mypkg\nVersion: 1.0.0\n"),
+        (
+            "NAMESPACE",
+            "export(add)\nexport(documented)\nexport(inherited)\n",
+        ),
+        (
+            "R/foo.R",
+            r#"#' Add two numbers
+#'
+#' @param x A number.
+#' @param z A number.
+#' @export
+add <- function(x, y) {
+  x + y
+}
+
+#' Documented
+#'
+#' @param x,... Values.
+#' @returns `x`.
+#' @export
+documented <- function(x, ...) x
+
+#' @inheritParams add
+#' @inherit add return
+#' @export
+inherited <- function(x, y) x
+
+#' Not exported
+#' @param a A value.
+internal <- function(b) b
+"#,
+        ),
+    ])?;
+
+    let output = case
+        .command()
+        .arg("check")
+        .arg(".")
+        .arg("--select")
+        .arg("roxygen_completeness")
+        .arg("--output-format")
+        .arg("compact")
+        .run();
+
+    assert!(output.stdout.contains(
+        "R/foo.R:4:1: roxygen_completeness `@param z` documents an argument that `add()` doesn't have."
+    ));
+    assert!(output.stdout.contains(
+        "R/foo.R:6:1: roxygen_completeness `add()` is exported but its documentation has no `@return` tag."
+    ));
+    assert!(output.stdout.contains(
+        "R/foo.R:6:1: roxygen_completeness Argument `y` of the exported function `add()` is not documented."
+    ));
+    assert!(!output.stdout.contains("documented()"));
+    assert!(!output.stdout.contains("inherited()"));
+    assert!(!output.stdout.contains("internal()"));
+
+    Ok(())
+}
+
+/// The rule is disabled by default.
+#[test]
+fn test_roxygen_completeness_disabled_by_default() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("DESCRIPTION", "Package: mypkg\nVersion: 1.0.0\n"),
+        ("NAMESPACE", "export(add)\n"),
+        (
+            "R/foo.R",
+            "#' Add\n#' @export\nadd <- function(x, y) x + y\n",
+        ),
+    ])?;
+
+    let output = case.command().arg("check").arg(".").run();
+
+    assert!(!output.stdout.contains("roxygen_completeness"));
+
+    Ok(())
+}
@@ -145,6 +145,12 @@ fn test_known_rule_disabled_by_default() -> anyhow::Result<()> {
 
     - [https://style.tidyverse.org/syntax.html#assignment-1](https://style.tidyverse.org/syntax.html#assignment-1)
 
+    ## Options
+
+    This rule can be configured in `[lint.assignment]` in `jarl.toml` with the following options: `operator`.
+
+    See the [configuration reference](../reference/config-file.md) for details.
+
     ----- stderr -----
     "#
     );
@@ -396,3 +402,40 @@ fn test_rule_without_name_shows_help() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// `jarl check --explain` prints the same documentation as `jarl rule`.
+#[test]
+fn test_check_explain() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+    let explain = case
+        .command()
+        .arg("check")
+        .arg("--explain")
+        .arg("all_equal")
+        .run();
+    let rule = case.command().arg("rule").arg("all_equal").run();
+
+    assert!(explain.status.success());
+    assert_eq!(explain.stdout, rule.stdout);
+    assert!(explain.stdout.contains("## What it does"));
+
+    Ok(())
+}
+
+/// `jarl check --explain` with an unknown rule errors with a suggestion.
+#[test]
+fn test_check_explain_unknown_rule() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+    let output = case
+        .command()
+        .arg("check")
+        .arg("--explain")
+        .arg("all_equl")
+        .run();
+
+    assert!(!output.status.success());
+    assert!(output.stderr.contains("unknown rule `all_equl`"));
+    assert!(output.stderr.contains("Did you mean `all_equal`?"));
+
+    Ok(())
+}
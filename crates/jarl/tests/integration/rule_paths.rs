@@ -0,0 +1,188 @@
+use crate::helpers::{CliTest, CommandExt};
+
+/// With `include`, a rule only runs on the files matching one of the patterns.
+#[test]
+fn test_rule_include_restricts_files() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("R/foo.R", "any(is.na(x))\n"),
+        ("data-raw/bar.R", "any(is.na(x))\n"),
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_is_na"]
+
+[lint.any_is_na]
+include = ["R/"]
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    warning: any_is_na
+     --> R/foo.R:1:1
+      |
+    1 | any(is.na(x))
+      | ------------- `any(is.na(...))` is inefficient.
+      |
+      = help: Use `anyNA(...)` instead.
+
+
+    ── Summary ──────────────────────────────────────
+    Found 1 error.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+/// With `exclude`, a rule doesn't run on the files matching one of the
+/// patterns. Other rules still run on those files.
+#[test]
+fn test_rule_exclude_skips_files() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("R/foo.R", "any(is.na(x))\n"),
+        ("data-raw/bar.R", "any(is.na(x))\nany(duplicated(x))\n"),
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_is_na", "any_duplicated"]
+
+[lint.any_is_na]
+exclude = ["data-raw/"]
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("data-raw")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    warning: any_duplicated
+     --> data-raw/bar.R:2:1
+      |
+    2 | any(duplicated(x))
+      | ------------------ `any(duplicated(...))` is inefficient.
+      |
+      = help: Use `anyDuplicated(...) > 0` instead.
+
+
+    ── Summary ──────────────────────────────────────
+    Found 1 error.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+/// `include`/`exclude` can be combined with the other options of a rule.
+#[test]
+fn test_rule_paths_with_rule_options() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("R/foo.R", "list(x = 1, x = 2)\nfoo(x = 1, x = 2)\n"),
+        ("tests/bar.R", "foo(x = 1, x = 2)\n"),
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["duplicated_arguments"]
+
+[lint.duplicated_arguments]
+extend-skipped-functions = ["list"]
+exclude = ["tests/"]
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name(),
+        @r#"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    warning: duplicated_arguments
+     --> R/foo.R:2:1
+      |
+    2 | foo(x = 1, x = 2)
+      | ----------------- Avoid duplicated arguments in function calls. Duplicated argument(s): "x".
+      |
+
+
+    ── Summary ──────────────────────────────────────
+    Found 1 error.
+
+    ----- stderr -----
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_rule_paths_invalid_glob_pattern() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("foo.R", "any(is.na(x))\n"),
+        (
+            "jarl.toml",
+            r#"
+[lint.any_is_na]
+include = ["[z-a]"]
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    Invalid `include` pattern '[z-a]' in `[lint.any_is_na]`: error parsing glob '[z-a]': invalid range; 'z' > 'a'
+    "
+    );
+
+    Ok(())
+}
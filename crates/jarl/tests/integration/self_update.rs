@@ -0,0 +1,26 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[cfg(not(feature = "self-update"))]
+#[test]
+fn test_self_without_feature() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+
+    insta::assert_snapshot!(
+        case.command()
+            .arg("self")
+            .arg("verify")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: This version of Jarl was built without `jarl self`. Use the tool that installed Jarl (e.g. `pip` or `cargo`) to update it, or install it with the standalone installer.
+    "
+    );
+
+    Ok(())
+}
@@ -0,0 +1,270 @@
+use crate::helpers::{CliTest, CommandExt};
+
+/// `[lint.severity]` changes the severity of some rules. Individual rules take
+/// precedence over groups.
+#[test]
+fn test_severity_in_toml() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))"),
+        ("test2.R", "any(duplicated(x))"),
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_is_na", "any_duplicated"]
+
+[lint.severity]
+any_is_na = "error"
+PERF = "info"
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::error title=Jarl (any_is_na),file=test.R,line=1,col=1::test.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::notice title=Jarl (any_duplicated),file=test2.R,line=1,col=1::test2.R:1:1 [any_duplicated] `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+/// Only violations whose severity is passed to `--exit-nonzero-on` lead to a
+/// non-zero exit code, but all violations are still reported.
+#[test]
+fn test_exit_nonzero_on() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))"),
+        ("test2.R", "any(duplicated(x))\nany(duplicated(y))"),
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_is_na", "any_duplicated"]
+
+[lint.severity]
+any_is_na = "info"
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .arg("--exit-nonzero-on")
+            .arg("error")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ::notice title=Jarl (any_is_na),file=test.R,line=1,col=1::test.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::warning title=Jarl (any_duplicated),file=test2.R,line=1,col=1::test2.R:1:1 [any_duplicated] `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+    ::warning title=Jarl (any_duplicated),file=test2.R,line=2,col=1::test2.R:2:1 [any_duplicated] `any(duplicated(...))` is inefficient. Use `anyDuplicated(...) > 0` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--statistics")
+            .arg("--exit-nonzero-on")
+            .arg("error,warning")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+        2 [*] any_duplicated
+        1 [*] any_is_na
+
+    Rules with `[*]` have an automatic safe fix.
+    Rules with `[^]` have an automatic unsafe fix.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_exit_nonzero_on_invalid_severity() -> anyhow::Result<()> {
+    let case = CliTest::with_files([("test.R", "any(is.na(x))")])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--exit-nonzero-on")
+            .arg("fatal")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'fatal' for '--exit-nonzero-on <SEVERITIES>': Invalid severity `fatal`. Expected one of: `error`, `warning`, `info`.
+
+    For more information, try '--help'.
+    "
+    );
+
+    Ok(())
+}
+
+/// An unknown rule name in `[lint.severity]` is a configuration error.
+#[test]
+fn test_severity_unknown_rule_name_errors() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))"),
+        (
+            "jarl.toml",
+            r#"
+[lint.severity]
+not_a_real_rule = "error"
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    Unknown rules in `[lint.severity]`: not_a_real_rule
+    "
+    );
+
+    Ok(())
+}
+
+/// `[lint.severity-by-kind]` takes precedence over `[lint.severity]` for the
+/// kinds of files it lists.
+#[test]
+fn test_severity_by_kind() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("DESCRIPTION", "Package: mypkg\n"),
+        ("R/foo.R", "any(is.na(x))\n"),
+        ("scripts/bar.R", "any(is.na(x))\n"),
+        ("report.Rmd", "```{r}\nany(is.na(x))\n```\n"),
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["any_is_na"]
+
+[lint.severity]
+any_is_na = "info"
+
+[lint.severity-by-kind]
+package = "error"
+notebook = "warning"
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .arg("--output-format")
+            .arg("github")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    ::error title=Jarl (any_is_na),file=R/foo.R,line=1,col=1::R/foo.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::warning title=Jarl (any_is_na),file=report.Rmd,line=2,col=1::report.Rmd:2:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+    ::notice title=Jarl (any_is_na),file=scripts/bar.R,line=1,col=1::scripts/bar.R:1:1 [any_is_na] `any(is.na(...))` is inefficient. Use `anyNA(...)` instead.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_severity_by_kind_unknown_kind_errors() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        ("test.R", "any(is.na(x))"),
+        (
+            "jarl.toml",
+            r#"
+[lint.severity-by-kind]
+vignette = "error"
+"#,
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    Unknown kinds of files in `[lint.severity-by-kind]`: vignette. Expected one of: `package`, `script`, `notebook`.
+    "
+    );
+
+    Ok(())
+}
@@ -42,7 +42,7 @@ stop('x')
     ----- stdout -----
        12 [*] any_is_na
         2 [^] condition_call
-        1 [ ] implicit_assignment
+        1 [^] implicit_assignment
 
     Rules with `[*]` have an automatic safe fix.
     Rules with `[^]` have an automatic unsafe fix.
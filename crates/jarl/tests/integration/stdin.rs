@@ -0,0 +1,260 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[test]
+fn test_stdin() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+
+    // The file doesn't need to exist.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("--stdin-filename")
+            .arg("R/foo.R")
+            .arg("-")
+            .run_with_stdin("x <- 1\nany(is.na(x))\n")
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    warning: any_is_na
+     --> R/foo.R:2:1
+      |
+    2 | any(is.na(x))
+      | ------------- `any(is.na(...))` is inefficient.
+      |
+      = help: Use `anyNA(...)` instead.
+
+
+    ── Summary ──────────────────────────────────────
+    Found 1 error.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    // The content of the file on disk is not used.
+    case.write_file("R/foo.R", "any(duplicated(x))")?;
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("--stdin-filename")
+            .arg("R/foo.R")
+            .arg("-")
+            .run_with_stdin("x <- 1\n")
+            .normalize_os_executable_name(),
+        @"
+
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    ── Summary ──────────────────────────────────────
+    All checks passed!
+
+    ----- stderr -----
+    "
+    );
+
+    // The snippet shows the code from stdin, not the file on disk.
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("--stdin-filename")
+            .arg("R/foo.R")
+            .arg("-")
+            .run_with_stdin("x <- c(1, NA)
+any(is.na(x))
+")
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    warning: any_is_na
+     --> R/foo.R:2:1
+      |
+    2 | any(is.na(x))
+      | ------------- `any(is.na(...))` is inefficient.
+      |
+      = help: Use `anyNA(...)` instead.
+
+
+    ── Summary ──────────────────────────────────────
+    Found 1 error.
+    1 fixable with the `--fix` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_stdin_uses_settings_of_filename() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+    case.write_file(
+        "subdir/jarl.toml",
+        r#"
+[lint]
+ignore = ["any_is_na"]
+"#,
+    )?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("--stdin-filename")
+            .arg("subdir/foo.R")
+            .arg("-")
+            .run_with_stdin("any(is.na(x))\nany(duplicated(x))\n")
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    warning: any_duplicated
+     --> subdir/foo.R:2:1
+      |
+    2 | any(duplicated(x))
+      | ------------------ `any(duplicated(...))` is inefficient.
+      |
+      = help: Use `anyDuplicated(...) > 0` instead.
+
+
+    ── Summary ──────────────────────────────────────
+    Found 1 error.
+    1 fixable with the `--fix` option.
+
+    ── Notes ────────────────────────────────────────
+    Used '[TEMP_DIR]/subdir/jarl.toml'
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_stdin_excluded_filename() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+    case.write_file(
+        "jarl.toml",
+        r#"
+[lint]
+exclude = ["data-raw/"]
+"#,
+    )?;
+
+    for filename in ["data-raw/foo.R", "renv/activate.R"] {
+        insta::assert_snapshot!(
+            &mut case
+                .command()
+                .arg("check")
+                .arg("--stdin-filename")
+                .arg(filename)
+                .arg("-")
+                .run_with_stdin("any(is.na(x))\n")
+                .normalize_os_executable_name(),
+            @"
+
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        "
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_stdin_requires_dash() -> anyhow::Result<()> {
+    let case = CliTest::with_file("foo.R", "any(is.na(x))")?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("--stdin-filename")
+            .arg("foo.R")
+            .arg(".")
+            .run_with_stdin("any(is.na(x))\n")
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: `--stdin-filename` requires `-` as the only path to check, e.g. `jarl check --stdin-filename R/foo.R -`.
+    "
+    );
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("-")
+            .run_with_stdin("any(is.na(x))\n")
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Checking code from stdin with `-` requires `--stdin-filename`, e.g. `jarl check --stdin-filename R/foo.R -`.
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_stdin_and_fix_incompatible() -> anyhow::Result<()> {
+    let case = CliTest::new()?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg("--stdin-filename")
+            .arg("foo.R")
+            .arg("-")
+            .arg("--fix")
+            .run_with_stdin("any(is.na(x))\n")
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the argument '--stdin-filename <FILENAME>' cannot be used with '--fix'
+
+    Usage: jarl check --stdin-filename <FILENAME> <FILES>...
+
+    For more information, try '--help'.
+    "
+    );
+
+    Ok(())
+}
@@ -717,13 +717,199 @@ unknown_field = ["value"]
     ----- stderr -----
     jarl failed
       Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
-    Unknown field `unknown_field` in `[lint]`. Expected one of: `select`, `extend-select`, `ignore`, `fixable`, `unfixable`, `exclude`, `default-exclude`, `include`, `per-file-ignores`, `check-roxygen`, `fix-roxygen`.
+    Unknown field `unknown_field` in `[lint]`. Expected one of: `profile`, `select`, `extend-select`, `ignore`, `fixable`, `unfixable`, `unsafe-fixes`, `exclude`, `extend-exclude`, `default-exclude`, `respect-gitignore`, `respect-rbuildignore`, `include-vignettes`, `include`, `per-file-ignores`, `severity`, `severity-by-kind`, `overrides`, `check-roxygen`, `fix-roxygen`, `skip-chunks`, `fixture-functions`, `preview`, `max-per-rule-per-file`.
     "
     );
 
     Ok(())
 }
 
+#[test]
+fn test_unknown_toml_field_suggests_close_name() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[lint.unreacheable-code]
+stopping-functions = ["abort"]
+"#,
+        ),
+        ("test.R", "any(is.na(x))"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @r#"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    Unknown field `unreacheable-code` in `[lint]`. Expected one of: `profile`, `select`, `extend-select`, `ignore`, `fixable`, `unfixable`, `unsafe-fixes`, `exclude`, `extend-exclude`, `default-exclude`, `respect-gitignore`, `respect-rbuildignore`, `include-vignettes`, `include`, `per-file-ignores`, `severity`, `severity-by-kind`, `overrides`, `check-roxygen`, `fix-roxygen`, `skip-chunks`, `fixture-functions`, `preview`, `max-per-rule-per-file`.
+      Help: Did you mean "unreachable_code"?
+    "#
+    );
+
+    // Misspelled rule names in other tables get suggestions too.
+    case.write_file("jarl.toml", "[lint.severity]\nany_is_naa = \"error\"\n")?;
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @r#"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    Unknown rules in `[lint.severity]`: any_is_naa
+      Help: Did you mean "any_is_na"?
+    "#
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_unknown_options_in_rule_table() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[lint.any_is_na]
+exclude = ["tests/**"]
+skipped-functions = ["foo"]
+"#,
+        ),
+        ("test.R", "any(is.na(x))"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    Unknown options in `[lint.any_is_na]`. This rule only accepts `include` and `exclude`.
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_fixture_functions() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[lint]
+select = ["implicit_assignment"]
+fixture-functions = ["local_test_db"]
+"#,
+        ),
+        (
+            "test.R",
+            "local_test_db(path <- tempfile())\nwithr::local_options(list(digits = d <- 3))",
+        ),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @"
+
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    warning: implicit_assignment
+     --> test.R:2:36
+      |
+    2 | withr::local_options(list(digits = d <- 3))
+      |                                    ------ Avoid implicit assignments in function calls.
+      |
+
+
+    ── Summary ──────────────────────────────────────
+    Found 1 error.
+    1 fix is available with the `--fix --unsafe-fixes` option.
+
+    ----- stderr -----
+    "
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_invalid_fixture_functions() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[lint]
+fixture-functions = ["withr::"]
+"#,
+        ),
+        ("test.R", "any(is.na(x))"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @r#"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    Invalid pattern in `fixture-functions` in `[lint]`: "withr::". Expected a function name like "local_options" or "withr::local_*".
+    "#
+    );
+
+    Ok(())
+}
+
 #[test]
 fn test_toml_without_linter_section() -> anyhow::Result<()> {
     let case = CliTest::with_files([
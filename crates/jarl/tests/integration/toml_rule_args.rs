@@ -683,6 +683,87 @@ quote = "foo"
     Ok(())
 }
 
+// scalar_logical_check ----------------------------------------
+
+#[test]
+fn test_scalar_logical_check_invalid_target_is_error() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[lint.scalar_logical_check]
+target = "checkmate"
+"#,
+        ),
+        ("test.R", "is.logical(x) && length(x) == 1 && !is.na(x)"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @r#"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    Invalid value for `target` in `[lint.scalar_logical_check]`: "checkmate". Expected "base" or "rlang".
+    "#
+    );
+
+    Ok(())
+}
+
+// split_apply_combine ----------------------------------------
+
+#[test]
+fn test_split_apply_combine_invalid_backend_is_error() -> anyhow::Result<()> {
+    let case = CliTest::with_files([
+        (
+            "jarl.toml",
+            r#"
+[lint]
+extend-select = ["split_apply_combine"]
+
+[lint.split_apply_combine]
+backend = "polars"
+"#,
+        ),
+        ("test.R", "do.call(rbind, lapply(split(df, df$g), f))"),
+    ])?;
+
+    insta::assert_snapshot!(
+        &mut case
+            .command()
+            .arg("check")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name()
+            .normalize_temp_paths(),
+        @r#"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    jarl failed
+      Cause: Invalid configuration in [TEMP_DIR]/jarl.toml:
+    Invalid value for `backend` in `[lint.split_apply_combine]`: "polars". Expected "base", "dplyr", or "data.table".
+    "#
+    );
+
+    Ok(())
+}
+
 // unreachable_code ----------------------------------------
 
 #[test]
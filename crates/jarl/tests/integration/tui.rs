@@ -0,0 +1,26 @@
+use crate::helpers::{CliTest, CommandExt};
+
+#[cfg(not(feature = "tui"))]
+#[test]
+fn test_tui_without_feature() -> anyhow::Result<()> {
+    let case = CliTest::with_file("test.R", "any(is.na(x))\n")?;
+
+    insta::assert_snapshot!(
+        case.command()
+            .arg("tui")
+            .arg(".")
+            .run()
+            .normalize_os_executable_name(),
+        @"
+
+    success: false
+    exit_code: 255
+    ----- stdout -----
+
+    ----- stderr -----
+    Error: This version of Jarl was built without `jarl tui`. It is available in the binaries of the releases, installed with the standalone installer.
+    "
+    );
+
+    Ok(())
+}
@@ -1,14 +1,18 @@
 //! Codegen tools for generating Syntax and AST definitions. Derived from Rust analyzer's codegen
 //!
 mod r_json_schema;
+mod rule_docs;
 
 use bpaf::Bpaf;
 
 pub use self::r_json_schema::generate_json_schema;
+pub use self::rule_docs::generate_rule_docs;
 
 #[derive(Debug, Clone, Bpaf)]
 #[bpaf(options)]
 pub enum TaskCommand {
     #[bpaf(command, long("json-schema"))]
     JsonSchema,
+    #[bpaf(command, long("docgen"))]
+    Docgen,
 }
@@ -1,6 +1,6 @@
 use xtask::{project_root, pushd, Result};
 
-use xtask_codegen::{generate_json_schema, task_command, TaskCommand};
+use xtask_codegen::{generate_json_schema, generate_rule_docs, task_command, TaskCommand};
 
 fn main() -> Result<()> {
     let _d = pushd(project_root());
@@ -10,6 +10,9 @@ fn main() -> Result<()> {
         TaskCommand::JsonSchema => {
             generate_json_schema()?;
         }
+        TaskCommand::Docgen => {
+            generate_rule_docs()?;
+        }
     }
 
     Ok(())
@@ -1,7 +1,13 @@
 use std::path::PathBuf;
 
+use jarl_core::rule_set::{Category, Rule};
+use serde_json::{json, Map, Value};
+
 const ROOT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../");
 
+/// Fields of `[lint]` (and of `[[lint.overrides]]`) that take a list of rules.
+const RULE_LIST_FIELDS: &[&str] = &["select", "extend-select", "ignore", "fixable", "unfixable"];
+
 pub fn generate_json_schema() -> anyhow::Result<()> {
     let schema = json_schema()?;
     let schema_path = schema_path();
@@ -11,10 +17,166 @@ pub fn generate_json_schema() -> anyhow::Result<()> {
 
 fn json_schema() -> anyhow::Result<String> {
     let schema = schemars::schema_for!(jarl_core::toml::TomlOptions);
+    let mut schema = serde_json::to_value(&schema)?;
+    add_rule_selectors(&mut schema)?;
+    add_rule_tables(&mut schema)?;
     let schema = serde_json::to_string_pretty(&schema)?;
     Ok(schema)
 }
 
+/// Restrict the rules listed in `select`, `ignore`, `[lint.severity]`, etc.
+/// to the names of the rules, the rule groups, and `ALL`.
+///
+/// Those fields are plain strings in the Rust types, so this can't be derived.
+fn add_rule_selectors(schema: &mut Value) -> anyhow::Result<()> {
+    let selectors: Vec<&str> = Rule::all()
+        .iter()
+        .map(|rule| rule.name())
+        .chain(Category::ALL.iter().map(|category| category.as_str()))
+        .chain(std::iter::once("ALL"))
+        .collect();
+
+    let defs = object_mut(schema, "$defs")?;
+    defs.insert(
+        "RuleSelector".to_string(),
+        json!({
+            "description": "The name of a rule, a group of rules (e.g. `PERF`), or `ALL`.",
+            "type": "string",
+            "enum": selectors,
+        }),
+    );
+    sort_keys(defs);
+
+    let selector = json!({ "$ref": "#/$defs/RuleSelector" });
+    for def in ["LinterTomlOptions", "OverrideTomlOptions"] {
+        let properties = object_mut(&mut defs[def], "properties")?;
+        for field in RULE_LIST_FIELDS {
+            if let Some(property) = properties.get_mut(*field) {
+                property["items"] = selector.clone();
+            }
+        }
+        if let Some(property) = properties.get_mut("per-file-ignores") {
+            property["additionalProperties"]["items"] = selector.clone();
+        }
+        if let Some(property) = properties.get_mut("severity") {
+            property["propertyNames"] = selector.clone();
+        }
+    }
+
+    Ok(())
+}
+
+/// Every `[lint.<rule>]` table accepts `include` and `exclude`, even for rules
+/// that don't have options. Those fields are removed before deserializing
+/// `jarl.toml`, so they are not part of the Rust types.
+fn add_rule_tables(schema: &mut Value) -> anyhow::Result<()> {
+    let path_properties = json!({
+        "include": {
+            "description": "Patterns of the files this rule runs on, in the same format as the top-level `include`.",
+            "type": "array",
+            "items": { "type": "string" },
+        },
+        "exclude": {
+            "description": "Patterns of the files this rule doesn't run on, in the same format as the top-level `exclude`.",
+            "type": "array",
+            "items": { "type": "string" },
+        },
+    });
+
+    let defs = object_mut(schema, "$defs")?;
+    defs.insert(
+        "RulePaths".to_string(),
+        json!({
+            "description": "Options of a rule that doesn't have other options.",
+            "type": "object",
+            "properties": path_properties,
+            "additionalProperties": false,
+        }),
+    );
+
+    // Rule tables that already exist point to an `*Options` definition,
+    // possibly through another definition (e.g. `AssignmentConfig`).
+    let mut options_defs = Vec::new();
+    let linter_properties = defs["LinterTomlOptions"]["properties"].clone();
+    for rule in Rule::all() {
+        if let Some(property) = linter_properties.get(rule.name()) {
+            collect_options_defs(defs, property, &mut options_defs);
+        }
+    }
+    for def in options_defs {
+        let properties = object_mut(&mut defs[def.as_str()], "properties")?;
+        for (name, property) in path_properties.as_object().into_iter().flatten() {
+            properties.insert(name.clone(), property.clone());
+        }
+    }
+    sort_keys(defs);
+
+    let properties = object_mut(&mut defs["LinterTomlOptions"], "properties")?;
+    for rule in Rule::all() {
+        if properties.contains_key(rule.name()) {
+            continue;
+        }
+        properties.insert(
+            rule.name().to_string(),
+            json!({
+                "title": format!("Options for the `{}` rule", rule.name()),
+                "anyOf": [
+                    { "$ref": "#/$defs/RulePaths" },
+                    { "type": "null" },
+                ],
+            }),
+        );
+    }
+    sort_keys(properties);
+
+    Ok(())
+}
+
+/// Collect the names of the `*Options` definitions referenced in `value`.
+fn collect_options_defs(defs: &Map<String, Value>, value: &Value, found: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            let def_name = map
+                .get("$ref")
+                .and_then(Value::as_str)
+                .and_then(|reference| reference.strip_prefix("#/$defs/"));
+            if let Some(def_name) = def_name {
+                if def_name.ends_with("Options") {
+                    if !found.iter().any(|name| name == def_name) {
+                        found.push(def_name.to_string());
+                    }
+                } else if let Some(def) = defs.get(def_name) {
+                    collect_options_defs(defs, def, found);
+                }
+            }
+            for value in map.values() {
+                collect_options_defs(defs, value, found);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                collect_options_defs(defs, value, found);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn object_mut<'a>(value: &'a mut Value, key: &str) -> anyhow::Result<&'a mut Map<String, Value>> {
+    value
+        .get_mut(key)
+        .and_then(Value::as_object_mut)
+        .ok_or_else(|| anyhow::anyhow!("Expected an object in `{key}` of the JSON schema."))
+}
+
+/// Sort the keys of `map` alphabetically, like the definitions generated by
+/// `schemars`.
+fn sort_keys(map: &mut Map<String, Value>) {
+    let mut entries: Vec<(String, Value)> = std::mem::take(map).into_iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    map.extend(entries);
+}
+
 fn schema_path() -> PathBuf {
     PathBuf::from(ROOT_DIR)
         .join("artifacts")
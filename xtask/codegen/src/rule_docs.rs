@@ -0,0 +1,167 @@
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context};
+use serde_json::Value;
+
+const ROOT_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../");
+
+/// Write the documentation of each rule to `docs/rules/<rule>.md`.
+///
+/// The documentation comes from the doc comment of the function implementing
+/// the rule, from `## What it does` to the function signature. Rules that have
+/// options get an additional `## Options` section listing them, based on the
+/// same schema as `artifacts/jarl.schema.json`.
+pub fn generate_rule_docs() -> anyhow::Result<()> {
+    let root = PathBuf::from(ROOT_DIR);
+    let lints_dir = root
+        .join("crates")
+        .join("jarl-core")
+        .join("src")
+        .join("lints");
+    let docs_dir = root.join("docs").join("rules");
+
+    let schema = serde_json::to_value(schemars::schema_for!(jarl_core::toml::TomlOptions))?;
+
+    let mut sources = Vec::new();
+    collect_rust_files(&lints_dir, &mut sources)?;
+    sources.sort();
+
+    if docs_dir.exists() {
+        fs::remove_dir_all(&docs_dir)?;
+    }
+    fs::create_dir_all(&docs_dir)?;
+
+    for source in sources {
+        let content = fs::read_to_string(&source)?;
+        let name = source
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or_else(|| anyhow!("Invalid file name: {}", source.display()))?;
+
+        let Some(mut doc) = rule_doc_from_source(name, &content)? else {
+            continue;
+        };
+
+        let options = rule_options(&schema, name);
+        if !options.is_empty() {
+            let options = options
+                .iter()
+                .map(|option| format!("`{option}`"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            doc.push_str(&format!(
+                "\n## Options\n\n\
+                 This rule can be configured in `[lint.{name}]` in `jarl.toml` with the \
+                 following options: {options}.\n\n\
+                 See the [configuration reference](../reference/config-file.md) for details.\n"
+            ));
+        }
+
+        fs::write(docs_dir.join(format!("{name}.md")), doc)?;
+    }
+
+    Ok(())
+}
+
+/// Extract the markdown documentation of the rule `name` from the content of
+/// its source file, or `None` if the file doesn't document a rule.
+fn rule_doc_from_source(name: &str, content: &str) -> anyhow::Result<Option<String>> {
+    let lines: Vec<&str> = content.lines().collect();
+
+    let Some(start) = lines
+        .iter()
+        .position(|line| line.contains("## What it does"))
+    else {
+        return Ok(None);
+    };
+
+    let version = lines
+        .iter()
+        .find_map(|line| line.strip_prefix("/// Version added: "))
+        .filter(|version| version.split('.').count() == 3)
+        .with_context(|| format!("Couldn't find the 'Version added' line for rule '{name}'."))?;
+
+    // The doc comment ends before the function implementing the rule.
+    let end = lines[start..]
+        .iter()
+        .position(|line| {
+            line.starts_with("impl Violation for")
+                || line.starts_with("fn ")
+                || line.starts_with("pub fn")
+        })
+        .map_or(lines.len(), |offset| start + offset);
+
+    let mut doc = format!(
+        "# {name}\n::: {{.callout-note title=\"Added in {version}\" .low-opacity}}\n:::\n\n"
+    );
+    for line in &lines[start..end] {
+        let line = line
+            .strip_prefix("/// ")
+            .or_else(|| line.strip_prefix("///"))
+            .unwrap_or(line);
+        doc.push_str(line);
+        doc.push('\n');
+    }
+
+    Ok(Some(doc))
+}
+
+/// Names of the options of the `[lint.<name>]` table in the schema of
+/// `jarl.toml`, if any.
+fn rule_options(schema: &Value, name: &str) -> BTreeSet<String> {
+    let mut options = BTreeSet::new();
+    if let Some(property) = schema.pointer(&format!("/$defs/LinterTomlOptions/properties/{name}")) {
+        collect_option_names(schema, property, &mut options);
+    }
+    options
+}
+
+/// Collect the properties of the `*Options` definitions referenced in `value`.
+fn collect_option_names(schema: &Value, value: &Value, options: &mut BTreeSet<String>) {
+    match value {
+        Value::Object(map) => {
+            let def = map
+                .get("$ref")
+                .and_then(Value::as_str)
+                .and_then(|reference| reference.strip_prefix("#/$defs/"))
+                .and_then(|def_name| {
+                    let def = schema.pointer(&format!("/$defs/{def_name}"))?;
+                    Some((def_name, def))
+                });
+            match def {
+                Some((def_name, def)) if def_name.ends_with("Options") => {
+                    if let Some(Value::Object(properties)) = def.get("properties") {
+                        options.extend(properties.keys().cloned());
+                    }
+                }
+                Some((_, def)) => collect_option_names(schema, def, options),
+                None => {}
+            }
+            for value in map.values() {
+                collect_option_names(schema, value, options);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                collect_option_names(schema, value, options);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_rust_files(dir: &Path, files: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_rust_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs")
+            && path.file_name().is_some_and(|name| name != "mod.rs")
+        {
+            files.push(path);
+        }
+    }
+    Ok(())
+}